@@ -172,6 +172,26 @@ class _ExerciseDebugScreenState extends ConsumerState<ExerciseDebugScreen> {
       posTagging: (_) => 'POS Tagging',
       crossVerseConnection: (_) => 'Cross-Verse Connection',
       echoRecall: (_) => 'Echo Recall',
+      grammarFeature: (_) => 'Grammar Feature',
+      mutashabihat: (_) => 'Mutashabihat',
+      wordOrder: (_) => 'Word Order',
+      identifySurah: (_) => 'Identify Surah',
+      identifyVerseNumber: (_) => 'Identify Verse Number',
+      identifyPageHalf: (_) => 'Identify Page Half',
+      transliterationToArabic: (_) => 'Transliteration to Arabic',
+      arabicToTransliteration: (_) => 'Arabic to Transliteration',
+      rootFamily: (_) => 'Root Family',
+      lemmaMeaning: (_) => 'Lemma Meaning',
+      recitePage: (_) => 'Recite Page',
+      pageFirstWord: (_) => 'Page First Word',
+      identifyVerseLine: (_) => 'Identify Verse Line',
+      listenIdentifyVerse: (_) => 'Listen and Identify Verse',
+      listenContinue: (_) => 'Listen and Continue',
+      listenIdentifyWord: (_) => 'Listen and Identify Word',
+      identifyTajweedRule: (_) => 'Identify Tajweed Rule',
+      findTajweedRule: (_) => 'Find Tajweed Rule',
+      tafsirKeyLesson: (_) => 'Tafsir Key Lesson',
+      revelationOccasion: (_) => 'Revelation Occasion',
     );
   }
 
@@ -163,6 +163,26 @@ class _ExerciseContainerState extends ConsumerState<ExerciseContainer> {
           // EchoRecall handles its own loading via start_echo_recall
           // No content pre-loading needed here
         },
+        grammarFeature: (e) async {},
+        mutashabihat: (e) async {},
+        wordOrder: (e) async {},
+        identifySurah: (e) async {},
+        identifyVerseNumber: (e) async {},
+        identifyPageHalf: (e) async {},
+        transliterationToArabic: (e) async {},
+        arabicToTransliteration: (e) async {},
+        rootFamily: (e) async {},
+        lemmaMeaning: (e) async {},
+        recitePage: (e) async {},
+        pageFirstWord: (e) async {},
+        identifyVerseLine: (e) async {},
+        listenIdentifyVerse: (e) async {},
+        listenContinue: (e) async {},
+        listenIdentifyWord: (e) async {},
+        identifyTajweedRule: (e) async {},
+        findTajweedRule: (e) async {},
+        tafsirKeyLesson: (e) async {},
+        revelationOccasion: (e) async {},
       );
 
       if (!mounted || requestId != _loadRequestId) {
@@ -305,6 +325,45 @@ class _ExerciseContainerState extends ConsumerState<ExerciseContainer> {
         ayahNodeIds: e.ayahNodeIds,
         onComplete: () => widget.onComplete(true),
       ),
+      // Variants without an in-app widget yet
+      grammarFeature: (_) => _buildUnsupported(),
+      mutashabihat: (_) => _buildUnsupported(),
+      wordOrder: (_) => _buildUnsupported(),
+      identifySurah: (_) => _buildUnsupported(),
+      identifyVerseNumber: (_) => _buildUnsupported(),
+      identifyPageHalf: (_) => _buildUnsupported(),
+      transliterationToArabic: (_) => _buildUnsupported(),
+      arabicToTransliteration: (_) => _buildUnsupported(),
+      rootFamily: (_) => _buildUnsupported(),
+      lemmaMeaning: (_) => _buildUnsupported(),
+      recitePage: (_) => _buildUnsupported(),
+      pageFirstWord: (_) => _buildUnsupported(),
+      identifyVerseLine: (_) => _buildUnsupported(),
+      listenIdentifyVerse: (_) => _buildUnsupported(),
+      listenContinue: (_) => _buildUnsupported(),
+      listenIdentifyWord: (_) => _buildUnsupported(),
+      identifyTajweedRule: (_) => _buildUnsupported(),
+      findTajweedRule: (_) => _buildUnsupported(),
+      tafsirKeyLesson: (_) => _buildUnsupported(),
+      revelationOccasion: (_) => _buildUnsupported(),
+    );
+  }
+
+  Widget _buildUnsupported() {
+    return Column(
+      mainAxisAlignment: MainAxisAlignment.center,
+      children: [
+        Text(
+          'This exercise type is not available in the app yet.',
+          style: Theme.of(context).textTheme.bodyLarge,
+          textAlign: TextAlign.center,
+        ),
+        const SizedBox(height: 20),
+        TextButton(
+          onPressed: () => widget.onComplete(false),
+          child: const Text('Skip'),
+        ),
+      ],
     );
   }
 
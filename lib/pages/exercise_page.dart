@@ -599,6 +599,26 @@ class _ExercisePageState extends ConsumerState<ExercisePage>
       posTagging: (_) => _timingQuick,
       crossVerseConnection: (_) => _timingStandard,
       echoRecall: (_) => _timingExtended,
+      grammarFeature: (_) => _timingQuick,
+      mutashabihat: (_) => _timingStandard,
+      wordOrder: (_) => _timingStandard,
+      identifySurah: (_) => _timingQuick,
+      identifyVerseNumber: (_) => _timingQuick,
+      identifyPageHalf: (_) => _timingQuick,
+      transliterationToArabic: (_) => _timingQuick,
+      arabicToTransliteration: (_) => _timingStandard,
+      rootFamily: (_) => _timingStandard,
+      lemmaMeaning: (_) => _timingStandard,
+      recitePage: (_) => _timingExtended,
+      pageFirstWord: (_) => _timingQuick,
+      identifyVerseLine: (_) => _timingQuick,
+      listenIdentifyVerse: (_) => _timingStandard,
+      listenContinue: (_) => _timingStandard,
+      listenIdentifyWord: (_) => _timingQuick,
+      identifyTajweedRule: (_) => _timingQuick,
+      findTajweedRule: (_) => _timingStandard,
+      tafsirKeyLesson: (_) => _timingStandard,
+      revelationOccasion: (_) => _timingStandard,
     );
   }
 }
@@ -266,6 +266,26 @@ class _SandboxPageState extends ConsumerState<SandboxPage> {
       posTagging: (_) => 'POS Tagging',
       crossVerseConnection: (_) => 'Cross-Verse Connection',
       echoRecall: (_) => 'Echo Recall',
+      grammarFeature: (_) => 'Grammar Feature',
+      mutashabihat: (_) => 'Mutashabihat',
+      wordOrder: (_) => 'Word Order',
+      identifySurah: (_) => 'Identify Surah',
+      identifyVerseNumber: (_) => 'Identify Verse Number',
+      identifyPageHalf: (_) => 'Identify Page Half',
+      transliterationToArabic: (_) => 'Transliteration to Arabic',
+      arabicToTransliteration: (_) => 'Arabic to Transliteration',
+      rootFamily: (_) => 'Root Family',
+      lemmaMeaning: (_) => 'Lemma Meaning',
+      recitePage: (_) => 'Recite Page',
+      pageFirstWord: (_) => 'Page First Word',
+      identifyVerseLine: (_) => 'Identify Verse Line',
+      listenIdentifyVerse: (_) => 'Listen and Identify Verse',
+      listenContinue: (_) => 'Listen and Continue',
+      listenIdentifyWord: (_) => 'Listen and Identify Word',
+      identifyTajweedRule: (_) => 'Identify Tajweed Rule',
+      findTajweedRule: (_) => 'Find Tajweed Rule',
+      tafsirKeyLesson: (_) => 'Tafsir Key Lesson',
+      revelationOccasion: (_) => 'Revelation Occasion',
     );
   }
 
@@ -292,6 +312,26 @@ class _SandboxPageState extends ConsumerState<SandboxPage> {
       posTagging: (e) => 'Node: ${e.nodeId}',
       crossVerseConnection: (e) => 'Related: ${e.relatedVerseIds.length}',
       echoRecall: (e) => 'Ayahs: ${e.ayahNodeIds.length}',
+      grammarFeature: (e) => 'Feature: ${e.feature}',
+      mutashabihat: (e) => 'Verse: ${e.verseKey}',
+      wordOrder: (e) => 'Words: ${e.correctSequence.length}',
+      identifySurah: (e) => 'Verse: ${e.verseKey}',
+      identifyVerseNumber: (e) => 'Verse: ${e.verseKey}',
+      identifyPageHalf: (e) => 'Verse: ${e.verseKey}',
+      transliterationToArabic: (e) => 'Node: ${e.nodeId}',
+      arabicToTransliteration: (e) => 'Verse: ${e.verseKey}',
+      rootFamily: (e) => 'Root: ${e.rootId}',
+      lemmaMeaning: (e) => 'Lemmas: ${e.lemmaIds.length}',
+      recitePage: (e) => 'Page: ${e.page}',
+      pageFirstWord: (e) => 'Page: ${e.page}',
+      identifyVerseLine: (e) => 'Verse: ${e.verseKey}',
+      listenIdentifyVerse: (e) => 'Verse: ${e.verseKey}',
+      listenContinue: (e) => 'Verse: ${e.verseKey}',
+      listenIdentifyWord: (e) => 'Verse: ${e.verseKey}',
+      identifyTajweedRule: (e) => 'Word: ${e.wordNodeId}',
+      findTajweedRule: (e) => 'Rule: ${e.ruleName}',
+      tafsirKeyLesson: (e) => 'Passage: ${e.passageKey}',
+      revelationOccasion: (e) => 'Passage: ${e.passageKey}',
     );
   }
 }
@@ -168,11 +168,11 @@ class SessionNotifier extends Notifier<SessionState> {
     if (session == null || currentItem == null) return;
 
     try {
+      // The item is graded on the server from its served exercise; only
+      // whether it was recalled is reported
       await _service.submitItem(
         sessionId: session.id,
-        nodeId: currentItem.nodeId,
-        exerciseType: currentItem.exerciseType,
-        grade: grade,
+        isCorrect: grade > 1,
         durationMs: durationMs,
       );
       await _loadNextItem();
@@ -214,6 +214,26 @@ class SessionNotifier extends Notifier<SessionState> {
         posTagging: (e) => e.nodeId,
         crossVerseConnection: (e) => e.nodeId,
         echoRecall: (e) => e.ayahNodeIds.isNotEmpty ? e.ayahNodeIds.first : '',
+        grammarFeature: (e) => e.nodeId,
+        mutashabihat: (e) => e.nodeId,
+        wordOrder: (e) => e.nodeId,
+        identifySurah: (e) => e.nodeId,
+        identifyVerseNumber: (e) => e.nodeId,
+        identifyPageHalf: (e) => e.nodeId,
+        transliterationToArabic: (e) => e.nodeId,
+        arabicToTransliteration: (e) => e.nodeId,
+        rootFamily: (e) => e.nodeId,
+        lemmaMeaning: (e) => e.nodeId,
+        recitePage: (e) => e.nodeId,
+        pageFirstWord: (e) => e.nodeId,
+        identifyVerseLine: (e) => e.nodeId,
+        listenIdentifyVerse: (e) => e.nodeId,
+        listenContinue: (e) => e.nodeId,
+        listenIdentifyWord: (e) => e.nodeId,
+        identifyTajweedRule: (e) => e.nodeId,
+        findTajweedRule: (e) => e.nodeId,
+        tafsirKeyLesson: (e) => e.nodeId,
+        revelationOccasion: (e) => e.nodeId,
       );
 
       if (exercise is api.ExerciseDataDto_EchoRecall) {
@@ -8,316 +8,269 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 part 'api.freezed.dart';
 
-// These functions are ignored because they are not marked as `pub`: `app`, `chapter_for_ukey`, `populate_nodes_from_content`, `stable_session_item_id`, `sync_setting_key`
-// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ExerciseDto`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`
-
-/// One-time setup: initializes databases and imports graph
-Future<String> setupDatabase({
-  required String contentDbPath,
-  required String userDbPath,
-  required List<int> kgBytes,
-}) => RustLib.instance.api.crateApiSetupDatabase(
-  contentDbPath: contentDbPath,
-  userDbPath: userDbPath,
-  kgBytes: kgBytes,
-);
+            // These functions are ignored because they are not marked as `pub`: `app`, `audio_source_parts`, `chapter_for_ukey`, `from_status`, `node_id_from_ukey`, `node_ids_from_ukeys`, `populate_nodes_from_content`, `record_session_item`, `resolve_status_scope`, `session_budget_mix_setting_key`, `stable_session_item_id`, `stored_arabic_level`, `sync_setting_key`, `tajweed_rule_from_slug`, `validate_exercise_answer`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ExerciseDto`, `PersistedSessionBudgetMix`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `try_from`
+
+
+            /// One-time setup: initializes databases and imports graph
+Future<String>  setupDatabase({required String contentDbPath , required String userDbPath , required List<int> kgBytes }) => RustLib.instance.api.crateApiSetupDatabase(contentDbPath: contentDbPath, userDbPath: userDbPath, kgBytes: kgBytes);
 
 /// Setup with in-memory databases (for testing)
-Future<String> setupDatabaseInMemory({required List<int> kgBytes}) =>
-    RustLib.instance.api.crateApiSetupDatabaseInMemory(kgBytes: kgBytes);
+Future<String>  setupDatabaseInMemory({required List<int> kgBytes }) => RustLib.instance.api.crateApiSetupDatabaseInMemory(kgBytes: kgBytes);
 
 /// Get exercises for review session
-Future<List<ExerciseDataDto>> getExercises({
-  required String userId,
-  required int limit,
-  int? surahFilter,
-  required bool isHighYield,
-}) => RustLib.instance.api.crateApiGetExercises(
-  userId: userId,
-  limit: limit,
-  surahFilter: surahFilter,
-  isHighYield: isHighYield,
-);
+Future<List<ExerciseDataDto>>  getExercises({required String userId , required int limit , int? surahFilter , required bool isHighYield }) => RustLib.instance.api.crateApiGetExercises(userId: userId, limit: limit, surahFilter: surahFilter, isHighYield: isHighYield);
 
 /// Start a new session and persist its state
-Future<SessionDto> startSession({
-  required String userId,
-  required String goalId,
-}) => RustLib.instance.api.crateApiStartSession(userId: userId, goalId: goalId);
+Future<SessionDto>  startSession({required String userId , required String goalId }) => RustLib.instance.api.crateApiStartSession(userId: userId, goalId: goalId);
 
 /// Get the active (incomplete) session for a user
-Future<SessionDto?> getActiveSession({required String userId}) =>
-    RustLib.instance.api.crateApiGetActiveSession(userId: userId);
+Future<SessionDto?>  getActiveSession({required String userId }) => RustLib.instance.api.crateApiGetActiveSession(userId: userId);
 
 /// Get the next session item (exercise) to present
-Future<SessionItemDto?> getNextSessionItem({required String sessionId}) =>
-    RustLib.instance.api.crateApiGetNextSessionItem(sessionId: sessionId);
-
-/// Submit a completed session item
-Future<String> submitSessionItem({
-  required String sessionId,
-  required String nodeId,
-  required String exerciseType,
-  required int grade,
-  required BigInt durationMs,
-}) => RustLib.instance.api.crateApiSubmitSessionItem(
-  sessionId: sessionId,
-  nodeId: nodeId,
-  exerciseType: exerciseType,
-  grade: grade,
-  durationMs: durationMs,
-);
+Future<SessionItemDto?>  getNextSessionItem({required String sessionId }) => RustLib.instance.api.crateApiGetNextSessionItem(sessionId: sessionId);
+
+/// Record the current session item from the app widget's own answer check
+///
+/// For exercises whose answer is checked on the device. The item is the
+/// exercise served by `get_next_session_item`, and its grade is worked out
+/// here from `is_correct`, response time and `hint_level` (0 for none), as
+/// for `submit_session_answer`. Prefer `submit_session_answer`, which also
+/// checks the answer.
+Future<String>  submitSessionItem({required String sessionId , required bool isCorrect , required BigInt durationMs , required int hintLevel }) => RustLib.instance.api.crateApiSubmitSessionItem(sessionId: sessionId, isCorrect: isCorrect, durationMs: durationMs, hintLevel: hintLevel);
+
+/// Check the answer to the current session item's exercise and record the item.
+///
+/// The answer is checked against the exercise served by
+/// `get_next_session_item`. `answer_json` is a serialized `AnswerInput`
+/// (e.g. `{"type":"word_id","value":...}`); anything else is taken as a text
+/// answer. `hint_level` is the most revealing hint shown (see
+/// `get_exercise_hints`), 0 for none. The returned `suggested_grade` is the
+/// grade recorded after accounting for response time and hints.
+Future<ValidationResultDto>  submitSessionAnswer({required String sessionId , required String answerJson , required BigInt durationMs , required int hintLevel }) => RustLib.instance.api.crateApiSubmitSessionAnswer(sessionId: sessionId, answerJson: answerJson, durationMs: durationMs, hintLevel: hintLevel);
+
+/// Progressive hints for an exercise, least revealing first. Reveal them one
+/// at a time; each caps the grade the answer can still earn.
+Future<List<ExerciseHintDto>>  getExerciseHints({required ExerciseDataDto exercise }) => RustLib.instance.api.crateApiGetExerciseHints(exercise: exercise);
+
+/// The hint at `step` (0-indexed) of an exercise's hint ladder, or None once
+/// every hint has been revealed
+Future<ExerciseHintDto?>  getExerciseHint({required ExerciseDataDto exercise , required int step }) => RustLib.instance.api.crateApiGetExerciseHint(exercise: exercise, step: step);
+
+/// Check an answer to an exercise without recording it (see `submit_session_answer`)
+Future<ValidationResultDto>  checkExerciseAnswer({required ExerciseDataDto exercise , required String answerJson }) => RustLib.instance.api.crateApiCheckExerciseAnswer(exercise: exercise, answerJson: answerJson);
 
 /// Complete a session and return summary
-Future<SessionSummaryDto> completeSession({required String sessionId}) =>
-    RustLib.instance.api.crateApiCompleteSession(sessionId: sessionId);
-
-Future<List<SyncMemoryStateDto>> getMemoryStatesSince({
-  required String userId,
-  required PlatformInt64 sinceMillis,
-}) => RustLib.instance.api.crateApiGetMemoryStatesSince(
-  userId: userId,
-  sinceMillis: sinceMillis,
-);
-
-Future<List<SyncSessionDto>> getSessionsSince({
-  required String userId,
-  required PlatformInt64 sinceMillis,
-}) => RustLib.instance.api.crateApiGetSessionsSince(
-  userId: userId,
-  sinceMillis: sinceMillis,
-);
-
-Future<List<SyncSessionItemDto>> getSessionItemsSince({
-  required String userId,
-  required PlatformInt64 sinceMillis,
-}) => RustLib.instance.api.crateApiGetSessionItemsSince(
-  userId: userId,
-  sinceMillis: sinceMillis,
-);
-
-Future<String> upsertMemoryStatesFromRemote({
-  required String userId,
-  required List<SyncMemoryStateDto> states,
-}) => RustLib.instance.api.crateApiUpsertMemoryStatesFromRemote(
-  userId: userId,
-  states: states,
-);
-
-Future<String> upsertSessionsFromRemote({
-  required String userId,
-  required List<SyncSessionDto> sessions,
-}) => RustLib.instance.api.crateApiUpsertSessionsFromRemote(
-  userId: userId,
-  sessions: sessions,
-);
-
-Future<String> upsertSessionItemsFromRemote({
-  required String userId,
-  required List<SyncSessionItemDto> items,
-}) => RustLib.instance.api.crateApiUpsertSessionItemsFromRemote(
-  userId: userId,
-  items: items,
-);
-
-Future<PlatformInt64> getLastSyncTimestamp({required String userId}) =>
-    RustLib.instance.api.crateApiGetLastSyncTimestamp(userId: userId);
-
-Future<String> setLastSyncTimestamp({
-  required String userId,
-  required PlatformInt64 timestamp,
-}) => RustLib.instance.api.crateApiSetLastSyncTimestamp(
-  userId: userId,
-  timestamp: timestamp,
-);
+Future<SessionSummaryDto>  completeSession({required String sessionId }) => RustLib.instance.api.crateApiCompleteSession(sessionId: sessionId);
+
+Future<List<SyncMemoryStateDto>>  getMemoryStatesSince({required String userId , required PlatformInt64 sinceMillis }) => RustLib.instance.api.crateApiGetMemoryStatesSince(userId: userId, sinceMillis: sinceMillis);
+
+Future<List<SyncSessionDto>>  getSessionsSince({required String userId , required PlatformInt64 sinceMillis }) => RustLib.instance.api.crateApiGetSessionsSince(userId: userId, sinceMillis: sinceMillis);
+
+Future<List<SyncSessionItemDto>>  getSessionItemsSince({required String userId , required PlatformInt64 sinceMillis }) => RustLib.instance.api.crateApiGetSessionItemsSince(userId: userId, sinceMillis: sinceMillis);
+
+Future<String>  upsertMemoryStatesFromRemote({required String userId , required List<SyncMemoryStateDto> states }) => RustLib.instance.api.crateApiUpsertMemoryStatesFromRemote(userId: userId, states: states);
+
+Future<String>  upsertSessionsFromRemote({required String userId , required List<SyncSessionDto> sessions }) => RustLib.instance.api.crateApiUpsertSessionsFromRemote(userId: userId, sessions: sessions);
+
+Future<String>  upsertSessionItemsFromRemote({required String userId , required List<SyncSessionItemDto> items }) => RustLib.instance.api.crateApiUpsertSessionItemsFromRemote(userId: userId, items: items);
+
+Future<PlatformInt64>  getLastSyncTimestamp({required String userId }) => RustLib.instance.api.crateApiGetLastSyncTimestamp(userId: userId);
+
+Future<String>  setLastSyncTimestamp({required String userId , required PlatformInt64 timestamp }) => RustLib.instance.api.crateApiSetLastSyncTimestamp(userId: userId, timestamp: timestamp);
+
+/// Get the page exercises for a mushaf page: recite the page and name its
+/// first word
+///
+/// The exercises are generated for the first verse of the page's memorization
+/// goal (`memorization:page-N`), so the content database must have been built
+/// with a mushaf layout.
+Future<List<ExerciseDataDto>>  getPageExercises({required int page }) => RustLib.instance.api.crateApiGetPageExercises(page: page);
+
+/// Rebuild an exercise from its node, type (`ExerciseData::type_name`) and
+/// the `seed` it was sent with
+///
+/// Pass an MCQ's `distractor_strategy` to pick the same distractors again;
+/// without it they are picked at the default difficulty.
+Future<ExerciseDataDto>  regenerateExercise({required String nodeId , required String exerciseType , required BigInt seed , DistractorStrategyDto? distractorStrategy }) => RustLib.instance.api.crateApiRegenerateExercise(nodeId: nodeId, exerciseType: exerciseType, seed: seed, distractorStrategy: distractorStrategy);
 
 /// Get exercises for a specific node (Sandbox/Preview)
-Future<List<ExerciseDataDto>> getExercisesForNode({required String nodeId}) =>
-    RustLib.instance.api.crateApiGetExercisesForNode(nodeId: nodeId);
+Future<List<ExerciseDataDto>>  getExercisesForNode({required String nodeId }) => RustLib.instance.api.crateApiGetExercisesForNode(nodeId: nodeId);
 
 /// Fetch node with metadata for Sandbox
-Future<NodeData?> fetchNodeWithMetadata({required String nodeId}) =>
-    RustLib.instance.api.crateApiFetchNodeWithMetadata(nodeId: nodeId);
+Future<NodeData?>  fetchNodeWithMetadata({required String nodeId }) => RustLib.instance.api.crateApiFetchNodeWithMetadata(nodeId: nodeId);
 
 /// Generate exercise using modern enum-based architecture (V2)
 ///
 /// This returns lightweight ExerciseData containing only keys/IDs.
 /// Flutter can then fetch content based on user preferences (Tajweed, Indopak, etc.)
-Future<ExerciseDataDto> generateExerciseV2({required String nodeId}) =>
-    RustLib.instance.api.crateApiGenerateExerciseV2(nodeId: nodeId);
+Future<ExerciseDataDto>  generateExerciseV2({required String nodeId }) => RustLib.instance.api.crateApiGenerateExerciseV2(nodeId: nodeId);
 
 /// Get verse content
-Future<VerseDto?> getVerse({required String verseKey}) =>
-    RustLib.instance.api.crateApiGetVerse(verseKey: verseKey);
+Future<VerseDto?>  getVerse({required String verseKey }) => RustLib.instance.api.crateApiGetVerse(verseKey: verseKey);
 
 /// Get word content
-Future<WordDto?> getWord({required int wordId}) =>
-    RustLib.instance.api.crateApiGetWord(wordId: wordId);
+Future<WordDto?>  getWord({required int wordId }) => RustLib.instance.api.crateApiGetWord(wordId: wordId);
 
 /// Get all words for a verse
-Future<List<WordDto>> getWordsForVerse({required String verseKey}) =>
-    RustLib.instance.api.crateApiGetWordsForVerse(verseKey: verseKey);
+Future<List<WordDto>>  getWordsForVerse({required String verseKey }) => RustLib.instance.api.crateApiGetWordsForVerse(verseKey: verseKey);
 
 /// Get word at specific position in a verse (resolves WORD_INSTANCE nodes)
-Future<WordDto?> getWordAtPosition({
-  required int chapter,
-  required int verse,
-  required int position,
-}) => RustLib.instance.api.crateApiGetWordAtPosition(
-  chapter: chapter,
-  verse: verse,
-  position: position,
-);
+Future<WordDto?>  getWordAtPosition({required int chapter , required int verse , required int position }) => RustLib.instance.api.crateApiGetWordAtPosition(chapter: chapter, verse: verse, position: position);
 
 /// Get word translation
-Future<String?> getWordTranslation({
-  required int wordId,
-  required int translatorId,
-}) => RustLib.instance.api.crateApiGetWordTranslation(
-  wordId: wordId,
-  translatorId: translatorId,
-);
+Future<String?>  getWordTranslation({required int wordId , required int translatorId }) => RustLib.instance.api.crateApiGetWordTranslation(wordId: wordId, translatorId: translatorId);
 
 /// Process a review
-Future<String> processReview({
-  required String userId,
-  required String nodeId,
-  required int grade,
-}) => RustLib.instance.api.crateApiProcessReview(
-  userId: userId,
-  nodeId: nodeId,
-  grade: grade,
-);
+Future<String>  processReview({required String userId , required String nodeId , required int grade }) => RustLib.instance.api.crateApiProcessReview(userId: userId, nodeId: nodeId, grade: grade);
+
+/// Grade a recitation of `verse_keys` (e.g., "1:1") from an ASR transcript
+///
+/// Each expected word is graded and reviewed; words the transcript skipped,
+/// substituted or repeated are reported along with jumps to other verses.
+Future<RecitationResultDto>  gradeRecitation({required String userId , required List<String> verseKeys , required List<TranscriptWordDto> transcript }) => RustLib.instance.api.crateApiGradeRecitation(userId: userId, verseKeys: verseKeys, transcript: transcript);
 
 /// Get dashboard stats
-Future<DashboardStatsDto> getDashboardStats({required String userId}) =>
-    RustLib.instance.api.crateApiGetDashboardStats(userId: userId);
+Future<DashboardStatsDto>  getDashboardStats({required String userId }) => RustLib.instance.api.crateApiGetDashboardStats(userId: userId);
 
 /// Get detailed stats for charts
-Future<DetailedStatsDto> getDetailedStats({required String userId}) =>
-    RustLib.instance.api.crateApiGetDetailedStats(userId: userId);
+Future<DetailedStatsDto>  getDetailedStats({required String userId }) => RustLib.instance.api.crateApiGetDetailedStats(userId: userId);
 
 /// Get debug stats
-Future<DebugStatsDto> getDebugStats({required String userId}) =>
-    RustLib.instance.api.crateApiGetDebugStats(userId: userId);
+Future<DebugStatsDto>  getDebugStats({required String userId }) => RustLib.instance.api.crateApiGetDebugStats(userId: userId);
 
 /// Close database pools (debug only)
 /// After calling this, the app must be restarted to reinitialize databases.
-Future<String> closeDatabases() =>
-    RustLib.instance.api.crateApiCloseDatabases();
+Future<String>  closeDatabases() => RustLib.instance.api.crateApiCloseDatabases();
 
 /// Get database health status with table counts and issues
-Future<DbHealthDto> getDbHealth() => RustLib.instance.api.crateApiGetDbHealth();
+Future<DbHealthDto>  getDbHealth() => RustLib.instance.api.crateApiGetDbHealth();
 
 /// Reset user progress
-Future<String> reseedDatabase({required String userId}) =>
-    RustLib.instance.api.crateApiReseedDatabase(userId: userId);
+Future<String>  reseedDatabase({required String userId }) => RustLib.instance.api.crateApiReseedDatabase(userId: userId);
 
 /// Get session preview
-Future<List<SessionPreviewDto>> getSessionPreview({
-  required String userId,
-  required int limit,
-  required bool isHighYield,
-}) => RustLib.instance.api.crateApiGetSessionPreview(
-  userId: userId,
-  limit: limit,
-  isHighYield: isHighYield,
-);
+Future<List<SessionPreviewDto>>  getSessionPreview({required String userId , required int limit , required bool isHighYield }) => RustLib.instance.api.crateApiGetSessionPreview(userId: userId, limit: limit, isHighYield: isHighYield);
 
 /// Clear session
-Future<String> clearSession() => RustLib.instance.api.crateApiClearSession();
+Future<String>  clearSession() => RustLib.instance.api.crateApiClearSession();
 
 /// Search nodes
-Future<List<NodeSearchDto>> searchNodes({
-  required String query,
-  required int limit,
-}) => RustLib.instance.api.crateApiSearchNodes(query: query, limit: limit);
+Future<List<NodeSearchDto>>  searchNodes({required String query , required int limit }) => RustLib.instance.api.crateApiSearchNodes(query: query, limit: limit);
 
 /// Get available surahs
-Future<List<SurahInfo>> getAvailableSurahs() =>
-    RustLib.instance.api.crateApiGetAvailableSurahs();
+Future<List<SurahInfo>>  getAvailableSurahs() => RustLib.instance.api.crateApiGetAvailableSurahs();
 
 /// Get all verses for a specific surah with user's preferred translation
-Future<List<VerseWithTranslationDto>> getSurahVerses({
-  required int chapterNumber,
-}) => RustLib.instance.api.crateApiGetSurahVerses(chapterNumber: chapterNumber);
+Future<List<VerseWithTranslationDto>>  getSurahVerses({required int chapterNumber }) => RustLib.instance.api.crateApiGetSurahVerses(chapterNumber: chapterNumber);
 
 /// Get all available languages
-Future<List<LanguageDto>> getLanguages() =>
-    RustLib.instance.api.crateApiGetLanguages();
+Future<List<LanguageDto>>  getLanguages() => RustLib.instance.api.crateApiGetLanguages();
 
 /// Get all translators for a given language
-Future<List<TranslatorDto>> getTranslatorsForLanguage({
-  required String languageCode,
-}) => RustLib.instance.api.crateApiGetTranslatorsForLanguage(
-  languageCode: languageCode,
-);
+Future<List<TranslatorDto>>  getTranslatorsForLanguage({required String languageCode }) => RustLib.instance.api.crateApiGetTranslatorsForLanguage(languageCode: languageCode);
 
 /// Get a specific translator by ID
-Future<TranslatorDto?> getTranslator({required int translatorId}) =>
-    RustLib.instance.api.crateApiGetTranslator(translatorId: translatorId);
+Future<TranslatorDto?>  getTranslator({required int translatorId }) => RustLib.instance.api.crateApiGetTranslator(translatorId: translatorId);
 
 /// Get user's preferred translator ID
-Future<int> getPreferredTranslatorId() =>
-    RustLib.instance.api.crateApiGetPreferredTranslatorId();
+Future<int>  getPreferredTranslatorId() => RustLib.instance.api.crateApiGetPreferredTranslatorId();
 
 /// Set user's preferred translator ID
-Future<String> setPreferredTranslatorId({required int translatorId}) => RustLib
-    .instance
-    .api
-    .crateApiSetPreferredTranslatorId(translatorId: translatorId);
+Future<String>  setPreferredTranslatorId({required int translatorId }) => RustLib.instance.api.crateApiSetPreferredTranslatorId(translatorId: translatorId);
+
+/// Get the script exercises are shown in ("uthmani", "indopak" or "simple")
+Future<String>  getPreferredScript() => RustLib.instance.api.crateApiGetPreferredScript();
+
+/// Set the script exercises are shown and checked in. Content missing in that
+/// script is shown in Uthmani.
+Future<String>  setPreferredScript({required String script }) => RustLib.instance.api.crateApiSetPreferredScript(script: script);
+
+/// Get the learner's Arabic reading level ("none", "basic_reading", ...), if known
+Future<String?>  getArabicLevel() => RustLib.instance.api.crateApiGetArabicLevel();
+
+/// Set the learner's Arabic reading level. Learners at "none" or
+/// "basic_reading" get transliteration exercises on new words.
+Future<String>  setArabicLevel({required String level }) => RustLib.instance.api.crateApiSetArabicLevel(level: level);
 
 /// Get verse translation for a specific translator
-Future<String?> getVerseTranslationByTranslator({
-  required String verseKey,
-  required int translatorId,
-}) => RustLib.instance.api.crateApiGetVerseTranslationByTranslator(
-  verseKey: verseKey,
-  translatorId: translatorId,
-);
+Future<String?>  getVerseTranslationByTranslator({required String verseKey , required int translatorId }) => RustLib.instance.api.crateApiGetVerseTranslationByTranslator(verseKey: verseKey, translatorId: translatorId);
 
 /// List available content packages (optionally filtered)
-Future<List<ContentPackageDto>> getAvailablePackages({
-  String? packageType,
-  String? languageCode,
-}) => RustLib.instance.api.crateApiGetAvailablePackages(
-  packageType: packageType,
-  languageCode: languageCode,
-);
+Future<List<ContentPackageDto>>  getAvailablePackages({String? packageType , String? languageCode }) => RustLib.instance.api.crateApiGetAvailablePackages(packageType: packageType, languageCode: languageCode);
 
 /// List installed content packages
-Future<List<InstalledPackageDto>> getInstalledPackages() =>
-    RustLib.instance.api.crateApiGetInstalledPackages();
+Future<List<InstalledPackageDto>>  getInstalledPackages() => RustLib.instance.api.crateApiGetInstalledPackages();
 
 /// Enable an installed package
-Future<String> enablePackage({required String packageId}) =>
-    RustLib.instance.api.crateApiEnablePackage(packageId: packageId);
+Future<String>  enablePackage({required String packageId }) => RustLib.instance.api.crateApiEnablePackage(packageId: packageId);
 
 /// Disable an installed package
-Future<String> disablePackage({required String packageId}) =>
-    RustLib.instance.api.crateApiDisablePackage(packageId: packageId);
+Future<String>  disablePackage({required String packageId }) => RustLib.instance.api.crateApiDisablePackage(packageId: packageId);
 
 /// Install a translation package from raw bytes
-Future<String> installTranslationPackFromBytes({
-  required String packageId,
-  required List<int> bytes,
-}) => RustLib.instance.api.crateApiInstallTranslationPackFromBytes(
-  packageId: packageId,
-  bytes: bytes,
-);
+Future<String>  installTranslationPackFromBytes({required String packageId , required List<int> bytes }) => RustLib.instance.api.crateApiInstallTranslationPackFromBytes(packageId: packageId, bytes: bytes);
+
+/// Install a package from a local SQLite or zip file
+///
+/// Audio files inside zip packages are extracted under `audio_dir` when given,
+/// and stored in content.db otherwise.
+Future<String>  installPackageFromFile({required String packageId , required String path , String? audioDir }) => RustLib.instance.api.crateApiInstallPackageFromFile(packageId: packageId, path: path, audioDir: audioDir);
+
+/// List reciters from installed and enabled audio packages
+Future<List<ReciterDto>>  getReciters() => RustLib.instance.api.crateApiGetReciters();
+
+/// Get a reciter's audio for a verse (e.g., "1:1") with word timings
+Future<VerseAudioDto?>  getVerseAudio({required String verseKey , required int reciterId }) => RustLib.instance.api.crateApiGetVerseAudio(verseKey: verseKey, reciterId: reciterId);
+
+/// Get a reciter's audio for the word at `position` (1-indexed) of a verse
+Future<WordAudioDto?>  getWordAudio({required String verseKey , required int position , required int reciterId }) => RustLib.instance.api.crateApiGetWordAudio(verseKey: verseKey, position: position, reciterId: reciterId);
+
+/// Get the tajweed annotations of a verse (e.g., "1:1"), for coloring its
+/// words
+Future<List<TajweedAnnotationDto>>  getTajweedAnnotations({required String verseKey }) => RustLib.instance.api.crateApiGetTajweedAnnotations(verseKey: verseKey);
+
+/// Get the tafsirs of installed and enabled tafsir packages
+Future<List<TafsirDto>>  getTafsirs() => RustLib.instance.api.crateApiGetTafsirs();
+
+/// Get a tafsir's passage commenting on a verse (e.g., "2:255")
+Future<TafsirPassageDto?>  getTafsirPassage({required String verseKey , required int tafsirId }) => RustLib.instance.api.crateApiGetTafsirPassage(verseKey: verseKey, tafsirId: tafsirId);
+
+/// Suspend nodes until explicitly unsuspended. Returns the number of nodes affected.
+Future<int>  suspendNodes({required String userId , required String scope }) => RustLib.instance.api.crateApiSuspendNodes(userId: userId, scope: scope);
+
+/// Bury nodes until the given instant (epoch milliseconds). Returns the number of nodes affected.
+Future<int>  buryNodes({required String userId , required String scope , required PlatformInt64 untilMillis }) => RustLib.instance.api.crateApiBuryNodes(userId: userId, scope: scope, untilMillis: untilMillis);
+
+/// Return suspended or buried nodes to normal rotation. Returns the number of nodes affected.
+Future<int>  unsuspendNodes({required String userId , required String scope }) => RustLib.instance.api.crateApiUnsuspendNodes(userId: userId, scope: scope);
+
+/// Clear FSRS/energy state for nodes while keeping their review history.
+/// Returns the number of memory states removed.
+Future<int>  resetNodes({required String userId , required String scope }) => RustLib.instance.api.crateApiResetNodes(userId: userId, scope: scope);
+
+/// Get the scheduling status of a node
+Future<NodeStatusDto>  getNodeStatus({required String userId , required String nodeId }) => RustLib.instance.api.crateApiGetNodeStatus(userId: userId, nodeId: nodeId);
+
+/// Load the semantic grading model from a local model2vec folder
+/// (tokenizer.json, model.safetensors, config.json), e.g. one downloaded by
+/// the app. Until a model is loaded, typed answers are graded lexically.
+Future<void>  initSemanticModelFromDir({required String modelDir }) => RustLib.instance.api.crateApiInitSemanticModelFromDir(modelDir: modelDir);
+
+/// Load the semantic grading model from bundled tokenizer.json and
+/// model.safetensors asset bytes. They are staged as files in `staging_dir`
+/// (e.g. the app's cache directory) while the model loads.
+Future<void>  initSemanticModelFromBytes({required List<int> tokenizerJson , required List<int> modelSafetensors , required String stagingDir }) => RustLib.instance.api.crateApiInitSemanticModelFromBytes(tokenizerJson: tokenizerJson, modelSafetensors: modelSafetensors, stagingDir: stagingDir);
+
+/// Whether semantic grading is available (false: lexical grading)
+Future<bool>  isSemanticModelLoaded() => RustLib.instance.api.crateApiIsSemanticModelLoaded();
 
 /// Start a new Echo Recall session
 ///
 /// Fetches all words from the specified ayahs, retrieves their current
 /// energy levels, and calculates initial visibility.
-Future<EchoRecallStateDto> startEchoRecall({
-  required String userId,
-  required List<String> ayahNodeIds,
-}) => RustLib.instance.api.crateApiStartEchoRecall(
-  userId: userId,
-  ayahNodeIds: ayahNodeIds,
-);
+Future<EchoRecallStateDto>  startEchoRecall({required String userId , required List<String> ayahNodeIds }) => RustLib.instance.api.crateApiStartEchoRecall(userId: userId, ayahNodeIds: ayahNodeIds);
 
 /// Submit a word recall and get updated state
 ///
@@ -326,1425 +279,1766 @@ Future<EchoRecallStateDto> startEchoRecall({
 ///
 /// Note: user_id and ayah_node_ids are included for API consistency but
 /// the stateless pattern means they're only used for context/logging.
-Future<EchoRecallStateDto> submitEchoRecall({
-  required String userId,
-  required List<String> ayahNodeIds,
-  required EchoRecallStateDto state,
-  required String wordNodeId,
-  required int recallTimeMs,
-}) => RustLib.instance.api.crateApiSubmitEchoRecall(
-  userId: userId,
-  ayahNodeIds: ayahNodeIds,
-  state: state,
-  wordNodeId: wordNodeId,
-  recallTimeMs: recallTimeMs,
-);
+Future<EchoRecallStateDto>  submitEchoRecall({required String userId , required List<String> ayahNodeIds , required EchoRecallStateDto state , required String wordNodeId , required int recallTimeMs }) => RustLib.instance.api.crateApiSubmitEchoRecall(userId: userId, ayahNodeIds: ayahNodeIds, state: state, wordNodeId: wordNodeId, recallTimeMs: recallTimeMs);
+
+/// Submit a word recall for an Echo Recall session item and checkpoint it
+///
+/// Same as `submit_echo_recall`, but the updated state is also saved under the
+/// session item so it survives the app being killed (see
+/// `resume_session_echo_recall`). The checkpoint is cleared when the item is
+/// recorded.
+Future<EchoRecallStateDto>  submitSessionEchoRecall({required String sessionId , required int position , required String userId , required List<String> ayahNodeIds , required EchoRecallStateDto state , required String wordNodeId , required int recallTimeMs }) => RustLib.instance.api.crateApiSubmitSessionEchoRecall(sessionId: sessionId, position: position, userId: userId, ayahNodeIds: ayahNodeIds, state: state, wordNodeId: wordNodeId, recallTimeMs: recallTimeMs);
+
+/// Restore the checkpointed state of an Echo Recall session item
+///
+/// Returns None when the item has no unexpired checkpoint, in which case the
+/// exercise is started fresh with `start_echo_recall`.
+Future<EchoRecallStateDto?>  resumeSessionEchoRecall({required String sessionId , required int position }) => RustLib.instance.api.crateApiResumeSessionEchoRecall(sessionId: sessionId, position: position);
 
 /// Get statistics for an Echo Recall session
-Future<EchoRecallStatsDto> echoRecallStats({
-  required EchoRecallStateDto state,
-}) => RustLib.instance.api.crateApiEchoRecallStats(state: state);
+Future<EchoRecallStatsDto>  echoRecallStats({required EchoRecallStateDto state }) => RustLib.instance.api.crateApiEchoRecallStats(state: state);
+
+/// Get the ayah junctions of an Echo Recall session and their strength
+Future<List<AyahJunctionDto>>  echoRecallJunctions({required EchoRecallStateDto state }) => RustLib.instance.api.crateApiEchoRecallJunctions(state: state);
 
 /// Finalize an Echo Recall session
 ///
 /// Persists energy updates to the user's memory states and emits telemetry.
+/// Each ayah junction is reviewed on its transition node (the contextual
+/// memorization of the following ayah), so weak transitions come back sooner.
 /// Accepts per-word timing metrics for detailed analytics.
-Future<EchoRecallResultDto> finalizeEchoRecall({
-  required String userId,
-  required EchoRecallStateDto state,
-  required EchoRecallMetricsDto metrics,
-}) => RustLib.instance.api.crateApiFinalizeEchoRecall(
-  userId: userId,
-  state: state,
-  metrics: metrics,
-);
+Future<EchoRecallResultDto>  finalizeEchoRecall({required String userId , required EchoRecallStateDto state , required EchoRecallMetricsDto metrics }) => RustLib.instance.api.crateApiFinalizeEchoRecall(userId: userId, state: state, metrics: metrics);
+
+/// Submit the next verse of an Ayah Chain session item
+///
+/// The chain is resumed from the item's checkpoint (or started for
+/// `chapter_node_id` if there is none), graded against `user_input`, and
+/// checkpointed again.
+Future<AyahChainStateDto>  submitSessionAyahChain({required String sessionId , required int position , required String userId , required String chapterNodeId , required String userInput }) => RustLib.instance.api.crateApiSubmitSessionAyahChain(sessionId: sessionId, position: position, userId: userId, chapterNodeId: chapterNodeId, userInput: userInput);
+
+/// Restore the checkpointed progress of an Ayah Chain session item
+///
+/// Returns None when the item has no unexpired checkpoint.
+Future<AyahChainStateDto?>  resumeSessionAyahChain({required String sessionId , required int position }) => RustLib.instance.api.crateApiResumeSessionAyahChain(sessionId: sessionId, position: position);
 
 /// Drain all pending telemetry events as JSON strings
 /// Call periodically from Dart (e.g. every 30s or on app background)
-Future<List<String>> drainTelemetryEvents() =>
-    RustLib.instance.api.crateApiDrainTelemetryEvents();
+Future<List<String>>  drainTelemetryEvents() => RustLib.instance.api.crateApiDrainTelemetryEvents();
 
 /// Get count of pending telemetry events
-Future<int> getTelemetryEventCount() =>
-    RustLib.instance.api.crateApiGetTelemetryEventCount();
+Future<int>  getTelemetryEventCount() => RustLib.instance.api.crateApiGetTelemetryEventCount();
 
 /// Debug: manually emit a test event (dev only)
-Future<String> debugEmitTestEvent() =>
-    RustLib.instance.api.crateApiDebugEmitTestEvent();
+Future<String>  debugEmitTestEvent() => RustLib.instance.api.crateApiDebugEmitTestEvent();
 
 /// Get energy snapshot for a node including neighbor energies
-Future<EnergySnapshotDto> getEnergySnapshot({
-  required String userId,
-  required String nodeId,
-}) => RustLib.instance.api.crateApiGetEnergySnapshot(
-  userId: userId,
-  nodeId: nodeId,
-);
+Future<EnergySnapshotDto>  getEnergySnapshot({required String userId , required String nodeId }) => RustLib.instance.api.crateApiGetEnergySnapshot(userId: userId, nodeId: nodeId);
 
 /// Simulate energy propagation without persisting changes
-Future<PropagationResultDto> simulatePropagation({
-  required String userId,
-  required String nodeId,
-  required double energyDelta,
-}) => RustLib.instance.api.crateApiSimulatePropagation(
-  userId: userId,
-  nodeId: nodeId,
-  energyDelta: energyDelta,
-);
+Future<PropagationResultDto>  simulatePropagation({required String userId , required String nodeId , required double energyDelta }) => RustLib.instance.api.crateApiSimulatePropagation(userId: userId, nodeId: nodeId, energyDelta: energyDelta);
 
 /// Parse a verse range string into individual node IDs
 /// Supports: "1:1-1:7" or "1:1-7" (shorthand for same chapter)
-Future<List<String>> parseNodeRange({required String range}) =>
-    RustLib.instance.api.crateApiParseNodeRange(range: range);
+Future<List<String>>  parseNodeRange({required String range }) => RustLib.instance.api.crateApiParseNodeRange(range: range);
 
 /// Query nodes with filters (type, energy range)
-Future<List<NodeSearchDto>> queryNodesFiltered({
-  required String userId,
-  required NodeFilterDto filter,
-  required int limit,
-}) => RustLib.instance.api.crateApiQueryNodesFiltered(
-  userId: userId,
-  filter: filter,
-  limit: limit,
-);
+Future<List<NodeSearchDto>>  queryNodesFiltered({required String userId , required NodeFilterDto filter , required int limit }) => RustLib.instance.api.crateApiQueryNodesFiltered(userId: userId, filter: filter, limit: limit);
 
 /// Execute a debug SQL query (debug builds only)
 /// Only SELECT queries are allowed for safety
-Future<DbQueryResultDto> executeDebugQuery({required String sql}) =>
-    RustLib.instance.api.crateApiExecuteDebugQuery(sql: sql);
+Future<DbQueryResultDto>  executeDebugQuery({required String sql }) => RustLib.instance.api.crateApiExecuteDebugQuery(sql: sql);
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AppState>>
+                abstract class AppState implements RustOpaqueInterface {
+                     ArcContentRepository get contentRepo;
+
 
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AppState>>
-abstract class AppState implements RustOpaqueInterface {
-  ArcContentRepository get contentRepo;
+ ArcExerciseService get exerciseService;
 
-  ArcExerciseService get exerciseService;
 
-  ArcLearningService get learningService;
+ ArcLearningService get learningService;
 
-  ArcSessionService get sessionService;
 
-  ArcUserRepository get userRepo;
+ ArcSessionService get sessionService;
+
+
+ ArcUserRepository get userRepo;
+
 
   set contentRepo(ArcContentRepository contentRepo);
 
+
   set exerciseService(ArcExerciseService exerciseService);
 
+
   set learningService(ArcLearningService learningService);
 
+
   set sessionService(ArcSessionService sessionService);
 
+
   set userRepo(ArcUserRepository userRepo);
-}
-
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc < ExerciseService >>>
-abstract class ArcExerciseService implements RustOpaqueInterface {}
-
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc < LearningService >>>
-abstract class ArcLearningService implements RustOpaqueInterface {}
-
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc < SessionService >>>
-abstract class ArcSessionService implements RustOpaqueInterface {}
-
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc < dyn ContentRepository >>>
-abstract class ArcContentRepository implements RustOpaqueInterface {}
-
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc < dyn UserRepository >>>
-abstract class ArcUserRepository implements RustOpaqueInterface {}
-
-class ActivityPointDto {
-  final String date;
-  final int count;
-
-  const ActivityPointDto({required this.date, required this.count});
-
-  @override
-  int get hashCode => date.hashCode ^ count.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is ActivityPointDto &&
-          runtimeType == other.runtimeType &&
-          date == other.date &&
-          count == other.count;
-}
-
-class ComprehensionDto {
-  final double memorization;
-  final double understanding;
-  final double context;
-
-  const ComprehensionDto({
-    required this.memorization,
-    required this.understanding,
-    required this.context,
-  });
-
-  @override
-  int get hashCode =>
-      memorization.hashCode ^ understanding.hashCode ^ context.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is ComprehensionDto &&
-          runtimeType == other.runtimeType &&
-          memorization == other.memorization &&
-          understanding == other.understanding &&
-          context == other.context;
-}
-
-class ContentPackageDto {
-  final String packageId;
-  final String packageType;
-  final String name;
-  final String? languageCode;
-  final String? author;
-  final String version;
-  final String? description;
-  final PlatformInt64? fileSize;
-  final String? downloadUrl;
-  final String? checksum;
-  final String? license;
-
-  const ContentPackageDto({
-    required this.packageId,
-    required this.packageType,
-    required this.name,
-    this.languageCode,
-    this.author,
-    required this.version,
-    this.description,
-    this.fileSize,
-    this.downloadUrl,
-    this.checksum,
-    this.license,
-  });
-
-  @override
-  int get hashCode =>
-      packageId.hashCode ^
-      packageType.hashCode ^
-      name.hashCode ^
-      languageCode.hashCode ^
-      author.hashCode ^
-      version.hashCode ^
-      description.hashCode ^
-      fileSize.hashCode ^
-      downloadUrl.hashCode ^
-      checksum.hashCode ^
-      license.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is ContentPackageDto &&
-          runtimeType == other.runtimeType &&
-          packageId == other.packageId &&
-          packageType == other.packageType &&
-          name == other.name &&
-          languageCode == other.languageCode &&
-          author == other.author &&
-          version == other.version &&
-          description == other.description &&
-          fileSize == other.fileSize &&
-          downloadUrl == other.downloadUrl &&
-          checksum == other.checksum &&
-          license == other.license;
-}
-
-class DashboardStatsDto {
-  final int reviewsToday;
-  final int streakDays;
-  final int dueCount;
-
-  const DashboardStatsDto({
-    required this.reviewsToday,
-    required this.streakDays,
-    required this.dueCount,
-  });
-
-  @override
-  int get hashCode =>
-      reviewsToday.hashCode ^ streakDays.hashCode ^ dueCount.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is DashboardStatsDto &&
-          runtimeType == other.runtimeType &&
-          reviewsToday == other.reviewsToday &&
-          streakDays == other.streakDays &&
-          dueCount == other.dueCount;
-}
-
-class DbHealthDto {
-  final PlatformInt64 chaptersCount;
-  final PlatformInt64 versesCount;
-  final PlatformInt64 wordsCount;
-  final PlatformInt64 nodesCount;
-  final PlatformInt64 edgesCount;
-  final PlatformInt64 userMemoryCount;
-  final bool isHealthy;
-  final List<String> issues;
-
-  const DbHealthDto({
-    required this.chaptersCount,
-    required this.versesCount,
-    required this.wordsCount,
-    required this.nodesCount,
-    required this.edgesCount,
-    required this.userMemoryCount,
-    required this.isHealthy,
-    required this.issues,
-  });
-
-  @override
-  int get hashCode =>
-      chaptersCount.hashCode ^
-      versesCount.hashCode ^
-      wordsCount.hashCode ^
-      nodesCount.hashCode ^
-      edgesCount.hashCode ^
-      userMemoryCount.hashCode ^
-      isHealthy.hashCode ^
-      issues.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is DbHealthDto &&
-          runtimeType == other.runtimeType &&
-          chaptersCount == other.chaptersCount &&
-          versesCount == other.versesCount &&
-          wordsCount == other.wordsCount &&
-          nodesCount == other.nodesCount &&
-          edgesCount == other.edgesCount &&
-          userMemoryCount == other.userMemoryCount &&
-          isHealthy == other.isHealthy &&
-          issues == other.issues;
-}
-
-class DbQueryResultDto {
-  final List<String> columns;
-  final List<List<String>> rows;
-
-  const DbQueryResultDto({required this.columns, required this.rows});
-
-  @override
-  int get hashCode => columns.hashCode ^ rows.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is DbQueryResultDto &&
-          runtimeType == other.runtimeType &&
-          columns == other.columns &&
-          rows == other.rows;
-}
-
-class DebugStatsDto {
-  final int totalNodesCount;
-  final int totalEdgesCount;
-  final int dueCount;
-
-  const DebugStatsDto({
-    required this.totalNodesCount,
-    required this.totalEdgesCount,
-    required this.dueCount,
-  });
-
-  @override
-  int get hashCode =>
-      totalNodesCount.hashCode ^ totalEdgesCount.hashCode ^ dueCount.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is DebugStatsDto &&
-          runtimeType == other.runtimeType &&
-          totalNodesCount == other.totalNodesCount &&
-          totalEdgesCount == other.totalEdgesCount &&
-          dueCount == other.dueCount;
-}
-
-class DetailedStatsDto {
-  final List<ActivityPointDto> activityHistory;
-  final ComprehensionDto comprehension;
-
-  const DetailedStatsDto({
-    required this.activityHistory,
-    required this.comprehension,
-  });
-
-  @override
-  int get hashCode => activityHistory.hashCode ^ comprehension.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is DetailedStatsDto &&
-          runtimeType == other.runtimeType &&
-          activityHistory == other.activityHistory &&
-          comprehension == other.comprehension;
-}
 
-/// Complete metrics for an Echo Recall session
-class EchoRecallMetricsDto {
-  final List<WordTimingDto> wordTimings;
-  final BigInt totalDurationMs;
-  final int struggles;
-
-  const EchoRecallMetricsDto({
-    required this.wordTimings,
-    required this.totalDurationMs,
-    required this.struggles,
-  });
-
-  @override
-  int get hashCode =>
-      wordTimings.hashCode ^ totalDurationMs.hashCode ^ struggles.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is EchoRecallMetricsDto &&
-          runtimeType == other.runtimeType &&
-          wordTimings == other.wordTimings &&
-          totalDurationMs == other.totalDurationMs &&
-          struggles == other.struggles;
-}
 
-/// Result from finalizing Echo Recall (energy updates + metrics acknowledgement)
-class EchoRecallResultDto {
-  final List<EnergyUpdateDto> energyUpdates;
-  final int wordsProcessed;
-  final double averageEnergy;
-
-  const EchoRecallResultDto({
-    required this.energyUpdates,
-    required this.wordsProcessed,
-    required this.averageEnergy,
-  });
-
-  @override
-  int get hashCode =>
-      energyUpdates.hashCode ^ wordsProcessed.hashCode ^ averageEnergy.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is EchoRecallResultDto &&
-          runtimeType == other.runtimeType &&
-          energyUpdates == other.energyUpdates &&
-          wordsProcessed == other.wordsProcessed &&
-          averageEnergy == other.averageEnergy;
-}
 
-/// Complete state of an Echo Recall session
-class EchoRecallStateDto {
-  /// All words in the session
-  final List<EchoRecallWordDto> words;
+                    
+                }
+                
 
-  const EchoRecallStateDto({required this.words});
 
-  @override
-  int get hashCode => words.hashCode;
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc < ExerciseService >>>
+                abstract class ArcExerciseService implements RustOpaqueInterface {
+                    
 
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is EchoRecallStateDto &&
-          runtimeType == other.runtimeType &&
-          words == other.words;
-}
+                    
+                }
+                
 
-/// Statistics for an Echo Recall session
-class EchoRecallStatsDto {
-  final int totalWords;
-  final int visibleCount;
-  final int obscuredCount;
-  final int hiddenCount;
-  final double averageEnergy;
-  final double masteryPercentage;
-
-  const EchoRecallStatsDto({
-    required this.totalWords,
-    required this.visibleCount,
-    required this.obscuredCount,
-    required this.hiddenCount,
-    required this.averageEnergy,
-    required this.masteryPercentage,
-  });
-
-  @override
-  int get hashCode =>
-      totalWords.hashCode ^
-      visibleCount.hashCode ^
-      obscuredCount.hashCode ^
-      hiddenCount.hashCode ^
-      averageEnergy.hashCode ^
-      masteryPercentage.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is EchoRecallStatsDto &&
-          runtimeType == other.runtimeType &&
-          totalWords == other.totalWords &&
-          visibleCount == other.visibleCount &&
-          obscuredCount == other.obscuredCount &&
-          hiddenCount == other.hiddenCount &&
-          averageEnergy == other.averageEnergy &&
-          masteryPercentage == other.masteryPercentage;
-}
 
-/// A single word in an Echo Recall session
-class EchoRecallWordDto {
-  /// Node ID (e.g., "WORD:101")
-  final String nodeId;
-
-  /// Arabic text
-  final String text;
-
-  /// Word visibility state
-  final WordVisibilityDto visibility;
-
-  /// Current energy level (0.0 to 1.0)
-  final double energy;
-
-  const EchoRecallWordDto({
-    required this.nodeId,
-    required this.text,
-    required this.visibility,
-    required this.energy,
-  });
-
-  @override
-  int get hashCode =>
-      nodeId.hashCode ^ text.hashCode ^ visibility.hashCode ^ energy.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is EchoRecallWordDto &&
-          runtimeType == other.runtimeType &&
-          nodeId == other.nodeId &&
-          text == other.text &&
-          visibility == other.visibility &&
-          energy == other.energy;
-}
-
-class EnergySnapshotDto {
-  final String nodeId;
-  final double energy;
-  final String? nodeType;
-  final String? knowledgeAxis;
-  final List<NodeEnergyDto> neighbors;
-
-  const EnergySnapshotDto({
-    required this.nodeId,
-    required this.energy,
-    this.nodeType,
-    this.knowledgeAxis,
-    required this.neighbors,
-  });
-
-  @override
-  int get hashCode =>
-      nodeId.hashCode ^
-      energy.hashCode ^
-      nodeType.hashCode ^
-      knowledgeAxis.hashCode ^
-      neighbors.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is EnergySnapshotDto &&
-          runtimeType == other.runtimeType &&
-          nodeId == other.nodeId &&
-          energy == other.energy &&
-          nodeType == other.nodeType &&
-          knowledgeAxis == other.knowledgeAxis &&
-          neighbors == other.neighbors;
-}
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc < LearningService >>>
+                abstract class ArcLearningService implements RustOpaqueInterface {
+                    
 
-/// Energy update result from finalizing Echo Recall
-class EnergyUpdateDto {
-  final String nodeId;
-  final double energy;
+                    
+                }
+                
 
-  const EnergyUpdateDto({required this.nodeId, required this.energy});
 
-  @override
-  int get hashCode => nodeId.hashCode ^ energy.hashCode;
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc < SessionService >>>
+                abstract class ArcSessionService implements RustOpaqueInterface {
+                    
 
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is EnergyUpdateDto &&
-          runtimeType == other.runtimeType &&
-          nodeId == other.nodeId &&
-          energy == other.energy;
-}
+                    
+                }
+                
 
-@freezed
-sealed class ExerciseDataDto with _$ExerciseDataDto {
-  const ExerciseDataDto._();
-
-  const factory ExerciseDataDto.memorization({required String nodeId}) =
-      ExerciseDataDto_Memorization;
-  const factory ExerciseDataDto.mcqArToEn({
-    required String nodeId,
-    required List<String> distractorNodeIds,
-  }) = ExerciseDataDto_McqArToEn;
-  const factory ExerciseDataDto.mcqEnToAr({
-    required String nodeId,
-    required List<String> distractorNodeIds,
-  }) = ExerciseDataDto_McqEnToAr;
-  const factory ExerciseDataDto.translation({required String nodeId}) =
-      ExerciseDataDto_Translation;
-  const factory ExerciseDataDto.contextualTranslation({
-    required String nodeId,
-    required String verseKey,
-  }) = ExerciseDataDto_ContextualTranslation;
-  const factory ExerciseDataDto.clozeDeletion({
-    required String nodeId,
-    required int blankPosition,
-  }) = ExerciseDataDto_ClozeDeletion;
-  const factory ExerciseDataDto.firstLetterHint({
-    required String nodeId,
-    required int wordPosition,
-  }) = ExerciseDataDto_FirstLetterHint;
-  const factory ExerciseDataDto.missingWordMcq({
-    required String nodeId,
-    required int blankPosition,
-    required List<String> distractorNodeIds,
-  }) = ExerciseDataDto_MissingWordMcq;
-  const factory ExerciseDataDto.nextWordMcq({
-    required String nodeId,
-    required int contextPosition,
-    required List<String> distractorNodeIds,
-  }) = ExerciseDataDto_NextWordMcq;
-  const factory ExerciseDataDto.fullVerseInput({required String nodeId}) =
-      ExerciseDataDto_FullVerseInput;
-  const factory ExerciseDataDto.ayahChain({
-    required String nodeId,
-    required List<String> verseKeys,
-    required BigInt currentIndex,
-    required BigInt completedCount,
-  }) = ExerciseDataDto_AyahChain;
-  const factory ExerciseDataDto.findMistake({
-    required String nodeId,
-    required int mistakePosition,
-    required String correctWordNodeId,
-    required String incorrectWordNodeId,
-  }) = ExerciseDataDto_FindMistake;
-  const factory ExerciseDataDto.ayahSequence({
-    required String nodeId,
-    required List<String> correctSequence,
-  }) = ExerciseDataDto_AyahSequence;
-  const factory ExerciseDataDto.sequenceRecall({
-    required String nodeId,
-    required List<String> correctSequence,
-    required List<List<String>> options,
-  }) = ExerciseDataDto_SequenceRecall;
-  const factory ExerciseDataDto.firstWordRecall({
-    required String nodeId,
-    required String verseKey,
-  }) = ExerciseDataDto_FirstWordRecall;
-  const factory ExerciseDataDto.identifyRoot({
-    required String nodeId,
-    required String root,
-  }) = ExerciseDataDto_IdentifyRoot;
-  const factory ExerciseDataDto.reverseCloze({
-    required String nodeId,
-    required int blankPosition,
-  }) = ExerciseDataDto_ReverseCloze;
-  const factory ExerciseDataDto.translatePhrase({
-    required String nodeId,
-    required int translatorId,
-  }) = ExerciseDataDto_TranslatePhrase;
-  const factory ExerciseDataDto.posTagging({
-    required String nodeId,
-    required String correctPos,
-    required List<String> options,
-  }) = ExerciseDataDto_PosTagging;
-  const factory ExerciseDataDto.crossVerseConnection({
-    required String nodeId,
-    required List<String> relatedVerseIds,
-    required String connectionTheme,
-  }) = ExerciseDataDto_CrossVerseConnection;
-
-  /// Echo Recall exercise - progressive blur memorization
-  const factory ExerciseDataDto.echoRecall({
-    /// User ID for session tracking
-    required String userId,
-
-    /// List of ayah node IDs to practice (e.g., ["VERSE:1:1", "VERSE:1:2"])
-    required List<String> ayahNodeIds,
-  }) = ExerciseDataDto_EchoRecall;
-}
 
-/// Hint shown for obscured words
-class HintDto {
-  /// "first", "last", or "both"
-  final String hintType;
-
-  /// First character hint (if applicable)
-  final String? firstChar;
-
-  /// Last character hint (if applicable)
-  final String? lastChar;
-
-  const HintDto({required this.hintType, this.firstChar, this.lastChar});
-
-  @override
-  int get hashCode =>
-      hintType.hashCode ^ firstChar.hashCode ^ lastChar.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is HintDto &&
-          runtimeType == other.runtimeType &&
-          hintType == other.hintType &&
-          firstChar == other.firstChar &&
-          lastChar == other.lastChar;
-}
-
-class InstalledPackageDto {
-  final String packageId;
-  final PlatformInt64 installedAt;
-  final bool enabled;
-
-  const InstalledPackageDto({
-    required this.packageId,
-    required this.installedAt,
-    required this.enabled,
-  });
-
-  @override
-  int get hashCode =>
-      packageId.hashCode ^ installedAt.hashCode ^ enabled.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is InstalledPackageDto &&
-          runtimeType == other.runtimeType &&
-          packageId == other.packageId &&
-          installedAt == other.installedAt &&
-          enabled == other.enabled;
-}
-
-class LanguageDto {
-  final String code;
-  final String englishName;
-  final String nativeName;
-  final String direction;
-
-  const LanguageDto({
-    required this.code,
-    required this.englishName,
-    required this.nativeName,
-    required this.direction,
-  });
-
-  @override
-  int get hashCode =>
-      code.hashCode ^
-      englishName.hashCode ^
-      nativeName.hashCode ^
-      direction.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is LanguageDto &&
-          runtimeType == other.runtimeType &&
-          code == other.code &&
-          englishName == other.englishName &&
-          nativeName == other.nativeName &&
-          direction == other.direction;
-}
-
-class NodeData {
-  final String id;
-  final String nodeType;
-  final Map<String, String> metadata;
-
-  const NodeData({
-    required this.id,
-    required this.nodeType,
-    required this.metadata,
-  });
-
-  @override
-  int get hashCode => id.hashCode ^ nodeType.hashCode ^ metadata.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is NodeData &&
-          runtimeType == other.runtimeType &&
-          id == other.id &&
-          nodeType == other.nodeType &&
-          metadata == other.metadata;
-}
-
-class NodeEnergyDto {
-  final String nodeId;
-  final double energy;
-  final double edgeWeight;
-
-  const NodeEnergyDto({
-    required this.nodeId,
-    required this.energy,
-    required this.edgeWeight,
-  });
-
-  @override
-  int get hashCode => nodeId.hashCode ^ energy.hashCode ^ edgeWeight.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is NodeEnergyDto &&
-          runtimeType == other.runtimeType &&
-          nodeId == other.nodeId &&
-          energy == other.energy &&
-          edgeWeight == other.edgeWeight;
-}
-
-class NodeFilterDto {
-  final String? nodeType;
-  final double? minEnergy;
-  final double? maxEnergy;
-  final String? range;
-
-  const NodeFilterDto({
-    this.nodeType,
-    this.minEnergy,
-    this.maxEnergy,
-    this.range,
-  });
-
-  @override
-  int get hashCode =>
-      nodeType.hashCode ^
-      minEnergy.hashCode ^
-      maxEnergy.hashCode ^
-      range.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is NodeFilterDto &&
-          runtimeType == other.runtimeType &&
-          nodeType == other.nodeType &&
-          minEnergy == other.minEnergy &&
-          maxEnergy == other.maxEnergy &&
-          range == other.range;
-}
-
-class NodeSearchDto {
-  final String nodeId;
-  final String nodeType;
-  final String? knowledgeAxis;
-  final String preview;
-
-  const NodeSearchDto({
-    required this.nodeId,
-    required this.nodeType,
-    this.knowledgeAxis,
-    required this.preview,
-  });
-
-  @override
-  int get hashCode =>
-      nodeId.hashCode ^
-      nodeType.hashCode ^
-      knowledgeAxis.hashCode ^
-      preview.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is NodeSearchDto &&
-          runtimeType == other.runtimeType &&
-          nodeId == other.nodeId &&
-          nodeType == other.nodeType &&
-          knowledgeAxis == other.knowledgeAxis &&
-          preview == other.preview;
-}
-
-class PropagationDiagnosticsDto {
-  final bool nodeFound;
-  final String? nodeType;
-  final int totalEdges;
-  final String message;
-
-  const PropagationDiagnosticsDto({
-    required this.nodeFound,
-    this.nodeType,
-    required this.totalEdges,
-    required this.message,
-  });
-
-  @override
-  int get hashCode =>
-      nodeFound.hashCode ^
-      nodeType.hashCode ^
-      totalEdges.hashCode ^
-      message.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is PropagationDiagnosticsDto &&
-          runtimeType == other.runtimeType &&
-          nodeFound == other.nodeFound &&
-          nodeType == other.nodeType &&
-          totalEdges == other.totalEdges &&
-          message == other.message;
-}
-
-class PropagationResultDto {
-  final List<NodeEnergyDto> before;
-  final List<NodeEnergyDto> after;
-  final PropagationDiagnosticsDto diagnostics;
-
-  const PropagationResultDto({
-    required this.before,
-    required this.after,
-    required this.diagnostics,
-  });
-
-  @override
-  int get hashCode => before.hashCode ^ after.hashCode ^ diagnostics.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is PropagationResultDto &&
-          runtimeType == other.runtimeType &&
-          before == other.before &&
-          after == other.after &&
-          diagnostics == other.diagnostics;
-}
-
-class SessionDto {
-  final String id;
-  final String userId;
-  final String goalId;
-  final PlatformInt64 startedAt;
-  final PlatformInt64? completedAt;
-  final int itemsCount;
-  final int itemsCompleted;
-
-  const SessionDto({
-    required this.id,
-    required this.userId,
-    required this.goalId,
-    required this.startedAt,
-    this.completedAt,
-    required this.itemsCount,
-    required this.itemsCompleted,
-  });
-
-  @override
-  int get hashCode =>
-      id.hashCode ^
-      userId.hashCode ^
-      goalId.hashCode ^
-      startedAt.hashCode ^
-      completedAt.hashCode ^
-      itemsCount.hashCode ^
-      itemsCompleted.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is SessionDto &&
-          runtimeType == other.runtimeType &&
-          id == other.id &&
-          userId == other.userId &&
-          goalId == other.goalId &&
-          startedAt == other.startedAt &&
-          completedAt == other.completedAt &&
-          itemsCount == other.itemsCount &&
-          itemsCompleted == other.itemsCompleted;
-}
-
-class SessionItemDto {
-  final String sessionId;
-  final int position;
-  final String nodeId;
-  final String exerciseType;
-  final ExerciseDataDto exercise;
-
-  const SessionItemDto({
-    required this.sessionId,
-    required this.position,
-    required this.nodeId,
-    required this.exerciseType,
-    required this.exercise,
-  });
-
-  @override
-  int get hashCode =>
-      sessionId.hashCode ^
-      position.hashCode ^
-      nodeId.hashCode ^
-      exerciseType.hashCode ^
-      exercise.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is SessionItemDto &&
-          runtimeType == other.runtimeType &&
-          sessionId == other.sessionId &&
-          position == other.position &&
-          nodeId == other.nodeId &&
-          exerciseType == other.exerciseType &&
-          exercise == other.exercise;
-}
-
-class SessionPreviewDto {
-  final String nodeId;
-  final String nodeType;
-  final String previewText;
-  final double energy;
-  final double priorityScore;
-
-  const SessionPreviewDto({
-    required this.nodeId,
-    required this.nodeType,
-    required this.previewText,
-    required this.energy,
-    required this.priorityScore,
-  });
-
-  @override
-  int get hashCode =>
-      nodeId.hashCode ^
-      nodeType.hashCode ^
-      previewText.hashCode ^
-      energy.hashCode ^
-      priorityScore.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is SessionPreviewDto &&
-          runtimeType == other.runtimeType &&
-          nodeId == other.nodeId &&
-          nodeType == other.nodeType &&
-          previewText == other.previewText &&
-          energy == other.energy &&
-          priorityScore == other.priorityScore;
-}
-
-class SessionSummaryDto {
-  final String sessionId;
-  final int itemsCount;
-  final int itemsCompleted;
-  final PlatformInt64 durationMs;
-  final int againCount;
-  final int hardCount;
-  final int goodCount;
-  final int easyCount;
-
-  const SessionSummaryDto({
-    required this.sessionId,
-    required this.itemsCount,
-    required this.itemsCompleted,
-    required this.durationMs,
-    required this.againCount,
-    required this.hardCount,
-    required this.goodCount,
-    required this.easyCount,
-  });
-
-  @override
-  int get hashCode =>
-      sessionId.hashCode ^
-      itemsCount.hashCode ^
-      itemsCompleted.hashCode ^
-      durationMs.hashCode ^
-      againCount.hashCode ^
-      hardCount.hashCode ^
-      goodCount.hashCode ^
-      easyCount.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is SessionSummaryDto &&
-          runtimeType == other.runtimeType &&
-          sessionId == other.sessionId &&
-          itemsCount == other.itemsCount &&
-          itemsCompleted == other.itemsCompleted &&
-          durationMs == other.durationMs &&
-          againCount == other.againCount &&
-          hardCount == other.hardCount &&
-          goodCount == other.goodCount &&
-          easyCount == other.easyCount;
-}
-
-class SurahInfo {
-  final int number;
-  final String name;
-  final String nameArabic;
-  final String nameTransliteration;
-  final String nameTranslation;
-  final int verseCount;
-  final String? revelationPlace;
-
-  const SurahInfo({
-    required this.number,
-    required this.name,
-    required this.nameArabic,
-    required this.nameTransliteration,
-    required this.nameTranslation,
-    required this.verseCount,
-    this.revelationPlace,
-  });
-
-  @override
-  int get hashCode =>
-      number.hashCode ^
-      name.hashCode ^
-      nameArabic.hashCode ^
-      nameTransliteration.hashCode ^
-      nameTranslation.hashCode ^
-      verseCount.hashCode ^
-      revelationPlace.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is SurahInfo &&
-          runtimeType == other.runtimeType &&
-          number == other.number &&
-          name == other.name &&
-          nameArabic == other.nameArabic &&
-          nameTransliteration == other.nameTransliteration &&
-          nameTranslation == other.nameTranslation &&
-          verseCount == other.verseCount &&
-          revelationPlace == other.revelationPlace;
-}
-
-class SyncMemoryStateDto {
-  final PlatformInt64 nodeId;
-  final double energy;
-  final double? fsrsStability;
-  final double? fsrsDifficulty;
-  final PlatformInt64? lastReviewedAt;
-  final PlatformInt64? nextReviewAt;
-  final PlatformInt64 clientUpdatedAt;
-
-  const SyncMemoryStateDto({
-    required this.nodeId,
-    required this.energy,
-    this.fsrsStability,
-    this.fsrsDifficulty,
-    this.lastReviewedAt,
-    this.nextReviewAt,
-    required this.clientUpdatedAt,
-  });
-
-  @override
-  int get hashCode =>
-      nodeId.hashCode ^
-      energy.hashCode ^
-      fsrsStability.hashCode ^
-      fsrsDifficulty.hashCode ^
-      lastReviewedAt.hashCode ^
-      nextReviewAt.hashCode ^
-      clientUpdatedAt.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is SyncMemoryStateDto &&
-          runtimeType == other.runtimeType &&
-          nodeId == other.nodeId &&
-          energy == other.energy &&
-          fsrsStability == other.fsrsStability &&
-          fsrsDifficulty == other.fsrsDifficulty &&
-          lastReviewedAt == other.lastReviewedAt &&
-          nextReviewAt == other.nextReviewAt &&
-          clientUpdatedAt == other.clientUpdatedAt;
-}
-
-class SyncSessionDto {
-  final String id;
-  final String? goalId;
-  final PlatformInt64 startedAt;
-  final PlatformInt64? completedAt;
-  final int itemsCompleted;
-  final PlatformInt64 clientUpdatedAt;
-
-  const SyncSessionDto({
-    required this.id,
-    this.goalId,
-    required this.startedAt,
-    this.completedAt,
-    required this.itemsCompleted,
-    required this.clientUpdatedAt,
-  });
-
-  @override
-  int get hashCode =>
-      id.hashCode ^
-      goalId.hashCode ^
-      startedAt.hashCode ^
-      completedAt.hashCode ^
-      itemsCompleted.hashCode ^
-      clientUpdatedAt.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is SyncSessionDto &&
-          runtimeType == other.runtimeType &&
-          id == other.id &&
-          goalId == other.goalId &&
-          startedAt == other.startedAt &&
-          completedAt == other.completedAt &&
-          itemsCompleted == other.itemsCompleted &&
-          clientUpdatedAt == other.clientUpdatedAt;
-}
-
-class SyncSessionItemDto {
-  final String id;
-  final String sessionId;
-  final PlatformInt64 nodeId;
-  final String exerciseType;
-  final int? grade;
-  final PlatformInt64? durationMs;
-  final PlatformInt64? completedAt;
-  final PlatformInt64 clientUpdatedAt;
-
-  const SyncSessionItemDto({
-    required this.id,
-    required this.sessionId,
-    required this.nodeId,
-    required this.exerciseType,
-    this.grade,
-    this.durationMs,
-    this.completedAt,
-    required this.clientUpdatedAt,
-  });
-
-  @override
-  int get hashCode =>
-      id.hashCode ^
-      sessionId.hashCode ^
-      nodeId.hashCode ^
-      exerciseType.hashCode ^
-      grade.hashCode ^
-      durationMs.hashCode ^
-      completedAt.hashCode ^
-      clientUpdatedAt.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is SyncSessionItemDto &&
-          runtimeType == other.runtimeType &&
-          id == other.id &&
-          sessionId == other.sessionId &&
-          nodeId == other.nodeId &&
-          exerciseType == other.exerciseType &&
-          grade == other.grade &&
-          durationMs == other.durationMs &&
-          completedAt == other.completedAt &&
-          clientUpdatedAt == other.clientUpdatedAt;
-}
-
-class TranslatorDto {
-  final int id;
-  final String slug;
-  final String fullName;
-  final String languageCode;
-  final String? description;
-  final String? license;
-
-  const TranslatorDto({
-    required this.id,
-    required this.slug,
-    required this.fullName,
-    required this.languageCode,
-    this.description,
-    this.license,
-  });
-
-  @override
-  int get hashCode =>
-      id.hashCode ^
-      slug.hashCode ^
-      fullName.hashCode ^
-      languageCode.hashCode ^
-      description.hashCode ^
-      license.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is TranslatorDto &&
-          runtimeType == other.runtimeType &&
-          id == other.id &&
-          slug == other.slug &&
-          fullName == other.fullName &&
-          languageCode == other.languageCode &&
-          description == other.description &&
-          license == other.license;
-}
-
-class VerseDto {
-  final String key;
-  final String textUthmani;
-  final int chapterNumber;
-  final int verseNumber;
-
-  const VerseDto({
-    required this.key,
-    required this.textUthmani,
-    required this.chapterNumber,
-    required this.verseNumber,
-  });
-
-  @override
-  int get hashCode =>
-      key.hashCode ^
-      textUthmani.hashCode ^
-      chapterNumber.hashCode ^
-      verseNumber.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is VerseDto &&
-          runtimeType == other.runtimeType &&
-          key == other.key &&
-          textUthmani == other.textUthmani &&
-          chapterNumber == other.chapterNumber &&
-          verseNumber == other.verseNumber;
-}
-
-class VerseWithTranslationDto {
-  final String key;
-  final String textUthmani;
-  final String? translation;
-  final int number;
-
-  const VerseWithTranslationDto({
-    required this.key,
-    required this.textUthmani,
-    this.translation,
-    required this.number,
-  });
-
-  @override
-  int get hashCode =>
-      key.hashCode ^
-      textUthmani.hashCode ^
-      translation.hashCode ^
-      number.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is VerseWithTranslationDto &&
-          runtimeType == other.runtimeType &&
-          key == other.key &&
-          textUthmani == other.textUthmani &&
-          translation == other.translation &&
-          number == other.number;
-}
-
-class WordDto {
-  final int id;
-  final String textUthmani;
-  final String verseKey;
-  final int position;
-
-  const WordDto({
-    required this.id,
-    required this.textUthmani,
-    required this.verseKey,
-    required this.position,
-  });
-
-  @override
-  int get hashCode =>
-      id.hashCode ^
-      textUthmani.hashCode ^
-      verseKey.hashCode ^
-      position.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is WordDto &&
-          runtimeType == other.runtimeType &&
-          id == other.id &&
-          textUthmani == other.textUthmani &&
-          verseKey == other.verseKey &&
-          position == other.position;
-}
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc < dyn ContentRepository >>>
+                abstract class ArcContentRepository implements RustOpaqueInterface {
+                    
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc < dyn UserRepository >>>
+                abstract class ArcUserRepository implements RustOpaqueInterface {
+                    
+
+                    
+                }
+                
+
+class ActivityPointDto  {
+                final String date;
+final int count;
+
+                const ActivityPointDto({required this.date ,required this.count ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => date.hashCode^count.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ActivityPointDto &&
+                runtimeType == other.runtimeType
+                && date == other.date&& count == other.count;
+        
+            }
+
+/// Progress of an Ayah Chain session item
+class AyahChainStateDto  {
+                /// Chapter node ID (e.g., "CHAPTER:1")
+final String nodeId;
+/// Verse to type next, None once the chain is complete or broken
+final String? currentVerseKey;
+final int completedCount;
+final int totalVerses;
+final bool isComplete;
+final bool mistakeMade;
+/// Grade of the last submission (1=Again, 2=Hard, 3=Good, 4=Easy)
+final int? lastGrade;
+
+                const AyahChainStateDto({required this.nodeId ,this.currentVerseKey ,required this.completedCount ,required this.totalVerses ,required this.isComplete ,required this.mistakeMade ,this.lastGrade ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => nodeId.hashCode^currentVerseKey.hashCode^completedCount.hashCode^totalVerses.hashCode^isComplete.hashCode^mistakeMade.hashCode^lastGrade.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is AyahChainStateDto &&
+                runtimeType == other.runtimeType
+                && nodeId == other.nodeId&& currentVerseKey == other.currentVerseKey&& completedCount == other.completedCount&& totalVerses == other.totalVerses&& isComplete == other.isComplete&& mistakeMade == other.mistakeMade&& lastGrade == other.lastGrade;
+        
+            }
+
+/// Transition between two consecutive ayahs of an Echo Recall session
+class AyahJunctionDto  {
+                /// Ayah ending at the junction (e.g., "VERSE:1:1")
+final String fromVerse;
+/// Ayah starting at the junction (e.g., "VERSE:1:2")
+final String toVerse;
+final String closingWord;
+final String openingWord;
+/// Energy of the weaker of the two words (0.0 to 1.0)
+final double strength;
+
+                const AyahJunctionDto({required this.fromVerse ,required this.toVerse ,required this.closingWord ,required this.openingWord ,required this.strength ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => fromVerse.hashCode^toVerse.hashCode^closingWord.hashCode^openingWord.hashCode^strength.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is AyahJunctionDto &&
+                runtimeType == other.runtimeType
+                && fromVerse == other.fromVerse&& toVerse == other.toVerse&& closingWord == other.closingWord&& openingWord == other.openingWord&& strength == other.strength;
+        
+            }
+
+class ComprehensionDto  {
+                final double memorization;
+final double understanding;
+final double context;
+
+                const ComprehensionDto({required this.memorization ,required this.understanding ,required this.context ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => memorization.hashCode^understanding.hashCode^context.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ComprehensionDto &&
+                runtimeType == other.runtimeType
+                && memorization == other.memorization&& understanding == other.understanding&& context == other.context;
+        
+            }
+
+class ContentPackageDto  {
+                final String packageId;
+final String packageType;
+final String name;
+final String? languageCode;
+final String? author;
+final String version;
+final String? description;
+final PlatformInt64? fileSize;
+final String? downloadUrl;
+final String? checksum;
+final String? license;
+
+                const ContentPackageDto({required this.packageId ,required this.packageType ,required this.name ,this.languageCode ,this.author ,required this.version ,this.description ,this.fileSize ,this.downloadUrl ,this.checksum ,this.license ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => packageId.hashCode^packageType.hashCode^name.hashCode^languageCode.hashCode^author.hashCode^version.hashCode^description.hashCode^fileSize.hashCode^downloadUrl.hashCode^checksum.hashCode^license.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ContentPackageDto &&
+                runtimeType == other.runtimeType
+                && packageId == other.packageId&& packageType == other.packageType&& name == other.name&& languageCode == other.languageCode&& author == other.author&& version == other.version&& description == other.description&& fileSize == other.fileSize&& downloadUrl == other.downloadUrl&& checksum == other.checksum&& license == other.license;
+        
+            }
+
+class DashboardStatsDto  {
+                final int reviewsToday;
+final int streakDays;
+final int dueCount;
+
+                const DashboardStatsDto({required this.reviewsToday ,required this.streakDays ,required this.dueCount ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => reviewsToday.hashCode^streakDays.hashCode^dueCount.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DashboardStatsDto &&
+                runtimeType == other.runtimeType
+                && reviewsToday == other.reviewsToday&& streakDays == other.streakDays&& dueCount == other.dueCount;
+        
+            }
+
+class DbHealthDto  {
+                final PlatformInt64 chaptersCount;
+final PlatformInt64 versesCount;
+final PlatformInt64 wordsCount;
+final PlatformInt64 nodesCount;
+final PlatformInt64 edgesCount;
+final PlatformInt64 userMemoryCount;
+final bool isHealthy;
+final List<String> issues;
+
+                const DbHealthDto({required this.chaptersCount ,required this.versesCount ,required this.wordsCount ,required this.nodesCount ,required this.edgesCount ,required this.userMemoryCount ,required this.isHealthy ,required this.issues ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => chaptersCount.hashCode^versesCount.hashCode^wordsCount.hashCode^nodesCount.hashCode^edgesCount.hashCode^userMemoryCount.hashCode^isHealthy.hashCode^issues.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DbHealthDto &&
+                runtimeType == other.runtimeType
+                && chaptersCount == other.chaptersCount&& versesCount == other.versesCount&& wordsCount == other.wordsCount&& nodesCount == other.nodesCount&& edgesCount == other.edgesCount&& userMemoryCount == other.userMemoryCount&& isHealthy == other.isHealthy&& issues == other.issues;
+        
+            }
+
+class DbQueryResultDto  {
+                final List<String> columns;
+final List<List<String>> rows;
+
+                const DbQueryResultDto({required this.columns ,required this.rows ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => columns.hashCode^rows.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DbQueryResultDto &&
+                runtimeType == other.runtimeType
+                && columns == other.columns&& rows == other.rows;
+        
+            }
+
+class DebugStatsDto  {
+                final int totalNodesCount;
+final int totalEdgesCount;
+final int dueCount;
+
+                const DebugStatsDto({required this.totalNodesCount ,required this.totalEdgesCount ,required this.dueCount ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => totalNodesCount.hashCode^totalEdgesCount.hashCode^dueCount.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DebugStatsDto &&
+                runtimeType == other.runtimeType
+                && totalNodesCount == other.totalNodesCount&& totalEdgesCount == other.totalEdgesCount&& dueCount == other.dueCount;
+        
+            }
+
+class DetailedStatsDto  {
+                final List<ActivityPointDto> activityHistory;
+final ComprehensionDto comprehension;
+
+                const DetailedStatsDto({required this.activityHistory ,required this.comprehension ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => activityHistory.hashCode^comprehension.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DetailedStatsDto &&
+                runtimeType == other.runtimeType
+                && activityHistory == other.activityHistory&& comprehension == other.comprehension;
+        
+            }
+
+/// How an MCQ's distractors were picked
+class DistractorStrategyDto  {
+                /// "easy", "medium" or "hard"
+final String difficulty;
+/// Scoring signals ("part_of_speech", "word_family", "length", "semantic", "frequency")
+final List<String> signals;
+
+                const DistractorStrategyDto({required this.difficulty ,required this.signals ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => difficulty.hashCode^signals.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DistractorStrategyDto &&
+                runtimeType == other.runtimeType
+                && difficulty == other.difficulty&& signals == other.signals;
+        
+            }
 
-/// Per-word timing for metrics
-class WordTimingDto {
-  final String wordNodeId;
-  final BigInt durationMs;
+/// Complete metrics for an Echo Recall session
+class EchoRecallMetricsDto  {
+                final List<WordTimingDto> wordTimings;
+final BigInt totalDurationMs;
+final int struggles;
+
+                const EchoRecallMetricsDto({required this.wordTimings ,required this.totalDurationMs ,required this.struggles ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => wordTimings.hashCode^totalDurationMs.hashCode^struggles.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is EchoRecallMetricsDto &&
+                runtimeType == other.runtimeType
+                && wordTimings == other.wordTimings&& totalDurationMs == other.totalDurationMs&& struggles == other.struggles;
+        
+            }
+
+/// Result from finalizing Echo Recall (energy updates + metrics acknowledgement)
+class EchoRecallResultDto  {
+                final List<EnergyUpdateDto> energyUpdates;
+final int wordsProcessed;
+final double averageEnergy;
+
+                const EchoRecallResultDto({required this.energyUpdates ,required this.wordsProcessed ,required this.averageEnergy ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => energyUpdates.hashCode^wordsProcessed.hashCode^averageEnergy.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is EchoRecallResultDto &&
+                runtimeType == other.runtimeType
+                && energyUpdates == other.energyUpdates&& wordsProcessed == other.wordsProcessed&& averageEnergy == other.averageEnergy;
+        
+            }
+
+/// Complete state of an Echo Recall session
+class EchoRecallStateDto  {
+                /// All words in the session
+final List<EchoRecallWordDto> words;
+
+                const EchoRecallStateDto({required this.words ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => words.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is EchoRecallStateDto &&
+                runtimeType == other.runtimeType
+                && words == other.words;
+        
+            }
+
+/// Statistics for an Echo Recall session
+class EchoRecallStatsDto  {
+                final int totalWords;
+final int visibleCount;
+final int obscuredCount;
+final int hiddenCount;
+final double averageEnergy;
+final double masteryPercentage;
+
+                const EchoRecallStatsDto({required this.totalWords ,required this.visibleCount ,required this.obscuredCount ,required this.hiddenCount ,required this.averageEnergy ,required this.masteryPercentage ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => totalWords.hashCode^visibleCount.hashCode^obscuredCount.hashCode^hiddenCount.hashCode^averageEnergy.hashCode^masteryPercentage.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is EchoRecallStatsDto &&
+                runtimeType == other.runtimeType
+                && totalWords == other.totalWords&& visibleCount == other.visibleCount&& obscuredCount == other.obscuredCount&& hiddenCount == other.hiddenCount&& averageEnergy == other.averageEnergy&& masteryPercentage == other.masteryPercentage;
+        
+            }
+
+/// A single word in an Echo Recall session
+class EchoRecallWordDto  {
+                /// Node ID (e.g., "WORD:101")
+final String nodeId;
+/// Arabic text
+final String text;
+/// Word visibility state
+final WordVisibilityDto visibility;
+/// Current energy level (0.0 to 1.0)
+final double energy;
+
+                const EchoRecallWordDto({required this.nodeId ,required this.text ,required this.visibility ,required this.energy ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => nodeId.hashCode^text.hashCode^visibility.hashCode^energy.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is EchoRecallWordDto &&
+                runtimeType == other.runtimeType
+                && nodeId == other.nodeId&& text == other.text&& visibility == other.visibility&& energy == other.energy;
+        
+            }
+
+class EnergySnapshotDto  {
+                final String nodeId;
+final double energy;
+final String? nodeType;
+final String? knowledgeAxis;
+final List<NodeEnergyDto> neighbors;
+
+                const EnergySnapshotDto({required this.nodeId ,required this.energy ,this.nodeType ,this.knowledgeAxis ,required this.neighbors ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => nodeId.hashCode^energy.hashCode^nodeType.hashCode^knowledgeAxis.hashCode^neighbors.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is EnergySnapshotDto &&
+                runtimeType == other.runtimeType
+                && nodeId == other.nodeId&& energy == other.energy&& nodeType == other.nodeType&& knowledgeAxis == other.knowledgeAxis&& neighbors == other.neighbors;
+        
+            }
 
-  const WordTimingDto({required this.wordNodeId, required this.durationMs});
+/// Energy update result from finalizing Echo Recall
+class EnergyUpdateDto  {
+                final String nodeId;
+final double energy;
+
+                const EnergyUpdateDto({required this.nodeId ,required this.energy ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => nodeId.hashCode^energy.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is EnergyUpdateDto &&
+                runtimeType == other.runtimeType
+                && nodeId == other.nodeId&& energy == other.energy;
+        
+            }
 
-  @override
-  int get hashCode => wordNodeId.hashCode ^ durationMs.hashCode;
+@freezed
+                sealed class ExerciseDataDto with _$ExerciseDataDto  {
+                    const ExerciseDataDto._();
+
+                     const factory ExerciseDataDto.memorization({   required String nodeId ,  required BigInt seed , }) = ExerciseDataDto_Memorization;
+ const factory ExerciseDataDto.mcqArToEn({   required String nodeId ,  required List<String> distractorNodeIds ,  required DistractorStrategyDto distractorStrategy ,  required BigInt seed , }) = ExerciseDataDto_McqArToEn;
+ const factory ExerciseDataDto.mcqEnToAr({   required String nodeId ,  required List<String> distractorNodeIds ,  required DistractorStrategyDto distractorStrategy ,  required BigInt seed , }) = ExerciseDataDto_McqEnToAr;
+ const factory ExerciseDataDto.translation({   required String nodeId ,  required BigInt seed , }) = ExerciseDataDto_Translation;
+ const factory ExerciseDataDto.contextualTranslation({   required String nodeId ,  required String verseKey ,  required BigInt seed , }) = ExerciseDataDto_ContextualTranslation;
+ const factory ExerciseDataDto.clozeDeletion({   required String nodeId ,  required int blankPosition ,  required BigInt seed , }) = ExerciseDataDto_ClozeDeletion;
+ const factory ExerciseDataDto.firstLetterHint({   required String nodeId ,  required int wordPosition ,  required BigInt seed , }) = ExerciseDataDto_FirstLetterHint;
+ const factory ExerciseDataDto.missingWordMcq({   required String nodeId ,  required int blankPosition ,  required List<String> distractorNodeIds ,  required DistractorStrategyDto distractorStrategy ,  required BigInt seed , }) = ExerciseDataDto_MissingWordMcq;
+ const factory ExerciseDataDto.nextWordMcq({   required String nodeId ,  required int contextPosition ,  required List<String> distractorNodeIds ,  required DistractorStrategyDto distractorStrategy ,  required BigInt seed , }) = ExerciseDataDto_NextWordMcq;
+ const factory ExerciseDataDto.fullVerseInput({   required String nodeId ,  required BigInt seed , }) = ExerciseDataDto_FullVerseInput;
+ const factory ExerciseDataDto.ayahChain({   required String nodeId ,  required List<String> verseKeys ,  required BigInt currentIndex ,  required BigInt completedCount ,  required BigInt seed , }) = ExerciseDataDto_AyahChain;
+ const factory ExerciseDataDto.findMistake({   required String nodeId ,  required int mistakePosition ,  required String correctWordNodeId ,  required String incorrectWordNodeId ,  required BigInt seed , }) = ExerciseDataDto_FindMistake;
+ const factory ExerciseDataDto.ayahSequence({   required String nodeId ,  required List<String> correctSequence ,  required BigInt seed , }) = ExerciseDataDto_AyahSequence;
+ const factory ExerciseDataDto.sequenceRecall({   required String nodeId ,  required List<String> correctSequence ,  required List<List<String>> options ,  required BigInt seed , }) = ExerciseDataDto_SequenceRecall;
+ const factory ExerciseDataDto.firstWordRecall({   required String nodeId ,  required String verseKey ,  required BigInt seed , }) = ExerciseDataDto_FirstWordRecall;
+ const factory ExerciseDataDto.identifyRoot({   required String nodeId ,  required String root ,  required BigInt seed , }) = ExerciseDataDto_IdentifyRoot;
+ const factory ExerciseDataDto.reverseCloze({   required String nodeId ,  required int blankPosition ,  required BigInt seed , }) = ExerciseDataDto_ReverseCloze;
+ const factory ExerciseDataDto.translatePhrase({   required String nodeId ,  required int translatorId ,  required BigInt seed , }) = ExerciseDataDto_TranslatePhrase;
+ const factory ExerciseDataDto.posTagging({   required String nodeId ,  required String correctPos ,  required List<String> options ,  required BigInt seed , }) = ExerciseDataDto_PosTagging;
+ const factory ExerciseDataDto.grammarFeature({   required String nodeId ,/// Feature slug ("verb_form", "tense", "mood", "person", "number",
+/// "gender", "case" or "state")
+  required String feature ,/// Question to show, e.g. "Is this word singular, dual or plural?"
+  required String prompt ,  required String correctValue ,  required List<String> options ,  required BigInt seed , }) = ExerciseDataDto_GrammarFeature;
+ const factory ExerciseDataDto.crossVerseConnection({   required String nodeId ,  required List<String> relatedVerseIds ,  required String connectionTheme ,  required BigInt seed , }) = ExerciseDataDto_CrossVerseConnection;
+ /// Echo Recall exercise - progressive blur memorization
+const factory ExerciseDataDto.echoRecall({ /// User ID for session tracking
+  required String userId ,/// List of ayah node IDs to practice (e.g., ["VERSE:1:1", "VERSE:1:2"])
+  required List<String> ayahNodeIds ,  required BigInt seed , }) = ExerciseDataDto_EchoRecall;
+ /// Mutashabihat exercise - pick the correct continuation among similar verses
+const factory ExerciseDataDto.mutashabihat({   required String nodeId ,  required String verseKey ,/// Number of leading words shown as the prompt
+  required int promptWordCount ,  required List<String> optionVerseKeys ,/// Word position where each option diverges (0 for the correct option)
+  required Int32List differingPositions ,  required BigInt seed , }) = ExerciseDataDto_Mutashabihat;
+ /// Word order exercise - rebuild a verse from shuffled tiles
+const factory ExerciseDataDto.wordOrder({   required String nodeId ,  required List<String> correctSequence ,  required List<String> shuffledWordNodeIds ,/// Tile text aligned with `shuffled_word_node_ids`
+  required List<String> tileTexts ,  required BigInt seed , }) = ExerciseDataDto_WordOrder;
+ const factory ExerciseDataDto.identifySurah({   required String nodeId ,  required String verseKey ,  required int correctChapter ,  required Int32List chapterOptions ,  required BigInt seed , }) = ExerciseDataDto_IdentifySurah;
+ const factory ExerciseDataDto.identifyVerseNumber({   required String nodeId ,  required String verseKey ,  required int correctVerseNumber ,  required Int32List verseNumberOptions ,  required BigInt seed , }) = ExerciseDataDto_IdentifyVerseNumber;
+ /// Page half identification - `half` is 1 (first) or 2 (second)
+const factory ExerciseDataDto.identifyPageHalf({   required String nodeId ,  required String verseKey ,  required int page ,  required int half ,  required BigInt seed , }) = ExerciseDataDto_IdentifyPageHalf;
+ /// Transliteration to Arabic - pick the Arabic word for the node's transliteration
+const factory ExerciseDataDto.transliterationToArabic({   required String nodeId ,  required List<String> distractorNodeIds ,  required DistractorStrategyDto distractorStrategy ,  required BigInt seed , }) = ExerciseDataDto_TransliterationToArabic;
+ /// Arabic to transliteration - type the transliteration of the highlighted word
+const factory ExerciseDataDto.arabicToTransliteration({   required String nodeId ,  required String verseKey ,  required BigInt seed , }) = ExerciseDataDto_ArabicToTransliteration;
+ /// Root family - pick every word in `verse_keys` derived from the root
+const factory ExerciseDataDto.rootFamily({   required String nodeId ,  required String rootId ,  required List<String> verseKeys ,  required List<String> familyWordNodeIds ,  required BigInt seed , }) = ExerciseDataDto_RootFamily;
+ /// Lemma meaning - match the root's lemmas to the shuffled meanings
+const factory ExerciseDataDto.lemmaMeaning({   required String nodeId ,  required String rootId ,  required List<String> lemmaIds ,  required List<String> meaningLemmaIds ,  required BigInt seed , }) = ExerciseDataDto_LemmaMeaning;
+ /// Recite page - type the page's words, from `first_word_node_id` in the
+/// first of `verse_keys` to `last_word_node_id` in the last
+const factory ExerciseDataDto.recitePage({   required String nodeId ,  required int page ,  required List<String> verseKeys ,  required String firstWordNodeId ,  required String lastWordNodeId ,  required BigInt seed , }) = ExerciseDataDto_RecitePage;
+ /// Page first word - pick the first word of the page
+const factory ExerciseDataDto.pageFirstWord({   required String nodeId ,  required int page ,  required String firstWordNodeId ,  required List<String> distractorNodeIds ,  required BigInt seed , }) = ExerciseDataDto_PageFirstWord;
+ /// Verse line identification - which line of `page` the verse starts on
+const factory ExerciseDataDto.identifyVerseLine({   required String nodeId ,  required String verseKey ,  required int page ,  required int correctLine ,  required Int32List lineOptions ,  required BigInt seed , }) = ExerciseDataDto_IdentifyVerseLine;
+ /// Listen and identify verse - which verse the clip (words
+/// `first_position..=last_position` of the reciter's verse audio) is from
+const factory ExerciseDataDto.listenIdentifyVerse({   required String nodeId ,  required int reciterId ,  required String verseKey ,  required int firstPosition ,  required int lastPosition ,  required PlatformInt64 startMs ,  required PlatformInt64 endMs ,  required List<String> optionVerseKeys ,  required BigInt seed , }) = ExerciseDataDto_ListenIdentifyVerse;
+ /// Listen and continue - pick the word that follows the clip
+const factory ExerciseDataDto.listenContinue({   required String nodeId ,  required int reciterId ,  required String verseKey ,  required int firstPosition ,  required int lastPosition ,  required PlatformInt64 startMs ,  required PlatformInt64 endMs ,  required List<String> distractorNodeIds ,  required DistractorStrategyDto distractorStrategy ,  required BigInt seed , }) = ExerciseDataDto_ListenContinue;
+ /// Listen and identify word - pick the word just heard. Without
+/// `start_ms`/`end_ms` the word has its own clip (see `get_word_audio`)
+const factory ExerciseDataDto.listenIdentifyWord({   required String nodeId ,  required int reciterId ,  required String verseKey ,  required int position ,  PlatformInt64? startMs ,  PlatformInt64? endMs ,  required List<String> distractorNodeIds ,  required DistractorStrategyDto distractorStrategy ,  required BigInt seed , }) = ExerciseDataDto_ListenIdentifyWord;
+ /// Identify tajweed rule - name the rule on characters
+/// `start_char..end_char` of the word's Uthmani text. Rules are slugs
+/// ("ikhfa", "madd_lazim", ...) with display names in `option_names`.
+const factory ExerciseDataDto.identifyTajweedRule({   required String nodeId ,  required String wordNodeId ,  required int startChar ,  required int endChar ,  required String correctRule ,  required List<String> ruleOptions ,  required List<String> optionNames ,  required BigInt seed , }) = ExerciseDataDto_IdentifyTajweedRule;
+ /// Find tajweed rule - pick every word of the verse where `rule` applies
+const factory ExerciseDataDto.findTajweedRule({   required String nodeId ,  required String rule ,  required String ruleName ,  required List<String> ruleWordNodeIds ,  required BigInt seed , }) = ExerciseDataDto_FindTajweedRule;
+ /// Tafsir key lesson - pick the key lesson of the passage beginning at
+/// `passage_key`; options are passages (fetch lessons with
+/// `get_tafsir_passage`)
+const factory ExerciseDataDto.tafsirKeyLesson({   required String nodeId ,  required int tafsirId ,  required String passageKey ,  required List<String> optionPassageKeys ,  required BigInt seed , }) = ExerciseDataDto_TafsirKeyLesson;
+ /// Revelation occasion - pick the verse the occasion reported for the
+/// passage beginning at `passage_key` is about
+const factory ExerciseDataDto.revelationOccasion({   required String nodeId ,  required int tafsirId ,  required String passageKey ,  required List<String> optionVerseKeys ,  required BigInt seed , }) = ExerciseDataDto_RevelationOccasion;
+
+                    
+
+                    
+                }
+
+/// One rung of an exercise's progressive hint ladder
+class ExerciseHintDto  {
+                /// 1=first letter, 2=first word, 3=word count, 4=translation, 5=partial reveal
+final int level;
+/// Level name ("first_letter", ...)
+final String levelName;
+final String text;
+/// Highest grade still achievable once shown (1=Again ... 4=Easy)
+final int maxGrade;
+
+                const ExerciseHintDto({required this.level ,required this.levelName ,required this.text ,required this.maxGrade ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => level.hashCode^levelName.hashCode^text.hashCode^maxGrade.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ExerciseHintDto &&
+                runtimeType == other.runtimeType
+                && level == other.level&& levelName == other.levelName&& text == other.text&& maxGrade == other.maxGrade;
+        
+            }
 
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is WordTimingDto &&
-          runtimeType == other.runtimeType &&
-          wordNodeId == other.wordNodeId &&
-          durationMs == other.durationMs;
-}
+/// Hint shown for obscured words
+class HintDto  {
+                /// "first", "last", or "both"
+final String hintType;
+/// First character hint (if applicable)
+final String? firstChar;
+/// Last character hint (if applicable)
+final String? lastChar;
+
+                const HintDto({required this.hintType ,this.firstChar ,this.lastChar ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => hintType.hashCode^firstChar.hashCode^lastChar.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is HintDto &&
+                runtimeType == other.runtimeType
+                && hintType == other.hintType&& firstChar == other.firstChar&& lastChar == other.lastChar;
+        
+            }
+
+class InstalledPackageDto  {
+                final String packageId;
+final PlatformInt64 installedAt;
+final bool enabled;
+
+                const InstalledPackageDto({required this.packageId ,required this.installedAt ,required this.enabled ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => packageId.hashCode^installedAt.hashCode^enabled.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is InstalledPackageDto &&
+                runtimeType == other.runtimeType
+                && packageId == other.packageId&& installedAt == other.installedAt&& enabled == other.enabled;
+        
+            }
+
+class LanguageDto  {
+                final String code;
+final String englishName;
+final String nativeName;
+final String direction;
+
+                const LanguageDto({required this.code ,required this.englishName ,required this.nativeName ,required this.direction ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => code.hashCode^englishName.hashCode^nativeName.hashCode^direction.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LanguageDto &&
+                runtimeType == other.runtimeType
+                && code == other.code&& englishName == other.englishName&& nativeName == other.nativeName&& direction == other.direction;
+        
+            }
+
+class NodeData  {
+                final String id;
+final String nodeType;
+final Map<String, String> metadata;
+
+                const NodeData({required this.id ,required this.nodeType ,required this.metadata ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^nodeType.hashCode^metadata.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is NodeData &&
+                runtimeType == other.runtimeType
+                && id == other.id&& nodeType == other.nodeType&& metadata == other.metadata;
+        
+            }
+
+class NodeEnergyDto  {
+                final String nodeId;
+final double energy;
+final double edgeWeight;
+
+                const NodeEnergyDto({required this.nodeId ,required this.energy ,required this.edgeWeight ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => nodeId.hashCode^energy.hashCode^edgeWeight.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is NodeEnergyDto &&
+                runtimeType == other.runtimeType
+                && nodeId == other.nodeId&& energy == other.energy&& edgeWeight == other.edgeWeight;
+        
+            }
+
+class NodeFilterDto  {
+                final String? nodeType;
+final double? minEnergy;
+final double? maxEnergy;
+final String? range;
+
+                const NodeFilterDto({this.nodeType ,this.minEnergy ,this.maxEnergy ,this.range ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => nodeType.hashCode^minEnergy.hashCode^maxEnergy.hashCode^range.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is NodeFilterDto &&
+                runtimeType == other.runtimeType
+                && nodeType == other.nodeType&& minEnergy == other.minEnergy&& maxEnergy == other.maxEnergy&& range == other.range;
+        
+            }
+
+class NodeSearchDto  {
+                final String nodeId;
+final String nodeType;
+final String? knowledgeAxis;
+final String preview;
+
+                const NodeSearchDto({required this.nodeId ,required this.nodeType ,this.knowledgeAxis ,required this.preview ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => nodeId.hashCode^nodeType.hashCode^knowledgeAxis.hashCode^preview.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is NodeSearchDto &&
+                runtimeType == other.runtimeType
+                && nodeId == other.nodeId&& nodeType == other.nodeType&& knowledgeAxis == other.knowledgeAxis&& preview == other.preview;
+        
+            }
+
+class NodeStatusDto  {
+                final String nodeId;
+/// "active", "suspended" or "buried"
+final String status;
+/// Epoch milliseconds, only set for buried nodes
+final PlatformInt64? buriedUntil;
+
+                const NodeStatusDto({required this.nodeId ,required this.status ,this.buriedUntil ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => nodeId.hashCode^status.hashCode^buriedUntil.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is NodeStatusDto &&
+                runtimeType == other.runtimeType
+                && nodeId == other.nodeId&& status == other.status&& buriedUntil == other.buriedUntil;
+        
+            }
+
+class PropagationDiagnosticsDto  {
+                final bool nodeFound;
+final String? nodeType;
+final int totalEdges;
+final String message;
+
+                const PropagationDiagnosticsDto({required this.nodeFound ,this.nodeType ,required this.totalEdges ,required this.message ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => nodeFound.hashCode^nodeType.hashCode^totalEdges.hashCode^message.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PropagationDiagnosticsDto &&
+                runtimeType == other.runtimeType
+                && nodeFound == other.nodeFound&& nodeType == other.nodeType&& totalEdges == other.totalEdges&& message == other.message;
+        
+            }
+
+class PropagationResultDto  {
+                final List<NodeEnergyDto> before;
+final List<NodeEnergyDto> after;
+final PropagationDiagnosticsDto diagnostics;
+
+                const PropagationResultDto({required this.before ,required this.after ,required this.diagnostics ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => before.hashCode^after.hashCode^diagnostics.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PropagationResultDto &&
+                runtimeType == other.runtimeType
+                && before == other.before&& after == other.after&& diagnostics == other.diagnostics;
+        
+            }
+
+/// A run of the recitation taken from another verse
+class RecitationJumpDto  {
+                final String verseKey;
+final int startPosition;
+final int wordCount;
+/// Last expected word recited before the jump
+final String? afterWordNodeId;
+final BigInt? startMs;
+
+                const RecitationJumpDto({required this.verseKey ,required this.startPosition ,required this.wordCount ,this.afterWordNodeId ,this.startMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => verseKey.hashCode^startPosition.hashCode^wordCount.hashCode^afterWordNodeId.hashCode^startMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RecitationJumpDto &&
+                runtimeType == other.runtimeType
+                && verseKey == other.verseKey&& startPosition == other.startPosition&& wordCount == other.wordCount&& afterWordNodeId == other.afterWordNodeId&& startMs == other.startMs;
+        
+            }
+
+/// Graded recitation of a verse range
+class RecitationResultDto  {
+                final List<RecitedWordDto> words;
+final List<RecitationJumpDto> jumps;
+final List<TranscriptWordDto> extraWords;
+final double accuracy;
+final int grade;
+
+                const RecitationResultDto({required this.words ,required this.jumps ,required this.extraWords ,required this.accuracy ,required this.grade ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => words.hashCode^jumps.hashCode^extraWords.hashCode^accuracy.hashCode^grade.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RecitationResultDto &&
+                runtimeType == other.runtimeType
+                && words == other.words&& jumps == other.jumps&& extraWords == other.extraWords&& accuracy == other.accuracy&& grade == other.grade;
+        
+            }
+
+/// How one expected word was recited
+class RecitedWordDto  {
+                final String wordNodeId;
+final String verseKey;
+final int position;
+final String text;
+/// "correct", "close", "substituted" or "skipped"
+final String kind;
+final String? heard;
+final double similarity;
+final bool repeated;
+final BigInt? startMs;
+final BigInt? endMs;
+/// Review grade recorded for the word (1-4)
+final int grade;
+
+                const RecitedWordDto({required this.wordNodeId ,required this.verseKey ,required this.position ,required this.text ,required this.kind ,this.heard ,required this.similarity ,required this.repeated ,this.startMs ,this.endMs ,required this.grade ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => wordNodeId.hashCode^verseKey.hashCode^position.hashCode^text.hashCode^kind.hashCode^heard.hashCode^similarity.hashCode^repeated.hashCode^startMs.hashCode^endMs.hashCode^grade.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RecitedWordDto &&
+                runtimeType == other.runtimeType
+                && wordNodeId == other.wordNodeId&& verseKey == other.verseKey&& position == other.position&& text == other.text&& kind == other.kind&& heard == other.heard&& similarity == other.similarity&& repeated == other.repeated&& startMs == other.startMs&& endMs == other.endMs&& grade == other.grade;
+        
+            }
+
+class ReciterDto  {
+                final int reciterId;
+final String slug;
+final String name;
+final String? style;
+final String? packageId;
+
+                const ReciterDto({required this.reciterId ,required this.slug ,required this.name ,this.style ,this.packageId ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => reciterId.hashCode^slug.hashCode^name.hashCode^style.hashCode^packageId.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ReciterDto &&
+                runtimeType == other.runtimeType
+                && reciterId == other.reciterId&& slug == other.slug&& name == other.name&& style == other.style&& packageId == other.packageId;
+        
+            }
+
+class SessionDto  {
+                final String id;
+final String userId;
+final String goalId;
+final PlatformInt64 startedAt;
+final PlatformInt64? completedAt;
+final int itemsCount;
+final int itemsCompleted;
+
+                const SessionDto({required this.id ,required this.userId ,required this.goalId ,required this.startedAt ,this.completedAt ,required this.itemsCount ,required this.itemsCompleted ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^userId.hashCode^goalId.hashCode^startedAt.hashCode^completedAt.hashCode^itemsCount.hashCode^itemsCompleted.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SessionDto &&
+                runtimeType == other.runtimeType
+                && id == other.id&& userId == other.userId&& goalId == other.goalId&& startedAt == other.startedAt&& completedAt == other.completedAt&& itemsCount == other.itemsCount&& itemsCompleted == other.itemsCompleted;
+        
+            }
+
+class SessionItemDto  {
+                final String sessionId;
+final int position;
+final String nodeId;
+final String exerciseType;
+final ExerciseDataDto exercise;
+
+                const SessionItemDto({required this.sessionId ,required this.position ,required this.nodeId ,required this.exerciseType ,required this.exercise ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => sessionId.hashCode^position.hashCode^nodeId.hashCode^exerciseType.hashCode^exercise.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SessionItemDto &&
+                runtimeType == other.runtimeType
+                && sessionId == other.sessionId&& position == other.position&& nodeId == other.nodeId&& exerciseType == other.exerciseType&& exercise == other.exercise;
+        
+            }
+
+class SessionPreviewDto  {
+                final String nodeId;
+final String nodeType;
+final String previewText;
+final double energy;
+final double priorityScore;
+
+                const SessionPreviewDto({required this.nodeId ,required this.nodeType ,required this.previewText ,required this.energy ,required this.priorityScore ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => nodeId.hashCode^nodeType.hashCode^previewText.hashCode^energy.hashCode^priorityScore.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SessionPreviewDto &&
+                runtimeType == other.runtimeType
+                && nodeId == other.nodeId&& nodeType == other.nodeType&& previewText == other.previewText&& energy == other.energy&& priorityScore == other.priorityScore;
+        
+            }
+
+class SessionSummaryDto  {
+                final String sessionId;
+final int itemsCount;
+final int itemsCompleted;
+final PlatformInt64 durationMs;
+final int againCount;
+final int hardCount;
+final int goodCount;
+final int easyCount;
+
+                const SessionSummaryDto({required this.sessionId ,required this.itemsCount ,required this.itemsCompleted ,required this.durationMs ,required this.againCount ,required this.hardCount ,required this.goodCount ,required this.easyCount ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => sessionId.hashCode^itemsCount.hashCode^itemsCompleted.hashCode^durationMs.hashCode^againCount.hashCode^hardCount.hashCode^goodCount.hashCode^easyCount.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SessionSummaryDto &&
+                runtimeType == other.runtimeType
+                && sessionId == other.sessionId&& itemsCount == other.itemsCount&& itemsCompleted == other.itemsCompleted&& durationMs == other.durationMs&& againCount == other.againCount&& hardCount == other.hardCount&& goodCount == other.goodCount&& easyCount == other.easyCount;
+        
+            }
+
+class SurahInfo  {
+                final int number;
+final String name;
+final String nameArabic;
+final String nameTransliteration;
+final String nameTranslation;
+final int verseCount;
+final String? revelationPlace;
+
+                const SurahInfo({required this.number ,required this.name ,required this.nameArabic ,required this.nameTransliteration ,required this.nameTranslation ,required this.verseCount ,this.revelationPlace ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => number.hashCode^name.hashCode^nameArabic.hashCode^nameTransliteration.hashCode^nameTranslation.hashCode^verseCount.hashCode^revelationPlace.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SurahInfo &&
+                runtimeType == other.runtimeType
+                && number == other.number&& name == other.name&& nameArabic == other.nameArabic&& nameTransliteration == other.nameTransliteration&& nameTranslation == other.nameTranslation&& verseCount == other.verseCount&& revelationPlace == other.revelationPlace;
+        
+            }
+
+class SyncMemoryStateDto  {
+                final PlatformInt64 nodeId;
+final double energy;
+final double? fsrsStability;
+final double? fsrsDifficulty;
+final PlatformInt64? lastReviewedAt;
+final PlatformInt64? nextReviewAt;
+final PlatformInt64 clientUpdatedAt;
+
+                const SyncMemoryStateDto({required this.nodeId ,required this.energy ,this.fsrsStability ,this.fsrsDifficulty ,this.lastReviewedAt ,this.nextReviewAt ,required this.clientUpdatedAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => nodeId.hashCode^energy.hashCode^fsrsStability.hashCode^fsrsDifficulty.hashCode^lastReviewedAt.hashCode^nextReviewAt.hashCode^clientUpdatedAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SyncMemoryStateDto &&
+                runtimeType == other.runtimeType
+                && nodeId == other.nodeId&& energy == other.energy&& fsrsStability == other.fsrsStability&& fsrsDifficulty == other.fsrsDifficulty&& lastReviewedAt == other.lastReviewedAt&& nextReviewAt == other.nextReviewAt&& clientUpdatedAt == other.clientUpdatedAt;
+        
+            }
+
+class SyncSessionDto  {
+                final String id;
+final String? goalId;
+final PlatformInt64 startedAt;
+final PlatformInt64? completedAt;
+final int itemsCompleted;
+final PlatformInt64 clientUpdatedAt;
+
+                const SyncSessionDto({required this.id ,this.goalId ,required this.startedAt ,this.completedAt ,required this.itemsCompleted ,required this.clientUpdatedAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^goalId.hashCode^startedAt.hashCode^completedAt.hashCode^itemsCompleted.hashCode^clientUpdatedAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SyncSessionDto &&
+                runtimeType == other.runtimeType
+                && id == other.id&& goalId == other.goalId&& startedAt == other.startedAt&& completedAt == other.completedAt&& itemsCompleted == other.itemsCompleted&& clientUpdatedAt == other.clientUpdatedAt;
+        
+            }
+
+class SyncSessionItemDto  {
+                final String id;
+final String sessionId;
+final PlatformInt64 nodeId;
+final String exerciseType;
+final int? grade;
+final PlatformInt64? durationMs;
+final PlatformInt64? completedAt;
+final PlatformInt64 clientUpdatedAt;
+
+                const SyncSessionItemDto({required this.id ,required this.sessionId ,required this.nodeId ,required this.exerciseType ,this.grade ,this.durationMs ,this.completedAt ,required this.clientUpdatedAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^sessionId.hashCode^nodeId.hashCode^exerciseType.hashCode^grade.hashCode^durationMs.hashCode^completedAt.hashCode^clientUpdatedAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SyncSessionItemDto &&
+                runtimeType == other.runtimeType
+                && id == other.id&& sessionId == other.sessionId&& nodeId == other.nodeId&& exerciseType == other.exerciseType&& grade == other.grade&& durationMs == other.durationMs&& completedAt == other.completedAt&& clientUpdatedAt == other.clientUpdatedAt;
+        
+            }
+
+class TafsirDto  {
+                final int tafsirId;
+final String slug;
+final String name;
+final String? author;
+final String? languageCode;
+
+                const TafsirDto({required this.tafsirId ,required this.slug ,required this.name ,this.author ,this.languageCode ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => tafsirId.hashCode^slug.hashCode^name.hashCode^author.hashCode^languageCode.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TafsirDto &&
+                runtimeType == other.runtimeType
+                && tafsirId == other.tafsirId&& slug == other.slug&& name == other.name&& author == other.author&& languageCode == other.languageCode;
+        
+            }
+
+/// Commentary on verses `start_verse..=end_verse` of a chapter
+class TafsirPassageDto  {
+                final int tafsirId;
+final int chapterNumber;
+final int startVerse;
+final int endVerse;
+final String text;
+final String? keyLesson;
+final String? revelationOccasion;
+final List<String> themes;
+
+                const TafsirPassageDto({required this.tafsirId ,required this.chapterNumber ,required this.startVerse ,required this.endVerse ,required this.text ,this.keyLesson ,this.revelationOccasion ,required this.themes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => tafsirId.hashCode^chapterNumber.hashCode^startVerse.hashCode^endVerse.hashCode^text.hashCode^keyLesson.hashCode^revelationOccasion.hashCode^themes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TafsirPassageDto &&
+                runtimeType == other.runtimeType
+                && tafsirId == other.tafsirId&& chapterNumber == other.chapterNumber&& startVerse == other.startVerse&& endVerse == other.endVerse&& text == other.text&& keyLesson == other.keyLesson&& revelationOccasion == other.revelationOccasion&& themes == other.themes;
+        
+            }
+
+/// A tajweed rule on characters `start_char..end_char` of a word's Uthmani
+/// text
+class TajweedAnnotationDto  {
+                final String wordNodeId;
+final int position;
+/// Rule slug ("ikhfa", "madd_lazim", ...)
+final String rule;
+final String ruleName;
+final int startChar;
+final int endChar;
+
+                const TajweedAnnotationDto({required this.wordNodeId ,required this.position ,required this.rule ,required this.ruleName ,required this.startChar ,required this.endChar ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => wordNodeId.hashCode^position.hashCode^rule.hashCode^ruleName.hashCode^startChar.hashCode^endChar.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TajweedAnnotationDto &&
+                runtimeType == other.runtimeType
+                && wordNodeId == other.wordNodeId&& position == other.position&& rule == other.rule&& ruleName == other.ruleName&& startChar == other.startChar&& endChar == other.endChar;
+        
+            }
+
+/// One recognized word of a recitation transcript
+class TranscriptWordDto  {
+                final String text;
+final BigInt? startMs;
+final BigInt? endMs;
+
+                const TranscriptWordDto({required this.text ,this.startMs ,this.endMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => text.hashCode^startMs.hashCode^endMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TranscriptWordDto &&
+                runtimeType == other.runtimeType
+                && text == other.text&& startMs == other.startMs&& endMs == other.endMs;
+        
+            }
+
+class TranslatorDto  {
+                final int id;
+final String slug;
+final String fullName;
+final String languageCode;
+final String? description;
+final String? license;
+
+                const TranslatorDto({required this.id ,required this.slug ,required this.fullName ,required this.languageCode ,this.description ,this.license ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^slug.hashCode^fullName.hashCode^languageCode.hashCode^description.hashCode^license.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TranslatorDto &&
+                runtimeType == other.runtimeType
+                && id == other.id&& slug == other.slug&& fullName == other.fullName&& languageCode == other.languageCode&& description == other.description&& license == other.license;
+        
+            }
+
+/// Result of checking an answer
+class ValidationResultDto  {
+                final bool isCorrect;
+final String? feedback;
+/// Partial credit (0.0 to 1.0)
+final double? similarityScore;
+/// Semantic grading label (Excellent/Partial/Incorrect)
+final String? semanticGrade;
+/// Revealed when the answer was wrong
+final String? correctAnswer;
+/// Suggested review grade (1=Again, 2=Hard, 3=Good, 4=Easy)
+final int suggestedGrade;
+
+                const ValidationResultDto({required this.isCorrect ,this.feedback ,this.similarityScore ,this.semanticGrade ,this.correctAnswer ,required this.suggestedGrade ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => isCorrect.hashCode^feedback.hashCode^similarityScore.hashCode^semanticGrade.hashCode^correctAnswer.hashCode^suggestedGrade.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ValidationResultDto &&
+                runtimeType == other.runtimeType
+                && isCorrect == other.isCorrect&& feedback == other.feedback&& similarityScore == other.similarityScore&& semanticGrade == other.semanticGrade&& correctAnswer == other.correctAnswer&& suggestedGrade == other.suggestedGrade;
+        
+            }
+
+/// Verse audio: a file path or inline bytes, plus word timings
+class VerseAudioDto  {
+                final int reciterId;
+final String verseKey;
+final String? audioPath;
+final Uint8List? audioBytes;
+final String mimeType;
+final PlatformInt64? durationMs;
+final List<WordSegmentDto> segments;
+
+                const VerseAudioDto({required this.reciterId ,required this.verseKey ,this.audioPath ,this.audioBytes ,required this.mimeType ,this.durationMs ,required this.segments ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => reciterId.hashCode^verseKey.hashCode^audioPath.hashCode^audioBytes.hashCode^mimeType.hashCode^durationMs.hashCode^segments.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is VerseAudioDto &&
+                runtimeType == other.runtimeType
+                && reciterId == other.reciterId&& verseKey == other.verseKey&& audioPath == other.audioPath&& audioBytes == other.audioBytes&& mimeType == other.mimeType&& durationMs == other.durationMs&& segments == other.segments;
+        
+            }
+
+class VerseDto  {
+                final String key;
+final String textUthmani;
+final int chapterNumber;
+final int verseNumber;
+
+                const VerseDto({required this.key ,required this.textUthmani ,required this.chapterNumber ,required this.verseNumber ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => key.hashCode^textUthmani.hashCode^chapterNumber.hashCode^verseNumber.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is VerseDto &&
+                runtimeType == other.runtimeType
+                && key == other.key&& textUthmani == other.textUthmani&& chapterNumber == other.chapterNumber&& verseNumber == other.verseNumber;
+        
+            }
+
+class VerseWithTranslationDto  {
+                final String key;
+final String textUthmani;
+final String? translation;
+final int number;
+
+                const VerseWithTranslationDto({required this.key ,required this.textUthmani ,this.translation ,required this.number ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => key.hashCode^textUthmani.hashCode^translation.hashCode^number.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is VerseWithTranslationDto &&
+                runtimeType == other.runtimeType
+                && key == other.key&& textUthmani == other.textUthmani&& translation == other.translation&& number == other.number;
+        
+            }
+
+/// Word audio: play the whole clip, or from `start_ms` to `end_ms` when the
+/// audio is the verse recitation
+class WordAudioDto  {
+                final int reciterId;
+final String verseKey;
+final int position;
+final String? audioPath;
+final Uint8List? audioBytes;
+final String mimeType;
+final PlatformInt64? durationMs;
+final PlatformInt64? startMs;
+final PlatformInt64? endMs;
+
+                const WordAudioDto({required this.reciterId ,required this.verseKey ,required this.position ,this.audioPath ,this.audioBytes ,required this.mimeType ,this.durationMs ,this.startMs ,this.endMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => reciterId.hashCode^verseKey.hashCode^position.hashCode^audioPath.hashCode^audioBytes.hashCode^mimeType.hashCode^durationMs.hashCode^startMs.hashCode^endMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is WordAudioDto &&
+                runtimeType == other.runtimeType
+                && reciterId == other.reciterId&& verseKey == other.verseKey&& position == other.position&& audioPath == other.audioPath&& audioBytes == other.audioBytes&& mimeType == other.mimeType&& durationMs == other.durationMs&& startMs == other.startMs&& endMs == other.endMs;
+        
+            }
+
+class WordDto  {
+                final int id;
+final String textUthmani;
+final String verseKey;
+final int position;
+
+                const WordDto({required this.id ,required this.textUthmani ,required this.verseKey ,required this.position ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^textUthmani.hashCode^verseKey.hashCode^position.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is WordDto &&
+                runtimeType == other.runtimeType
+                && id == other.id&& textUthmani == other.textUthmani&& verseKey == other.verseKey&& position == other.position;
+        
+            }
+
+/// Timing of one word within a verse recitation
+class WordSegmentDto  {
+                final int position;
+final PlatformInt64 startMs;
+final PlatformInt64 endMs;
+
+                const WordSegmentDto({required this.position ,required this.startMs ,required this.endMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => position.hashCode^startMs.hashCode^endMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is WordSegmentDto &&
+                runtimeType == other.runtimeType
+                && position == other.position&& startMs == other.startMs&& endMs == other.endMs;
+        
+            }
+
+/// Per-word timing for metrics
+class WordTimingDto  {
+                final String wordNodeId;
+final BigInt durationMs;
+
+                const WordTimingDto({required this.wordNodeId ,required this.durationMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => wordNodeId.hashCode^durationMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is WordTimingDto &&
+                runtimeType == other.runtimeType
+                && wordNodeId == other.wordNodeId&& durationMs == other.durationMs;
+        
+            }
 
 /// Visibility state for a word in Echo Recall
-class WordVisibilityDto {
-  /// "visible", "obscured", or "hidden"
-  final String visibilityType;
-
-  /// Hint shown when obscured
-  final HintDto? hint;
-
-  /// Blur coverage (0.0 to 1.0) when obscured
-  final double? coverage;
-
-  const WordVisibilityDto({
-    required this.visibilityType,
-    this.hint,
-    this.coverage,
-  });
-
-  @override
-  int get hashCode =>
-      visibilityType.hashCode ^ hint.hashCode ^ coverage.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is WordVisibilityDto &&
-          runtimeType == other.runtimeType &&
-          visibilityType == other.visibilityType &&
-          hint == other.hint &&
-          coverage == other.coverage;
-}
+class WordVisibilityDto  {
+                /// "visible", "obscured", or "hidden"
+final String visibilityType;
+/// Hint shown when obscured
+final HintDto? hint;
+/// Blur coverage (0.0 to 1.0) when obscured
+final double? coverage;
+
+                const WordVisibilityDto({required this.visibilityType ,this.hint ,this.coverage ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => visibilityType.hashCode^hint.hashCode^coverage.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is WordVisibilityDto &&
+                runtimeType == other.runtimeType
+                && visibilityType == other.visibilityType&& hint == other.hint&& coverage == other.coverage;
+        
+            }
+            
\ No newline at end of file
@@ -7,6986 +7,6244 @@ import 'api.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
-import 'frb_generated.io.dart'
-    if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'frb_generated.io.dart' if (dart.library.js_interop) 'frb_generated.web.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-/// Main entrypoint of the Rust API
-class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
-  @internal
-  static final instance = RustLib._();
-
-  RustLib._();
-
-  /// Initialize flutter_rust_bridge
-  static Future<void> init({
-    RustLibApi? api,
-    BaseHandler? handler,
-    ExternalLibrary? externalLibrary,
-    bool forceSameCodegenVersion = true,
-  }) async {
-    await instance.initImpl(
-      api: api,
-      handler: handler,
-      externalLibrary: externalLibrary,
-      forceSameCodegenVersion: forceSameCodegenVersion,
-    );
-  }
-
-  /// Initialize flutter_rust_bridge in mock mode.
-  /// No libraries for FFI are loaded.
-  static void initMock({required RustLibApi api}) {
-    instance.initMockImpl(api: api);
-  }
-
-  /// Dispose flutter_rust_bridge
-  ///
-  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
-  /// is automatically disposed when the app stops.
-  static void dispose() => instance.disposeImpl();
-
-  @override
-  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor =>
-      RustLibApiImpl.new;
-
-  @override
-  WireConstructor<RustLibWire> get wireConstructor =>
-      RustLibWire.fromExternalLibrary;
-
-  @override
-  Future<void> executeRustInitializers() async {
-    await api.crateApiInitApp();
-  }
-
-  @override
-  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig =>
-      kDefaultExternalLibraryLoaderConfig;
-
-  @override
-  String get codegenVersion => '2.11.1';
 
-  @override
-  int get rustContentHash => -1706751684;
+                /// Main entrypoint of the Rust API
+                class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
+                  @internal
+                  static final instance = RustLib._();
 
-  static const kDefaultExternalLibraryLoaderConfig =
-      ExternalLibraryLoaderConfig(
-        stem: 'iqrah_api',
-        ioDirectory: 'rust/crates/iqrah-api/target/release/',
-        webPrefix: 'pkg/',
-      );
-}
+                  RustLib._();
 
-abstract class RustLibApi extends BaseApi {
-  ArcContentRepository crateApiAppStateAutoAccessorGetContentRepo({
-    required AppState that,
-  });
+                  /// Initialize flutter_rust_bridge
+                  static Future<void> init({
+                    RustLibApi? api,
+                    BaseHandler? handler,
+                    ExternalLibrary? externalLibrary,
+                    bool forceSameCodegenVersion = true,
+                  }) async {
+                    await instance.initImpl(
+                      api: api,
+                      handler: handler,
+                      externalLibrary: externalLibrary,
+                      forceSameCodegenVersion: forceSameCodegenVersion,
+                    );
+                  }
 
-  ArcExerciseService crateApiAppStateAutoAccessorGetExerciseService({
-    required AppState that,
-  });
+                  /// Initialize flutter_rust_bridge in mock mode.
+                  /// No libraries for FFI are loaded.
+                  static void initMock({
+                    required RustLibApi api,
+                  }) {
+                    instance.initMockImpl(
+                      api: api,
+                    );
+                  }
 
-  ArcLearningService crateApiAppStateAutoAccessorGetLearningService({
-    required AppState that,
-  });
+                  /// Dispose flutter_rust_bridge
+                  ///
+                  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
+                  /// is automatically disposed when the app stops.
+                  static void dispose() => instance.disposeImpl();
 
-  ArcSessionService crateApiAppStateAutoAccessorGetSessionService({
-    required AppState that,
-  });
+                  @override
+                  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor => RustLibApiImpl.new;
 
-  ArcUserRepository crateApiAppStateAutoAccessorGetUserRepo({
-    required AppState that,
-  });
+                  @override
+                  WireConstructor<RustLibWire> get wireConstructor => RustLibWire.fromExternalLibrary;
 
-  void crateApiAppStateAutoAccessorSetContentRepo({
-    required AppState that,
-    required ArcContentRepository contentRepo,
-  });
+                  @override
+                  Future<void> executeRustInitializers() async {
+                    await api.crateApiInitApp();
 
-  void crateApiAppStateAutoAccessorSetExerciseService({
-    required AppState that,
-    required ArcExerciseService exerciseService,
-  });
+                  }
 
-  void crateApiAppStateAutoAccessorSetLearningService({
-    required AppState that,
-    required ArcLearningService learningService,
-  });
+                  @override
+                  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig => kDefaultExternalLibraryLoaderConfig;
 
-  void crateApiAppStateAutoAccessorSetSessionService({
-    required AppState that,
-    required ArcSessionService sessionService,
-  });
+                  @override
+                  String get codegenVersion => '2.11.1';
 
-  void crateApiAppStateAutoAccessorSetUserRepo({
-    required AppState that,
-    required ArcUserRepository userRepo,
-  });
+                  @override
+                  int get rustContentHash => -1226003283;
 
-  Future<String> crateApiClearSession();
+                  static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
+                    stem: 'iqrah_api',
+                    ioDirectory: 'rust/crates/iqrah-api/target/release/',
+                    webPrefix: 'pkg/',
+                  );
+                }
+                
 
-  Future<String> crateApiCloseDatabases();
+                abstract class RustLibApi extends BaseApi {
+                  ArcContentRepository crateApiAppStateAutoAccessorGetContentRepo({required AppState that });
 
-  Future<SessionSummaryDto> crateApiCompleteSession({
-    required String sessionId,
-  });
+ArcExerciseService crateApiAppStateAutoAccessorGetExerciseService({required AppState that });
 
-  Future<String> crateApiDebugEmitTestEvent();
+ArcLearningService crateApiAppStateAutoAccessorGetLearningService({required AppState that });
 
-  Future<String> crateApiDisablePackage({required String packageId});
+ArcSessionService crateApiAppStateAutoAccessorGetSessionService({required AppState that });
 
-  Future<List<String>> crateApiDrainTelemetryEvents();
+ArcUserRepository crateApiAppStateAutoAccessorGetUserRepo({required AppState that });
 
-  Future<EchoRecallStatsDto> crateApiEchoRecallStats({
-    required EchoRecallStateDto state,
-  });
+void crateApiAppStateAutoAccessorSetContentRepo({required AppState that , required ArcContentRepository contentRepo });
 
-  Future<String> crateApiEnablePackage({required String packageId});
+void crateApiAppStateAutoAccessorSetExerciseService({required AppState that , required ArcExerciseService exerciseService });
 
-  Future<DbQueryResultDto> crateApiExecuteDebugQuery({required String sql});
+void crateApiAppStateAutoAccessorSetLearningService({required AppState that , required ArcLearningService learningService });
 
-  Future<NodeData?> crateApiFetchNodeWithMetadata({required String nodeId});
+void crateApiAppStateAutoAccessorSetSessionService({required AppState that , required ArcSessionService sessionService });
 
-  Future<EchoRecallResultDto> crateApiFinalizeEchoRecall({
-    required String userId,
-    required EchoRecallStateDto state,
-    required EchoRecallMetricsDto metrics,
-  });
+void crateApiAppStateAutoAccessorSetUserRepo({required AppState that , required ArcUserRepository userRepo });
 
-  Future<ExerciseDataDto> crateApiGenerateExerciseV2({required String nodeId});
+Future<int> crateApiBuryNodes({required String userId , required String scope , required PlatformInt64 untilMillis });
 
-  Future<SessionDto?> crateApiGetActiveSession({required String userId});
+Future<ValidationResultDto> crateApiCheckExerciseAnswer({required ExerciseDataDto exercise , required String answerJson });
 
-  Future<List<ContentPackageDto>> crateApiGetAvailablePackages({
-    String? packageType,
-    String? languageCode,
-  });
+Future<String> crateApiClearSession();
 
-  Future<List<SurahInfo>> crateApiGetAvailableSurahs();
+Future<String> crateApiCloseDatabases();
 
-  Future<DashboardStatsDto> crateApiGetDashboardStats({required String userId});
+Future<SessionSummaryDto> crateApiCompleteSession({required String sessionId });
 
-  Future<DbHealthDto> crateApiGetDbHealth();
+Future<String> crateApiDebugEmitTestEvent();
 
-  Future<DebugStatsDto> crateApiGetDebugStats({required String userId});
+Future<String> crateApiDisablePackage({required String packageId });
 
-  Future<DetailedStatsDto> crateApiGetDetailedStats({required String userId});
+Future<List<String>> crateApiDrainTelemetryEvents();
 
-  Future<EnergySnapshotDto> crateApiGetEnergySnapshot({
-    required String userId,
-    required String nodeId,
-  });
+Future<List<AyahJunctionDto>> crateApiEchoRecallJunctions({required EchoRecallStateDto state });
 
-  Future<List<ExerciseDataDto>> crateApiGetExercises({
-    required String userId,
-    required int limit,
-    int? surahFilter,
-    required bool isHighYield,
-  });
+Future<EchoRecallStatsDto> crateApiEchoRecallStats({required EchoRecallStateDto state });
 
-  Future<List<ExerciseDataDto>> crateApiGetExercisesForNode({
-    required String nodeId,
-  });
+Future<String> crateApiEnablePackage({required String packageId });
 
-  Future<List<InstalledPackageDto>> crateApiGetInstalledPackages();
+Future<DbQueryResultDto> crateApiExecuteDebugQuery({required String sql });
 
-  Future<List<LanguageDto>> crateApiGetLanguages();
+Future<NodeData?> crateApiFetchNodeWithMetadata({required String nodeId });
 
-  Future<PlatformInt64> crateApiGetLastSyncTimestamp({required String userId});
+Future<EchoRecallResultDto> crateApiFinalizeEchoRecall({required String userId , required EchoRecallStateDto state , required EchoRecallMetricsDto metrics });
 
-  Future<List<SyncMemoryStateDto>> crateApiGetMemoryStatesSince({
-    required String userId,
-    required PlatformInt64 sinceMillis,
-  });
+Future<ExerciseDataDto> crateApiGenerateExerciseV2({required String nodeId });
 
-  Future<SessionItemDto?> crateApiGetNextSessionItem({
-    required String sessionId,
-  });
+Future<SessionDto?> crateApiGetActiveSession({required String userId });
 
-  Future<int> crateApiGetPreferredTranslatorId();
+Future<String?> crateApiGetArabicLevel();
 
-  Future<List<SyncSessionItemDto>> crateApiGetSessionItemsSince({
-    required String userId,
-    required PlatformInt64 sinceMillis,
-  });
+Future<List<ContentPackageDto>> crateApiGetAvailablePackages({String? packageType , String? languageCode });
 
-  Future<List<SessionPreviewDto>> crateApiGetSessionPreview({
-    required String userId,
-    required int limit,
-    required bool isHighYield,
-  });
+Future<List<SurahInfo>> crateApiGetAvailableSurahs();
 
-  Future<List<SyncSessionDto>> crateApiGetSessionsSince({
-    required String userId,
-    required PlatformInt64 sinceMillis,
-  });
+Future<DashboardStatsDto> crateApiGetDashboardStats({required String userId });
 
-  Future<List<VerseWithTranslationDto>> crateApiGetSurahVerses({
-    required int chapterNumber,
-  });
+Future<DbHealthDto> crateApiGetDbHealth();
 
-  Future<int> crateApiGetTelemetryEventCount();
+Future<DebugStatsDto> crateApiGetDebugStats({required String userId });
 
-  Future<TranslatorDto?> crateApiGetTranslator({required int translatorId});
+Future<DetailedStatsDto> crateApiGetDetailedStats({required String userId });
 
-  Future<List<TranslatorDto>> crateApiGetTranslatorsForLanguage({
-    required String languageCode,
-  });
+Future<EnergySnapshotDto> crateApiGetEnergySnapshot({required String userId , required String nodeId });
 
-  Future<VerseDto?> crateApiGetVerse({required String verseKey});
+Future<ExerciseHintDto?> crateApiGetExerciseHint({required ExerciseDataDto exercise , required int step });
 
-  Future<String?> crateApiGetVerseTranslationByTranslator({
-    required String verseKey,
-    required int translatorId,
-  });
+Future<List<ExerciseHintDto>> crateApiGetExerciseHints({required ExerciseDataDto exercise });
 
-  Future<WordDto?> crateApiGetWord({required int wordId});
+Future<List<ExerciseDataDto>> crateApiGetExercises({required String userId , required int limit , int? surahFilter , required bool isHighYield });
 
-  Future<WordDto?> crateApiGetWordAtPosition({
-    required int chapter,
-    required int verse,
-    required int position,
-  });
+Future<List<ExerciseDataDto>> crateApiGetExercisesForNode({required String nodeId });
 
-  Future<String?> crateApiGetWordTranslation({
-    required int wordId,
-    required int translatorId,
-  });
+Future<List<InstalledPackageDto>> crateApiGetInstalledPackages();
 
-  Future<List<WordDto>> crateApiGetWordsForVerse({required String verseKey});
+Future<List<LanguageDto>> crateApiGetLanguages();
 
-  Future<void> crateApiInitApp();
+Future<PlatformInt64> crateApiGetLastSyncTimestamp({required String userId });
 
-  Future<String> crateApiInstallTranslationPackFromBytes({
-    required String packageId,
-    required List<int> bytes,
-  });
+Future<List<SyncMemoryStateDto>> crateApiGetMemoryStatesSince({required String userId , required PlatformInt64 sinceMillis });
 
-  Future<List<String>> crateApiParseNodeRange({required String range});
+Future<SessionItemDto?> crateApiGetNextSessionItem({required String sessionId });
 
-  Future<String> crateApiProcessReview({
-    required String userId,
-    required String nodeId,
-    required int grade,
-  });
+Future<NodeStatusDto> crateApiGetNodeStatus({required String userId , required String nodeId });
 
-  Future<List<NodeSearchDto>> crateApiQueryNodesFiltered({
-    required String userId,
-    required NodeFilterDto filter,
-    required int limit,
-  });
+Future<List<ExerciseDataDto>> crateApiGetPageExercises({required int page });
 
-  Future<String> crateApiReseedDatabase({required String userId});
+Future<String> crateApiGetPreferredScript();
 
-  Future<List<NodeSearchDto>> crateApiSearchNodes({
-    required String query,
-    required int limit,
-  });
+Future<int> crateApiGetPreferredTranslatorId();
 
-  Future<String> crateApiSetLastSyncTimestamp({
-    required String userId,
-    required PlatformInt64 timestamp,
-  });
+Future<List<ReciterDto>> crateApiGetReciters();
 
-  Future<String> crateApiSetPreferredTranslatorId({required int translatorId});
+Future<List<SyncSessionItemDto>> crateApiGetSessionItemsSince({required String userId , required PlatformInt64 sinceMillis });
 
-  Future<String> crateApiSetupDatabase({
-    required String contentDbPath,
-    required String userDbPath,
-    required List<int> kgBytes,
-  });
+Future<List<SessionPreviewDto>> crateApiGetSessionPreview({required String userId , required int limit , required bool isHighYield });
 
-  Future<String> crateApiSetupDatabaseInMemory({required List<int> kgBytes});
+Future<List<SyncSessionDto>> crateApiGetSessionsSince({required String userId , required PlatformInt64 sinceMillis });
 
-  Future<PropagationResultDto> crateApiSimulatePropagation({
-    required String userId,
-    required String nodeId,
-    required double energyDelta,
-  });
+Future<List<VerseWithTranslationDto>> crateApiGetSurahVerses({required int chapterNumber });
 
-  Future<EchoRecallStateDto> crateApiStartEchoRecall({
-    required String userId,
-    required List<String> ayahNodeIds,
-  });
+Future<TafsirPassageDto?> crateApiGetTafsirPassage({required String verseKey , required int tafsirId });
 
-  Future<SessionDto> crateApiStartSession({
-    required String userId,
-    required String goalId,
-  });
+Future<List<TafsirDto>> crateApiGetTafsirs();
 
-  Future<EchoRecallStateDto> crateApiSubmitEchoRecall({
-    required String userId,
-    required List<String> ayahNodeIds,
-    required EchoRecallStateDto state,
-    required String wordNodeId,
-    required int recallTimeMs,
-  });
-
-  Future<String> crateApiSubmitSessionItem({
-    required String sessionId,
-    required String nodeId,
-    required String exerciseType,
-    required int grade,
-    required BigInt durationMs,
-  });
-
-  Future<String> crateApiUpsertMemoryStatesFromRemote({
-    required String userId,
-    required List<SyncMemoryStateDto> states,
-  });
-
-  Future<String> crateApiUpsertSessionItemsFromRemote({
-    required String userId,
-    required List<SyncSessionItemDto> items,
-  });
-
-  Future<String> crateApiUpsertSessionsFromRemote({
-    required String userId,
-    required List<SyncSessionDto> sessions,
-  });
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_AppState;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_AppState;
-
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_AppStatePtr;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_ArcExerciseService;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_ArcExerciseService;
-
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_ArcExerciseServicePtr;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_ArcLearningService;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_ArcLearningService;
-
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_ArcLearningServicePtr;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_ArcSessionService;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_ArcSessionService;
-
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_ArcSessionServicePtr;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_ArcContentRepository;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_ArcContentRepository;
-
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_ArcContentRepositoryPtr;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_ArcUserRepository;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_ArcUserRepository;
-
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_ArcUserRepositoryPtr;
-}
-
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
-
-  @override
-  ArcContentRepository crateApiAppStateAutoAccessorGetContentRepo({
-    required AppState that,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepository,
+Future<List<TajweedAnnotationDto>> crateApiGetTajweedAnnotations({required String verseKey });
+
+Future<int> crateApiGetTelemetryEventCount();
+
+Future<TranslatorDto?> crateApiGetTranslator({required int translatorId });
+
+Future<List<TranslatorDto>> crateApiGetTranslatorsForLanguage({required String languageCode });
+
+Future<VerseDto?> crateApiGetVerse({required String verseKey });
+
+Future<VerseAudioDto?> crateApiGetVerseAudio({required String verseKey , required int reciterId });
+
+Future<String?> crateApiGetVerseTranslationByTranslator({required String verseKey , required int translatorId });
+
+Future<WordDto?> crateApiGetWord({required int wordId });
+
+Future<WordDto?> crateApiGetWordAtPosition({required int chapter , required int verse , required int position });
+
+Future<WordAudioDto?> crateApiGetWordAudio({required String verseKey , required int position , required int reciterId });
+
+Future<String?> crateApiGetWordTranslation({required int wordId , required int translatorId });
+
+Future<List<WordDto>> crateApiGetWordsForVerse({required String verseKey });
+
+Future<RecitationResultDto> crateApiGradeRecitation({required String userId , required List<String> verseKeys , required List<TranscriptWordDto> transcript });
+
+Future<void> crateApiInitApp();
+
+Future<void> crateApiInitSemanticModelFromBytes({required List<int> tokenizerJson , required List<int> modelSafetensors , required String stagingDir });
+
+Future<void> crateApiInitSemanticModelFromDir({required String modelDir });
+
+Future<String> crateApiInstallPackageFromFile({required String packageId , required String path , String? audioDir });
+
+Future<String> crateApiInstallTranslationPackFromBytes({required String packageId , required List<int> bytes });
+
+Future<bool> crateApiIsSemanticModelLoaded();
+
+Future<List<String>> crateApiParseNodeRange({required String range });
+
+Future<String> crateApiProcessReview({required String userId , required String nodeId , required int grade });
+
+Future<List<NodeSearchDto>> crateApiQueryNodesFiltered({required String userId , required NodeFilterDto filter , required int limit });
+
+Future<ExerciseDataDto> crateApiRegenerateExercise({required String nodeId , required String exerciseType , required BigInt seed , DistractorStrategyDto? distractorStrategy });
+
+Future<String> crateApiReseedDatabase({required String userId });
+
+Future<int> crateApiResetNodes({required String userId , required String scope });
+
+Future<AyahChainStateDto?> crateApiResumeSessionAyahChain({required String sessionId , required int position });
+
+Future<EchoRecallStateDto?> crateApiResumeSessionEchoRecall({required String sessionId , required int position });
+
+Future<List<NodeSearchDto>> crateApiSearchNodes({required String query , required int limit });
+
+Future<String> crateApiSetArabicLevel({required String level });
+
+Future<String> crateApiSetLastSyncTimestamp({required String userId , required PlatformInt64 timestamp });
+
+Future<String> crateApiSetPreferredScript({required String script });
+
+Future<String> crateApiSetPreferredTranslatorId({required int translatorId });
+
+Future<String> crateApiSetupDatabase({required String contentDbPath , required String userDbPath , required List<int> kgBytes });
+
+Future<String> crateApiSetupDatabaseInMemory({required List<int> kgBytes });
+
+Future<PropagationResultDto> crateApiSimulatePropagation({required String userId , required String nodeId , required double energyDelta });
+
+Future<EchoRecallStateDto> crateApiStartEchoRecall({required String userId , required List<String> ayahNodeIds });
+
+Future<SessionDto> crateApiStartSession({required String userId , required String goalId });
+
+Future<EchoRecallStateDto> crateApiSubmitEchoRecall({required String userId , required List<String> ayahNodeIds , required EchoRecallStateDto state , required String wordNodeId , required int recallTimeMs });
+
+Future<ValidationResultDto> crateApiSubmitSessionAnswer({required String sessionId , required String answerJson , required BigInt durationMs , required int hintLevel });
+
+Future<AyahChainStateDto> crateApiSubmitSessionAyahChain({required String sessionId , required int position , required String userId , required String chapterNodeId , required String userInput });
+
+Future<EchoRecallStateDto> crateApiSubmitSessionEchoRecall({required String sessionId , required int position , required String userId , required List<String> ayahNodeIds , required EchoRecallStateDto state , required String wordNodeId , required int recallTimeMs });
+
+Future<String> crateApiSubmitSessionItem({required String sessionId , required bool isCorrect , required BigInt durationMs , required int hintLevel });
+
+Future<int> crateApiSuspendNodes({required String userId , required String scope });
+
+Future<int> crateApiUnsuspendNodes({required String userId , required String scope });
+
+Future<String> crateApiUpsertMemoryStatesFromRemote({required String userId , required List<SyncMemoryStateDto> states });
+
+Future<String> crateApiUpsertSessionItemsFromRemote({required String userId , required List<SyncSessionItemDto> items });
+
+Future<String> crateApiUpsertSessionsFromRemote({required String userId , required List<SyncSessionDto> sessions });
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_AppState;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_AppState;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_AppStatePtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_ArcExerciseService;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_ArcExerciseService;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ArcExerciseServicePtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_ArcLearningService;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_ArcLearningService;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ArcLearningServicePtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_ArcSessionService;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_ArcSessionService;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ArcSessionServicePtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_ArcContentRepository;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_ArcContentRepository;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ArcContentRepositoryPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_ArcUserRepository;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_ArcUserRepository;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ArcUserRepositoryPtr;
+
+
+                }
+                
+
+                class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+                  RustLibApiImpl({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  @override ArcContentRepository crateApiAppStateAutoAccessorGetContentRepo({required AppState that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepository,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiAppStateAutoAccessorGetContentRepoConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiAppStateAutoAccessorGetContentRepoConstMeta =>
-      const TaskConstMeta(
-        debugName: "AppState_auto_accessor_get_content_repo",
-        argNames: ["that"],
-      );
-
-  @override
-  ArcExerciseService crateApiAppStateAutoAccessorGetExerciseService({
-    required AppState that,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseService,
+        )
+        ,
+            constMeta: kCrateApiAppStateAutoAccessorGetContentRepoConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAppStateAutoAccessorGetContentRepoConstMeta => const TaskConstMeta(
+            debugName: "AppState_auto_accessor_get_content_repo",
+            argNames: ["that"],
+        );
+        
+
+@override ArcExerciseService crateApiAppStateAutoAccessorGetExerciseService({required AppState that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseService,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiAppStateAutoAccessorGetExerciseServiceConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiAppStateAutoAccessorGetExerciseServiceConstMeta =>
-      const TaskConstMeta(
-        debugName: "AppState_auto_accessor_get_exercise_service",
-        argNames: ["that"],
-      );
-
-  @override
-  ArcLearningService crateApiAppStateAutoAccessorGetLearningService({
-    required AppState that,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningService,
+        )
+        ,
+            constMeta: kCrateApiAppStateAutoAccessorGetExerciseServiceConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAppStateAutoAccessorGetExerciseServiceConstMeta => const TaskConstMeta(
+            debugName: "AppState_auto_accessor_get_exercise_service",
+            argNames: ["that"],
+        );
+        
+
+@override ArcLearningService crateApiAppStateAutoAccessorGetLearningService({required AppState that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningService,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiAppStateAutoAccessorGetLearningServiceConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiAppStateAutoAccessorGetLearningServiceConstMeta =>
-      const TaskConstMeta(
-        debugName: "AppState_auto_accessor_get_learning_service",
-        argNames: ["that"],
-      );
-
-  @override
-  ArcSessionService crateApiAppStateAutoAccessorGetSessionService({
-    required AppState that,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionService,
+        )
+        ,
+            constMeta: kCrateApiAppStateAutoAccessorGetLearningServiceConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAppStateAutoAccessorGetLearningServiceConstMeta => const TaskConstMeta(
+            debugName: "AppState_auto_accessor_get_learning_service",
+            argNames: ["that"],
+        );
+        
+
+@override ArcSessionService crateApiAppStateAutoAccessorGetSessionService({required AppState that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionService,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiAppStateAutoAccessorGetSessionServiceConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiAppStateAutoAccessorGetSessionServiceConstMeta =>
-      const TaskConstMeta(
-        debugName: "AppState_auto_accessor_get_session_service",
-        argNames: ["that"],
-      );
-
-  @override
-  ArcUserRepository crateApiAppStateAutoAccessorGetUserRepo({
-    required AppState that,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepository,
+        )
+        ,
+            constMeta: kCrateApiAppStateAutoAccessorGetSessionServiceConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAppStateAutoAccessorGetSessionServiceConstMeta => const TaskConstMeta(
+            debugName: "AppState_auto_accessor_get_session_service",
+            argNames: ["that"],
+        );
+        
+
+@override ArcUserRepository crateApiAppStateAutoAccessorGetUserRepo({required AppState that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepository,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiAppStateAutoAccessorGetUserRepoConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiAppStateAutoAccessorGetUserRepoConstMeta =>
-      const TaskConstMeta(
-        debugName: "AppState_auto_accessor_get_user_repo",
-        argNames: ["that"],
-      );
-
-  @override
-  void crateApiAppStateAutoAccessorSetContentRepo({
-    required AppState that,
-    required ArcContentRepository contentRepo,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(
-            that,
-            serializer,
-          );
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepository(
-            contentRepo,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiAppStateAutoAccessorGetUserRepoConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAppStateAutoAccessorGetUserRepoConstMeta => const TaskConstMeta(
+            debugName: "AppState_auto_accessor_get_user_repo",
+            argNames: ["that"],
+        );
+        
+
+@override void crateApiAppStateAutoAccessorSetContentRepo({required AppState that , required ArcContentRepository contentRepo })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(that, serializer);
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepository(contentRepo, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiAppStateAutoAccessorSetContentRepoConstMeta,
-        argValues: [that, contentRepo],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiAppStateAutoAccessorSetContentRepoConstMeta =>
-      const TaskConstMeta(
-        debugName: "AppState_auto_accessor_set_content_repo",
-        argNames: ["that", "contentRepo"],
-      );
-
-  @override
-  void crateApiAppStateAutoAccessorSetExerciseService({
-    required AppState that,
-    required ArcExerciseService exerciseService,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(
-            that,
-            serializer,
-          );
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseService(
-            exerciseService,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiAppStateAutoAccessorSetContentRepoConstMeta,
+            argValues: [that, contentRepo],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAppStateAutoAccessorSetContentRepoConstMeta => const TaskConstMeta(
+            debugName: "AppState_auto_accessor_set_content_repo",
+            argNames: ["that", "contentRepo"],
+        );
+        
+
+@override void crateApiAppStateAutoAccessorSetExerciseService({required AppState that , required ArcExerciseService exerciseService })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(that, serializer);
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseService(exerciseService, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiAppStateAutoAccessorSetExerciseServiceConstMeta,
-        argValues: [that, exerciseService],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiAppStateAutoAccessorSetExerciseServiceConstMeta =>
-      const TaskConstMeta(
-        debugName: "AppState_auto_accessor_set_exercise_service",
-        argNames: ["that", "exerciseService"],
-      );
-
-  @override
-  void crateApiAppStateAutoAccessorSetLearningService({
-    required AppState that,
-    required ArcLearningService learningService,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(
-            that,
-            serializer,
-          );
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningService(
-            learningService,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiAppStateAutoAccessorSetExerciseServiceConstMeta,
+            argValues: [that, exerciseService],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAppStateAutoAccessorSetExerciseServiceConstMeta => const TaskConstMeta(
+            debugName: "AppState_auto_accessor_set_exercise_service",
+            argNames: ["that", "exerciseService"],
+        );
+        
+
+@override void crateApiAppStateAutoAccessorSetLearningService({required AppState that , required ArcLearningService learningService })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(that, serializer);
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningService(learningService, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiAppStateAutoAccessorSetLearningServiceConstMeta,
-        argValues: [that, learningService],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiAppStateAutoAccessorSetLearningServiceConstMeta =>
-      const TaskConstMeta(
-        debugName: "AppState_auto_accessor_set_learning_service",
-        argNames: ["that", "learningService"],
-      );
-
-  @override
-  void crateApiAppStateAutoAccessorSetSessionService({
-    required AppState that,
-    required ArcSessionService sessionService,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(
-            that,
-            serializer,
-          );
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionService(
-            sessionService,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiAppStateAutoAccessorSetLearningServiceConstMeta,
+            argValues: [that, learningService],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAppStateAutoAccessorSetLearningServiceConstMeta => const TaskConstMeta(
+            debugName: "AppState_auto_accessor_set_learning_service",
+            argNames: ["that", "learningService"],
+        );
+        
+
+@override void crateApiAppStateAutoAccessorSetSessionService({required AppState that , required ArcSessionService sessionService })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(that, serializer);
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionService(sessionService, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiAppStateAutoAccessorSetSessionServiceConstMeta,
-        argValues: [that, sessionService],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiAppStateAutoAccessorSetSessionServiceConstMeta =>
-      const TaskConstMeta(
-        debugName: "AppState_auto_accessor_set_session_service",
-        argNames: ["that", "sessionService"],
-      );
-
-  @override
-  void crateApiAppStateAutoAccessorSetUserRepo({
-    required AppState that,
-    required ArcUserRepository userRepo,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(
-            that,
-            serializer,
-          );
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepository(
-            userRepo,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiAppStateAutoAccessorSetSessionServiceConstMeta,
+            argValues: [that, sessionService],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAppStateAutoAccessorSetSessionServiceConstMeta => const TaskConstMeta(
+            debugName: "AppState_auto_accessor_set_session_service",
+            argNames: ["that", "sessionService"],
+        );
+        
+
+@override void crateApiAppStateAutoAccessorSetUserRepo({required AppState that , required ArcUserRepository userRepo })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(that, serializer);
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepository(userRepo, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiAppStateAutoAccessorSetUserRepoConstMeta,
-        argValues: [that, userRepo],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiAppStateAutoAccessorSetUserRepoConstMeta =>
-      const TaskConstMeta(
-        debugName: "AppState_auto_accessor_set_user_repo",
-        argNames: ["that", "userRepo"],
-      );
-
-  @override
-  Future<String> crateApiClearSession() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 11,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiAppStateAutoAccessorSetUserRepoConstMeta,
+            argValues: [that, userRepo],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAppStateAutoAccessorSetUserRepoConstMeta => const TaskConstMeta(
+            debugName: "AppState_auto_accessor_set_user_repo",
+            argNames: ["that", "userRepo"],
+        );
+        
+
+@override Future<int> crateApiBuryNodes({required String userId , required String scope , required PlatformInt64 untilMillis })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(userId, serializer);
+sse_encode_String(scope, serializer);
+sse_encode_i_64(untilMillis, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiBuryNodesConstMeta,
+            argValues: [userId, scope, untilMillis],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBuryNodesConstMeta => const TaskConstMeta(
+            debugName: "bury_nodes",
+            argNames: ["userId", "scope", "untilMillis"],
+        );
+        
+
+@override Future<ValidationResultDto> crateApiCheckExerciseAnswer({required ExerciseDataDto exercise , required String answerJson })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_exercise_data_dto(exercise, serializer);
+sse_encode_String(answerJson, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_validation_result_dto,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiCheckExerciseAnswerConstMeta,
+            argValues: [exercise, answerJson],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCheckExerciseAnswerConstMeta => const TaskConstMeta(
+            debugName: "check_exercise_answer",
+            argNames: ["exercise", "answerJson"],
+        );
+        
+
+@override Future<String> crateApiClearSession()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiClearSessionConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiClearSessionConstMeta =>
-      const TaskConstMeta(debugName: "clear_session", argNames: []);
-
-  @override
-  Future<String> crateApiCloseDatabases() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 12,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiClearSessionConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiClearSessionConstMeta => const TaskConstMeta(
+            debugName: "clear_session",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiCloseDatabases()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiCloseDatabasesConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiCloseDatabasesConstMeta =>
-      const TaskConstMeta(debugName: "close_databases", argNames: []);
-
-  @override
-  Future<SessionSummaryDto> crateApiCompleteSession({
-    required String sessionId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(sessionId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 13,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiCloseDatabasesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCloseDatabasesConstMeta => const TaskConstMeta(
+            debugName: "close_databases",
+            argNames: [],
+        );
+        
+
+@override Future<SessionSummaryDto> crateApiCompleteSession({required String sessionId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(sessionId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_session_summary_dto,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiCompleteSessionConstMeta,
-        argValues: [sessionId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiCompleteSessionConstMeta => const TaskConstMeta(
-    debugName: "complete_session",
-    argNames: ["sessionId"],
-  );
-
-  @override
-  Future<String> crateApiDebugEmitTestEvent() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 14,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiCompleteSessionConstMeta,
+            argValues: [sessionId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCompleteSessionConstMeta => const TaskConstMeta(
+            debugName: "complete_session",
+            argNames: ["sessionId"],
+        );
+        
+
+@override Future<String> crateApiDebugEmitTestEvent()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiDebugEmitTestEventConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiDebugEmitTestEventConstMeta =>
-      const TaskConstMeta(debugName: "debug_emit_test_event", argNames: []);
-
-  @override
-  Future<String> crateApiDisablePackage({required String packageId}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(packageId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 15,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiDebugEmitTestEventConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDebugEmitTestEventConstMeta => const TaskConstMeta(
+            debugName: "debug_emit_test_event",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiDisablePackage({required String packageId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(packageId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiDisablePackageConstMeta,
-        argValues: [packageId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiDisablePackageConstMeta => const TaskConstMeta(
-    debugName: "disable_package",
-    argNames: ["packageId"],
-  );
-
-  @override
-  Future<List<String>> crateApiDrainTelemetryEvents() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 16,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiDisablePackageConstMeta,
+            argValues: [packageId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDisablePackageConstMeta => const TaskConstMeta(
+            debugName: "disable_package",
+            argNames: ["packageId"],
+        );
+        
+
+@override Future<List<String>> crateApiDrainTelemetryEvents()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiDrainTelemetryEventsConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiDrainTelemetryEventsConstMeta =>
-      const TaskConstMeta(debugName: "drain_telemetry_events", argNames: []);
-
-  @override
-  Future<EchoRecallStatsDto> crateApiEchoRecallStats({
-    required EchoRecallStateDto state,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_echo_recall_state_dto(state, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 17,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiDrainTelemetryEventsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDrainTelemetryEventsConstMeta => const TaskConstMeta(
+            debugName: "drain_telemetry_events",
+            argNames: [],
+        );
+        
+
+@override Future<List<AyahJunctionDto>> crateApiEchoRecallJunctions({required EchoRecallStateDto state })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_echo_recall_state_dto(state, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_ayah_junction_dto,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEchoRecallJunctionsConstMeta,
+            argValues: [state],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEchoRecallJunctionsConstMeta => const TaskConstMeta(
+            debugName: "echo_recall_junctions",
+            argNames: ["state"],
+        );
+        
+
+@override Future<EchoRecallStatsDto> crateApiEchoRecallStats({required EchoRecallStateDto state })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_echo_recall_state_dto(state, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_echo_recall_stats_dto,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiEchoRecallStatsConstMeta,
-        argValues: [state],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiEchoRecallStatsConstMeta =>
-      const TaskConstMeta(debugName: "echo_recall_stats", argNames: ["state"]);
-
-  @override
-  Future<String> crateApiEnablePackage({required String packageId}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(packageId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 18,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiEchoRecallStatsConstMeta,
+            argValues: [state],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEchoRecallStatsConstMeta => const TaskConstMeta(
+            debugName: "echo_recall_stats",
+            argNames: ["state"],
+        );
+        
+
+@override Future<String> crateApiEnablePackage({required String packageId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(packageId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiEnablePackageConstMeta,
-        argValues: [packageId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiEnablePackageConstMeta =>
-      const TaskConstMeta(debugName: "enable_package", argNames: ["packageId"]);
-
-  @override
-  Future<DbQueryResultDto> crateApiExecuteDebugQuery({required String sql}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(sql, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 19,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiEnablePackageConstMeta,
+            argValues: [packageId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnablePackageConstMeta => const TaskConstMeta(
+            debugName: "enable_package",
+            argNames: ["packageId"],
+        );
+        
+
+@override Future<DbQueryResultDto> crateApiExecuteDebugQuery({required String sql })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(sql, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_db_query_result_dto,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiExecuteDebugQueryConstMeta,
-        argValues: [sql],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiExecuteDebugQueryConstMeta =>
-      const TaskConstMeta(debugName: "execute_debug_query", argNames: ["sql"]);
-
-  @override
-  Future<NodeData?> crateApiFetchNodeWithMetadata({required String nodeId}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(nodeId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 20,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiExecuteDebugQueryConstMeta,
+            argValues: [sql],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExecuteDebugQueryConstMeta => const TaskConstMeta(
+            debugName: "execute_debug_query",
+            argNames: ["sql"],
+        );
+        
+
+@override Future<NodeData?> crateApiFetchNodeWithMetadata({required String nodeId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(nodeId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_opt_box_autoadd_node_data,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiFetchNodeWithMetadataConstMeta,
-        argValues: [nodeId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiFetchNodeWithMetadataConstMeta =>
-      const TaskConstMeta(
-        debugName: "fetch_node_with_metadata",
-        argNames: ["nodeId"],
-      );
-
-  @override
-  Future<EchoRecallResultDto> crateApiFinalizeEchoRecall({
-    required String userId,
-    required EchoRecallStateDto state,
-    required EchoRecallMetricsDto metrics,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(userId, serializer);
-          sse_encode_box_autoadd_echo_recall_state_dto(state, serializer);
-          sse_encode_box_autoadd_echo_recall_metrics_dto(metrics, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 21,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiFetchNodeWithMetadataConstMeta,
+            argValues: [nodeId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFetchNodeWithMetadataConstMeta => const TaskConstMeta(
+            debugName: "fetch_node_with_metadata",
+            argNames: ["nodeId"],
+        );
+        
+
+@override Future<EchoRecallResultDto> crateApiFinalizeEchoRecall({required String userId , required EchoRecallStateDto state , required EchoRecallMetricsDto metrics })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(userId, serializer);
+sse_encode_box_autoadd_echo_recall_state_dto(state, serializer);
+sse_encode_box_autoadd_echo_recall_metrics_dto(metrics, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_echo_recall_result_dto,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiFinalizeEchoRecallConstMeta,
-        argValues: [userId, state, metrics],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiFinalizeEchoRecallConstMeta => const TaskConstMeta(
-    debugName: "finalize_echo_recall",
-    argNames: ["userId", "state", "metrics"],
-  );
-
-  @override
-  Future<ExerciseDataDto> crateApiGenerateExerciseV2({required String nodeId}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(nodeId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 22,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiFinalizeEchoRecallConstMeta,
+            argValues: [userId, state, metrics],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFinalizeEchoRecallConstMeta => const TaskConstMeta(
+            debugName: "finalize_echo_recall",
+            argNames: ["userId", "state", "metrics"],
+        );
+        
+
+@override Future<ExerciseDataDto> crateApiGenerateExerciseV2({required String nodeId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(nodeId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_exercise_data_dto,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiGenerateExerciseV2ConstMeta,
-        argValues: [nodeId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGenerateExerciseV2ConstMeta => const TaskConstMeta(
-    debugName: "generate_exercise_v2",
-    argNames: ["nodeId"],
-  );
-
-  @override
-  Future<SessionDto?> crateApiGetActiveSession({required String userId}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(userId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 23,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGenerateExerciseV2ConstMeta,
+            argValues: [nodeId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGenerateExerciseV2ConstMeta => const TaskConstMeta(
+            debugName: "generate_exercise_v2",
+            argNames: ["nodeId"],
+        );
+        
+
+@override Future<SessionDto?> crateApiGetActiveSession({required String userId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(userId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_opt_box_autoadd_session_dto,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiGetActiveSessionConstMeta,
-        argValues: [userId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetActiveSessionConstMeta => const TaskConstMeta(
-    debugName: "get_active_session",
-    argNames: ["userId"],
-  );
-
-  @override
-  Future<List<ContentPackageDto>> crateApiGetAvailablePackages({
-    String? packageType,
-    String? languageCode,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_opt_String(packageType, serializer);
-          sse_encode_opt_String(languageCode, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 24,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetActiveSessionConstMeta,
+            argValues: [userId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetActiveSessionConstMeta => const TaskConstMeta(
+            debugName: "get_active_session",
+            argNames: ["userId"],
+        );
+        
+
+@override Future<String?> crateApiGetArabicLevel()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiGetArabicLevelConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetArabicLevelConstMeta => const TaskConstMeta(
+            debugName: "get_arabic_level",
+            argNames: [],
+        );
+        
+
+@override Future<List<ContentPackageDto>> crateApiGetAvailablePackages({String? packageType , String? languageCode })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_opt_String(packageType, serializer);
+sse_encode_opt_String(languageCode, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_content_package_dto,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiGetAvailablePackagesConstMeta,
-        argValues: [packageType, languageCode],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetAvailablePackagesConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_available_packages",
-        argNames: ["packageType", "languageCode"],
-      );
-
-  @override
-  Future<List<SurahInfo>> crateApiGetAvailableSurahs() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 25,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetAvailablePackagesConstMeta,
+            argValues: [packageType, languageCode],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetAvailablePackagesConstMeta => const TaskConstMeta(
+            debugName: "get_available_packages",
+            argNames: ["packageType", "languageCode"],
+        );
+        
+
+@override Future<List<SurahInfo>> crateApiGetAvailableSurahs()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_surah_info,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiGetAvailableSurahsConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetAvailableSurahsConstMeta =>
-      const TaskConstMeta(debugName: "get_available_surahs", argNames: []);
-
-  @override
-  Future<DashboardStatsDto> crateApiGetDashboardStats({
-    required String userId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(userId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 26,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetAvailableSurahsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetAvailableSurahsConstMeta => const TaskConstMeta(
+            debugName: "get_available_surahs",
+            argNames: [],
+        );
+        
+
+@override Future<DashboardStatsDto> crateApiGetDashboardStats({required String userId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(userId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_dashboard_stats_dto,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiGetDashboardStatsConstMeta,
-        argValues: [userId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetDashboardStatsConstMeta => const TaskConstMeta(
-    debugName: "get_dashboard_stats",
-    argNames: ["userId"],
-  );
-
-  @override
-  Future<DbHealthDto> crateApiGetDbHealth() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 27,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetDashboardStatsConstMeta,
+            argValues: [userId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetDashboardStatsConstMeta => const TaskConstMeta(
+            debugName: "get_dashboard_stats",
+            argNames: ["userId"],
+        );
+        
+
+@override Future<DbHealthDto> crateApiGetDbHealth()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_db_health_dto,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiGetDbHealthConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetDbHealthConstMeta =>
-      const TaskConstMeta(debugName: "get_db_health", argNames: []);
-
-  @override
-  Future<DebugStatsDto> crateApiGetDebugStats({required String userId}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(userId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 28,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetDbHealthConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetDbHealthConstMeta => const TaskConstMeta(
+            debugName: "get_db_health",
+            argNames: [],
+        );
+        
+
+@override Future<DebugStatsDto> crateApiGetDebugStats({required String userId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(userId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_debug_stats_dto,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiGetDebugStatsConstMeta,
-        argValues: [userId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetDebugStatsConstMeta =>
-      const TaskConstMeta(debugName: "get_debug_stats", argNames: ["userId"]);
-
-  @override
-  Future<DetailedStatsDto> crateApiGetDetailedStats({required String userId}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(userId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 29,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetDebugStatsConstMeta,
+            argValues: [userId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetDebugStatsConstMeta => const TaskConstMeta(
+            debugName: "get_debug_stats",
+            argNames: ["userId"],
+        );
+        
+
+@override Future<DetailedStatsDto> crateApiGetDetailedStats({required String userId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(userId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_detailed_stats_dto,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiGetDetailedStatsConstMeta,
-        argValues: [userId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetDetailedStatsConstMeta => const TaskConstMeta(
-    debugName: "get_detailed_stats",
-    argNames: ["userId"],
-  );
-
-  @override
-  Future<EnergySnapshotDto> crateApiGetEnergySnapshot({
-    required String userId,
-    required String nodeId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(userId, serializer);
-          sse_encode_String(nodeId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 30,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetDetailedStatsConstMeta,
+            argValues: [userId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetDetailedStatsConstMeta => const TaskConstMeta(
+            debugName: "get_detailed_stats",
+            argNames: ["userId"],
+        );
+        
+
+@override Future<EnergySnapshotDto> crateApiGetEnergySnapshot({required String userId , required String nodeId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(userId, serializer);
+sse_encode_String(nodeId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_energy_snapshot_dto,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiGetEnergySnapshotConstMeta,
-        argValues: [userId, nodeId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetEnergySnapshotConstMeta => const TaskConstMeta(
-    debugName: "get_energy_snapshot",
-    argNames: ["userId", "nodeId"],
-  );
-
-  @override
-  Future<List<ExerciseDataDto>> crateApiGetExercises({
-    required String userId,
-    required int limit,
-    int? surahFilter,
-    required bool isHighYield,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(userId, serializer);
-          sse_encode_u_32(limit, serializer);
-          sse_encode_opt_box_autoadd_i_32(surahFilter, serializer);
-          sse_encode_bool(isHighYield, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 31,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetEnergySnapshotConstMeta,
+            argValues: [userId, nodeId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetEnergySnapshotConstMeta => const TaskConstMeta(
+            debugName: "get_energy_snapshot",
+            argNames: ["userId", "nodeId"],
+        );
+        
+
+@override Future<ExerciseHintDto?> crateApiGetExerciseHint({required ExerciseDataDto exercise , required int step })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_exercise_data_dto(exercise, serializer);
+sse_encode_u_32(step, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_exercise_hint_dto,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiGetExerciseHintConstMeta,
+            argValues: [exercise, step],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetExerciseHintConstMeta => const TaskConstMeta(
+            debugName: "get_exercise_hint",
+            argNames: ["exercise", "step"],
+        );
+        
+
+@override Future<List<ExerciseHintDto>> crateApiGetExerciseHints({required ExerciseDataDto exercise })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_exercise_data_dto(exercise, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_exercise_hint_dto,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiGetExerciseHintsConstMeta,
+            argValues: [exercise],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetExerciseHintsConstMeta => const TaskConstMeta(
+            debugName: "get_exercise_hints",
+            argNames: ["exercise"],
+        );
+        
+
+@override Future<List<ExerciseDataDto>> crateApiGetExercises({required String userId , required int limit , int? surahFilter , required bool isHighYield })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(userId, serializer);
+sse_encode_u_32(limit, serializer);
+sse_encode_opt_box_autoadd_i_32(surahFilter, serializer);
+sse_encode_bool(isHighYield, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_exercise_data_dto,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiGetExercisesConstMeta,
-        argValues: [userId, limit, surahFilter, isHighYield],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetExercisesConstMeta => const TaskConstMeta(
-    debugName: "get_exercises",
-    argNames: ["userId", "limit", "surahFilter", "isHighYield"],
-  );
-
-  @override
-  Future<List<ExerciseDataDto>> crateApiGetExercisesForNode({
-    required String nodeId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(nodeId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 32,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetExercisesConstMeta,
+            argValues: [userId, limit, surahFilter, isHighYield],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetExercisesConstMeta => const TaskConstMeta(
+            debugName: "get_exercises",
+            argNames: ["userId", "limit", "surahFilter", "isHighYield"],
+        );
+        
+
+@override Future<List<ExerciseDataDto>> crateApiGetExercisesForNode({required String nodeId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(nodeId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_exercise_data_dto,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiGetExercisesForNodeConstMeta,
-        argValues: [nodeId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetExercisesForNodeConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_exercises_for_node",
-        argNames: ["nodeId"],
-      );
-
-  @override
-  Future<List<InstalledPackageDto>> crateApiGetInstalledPackages() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 33,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetExercisesForNodeConstMeta,
+            argValues: [nodeId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetExercisesForNodeConstMeta => const TaskConstMeta(
+            debugName: "get_exercises_for_node",
+            argNames: ["nodeId"],
+        );
+        
+
+@override Future<List<InstalledPackageDto>> crateApiGetInstalledPackages()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_installed_package_dto,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiGetInstalledPackagesConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetInstalledPackagesConstMeta =>
-      const TaskConstMeta(debugName: "get_installed_packages", argNames: []);
-
-  @override
-  Future<List<LanguageDto>> crateApiGetLanguages() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 34,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetInstalledPackagesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetInstalledPackagesConstMeta => const TaskConstMeta(
+            debugName: "get_installed_packages",
+            argNames: [],
+        );
+        
+
+@override Future<List<LanguageDto>> crateApiGetLanguages()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_language_dto,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiGetLanguagesConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetLanguagesConstMeta =>
-      const TaskConstMeta(debugName: "get_languages", argNames: []);
-
-  @override
-  Future<PlatformInt64> crateApiGetLastSyncTimestamp({required String userId}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(userId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 35,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetLanguagesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetLanguagesConstMeta => const TaskConstMeta(
+            debugName: "get_languages",
+            argNames: [],
+        );
+        
+
+@override Future<PlatformInt64> crateApiGetLastSyncTimestamp({required String userId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(userId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_i_64,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiGetLastSyncTimestampConstMeta,
-        argValues: [userId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetLastSyncTimestampConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_last_sync_timestamp",
-        argNames: ["userId"],
-      );
-
-  @override
-  Future<List<SyncMemoryStateDto>> crateApiGetMemoryStatesSince({
-    required String userId,
-    required PlatformInt64 sinceMillis,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(userId, serializer);
-          sse_encode_i_64(sinceMillis, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 36,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetLastSyncTimestampConstMeta,
+            argValues: [userId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetLastSyncTimestampConstMeta => const TaskConstMeta(
+            debugName: "get_last_sync_timestamp",
+            argNames: ["userId"],
+        );
+        
+
+@override Future<List<SyncMemoryStateDto>> crateApiGetMemoryStatesSince({required String userId , required PlatformInt64 sinceMillis })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(userId, serializer);
+sse_encode_i_64(sinceMillis, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_sync_memory_state_dto,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiGetMemoryStatesSinceConstMeta,
-        argValues: [userId, sinceMillis],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetMemoryStatesSinceConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_memory_states_since",
-        argNames: ["userId", "sinceMillis"],
-      );
-
-  @override
-  Future<SessionItemDto?> crateApiGetNextSessionItem({
-    required String sessionId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(sessionId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 37,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetMemoryStatesSinceConstMeta,
+            argValues: [userId, sinceMillis],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetMemoryStatesSinceConstMeta => const TaskConstMeta(
+            debugName: "get_memory_states_since",
+            argNames: ["userId", "sinceMillis"],
+        );
+        
+
+@override Future<SessionItemDto?> crateApiGetNextSessionItem({required String sessionId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(sessionId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_opt_box_autoadd_session_item_dto,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiGetNextSessionItemConstMeta,
-        argValues: [sessionId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetNextSessionItemConstMeta => const TaskConstMeta(
-    debugName: "get_next_session_item",
-    argNames: ["sessionId"],
-  );
-
-  @override
-  Future<int> crateApiGetPreferredTranslatorId() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 38,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetNextSessionItemConstMeta,
+            argValues: [sessionId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetNextSessionItemConstMeta => const TaskConstMeta(
+            debugName: "get_next_session_item",
+            argNames: ["sessionId"],
+        );
+        
+
+@override Future<NodeStatusDto> crateApiGetNodeStatus({required String userId , required String nodeId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(userId, serializer);
+sse_encode_String(nodeId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_node_status_dto,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiGetNodeStatusConstMeta,
+            argValues: [userId, nodeId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetNodeStatusConstMeta => const TaskConstMeta(
+            debugName: "get_node_status",
+            argNames: ["userId", "nodeId"],
+        );
+        
+
+@override Future<List<ExerciseDataDto>> crateApiGetPageExercises({required int page })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(page, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_exercise_data_dto,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiGetPageExercisesConstMeta,
+            argValues: [page],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetPageExercisesConstMeta => const TaskConstMeta(
+            debugName: "get_page_exercises",
+            argNames: ["page"],
+        );
+        
+
+@override Future<String> crateApiGetPreferredScript()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiGetPreferredScriptConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetPreferredScriptConstMeta => const TaskConstMeta(
+            debugName: "get_preferred_script",
+            argNames: [],
+        );
+        
+
+@override Future<int> crateApiGetPreferredTranslatorId()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_i_32,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiGetPreferredTranslatorIdConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetPreferredTranslatorIdConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_preferred_translator_id",
-        argNames: [],
-      );
-
-  @override
-  Future<List<SyncSessionItemDto>> crateApiGetSessionItemsSince({
-    required String userId,
-    required PlatformInt64 sinceMillis,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(userId, serializer);
-          sse_encode_i_64(sinceMillis, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 39,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetPreferredTranslatorIdConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetPreferredTranslatorIdConstMeta => const TaskConstMeta(
+            debugName: "get_preferred_translator_id",
+            argNames: [],
+        );
+        
+
+@override Future<List<ReciterDto>> crateApiGetReciters()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_reciter_dto,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiGetRecitersConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetRecitersConstMeta => const TaskConstMeta(
+            debugName: "get_reciters",
+            argNames: [],
+        );
+        
+
+@override Future<List<SyncSessionItemDto>> crateApiGetSessionItemsSince({required String userId , required PlatformInt64 sinceMillis })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(userId, serializer);
+sse_encode_i_64(sinceMillis, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_sync_session_item_dto,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiGetSessionItemsSinceConstMeta,
-        argValues: [userId, sinceMillis],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetSessionItemsSinceConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_session_items_since",
-        argNames: ["userId", "sinceMillis"],
-      );
-
-  @override
-  Future<List<SessionPreviewDto>> crateApiGetSessionPreview({
-    required String userId,
-    required int limit,
-    required bool isHighYield,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(userId, serializer);
-          sse_encode_u_32(limit, serializer);
-          sse_encode_bool(isHighYield, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 40,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetSessionItemsSinceConstMeta,
+            argValues: [userId, sinceMillis],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetSessionItemsSinceConstMeta => const TaskConstMeta(
+            debugName: "get_session_items_since",
+            argNames: ["userId", "sinceMillis"],
+        );
+        
+
+@override Future<List<SessionPreviewDto>> crateApiGetSessionPreview({required String userId , required int limit , required bool isHighYield })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(userId, serializer);
+sse_encode_u_32(limit, serializer);
+sse_encode_bool(isHighYield, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_session_preview_dto,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiGetSessionPreviewConstMeta,
-        argValues: [userId, limit, isHighYield],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetSessionPreviewConstMeta => const TaskConstMeta(
-    debugName: "get_session_preview",
-    argNames: ["userId", "limit", "isHighYield"],
-  );
-
-  @override
-  Future<List<SyncSessionDto>> crateApiGetSessionsSince({
-    required String userId,
-    required PlatformInt64 sinceMillis,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(userId, serializer);
-          sse_encode_i_64(sinceMillis, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 41,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetSessionPreviewConstMeta,
+            argValues: [userId, limit, isHighYield],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetSessionPreviewConstMeta => const TaskConstMeta(
+            debugName: "get_session_preview",
+            argNames: ["userId", "limit", "isHighYield"],
+        );
+        
+
+@override Future<List<SyncSessionDto>> crateApiGetSessionsSince({required String userId , required PlatformInt64 sinceMillis })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(userId, serializer);
+sse_encode_i_64(sinceMillis, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_sync_session_dto,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiGetSessionsSinceConstMeta,
-        argValues: [userId, sinceMillis],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetSessionsSinceConstMeta => const TaskConstMeta(
-    debugName: "get_sessions_since",
-    argNames: ["userId", "sinceMillis"],
-  );
-
-  @override
-  Future<List<VerseWithTranslationDto>> crateApiGetSurahVerses({
-    required int chapterNumber,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_32(chapterNumber, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 42,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetSessionsSinceConstMeta,
+            argValues: [userId, sinceMillis],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetSessionsSinceConstMeta => const TaskConstMeta(
+            debugName: "get_sessions_since",
+            argNames: ["userId", "sinceMillis"],
+        );
+        
+
+@override Future<List<VerseWithTranslationDto>> crateApiGetSurahVerses({required int chapterNumber })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(chapterNumber, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_verse_with_translation_dto,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiGetSurahVersesConstMeta,
-        argValues: [chapterNumber],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetSurahVersesConstMeta => const TaskConstMeta(
-    debugName: "get_surah_verses",
-    argNames: ["chapterNumber"],
-  );
-
-  @override
-  Future<int> crateApiGetTelemetryEventCount() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 43,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetSurahVersesConstMeta,
+            argValues: [chapterNumber],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetSurahVersesConstMeta => const TaskConstMeta(
+            debugName: "get_surah_verses",
+            argNames: ["chapterNumber"],
+        );
+        
+
+@override Future<TafsirPassageDto?> crateApiGetTafsirPassage({required String verseKey , required int tafsirId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(verseKey, serializer);
+sse_encode_i_32(tafsirId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 53, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_tafsir_passage_dto,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiGetTafsirPassageConstMeta,
+            argValues: [verseKey, tafsirId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetTafsirPassageConstMeta => const TaskConstMeta(
+            debugName: "get_tafsir_passage",
+            argNames: ["verseKey", "tafsirId"],
+        );
+        
+
+@override Future<List<TafsirDto>> crateApiGetTafsirs()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 54, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_tafsir_dto,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiGetTafsirsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetTafsirsConstMeta => const TaskConstMeta(
+            debugName: "get_tafsirs",
+            argNames: [],
+        );
+        
+
+@override Future<List<TajweedAnnotationDto>> crateApiGetTajweedAnnotations({required String verseKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(verseKey, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 55, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_tajweed_annotation_dto,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiGetTajweedAnnotationsConstMeta,
+            argValues: [verseKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetTajweedAnnotationsConstMeta => const TaskConstMeta(
+            debugName: "get_tajweed_annotations",
+            argNames: ["verseKey"],
+        );
+        
+
+@override Future<int> crateApiGetTelemetryEventCount()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 56, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_32,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiGetTelemetryEventCountConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetTelemetryEventCountConstMeta =>
-      const TaskConstMeta(debugName: "get_telemetry_event_count", argNames: []);
-
-  @override
-  Future<TranslatorDto?> crateApiGetTranslator({required int translatorId}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_32(translatorId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 44,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetTelemetryEventCountConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetTelemetryEventCountConstMeta => const TaskConstMeta(
+            debugName: "get_telemetry_event_count",
+            argNames: [],
+        );
+        
+
+@override Future<TranslatorDto?> crateApiGetTranslator({required int translatorId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(translatorId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 57, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_opt_box_autoadd_translator_dto,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiGetTranslatorConstMeta,
-        argValues: [translatorId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetTranslatorConstMeta => const TaskConstMeta(
-    debugName: "get_translator",
-    argNames: ["translatorId"],
-  );
-
-  @override
-  Future<List<TranslatorDto>> crateApiGetTranslatorsForLanguage({
-    required String languageCode,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(languageCode, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 45,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetTranslatorConstMeta,
+            argValues: [translatorId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetTranslatorConstMeta => const TaskConstMeta(
+            debugName: "get_translator",
+            argNames: ["translatorId"],
+        );
+        
+
+@override Future<List<TranslatorDto>> crateApiGetTranslatorsForLanguage({required String languageCode })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(languageCode, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_translator_dto,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiGetTranslatorsForLanguageConstMeta,
-        argValues: [languageCode],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetTranslatorsForLanguageConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_translators_for_language",
-        argNames: ["languageCode"],
-      );
-
-  @override
-  Future<VerseDto?> crateApiGetVerse({required String verseKey}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(verseKey, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 46,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetTranslatorsForLanguageConstMeta,
+            argValues: [languageCode],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetTranslatorsForLanguageConstMeta => const TaskConstMeta(
+            debugName: "get_translators_for_language",
+            argNames: ["languageCode"],
+        );
+        
+
+@override Future<VerseDto?> crateApiGetVerse({required String verseKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(verseKey, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 59, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_opt_box_autoadd_verse_dto,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiGetVerseConstMeta,
-        argValues: [verseKey],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetVerseConstMeta =>
-      const TaskConstMeta(debugName: "get_verse", argNames: ["verseKey"]);
-
-  @override
-  Future<String?> crateApiGetVerseTranslationByTranslator({
-    required String verseKey,
-    required int translatorId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(verseKey, serializer);
-          sse_encode_i_32(translatorId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 47,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetVerseConstMeta,
+            argValues: [verseKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetVerseConstMeta => const TaskConstMeta(
+            debugName: "get_verse",
+            argNames: ["verseKey"],
+        );
+        
+
+@override Future<VerseAudioDto?> crateApiGetVerseAudio({required String verseKey , required int reciterId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(verseKey, serializer);
+sse_encode_i_32(reciterId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 60, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_verse_audio_dto,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiGetVerseAudioConstMeta,
+            argValues: [verseKey, reciterId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetVerseAudioConstMeta => const TaskConstMeta(
+            debugName: "get_verse_audio",
+            argNames: ["verseKey", "reciterId"],
+        );
+        
+
+@override Future<String?> crateApiGetVerseTranslationByTranslator({required String verseKey , required int translatorId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(verseKey, serializer);
+sse_encode_i_32(translatorId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_opt_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiGetVerseTranslationByTranslatorConstMeta,
-        argValues: [verseKey, translatorId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetVerseTranslationByTranslatorConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_verse_translation_by_translator",
-        argNames: ["verseKey", "translatorId"],
-      );
-
-  @override
-  Future<WordDto?> crateApiGetWord({required int wordId}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_32(wordId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 48,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetVerseTranslationByTranslatorConstMeta,
+            argValues: [verseKey, translatorId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetVerseTranslationByTranslatorConstMeta => const TaskConstMeta(
+            debugName: "get_verse_translation_by_translator",
+            argNames: ["verseKey", "translatorId"],
+        );
+        
+
+@override Future<WordDto?> crateApiGetWord({required int wordId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(wordId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 62, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_opt_box_autoadd_word_dto,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiGetWordConstMeta,
-        argValues: [wordId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetWordConstMeta =>
-      const TaskConstMeta(debugName: "get_word", argNames: ["wordId"]);
-
-  @override
-  Future<WordDto?> crateApiGetWordAtPosition({
-    required int chapter,
-    required int verse,
-    required int position,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_32(chapter, serializer);
-          sse_encode_i_32(verse, serializer);
-          sse_encode_i_32(position, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 49,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetWordConstMeta,
+            argValues: [wordId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetWordConstMeta => const TaskConstMeta(
+            debugName: "get_word",
+            argNames: ["wordId"],
+        );
+        
+
+@override Future<WordDto?> crateApiGetWordAtPosition({required int chapter , required int verse , required int position })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(chapter, serializer);
+sse_encode_i_32(verse, serializer);
+sse_encode_i_32(position, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 63, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_opt_box_autoadd_word_dto,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiGetWordAtPositionConstMeta,
-        argValues: [chapter, verse, position],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetWordAtPositionConstMeta => const TaskConstMeta(
-    debugName: "get_word_at_position",
-    argNames: ["chapter", "verse", "position"],
-  );
-
-  @override
-  Future<String?> crateApiGetWordTranslation({
-    required int wordId,
-    required int translatorId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_32(wordId, serializer);
-          sse_encode_i_32(translatorId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 50,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetWordAtPositionConstMeta,
+            argValues: [chapter, verse, position],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetWordAtPositionConstMeta => const TaskConstMeta(
+            debugName: "get_word_at_position",
+            argNames: ["chapter", "verse", "position"],
+        );
+        
+
+@override Future<WordAudioDto?> crateApiGetWordAudio({required String verseKey , required int position , required int reciterId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(verseKey, serializer);
+sse_encode_i_32(position, serializer);
+sse_encode_i_32(reciterId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 64, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_word_audio_dto,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiGetWordAudioConstMeta,
+            argValues: [verseKey, position, reciterId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetWordAudioConstMeta => const TaskConstMeta(
+            debugName: "get_word_audio",
+            argNames: ["verseKey", "position", "reciterId"],
+        );
+        
+
+@override Future<String?> crateApiGetWordTranslation({required int wordId , required int translatorId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(wordId, serializer);
+sse_encode_i_32(translatorId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 65, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_opt_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiGetWordTranslationConstMeta,
-        argValues: [wordId, translatorId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetWordTranslationConstMeta => const TaskConstMeta(
-    debugName: "get_word_translation",
-    argNames: ["wordId", "translatorId"],
-  );
-
-  @override
-  Future<List<WordDto>> crateApiGetWordsForVerse({required String verseKey}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(verseKey, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 51,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetWordTranslationConstMeta,
+            argValues: [wordId, translatorId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetWordTranslationConstMeta => const TaskConstMeta(
+            debugName: "get_word_translation",
+            argNames: ["wordId", "translatorId"],
+        );
+        
+
+@override Future<List<WordDto>> crateApiGetWordsForVerse({required String verseKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(verseKey, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 66, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_word_dto,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiGetWordsForVerseConstMeta,
-        argValues: [verseKey],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetWordsForVerseConstMeta => const TaskConstMeta(
-    debugName: "get_words_for_verse",
-    argNames: ["verseKey"],
-  );
-
-  @override
-  Future<void> crateApiInitApp() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 52,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetWordsForVerseConstMeta,
+            argValues: [verseKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetWordsForVerseConstMeta => const TaskConstMeta(
+            debugName: "get_words_for_verse",
+            argNames: ["verseKey"],
+        );
+        
+
+@override Future<RecitationResultDto> crateApiGradeRecitation({required String userId , required List<String> verseKeys , required List<TranscriptWordDto> transcript })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(userId, serializer);
+sse_encode_list_String(verseKeys, serializer);
+sse_encode_list_transcript_word_dto(transcript, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 67, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_recitation_result_dto,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiGradeRecitationConstMeta,
+            argValues: [userId, verseKeys, transcript],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGradeRecitationConstMeta => const TaskConstMeta(
+            debugName: "grade_recitation",
+            argNames: ["userId", "verseKeys", "transcript"],
+        );
+        
+
+@override Future<void> crateApiInitApp()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 68, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiInitAppConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiInitAppConstMeta =>
-      const TaskConstMeta(debugName: "init_app", argNames: []);
-
-  @override
-  Future<String> crateApiInstallTranslationPackFromBytes({
-    required String packageId,
-    required List<int> bytes,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(packageId, serializer);
-          sse_encode_list_prim_u_8_loose(bytes, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 53,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiInitAppConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInitAppConstMeta => const TaskConstMeta(
+            debugName: "init_app",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiInitSemanticModelFromBytes({required List<int> tokenizerJson , required List<int> modelSafetensors , required String stagingDir })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(tokenizerJson, serializer);
+sse_encode_list_prim_u_8_loose(modelSafetensors, serializer);
+sse_encode_String(stagingDir, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiInstallTranslationPackFromBytesConstMeta,
-        argValues: [packageId, bytes],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiInstallTranslationPackFromBytesConstMeta =>
-      const TaskConstMeta(
-        debugName: "install_translation_pack_from_bytes",
-        argNames: ["packageId", "bytes"],
-      );
-
-  @override
-  Future<List<String>> crateApiParseNodeRange({required String range}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(range, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 54,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_list_String,
+        )
+        ,
+            constMeta: kCrateApiInitSemanticModelFromBytesConstMeta,
+            argValues: [tokenizerJson, modelSafetensors, stagingDir],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInitSemanticModelFromBytesConstMeta => const TaskConstMeta(
+            debugName: "init_semantic_model_from_bytes",
+            argNames: ["tokenizerJson", "modelSafetensors", "stagingDir"],
+        );
+        
+
+@override Future<void> crateApiInitSemanticModelFromDir({required String modelDir })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(modelDir, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 70, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiParseNodeRangeConstMeta,
-        argValues: [range],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiParseNodeRangeConstMeta =>
-      const TaskConstMeta(debugName: "parse_node_range", argNames: ["range"]);
-
-  @override
-  Future<String> crateApiProcessReview({
-    required String userId,
-    required String nodeId,
-    required int grade,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(userId, serializer);
-          sse_encode_String(nodeId, serializer);
-          sse_encode_u_8(grade, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 55,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiInitSemanticModelFromDirConstMeta,
+            argValues: [modelDir],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInitSemanticModelFromDirConstMeta => const TaskConstMeta(
+            debugName: "init_semantic_model_from_dir",
+            argNames: ["modelDir"],
+        );
+        
+
+@override Future<String> crateApiInstallPackageFromFile({required String packageId , required String path , String? audioDir })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(packageId, serializer);
+sse_encode_String(path, serializer);
+sse_encode_opt_String(audioDir, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 71, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiProcessReviewConstMeta,
-        argValues: [userId, nodeId, grade],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiProcessReviewConstMeta => const TaskConstMeta(
-    debugName: "process_review",
-    argNames: ["userId", "nodeId", "grade"],
-  );
-
-  @override
-  Future<List<NodeSearchDto>> crateApiQueryNodesFiltered({
-    required String userId,
-    required NodeFilterDto filter,
-    required int limit,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(userId, serializer);
-          sse_encode_box_autoadd_node_filter_dto(filter, serializer);
-          sse_encode_u_32(limit, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 56,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_list_node_search_dto,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiQueryNodesFilteredConstMeta,
-        argValues: [userId, filter, limit],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiQueryNodesFilteredConstMeta => const TaskConstMeta(
-    debugName: "query_nodes_filtered",
-    argNames: ["userId", "filter", "limit"],
-  );
-
-  @override
-  Future<String> crateApiReseedDatabase({required String userId}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(userId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 57,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiInstallPackageFromFileConstMeta,
+            argValues: [packageId, path, audioDir],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInstallPackageFromFileConstMeta => const TaskConstMeta(
+            debugName: "install_package_from_file",
+            argNames: ["packageId", "path", "audioDir"],
+        );
+        
+
+@override Future<String> crateApiInstallTranslationPackFromBytes({required String packageId , required List<int> bytes })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(packageId, serializer);
+sse_encode_list_prim_u_8_loose(bytes, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 72, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiReseedDatabaseConstMeta,
-        argValues: [userId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiReseedDatabaseConstMeta =>
-      const TaskConstMeta(debugName: "reseed_database", argNames: ["userId"]);
-
-  @override
-  Future<List<NodeSearchDto>> crateApiSearchNodes({
-    required String query,
-    required int limit,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(query, serializer);
-          sse_encode_u_32(limit, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 58,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_list_node_search_dto,
+        )
+        ,
+            constMeta: kCrateApiInstallTranslationPackFromBytesConstMeta,
+            argValues: [packageId, bytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInstallTranslationPackFromBytesConstMeta => const TaskConstMeta(
+            debugName: "install_translation_pack_from_bytes",
+            argNames: ["packageId", "bytes"],
+        );
+        
+
+@override Future<bool> crateApiIsSemanticModelLoaded()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 73, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiIsSemanticModelLoadedConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIsSemanticModelLoadedConstMeta => const TaskConstMeta(
+            debugName: "is_semantic_model_loaded",
+            argNames: [],
+        );
+        
+
+@override Future<List<String>> crateApiParseNodeRange({required String range })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(range, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 74, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiSearchNodesConstMeta,
-        argValues: [query, limit],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSearchNodesConstMeta => const TaskConstMeta(
-    debugName: "search_nodes",
-    argNames: ["query", "limit"],
-  );
-
-  @override
-  Future<String> crateApiSetLastSyncTimestamp({
-    required String userId,
-    required PlatformInt64 timestamp,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(userId, serializer);
-          sse_encode_i_64(timestamp, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 59,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiParseNodeRangeConstMeta,
+            argValues: [range],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiParseNodeRangeConstMeta => const TaskConstMeta(
+            debugName: "parse_node_range",
+            argNames: ["range"],
+        );
+        
+
+@override Future<String> crateApiProcessReview({required String userId , required String nodeId , required int grade })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(userId, serializer);
+sse_encode_String(nodeId, serializer);
+sse_encode_u_8(grade, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 75, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiSetLastSyncTimestampConstMeta,
-        argValues: [userId, timestamp],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSetLastSyncTimestampConstMeta =>
-      const TaskConstMeta(
-        debugName: "set_last_sync_timestamp",
-        argNames: ["userId", "timestamp"],
-      );
-
-  @override
-  Future<String> crateApiSetPreferredTranslatorId({required int translatorId}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_32(translatorId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 60,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiProcessReviewConstMeta,
+            argValues: [userId, nodeId, grade],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProcessReviewConstMeta => const TaskConstMeta(
+            debugName: "process_review",
+            argNames: ["userId", "nodeId", "grade"],
+        );
+        
+
+@override Future<List<NodeSearchDto>> crateApiQueryNodesFiltered({required String userId , required NodeFilterDto filter , required int limit })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(userId, serializer);
+sse_encode_box_autoadd_node_filter_dto(filter, serializer);
+sse_encode_u_32(limit, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 76, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_node_search_dto,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiSetPreferredTranslatorIdConstMeta,
-        argValues: [translatorId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSetPreferredTranslatorIdConstMeta =>
-      const TaskConstMeta(
-        debugName: "set_preferred_translator_id",
-        argNames: ["translatorId"],
-      );
-
-  @override
-  Future<String> crateApiSetupDatabase({
-    required String contentDbPath,
-    required String userDbPath,
-    required List<int> kgBytes,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(contentDbPath, serializer);
-          sse_encode_String(userDbPath, serializer);
-          sse_encode_list_prim_u_8_loose(kgBytes, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 61,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiQueryNodesFilteredConstMeta,
+            argValues: [userId, filter, limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiQueryNodesFilteredConstMeta => const TaskConstMeta(
+            debugName: "query_nodes_filtered",
+            argNames: ["userId", "filter", "limit"],
+        );
+        
+
+@override Future<ExerciseDataDto> crateApiRegenerateExercise({required String nodeId , required String exerciseType , required BigInt seed , DistractorStrategyDto? distractorStrategy })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(nodeId, serializer);
+sse_encode_String(exerciseType, serializer);
+sse_encode_u_64(seed, serializer);
+sse_encode_opt_box_autoadd_distractor_strategy_dto(distractorStrategy, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 77, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_exercise_data_dto,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiSetupDatabaseConstMeta,
-        argValues: [contentDbPath, userDbPath, kgBytes],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSetupDatabaseConstMeta => const TaskConstMeta(
-    debugName: "setup_database",
-    argNames: ["contentDbPath", "userDbPath", "kgBytes"],
-  );
-
-  @override
-  Future<String> crateApiSetupDatabaseInMemory({required List<int> kgBytes}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(kgBytes, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 62,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiRegenerateExerciseConstMeta,
+            argValues: [nodeId, exerciseType, seed, distractorStrategy],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRegenerateExerciseConstMeta => const TaskConstMeta(
+            debugName: "regenerate_exercise",
+            argNames: ["nodeId", "exerciseType", "seed", "distractorStrategy"],
+        );
+        
+
+@override Future<String> crateApiReseedDatabase({required String userId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(userId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 78, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiSetupDatabaseInMemoryConstMeta,
-        argValues: [kgBytes],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSetupDatabaseInMemoryConstMeta =>
-      const TaskConstMeta(
-        debugName: "setup_database_in_memory",
-        argNames: ["kgBytes"],
-      );
-
-  @override
-  Future<PropagationResultDto> crateApiSimulatePropagation({
-    required String userId,
-    required String nodeId,
-    required double energyDelta,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(userId, serializer);
-          sse_encode_String(nodeId, serializer);
-          sse_encode_f_64(energyDelta, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 63,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_propagation_result_dto,
+        )
+        ,
+            constMeta: kCrateApiReseedDatabaseConstMeta,
+            argValues: [userId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiReseedDatabaseConstMeta => const TaskConstMeta(
+            debugName: "reseed_database",
+            argNames: ["userId"],
+        );
+        
+
+@override Future<int> crateApiResetNodes({required String userId , required String scope })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(userId, serializer);
+sse_encode_String(scope, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 79, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiSimulatePropagationConstMeta,
-        argValues: [userId, nodeId, energyDelta],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSimulatePropagationConstMeta =>
-      const TaskConstMeta(
-        debugName: "simulate_propagation",
-        argNames: ["userId", "nodeId", "energyDelta"],
-      );
-
-  @override
-  Future<EchoRecallStateDto> crateApiStartEchoRecall({
-    required String userId,
-    required List<String> ayahNodeIds,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(userId, serializer);
-          sse_encode_list_String(ayahNodeIds, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 64,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_echo_recall_state_dto,
+        )
+        ,
+            constMeta: kCrateApiResetNodesConstMeta,
+            argValues: [userId, scope],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiResetNodesConstMeta => const TaskConstMeta(
+            debugName: "reset_nodes",
+            argNames: ["userId", "scope"],
+        );
+        
+
+@override Future<AyahChainStateDto?> crateApiResumeSessionAyahChain({required String sessionId , required int position })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(sessionId, serializer);
+sse_encode_i_32(position, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 80, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_ayah_chain_state_dto,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiStartEchoRecallConstMeta,
-        argValues: [userId, ayahNodeIds],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiStartEchoRecallConstMeta => const TaskConstMeta(
-    debugName: "start_echo_recall",
-    argNames: ["userId", "ayahNodeIds"],
-  );
-
-  @override
-  Future<SessionDto> crateApiStartSession({
-    required String userId,
-    required String goalId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(userId, serializer);
-          sse_encode_String(goalId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 65,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_session_dto,
+        )
+        ,
+            constMeta: kCrateApiResumeSessionAyahChainConstMeta,
+            argValues: [sessionId, position],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiResumeSessionAyahChainConstMeta => const TaskConstMeta(
+            debugName: "resume_session_ayah_chain",
+            argNames: ["sessionId", "position"],
+        );
+        
+
+@override Future<EchoRecallStateDto?> crateApiResumeSessionEchoRecall({required String sessionId , required int position })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(sessionId, serializer);
+sse_encode_i_32(position, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 81, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_echo_recall_state_dto,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiStartSessionConstMeta,
-        argValues: [userId, goalId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiStartSessionConstMeta => const TaskConstMeta(
-    debugName: "start_session",
-    argNames: ["userId", "goalId"],
-  );
-
-  @override
-  Future<EchoRecallStateDto> crateApiSubmitEchoRecall({
-    required String userId,
-    required List<String> ayahNodeIds,
-    required EchoRecallStateDto state,
-    required String wordNodeId,
-    required int recallTimeMs,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(userId, serializer);
-          sse_encode_list_String(ayahNodeIds, serializer);
-          sse_encode_box_autoadd_echo_recall_state_dto(state, serializer);
-          sse_encode_String(wordNodeId, serializer);
-          sse_encode_u_32(recallTimeMs, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 66,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_echo_recall_state_dto,
+        )
+        ,
+            constMeta: kCrateApiResumeSessionEchoRecallConstMeta,
+            argValues: [sessionId, position],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiResumeSessionEchoRecallConstMeta => const TaskConstMeta(
+            debugName: "resume_session_echo_recall",
+            argNames: ["sessionId", "position"],
+        );
+        
+
+@override Future<List<NodeSearchDto>> crateApiSearchNodes({required String query , required int limit })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(query, serializer);
+sse_encode_u_32(limit, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 82, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_node_search_dto,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiSubmitEchoRecallConstMeta,
-        argValues: [userId, ayahNodeIds, state, wordNodeId, recallTimeMs],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSubmitEchoRecallConstMeta => const TaskConstMeta(
-    debugName: "submit_echo_recall",
-    argNames: ["userId", "ayahNodeIds", "state", "wordNodeId", "recallTimeMs"],
-  );
-
-  @override
-  Future<String> crateApiSubmitSessionItem({
-    required String sessionId,
-    required String nodeId,
-    required String exerciseType,
-    required int grade,
-    required BigInt durationMs,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(sessionId, serializer);
-          sse_encode_String(nodeId, serializer);
-          sse_encode_String(exerciseType, serializer);
-          sse_encode_u_8(grade, serializer);
-          sse_encode_u_64(durationMs, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 67,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSearchNodesConstMeta,
+            argValues: [query, limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchNodesConstMeta => const TaskConstMeta(
+            debugName: "search_nodes",
+            argNames: ["query", "limit"],
+        );
+        
+
+@override Future<String> crateApiSetArabicLevel({required String level })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(level, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 83, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiSubmitSessionItemConstMeta,
-        argValues: [sessionId, nodeId, exerciseType, grade, durationMs],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSubmitSessionItemConstMeta => const TaskConstMeta(
-    debugName: "submit_session_item",
-    argNames: ["sessionId", "nodeId", "exerciseType", "grade", "durationMs"],
-  );
-
-  @override
-  Future<String> crateApiUpsertMemoryStatesFromRemote({
-    required String userId,
-    required List<SyncMemoryStateDto> states,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(userId, serializer);
-          sse_encode_list_sync_memory_state_dto(states, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 68,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSetArabicLevelConstMeta,
+            argValues: [level],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSetArabicLevelConstMeta => const TaskConstMeta(
+            debugName: "set_arabic_level",
+            argNames: ["level"],
+        );
+        
+
+@override Future<String> crateApiSetLastSyncTimestamp({required String userId , required PlatformInt64 timestamp })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(userId, serializer);
+sse_encode_i_64(timestamp, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 84, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiUpsertMemoryStatesFromRemoteConstMeta,
-        argValues: [userId, states],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiUpsertMemoryStatesFromRemoteConstMeta =>
-      const TaskConstMeta(
-        debugName: "upsert_memory_states_from_remote",
-        argNames: ["userId", "states"],
-      );
-
-  @override
-  Future<String> crateApiUpsertSessionItemsFromRemote({
-    required String userId,
-    required List<SyncSessionItemDto> items,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(userId, serializer);
-          sse_encode_list_sync_session_item_dto(items, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 69,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSetLastSyncTimestampConstMeta,
+            argValues: [userId, timestamp],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSetLastSyncTimestampConstMeta => const TaskConstMeta(
+            debugName: "set_last_sync_timestamp",
+            argNames: ["userId", "timestamp"],
+        );
+        
+
+@override Future<String> crateApiSetPreferredScript({required String script })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(script, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 85, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiUpsertSessionItemsFromRemoteConstMeta,
-        argValues: [userId, items],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiUpsertSessionItemsFromRemoteConstMeta =>
-      const TaskConstMeta(
-        debugName: "upsert_session_items_from_remote",
-        argNames: ["userId", "items"],
-      );
-
-  @override
-  Future<String> crateApiUpsertSessionsFromRemote({
-    required String userId,
-    required List<SyncSessionDto> sessions,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(userId, serializer);
-          sse_encode_list_sync_session_dto(sessions, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 70,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSetPreferredScriptConstMeta,
+            argValues: [script],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSetPreferredScriptConstMeta => const TaskConstMeta(
+            debugName: "set_preferred_script",
+            argNames: ["script"],
+        );
+        
+
+@override Future<String> crateApiSetPreferredTranslatorId({required int translatorId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(translatorId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 86, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiUpsertSessionsFromRemoteConstMeta,
-        argValues: [userId, sessions],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiUpsertSessionsFromRemoteConstMeta =>
-      const TaskConstMeta(
-        debugName: "upsert_sessions_from_remote",
-        argNames: ["userId", "sessions"],
-      );
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_AppState => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_AppState => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_ArcExerciseService => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseService;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_ArcExerciseService => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseService;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_ArcLearningService => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningService;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_ArcLearningService => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningService;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_ArcSessionService => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionService;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_ArcSessionService => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionService;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_ArcContentRepository => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepository;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_ArcContentRepository => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepository;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_ArcUserRepository => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepository;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_ArcUserRepository => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepository;
-
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
-  }
-
-  @protected
-  AppState
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AppStateImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  ArcExerciseService
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseService(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ArcExerciseServiceImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  ArcLearningService
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningService(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ArcLearningServiceImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  ArcSessionService
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionService(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ArcSessionServiceImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  ArcContentRepository
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepository(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ArcContentRepositoryImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  ArcUserRepository
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepository(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ArcUserRepositoryImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  AppState
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AppStateImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  AppState
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AppStateImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Map<String, String> dco_decode_Map_String_String_None(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Map.fromEntries(
-      dco_decode_list_record_string_string(
-        raw,
-      ).map((e) => MapEntry(e.$1, e.$2)),
-    );
-  }
-
-  @protected
-  AppState
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AppStateImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  ArcExerciseService
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseService(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ArcExerciseServiceImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  ArcLearningService
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningService(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ArcLearningServiceImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  ArcSessionService
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionService(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ArcSessionServiceImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  ArcContentRepository
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepository(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ArcContentRepositoryImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  ArcUserRepository
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepository(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ArcUserRepositoryImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
-
-  @protected
-  ActivityPointDto dco_decode_activity_point_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return ActivityPointDto(
-      date: dco_decode_String(arr[0]),
-      count: dco_decode_i_32(arr[1]),
-    );
-  }
-
-  @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
-  }
-
-  @protected
-  EchoRecallMetricsDto dco_decode_box_autoadd_echo_recall_metrics_dto(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_echo_recall_metrics_dto(raw);
-  }
-
-  @protected
-  EchoRecallStateDto dco_decode_box_autoadd_echo_recall_state_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_echo_recall_state_dto(raw);
-  }
-
-  @protected
-  double dco_decode_box_autoadd_f_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
-  }
-
-  @protected
-  HintDto dco_decode_box_autoadd_hint_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_hint_dto(raw);
-  }
-
-  @protected
-  int dco_decode_box_autoadd_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_i_64(raw);
-  }
-
-  @protected
-  NodeData dco_decode_box_autoadd_node_data(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_node_data(raw);
-  }
-
-  @protected
-  NodeFilterDto dco_decode_box_autoadd_node_filter_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_node_filter_dto(raw);
-  }
-
-  @protected
-  SessionDto dco_decode_box_autoadd_session_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_session_dto(raw);
-  }
-
-  @protected
-  SessionItemDto dco_decode_box_autoadd_session_item_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_session_item_dto(raw);
-  }
-
-  @protected
-  TranslatorDto dco_decode_box_autoadd_translator_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_translator_dto(raw);
-  }
-
-  @protected
-  VerseDto dco_decode_box_autoadd_verse_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_verse_dto(raw);
-  }
-
-  @protected
-  WordDto dco_decode_box_autoadd_word_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_word_dto(raw);
-  }
-
-  @protected
-  ComprehensionDto dco_decode_comprehension_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return ComprehensionDto(
-      memorization: dco_decode_f_64(arr[0]),
-      understanding: dco_decode_f_64(arr[1]),
-      context: dco_decode_f_64(arr[2]),
-    );
-  }
-
-  @protected
-  ContentPackageDto dco_decode_content_package_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 11)
-      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
-    return ContentPackageDto(
-      packageId: dco_decode_String(arr[0]),
-      packageType: dco_decode_String(arr[1]),
-      name: dco_decode_String(arr[2]),
-      languageCode: dco_decode_opt_String(arr[3]),
-      author: dco_decode_opt_String(arr[4]),
-      version: dco_decode_String(arr[5]),
-      description: dco_decode_opt_String(arr[6]),
-      fileSize: dco_decode_opt_box_autoadd_i_64(arr[7]),
-      downloadUrl: dco_decode_opt_String(arr[8]),
-      checksum: dco_decode_opt_String(arr[9]),
-      license: dco_decode_opt_String(arr[10]),
-    );
-  }
-
-  @protected
-  DashboardStatsDto dco_decode_dashboard_stats_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return DashboardStatsDto(
-      reviewsToday: dco_decode_u_32(arr[0]),
-      streakDays: dco_decode_u_32(arr[1]),
-      dueCount: dco_decode_u_32(arr[2]),
-    );
-  }
-
-  @protected
-  DbHealthDto dco_decode_db_health_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 8)
-      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
-    return DbHealthDto(
-      chaptersCount: dco_decode_i_64(arr[0]),
-      versesCount: dco_decode_i_64(arr[1]),
-      wordsCount: dco_decode_i_64(arr[2]),
-      nodesCount: dco_decode_i_64(arr[3]),
-      edgesCount: dco_decode_i_64(arr[4]),
-      userMemoryCount: dco_decode_i_64(arr[5]),
-      isHealthy: dco_decode_bool(arr[6]),
-      issues: dco_decode_list_String(arr[7]),
-    );
-  }
-
-  @protected
-  DbQueryResultDto dco_decode_db_query_result_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return DbQueryResultDto(
-      columns: dco_decode_list_String(arr[0]),
-      rows: dco_decode_list_list_String(arr[1]),
-    );
-  }
-
-  @protected
-  DebugStatsDto dco_decode_debug_stats_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return DebugStatsDto(
-      totalNodesCount: dco_decode_u_32(arr[0]),
-      totalEdgesCount: dco_decode_u_32(arr[1]),
-      dueCount: dco_decode_u_32(arr[2]),
-    );
-  }
-
-  @protected
-  DetailedStatsDto dco_decode_detailed_stats_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return DetailedStatsDto(
-      activityHistory: dco_decode_list_activity_point_dto(arr[0]),
-      comprehension: dco_decode_comprehension_dto(arr[1]),
-    );
-  }
-
-  @protected
-  EchoRecallMetricsDto dco_decode_echo_recall_metrics_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return EchoRecallMetricsDto(
-      wordTimings: dco_decode_list_word_timing_dto(arr[0]),
-      totalDurationMs: dco_decode_u_64(arr[1]),
-      struggles: dco_decode_u_32(arr[2]),
-    );
-  }
-
-  @protected
-  EchoRecallResultDto dco_decode_echo_recall_result_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return EchoRecallResultDto(
-      energyUpdates: dco_decode_list_energy_update_dto(arr[0]),
-      wordsProcessed: dco_decode_u_32(arr[1]),
-      averageEnergy: dco_decode_f_64(arr[2]),
-    );
-  }
-
-  @protected
-  EchoRecallStateDto dco_decode_echo_recall_state_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 1)
-      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return EchoRecallStateDto(
-      words: dco_decode_list_echo_recall_word_dto(arr[0]),
-    );
-  }
-
-  @protected
-  EchoRecallStatsDto dco_decode_echo_recall_stats_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 6)
-      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
-    return EchoRecallStatsDto(
-      totalWords: dco_decode_u_32(arr[0]),
-      visibleCount: dco_decode_u_32(arr[1]),
-      obscuredCount: dco_decode_u_32(arr[2]),
-      hiddenCount: dco_decode_u_32(arr[3]),
-      averageEnergy: dco_decode_f_64(arr[4]),
-      masteryPercentage: dco_decode_f_64(arr[5]),
-    );
-  }
-
-  @protected
-  EchoRecallWordDto dco_decode_echo_recall_word_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return EchoRecallWordDto(
-      nodeId: dco_decode_String(arr[0]),
-      text: dco_decode_String(arr[1]),
-      visibility: dco_decode_word_visibility_dto(arr[2]),
-      energy: dco_decode_f_64(arr[3]),
-    );
-  }
-
-  @protected
-  EnergySnapshotDto dco_decode_energy_snapshot_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return EnergySnapshotDto(
-      nodeId: dco_decode_String(arr[0]),
-      energy: dco_decode_f_64(arr[1]),
-      nodeType: dco_decode_opt_String(arr[2]),
-      knowledgeAxis: dco_decode_opt_String(arr[3]),
-      neighbors: dco_decode_list_node_energy_dto(arr[4]),
-    );
-  }
-
-  @protected
-  EnergyUpdateDto dco_decode_energy_update_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return EnergyUpdateDto(
-      nodeId: dco_decode_String(arr[0]),
-      energy: dco_decode_f_64(arr[1]),
-    );
-  }
-
-  @protected
-  ExerciseDataDto dco_decode_exercise_data_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    switch (raw[0]) {
-      case 0:
-        return ExerciseDataDto_Memorization(nodeId: dco_decode_String(raw[1]));
-      case 1:
-        return ExerciseDataDto_McqArToEn(
-          nodeId: dco_decode_String(raw[1]),
-          distractorNodeIds: dco_decode_list_String(raw[2]),
-        );
-      case 2:
-        return ExerciseDataDto_McqEnToAr(
-          nodeId: dco_decode_String(raw[1]),
-          distractorNodeIds: dco_decode_list_String(raw[2]),
-        );
-      case 3:
-        return ExerciseDataDto_Translation(nodeId: dco_decode_String(raw[1]));
-      case 4:
-        return ExerciseDataDto_ContextualTranslation(
-          nodeId: dco_decode_String(raw[1]),
-          verseKey: dco_decode_String(raw[2]),
-        );
-      case 5:
-        return ExerciseDataDto_ClozeDeletion(
-          nodeId: dco_decode_String(raw[1]),
-          blankPosition: dco_decode_i_32(raw[2]),
-        );
-      case 6:
-        return ExerciseDataDto_FirstLetterHint(
-          nodeId: dco_decode_String(raw[1]),
-          wordPosition: dco_decode_i_32(raw[2]),
-        );
-      case 7:
-        return ExerciseDataDto_MissingWordMcq(
-          nodeId: dco_decode_String(raw[1]),
-          blankPosition: dco_decode_i_32(raw[2]),
-          distractorNodeIds: dco_decode_list_String(raw[3]),
-        );
-      case 8:
-        return ExerciseDataDto_NextWordMcq(
-          nodeId: dco_decode_String(raw[1]),
-          contextPosition: dco_decode_i_32(raw[2]),
-          distractorNodeIds: dco_decode_list_String(raw[3]),
-        );
-      case 9:
-        return ExerciseDataDto_FullVerseInput(
-          nodeId: dco_decode_String(raw[1]),
-        );
-      case 10:
-        return ExerciseDataDto_AyahChain(
-          nodeId: dco_decode_String(raw[1]),
-          verseKeys: dco_decode_list_String(raw[2]),
-          currentIndex: dco_decode_usize(raw[3]),
-          completedCount: dco_decode_usize(raw[4]),
-        );
-      case 11:
-        return ExerciseDataDto_FindMistake(
-          nodeId: dco_decode_String(raw[1]),
-          mistakePosition: dco_decode_i_32(raw[2]),
-          correctWordNodeId: dco_decode_String(raw[3]),
-          incorrectWordNodeId: dco_decode_String(raw[4]),
-        );
-      case 12:
-        return ExerciseDataDto_AyahSequence(
-          nodeId: dco_decode_String(raw[1]),
-          correctSequence: dco_decode_list_String(raw[2]),
-        );
-      case 13:
-        return ExerciseDataDto_SequenceRecall(
-          nodeId: dco_decode_String(raw[1]),
-          correctSequence: dco_decode_list_String(raw[2]),
-          options: dco_decode_list_list_String(raw[3]),
-        );
-      case 14:
-        return ExerciseDataDto_FirstWordRecall(
-          nodeId: dco_decode_String(raw[1]),
-          verseKey: dco_decode_String(raw[2]),
-        );
-      case 15:
-        return ExerciseDataDto_IdentifyRoot(
-          nodeId: dco_decode_String(raw[1]),
-          root: dco_decode_String(raw[2]),
-        );
-      case 16:
-        return ExerciseDataDto_ReverseCloze(
-          nodeId: dco_decode_String(raw[1]),
-          blankPosition: dco_decode_i_32(raw[2]),
-        );
-      case 17:
-        return ExerciseDataDto_TranslatePhrase(
-          nodeId: dco_decode_String(raw[1]),
-          translatorId: dco_decode_i_32(raw[2]),
-        );
-      case 18:
-        return ExerciseDataDto_PosTagging(
-          nodeId: dco_decode_String(raw[1]),
-          correctPos: dco_decode_String(raw[2]),
-          options: dco_decode_list_String(raw[3]),
-        );
-      case 19:
-        return ExerciseDataDto_CrossVerseConnection(
-          nodeId: dco_decode_String(raw[1]),
-          relatedVerseIds: dco_decode_list_String(raw[2]),
-          connectionTheme: dco_decode_String(raw[3]),
-        );
-      case 20:
-        return ExerciseDataDto_EchoRecall(
-          userId: dco_decode_String(raw[1]),
-          ayahNodeIds: dco_decode_list_String(raw[2]),
-        );
-      default:
-        throw Exception("unreachable");
-    }
-  }
-
-  @protected
-  double dco_decode_f_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
-  }
-
-  @protected
-  HintDto dco_decode_hint_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return HintDto(
-      hintType: dco_decode_String(arr[0]),
-      firstChar: dco_decode_opt_String(arr[1]),
-      lastChar: dco_decode_opt_String(arr[2]),
-    );
-  }
-
-  @protected
-  int dco_decode_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeI64(raw);
-  }
-
-  @protected
-  InstalledPackageDto dco_decode_installed_package_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return InstalledPackageDto(
-      packageId: dco_decode_String(arr[0]),
-      installedAt: dco_decode_i_64(arr[1]),
-      enabled: dco_decode_bool(arr[2]),
-    );
-  }
-
-  @protected
-  LanguageDto dco_decode_language_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return LanguageDto(
-      code: dco_decode_String(arr[0]),
-      englishName: dco_decode_String(arr[1]),
-      nativeName: dco_decode_String(arr[2]),
-      direction: dco_decode_String(arr[3]),
-    );
-  }
-
-  @protected
-  List<String> dco_decode_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_String).toList();
-  }
-
-  @protected
-  List<ActivityPointDto> dco_decode_list_activity_point_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_activity_point_dto).toList();
-  }
-
-  @protected
-  List<ContentPackageDto> dco_decode_list_content_package_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_content_package_dto).toList();
-  }
-
-  @protected
-  List<EchoRecallWordDto> dco_decode_list_echo_recall_word_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_echo_recall_word_dto).toList();
-  }
-
-  @protected
-  List<EnergyUpdateDto> dco_decode_list_energy_update_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_energy_update_dto).toList();
-  }
-
-  @protected
-  List<ExerciseDataDto> dco_decode_list_exercise_data_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_exercise_data_dto).toList();
-  }
-
-  @protected
-  List<InstalledPackageDto> dco_decode_list_installed_package_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(dco_decode_installed_package_dto)
-        .toList();
-  }
-
-  @protected
-  List<LanguageDto> dco_decode_list_language_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_language_dto).toList();
-  }
-
-  @protected
-  List<List<String>> dco_decode_list_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_list_String).toList();
-  }
-
-  @protected
-  List<NodeEnergyDto> dco_decode_list_node_energy_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_node_energy_dto).toList();
-  }
-
-  @protected
-  List<NodeSearchDto> dco_decode_list_node_search_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_node_search_dto).toList();
-  }
-
-  @protected
-  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as List<int>;
-  }
-
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
-  }
-
-  @protected
-  List<(String, String)> dco_decode_list_record_string_string(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_record_string_string).toList();
-  }
-
-  @protected
-  List<SessionPreviewDto> dco_decode_list_session_preview_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_session_preview_dto).toList();
-  }
-
-  @protected
-  List<SurahInfo> dco_decode_list_surah_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_surah_info).toList();
-  }
-
-  @protected
-  List<SyncMemoryStateDto> dco_decode_list_sync_memory_state_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(dco_decode_sync_memory_state_dto)
-        .toList();
-  }
-
-  @protected
-  List<SyncSessionDto> dco_decode_list_sync_session_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_sync_session_dto).toList();
-  }
-
-  @protected
-  List<SyncSessionItemDto> dco_decode_list_sync_session_item_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(dco_decode_sync_session_item_dto)
-        .toList();
-  }
-
-  @protected
-  List<TranslatorDto> dco_decode_list_translator_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_translator_dto).toList();
-  }
-
-  @protected
-  List<VerseWithTranslationDto> dco_decode_list_verse_with_translation_dto(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(dco_decode_verse_with_translation_dto)
-        .toList();
-  }
-
-  @protected
-  List<WordDto> dco_decode_list_word_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_word_dto).toList();
-  }
-
-  @protected
-  List<WordTimingDto> dco_decode_list_word_timing_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_word_timing_dto).toList();
-  }
-
-  @protected
-  NodeData dco_decode_node_data(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return NodeData(
-      id: dco_decode_String(arr[0]),
-      nodeType: dco_decode_String(arr[1]),
-      metadata: dco_decode_Map_String_String_None(arr[2]),
-    );
-  }
-
-  @protected
-  NodeEnergyDto dco_decode_node_energy_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return NodeEnergyDto(
-      nodeId: dco_decode_String(arr[0]),
-      energy: dco_decode_f_64(arr[1]),
-      edgeWeight: dco_decode_f_64(arr[2]),
-    );
-  }
-
-  @protected
-  NodeFilterDto dco_decode_node_filter_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return NodeFilterDto(
-      nodeType: dco_decode_opt_String(arr[0]),
-      minEnergy: dco_decode_opt_box_autoadd_f_64(arr[1]),
-      maxEnergy: dco_decode_opt_box_autoadd_f_64(arr[2]),
-      range: dco_decode_opt_String(arr[3]),
-    );
-  }
-
-  @protected
-  NodeSearchDto dco_decode_node_search_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return NodeSearchDto(
-      nodeId: dco_decode_String(arr[0]),
-      nodeType: dco_decode_String(arr[1]),
-      knowledgeAxis: dco_decode_opt_String(arr[2]),
-      preview: dco_decode_String(arr[3]),
-    );
-  }
-
-  @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
-  }
-
-  @protected
-  double? dco_decode_opt_box_autoadd_f_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_f_64(raw);
-  }
-
-  @protected
-  HintDto? dco_decode_opt_box_autoadd_hint_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_hint_dto(raw);
-  }
-
-  @protected
-  int? dco_decode_opt_box_autoadd_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_i_32(raw);
-  }
-
-  @protected
-  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_i_64(raw);
-  }
-
-  @protected
-  NodeData? dco_decode_opt_box_autoadd_node_data(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_node_data(raw);
-  }
-
-  @protected
-  SessionDto? dco_decode_opt_box_autoadd_session_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_session_dto(raw);
-  }
-
-  @protected
-  SessionItemDto? dco_decode_opt_box_autoadd_session_item_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_session_item_dto(raw);
-  }
-
-  @protected
-  TranslatorDto? dco_decode_opt_box_autoadd_translator_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_translator_dto(raw);
-  }
-
-  @protected
-  VerseDto? dco_decode_opt_box_autoadd_verse_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_verse_dto(raw);
-  }
-
-  @protected
-  WordDto? dco_decode_opt_box_autoadd_word_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_word_dto(raw);
-  }
-
-  @protected
-  PropagationDiagnosticsDto dco_decode_propagation_diagnostics_dto(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return PropagationDiagnosticsDto(
-      nodeFound: dco_decode_bool(arr[0]),
-      nodeType: dco_decode_opt_String(arr[1]),
-      totalEdges: dco_decode_u_32(arr[2]),
-      message: dco_decode_String(arr[3]),
-    );
-  }
-
-  @protected
-  PropagationResultDto dco_decode_propagation_result_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return PropagationResultDto(
-      before: dco_decode_list_node_energy_dto(arr[0]),
-      after: dco_decode_list_node_energy_dto(arr[1]),
-      diagnostics: dco_decode_propagation_diagnostics_dto(arr[2]),
-    );
-  }
-
-  @protected
-  (String, String) dco_decode_record_string_string(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (dco_decode_String(arr[0]), dco_decode_String(arr[1]));
-  }
-
-  @protected
-  SessionDto dco_decode_session_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 7)
-      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
-    return SessionDto(
-      id: dco_decode_String(arr[0]),
-      userId: dco_decode_String(arr[1]),
-      goalId: dco_decode_String(arr[2]),
-      startedAt: dco_decode_i_64(arr[3]),
-      completedAt: dco_decode_opt_box_autoadd_i_64(arr[4]),
-      itemsCount: dco_decode_i_32(arr[5]),
-      itemsCompleted: dco_decode_i_32(arr[6]),
-    );
-  }
-
-  @protected
-  SessionItemDto dco_decode_session_item_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return SessionItemDto(
-      sessionId: dco_decode_String(arr[0]),
-      position: dco_decode_i_32(arr[1]),
-      nodeId: dco_decode_String(arr[2]),
-      exerciseType: dco_decode_String(arr[3]),
-      exercise: dco_decode_exercise_data_dto(arr[4]),
-    );
-  }
-
-  @protected
-  SessionPreviewDto dco_decode_session_preview_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return SessionPreviewDto(
-      nodeId: dco_decode_String(arr[0]),
-      nodeType: dco_decode_String(arr[1]),
-      previewText: dco_decode_String(arr[2]),
-      energy: dco_decode_f_64(arr[3]),
-      priorityScore: dco_decode_f_64(arr[4]),
-    );
-  }
-
-  @protected
-  SessionSummaryDto dco_decode_session_summary_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 8)
-      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
-    return SessionSummaryDto(
-      sessionId: dco_decode_String(arr[0]),
-      itemsCount: dco_decode_i_32(arr[1]),
-      itemsCompleted: dco_decode_i_32(arr[2]),
-      durationMs: dco_decode_i_64(arr[3]),
-      againCount: dco_decode_i_32(arr[4]),
-      hardCount: dco_decode_i_32(arr[5]),
-      goodCount: dco_decode_i_32(arr[6]),
-      easyCount: dco_decode_i_32(arr[7]),
-    );
-  }
-
-  @protected
-  SurahInfo dco_decode_surah_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 7)
-      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
-    return SurahInfo(
-      number: dco_decode_i_32(arr[0]),
-      name: dco_decode_String(arr[1]),
-      nameArabic: dco_decode_String(arr[2]),
-      nameTransliteration: dco_decode_String(arr[3]),
-      nameTranslation: dco_decode_String(arr[4]),
-      verseCount: dco_decode_i_32(arr[5]),
-      revelationPlace: dco_decode_opt_String(arr[6]),
-    );
-  }
-
-  @protected
-  SyncMemoryStateDto dco_decode_sync_memory_state_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 7)
-      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
-    return SyncMemoryStateDto(
-      nodeId: dco_decode_i_64(arr[0]),
-      energy: dco_decode_f_64(arr[1]),
-      fsrsStability: dco_decode_opt_box_autoadd_f_64(arr[2]),
-      fsrsDifficulty: dco_decode_opt_box_autoadd_f_64(arr[3]),
-      lastReviewedAt: dco_decode_opt_box_autoadd_i_64(arr[4]),
-      nextReviewAt: dco_decode_opt_box_autoadd_i_64(arr[5]),
-      clientUpdatedAt: dco_decode_i_64(arr[6]),
-    );
-  }
-
-  @protected
-  SyncSessionDto dco_decode_sync_session_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 6)
-      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
-    return SyncSessionDto(
-      id: dco_decode_String(arr[0]),
-      goalId: dco_decode_opt_String(arr[1]),
-      startedAt: dco_decode_i_64(arr[2]),
-      completedAt: dco_decode_opt_box_autoadd_i_64(arr[3]),
-      itemsCompleted: dco_decode_i_32(arr[4]),
-      clientUpdatedAt: dco_decode_i_64(arr[5]),
-    );
-  }
-
-  @protected
-  SyncSessionItemDto dco_decode_sync_session_item_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 8)
-      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
-    return SyncSessionItemDto(
-      id: dco_decode_String(arr[0]),
-      sessionId: dco_decode_String(arr[1]),
-      nodeId: dco_decode_i_64(arr[2]),
-      exerciseType: dco_decode_String(arr[3]),
-      grade: dco_decode_opt_box_autoadd_i_32(arr[4]),
-      durationMs: dco_decode_opt_box_autoadd_i_64(arr[5]),
-      completedAt: dco_decode_opt_box_autoadd_i_64(arr[6]),
-      clientUpdatedAt: dco_decode_i_64(arr[7]),
-    );
-  }
-
-  @protected
-  TranslatorDto dco_decode_translator_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 6)
-      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
-    return TranslatorDto(
-      id: dco_decode_i_32(arr[0]),
-      slug: dco_decode_String(arr[1]),
-      fullName: dco_decode_String(arr[2]),
-      languageCode: dco_decode_String(arr[3]),
-      description: dco_decode_opt_String(arr[4]),
-      license: dco_decode_opt_String(arr[5]),
-    );
-  }
-
-  @protected
-  int dco_decode_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  BigInt dco_decode_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
-  }
-
-  @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
-  }
-
-  @protected
-  BigInt dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
-  }
-
-  @protected
-  VerseDto dco_decode_verse_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return VerseDto(
-      key: dco_decode_String(arr[0]),
-      textUthmani: dco_decode_String(arr[1]),
-      chapterNumber: dco_decode_i_32(arr[2]),
-      verseNumber: dco_decode_i_32(arr[3]),
-    );
-  }
-
-  @protected
-  VerseWithTranslationDto dco_decode_verse_with_translation_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return VerseWithTranslationDto(
-      key: dco_decode_String(arr[0]),
-      textUthmani: dco_decode_String(arr[1]),
-      translation: dco_decode_opt_String(arr[2]),
-      number: dco_decode_i_32(arr[3]),
-    );
-  }
-
-  @protected
-  WordDto dco_decode_word_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return WordDto(
-      id: dco_decode_i_32(arr[0]),
-      textUthmani: dco_decode_String(arr[1]),
-      verseKey: dco_decode_String(arr[2]),
-      position: dco_decode_i_32(arr[3]),
-    );
-  }
-
-  @protected
-  WordTimingDto dco_decode_word_timing_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return WordTimingDto(
-      wordNodeId: dco_decode_String(arr[0]),
-      durationMs: dco_decode_u_64(arr[1]),
-    );
-  }
-
-  @protected
-  WordVisibilityDto dco_decode_word_visibility_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return WordVisibilityDto(
-      visibilityType: dco_decode_String(arr[0]),
-      hint: dco_decode_opt_box_autoadd_hint_dto(arr[1]),
-      coverage: dco_decode_opt_box_autoadd_f_64(arr[2]),
-    );
-  }
-
-  @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
-  }
-
-  @protected
-  AppState
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return AppStateImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  ArcExerciseService
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseService(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return ArcExerciseServiceImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  ArcLearningService
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningService(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return ArcLearningServiceImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  ArcSessionService
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionService(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return ArcSessionServiceImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  ArcContentRepository
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepository(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return ArcContentRepositoryImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  ArcUserRepository
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepository(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return ArcUserRepositoryImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  AppState
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return AppStateImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  AppState
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return AppStateImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  Map<String, String> sse_decode_Map_String_String_None(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_record_string_string(deserializer);
-    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
-  }
-
-  @protected
-  AppState
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return AppStateImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  ArcExerciseService
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseService(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return ArcExerciseServiceImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  ArcLearningService
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningService(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return ArcLearningServiceImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  ArcSessionService
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionService(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return ArcSessionServiceImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  ArcContentRepository
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepository(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return ArcContentRepositoryImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  ArcUserRepository
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepository(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return ArcUserRepositoryImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  String sse_decode_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
-  }
-
-  @protected
-  ActivityPointDto sse_decode_activity_point_dto(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_date = sse_decode_String(deserializer);
-    var var_count = sse_decode_i_32(deserializer);
-    return ActivityPointDto(date: var_date, count: var_count);
-  }
-
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
-  }
-
-  @protected
-  EchoRecallMetricsDto sse_decode_box_autoadd_echo_recall_metrics_dto(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_echo_recall_metrics_dto(deserializer));
-  }
-
-  @protected
-  EchoRecallStateDto sse_decode_box_autoadd_echo_recall_state_dto(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_echo_recall_state_dto(deserializer));
-  }
-
-  @protected
-  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_f_64(deserializer));
-  }
-
-  @protected
-  HintDto sse_decode_box_autoadd_hint_dto(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_hint_dto(deserializer));
-  }
-
-  @protected
-  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_i_64(deserializer));
-  }
-
-  @protected
-  NodeData sse_decode_box_autoadd_node_data(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_node_data(deserializer));
-  }
-
-  @protected
-  NodeFilterDto sse_decode_box_autoadd_node_filter_dto(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_node_filter_dto(deserializer));
-  }
-
-  @protected
-  SessionDto sse_decode_box_autoadd_session_dto(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_session_dto(deserializer));
-  }
-
-  @protected
-  SessionItemDto sse_decode_box_autoadd_session_item_dto(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_session_item_dto(deserializer));
-  }
-
-  @protected
-  TranslatorDto sse_decode_box_autoadd_translator_dto(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_translator_dto(deserializer));
-  }
-
-  @protected
-  VerseDto sse_decode_box_autoadd_verse_dto(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_verse_dto(deserializer));
-  }
-
-  @protected
-  WordDto sse_decode_box_autoadd_word_dto(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_word_dto(deserializer));
-  }
-
-  @protected
-  ComprehensionDto sse_decode_comprehension_dto(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_memorization = sse_decode_f_64(deserializer);
-    var var_understanding = sse_decode_f_64(deserializer);
-    var var_context = sse_decode_f_64(deserializer);
-    return ComprehensionDto(
-      memorization: var_memorization,
-      understanding: var_understanding,
-      context: var_context,
-    );
-  }
-
-  @protected
-  ContentPackageDto sse_decode_content_package_dto(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_packageId = sse_decode_String(deserializer);
-    var var_packageType = sse_decode_String(deserializer);
-    var var_name = sse_decode_String(deserializer);
-    var var_languageCode = sse_decode_opt_String(deserializer);
-    var var_author = sse_decode_opt_String(deserializer);
-    var var_version = sse_decode_String(deserializer);
-    var var_description = sse_decode_opt_String(deserializer);
-    var var_fileSize = sse_decode_opt_box_autoadd_i_64(deserializer);
-    var var_downloadUrl = sse_decode_opt_String(deserializer);
-    var var_checksum = sse_decode_opt_String(deserializer);
-    var var_license = sse_decode_opt_String(deserializer);
-    return ContentPackageDto(
-      packageId: var_packageId,
-      packageType: var_packageType,
-      name: var_name,
-      languageCode: var_languageCode,
-      author: var_author,
-      version: var_version,
-      description: var_description,
-      fileSize: var_fileSize,
-      downloadUrl: var_downloadUrl,
-      checksum: var_checksum,
-      license: var_license,
-    );
-  }
-
-  @protected
-  DashboardStatsDto sse_decode_dashboard_stats_dto(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_reviewsToday = sse_decode_u_32(deserializer);
-    var var_streakDays = sse_decode_u_32(deserializer);
-    var var_dueCount = sse_decode_u_32(deserializer);
-    return DashboardStatsDto(
-      reviewsToday: var_reviewsToday,
-      streakDays: var_streakDays,
-      dueCount: var_dueCount,
-    );
-  }
-
-  @protected
-  DbHealthDto sse_decode_db_health_dto(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_chaptersCount = sse_decode_i_64(deserializer);
-    var var_versesCount = sse_decode_i_64(deserializer);
-    var var_wordsCount = sse_decode_i_64(deserializer);
-    var var_nodesCount = sse_decode_i_64(deserializer);
-    var var_edgesCount = sse_decode_i_64(deserializer);
-    var var_userMemoryCount = sse_decode_i_64(deserializer);
-    var var_isHealthy = sse_decode_bool(deserializer);
-    var var_issues = sse_decode_list_String(deserializer);
-    return DbHealthDto(
-      chaptersCount: var_chaptersCount,
-      versesCount: var_versesCount,
-      wordsCount: var_wordsCount,
-      nodesCount: var_nodesCount,
-      edgesCount: var_edgesCount,
-      userMemoryCount: var_userMemoryCount,
-      isHealthy: var_isHealthy,
-      issues: var_issues,
-    );
-  }
-
-  @protected
-  DbQueryResultDto sse_decode_db_query_result_dto(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_columns = sse_decode_list_String(deserializer);
-    var var_rows = sse_decode_list_list_String(deserializer);
-    return DbQueryResultDto(columns: var_columns, rows: var_rows);
-  }
-
-  @protected
-  DebugStatsDto sse_decode_debug_stats_dto(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_totalNodesCount = sse_decode_u_32(deserializer);
-    var var_totalEdgesCount = sse_decode_u_32(deserializer);
-    var var_dueCount = sse_decode_u_32(deserializer);
-    return DebugStatsDto(
-      totalNodesCount: var_totalNodesCount,
-      totalEdgesCount: var_totalEdgesCount,
-      dueCount: var_dueCount,
-    );
-  }
-
-  @protected
-  DetailedStatsDto sse_decode_detailed_stats_dto(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_activityHistory = sse_decode_list_activity_point_dto(deserializer);
-    var var_comprehension = sse_decode_comprehension_dto(deserializer);
-    return DetailedStatsDto(
-      activityHistory: var_activityHistory,
-      comprehension: var_comprehension,
-    );
-  }
-
-  @protected
-  EchoRecallMetricsDto sse_decode_echo_recall_metrics_dto(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_wordTimings = sse_decode_list_word_timing_dto(deserializer);
-    var var_totalDurationMs = sse_decode_u_64(deserializer);
-    var var_struggles = sse_decode_u_32(deserializer);
-    return EchoRecallMetricsDto(
-      wordTimings: var_wordTimings,
-      totalDurationMs: var_totalDurationMs,
-      struggles: var_struggles,
-    );
-  }
-
-  @protected
-  EchoRecallResultDto sse_decode_echo_recall_result_dto(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_energyUpdates = sse_decode_list_energy_update_dto(deserializer);
-    var var_wordsProcessed = sse_decode_u_32(deserializer);
-    var var_averageEnergy = sse_decode_f_64(deserializer);
-    return EchoRecallResultDto(
-      energyUpdates: var_energyUpdates,
-      wordsProcessed: var_wordsProcessed,
-      averageEnergy: var_averageEnergy,
-    );
-  }
-
-  @protected
-  EchoRecallStateDto sse_decode_echo_recall_state_dto(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_words = sse_decode_list_echo_recall_word_dto(deserializer);
-    return EchoRecallStateDto(words: var_words);
-  }
-
-  @protected
-  EchoRecallStatsDto sse_decode_echo_recall_stats_dto(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_totalWords = sse_decode_u_32(deserializer);
-    var var_visibleCount = sse_decode_u_32(deserializer);
-    var var_obscuredCount = sse_decode_u_32(deserializer);
-    var var_hiddenCount = sse_decode_u_32(deserializer);
-    var var_averageEnergy = sse_decode_f_64(deserializer);
-    var var_masteryPercentage = sse_decode_f_64(deserializer);
-    return EchoRecallStatsDto(
-      totalWords: var_totalWords,
-      visibleCount: var_visibleCount,
-      obscuredCount: var_obscuredCount,
-      hiddenCount: var_hiddenCount,
-      averageEnergy: var_averageEnergy,
-      masteryPercentage: var_masteryPercentage,
-    );
-  }
-
-  @protected
-  EchoRecallWordDto sse_decode_echo_recall_word_dto(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_nodeId = sse_decode_String(deserializer);
-    var var_text = sse_decode_String(deserializer);
-    var var_visibility = sse_decode_word_visibility_dto(deserializer);
-    var var_energy = sse_decode_f_64(deserializer);
-    return EchoRecallWordDto(
-      nodeId: var_nodeId,
-      text: var_text,
-      visibility: var_visibility,
-      energy: var_energy,
-    );
-  }
-
-  @protected
-  EnergySnapshotDto sse_decode_energy_snapshot_dto(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_nodeId = sse_decode_String(deserializer);
-    var var_energy = sse_decode_f_64(deserializer);
-    var var_nodeType = sse_decode_opt_String(deserializer);
-    var var_knowledgeAxis = sse_decode_opt_String(deserializer);
-    var var_neighbors = sse_decode_list_node_energy_dto(deserializer);
-    return EnergySnapshotDto(
-      nodeId: var_nodeId,
-      energy: var_energy,
-      nodeType: var_nodeType,
-      knowledgeAxis: var_knowledgeAxis,
-      neighbors: var_neighbors,
-    );
-  }
-
-  @protected
-  EnergyUpdateDto sse_decode_energy_update_dto(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_nodeId = sse_decode_String(deserializer);
-    var var_energy = sse_decode_f_64(deserializer);
-    return EnergyUpdateDto(nodeId: var_nodeId, energy: var_energy);
-  }
-
-  @protected
-  ExerciseDataDto sse_decode_exercise_data_dto(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var tag_ = sse_decode_i_32(deserializer);
-    switch (tag_) {
-      case 0:
-        var var_nodeId = sse_decode_String(deserializer);
-        return ExerciseDataDto_Memorization(nodeId: var_nodeId);
-      case 1:
-        var var_nodeId = sse_decode_String(deserializer);
-        var var_distractorNodeIds = sse_decode_list_String(deserializer);
-        return ExerciseDataDto_McqArToEn(
-          nodeId: var_nodeId,
-          distractorNodeIds: var_distractorNodeIds,
-        );
-      case 2:
-        var var_nodeId = sse_decode_String(deserializer);
-        var var_distractorNodeIds = sse_decode_list_String(deserializer);
-        return ExerciseDataDto_McqEnToAr(
-          nodeId: var_nodeId,
-          distractorNodeIds: var_distractorNodeIds,
+        )
+        ,
+            constMeta: kCrateApiSetPreferredTranslatorIdConstMeta,
+            argValues: [translatorId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSetPreferredTranslatorIdConstMeta => const TaskConstMeta(
+            debugName: "set_preferred_translator_id",
+            argNames: ["translatorId"],
         );
-      case 3:
-        var var_nodeId = sse_decode_String(deserializer);
-        return ExerciseDataDto_Translation(nodeId: var_nodeId);
-      case 4:
-        var var_nodeId = sse_decode_String(deserializer);
-        var var_verseKey = sse_decode_String(deserializer);
-        return ExerciseDataDto_ContextualTranslation(
-          nodeId: var_nodeId,
-          verseKey: var_verseKey,
+        
+
+@override Future<String> crateApiSetupDatabase({required String contentDbPath , required String userDbPath , required List<int> kgBytes })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(contentDbPath, serializer);
+sse_encode_String(userDbPath, serializer);
+sse_encode_list_prim_u_8_loose(kgBytes, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 87, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSetupDatabaseConstMeta,
+            argValues: [contentDbPath, userDbPath, kgBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSetupDatabaseConstMeta => const TaskConstMeta(
+            debugName: "setup_database",
+            argNames: ["contentDbPath", "userDbPath", "kgBytes"],
         );
-      case 5:
-        var var_nodeId = sse_decode_String(deserializer);
-        var var_blankPosition = sse_decode_i_32(deserializer);
-        return ExerciseDataDto_ClozeDeletion(
-          nodeId: var_nodeId,
-          blankPosition: var_blankPosition,
+        
+
+@override Future<String> crateApiSetupDatabaseInMemory({required List<int> kgBytes })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(kgBytes, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 88, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSetupDatabaseInMemoryConstMeta,
+            argValues: [kgBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSetupDatabaseInMemoryConstMeta => const TaskConstMeta(
+            debugName: "setup_database_in_memory",
+            argNames: ["kgBytes"],
         );
-      case 6:
-        var var_nodeId = sse_decode_String(deserializer);
-        var var_wordPosition = sse_decode_i_32(deserializer);
-        return ExerciseDataDto_FirstLetterHint(
-          nodeId: var_nodeId,
-          wordPosition: var_wordPosition,
+        
+
+@override Future<PropagationResultDto> crateApiSimulatePropagation({required String userId , required String nodeId , required double energyDelta })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(userId, serializer);
+sse_encode_String(nodeId, serializer);
+sse_encode_f_64(energyDelta, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 89, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_propagation_result_dto,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSimulatePropagationConstMeta,
+            argValues: [userId, nodeId, energyDelta],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimulatePropagationConstMeta => const TaskConstMeta(
+            debugName: "simulate_propagation",
+            argNames: ["userId", "nodeId", "energyDelta"],
         );
-      case 7:
-        var var_nodeId = sse_decode_String(deserializer);
-        var var_blankPosition = sse_decode_i_32(deserializer);
-        var var_distractorNodeIds = sse_decode_list_String(deserializer);
-        return ExerciseDataDto_MissingWordMcq(
-          nodeId: var_nodeId,
-          blankPosition: var_blankPosition,
-          distractorNodeIds: var_distractorNodeIds,
+        
+
+@override Future<EchoRecallStateDto> crateApiStartEchoRecall({required String userId , required List<String> ayahNodeIds })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(userId, serializer);
+sse_encode_list_String(ayahNodeIds, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 90, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_echo_recall_state_dto,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiStartEchoRecallConstMeta,
+            argValues: [userId, ayahNodeIds],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStartEchoRecallConstMeta => const TaskConstMeta(
+            debugName: "start_echo_recall",
+            argNames: ["userId", "ayahNodeIds"],
         );
-      case 8:
-        var var_nodeId = sse_decode_String(deserializer);
-        var var_contextPosition = sse_decode_i_32(deserializer);
-        var var_distractorNodeIds = sse_decode_list_String(deserializer);
-        return ExerciseDataDto_NextWordMcq(
-          nodeId: var_nodeId,
-          contextPosition: var_contextPosition,
-          distractorNodeIds: var_distractorNodeIds,
+        
+
+@override Future<SessionDto> crateApiStartSession({required String userId , required String goalId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(userId, serializer);
+sse_encode_String(goalId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 91, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_session_dto,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiStartSessionConstMeta,
+            argValues: [userId, goalId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStartSessionConstMeta => const TaskConstMeta(
+            debugName: "start_session",
+            argNames: ["userId", "goalId"],
         );
-      case 9:
-        var var_nodeId = sse_decode_String(deserializer);
-        return ExerciseDataDto_FullVerseInput(nodeId: var_nodeId);
-      case 10:
-        var var_nodeId = sse_decode_String(deserializer);
-        var var_verseKeys = sse_decode_list_String(deserializer);
-        var var_currentIndex = sse_decode_usize(deserializer);
-        var var_completedCount = sse_decode_usize(deserializer);
-        return ExerciseDataDto_AyahChain(
-          nodeId: var_nodeId,
-          verseKeys: var_verseKeys,
-          currentIndex: var_currentIndex,
-          completedCount: var_completedCount,
+        
+
+@override Future<EchoRecallStateDto> crateApiSubmitEchoRecall({required String userId , required List<String> ayahNodeIds , required EchoRecallStateDto state , required String wordNodeId , required int recallTimeMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(userId, serializer);
+sse_encode_list_String(ayahNodeIds, serializer);
+sse_encode_box_autoadd_echo_recall_state_dto(state, serializer);
+sse_encode_String(wordNodeId, serializer);
+sse_encode_u_32(recallTimeMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 92, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_echo_recall_state_dto,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSubmitEchoRecallConstMeta,
+            argValues: [userId, ayahNodeIds, state, wordNodeId, recallTimeMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSubmitEchoRecallConstMeta => const TaskConstMeta(
+            debugName: "submit_echo_recall",
+            argNames: ["userId", "ayahNodeIds", "state", "wordNodeId", "recallTimeMs"],
         );
-      case 11:
-        var var_nodeId = sse_decode_String(deserializer);
-        var var_mistakePosition = sse_decode_i_32(deserializer);
-        var var_correctWordNodeId = sse_decode_String(deserializer);
-        var var_incorrectWordNodeId = sse_decode_String(deserializer);
-        return ExerciseDataDto_FindMistake(
-          nodeId: var_nodeId,
-          mistakePosition: var_mistakePosition,
-          correctWordNodeId: var_correctWordNodeId,
-          incorrectWordNodeId: var_incorrectWordNodeId,
+        
+
+@override Future<ValidationResultDto> crateApiSubmitSessionAnswer({required String sessionId , required String answerJson , required BigInt durationMs , required int hintLevel })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(sessionId, serializer);
+sse_encode_String(answerJson, serializer);
+sse_encode_u_64(durationMs, serializer);
+sse_encode_u_8(hintLevel, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 93, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_validation_result_dto,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSubmitSessionAnswerConstMeta,
+            argValues: [sessionId, answerJson, durationMs, hintLevel],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSubmitSessionAnswerConstMeta => const TaskConstMeta(
+            debugName: "submit_session_answer",
+            argNames: ["sessionId", "answerJson", "durationMs", "hintLevel"],
         );
-      case 12:
-        var var_nodeId = sse_decode_String(deserializer);
-        var var_correctSequence = sse_decode_list_String(deserializer);
-        return ExerciseDataDto_AyahSequence(
-          nodeId: var_nodeId,
-          correctSequence: var_correctSequence,
+        
+
+@override Future<AyahChainStateDto> crateApiSubmitSessionAyahChain({required String sessionId , required int position , required String userId , required String chapterNodeId , required String userInput })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(sessionId, serializer);
+sse_encode_i_32(position, serializer);
+sse_encode_String(userId, serializer);
+sse_encode_String(chapterNodeId, serializer);
+sse_encode_String(userInput, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 94, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ayah_chain_state_dto,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSubmitSessionAyahChainConstMeta,
+            argValues: [sessionId, position, userId, chapterNodeId, userInput],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSubmitSessionAyahChainConstMeta => const TaskConstMeta(
+            debugName: "submit_session_ayah_chain",
+            argNames: ["sessionId", "position", "userId", "chapterNodeId", "userInput"],
         );
-      case 13:
-        var var_nodeId = sse_decode_String(deserializer);
-        var var_correctSequence = sse_decode_list_String(deserializer);
-        var var_options = sse_decode_list_list_String(deserializer);
-        return ExerciseDataDto_SequenceRecall(
-          nodeId: var_nodeId,
-          correctSequence: var_correctSequence,
-          options: var_options,
+        
+
+@override Future<EchoRecallStateDto> crateApiSubmitSessionEchoRecall({required String sessionId , required int position , required String userId , required List<String> ayahNodeIds , required EchoRecallStateDto state , required String wordNodeId , required int recallTimeMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(sessionId, serializer);
+sse_encode_i_32(position, serializer);
+sse_encode_String(userId, serializer);
+sse_encode_list_String(ayahNodeIds, serializer);
+sse_encode_box_autoadd_echo_recall_state_dto(state, serializer);
+sse_encode_String(wordNodeId, serializer);
+sse_encode_u_32(recallTimeMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 95, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_echo_recall_state_dto,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSubmitSessionEchoRecallConstMeta,
+            argValues: [sessionId, position, userId, ayahNodeIds, state, wordNodeId, recallTimeMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSubmitSessionEchoRecallConstMeta => const TaskConstMeta(
+            debugName: "submit_session_echo_recall",
+            argNames: ["sessionId", "position", "userId", "ayahNodeIds", "state", "wordNodeId", "recallTimeMs"],
         );
-      case 14:
-        var var_nodeId = sse_decode_String(deserializer);
-        var var_verseKey = sse_decode_String(deserializer);
-        return ExerciseDataDto_FirstWordRecall(
-          nodeId: var_nodeId,
-          verseKey: var_verseKey,
+        
+
+@override Future<String> crateApiSubmitSessionItem({required String sessionId , required bool isCorrect , required BigInt durationMs , required int hintLevel })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(sessionId, serializer);
+sse_encode_bool(isCorrect, serializer);
+sse_encode_u_64(durationMs, serializer);
+sse_encode_u_8(hintLevel, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 96, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSubmitSessionItemConstMeta,
+            argValues: [sessionId, isCorrect, durationMs, hintLevel],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSubmitSessionItemConstMeta => const TaskConstMeta(
+            debugName: "submit_session_item",
+            argNames: ["sessionId", "isCorrect", "durationMs", "hintLevel"],
         );
-      case 15:
-        var var_nodeId = sse_decode_String(deserializer);
-        var var_root = sse_decode_String(deserializer);
-        return ExerciseDataDto_IdentifyRoot(nodeId: var_nodeId, root: var_root);
-      case 16:
-        var var_nodeId = sse_decode_String(deserializer);
-        var var_blankPosition = sse_decode_i_32(deserializer);
-        return ExerciseDataDto_ReverseCloze(
-          nodeId: var_nodeId,
-          blankPosition: var_blankPosition,
+        
+
+@override Future<int> crateApiSuspendNodes({required String userId , required String scope })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(userId, serializer);
+sse_encode_String(scope, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 97, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSuspendNodesConstMeta,
+            argValues: [userId, scope],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSuspendNodesConstMeta => const TaskConstMeta(
+            debugName: "suspend_nodes",
+            argNames: ["userId", "scope"],
         );
-      case 17:
-        var var_nodeId = sse_decode_String(deserializer);
-        var var_translatorId = sse_decode_i_32(deserializer);
-        return ExerciseDataDto_TranslatePhrase(
-          nodeId: var_nodeId,
-          translatorId: var_translatorId,
+        
+
+@override Future<int> crateApiUnsuspendNodes({required String userId , required String scope })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(userId, serializer);
+sse_encode_String(scope, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 98, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiUnsuspendNodesConstMeta,
+            argValues: [userId, scope],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiUnsuspendNodesConstMeta => const TaskConstMeta(
+            debugName: "unsuspend_nodes",
+            argNames: ["userId", "scope"],
         );
-      case 18:
-        var var_nodeId = sse_decode_String(deserializer);
-        var var_correctPos = sse_decode_String(deserializer);
-        var var_options = sse_decode_list_String(deserializer);
-        return ExerciseDataDto_PosTagging(
-          nodeId: var_nodeId,
-          correctPos: var_correctPos,
-          options: var_options,
+        
+
+@override Future<String> crateApiUpsertMemoryStatesFromRemote({required String userId , required List<SyncMemoryStateDto> states })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(userId, serializer);
+sse_encode_list_sync_memory_state_dto(states, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 99, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiUpsertMemoryStatesFromRemoteConstMeta,
+            argValues: [userId, states],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiUpsertMemoryStatesFromRemoteConstMeta => const TaskConstMeta(
+            debugName: "upsert_memory_states_from_remote",
+            argNames: ["userId", "states"],
         );
-      case 19:
-        var var_nodeId = sse_decode_String(deserializer);
-        var var_relatedVerseIds = sse_decode_list_String(deserializer);
-        var var_connectionTheme = sse_decode_String(deserializer);
-        return ExerciseDataDto_CrossVerseConnection(
-          nodeId: var_nodeId,
-          relatedVerseIds: var_relatedVerseIds,
-          connectionTheme: var_connectionTheme,
+        
+
+@override Future<String> crateApiUpsertSessionItemsFromRemote({required String userId , required List<SyncSessionItemDto> items })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(userId, serializer);
+sse_encode_list_sync_session_item_dto(items, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 100, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiUpsertSessionItemsFromRemoteConstMeta,
+            argValues: [userId, items],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiUpsertSessionItemsFromRemoteConstMeta => const TaskConstMeta(
+            debugName: "upsert_session_items_from_remote",
+            argNames: ["userId", "items"],
         );
-      case 20:
-        var var_userId = sse_decode_String(deserializer);
-        var var_ayahNodeIds = sse_decode_list_String(deserializer);
-        return ExerciseDataDto_EchoRecall(
-          userId: var_userId,
-          ayahNodeIds: var_ayahNodeIds,
+        
+
+@override Future<String> crateApiUpsertSessionsFromRemote({required String userId , required List<SyncSessionDto> sessions })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(userId, serializer);
+sse_encode_list_sync_session_dto(sessions, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 101, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiUpsertSessionsFromRemoteConstMeta,
+            argValues: [userId, sessions],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiUpsertSessionsFromRemoteConstMeta => const TaskConstMeta(
+            debugName: "upsert_sessions_from_remote",
+            argNames: ["userId", "sessions"],
         );
-      default:
-        throw UnimplementedError('');
-    }
-  }
-
-  @protected
-  double sse_decode_f_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat64();
-  }
-
-  @protected
-  HintDto sse_decode_hint_dto(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_hintType = sse_decode_String(deserializer);
-    var var_firstChar = sse_decode_opt_String(deserializer);
-    var var_lastChar = sse_decode_opt_String(deserializer);
-    return HintDto(
-      hintType: var_hintType,
-      firstChar: var_firstChar,
-      lastChar: var_lastChar,
-    );
-  }
-
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
-  }
-
-  @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getPlatformInt64();
-  }
-
-  @protected
-  InstalledPackageDto sse_decode_installed_package_dto(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_packageId = sse_decode_String(deserializer);
-    var var_installedAt = sse_decode_i_64(deserializer);
-    var var_enabled = sse_decode_bool(deserializer);
-    return InstalledPackageDto(
-      packageId: var_packageId,
-      installedAt: var_installedAt,
-      enabled: var_enabled,
-    );
-  }
-
-  @protected
-  LanguageDto sse_decode_language_dto(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_code = sse_decode_String(deserializer);
-    var var_englishName = sse_decode_String(deserializer);
-    var var_nativeName = sse_decode_String(deserializer);
-    var var_direction = sse_decode_String(deserializer);
-    return LanguageDto(
-      code: var_code,
-      englishName: var_englishName,
-      nativeName: var_nativeName,
-      direction: var_direction,
-    );
-  }
-
-  @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <String>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<ActivityPointDto> sse_decode_list_activity_point_dto(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ActivityPointDto>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_activity_point_dto(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<ContentPackageDto> sse_decode_list_content_package_dto(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ContentPackageDto>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_content_package_dto(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<EchoRecallWordDto> sse_decode_list_echo_recall_word_dto(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <EchoRecallWordDto>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_echo_recall_word_dto(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<EnergyUpdateDto> sse_decode_list_energy_update_dto(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <EnergyUpdateDto>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_energy_update_dto(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<ExerciseDataDto> sse_decode_list_exercise_data_dto(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ExerciseDataDto>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_exercise_data_dto(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<InstalledPackageDto> sse_decode_list_installed_package_dto(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <InstalledPackageDto>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_installed_package_dto(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<LanguageDto> sse_decode_list_language_dto(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <LanguageDto>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_language_dto(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<List<String>> sse_decode_list_list_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <List<String>>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_list_String(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<NodeEnergyDto> sse_decode_list_node_energy_dto(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <NodeEnergyDto>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_node_energy_dto(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<NodeSearchDto> sse_decode_list_node_search_dto(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <NodeSearchDto>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_node_search_dto(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  List<(String, String)> sse_decode_list_record_string_string(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, String)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_string(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<SessionPreviewDto> sse_decode_list_session_preview_dto(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <SessionPreviewDto>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_session_preview_dto(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<SurahInfo> sse_decode_list_surah_info(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <SurahInfo>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_surah_info(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<SyncMemoryStateDto> sse_decode_list_sync_memory_state_dto(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <SyncMemoryStateDto>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_sync_memory_state_dto(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<SyncSessionDto> sse_decode_list_sync_session_dto(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <SyncSessionDto>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_sync_session_dto(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<SyncSessionItemDto> sse_decode_list_sync_session_item_dto(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <SyncSessionItemDto>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_sync_session_item_dto(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<TranslatorDto> sse_decode_list_translator_dto(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <TranslatorDto>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_translator_dto(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<VerseWithTranslationDto> sse_decode_list_verse_with_translation_dto(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <VerseWithTranslationDto>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_verse_with_translation_dto(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<WordDto> sse_decode_list_word_dto(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <WordDto>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_word_dto(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<WordTimingDto> sse_decode_list_word_timing_dto(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <WordTimingDto>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_word_timing_dto(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  NodeData sse_decode_node_data(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_String(deserializer);
-    var var_nodeType = sse_decode_String(deserializer);
-    var var_metadata = sse_decode_Map_String_String_None(deserializer);
-    return NodeData(id: var_id, nodeType: var_nodeType, metadata: var_metadata);
-  }
-
-  @protected
-  NodeEnergyDto sse_decode_node_energy_dto(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_nodeId = sse_decode_String(deserializer);
-    var var_energy = sse_decode_f_64(deserializer);
-    var var_edgeWeight = sse_decode_f_64(deserializer);
-    return NodeEnergyDto(
-      nodeId: var_nodeId,
-      energy: var_energy,
-      edgeWeight: var_edgeWeight,
-    );
-  }
-
-  @protected
-  NodeFilterDto sse_decode_node_filter_dto(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_nodeType = sse_decode_opt_String(deserializer);
-    var var_minEnergy = sse_decode_opt_box_autoadd_f_64(deserializer);
-    var var_maxEnergy = sse_decode_opt_box_autoadd_f_64(deserializer);
-    var var_range = sse_decode_opt_String(deserializer);
-    return NodeFilterDto(
-      nodeType: var_nodeType,
-      minEnergy: var_minEnergy,
-      maxEnergy: var_maxEnergy,
-      range: var_range,
-    );
-  }
-
-  @protected
-  NodeSearchDto sse_decode_node_search_dto(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_nodeId = sse_decode_String(deserializer);
-    var var_nodeType = sse_decode_String(deserializer);
-    var var_knowledgeAxis = sse_decode_opt_String(deserializer);
-    var var_preview = sse_decode_String(deserializer);
-    return NodeSearchDto(
-      nodeId: var_nodeId,
-      nodeType: var_nodeType,
-      knowledgeAxis: var_knowledgeAxis,
-      preview: var_preview,
-    );
-  }
-
-  @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_f_64(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  HintDto? sse_decode_opt_box_autoadd_hint_dto(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_hint_dto(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_i_32(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_i_64(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  NodeData? sse_decode_opt_box_autoadd_node_data(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_node_data(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  SessionDto? sse_decode_opt_box_autoadd_session_dto(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_session_dto(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  SessionItemDto? sse_decode_opt_box_autoadd_session_item_dto(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_session_item_dto(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  TranslatorDto? sse_decode_opt_box_autoadd_translator_dto(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_translator_dto(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  VerseDto? sse_decode_opt_box_autoadd_verse_dto(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_verse_dto(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  WordDto? sse_decode_opt_box_autoadd_word_dto(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_word_dto(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  PropagationDiagnosticsDto sse_decode_propagation_diagnostics_dto(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_nodeFound = sse_decode_bool(deserializer);
-    var var_nodeType = sse_decode_opt_String(deserializer);
-    var var_totalEdges = sse_decode_u_32(deserializer);
-    var var_message = sse_decode_String(deserializer);
-    return PropagationDiagnosticsDto(
-      nodeFound: var_nodeFound,
-      nodeType: var_nodeType,
-      totalEdges: var_totalEdges,
-      message: var_message,
-    );
-  }
-
-  @protected
-  PropagationResultDto sse_decode_propagation_result_dto(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_before = sse_decode_list_node_energy_dto(deserializer);
-    var var_after = sse_decode_list_node_energy_dto(deserializer);
-    var var_diagnostics = sse_decode_propagation_diagnostics_dto(deserializer);
-    return PropagationResultDto(
-      before: var_before,
-      after: var_after,
-      diagnostics: var_diagnostics,
-    );
-  }
-
-  @protected
-  (String, String) sse_decode_record_string_string(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_String(deserializer);
-    var var_field1 = sse_decode_String(deserializer);
-    return (var_field0, var_field1);
-  }
-
-  @protected
-  SessionDto sse_decode_session_dto(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_String(deserializer);
-    var var_userId = sse_decode_String(deserializer);
-    var var_goalId = sse_decode_String(deserializer);
-    var var_startedAt = sse_decode_i_64(deserializer);
-    var var_completedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
-    var var_itemsCount = sse_decode_i_32(deserializer);
-    var var_itemsCompleted = sse_decode_i_32(deserializer);
-    return SessionDto(
-      id: var_id,
-      userId: var_userId,
-      goalId: var_goalId,
-      startedAt: var_startedAt,
-      completedAt: var_completedAt,
-      itemsCount: var_itemsCount,
-      itemsCompleted: var_itemsCompleted,
-    );
-  }
-
-  @protected
-  SessionItemDto sse_decode_session_item_dto(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_sessionId = sse_decode_String(deserializer);
-    var var_position = sse_decode_i_32(deserializer);
-    var var_nodeId = sse_decode_String(deserializer);
-    var var_exerciseType = sse_decode_String(deserializer);
-    var var_exercise = sse_decode_exercise_data_dto(deserializer);
-    return SessionItemDto(
-      sessionId: var_sessionId,
-      position: var_position,
-      nodeId: var_nodeId,
-      exerciseType: var_exerciseType,
-      exercise: var_exercise,
-    );
-  }
-
-  @protected
-  SessionPreviewDto sse_decode_session_preview_dto(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_nodeId = sse_decode_String(deserializer);
-    var var_nodeType = sse_decode_String(deserializer);
-    var var_previewText = sse_decode_String(deserializer);
-    var var_energy = sse_decode_f_64(deserializer);
-    var var_priorityScore = sse_decode_f_64(deserializer);
-    return SessionPreviewDto(
-      nodeId: var_nodeId,
-      nodeType: var_nodeType,
-      previewText: var_previewText,
-      energy: var_energy,
-      priorityScore: var_priorityScore,
-    );
-  }
-
-  @protected
-  SessionSummaryDto sse_decode_session_summary_dto(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_sessionId = sse_decode_String(deserializer);
-    var var_itemsCount = sse_decode_i_32(deserializer);
-    var var_itemsCompleted = sse_decode_i_32(deserializer);
-    var var_durationMs = sse_decode_i_64(deserializer);
-    var var_againCount = sse_decode_i_32(deserializer);
-    var var_hardCount = sse_decode_i_32(deserializer);
-    var var_goodCount = sse_decode_i_32(deserializer);
-    var var_easyCount = sse_decode_i_32(deserializer);
-    return SessionSummaryDto(
-      sessionId: var_sessionId,
-      itemsCount: var_itemsCount,
-      itemsCompleted: var_itemsCompleted,
-      durationMs: var_durationMs,
-      againCount: var_againCount,
-      hardCount: var_hardCount,
-      goodCount: var_goodCount,
-      easyCount: var_easyCount,
-    );
-  }
-
-  @protected
-  SurahInfo sse_decode_surah_info(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_number = sse_decode_i_32(deserializer);
-    var var_name = sse_decode_String(deserializer);
-    var var_nameArabic = sse_decode_String(deserializer);
-    var var_nameTransliteration = sse_decode_String(deserializer);
-    var var_nameTranslation = sse_decode_String(deserializer);
-    var var_verseCount = sse_decode_i_32(deserializer);
-    var var_revelationPlace = sse_decode_opt_String(deserializer);
-    return SurahInfo(
-      number: var_number,
-      name: var_name,
-      nameArabic: var_nameArabic,
-      nameTransliteration: var_nameTransliteration,
-      nameTranslation: var_nameTranslation,
-      verseCount: var_verseCount,
-      revelationPlace: var_revelationPlace,
-    );
-  }
-
-  @protected
-  SyncMemoryStateDto sse_decode_sync_memory_state_dto(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_nodeId = sse_decode_i_64(deserializer);
-    var var_energy = sse_decode_f_64(deserializer);
-    var var_fsrsStability = sse_decode_opt_box_autoadd_f_64(deserializer);
-    var var_fsrsDifficulty = sse_decode_opt_box_autoadd_f_64(deserializer);
-    var var_lastReviewedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
-    var var_nextReviewAt = sse_decode_opt_box_autoadd_i_64(deserializer);
-    var var_clientUpdatedAt = sse_decode_i_64(deserializer);
-    return SyncMemoryStateDto(
-      nodeId: var_nodeId,
-      energy: var_energy,
-      fsrsStability: var_fsrsStability,
-      fsrsDifficulty: var_fsrsDifficulty,
-      lastReviewedAt: var_lastReviewedAt,
-      nextReviewAt: var_nextReviewAt,
-      clientUpdatedAt: var_clientUpdatedAt,
-    );
-  }
-
-  @protected
-  SyncSessionDto sse_decode_sync_session_dto(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_String(deserializer);
-    var var_goalId = sse_decode_opt_String(deserializer);
-    var var_startedAt = sse_decode_i_64(deserializer);
-    var var_completedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
-    var var_itemsCompleted = sse_decode_i_32(deserializer);
-    var var_clientUpdatedAt = sse_decode_i_64(deserializer);
-    return SyncSessionDto(
-      id: var_id,
-      goalId: var_goalId,
-      startedAt: var_startedAt,
-      completedAt: var_completedAt,
-      itemsCompleted: var_itemsCompleted,
-      clientUpdatedAt: var_clientUpdatedAt,
-    );
-  }
-
-  @protected
-  SyncSessionItemDto sse_decode_sync_session_item_dto(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_String(deserializer);
-    var var_sessionId = sse_decode_String(deserializer);
-    var var_nodeId = sse_decode_i_64(deserializer);
-    var var_exerciseType = sse_decode_String(deserializer);
-    var var_grade = sse_decode_opt_box_autoadd_i_32(deserializer);
-    var var_durationMs = sse_decode_opt_box_autoadd_i_64(deserializer);
-    var var_completedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
-    var var_clientUpdatedAt = sse_decode_i_64(deserializer);
-    return SyncSessionItemDto(
-      id: var_id,
-      sessionId: var_sessionId,
-      nodeId: var_nodeId,
-      exerciseType: var_exerciseType,
-      grade: var_grade,
-      durationMs: var_durationMs,
-      completedAt: var_completedAt,
-      clientUpdatedAt: var_clientUpdatedAt,
-    );
-  }
-
-  @protected
-  TranslatorDto sse_decode_translator_dto(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_i_32(deserializer);
-    var var_slug = sse_decode_String(deserializer);
-    var var_fullName = sse_decode_String(deserializer);
-    var var_languageCode = sse_decode_String(deserializer);
-    var var_description = sse_decode_opt_String(deserializer);
-    var var_license = sse_decode_opt_String(deserializer);
-    return TranslatorDto(
-      id: var_id,
-      slug: var_slug,
-      fullName: var_fullName,
-      languageCode: var_languageCode,
-      description: var_description,
-      license: var_license,
-    );
-  }
-
-  @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
-  }
-
-  @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
-  }
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
-  }
-
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
-  }
-
-  @protected
-  VerseDto sse_decode_verse_dto(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_key = sse_decode_String(deserializer);
-    var var_textUthmani = sse_decode_String(deserializer);
-    var var_chapterNumber = sse_decode_i_32(deserializer);
-    var var_verseNumber = sse_decode_i_32(deserializer);
-    return VerseDto(
-      key: var_key,
-      textUthmani: var_textUthmani,
-      chapterNumber: var_chapterNumber,
-      verseNumber: var_verseNumber,
-    );
-  }
-
-  @protected
-  VerseWithTranslationDto sse_decode_verse_with_translation_dto(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_key = sse_decode_String(deserializer);
-    var var_textUthmani = sse_decode_String(deserializer);
-    var var_translation = sse_decode_opt_String(deserializer);
-    var var_number = sse_decode_i_32(deserializer);
-    return VerseWithTranslationDto(
-      key: var_key,
-      textUthmani: var_textUthmani,
-      translation: var_translation,
-      number: var_number,
-    );
-  }
-
-  @protected
-  WordDto sse_decode_word_dto(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_i_32(deserializer);
-    var var_textUthmani = sse_decode_String(deserializer);
-    var var_verseKey = sse_decode_String(deserializer);
-    var var_position = sse_decode_i_32(deserializer);
-    return WordDto(
-      id: var_id,
-      textUthmani: var_textUthmani,
-      verseKey: var_verseKey,
-      position: var_position,
-    );
-  }
-
-  @protected
-  WordTimingDto sse_decode_word_timing_dto(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_wordNodeId = sse_decode_String(deserializer);
-    var var_durationMs = sse_decode_u_64(deserializer);
-    return WordTimingDto(
-      wordNodeId: var_wordNodeId,
-      durationMs: var_durationMs,
-    );
-  }
-
-  @protected
-  WordVisibilityDto sse_decode_word_visibility_dto(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_visibilityType = sse_decode_String(deserializer);
-    var var_hint = sse_decode_opt_box_autoadd_hint_dto(deserializer);
-    var var_coverage = sse_decode_opt_box_autoadd_f_64(deserializer);
-    return WordVisibilityDto(
-      visibilityType: var_visibilityType,
-      hint: var_hint,
-      coverage: var_coverage,
-    );
-  }
-
-  @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
-  }
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(
-    AppState self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as AppStateImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseService(
-    ArcExerciseService self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as ArcExerciseServiceImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningService(
-    ArcLearningService self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as ArcLearningServiceImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionService(
-    ArcSessionService self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as ArcSessionServiceImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepository(
-    ArcContentRepository self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as ArcContentRepositoryImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepository(
-    ArcUserRepository self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as ArcUserRepositoryImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(
-    AppState self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as AppStateImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(
-    AppState self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as AppStateImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
-  }
-
-  @protected
-  void sse_encode_Map_String_String_None(
-    Map<String, String> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_record_string_string(
-      self.entries.map((e) => (e.key, e.value)).toList(),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(
-    AppState self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as AppStateImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseService(
-    ArcExerciseService self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as ArcExerciseServiceImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningService(
-    ArcLearningService self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as ArcLearningServiceImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionService(
-    ArcSessionService self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as ArcSessionServiceImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepository(
-    ArcContentRepository self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as ArcContentRepositoryImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepository(
-    ArcUserRepository self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as ArcUserRepositoryImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
-  }
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
-  }
-
-  @protected
-  void sse_encode_activity_point_dto(
-    ActivityPointDto self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.date, serializer);
-    sse_encode_i_32(self.count, serializer);
-  }
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_echo_recall_metrics_dto(
-    EchoRecallMetricsDto self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_echo_recall_metrics_dto(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_echo_recall_state_dto(
-    EchoRecallStateDto self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_echo_recall_state_dto(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_f_64(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_hint_dto(HintDto self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_hint_dto(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_i_64(
-    PlatformInt64 self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_64(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_node_data(
-    NodeData self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_node_data(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_node_filter_dto(
-    NodeFilterDto self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_node_filter_dto(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_session_dto(
-    SessionDto self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_session_dto(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_session_item_dto(
-    SessionItemDto self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_session_item_dto(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_translator_dto(
-    TranslatorDto self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_translator_dto(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_verse_dto(
-    VerseDto self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_verse_dto(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_word_dto(WordDto self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_word_dto(self, serializer);
-  }
-
-  @protected
-  void sse_encode_comprehension_dto(
-    ComprehensionDto self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_f_64(self.memorization, serializer);
-    sse_encode_f_64(self.understanding, serializer);
-    sse_encode_f_64(self.context, serializer);
-  }
-
-  @protected
-  void sse_encode_content_package_dto(
-    ContentPackageDto self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.packageId, serializer);
-    sse_encode_String(self.packageType, serializer);
-    sse_encode_String(self.name, serializer);
-    sse_encode_opt_String(self.languageCode, serializer);
-    sse_encode_opt_String(self.author, serializer);
-    sse_encode_String(self.version, serializer);
-    sse_encode_opt_String(self.description, serializer);
-    sse_encode_opt_box_autoadd_i_64(self.fileSize, serializer);
-    sse_encode_opt_String(self.downloadUrl, serializer);
-    sse_encode_opt_String(self.checksum, serializer);
-    sse_encode_opt_String(self.license, serializer);
-  }
-
-  @protected
-  void sse_encode_dashboard_stats_dto(
-    DashboardStatsDto self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.reviewsToday, serializer);
-    sse_encode_u_32(self.streakDays, serializer);
-    sse_encode_u_32(self.dueCount, serializer);
-  }
-
-  @protected
-  void sse_encode_db_health_dto(DbHealthDto self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_64(self.chaptersCount, serializer);
-    sse_encode_i_64(self.versesCount, serializer);
-    sse_encode_i_64(self.wordsCount, serializer);
-    sse_encode_i_64(self.nodesCount, serializer);
-    sse_encode_i_64(self.edgesCount, serializer);
-    sse_encode_i_64(self.userMemoryCount, serializer);
-    sse_encode_bool(self.isHealthy, serializer);
-    sse_encode_list_String(self.issues, serializer);
-  }
-
-  @protected
-  void sse_encode_db_query_result_dto(
-    DbQueryResultDto self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_String(self.columns, serializer);
-    sse_encode_list_list_String(self.rows, serializer);
-  }
-
-  @protected
-  void sse_encode_debug_stats_dto(
-    DebugStatsDto self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.totalNodesCount, serializer);
-    sse_encode_u_32(self.totalEdgesCount, serializer);
-    sse_encode_u_32(self.dueCount, serializer);
-  }
-
-  @protected
-  void sse_encode_detailed_stats_dto(
-    DetailedStatsDto self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_activity_point_dto(self.activityHistory, serializer);
-    sse_encode_comprehension_dto(self.comprehension, serializer);
-  }
-
-  @protected
-  void sse_encode_echo_recall_metrics_dto(
-    EchoRecallMetricsDto self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_word_timing_dto(self.wordTimings, serializer);
-    sse_encode_u_64(self.totalDurationMs, serializer);
-    sse_encode_u_32(self.struggles, serializer);
-  }
-
-  @protected
-  void sse_encode_echo_recall_result_dto(
-    EchoRecallResultDto self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_energy_update_dto(self.energyUpdates, serializer);
-    sse_encode_u_32(self.wordsProcessed, serializer);
-    sse_encode_f_64(self.averageEnergy, serializer);
-  }
-
-  @protected
-  void sse_encode_echo_recall_state_dto(
-    EchoRecallStateDto self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_echo_recall_word_dto(self.words, serializer);
-  }
-
-  @protected
-  void sse_encode_echo_recall_stats_dto(
-    EchoRecallStatsDto self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.totalWords, serializer);
-    sse_encode_u_32(self.visibleCount, serializer);
-    sse_encode_u_32(self.obscuredCount, serializer);
-    sse_encode_u_32(self.hiddenCount, serializer);
-    sse_encode_f_64(self.averageEnergy, serializer);
-    sse_encode_f_64(self.masteryPercentage, serializer);
-  }
-
-  @protected
-  void sse_encode_echo_recall_word_dto(
-    EchoRecallWordDto self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.nodeId, serializer);
-    sse_encode_String(self.text, serializer);
-    sse_encode_word_visibility_dto(self.visibility, serializer);
-    sse_encode_f_64(self.energy, serializer);
-  }
-
-  @protected
-  void sse_encode_energy_snapshot_dto(
-    EnergySnapshotDto self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.nodeId, serializer);
-    sse_encode_f_64(self.energy, serializer);
-    sse_encode_opt_String(self.nodeType, serializer);
-    sse_encode_opt_String(self.knowledgeAxis, serializer);
-    sse_encode_list_node_energy_dto(self.neighbors, serializer);
-  }
-
-  @protected
-  void sse_encode_energy_update_dto(
-    EnergyUpdateDto self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.nodeId, serializer);
-    sse_encode_f_64(self.energy, serializer);
-  }
-
-  @protected
-  void sse_encode_exercise_data_dto(
-    ExerciseDataDto self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    switch (self) {
-      case ExerciseDataDto_Memorization(nodeId: final nodeId):
-        sse_encode_i_32(0, serializer);
-        sse_encode_String(nodeId, serializer);
-      case ExerciseDataDto_McqArToEn(
-        nodeId: final nodeId,
-        distractorNodeIds: final distractorNodeIds,
-      ):
-        sse_encode_i_32(1, serializer);
-        sse_encode_String(nodeId, serializer);
-        sse_encode_list_String(distractorNodeIds, serializer);
-      case ExerciseDataDto_McqEnToAr(
-        nodeId: final nodeId,
-        distractorNodeIds: final distractorNodeIds,
-      ):
-        sse_encode_i_32(2, serializer);
-        sse_encode_String(nodeId, serializer);
-        sse_encode_list_String(distractorNodeIds, serializer);
-      case ExerciseDataDto_Translation(nodeId: final nodeId):
-        sse_encode_i_32(3, serializer);
-        sse_encode_String(nodeId, serializer);
-      case ExerciseDataDto_ContextualTranslation(
-        nodeId: final nodeId,
-        verseKey: final verseKey,
-      ):
-        sse_encode_i_32(4, serializer);
-        sse_encode_String(nodeId, serializer);
-        sse_encode_String(verseKey, serializer);
-      case ExerciseDataDto_ClozeDeletion(
-        nodeId: final nodeId,
-        blankPosition: final blankPosition,
-      ):
-        sse_encode_i_32(5, serializer);
-        sse_encode_String(nodeId, serializer);
-        sse_encode_i_32(blankPosition, serializer);
-      case ExerciseDataDto_FirstLetterHint(
-        nodeId: final nodeId,
-        wordPosition: final wordPosition,
-      ):
-        sse_encode_i_32(6, serializer);
-        sse_encode_String(nodeId, serializer);
-        sse_encode_i_32(wordPosition, serializer);
-      case ExerciseDataDto_MissingWordMcq(
-        nodeId: final nodeId,
-        blankPosition: final blankPosition,
-        distractorNodeIds: final distractorNodeIds,
-      ):
-        sse_encode_i_32(7, serializer);
-        sse_encode_String(nodeId, serializer);
-        sse_encode_i_32(blankPosition, serializer);
-        sse_encode_list_String(distractorNodeIds, serializer);
-      case ExerciseDataDto_NextWordMcq(
-        nodeId: final nodeId,
-        contextPosition: final contextPosition,
-        distractorNodeIds: final distractorNodeIds,
-      ):
-        sse_encode_i_32(8, serializer);
-        sse_encode_String(nodeId, serializer);
-        sse_encode_i_32(contextPosition, serializer);
-        sse_encode_list_String(distractorNodeIds, serializer);
-      case ExerciseDataDto_FullVerseInput(nodeId: final nodeId):
-        sse_encode_i_32(9, serializer);
-        sse_encode_String(nodeId, serializer);
-      case ExerciseDataDto_AyahChain(
-        nodeId: final nodeId,
-        verseKeys: final verseKeys,
-        currentIndex: final currentIndex,
-        completedCount: final completedCount,
-      ):
-        sse_encode_i_32(10, serializer);
-        sse_encode_String(nodeId, serializer);
-        sse_encode_list_String(verseKeys, serializer);
-        sse_encode_usize(currentIndex, serializer);
-        sse_encode_usize(completedCount, serializer);
-      case ExerciseDataDto_FindMistake(
-        nodeId: final nodeId,
-        mistakePosition: final mistakePosition,
-        correctWordNodeId: final correctWordNodeId,
-        incorrectWordNodeId: final incorrectWordNodeId,
-      ):
-        sse_encode_i_32(11, serializer);
-        sse_encode_String(nodeId, serializer);
-        sse_encode_i_32(mistakePosition, serializer);
-        sse_encode_String(correctWordNodeId, serializer);
-        sse_encode_String(incorrectWordNodeId, serializer);
-      case ExerciseDataDto_AyahSequence(
-        nodeId: final nodeId,
-        correctSequence: final correctSequence,
-      ):
-        sse_encode_i_32(12, serializer);
-        sse_encode_String(nodeId, serializer);
-        sse_encode_list_String(correctSequence, serializer);
-      case ExerciseDataDto_SequenceRecall(
-        nodeId: final nodeId,
-        correctSequence: final correctSequence,
-        options: final options,
-      ):
-        sse_encode_i_32(13, serializer);
-        sse_encode_String(nodeId, serializer);
-        sse_encode_list_String(correctSequence, serializer);
-        sse_encode_list_list_String(options, serializer);
-      case ExerciseDataDto_FirstWordRecall(
-        nodeId: final nodeId,
-        verseKey: final verseKey,
-      ):
-        sse_encode_i_32(14, serializer);
-        sse_encode_String(nodeId, serializer);
-        sse_encode_String(verseKey, serializer);
-      case ExerciseDataDto_IdentifyRoot(nodeId: final nodeId, root: final root):
-        sse_encode_i_32(15, serializer);
-        sse_encode_String(nodeId, serializer);
-        sse_encode_String(root, serializer);
-      case ExerciseDataDto_ReverseCloze(
-        nodeId: final nodeId,
-        blankPosition: final blankPosition,
-      ):
-        sse_encode_i_32(16, serializer);
-        sse_encode_String(nodeId, serializer);
-        sse_encode_i_32(blankPosition, serializer);
-      case ExerciseDataDto_TranslatePhrase(
-        nodeId: final nodeId,
-        translatorId: final translatorId,
-      ):
-        sse_encode_i_32(17, serializer);
-        sse_encode_String(nodeId, serializer);
-        sse_encode_i_32(translatorId, serializer);
-      case ExerciseDataDto_PosTagging(
-        nodeId: final nodeId,
-        correctPos: final correctPos,
-        options: final options,
-      ):
-        sse_encode_i_32(18, serializer);
-        sse_encode_String(nodeId, serializer);
-        sse_encode_String(correctPos, serializer);
-        sse_encode_list_String(options, serializer);
-      case ExerciseDataDto_CrossVerseConnection(
-        nodeId: final nodeId,
-        relatedVerseIds: final relatedVerseIds,
-        connectionTheme: final connectionTheme,
-      ):
-        sse_encode_i_32(19, serializer);
-        sse_encode_String(nodeId, serializer);
-        sse_encode_list_String(relatedVerseIds, serializer);
-        sse_encode_String(connectionTheme, serializer);
-      case ExerciseDataDto_EchoRecall(
-        userId: final userId,
-        ayahNodeIds: final ayahNodeIds,
-      ):
-        sse_encode_i_32(20, serializer);
-        sse_encode_String(userId, serializer);
-        sse_encode_list_String(ayahNodeIds, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_f_64(double self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat64(self);
-  }
-
-  @protected
-  void sse_encode_hint_dto(HintDto self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.hintType, serializer);
-    sse_encode_opt_String(self.firstChar, serializer);
-    sse_encode_opt_String(self.lastChar, serializer);
-  }
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
-
-  @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putPlatformInt64(self);
-  }
-
-  @protected
-  void sse_encode_installed_package_dto(
-    InstalledPackageDto self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.packageId, serializer);
-    sse_encode_i_64(self.installedAt, serializer);
-    sse_encode_bool(self.enabled, serializer);
-  }
-
-  @protected
-  void sse_encode_language_dto(LanguageDto self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.code, serializer);
-    sse_encode_String(self.englishName, serializer);
-    sse_encode_String(self.nativeName, serializer);
-    sse_encode_String(self.direction, serializer);
-  }
-
-  @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_String(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_activity_point_dto(
-    List<ActivityPointDto> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_activity_point_dto(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_content_package_dto(
-    List<ContentPackageDto> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_content_package_dto(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_echo_recall_word_dto(
-    List<EchoRecallWordDto> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_echo_recall_word_dto(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_energy_update_dto(
-    List<EnergyUpdateDto> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_energy_update_dto(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_exercise_data_dto(
-    List<ExerciseDataDto> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_exercise_data_dto(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_installed_package_dto(
-    List<InstalledPackageDto> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_installed_package_dto(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_language_dto(
-    List<LanguageDto> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_language_dto(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_list_String(
-    List<List<String>> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_list_String(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_node_energy_dto(
-    List<NodeEnergyDto> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_node_energy_dto(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_node_search_dto(
-    List<NodeSearchDto> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_node_search_dto(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_loose(
-    List<int> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(
-      self is Uint8List ? self : Uint8List.fromList(self),
-    );
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
-  }
-
-  @protected
-  void sse_encode_list_record_string_string(
-    List<(String, String)> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_string_string(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_session_preview_dto(
-    List<SessionPreviewDto> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_session_preview_dto(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_surah_info(
-    List<SurahInfo> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_surah_info(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_sync_memory_state_dto(
-    List<SyncMemoryStateDto> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_sync_memory_state_dto(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_sync_session_dto(
-    List<SyncSessionDto> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_sync_session_dto(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_sync_session_item_dto(
-    List<SyncSessionItemDto> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_sync_session_item_dto(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_translator_dto(
-    List<TranslatorDto> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_translator_dto(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_verse_with_translation_dto(
-    List<VerseWithTranslationDto> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_verse_with_translation_dto(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_word_dto(List<WordDto> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_word_dto(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_word_timing_dto(
-    List<WordTimingDto> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_word_timing_dto(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_node_data(NodeData self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.id, serializer);
-    sse_encode_String(self.nodeType, serializer);
-    sse_encode_Map_String_String_None(self.metadata, serializer);
-  }
-
-  @protected
-  void sse_encode_node_energy_dto(
-    NodeEnergyDto self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.nodeId, serializer);
-    sse_encode_f_64(self.energy, serializer);
-    sse_encode_f_64(self.edgeWeight, serializer);
-  }
-
-  @protected
-  void sse_encode_node_filter_dto(
-    NodeFilterDto self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_opt_String(self.nodeType, serializer);
-    sse_encode_opt_box_autoadd_f_64(self.minEnergy, serializer);
-    sse_encode_opt_box_autoadd_f_64(self.maxEnergy, serializer);
-    sse_encode_opt_String(self.range, serializer);
-  }
-
-  @protected
-  void sse_encode_node_search_dto(
-    NodeSearchDto self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.nodeId, serializer);
-    sse_encode_String(self.nodeType, serializer);
-    sse_encode_opt_String(self.knowledgeAxis, serializer);
-    sse_encode_String(self.preview, serializer);
-  }
-
-  @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_String(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_f_64(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_hint_dto(
-    HintDto? self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_hint_dto(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_i_32(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_i_64(
-    PlatformInt64? self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_i_64(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_node_data(
-    NodeData? self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_node_data(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_session_dto(
-    SessionDto? self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_session_dto(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_session_item_dto(
-    SessionItemDto? self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_session_item_dto(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_translator_dto(
-    TranslatorDto? self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_translator_dto(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_verse_dto(
-    VerseDto? self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_verse_dto(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_word_dto(
-    WordDto? self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_word_dto(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_propagation_diagnostics_dto(
-    PropagationDiagnosticsDto self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_bool(self.nodeFound, serializer);
-    sse_encode_opt_String(self.nodeType, serializer);
-    sse_encode_u_32(self.totalEdges, serializer);
-    sse_encode_String(self.message, serializer);
-  }
-
-  @protected
-  void sse_encode_propagation_result_dto(
-    PropagationResultDto self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_node_energy_dto(self.before, serializer);
-    sse_encode_list_node_energy_dto(self.after, serializer);
-    sse_encode_propagation_diagnostics_dto(self.diagnostics, serializer);
-  }
-
-  @protected
-  void sse_encode_record_string_string(
-    (String, String) self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.$1, serializer);
-    sse_encode_String(self.$2, serializer);
-  }
-
-  @protected
-  void sse_encode_session_dto(SessionDto self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.id, serializer);
-    sse_encode_String(self.userId, serializer);
-    sse_encode_String(self.goalId, serializer);
-    sse_encode_i_64(self.startedAt, serializer);
-    sse_encode_opt_box_autoadd_i_64(self.completedAt, serializer);
-    sse_encode_i_32(self.itemsCount, serializer);
-    sse_encode_i_32(self.itemsCompleted, serializer);
-  }
-
-  @protected
-  void sse_encode_session_item_dto(
-    SessionItemDto self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.sessionId, serializer);
-    sse_encode_i_32(self.position, serializer);
-    sse_encode_String(self.nodeId, serializer);
-    sse_encode_String(self.exerciseType, serializer);
-    sse_encode_exercise_data_dto(self.exercise, serializer);
-  }
-
-  @protected
-  void sse_encode_session_preview_dto(
-    SessionPreviewDto self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.nodeId, serializer);
-    sse_encode_String(self.nodeType, serializer);
-    sse_encode_String(self.previewText, serializer);
-    sse_encode_f_64(self.energy, serializer);
-    sse_encode_f_64(self.priorityScore, serializer);
-  }
-
-  @protected
-  void sse_encode_session_summary_dto(
-    SessionSummaryDto self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.sessionId, serializer);
-    sse_encode_i_32(self.itemsCount, serializer);
-    sse_encode_i_32(self.itemsCompleted, serializer);
-    sse_encode_i_64(self.durationMs, serializer);
-    sse_encode_i_32(self.againCount, serializer);
-    sse_encode_i_32(self.hardCount, serializer);
-    sse_encode_i_32(self.goodCount, serializer);
-    sse_encode_i_32(self.easyCount, serializer);
-  }
-
-  @protected
-  void sse_encode_surah_info(SurahInfo self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.number, serializer);
-    sse_encode_String(self.name, serializer);
-    sse_encode_String(self.nameArabic, serializer);
-    sse_encode_String(self.nameTransliteration, serializer);
-    sse_encode_String(self.nameTranslation, serializer);
-    sse_encode_i_32(self.verseCount, serializer);
-    sse_encode_opt_String(self.revelationPlace, serializer);
-  }
-
-  @protected
-  void sse_encode_sync_memory_state_dto(
-    SyncMemoryStateDto self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_64(self.nodeId, serializer);
-    sse_encode_f_64(self.energy, serializer);
-    sse_encode_opt_box_autoadd_f_64(self.fsrsStability, serializer);
-    sse_encode_opt_box_autoadd_f_64(self.fsrsDifficulty, serializer);
-    sse_encode_opt_box_autoadd_i_64(self.lastReviewedAt, serializer);
-    sse_encode_opt_box_autoadd_i_64(self.nextReviewAt, serializer);
-    sse_encode_i_64(self.clientUpdatedAt, serializer);
-  }
-
-  @protected
-  void sse_encode_sync_session_dto(
-    SyncSessionDto self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.id, serializer);
-    sse_encode_opt_String(self.goalId, serializer);
-    sse_encode_i_64(self.startedAt, serializer);
-    sse_encode_opt_box_autoadd_i_64(self.completedAt, serializer);
-    sse_encode_i_32(self.itemsCompleted, serializer);
-    sse_encode_i_64(self.clientUpdatedAt, serializer);
-  }
-
-  @protected
-  void sse_encode_sync_session_item_dto(
-    SyncSessionItemDto self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.id, serializer);
-    sse_encode_String(self.sessionId, serializer);
-    sse_encode_i_64(self.nodeId, serializer);
-    sse_encode_String(self.exerciseType, serializer);
-    sse_encode_opt_box_autoadd_i_32(self.grade, serializer);
-    sse_encode_opt_box_autoadd_i_64(self.durationMs, serializer);
-    sse_encode_opt_box_autoadd_i_64(self.completedAt, serializer);
-    sse_encode_i_64(self.clientUpdatedAt, serializer);
-  }
-
-  @protected
-  void sse_encode_translator_dto(TranslatorDto self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.id, serializer);
-    sse_encode_String(self.slug, serializer);
-    sse_encode_String(self.fullName, serializer);
-    sse_encode_String(self.languageCode, serializer);
-    sse_encode_opt_String(self.description, serializer);
-    sse_encode_opt_String(self.license, serializer);
-  }
-
-  @protected
-  void sse_encode_u_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint32(self);
-  }
-
-  @protected
-  void sse_encode_u_64(BigInt self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
-  }
-
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
-  }
-
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
-  }
-
-  @protected
-  void sse_encode_verse_dto(VerseDto self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.key, serializer);
-    sse_encode_String(self.textUthmani, serializer);
-    sse_encode_i_32(self.chapterNumber, serializer);
-    sse_encode_i_32(self.verseNumber, serializer);
-  }
-
-  @protected
-  void sse_encode_verse_with_translation_dto(
-    VerseWithTranslationDto self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.key, serializer);
-    sse_encode_String(self.textUthmani, serializer);
-    sse_encode_opt_String(self.translation, serializer);
-    sse_encode_i_32(self.number, serializer);
-  }
-
-  @protected
-  void sse_encode_word_dto(WordDto self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.id, serializer);
-    sse_encode_String(self.textUthmani, serializer);
-    sse_encode_String(self.verseKey, serializer);
-    sse_encode_i_32(self.position, serializer);
-  }
-
-  @protected
-  void sse_encode_word_timing_dto(
-    WordTimingDto self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.wordNodeId, serializer);
-    sse_encode_u_64(self.durationMs, serializer);
-  }
-
-  @protected
-  void sse_encode_word_visibility_dto(
-    WordVisibilityDto self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.visibilityType, serializer);
-    sse_encode_opt_box_autoadd_hint_dto(self.hint, serializer);
-    sse_encode_opt_box_autoadd_f_64(self.coverage, serializer);
-  }
-}
-
-@sealed
-class AppStateImpl extends RustOpaque implements AppState {
-  // Not to be used by end users
-  AppStateImpl.frbInternalDcoDecode(List<dynamic> wire)
-    : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  AppStateImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_AppState,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_AppState,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_AppStatePtr,
-  );
-
-  ArcContentRepository get contentRepo => RustLib.instance.api
-      .crateApiAppStateAutoAccessorGetContentRepo(that: this);
-
-  ArcExerciseService get exerciseService => RustLib.instance.api
-      .crateApiAppStateAutoAccessorGetExerciseService(that: this);
-
-  ArcLearningService get learningService => RustLib.instance.api
-      .crateApiAppStateAutoAccessorGetLearningService(that: this);
-
-  ArcSessionService get sessionService => RustLib.instance.api
-      .crateApiAppStateAutoAccessorGetSessionService(that: this);
-
-  ArcUserRepository get userRepo =>
-      RustLib.instance.api.crateApiAppStateAutoAccessorGetUserRepo(that: this);
-
-  set contentRepo(ArcContentRepository contentRepo) =>
-      RustLib.instance.api.crateApiAppStateAutoAccessorSetContentRepo(
-        that: this,
-        contentRepo: contentRepo,
-      );
-
-  set exerciseService(ArcExerciseService exerciseService) =>
-      RustLib.instance.api.crateApiAppStateAutoAccessorSetExerciseService(
-        that: this,
-        exerciseService: exerciseService,
-      );
-
-  set learningService(ArcLearningService learningService) =>
-      RustLib.instance.api.crateApiAppStateAutoAccessorSetLearningService(
-        that: this,
-        learningService: learningService,
-      );
-
-  set sessionService(ArcSessionService sessionService) =>
-      RustLib.instance.api.crateApiAppStateAutoAccessorSetSessionService(
-        that: this,
-        sessionService: sessionService,
-      );
-
-  set userRepo(ArcUserRepository userRepo) => RustLib.instance.api
-      .crateApiAppStateAutoAccessorSetUserRepo(that: this, userRepo: userRepo);
-}
-
-@sealed
-class ArcContentRepositoryImpl extends RustOpaque
-    implements ArcContentRepository {
-  // Not to be used by end users
-  ArcContentRepositoryImpl.frbInternalDcoDecode(List<dynamic> wire)
-    : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  ArcContentRepositoryImpl.frbInternalSseDecode(
-    BigInt ptr,
-    int externalSizeOnNative,
-  ) : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount: RustLib
-        .instance
-        .api
-        .rust_arc_increment_strong_count_ArcContentRepository,
-    rustArcDecrementStrongCount: RustLib
-        .instance
-        .api
-        .rust_arc_decrement_strong_count_ArcContentRepository,
-    rustArcDecrementStrongCountPtr: RustLib
-        .instance
-        .api
-        .rust_arc_decrement_strong_count_ArcContentRepositoryPtr,
-  );
-}
-
-@sealed
-class ArcExerciseServiceImpl extends RustOpaque implements ArcExerciseService {
-  // Not to be used by end users
-  ArcExerciseServiceImpl.frbInternalDcoDecode(List<dynamic> wire)
-    : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  ArcExerciseServiceImpl.frbInternalSseDecode(
-    BigInt ptr,
-    int externalSizeOnNative,
-  ) : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_ArcExerciseService,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_ArcExerciseService,
-    rustArcDecrementStrongCountPtr: RustLib
-        .instance
-        .api
-        .rust_arc_decrement_strong_count_ArcExerciseServicePtr,
-  );
-}
-
-@sealed
-class ArcLearningServiceImpl extends RustOpaque implements ArcLearningService {
-  // Not to be used by end users
-  ArcLearningServiceImpl.frbInternalDcoDecode(List<dynamic> wire)
-    : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  ArcLearningServiceImpl.frbInternalSseDecode(
-    BigInt ptr,
-    int externalSizeOnNative,
-  ) : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_ArcLearningService,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_ArcLearningService,
-    rustArcDecrementStrongCountPtr: RustLib
-        .instance
-        .api
-        .rust_arc_decrement_strong_count_ArcLearningServicePtr,
-  );
-}
-
-@sealed
-class ArcSessionServiceImpl extends RustOpaque implements ArcSessionService {
-  // Not to be used by end users
-  ArcSessionServiceImpl.frbInternalDcoDecode(List<dynamic> wire)
-    : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  ArcSessionServiceImpl.frbInternalSseDecode(
-    BigInt ptr,
-    int externalSizeOnNative,
-  ) : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_ArcSessionService,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_ArcSessionService,
-    rustArcDecrementStrongCountPtr: RustLib
-        .instance
-        .api
-        .rust_arc_decrement_strong_count_ArcSessionServicePtr,
-  );
-}
-
-@sealed
-class ArcUserRepositoryImpl extends RustOpaque implements ArcUserRepository {
-  // Not to be used by end users
-  ArcUserRepositoryImpl.frbInternalDcoDecode(List<dynamic> wire)
-    : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  ArcUserRepositoryImpl.frbInternalSseDecode(
-    BigInt ptr,
-    int externalSizeOnNative,
-  ) : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_ArcUserRepository,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_ArcUserRepository,
-    rustArcDecrementStrongCountPtr: RustLib
-        .instance
-        .api
-        .rust_arc_decrement_strong_count_ArcUserRepositoryPtr,
-  );
-}
+        
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_AppState => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_AppState => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_ArcExerciseService => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseService;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_ArcExerciseService => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseService;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_ArcLearningService => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningService;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_ArcLearningService => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningService;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_ArcSessionService => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionService;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_ArcSessionService => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionService;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_ArcContentRepository => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepository;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_ArcContentRepository => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepository;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_ArcUserRepository => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepository;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_ArcUserRepository => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepository;
+
+
+
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AnyhowException(raw as String); }
+
+@protected AppState dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AppStateImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected ArcExerciseService dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseService(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ArcExerciseServiceImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected ArcLearningService dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningService(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ArcLearningServiceImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected ArcSessionService dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionService(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ArcSessionServiceImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected ArcContentRepository dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepository(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ArcContentRepositoryImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected ArcUserRepository dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepository(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ArcUserRepositoryImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected AppState dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AppStateImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected AppState dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AppStateImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Map<String, String> dco_decode_Map_String_String_None(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Map.fromEntries(dco_decode_list_record_string_string(raw).map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected AppState dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AppStateImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected ArcExerciseService dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseService(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ArcExerciseServiceImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected ArcLearningService dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningService(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ArcLearningServiceImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected ArcSessionService dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionService(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ArcSessionServiceImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected ArcContentRepository dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepository(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ArcContentRepositoryImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected ArcUserRepository dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepository(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ArcUserRepositoryImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected String dco_decode_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as String; }
+
+@protected ActivityPointDto dco_decode_activity_point_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ActivityPointDto(date: dco_decode_String(arr[0]),
+count: dco_decode_i_32(arr[1]),); }
+
+@protected AyahChainStateDto dco_decode_ayah_chain_state_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return AyahChainStateDto(nodeId: dco_decode_String(arr[0]),
+currentVerseKey: dco_decode_opt_String(arr[1]),
+completedCount: dco_decode_u_32(arr[2]),
+totalVerses: dco_decode_u_32(arr[3]),
+isComplete: dco_decode_bool(arr[4]),
+mistakeMade: dco_decode_bool(arr[5]),
+lastGrade: dco_decode_opt_box_autoadd_u_8(arr[6]),); }
+
+@protected AyahJunctionDto dco_decode_ayah_junction_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return AyahJunctionDto(fromVerse: dco_decode_String(arr[0]),
+toVerse: dco_decode_String(arr[1]),
+closingWord: dco_decode_String(arr[2]),
+openingWord: dco_decode_String(arr[3]),
+strength: dco_decode_f_64(arr[4]),); }
+
+@protected bool dco_decode_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected AyahChainStateDto dco_decode_box_autoadd_ayah_chain_state_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_ayah_chain_state_dto(raw); }
+
+@protected DistractorStrategyDto dco_decode_box_autoadd_distractor_strategy_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_distractor_strategy_dto(raw); }
+
+@protected EchoRecallMetricsDto dco_decode_box_autoadd_echo_recall_metrics_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_echo_recall_metrics_dto(raw); }
+
+@protected EchoRecallStateDto dco_decode_box_autoadd_echo_recall_state_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_echo_recall_state_dto(raw); }
+
+@protected ExerciseDataDto dco_decode_box_autoadd_exercise_data_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_exercise_data_dto(raw); }
+
+@protected ExerciseHintDto dco_decode_box_autoadd_exercise_hint_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_exercise_hint_dto(raw); }
+
+@protected double dco_decode_box_autoadd_f_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected double dco_decode_box_autoadd_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected HintDto dco_decode_box_autoadd_hint_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_hint_dto(raw); }
+
+@protected int dco_decode_box_autoadd_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_i_64(raw); }
+
+@protected NodeData dco_decode_box_autoadd_node_data(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_node_data(raw); }
+
+@protected NodeFilterDto dco_decode_box_autoadd_node_filter_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_node_filter_dto(raw); }
+
+@protected SessionDto dco_decode_box_autoadd_session_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_session_dto(raw); }
+
+@protected SessionItemDto dco_decode_box_autoadd_session_item_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_session_item_dto(raw); }
+
+@protected TafsirPassageDto dco_decode_box_autoadd_tafsir_passage_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_tafsir_passage_dto(raw); }
+
+@protected TranslatorDto dco_decode_box_autoadd_translator_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_translator_dto(raw); }
+
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_u_64(raw); }
+
+@protected int dco_decode_box_autoadd_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected VerseAudioDto dco_decode_box_autoadd_verse_audio_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_verse_audio_dto(raw); }
+
+@protected VerseDto dco_decode_box_autoadd_verse_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_verse_dto(raw); }
+
+@protected WordAudioDto dco_decode_box_autoadd_word_audio_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_word_audio_dto(raw); }
+
+@protected WordDto dco_decode_box_autoadd_word_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_word_dto(raw); }
+
+@protected ComprehensionDto dco_decode_comprehension_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return ComprehensionDto(memorization: dco_decode_f_64(arr[0]),
+understanding: dco_decode_f_64(arr[1]),
+context: dco_decode_f_64(arr[2]),); }
+
+@protected ContentPackageDto dco_decode_content_package_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 11) throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+                return ContentPackageDto(packageId: dco_decode_String(arr[0]),
+packageType: dco_decode_String(arr[1]),
+name: dco_decode_String(arr[2]),
+languageCode: dco_decode_opt_String(arr[3]),
+author: dco_decode_opt_String(arr[4]),
+version: dco_decode_String(arr[5]),
+description: dco_decode_opt_String(arr[6]),
+fileSize: dco_decode_opt_box_autoadd_i_64(arr[7]),
+downloadUrl: dco_decode_opt_String(arr[8]),
+checksum: dco_decode_opt_String(arr[9]),
+license: dco_decode_opt_String(arr[10]),); }
+
+@protected DashboardStatsDto dco_decode_dashboard_stats_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return DashboardStatsDto(reviewsToday: dco_decode_u_32(arr[0]),
+streakDays: dco_decode_u_32(arr[1]),
+dueCount: dco_decode_u_32(arr[2]),); }
+
+@protected DbHealthDto dco_decode_db_health_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return DbHealthDto(chaptersCount: dco_decode_i_64(arr[0]),
+versesCount: dco_decode_i_64(arr[1]),
+wordsCount: dco_decode_i_64(arr[2]),
+nodesCount: dco_decode_i_64(arr[3]),
+edgesCount: dco_decode_i_64(arr[4]),
+userMemoryCount: dco_decode_i_64(arr[5]),
+isHealthy: dco_decode_bool(arr[6]),
+issues: dco_decode_list_String(arr[7]),); }
+
+@protected DbQueryResultDto dco_decode_db_query_result_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return DbQueryResultDto(columns: dco_decode_list_String(arr[0]),
+rows: dco_decode_list_list_String(arr[1]),); }
+
+@protected DebugStatsDto dco_decode_debug_stats_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return DebugStatsDto(totalNodesCount: dco_decode_u_32(arr[0]),
+totalEdgesCount: dco_decode_u_32(arr[1]),
+dueCount: dco_decode_u_32(arr[2]),); }
+
+@protected DetailedStatsDto dco_decode_detailed_stats_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return DetailedStatsDto(activityHistory: dco_decode_list_activity_point_dto(arr[0]),
+comprehension: dco_decode_comprehension_dto(arr[1]),); }
+
+@protected DistractorStrategyDto dco_decode_distractor_strategy_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return DistractorStrategyDto(difficulty: dco_decode_String(arr[0]),
+signals: dco_decode_list_String(arr[1]),); }
+
+@protected EchoRecallMetricsDto dco_decode_echo_recall_metrics_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return EchoRecallMetricsDto(wordTimings: dco_decode_list_word_timing_dto(arr[0]),
+totalDurationMs: dco_decode_u_64(arr[1]),
+struggles: dco_decode_u_32(arr[2]),); }
+
+@protected EchoRecallResultDto dco_decode_echo_recall_result_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return EchoRecallResultDto(energyUpdates: dco_decode_list_energy_update_dto(arr[0]),
+wordsProcessed: dco_decode_u_32(arr[1]),
+averageEnergy: dco_decode_f_64(arr[2]),); }
+
+@protected EchoRecallStateDto dco_decode_echo_recall_state_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+                return EchoRecallStateDto(words: dco_decode_list_echo_recall_word_dto(arr[0]),); }
+
+@protected EchoRecallStatsDto dco_decode_echo_recall_stats_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return EchoRecallStatsDto(totalWords: dco_decode_u_32(arr[0]),
+visibleCount: dco_decode_u_32(arr[1]),
+obscuredCount: dco_decode_u_32(arr[2]),
+hiddenCount: dco_decode_u_32(arr[3]),
+averageEnergy: dco_decode_f_64(arr[4]),
+masteryPercentage: dco_decode_f_64(arr[5]),); }
+
+@protected EchoRecallWordDto dco_decode_echo_recall_word_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return EchoRecallWordDto(nodeId: dco_decode_String(arr[0]),
+text: dco_decode_String(arr[1]),
+visibility: dco_decode_word_visibility_dto(arr[2]),
+energy: dco_decode_f_64(arr[3]),); }
+
+@protected EnergySnapshotDto dco_decode_energy_snapshot_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return EnergySnapshotDto(nodeId: dco_decode_String(arr[0]),
+energy: dco_decode_f_64(arr[1]),
+nodeType: dco_decode_opt_String(arr[2]),
+knowledgeAxis: dco_decode_opt_String(arr[3]),
+neighbors: dco_decode_list_node_energy_dto(arr[4]),); }
+
+@protected EnergyUpdateDto dco_decode_energy_update_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return EnergyUpdateDto(nodeId: dco_decode_String(arr[0]),
+energy: dco_decode_f_64(arr[1]),); }
+
+@protected ExerciseDataDto dco_decode_exercise_data_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return ExerciseDataDto_Memorization(nodeId: dco_decode_String(raw[1]),seed: dco_decode_u_64(raw[2]),);
+case 1: return ExerciseDataDto_McqArToEn(nodeId: dco_decode_String(raw[1]),distractorNodeIds: dco_decode_list_String(raw[2]),distractorStrategy: dco_decode_distractor_strategy_dto(raw[3]),seed: dco_decode_u_64(raw[4]),);
+case 2: return ExerciseDataDto_McqEnToAr(nodeId: dco_decode_String(raw[1]),distractorNodeIds: dco_decode_list_String(raw[2]),distractorStrategy: dco_decode_distractor_strategy_dto(raw[3]),seed: dco_decode_u_64(raw[4]),);
+case 3: return ExerciseDataDto_Translation(nodeId: dco_decode_String(raw[1]),seed: dco_decode_u_64(raw[2]),);
+case 4: return ExerciseDataDto_ContextualTranslation(nodeId: dco_decode_String(raw[1]),verseKey: dco_decode_String(raw[2]),seed: dco_decode_u_64(raw[3]),);
+case 5: return ExerciseDataDto_ClozeDeletion(nodeId: dco_decode_String(raw[1]),blankPosition: dco_decode_i_32(raw[2]),seed: dco_decode_u_64(raw[3]),);
+case 6: return ExerciseDataDto_FirstLetterHint(nodeId: dco_decode_String(raw[1]),wordPosition: dco_decode_i_32(raw[2]),seed: dco_decode_u_64(raw[3]),);
+case 7: return ExerciseDataDto_MissingWordMcq(nodeId: dco_decode_String(raw[1]),blankPosition: dco_decode_i_32(raw[2]),distractorNodeIds: dco_decode_list_String(raw[3]),distractorStrategy: dco_decode_distractor_strategy_dto(raw[4]),seed: dco_decode_u_64(raw[5]),);
+case 8: return ExerciseDataDto_NextWordMcq(nodeId: dco_decode_String(raw[1]),contextPosition: dco_decode_i_32(raw[2]),distractorNodeIds: dco_decode_list_String(raw[3]),distractorStrategy: dco_decode_distractor_strategy_dto(raw[4]),seed: dco_decode_u_64(raw[5]),);
+case 9: return ExerciseDataDto_FullVerseInput(nodeId: dco_decode_String(raw[1]),seed: dco_decode_u_64(raw[2]),);
+case 10: return ExerciseDataDto_AyahChain(nodeId: dco_decode_String(raw[1]),verseKeys: dco_decode_list_String(raw[2]),currentIndex: dco_decode_usize(raw[3]),completedCount: dco_decode_usize(raw[4]),seed: dco_decode_u_64(raw[5]),);
+case 11: return ExerciseDataDto_FindMistake(nodeId: dco_decode_String(raw[1]),mistakePosition: dco_decode_i_32(raw[2]),correctWordNodeId: dco_decode_String(raw[3]),incorrectWordNodeId: dco_decode_String(raw[4]),seed: dco_decode_u_64(raw[5]),);
+case 12: return ExerciseDataDto_AyahSequence(nodeId: dco_decode_String(raw[1]),correctSequence: dco_decode_list_String(raw[2]),seed: dco_decode_u_64(raw[3]),);
+case 13: return ExerciseDataDto_SequenceRecall(nodeId: dco_decode_String(raw[1]),correctSequence: dco_decode_list_String(raw[2]),options: dco_decode_list_list_String(raw[3]),seed: dco_decode_u_64(raw[4]),);
+case 14: return ExerciseDataDto_FirstWordRecall(nodeId: dco_decode_String(raw[1]),verseKey: dco_decode_String(raw[2]),seed: dco_decode_u_64(raw[3]),);
+case 15: return ExerciseDataDto_IdentifyRoot(nodeId: dco_decode_String(raw[1]),root: dco_decode_String(raw[2]),seed: dco_decode_u_64(raw[3]),);
+case 16: return ExerciseDataDto_ReverseCloze(nodeId: dco_decode_String(raw[1]),blankPosition: dco_decode_i_32(raw[2]),seed: dco_decode_u_64(raw[3]),);
+case 17: return ExerciseDataDto_TranslatePhrase(nodeId: dco_decode_String(raw[1]),translatorId: dco_decode_i_32(raw[2]),seed: dco_decode_u_64(raw[3]),);
+case 18: return ExerciseDataDto_PosTagging(nodeId: dco_decode_String(raw[1]),correctPos: dco_decode_String(raw[2]),options: dco_decode_list_String(raw[3]),seed: dco_decode_u_64(raw[4]),);
+case 19: return ExerciseDataDto_GrammarFeature(nodeId: dco_decode_String(raw[1]),feature: dco_decode_String(raw[2]),prompt: dco_decode_String(raw[3]),correctValue: dco_decode_String(raw[4]),options: dco_decode_list_String(raw[5]),seed: dco_decode_u_64(raw[6]),);
+case 20: return ExerciseDataDto_CrossVerseConnection(nodeId: dco_decode_String(raw[1]),relatedVerseIds: dco_decode_list_String(raw[2]),connectionTheme: dco_decode_String(raw[3]),seed: dco_decode_u_64(raw[4]),);
+case 21: return ExerciseDataDto_EchoRecall(userId: dco_decode_String(raw[1]),ayahNodeIds: dco_decode_list_String(raw[2]),seed: dco_decode_u_64(raw[3]),);
+case 22: return ExerciseDataDto_Mutashabihat(nodeId: dco_decode_String(raw[1]),verseKey: dco_decode_String(raw[2]),promptWordCount: dco_decode_i_32(raw[3]),optionVerseKeys: dco_decode_list_String(raw[4]),differingPositions: dco_decode_list_prim_i_32_strict(raw[5]),seed: dco_decode_u_64(raw[6]),);
+case 23: return ExerciseDataDto_WordOrder(nodeId: dco_decode_String(raw[1]),correctSequence: dco_decode_list_String(raw[2]),shuffledWordNodeIds: dco_decode_list_String(raw[3]),tileTexts: dco_decode_list_String(raw[4]),seed: dco_decode_u_64(raw[5]),);
+case 24: return ExerciseDataDto_IdentifySurah(nodeId: dco_decode_String(raw[1]),verseKey: dco_decode_String(raw[2]),correctChapter: dco_decode_i_32(raw[3]),chapterOptions: dco_decode_list_prim_i_32_strict(raw[4]),seed: dco_decode_u_64(raw[5]),);
+case 25: return ExerciseDataDto_IdentifyVerseNumber(nodeId: dco_decode_String(raw[1]),verseKey: dco_decode_String(raw[2]),correctVerseNumber: dco_decode_i_32(raw[3]),verseNumberOptions: dco_decode_list_prim_i_32_strict(raw[4]),seed: dco_decode_u_64(raw[5]),);
+case 26: return ExerciseDataDto_IdentifyPageHalf(nodeId: dco_decode_String(raw[1]),verseKey: dco_decode_String(raw[2]),page: dco_decode_i_32(raw[3]),half: dco_decode_i_32(raw[4]),seed: dco_decode_u_64(raw[5]),);
+case 27: return ExerciseDataDto_TransliterationToArabic(nodeId: dco_decode_String(raw[1]),distractorNodeIds: dco_decode_list_String(raw[2]),distractorStrategy: dco_decode_distractor_strategy_dto(raw[3]),seed: dco_decode_u_64(raw[4]),);
+case 28: return ExerciseDataDto_ArabicToTransliteration(nodeId: dco_decode_String(raw[1]),verseKey: dco_decode_String(raw[2]),seed: dco_decode_u_64(raw[3]),);
+case 29: return ExerciseDataDto_RootFamily(nodeId: dco_decode_String(raw[1]),rootId: dco_decode_String(raw[2]),verseKeys: dco_decode_list_String(raw[3]),familyWordNodeIds: dco_decode_list_String(raw[4]),seed: dco_decode_u_64(raw[5]),);
+case 30: return ExerciseDataDto_LemmaMeaning(nodeId: dco_decode_String(raw[1]),rootId: dco_decode_String(raw[2]),lemmaIds: dco_decode_list_String(raw[3]),meaningLemmaIds: dco_decode_list_String(raw[4]),seed: dco_decode_u_64(raw[5]),);
+case 31: return ExerciseDataDto_RecitePage(nodeId: dco_decode_String(raw[1]),page: dco_decode_i_32(raw[2]),verseKeys: dco_decode_list_String(raw[3]),firstWordNodeId: dco_decode_String(raw[4]),lastWordNodeId: dco_decode_String(raw[5]),seed: dco_decode_u_64(raw[6]),);
+case 32: return ExerciseDataDto_PageFirstWord(nodeId: dco_decode_String(raw[1]),page: dco_decode_i_32(raw[2]),firstWordNodeId: dco_decode_String(raw[3]),distractorNodeIds: dco_decode_list_String(raw[4]),seed: dco_decode_u_64(raw[5]),);
+case 33: return ExerciseDataDto_IdentifyVerseLine(nodeId: dco_decode_String(raw[1]),verseKey: dco_decode_String(raw[2]),page: dco_decode_i_32(raw[3]),correctLine: dco_decode_i_32(raw[4]),lineOptions: dco_decode_list_prim_i_32_strict(raw[5]),seed: dco_decode_u_64(raw[6]),);
+case 34: return ExerciseDataDto_ListenIdentifyVerse(nodeId: dco_decode_String(raw[1]),reciterId: dco_decode_i_32(raw[2]),verseKey: dco_decode_String(raw[3]),firstPosition: dco_decode_i_32(raw[4]),lastPosition: dco_decode_i_32(raw[5]),startMs: dco_decode_i_64(raw[6]),endMs: dco_decode_i_64(raw[7]),optionVerseKeys: dco_decode_list_String(raw[8]),seed: dco_decode_u_64(raw[9]),);
+case 35: return ExerciseDataDto_ListenContinue(nodeId: dco_decode_String(raw[1]),reciterId: dco_decode_i_32(raw[2]),verseKey: dco_decode_String(raw[3]),firstPosition: dco_decode_i_32(raw[4]),lastPosition: dco_decode_i_32(raw[5]),startMs: dco_decode_i_64(raw[6]),endMs: dco_decode_i_64(raw[7]),distractorNodeIds: dco_decode_list_String(raw[8]),distractorStrategy: dco_decode_distractor_strategy_dto(raw[9]),seed: dco_decode_u_64(raw[10]),);
+case 36: return ExerciseDataDto_ListenIdentifyWord(nodeId: dco_decode_String(raw[1]),reciterId: dco_decode_i_32(raw[2]),verseKey: dco_decode_String(raw[3]),position: dco_decode_i_32(raw[4]),startMs: dco_decode_opt_box_autoadd_i_64(raw[5]),endMs: dco_decode_opt_box_autoadd_i_64(raw[6]),distractorNodeIds: dco_decode_list_String(raw[7]),distractorStrategy: dco_decode_distractor_strategy_dto(raw[8]),seed: dco_decode_u_64(raw[9]),);
+case 37: return ExerciseDataDto_IdentifyTajweedRule(nodeId: dco_decode_String(raw[1]),wordNodeId: dco_decode_String(raw[2]),startChar: dco_decode_i_32(raw[3]),endChar: dco_decode_i_32(raw[4]),correctRule: dco_decode_String(raw[5]),ruleOptions: dco_decode_list_String(raw[6]),optionNames: dco_decode_list_String(raw[7]),seed: dco_decode_u_64(raw[8]),);
+case 38: return ExerciseDataDto_FindTajweedRule(nodeId: dco_decode_String(raw[1]),rule: dco_decode_String(raw[2]),ruleName: dco_decode_String(raw[3]),ruleWordNodeIds: dco_decode_list_String(raw[4]),seed: dco_decode_u_64(raw[5]),);
+case 39: return ExerciseDataDto_TafsirKeyLesson(nodeId: dco_decode_String(raw[1]),tafsirId: dco_decode_i_32(raw[2]),passageKey: dco_decode_String(raw[3]),optionPassageKeys: dco_decode_list_String(raw[4]),seed: dco_decode_u_64(raw[5]),);
+case 40: return ExerciseDataDto_RevelationOccasion(nodeId: dco_decode_String(raw[1]),tafsirId: dco_decode_i_32(raw[2]),passageKey: dco_decode_String(raw[3]),optionVerseKeys: dco_decode_list_String(raw[4]),seed: dco_decode_u_64(raw[5]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected ExerciseHintDto dco_decode_exercise_hint_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return ExerciseHintDto(level: dco_decode_u_8(arr[0]),
+levelName: dco_decode_String(arr[1]),
+text: dco_decode_String(arr[2]),
+maxGrade: dco_decode_u_8(arr[3]),); }
+
+@protected double dco_decode_f_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected double dco_decode_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected HintDto dco_decode_hint_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return HintDto(hintType: dco_decode_String(arr[0]),
+firstChar: dco_decode_opt_String(arr[1]),
+lastChar: dco_decode_opt_String(arr[2]),); }
+
+@protected int dco_decode_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected PlatformInt64 dco_decode_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeI64(raw); }
+
+@protected InstalledPackageDto dco_decode_installed_package_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return InstalledPackageDto(packageId: dco_decode_String(arr[0]),
+installedAt: dco_decode_i_64(arr[1]),
+enabled: dco_decode_bool(arr[2]),); }
+
+@protected LanguageDto dco_decode_language_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return LanguageDto(code: dco_decode_String(arr[0]),
+englishName: dco_decode_String(arr[1]),
+nativeName: dco_decode_String(arr[2]),
+direction: dco_decode_String(arr[3]),); }
+
+@protected List<String> dco_decode_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_String).toList(); }
+
+@protected List<ActivityPointDto> dco_decode_list_activity_point_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_activity_point_dto).toList(); }
+
+@protected List<AyahJunctionDto> dco_decode_list_ayah_junction_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_ayah_junction_dto).toList(); }
+
+@protected List<ContentPackageDto> dco_decode_list_content_package_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_content_package_dto).toList(); }
+
+@protected List<EchoRecallWordDto> dco_decode_list_echo_recall_word_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_echo_recall_word_dto).toList(); }
+
+@protected List<EnergyUpdateDto> dco_decode_list_energy_update_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_energy_update_dto).toList(); }
+
+@protected List<ExerciseDataDto> dco_decode_list_exercise_data_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_exercise_data_dto).toList(); }
+
+@protected List<ExerciseHintDto> dco_decode_list_exercise_hint_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_exercise_hint_dto).toList(); }
+
+@protected List<InstalledPackageDto> dco_decode_list_installed_package_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_installed_package_dto).toList(); }
+
+@protected List<LanguageDto> dco_decode_list_language_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_language_dto).toList(); }
+
+@protected List<List<String>> dco_decode_list_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_list_String).toList(); }
+
+@protected List<NodeEnergyDto> dco_decode_list_node_energy_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_node_energy_dto).toList(); }
+
+@protected List<NodeSearchDto> dco_decode_list_node_search_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_node_search_dto).toList(); }
+
+@protected Int32List dco_decode_list_prim_i_32_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Int32List; }
+
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as List<int>; }
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint8List; }
+
+@protected List<RecitationJumpDto> dco_decode_list_recitation_jump_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_recitation_jump_dto).toList(); }
+
+@protected List<RecitedWordDto> dco_decode_list_recited_word_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_recited_word_dto).toList(); }
+
+@protected List<ReciterDto> dco_decode_list_reciter_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_reciter_dto).toList(); }
+
+@protected List<(String,String)> dco_decode_list_record_string_string(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_record_string_string).toList(); }
+
+@protected List<SessionPreviewDto> dco_decode_list_session_preview_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_session_preview_dto).toList(); }
+
+@protected List<SurahInfo> dco_decode_list_surah_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_surah_info).toList(); }
+
+@protected List<SyncMemoryStateDto> dco_decode_list_sync_memory_state_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_sync_memory_state_dto).toList(); }
+
+@protected List<SyncSessionDto> dco_decode_list_sync_session_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_sync_session_dto).toList(); }
+
+@protected List<SyncSessionItemDto> dco_decode_list_sync_session_item_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_sync_session_item_dto).toList(); }
+
+@protected List<TafsirDto> dco_decode_list_tafsir_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_tafsir_dto).toList(); }
+
+@protected List<TajweedAnnotationDto> dco_decode_list_tajweed_annotation_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_tajweed_annotation_dto).toList(); }
+
+@protected List<TranscriptWordDto> dco_decode_list_transcript_word_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_transcript_word_dto).toList(); }
+
+@protected List<TranslatorDto> dco_decode_list_translator_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_translator_dto).toList(); }
+
+@protected List<VerseWithTranslationDto> dco_decode_list_verse_with_translation_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_verse_with_translation_dto).toList(); }
+
+@protected List<WordDto> dco_decode_list_word_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_word_dto).toList(); }
+
+@protected List<WordSegmentDto> dco_decode_list_word_segment_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_word_segment_dto).toList(); }
+
+@protected List<WordTimingDto> dco_decode_list_word_timing_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_word_timing_dto).toList(); }
+
+@protected NodeData dco_decode_node_data(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return NodeData(id: dco_decode_String(arr[0]),
+nodeType: dco_decode_String(arr[1]),
+metadata: dco_decode_Map_String_String_None(arr[2]),); }
+
+@protected NodeEnergyDto dco_decode_node_energy_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return NodeEnergyDto(nodeId: dco_decode_String(arr[0]),
+energy: dco_decode_f_64(arr[1]),
+edgeWeight: dco_decode_f_64(arr[2]),); }
+
+@protected NodeFilterDto dco_decode_node_filter_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return NodeFilterDto(nodeType: dco_decode_opt_String(arr[0]),
+minEnergy: dco_decode_opt_box_autoadd_f_64(arr[1]),
+maxEnergy: dco_decode_opt_box_autoadd_f_64(arr[2]),
+range: dco_decode_opt_String(arr[3]),); }
+
+@protected NodeSearchDto dco_decode_node_search_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return NodeSearchDto(nodeId: dco_decode_String(arr[0]),
+nodeType: dco_decode_String(arr[1]),
+knowledgeAxis: dco_decode_opt_String(arr[2]),
+preview: dco_decode_String(arr[3]),); }
+
+@protected NodeStatusDto dco_decode_node_status_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return NodeStatusDto(nodeId: dco_decode_String(arr[0]),
+status: dco_decode_String(arr[1]),
+buriedUntil: dco_decode_opt_box_autoadd_i_64(arr[2]),); }
+
+@protected String? dco_decode_opt_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_String(raw); }
+
+@protected AyahChainStateDto? dco_decode_opt_box_autoadd_ayah_chain_state_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_ayah_chain_state_dto(raw); }
+
+@protected DistractorStrategyDto? dco_decode_opt_box_autoadd_distractor_strategy_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_distractor_strategy_dto(raw); }
+
+@protected EchoRecallStateDto? dco_decode_opt_box_autoadd_echo_recall_state_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_echo_recall_state_dto(raw); }
+
+@protected ExerciseHintDto? dco_decode_opt_box_autoadd_exercise_hint_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_exercise_hint_dto(raw); }
+
+@protected double? dco_decode_opt_box_autoadd_f_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_f_32(raw); }
+
+@protected double? dco_decode_opt_box_autoadd_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_f_64(raw); }
+
+@protected HintDto? dco_decode_opt_box_autoadd_hint_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_hint_dto(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_i_32(raw); }
+
+@protected PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_i_64(raw); }
+
+@protected NodeData? dco_decode_opt_box_autoadd_node_data(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_node_data(raw); }
+
+@protected SessionDto? dco_decode_opt_box_autoadd_session_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_session_dto(raw); }
+
+@protected SessionItemDto? dco_decode_opt_box_autoadd_session_item_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_session_item_dto(raw); }
+
+@protected TafsirPassageDto? dco_decode_opt_box_autoadd_tafsir_passage_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_tafsir_passage_dto(raw); }
+
+@protected TranslatorDto? dco_decode_opt_box_autoadd_translator_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_translator_dto(raw); }
+
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_64(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_8(raw); }
+
+@protected VerseAudioDto? dco_decode_opt_box_autoadd_verse_audio_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_verse_audio_dto(raw); }
+
+@protected VerseDto? dco_decode_opt_box_autoadd_verse_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_verse_dto(raw); }
+
+@protected WordAudioDto? dco_decode_opt_box_autoadd_word_audio_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_word_audio_dto(raw); }
+
+@protected WordDto? dco_decode_opt_box_autoadd_word_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_word_dto(raw); }
+
+@protected Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_list_prim_u_8_strict(raw); }
+
+@protected PropagationDiagnosticsDto dco_decode_propagation_diagnostics_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return PropagationDiagnosticsDto(nodeFound: dco_decode_bool(arr[0]),
+nodeType: dco_decode_opt_String(arr[1]),
+totalEdges: dco_decode_u_32(arr[2]),
+message: dco_decode_String(arr[3]),); }
+
+@protected PropagationResultDto dco_decode_propagation_result_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return PropagationResultDto(before: dco_decode_list_node_energy_dto(arr[0]),
+after: dco_decode_list_node_energy_dto(arr[1]),
+diagnostics: dco_decode_propagation_diagnostics_dto(arr[2]),); }
+
+@protected RecitationJumpDto dco_decode_recitation_jump_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return RecitationJumpDto(verseKey: dco_decode_String(arr[0]),
+startPosition: dco_decode_i_32(arr[1]),
+wordCount: dco_decode_u_32(arr[2]),
+afterWordNodeId: dco_decode_opt_String(arr[3]),
+startMs: dco_decode_opt_box_autoadd_u_64(arr[4]),); }
+
+@protected RecitationResultDto dco_decode_recitation_result_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return RecitationResultDto(words: dco_decode_list_recited_word_dto(arr[0]),
+jumps: dco_decode_list_recitation_jump_dto(arr[1]),
+extraWords: dco_decode_list_transcript_word_dto(arr[2]),
+accuracy: dco_decode_f_64(arr[3]),
+grade: dco_decode_u_8(arr[4]),); }
+
+@protected RecitedWordDto dco_decode_recited_word_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 11) throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+                return RecitedWordDto(wordNodeId: dco_decode_String(arr[0]),
+verseKey: dco_decode_String(arr[1]),
+position: dco_decode_i_32(arr[2]),
+text: dco_decode_String(arr[3]),
+kind: dco_decode_String(arr[4]),
+heard: dco_decode_opt_String(arr[5]),
+similarity: dco_decode_f_64(arr[6]),
+repeated: dco_decode_bool(arr[7]),
+startMs: dco_decode_opt_box_autoadd_u_64(arr[8]),
+endMs: dco_decode_opt_box_autoadd_u_64(arr[9]),
+grade: dco_decode_u_8(arr[10]),); }
+
+@protected ReciterDto dco_decode_reciter_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return ReciterDto(reciterId: dco_decode_i_32(arr[0]),
+slug: dco_decode_String(arr[1]),
+name: dco_decode_String(arr[2]),
+style: dco_decode_opt_String(arr[3]),
+packageId: dco_decode_opt_String(arr[4]),); }
+
+@protected (String,String) dco_decode_record_string_string(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_String(arr[0]),dco_decode_String(arr[1]),); }
+
+@protected SessionDto dco_decode_session_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return SessionDto(id: dco_decode_String(arr[0]),
+userId: dco_decode_String(arr[1]),
+goalId: dco_decode_String(arr[2]),
+startedAt: dco_decode_i_64(arr[3]),
+completedAt: dco_decode_opt_box_autoadd_i_64(arr[4]),
+itemsCount: dco_decode_i_32(arr[5]),
+itemsCompleted: dco_decode_i_32(arr[6]),); }
+
+@protected SessionItemDto dco_decode_session_item_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return SessionItemDto(sessionId: dco_decode_String(arr[0]),
+position: dco_decode_i_32(arr[1]),
+nodeId: dco_decode_String(arr[2]),
+exerciseType: dco_decode_String(arr[3]),
+exercise: dco_decode_exercise_data_dto(arr[4]),); }
+
+@protected SessionPreviewDto dco_decode_session_preview_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return SessionPreviewDto(nodeId: dco_decode_String(arr[0]),
+nodeType: dco_decode_String(arr[1]),
+previewText: dco_decode_String(arr[2]),
+energy: dco_decode_f_64(arr[3]),
+priorityScore: dco_decode_f_64(arr[4]),); }
+
+@protected SessionSummaryDto dco_decode_session_summary_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return SessionSummaryDto(sessionId: dco_decode_String(arr[0]),
+itemsCount: dco_decode_i_32(arr[1]),
+itemsCompleted: dco_decode_i_32(arr[2]),
+durationMs: dco_decode_i_64(arr[3]),
+againCount: dco_decode_i_32(arr[4]),
+hardCount: dco_decode_i_32(arr[5]),
+goodCount: dco_decode_i_32(arr[6]),
+easyCount: dco_decode_i_32(arr[7]),); }
+
+@protected SurahInfo dco_decode_surah_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return SurahInfo(number: dco_decode_i_32(arr[0]),
+name: dco_decode_String(arr[1]),
+nameArabic: dco_decode_String(arr[2]),
+nameTransliteration: dco_decode_String(arr[3]),
+nameTranslation: dco_decode_String(arr[4]),
+verseCount: dco_decode_i_32(arr[5]),
+revelationPlace: dco_decode_opt_String(arr[6]),); }
+
+@protected SyncMemoryStateDto dco_decode_sync_memory_state_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return SyncMemoryStateDto(nodeId: dco_decode_i_64(arr[0]),
+energy: dco_decode_f_64(arr[1]),
+fsrsStability: dco_decode_opt_box_autoadd_f_64(arr[2]),
+fsrsDifficulty: dco_decode_opt_box_autoadd_f_64(arr[3]),
+lastReviewedAt: dco_decode_opt_box_autoadd_i_64(arr[4]),
+nextReviewAt: dco_decode_opt_box_autoadd_i_64(arr[5]),
+clientUpdatedAt: dco_decode_i_64(arr[6]),); }
+
+@protected SyncSessionDto dco_decode_sync_session_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return SyncSessionDto(id: dco_decode_String(arr[0]),
+goalId: dco_decode_opt_String(arr[1]),
+startedAt: dco_decode_i_64(arr[2]),
+completedAt: dco_decode_opt_box_autoadd_i_64(arr[3]),
+itemsCompleted: dco_decode_i_32(arr[4]),
+clientUpdatedAt: dco_decode_i_64(arr[5]),); }
+
+@protected SyncSessionItemDto dco_decode_sync_session_item_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return SyncSessionItemDto(id: dco_decode_String(arr[0]),
+sessionId: dco_decode_String(arr[1]),
+nodeId: dco_decode_i_64(arr[2]),
+exerciseType: dco_decode_String(arr[3]),
+grade: dco_decode_opt_box_autoadd_i_32(arr[4]),
+durationMs: dco_decode_opt_box_autoadd_i_64(arr[5]),
+completedAt: dco_decode_opt_box_autoadd_i_64(arr[6]),
+clientUpdatedAt: dco_decode_i_64(arr[7]),); }
+
+@protected TafsirDto dco_decode_tafsir_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return TafsirDto(tafsirId: dco_decode_i_32(arr[0]),
+slug: dco_decode_String(arr[1]),
+name: dco_decode_String(arr[2]),
+author: dco_decode_opt_String(arr[3]),
+languageCode: dco_decode_opt_String(arr[4]),); }
+
+@protected TafsirPassageDto dco_decode_tafsir_passage_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return TafsirPassageDto(tafsirId: dco_decode_i_32(arr[0]),
+chapterNumber: dco_decode_i_32(arr[1]),
+startVerse: dco_decode_i_32(arr[2]),
+endVerse: dco_decode_i_32(arr[3]),
+text: dco_decode_String(arr[4]),
+keyLesson: dco_decode_opt_String(arr[5]),
+revelationOccasion: dco_decode_opt_String(arr[6]),
+themes: dco_decode_list_String(arr[7]),); }
+
+@protected TajweedAnnotationDto dco_decode_tajweed_annotation_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return TajweedAnnotationDto(wordNodeId: dco_decode_String(arr[0]),
+position: dco_decode_i_32(arr[1]),
+rule: dco_decode_String(arr[2]),
+ruleName: dco_decode_String(arr[3]),
+startChar: dco_decode_i_32(arr[4]),
+endChar: dco_decode_i_32(arr[5]),); }
+
+@protected TranscriptWordDto dco_decode_transcript_word_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return TranscriptWordDto(text: dco_decode_String(arr[0]),
+startMs: dco_decode_opt_box_autoadd_u_64(arr[1]),
+endMs: dco_decode_opt_box_autoadd_u_64(arr[2]),); }
+
+@protected TranslatorDto dco_decode_translator_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return TranslatorDto(id: dco_decode_i_32(arr[0]),
+slug: dco_decode_String(arr[1]),
+fullName: dco_decode_String(arr[2]),
+languageCode: dco_decode_String(arr[3]),
+description: dco_decode_opt_String(arr[4]),
+license: dco_decode_opt_String(arr[5]),); }
+
+@protected int dco_decode_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected int dco_decode_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected void dco_decode_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return; }
+
+@protected BigInt dco_decode_usize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected ValidationResultDto dco_decode_validation_result_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return ValidationResultDto(isCorrect: dco_decode_bool(arr[0]),
+feedback: dco_decode_opt_String(arr[1]),
+similarityScore: dco_decode_opt_box_autoadd_f_32(arr[2]),
+semanticGrade: dco_decode_opt_String(arr[3]),
+correctAnswer: dco_decode_opt_String(arr[4]),
+suggestedGrade: dco_decode_u_8(arr[5]),); }
+
+@protected VerseAudioDto dco_decode_verse_audio_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return VerseAudioDto(reciterId: dco_decode_i_32(arr[0]),
+verseKey: dco_decode_String(arr[1]),
+audioPath: dco_decode_opt_String(arr[2]),
+audioBytes: dco_decode_opt_list_prim_u_8_strict(arr[3]),
+mimeType: dco_decode_String(arr[4]),
+durationMs: dco_decode_opt_box_autoadd_i_64(arr[5]),
+segments: dco_decode_list_word_segment_dto(arr[6]),); }
+
+@protected VerseDto dco_decode_verse_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return VerseDto(key: dco_decode_String(arr[0]),
+textUthmani: dco_decode_String(arr[1]),
+chapterNumber: dco_decode_i_32(arr[2]),
+verseNumber: dco_decode_i_32(arr[3]),); }
+
+@protected VerseWithTranslationDto dco_decode_verse_with_translation_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return VerseWithTranslationDto(key: dco_decode_String(arr[0]),
+textUthmani: dco_decode_String(arr[1]),
+translation: dco_decode_opt_String(arr[2]),
+number: dco_decode_i_32(arr[3]),); }
+
+@protected WordAudioDto dco_decode_word_audio_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+                return WordAudioDto(reciterId: dco_decode_i_32(arr[0]),
+verseKey: dco_decode_String(arr[1]),
+position: dco_decode_i_32(arr[2]),
+audioPath: dco_decode_opt_String(arr[3]),
+audioBytes: dco_decode_opt_list_prim_u_8_strict(arr[4]),
+mimeType: dco_decode_String(arr[5]),
+durationMs: dco_decode_opt_box_autoadd_i_64(arr[6]),
+startMs: dco_decode_opt_box_autoadd_i_64(arr[7]),
+endMs: dco_decode_opt_box_autoadd_i_64(arr[8]),); }
+
+@protected WordDto dco_decode_word_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return WordDto(id: dco_decode_i_32(arr[0]),
+textUthmani: dco_decode_String(arr[1]),
+verseKey: dco_decode_String(arr[2]),
+position: dco_decode_i_32(arr[3]),); }
+
+@protected WordSegmentDto dco_decode_word_segment_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return WordSegmentDto(position: dco_decode_i_32(arr[0]),
+startMs: dco_decode_i_64(arr[1]),
+endMs: dco_decode_i_64(arr[2]),); }
+
+@protected WordTimingDto dco_decode_word_timing_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return WordTimingDto(wordNodeId: dco_decode_String(arr[0]),
+durationMs: dco_decode_u_64(arr[1]),); }
+
+@protected WordVisibilityDto dco_decode_word_visibility_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return WordVisibilityDto(visibilityType: dco_decode_String(arr[0]),
+hint: dco_decode_opt_box_autoadd_hint_dto(arr[1]),
+coverage: dco_decode_opt_box_autoadd_f_64(arr[2]),); }
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_String(deserializer);
+        return AnyhowException(inner); }
+
+@protected AppState sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return AppStateImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected ArcExerciseService sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseService(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ArcExerciseServiceImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected ArcLearningService sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningService(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ArcLearningServiceImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected ArcSessionService sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionService(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ArcSessionServiceImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected ArcContentRepository sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepository(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ArcContentRepositoryImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected ArcUserRepository sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepository(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ArcUserRepositoryImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected AppState sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return AppStateImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected AppState sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return AppStateImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Map<String, String> sse_decode_Map_String_String_None(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_record_string_string(deserializer);
+        return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected AppState sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return AppStateImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected ArcExerciseService sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseService(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ArcExerciseServiceImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected ArcLearningService sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningService(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ArcLearningServiceImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected ArcSessionService sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionService(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ArcSessionServiceImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected ArcContentRepository sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepository(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ArcContentRepositoryImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected ArcUserRepository sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepository(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ArcUserRepositoryImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected String sse_decode_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return utf8.decoder.convert(inner); }
+
+@protected ActivityPointDto sse_decode_activity_point_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_date = sse_decode_String(deserializer);
+var var_count = sse_decode_i_32(deserializer);
+return ActivityPointDto(date: var_date, count: var_count); }
+
+@protected AyahChainStateDto sse_decode_ayah_chain_state_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_nodeId = sse_decode_String(deserializer);
+var var_currentVerseKey = sse_decode_opt_String(deserializer);
+var var_completedCount = sse_decode_u_32(deserializer);
+var var_totalVerses = sse_decode_u_32(deserializer);
+var var_isComplete = sse_decode_bool(deserializer);
+var var_mistakeMade = sse_decode_bool(deserializer);
+var var_lastGrade = sse_decode_opt_box_autoadd_u_8(deserializer);
+return AyahChainStateDto(nodeId: var_nodeId, currentVerseKey: var_currentVerseKey, completedCount: var_completedCount, totalVerses: var_totalVerses, isComplete: var_isComplete, mistakeMade: var_mistakeMade, lastGrade: var_lastGrade); }
+
+@protected AyahJunctionDto sse_decode_ayah_junction_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_fromVerse = sse_decode_String(deserializer);
+var var_toVerse = sse_decode_String(deserializer);
+var var_closingWord = sse_decode_String(deserializer);
+var var_openingWord = sse_decode_String(deserializer);
+var var_strength = sse_decode_f_64(deserializer);
+return AyahJunctionDto(fromVerse: var_fromVerse, toVerse: var_toVerse, closingWord: var_closingWord, openingWord: var_openingWord, strength: var_strength); }
+
+@protected bool sse_decode_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8() != 0; }
+
+@protected AyahChainStateDto sse_decode_box_autoadd_ayah_chain_state_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_ayah_chain_state_dto(deserializer)); }
+
+@protected DistractorStrategyDto sse_decode_box_autoadd_distractor_strategy_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_distractor_strategy_dto(deserializer)); }
+
+@protected EchoRecallMetricsDto sse_decode_box_autoadd_echo_recall_metrics_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_echo_recall_metrics_dto(deserializer)); }
+
+@protected EchoRecallStateDto sse_decode_box_autoadd_echo_recall_state_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_echo_recall_state_dto(deserializer)); }
+
+@protected ExerciseDataDto sse_decode_box_autoadd_exercise_data_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_exercise_data_dto(deserializer)); }
+
+@protected ExerciseHintDto sse_decode_box_autoadd_exercise_hint_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_exercise_hint_dto(deserializer)); }
+
+@protected double sse_decode_box_autoadd_f_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_f_32(deserializer)); }
+
+@protected double sse_decode_box_autoadd_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_f_64(deserializer)); }
+
+@protected HintDto sse_decode_box_autoadd_hint_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_hint_dto(deserializer)); }
+
+@protected int sse_decode_box_autoadd_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_i_32(deserializer)); }
+
+@protected PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_i_64(deserializer)); }
+
+@protected NodeData sse_decode_box_autoadd_node_data(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_node_data(deserializer)); }
+
+@protected NodeFilterDto sse_decode_box_autoadd_node_filter_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_node_filter_dto(deserializer)); }
+
+@protected SessionDto sse_decode_box_autoadd_session_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_session_dto(deserializer)); }
+
+@protected SessionItemDto sse_decode_box_autoadd_session_item_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_session_item_dto(deserializer)); }
+
+@protected TafsirPassageDto sse_decode_box_autoadd_tafsir_passage_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_tafsir_passage_dto(deserializer)); }
+
+@protected TranslatorDto sse_decode_box_autoadd_translator_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_translator_dto(deserializer)); }
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_64(deserializer)); }
+
+@protected int sse_decode_box_autoadd_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_8(deserializer)); }
+
+@protected VerseAudioDto sse_decode_box_autoadd_verse_audio_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_verse_audio_dto(deserializer)); }
+
+@protected VerseDto sse_decode_box_autoadd_verse_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_verse_dto(deserializer)); }
+
+@protected WordAudioDto sse_decode_box_autoadd_word_audio_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_word_audio_dto(deserializer)); }
+
+@protected WordDto sse_decode_box_autoadd_word_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_word_dto(deserializer)); }
+
+@protected ComprehensionDto sse_decode_comprehension_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_memorization = sse_decode_f_64(deserializer);
+var var_understanding = sse_decode_f_64(deserializer);
+var var_context = sse_decode_f_64(deserializer);
+return ComprehensionDto(memorization: var_memorization, understanding: var_understanding, context: var_context); }
+
+@protected ContentPackageDto sse_decode_content_package_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_packageId = sse_decode_String(deserializer);
+var var_packageType = sse_decode_String(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_languageCode = sse_decode_opt_String(deserializer);
+var var_author = sse_decode_opt_String(deserializer);
+var var_version = sse_decode_String(deserializer);
+var var_description = sse_decode_opt_String(deserializer);
+var var_fileSize = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_downloadUrl = sse_decode_opt_String(deserializer);
+var var_checksum = sse_decode_opt_String(deserializer);
+var var_license = sse_decode_opt_String(deserializer);
+return ContentPackageDto(packageId: var_packageId, packageType: var_packageType, name: var_name, languageCode: var_languageCode, author: var_author, version: var_version, description: var_description, fileSize: var_fileSize, downloadUrl: var_downloadUrl, checksum: var_checksum, license: var_license); }
+
+@protected DashboardStatsDto sse_decode_dashboard_stats_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_reviewsToday = sse_decode_u_32(deserializer);
+var var_streakDays = sse_decode_u_32(deserializer);
+var var_dueCount = sse_decode_u_32(deserializer);
+return DashboardStatsDto(reviewsToday: var_reviewsToday, streakDays: var_streakDays, dueCount: var_dueCount); }
+
+@protected DbHealthDto sse_decode_db_health_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_chaptersCount = sse_decode_i_64(deserializer);
+var var_versesCount = sse_decode_i_64(deserializer);
+var var_wordsCount = sse_decode_i_64(deserializer);
+var var_nodesCount = sse_decode_i_64(deserializer);
+var var_edgesCount = sse_decode_i_64(deserializer);
+var var_userMemoryCount = sse_decode_i_64(deserializer);
+var var_isHealthy = sse_decode_bool(deserializer);
+var var_issues = sse_decode_list_String(deserializer);
+return DbHealthDto(chaptersCount: var_chaptersCount, versesCount: var_versesCount, wordsCount: var_wordsCount, nodesCount: var_nodesCount, edgesCount: var_edgesCount, userMemoryCount: var_userMemoryCount, isHealthy: var_isHealthy, issues: var_issues); }
+
+@protected DbQueryResultDto sse_decode_db_query_result_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_columns = sse_decode_list_String(deserializer);
+var var_rows = sse_decode_list_list_String(deserializer);
+return DbQueryResultDto(columns: var_columns, rows: var_rows); }
+
+@protected DebugStatsDto sse_decode_debug_stats_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_totalNodesCount = sse_decode_u_32(deserializer);
+var var_totalEdgesCount = sse_decode_u_32(deserializer);
+var var_dueCount = sse_decode_u_32(deserializer);
+return DebugStatsDto(totalNodesCount: var_totalNodesCount, totalEdgesCount: var_totalEdgesCount, dueCount: var_dueCount); }
+
+@protected DetailedStatsDto sse_decode_detailed_stats_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_activityHistory = sse_decode_list_activity_point_dto(deserializer);
+var var_comprehension = sse_decode_comprehension_dto(deserializer);
+return DetailedStatsDto(activityHistory: var_activityHistory, comprehension: var_comprehension); }
+
+@protected DistractorStrategyDto sse_decode_distractor_strategy_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_difficulty = sse_decode_String(deserializer);
+var var_signals = sse_decode_list_String(deserializer);
+return DistractorStrategyDto(difficulty: var_difficulty, signals: var_signals); }
+
+@protected EchoRecallMetricsDto sse_decode_echo_recall_metrics_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_wordTimings = sse_decode_list_word_timing_dto(deserializer);
+var var_totalDurationMs = sse_decode_u_64(deserializer);
+var var_struggles = sse_decode_u_32(deserializer);
+return EchoRecallMetricsDto(wordTimings: var_wordTimings, totalDurationMs: var_totalDurationMs, struggles: var_struggles); }
+
+@protected EchoRecallResultDto sse_decode_echo_recall_result_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_energyUpdates = sse_decode_list_energy_update_dto(deserializer);
+var var_wordsProcessed = sse_decode_u_32(deserializer);
+var var_averageEnergy = sse_decode_f_64(deserializer);
+return EchoRecallResultDto(energyUpdates: var_energyUpdates, wordsProcessed: var_wordsProcessed, averageEnergy: var_averageEnergy); }
+
+@protected EchoRecallStateDto sse_decode_echo_recall_state_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_words = sse_decode_list_echo_recall_word_dto(deserializer);
+return EchoRecallStateDto(words: var_words); }
+
+@protected EchoRecallStatsDto sse_decode_echo_recall_stats_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_totalWords = sse_decode_u_32(deserializer);
+var var_visibleCount = sse_decode_u_32(deserializer);
+var var_obscuredCount = sse_decode_u_32(deserializer);
+var var_hiddenCount = sse_decode_u_32(deserializer);
+var var_averageEnergy = sse_decode_f_64(deserializer);
+var var_masteryPercentage = sse_decode_f_64(deserializer);
+return EchoRecallStatsDto(totalWords: var_totalWords, visibleCount: var_visibleCount, obscuredCount: var_obscuredCount, hiddenCount: var_hiddenCount, averageEnergy: var_averageEnergy, masteryPercentage: var_masteryPercentage); }
+
+@protected EchoRecallWordDto sse_decode_echo_recall_word_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_nodeId = sse_decode_String(deserializer);
+var var_text = sse_decode_String(deserializer);
+var var_visibility = sse_decode_word_visibility_dto(deserializer);
+var var_energy = sse_decode_f_64(deserializer);
+return EchoRecallWordDto(nodeId: var_nodeId, text: var_text, visibility: var_visibility, energy: var_energy); }
+
+@protected EnergySnapshotDto sse_decode_energy_snapshot_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_nodeId = sse_decode_String(deserializer);
+var var_energy = sse_decode_f_64(deserializer);
+var var_nodeType = sse_decode_opt_String(deserializer);
+var var_knowledgeAxis = sse_decode_opt_String(deserializer);
+var var_neighbors = sse_decode_list_node_energy_dto(deserializer);
+return EnergySnapshotDto(nodeId: var_nodeId, energy: var_energy, nodeType: var_nodeType, knowledgeAxis: var_knowledgeAxis, neighbors: var_neighbors); }
+
+@protected EnergyUpdateDto sse_decode_energy_update_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_nodeId = sse_decode_String(deserializer);
+var var_energy = sse_decode_f_64(deserializer);
+return EnergyUpdateDto(nodeId: var_nodeId, energy: var_energy); }
+
+@protected ExerciseDataDto sse_decode_exercise_data_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_nodeId = sse_decode_String(deserializer);
+var var_seed = sse_decode_u_64(deserializer);
+return ExerciseDataDto_Memorization(nodeId: var_nodeId, seed: var_seed);case 1: var var_nodeId = sse_decode_String(deserializer);
+var var_distractorNodeIds = sse_decode_list_String(deserializer);
+var var_distractorStrategy = sse_decode_distractor_strategy_dto(deserializer);
+var var_seed = sse_decode_u_64(deserializer);
+return ExerciseDataDto_McqArToEn(nodeId: var_nodeId, distractorNodeIds: var_distractorNodeIds, distractorStrategy: var_distractorStrategy, seed: var_seed);case 2: var var_nodeId = sse_decode_String(deserializer);
+var var_distractorNodeIds = sse_decode_list_String(deserializer);
+var var_distractorStrategy = sse_decode_distractor_strategy_dto(deserializer);
+var var_seed = sse_decode_u_64(deserializer);
+return ExerciseDataDto_McqEnToAr(nodeId: var_nodeId, distractorNodeIds: var_distractorNodeIds, distractorStrategy: var_distractorStrategy, seed: var_seed);case 3: var var_nodeId = sse_decode_String(deserializer);
+var var_seed = sse_decode_u_64(deserializer);
+return ExerciseDataDto_Translation(nodeId: var_nodeId, seed: var_seed);case 4: var var_nodeId = sse_decode_String(deserializer);
+var var_verseKey = sse_decode_String(deserializer);
+var var_seed = sse_decode_u_64(deserializer);
+return ExerciseDataDto_ContextualTranslation(nodeId: var_nodeId, verseKey: var_verseKey, seed: var_seed);case 5: var var_nodeId = sse_decode_String(deserializer);
+var var_blankPosition = sse_decode_i_32(deserializer);
+var var_seed = sse_decode_u_64(deserializer);
+return ExerciseDataDto_ClozeDeletion(nodeId: var_nodeId, blankPosition: var_blankPosition, seed: var_seed);case 6: var var_nodeId = sse_decode_String(deserializer);
+var var_wordPosition = sse_decode_i_32(deserializer);
+var var_seed = sse_decode_u_64(deserializer);
+return ExerciseDataDto_FirstLetterHint(nodeId: var_nodeId, wordPosition: var_wordPosition, seed: var_seed);case 7: var var_nodeId = sse_decode_String(deserializer);
+var var_blankPosition = sse_decode_i_32(deserializer);
+var var_distractorNodeIds = sse_decode_list_String(deserializer);
+var var_distractorStrategy = sse_decode_distractor_strategy_dto(deserializer);
+var var_seed = sse_decode_u_64(deserializer);
+return ExerciseDataDto_MissingWordMcq(nodeId: var_nodeId, blankPosition: var_blankPosition, distractorNodeIds: var_distractorNodeIds, distractorStrategy: var_distractorStrategy, seed: var_seed);case 8: var var_nodeId = sse_decode_String(deserializer);
+var var_contextPosition = sse_decode_i_32(deserializer);
+var var_distractorNodeIds = sse_decode_list_String(deserializer);
+var var_distractorStrategy = sse_decode_distractor_strategy_dto(deserializer);
+var var_seed = sse_decode_u_64(deserializer);
+return ExerciseDataDto_NextWordMcq(nodeId: var_nodeId, contextPosition: var_contextPosition, distractorNodeIds: var_distractorNodeIds, distractorStrategy: var_distractorStrategy, seed: var_seed);case 9: var var_nodeId = sse_decode_String(deserializer);
+var var_seed = sse_decode_u_64(deserializer);
+return ExerciseDataDto_FullVerseInput(nodeId: var_nodeId, seed: var_seed);case 10: var var_nodeId = sse_decode_String(deserializer);
+var var_verseKeys = sse_decode_list_String(deserializer);
+var var_currentIndex = sse_decode_usize(deserializer);
+var var_completedCount = sse_decode_usize(deserializer);
+var var_seed = sse_decode_u_64(deserializer);
+return ExerciseDataDto_AyahChain(nodeId: var_nodeId, verseKeys: var_verseKeys, currentIndex: var_currentIndex, completedCount: var_completedCount, seed: var_seed);case 11: var var_nodeId = sse_decode_String(deserializer);
+var var_mistakePosition = sse_decode_i_32(deserializer);
+var var_correctWordNodeId = sse_decode_String(deserializer);
+var var_incorrectWordNodeId = sse_decode_String(deserializer);
+var var_seed = sse_decode_u_64(deserializer);
+return ExerciseDataDto_FindMistake(nodeId: var_nodeId, mistakePosition: var_mistakePosition, correctWordNodeId: var_correctWordNodeId, incorrectWordNodeId: var_incorrectWordNodeId, seed: var_seed);case 12: var var_nodeId = sse_decode_String(deserializer);
+var var_correctSequence = sse_decode_list_String(deserializer);
+var var_seed = sse_decode_u_64(deserializer);
+return ExerciseDataDto_AyahSequence(nodeId: var_nodeId, correctSequence: var_correctSequence, seed: var_seed);case 13: var var_nodeId = sse_decode_String(deserializer);
+var var_correctSequence = sse_decode_list_String(deserializer);
+var var_options = sse_decode_list_list_String(deserializer);
+var var_seed = sse_decode_u_64(deserializer);
+return ExerciseDataDto_SequenceRecall(nodeId: var_nodeId, correctSequence: var_correctSequence, options: var_options, seed: var_seed);case 14: var var_nodeId = sse_decode_String(deserializer);
+var var_verseKey = sse_decode_String(deserializer);
+var var_seed = sse_decode_u_64(deserializer);
+return ExerciseDataDto_FirstWordRecall(nodeId: var_nodeId, verseKey: var_verseKey, seed: var_seed);case 15: var var_nodeId = sse_decode_String(deserializer);
+var var_root = sse_decode_String(deserializer);
+var var_seed = sse_decode_u_64(deserializer);
+return ExerciseDataDto_IdentifyRoot(nodeId: var_nodeId, root: var_root, seed: var_seed);case 16: var var_nodeId = sse_decode_String(deserializer);
+var var_blankPosition = sse_decode_i_32(deserializer);
+var var_seed = sse_decode_u_64(deserializer);
+return ExerciseDataDto_ReverseCloze(nodeId: var_nodeId, blankPosition: var_blankPosition, seed: var_seed);case 17: var var_nodeId = sse_decode_String(deserializer);
+var var_translatorId = sse_decode_i_32(deserializer);
+var var_seed = sse_decode_u_64(deserializer);
+return ExerciseDataDto_TranslatePhrase(nodeId: var_nodeId, translatorId: var_translatorId, seed: var_seed);case 18: var var_nodeId = sse_decode_String(deserializer);
+var var_correctPos = sse_decode_String(deserializer);
+var var_options = sse_decode_list_String(deserializer);
+var var_seed = sse_decode_u_64(deserializer);
+return ExerciseDataDto_PosTagging(nodeId: var_nodeId, correctPos: var_correctPos, options: var_options, seed: var_seed);case 19: var var_nodeId = sse_decode_String(deserializer);
+var var_feature = sse_decode_String(deserializer);
+var var_prompt = sse_decode_String(deserializer);
+var var_correctValue = sse_decode_String(deserializer);
+var var_options = sse_decode_list_String(deserializer);
+var var_seed = sse_decode_u_64(deserializer);
+return ExerciseDataDto_GrammarFeature(nodeId: var_nodeId, feature: var_feature, prompt: var_prompt, correctValue: var_correctValue, options: var_options, seed: var_seed);case 20: var var_nodeId = sse_decode_String(deserializer);
+var var_relatedVerseIds = sse_decode_list_String(deserializer);
+var var_connectionTheme = sse_decode_String(deserializer);
+var var_seed = sse_decode_u_64(deserializer);
+return ExerciseDataDto_CrossVerseConnection(nodeId: var_nodeId, relatedVerseIds: var_relatedVerseIds, connectionTheme: var_connectionTheme, seed: var_seed);case 21: var var_userId = sse_decode_String(deserializer);
+var var_ayahNodeIds = sse_decode_list_String(deserializer);
+var var_seed = sse_decode_u_64(deserializer);
+return ExerciseDataDto_EchoRecall(userId: var_userId, ayahNodeIds: var_ayahNodeIds, seed: var_seed);case 22: var var_nodeId = sse_decode_String(deserializer);
+var var_verseKey = sse_decode_String(deserializer);
+var var_promptWordCount = sse_decode_i_32(deserializer);
+var var_optionVerseKeys = sse_decode_list_String(deserializer);
+var var_differingPositions = sse_decode_list_prim_i_32_strict(deserializer);
+var var_seed = sse_decode_u_64(deserializer);
+return ExerciseDataDto_Mutashabihat(nodeId: var_nodeId, verseKey: var_verseKey, promptWordCount: var_promptWordCount, optionVerseKeys: var_optionVerseKeys, differingPositions: var_differingPositions, seed: var_seed);case 23: var var_nodeId = sse_decode_String(deserializer);
+var var_correctSequence = sse_decode_list_String(deserializer);
+var var_shuffledWordNodeIds = sse_decode_list_String(deserializer);
+var var_tileTexts = sse_decode_list_String(deserializer);
+var var_seed = sse_decode_u_64(deserializer);
+return ExerciseDataDto_WordOrder(nodeId: var_nodeId, correctSequence: var_correctSequence, shuffledWordNodeIds: var_shuffledWordNodeIds, tileTexts: var_tileTexts, seed: var_seed);case 24: var var_nodeId = sse_decode_String(deserializer);
+var var_verseKey = sse_decode_String(deserializer);
+var var_correctChapter = sse_decode_i_32(deserializer);
+var var_chapterOptions = sse_decode_list_prim_i_32_strict(deserializer);
+var var_seed = sse_decode_u_64(deserializer);
+return ExerciseDataDto_IdentifySurah(nodeId: var_nodeId, verseKey: var_verseKey, correctChapter: var_correctChapter, chapterOptions: var_chapterOptions, seed: var_seed);case 25: var var_nodeId = sse_decode_String(deserializer);
+var var_verseKey = sse_decode_String(deserializer);
+var var_correctVerseNumber = sse_decode_i_32(deserializer);
+var var_verseNumberOptions = sse_decode_list_prim_i_32_strict(deserializer);
+var var_seed = sse_decode_u_64(deserializer);
+return ExerciseDataDto_IdentifyVerseNumber(nodeId: var_nodeId, verseKey: var_verseKey, correctVerseNumber: var_correctVerseNumber, verseNumberOptions: var_verseNumberOptions, seed: var_seed);case 26: var var_nodeId = sse_decode_String(deserializer);
+var var_verseKey = sse_decode_String(deserializer);
+var var_page = sse_decode_i_32(deserializer);
+var var_half = sse_decode_i_32(deserializer);
+var var_seed = sse_decode_u_64(deserializer);
+return ExerciseDataDto_IdentifyPageHalf(nodeId: var_nodeId, verseKey: var_verseKey, page: var_page, half: var_half, seed: var_seed);case 27: var var_nodeId = sse_decode_String(deserializer);
+var var_distractorNodeIds = sse_decode_list_String(deserializer);
+var var_distractorStrategy = sse_decode_distractor_strategy_dto(deserializer);
+var var_seed = sse_decode_u_64(deserializer);
+return ExerciseDataDto_TransliterationToArabic(nodeId: var_nodeId, distractorNodeIds: var_distractorNodeIds, distractorStrategy: var_distractorStrategy, seed: var_seed);case 28: var var_nodeId = sse_decode_String(deserializer);
+var var_verseKey = sse_decode_String(deserializer);
+var var_seed = sse_decode_u_64(deserializer);
+return ExerciseDataDto_ArabicToTransliteration(nodeId: var_nodeId, verseKey: var_verseKey, seed: var_seed);case 29: var var_nodeId = sse_decode_String(deserializer);
+var var_rootId = sse_decode_String(deserializer);
+var var_verseKeys = sse_decode_list_String(deserializer);
+var var_familyWordNodeIds = sse_decode_list_String(deserializer);
+var var_seed = sse_decode_u_64(deserializer);
+return ExerciseDataDto_RootFamily(nodeId: var_nodeId, rootId: var_rootId, verseKeys: var_verseKeys, familyWordNodeIds: var_familyWordNodeIds, seed: var_seed);case 30: var var_nodeId = sse_decode_String(deserializer);
+var var_rootId = sse_decode_String(deserializer);
+var var_lemmaIds = sse_decode_list_String(deserializer);
+var var_meaningLemmaIds = sse_decode_list_String(deserializer);
+var var_seed = sse_decode_u_64(deserializer);
+return ExerciseDataDto_LemmaMeaning(nodeId: var_nodeId, rootId: var_rootId, lemmaIds: var_lemmaIds, meaningLemmaIds: var_meaningLemmaIds, seed: var_seed);case 31: var var_nodeId = sse_decode_String(deserializer);
+var var_page = sse_decode_i_32(deserializer);
+var var_verseKeys = sse_decode_list_String(deserializer);
+var var_firstWordNodeId = sse_decode_String(deserializer);
+var var_lastWordNodeId = sse_decode_String(deserializer);
+var var_seed = sse_decode_u_64(deserializer);
+return ExerciseDataDto_RecitePage(nodeId: var_nodeId, page: var_page, verseKeys: var_verseKeys, firstWordNodeId: var_firstWordNodeId, lastWordNodeId: var_lastWordNodeId, seed: var_seed);case 32: var var_nodeId = sse_decode_String(deserializer);
+var var_page = sse_decode_i_32(deserializer);
+var var_firstWordNodeId = sse_decode_String(deserializer);
+var var_distractorNodeIds = sse_decode_list_String(deserializer);
+var var_seed = sse_decode_u_64(deserializer);
+return ExerciseDataDto_PageFirstWord(nodeId: var_nodeId, page: var_page, firstWordNodeId: var_firstWordNodeId, distractorNodeIds: var_distractorNodeIds, seed: var_seed);case 33: var var_nodeId = sse_decode_String(deserializer);
+var var_verseKey = sse_decode_String(deserializer);
+var var_page = sse_decode_i_32(deserializer);
+var var_correctLine = sse_decode_i_32(deserializer);
+var var_lineOptions = sse_decode_list_prim_i_32_strict(deserializer);
+var var_seed = sse_decode_u_64(deserializer);
+return ExerciseDataDto_IdentifyVerseLine(nodeId: var_nodeId, verseKey: var_verseKey, page: var_page, correctLine: var_correctLine, lineOptions: var_lineOptions, seed: var_seed);case 34: var var_nodeId = sse_decode_String(deserializer);
+var var_reciterId = sse_decode_i_32(deserializer);
+var var_verseKey = sse_decode_String(deserializer);
+var var_firstPosition = sse_decode_i_32(deserializer);
+var var_lastPosition = sse_decode_i_32(deserializer);
+var var_startMs = sse_decode_i_64(deserializer);
+var var_endMs = sse_decode_i_64(deserializer);
+var var_optionVerseKeys = sse_decode_list_String(deserializer);
+var var_seed = sse_decode_u_64(deserializer);
+return ExerciseDataDto_ListenIdentifyVerse(nodeId: var_nodeId, reciterId: var_reciterId, verseKey: var_verseKey, firstPosition: var_firstPosition, lastPosition: var_lastPosition, startMs: var_startMs, endMs: var_endMs, optionVerseKeys: var_optionVerseKeys, seed: var_seed);case 35: var var_nodeId = sse_decode_String(deserializer);
+var var_reciterId = sse_decode_i_32(deserializer);
+var var_verseKey = sse_decode_String(deserializer);
+var var_firstPosition = sse_decode_i_32(deserializer);
+var var_lastPosition = sse_decode_i_32(deserializer);
+var var_startMs = sse_decode_i_64(deserializer);
+var var_endMs = sse_decode_i_64(deserializer);
+var var_distractorNodeIds = sse_decode_list_String(deserializer);
+var var_distractorStrategy = sse_decode_distractor_strategy_dto(deserializer);
+var var_seed = sse_decode_u_64(deserializer);
+return ExerciseDataDto_ListenContinue(nodeId: var_nodeId, reciterId: var_reciterId, verseKey: var_verseKey, firstPosition: var_firstPosition, lastPosition: var_lastPosition, startMs: var_startMs, endMs: var_endMs, distractorNodeIds: var_distractorNodeIds, distractorStrategy: var_distractorStrategy, seed: var_seed);case 36: var var_nodeId = sse_decode_String(deserializer);
+var var_reciterId = sse_decode_i_32(deserializer);
+var var_verseKey = sse_decode_String(deserializer);
+var var_position = sse_decode_i_32(deserializer);
+var var_startMs = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_endMs = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_distractorNodeIds = sse_decode_list_String(deserializer);
+var var_distractorStrategy = sse_decode_distractor_strategy_dto(deserializer);
+var var_seed = sse_decode_u_64(deserializer);
+return ExerciseDataDto_ListenIdentifyWord(nodeId: var_nodeId, reciterId: var_reciterId, verseKey: var_verseKey, position: var_position, startMs: var_startMs, endMs: var_endMs, distractorNodeIds: var_distractorNodeIds, distractorStrategy: var_distractorStrategy, seed: var_seed);case 37: var var_nodeId = sse_decode_String(deserializer);
+var var_wordNodeId = sse_decode_String(deserializer);
+var var_startChar = sse_decode_i_32(deserializer);
+var var_endChar = sse_decode_i_32(deserializer);
+var var_correctRule = sse_decode_String(deserializer);
+var var_ruleOptions = sse_decode_list_String(deserializer);
+var var_optionNames = sse_decode_list_String(deserializer);
+var var_seed = sse_decode_u_64(deserializer);
+return ExerciseDataDto_IdentifyTajweedRule(nodeId: var_nodeId, wordNodeId: var_wordNodeId, startChar: var_startChar, endChar: var_endChar, correctRule: var_correctRule, ruleOptions: var_ruleOptions, optionNames: var_optionNames, seed: var_seed);case 38: var var_nodeId = sse_decode_String(deserializer);
+var var_rule = sse_decode_String(deserializer);
+var var_ruleName = sse_decode_String(deserializer);
+var var_ruleWordNodeIds = sse_decode_list_String(deserializer);
+var var_seed = sse_decode_u_64(deserializer);
+return ExerciseDataDto_FindTajweedRule(nodeId: var_nodeId, rule: var_rule, ruleName: var_ruleName, ruleWordNodeIds: var_ruleWordNodeIds, seed: var_seed);case 39: var var_nodeId = sse_decode_String(deserializer);
+var var_tafsirId = sse_decode_i_32(deserializer);
+var var_passageKey = sse_decode_String(deserializer);
+var var_optionPassageKeys = sse_decode_list_String(deserializer);
+var var_seed = sse_decode_u_64(deserializer);
+return ExerciseDataDto_TafsirKeyLesson(nodeId: var_nodeId, tafsirId: var_tafsirId, passageKey: var_passageKey, optionPassageKeys: var_optionPassageKeys, seed: var_seed);case 40: var var_nodeId = sse_decode_String(deserializer);
+var var_tafsirId = sse_decode_i_32(deserializer);
+var var_passageKey = sse_decode_String(deserializer);
+var var_optionVerseKeys = sse_decode_list_String(deserializer);
+var var_seed = sse_decode_u_64(deserializer);
+return ExerciseDataDto_RevelationOccasion(nodeId: var_nodeId, tafsirId: var_tafsirId, passageKey: var_passageKey, optionVerseKeys: var_optionVerseKeys, seed: var_seed); default: throw UnimplementedError(''); }
+             }
+
+@protected ExerciseHintDto sse_decode_exercise_hint_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_level = sse_decode_u_8(deserializer);
+var var_levelName = sse_decode_String(deserializer);
+var var_text = sse_decode_String(deserializer);
+var var_maxGrade = sse_decode_u_8(deserializer);
+return ExerciseHintDto(level: var_level, levelName: var_levelName, text: var_text, maxGrade: var_maxGrade); }
+
+@protected double sse_decode_f_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat32(); }
+
+@protected double sse_decode_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat64(); }
+
+@protected HintDto sse_decode_hint_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_hintType = sse_decode_String(deserializer);
+var var_firstChar = sse_decode_opt_String(deserializer);
+var var_lastChar = sse_decode_opt_String(deserializer);
+return HintDto(hintType: var_hintType, firstChar: var_firstChar, lastChar: var_lastChar); }
+
+@protected int sse_decode_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getInt32(); }
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getPlatformInt64(); }
+
+@protected InstalledPackageDto sse_decode_installed_package_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_packageId = sse_decode_String(deserializer);
+var var_installedAt = sse_decode_i_64(deserializer);
+var var_enabled = sse_decode_bool(deserializer);
+return InstalledPackageDto(packageId: var_packageId, installedAt: var_installedAt, enabled: var_enabled); }
+
+@protected LanguageDto sse_decode_language_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_code = sse_decode_String(deserializer);
+var var_englishName = sse_decode_String(deserializer);
+var var_nativeName = sse_decode_String(deserializer);
+var var_direction = sse_decode_String(deserializer);
+return LanguageDto(code: var_code, englishName: var_englishName, nativeName: var_nativeName, direction: var_direction); }
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <String>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_String(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ActivityPointDto> sse_decode_list_activity_point_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ActivityPointDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_activity_point_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<AyahJunctionDto> sse_decode_list_ayah_junction_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <AyahJunctionDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_ayah_junction_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ContentPackageDto> sse_decode_list_content_package_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ContentPackageDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_content_package_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<EchoRecallWordDto> sse_decode_list_echo_recall_word_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <EchoRecallWordDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_echo_recall_word_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<EnergyUpdateDto> sse_decode_list_energy_update_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <EnergyUpdateDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_energy_update_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ExerciseDataDto> sse_decode_list_exercise_data_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ExerciseDataDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_exercise_data_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ExerciseHintDto> sse_decode_list_exercise_hint_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ExerciseHintDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_exercise_hint_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<InstalledPackageDto> sse_decode_list_installed_package_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <InstalledPackageDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_installed_package_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<LanguageDto> sse_decode_list_language_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <LanguageDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_language_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<List<String>> sse_decode_list_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <List<String>>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_list_String(deserializer)); }
+        return ans_;
+         }
+
+@protected List<NodeEnergyDto> sse_decode_list_node_energy_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <NodeEnergyDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_node_energy_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<NodeSearchDto> sse_decode_list_node_search_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <NodeSearchDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_node_search_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected Int32List sse_decode_list_prim_i_32_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getInt32List(len_); }
+
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected List<RecitationJumpDto> sse_decode_list_recitation_jump_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <RecitationJumpDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_recitation_jump_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<RecitedWordDto> sse_decode_list_recited_word_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <RecitedWordDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_recited_word_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ReciterDto> sse_decode_list_reciter_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ReciterDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_reciter_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<(String,String)> sse_decode_list_record_string_string(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <(String,String)>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_record_string_string(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SessionPreviewDto> sse_decode_list_session_preview_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SessionPreviewDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_session_preview_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SurahInfo> sse_decode_list_surah_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SurahInfo>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_surah_info(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SyncMemoryStateDto> sse_decode_list_sync_memory_state_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SyncMemoryStateDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_sync_memory_state_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SyncSessionDto> sse_decode_list_sync_session_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SyncSessionDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_sync_session_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SyncSessionItemDto> sse_decode_list_sync_session_item_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SyncSessionItemDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_sync_session_item_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<TafsirDto> sse_decode_list_tafsir_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <TafsirDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_tafsir_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<TajweedAnnotationDto> sse_decode_list_tajweed_annotation_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <TajweedAnnotationDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_tajweed_annotation_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<TranscriptWordDto> sse_decode_list_transcript_word_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <TranscriptWordDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_transcript_word_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<TranslatorDto> sse_decode_list_translator_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <TranslatorDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_translator_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<VerseWithTranslationDto> sse_decode_list_verse_with_translation_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <VerseWithTranslationDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_verse_with_translation_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<WordDto> sse_decode_list_word_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <WordDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_word_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<WordSegmentDto> sse_decode_list_word_segment_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <WordSegmentDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_word_segment_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<WordTimingDto> sse_decode_list_word_timing_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <WordTimingDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_word_timing_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected NodeData sse_decode_node_data(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_String(deserializer);
+var var_nodeType = sse_decode_String(deserializer);
+var var_metadata = sse_decode_Map_String_String_None(deserializer);
+return NodeData(id: var_id, nodeType: var_nodeType, metadata: var_metadata); }
+
+@protected NodeEnergyDto sse_decode_node_energy_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_nodeId = sse_decode_String(deserializer);
+var var_energy = sse_decode_f_64(deserializer);
+var var_edgeWeight = sse_decode_f_64(deserializer);
+return NodeEnergyDto(nodeId: var_nodeId, energy: var_energy, edgeWeight: var_edgeWeight); }
+
+@protected NodeFilterDto sse_decode_node_filter_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_nodeType = sse_decode_opt_String(deserializer);
+var var_minEnergy = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_maxEnergy = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_range = sse_decode_opt_String(deserializer);
+return NodeFilterDto(nodeType: var_nodeType, minEnergy: var_minEnergy, maxEnergy: var_maxEnergy, range: var_range); }
+
+@protected NodeSearchDto sse_decode_node_search_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_nodeId = sse_decode_String(deserializer);
+var var_nodeType = sse_decode_String(deserializer);
+var var_knowledgeAxis = sse_decode_opt_String(deserializer);
+var var_preview = sse_decode_String(deserializer);
+return NodeSearchDto(nodeId: var_nodeId, nodeType: var_nodeType, knowledgeAxis: var_knowledgeAxis, preview: var_preview); }
+
+@protected NodeStatusDto sse_decode_node_status_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_nodeId = sse_decode_String(deserializer);
+var var_status = sse_decode_String(deserializer);
+var var_buriedUntil = sse_decode_opt_box_autoadd_i_64(deserializer);
+return NodeStatusDto(nodeId: var_nodeId, status: var_status, buriedUntil: var_buriedUntil); }
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_String(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected AyahChainStateDto? sse_decode_opt_box_autoadd_ayah_chain_state_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_ayah_chain_state_dto(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected DistractorStrategyDto? sse_decode_opt_box_autoadd_distractor_strategy_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_distractor_strategy_dto(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected EchoRecallStateDto? sse_decode_opt_box_autoadd_echo_recall_state_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_echo_recall_state_dto(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected ExerciseHintDto? sse_decode_opt_box_autoadd_exercise_hint_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_exercise_hint_dto(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected double? sse_decode_opt_box_autoadd_f_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_f_32(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_f_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected HintDto? sse_decode_opt_box_autoadd_hint_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_hint_dto(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_i_32(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_i_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected NodeData? sse_decode_opt_box_autoadd_node_data(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_node_data(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected SessionDto? sse_decode_opt_box_autoadd_session_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_session_dto(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected SessionItemDto? sse_decode_opt_box_autoadd_session_item_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_session_item_dto(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected TafsirPassageDto? sse_decode_opt_box_autoadd_tafsir_passage_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_tafsir_passage_dto(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected TranslatorDto? sse_decode_opt_box_autoadd_translator_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_translator_dto(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_8(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected VerseAudioDto? sse_decode_opt_box_autoadd_verse_audio_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_verse_audio_dto(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected VerseDto? sse_decode_opt_box_autoadd_verse_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_verse_dto(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected WordAudioDto? sse_decode_opt_box_autoadd_word_audio_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_word_audio_dto(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected WordDto? sse_decode_opt_box_autoadd_word_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_word_dto(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_list_prim_u_8_strict(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PropagationDiagnosticsDto sse_decode_propagation_diagnostics_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_nodeFound = sse_decode_bool(deserializer);
+var var_nodeType = sse_decode_opt_String(deserializer);
+var var_totalEdges = sse_decode_u_32(deserializer);
+var var_message = sse_decode_String(deserializer);
+return PropagationDiagnosticsDto(nodeFound: var_nodeFound, nodeType: var_nodeType, totalEdges: var_totalEdges, message: var_message); }
+
+@protected PropagationResultDto sse_decode_propagation_result_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_before = sse_decode_list_node_energy_dto(deserializer);
+var var_after = sse_decode_list_node_energy_dto(deserializer);
+var var_diagnostics = sse_decode_propagation_diagnostics_dto(deserializer);
+return PropagationResultDto(before: var_before, after: var_after, diagnostics: var_diagnostics); }
+
+@protected RecitationJumpDto sse_decode_recitation_jump_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_verseKey = sse_decode_String(deserializer);
+var var_startPosition = sse_decode_i_32(deserializer);
+var var_wordCount = sse_decode_u_32(deserializer);
+var var_afterWordNodeId = sse_decode_opt_String(deserializer);
+var var_startMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+return RecitationJumpDto(verseKey: var_verseKey, startPosition: var_startPosition, wordCount: var_wordCount, afterWordNodeId: var_afterWordNodeId, startMs: var_startMs); }
+
+@protected RecitationResultDto sse_decode_recitation_result_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_words = sse_decode_list_recited_word_dto(deserializer);
+var var_jumps = sse_decode_list_recitation_jump_dto(deserializer);
+var var_extraWords = sse_decode_list_transcript_word_dto(deserializer);
+var var_accuracy = sse_decode_f_64(deserializer);
+var var_grade = sse_decode_u_8(deserializer);
+return RecitationResultDto(words: var_words, jumps: var_jumps, extraWords: var_extraWords, accuracy: var_accuracy, grade: var_grade); }
+
+@protected RecitedWordDto sse_decode_recited_word_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_wordNodeId = sse_decode_String(deserializer);
+var var_verseKey = sse_decode_String(deserializer);
+var var_position = sse_decode_i_32(deserializer);
+var var_text = sse_decode_String(deserializer);
+var var_kind = sse_decode_String(deserializer);
+var var_heard = sse_decode_opt_String(deserializer);
+var var_similarity = sse_decode_f_64(deserializer);
+var var_repeated = sse_decode_bool(deserializer);
+var var_startMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_endMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_grade = sse_decode_u_8(deserializer);
+return RecitedWordDto(wordNodeId: var_wordNodeId, verseKey: var_verseKey, position: var_position, text: var_text, kind: var_kind, heard: var_heard, similarity: var_similarity, repeated: var_repeated, startMs: var_startMs, endMs: var_endMs, grade: var_grade); }
+
+@protected ReciterDto sse_decode_reciter_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_reciterId = sse_decode_i_32(deserializer);
+var var_slug = sse_decode_String(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_style = sse_decode_opt_String(deserializer);
+var var_packageId = sse_decode_opt_String(deserializer);
+return ReciterDto(reciterId: var_reciterId, slug: var_slug, name: var_name, style: var_style, packageId: var_packageId); }
+
+@protected (String,String) sse_decode_record_string_string(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_String(deserializer);
+var var_field1 = sse_decode_String(deserializer);
+return (var_field0, var_field1); }
+
+@protected SessionDto sse_decode_session_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_String(deserializer);
+var var_userId = sse_decode_String(deserializer);
+var var_goalId = sse_decode_String(deserializer);
+var var_startedAt = sse_decode_i_64(deserializer);
+var var_completedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_itemsCount = sse_decode_i_32(deserializer);
+var var_itemsCompleted = sse_decode_i_32(deserializer);
+return SessionDto(id: var_id, userId: var_userId, goalId: var_goalId, startedAt: var_startedAt, completedAt: var_completedAt, itemsCount: var_itemsCount, itemsCompleted: var_itemsCompleted); }
+
+@protected SessionItemDto sse_decode_session_item_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_sessionId = sse_decode_String(deserializer);
+var var_position = sse_decode_i_32(deserializer);
+var var_nodeId = sse_decode_String(deserializer);
+var var_exerciseType = sse_decode_String(deserializer);
+var var_exercise = sse_decode_exercise_data_dto(deserializer);
+return SessionItemDto(sessionId: var_sessionId, position: var_position, nodeId: var_nodeId, exerciseType: var_exerciseType, exercise: var_exercise); }
+
+@protected SessionPreviewDto sse_decode_session_preview_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_nodeId = sse_decode_String(deserializer);
+var var_nodeType = sse_decode_String(deserializer);
+var var_previewText = sse_decode_String(deserializer);
+var var_energy = sse_decode_f_64(deserializer);
+var var_priorityScore = sse_decode_f_64(deserializer);
+return SessionPreviewDto(nodeId: var_nodeId, nodeType: var_nodeType, previewText: var_previewText, energy: var_energy, priorityScore: var_priorityScore); }
+
+@protected SessionSummaryDto sse_decode_session_summary_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_sessionId = sse_decode_String(deserializer);
+var var_itemsCount = sse_decode_i_32(deserializer);
+var var_itemsCompleted = sse_decode_i_32(deserializer);
+var var_durationMs = sse_decode_i_64(deserializer);
+var var_againCount = sse_decode_i_32(deserializer);
+var var_hardCount = sse_decode_i_32(deserializer);
+var var_goodCount = sse_decode_i_32(deserializer);
+var var_easyCount = sse_decode_i_32(deserializer);
+return SessionSummaryDto(sessionId: var_sessionId, itemsCount: var_itemsCount, itemsCompleted: var_itemsCompleted, durationMs: var_durationMs, againCount: var_againCount, hardCount: var_hardCount, goodCount: var_goodCount, easyCount: var_easyCount); }
+
+@protected SurahInfo sse_decode_surah_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_number = sse_decode_i_32(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_nameArabic = sse_decode_String(deserializer);
+var var_nameTransliteration = sse_decode_String(deserializer);
+var var_nameTranslation = sse_decode_String(deserializer);
+var var_verseCount = sse_decode_i_32(deserializer);
+var var_revelationPlace = sse_decode_opt_String(deserializer);
+return SurahInfo(number: var_number, name: var_name, nameArabic: var_nameArabic, nameTransliteration: var_nameTransliteration, nameTranslation: var_nameTranslation, verseCount: var_verseCount, revelationPlace: var_revelationPlace); }
+
+@protected SyncMemoryStateDto sse_decode_sync_memory_state_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_nodeId = sse_decode_i_64(deserializer);
+var var_energy = sse_decode_f_64(deserializer);
+var var_fsrsStability = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_fsrsDifficulty = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_lastReviewedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_nextReviewAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_clientUpdatedAt = sse_decode_i_64(deserializer);
+return SyncMemoryStateDto(nodeId: var_nodeId, energy: var_energy, fsrsStability: var_fsrsStability, fsrsDifficulty: var_fsrsDifficulty, lastReviewedAt: var_lastReviewedAt, nextReviewAt: var_nextReviewAt, clientUpdatedAt: var_clientUpdatedAt); }
+
+@protected SyncSessionDto sse_decode_sync_session_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_String(deserializer);
+var var_goalId = sse_decode_opt_String(deserializer);
+var var_startedAt = sse_decode_i_64(deserializer);
+var var_completedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_itemsCompleted = sse_decode_i_32(deserializer);
+var var_clientUpdatedAt = sse_decode_i_64(deserializer);
+return SyncSessionDto(id: var_id, goalId: var_goalId, startedAt: var_startedAt, completedAt: var_completedAt, itemsCompleted: var_itemsCompleted, clientUpdatedAt: var_clientUpdatedAt); }
+
+@protected SyncSessionItemDto sse_decode_sync_session_item_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_String(deserializer);
+var var_sessionId = sse_decode_String(deserializer);
+var var_nodeId = sse_decode_i_64(deserializer);
+var var_exerciseType = sse_decode_String(deserializer);
+var var_grade = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_durationMs = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_completedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_clientUpdatedAt = sse_decode_i_64(deserializer);
+return SyncSessionItemDto(id: var_id, sessionId: var_sessionId, nodeId: var_nodeId, exerciseType: var_exerciseType, grade: var_grade, durationMs: var_durationMs, completedAt: var_completedAt, clientUpdatedAt: var_clientUpdatedAt); }
+
+@protected TafsirDto sse_decode_tafsir_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_tafsirId = sse_decode_i_32(deserializer);
+var var_slug = sse_decode_String(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_author = sse_decode_opt_String(deserializer);
+var var_languageCode = sse_decode_opt_String(deserializer);
+return TafsirDto(tafsirId: var_tafsirId, slug: var_slug, name: var_name, author: var_author, languageCode: var_languageCode); }
+
+@protected TafsirPassageDto sse_decode_tafsir_passage_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_tafsirId = sse_decode_i_32(deserializer);
+var var_chapterNumber = sse_decode_i_32(deserializer);
+var var_startVerse = sse_decode_i_32(deserializer);
+var var_endVerse = sse_decode_i_32(deserializer);
+var var_text = sse_decode_String(deserializer);
+var var_keyLesson = sse_decode_opt_String(deserializer);
+var var_revelationOccasion = sse_decode_opt_String(deserializer);
+var var_themes = sse_decode_list_String(deserializer);
+return TafsirPassageDto(tafsirId: var_tafsirId, chapterNumber: var_chapterNumber, startVerse: var_startVerse, endVerse: var_endVerse, text: var_text, keyLesson: var_keyLesson, revelationOccasion: var_revelationOccasion, themes: var_themes); }
+
+@protected TajweedAnnotationDto sse_decode_tajweed_annotation_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_wordNodeId = sse_decode_String(deserializer);
+var var_position = sse_decode_i_32(deserializer);
+var var_rule = sse_decode_String(deserializer);
+var var_ruleName = sse_decode_String(deserializer);
+var var_startChar = sse_decode_i_32(deserializer);
+var var_endChar = sse_decode_i_32(deserializer);
+return TajweedAnnotationDto(wordNodeId: var_wordNodeId, position: var_position, rule: var_rule, ruleName: var_ruleName, startChar: var_startChar, endChar: var_endChar); }
+
+@protected TranscriptWordDto sse_decode_transcript_word_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_text = sse_decode_String(deserializer);
+var var_startMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_endMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+return TranscriptWordDto(text: var_text, startMs: var_startMs, endMs: var_endMs); }
+
+@protected TranslatorDto sse_decode_translator_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_32(deserializer);
+var var_slug = sse_decode_String(deserializer);
+var var_fullName = sse_decode_String(deserializer);
+var var_languageCode = sse_decode_String(deserializer);
+var var_description = sse_decode_opt_String(deserializer);
+var var_license = sse_decode_opt_String(deserializer);
+return TranslatorDto(id: var_id, slug: var_slug, fullName: var_fullName, languageCode: var_languageCode, description: var_description, license: var_license); }
+
+@protected int sse_decode_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint32(); }
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected int sse_decode_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8(); }
+
+@protected void sse_decode_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected ValidationResultDto sse_decode_validation_result_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_isCorrect = sse_decode_bool(deserializer);
+var var_feedback = sse_decode_opt_String(deserializer);
+var var_similarityScore = sse_decode_opt_box_autoadd_f_32(deserializer);
+var var_semanticGrade = sse_decode_opt_String(deserializer);
+var var_correctAnswer = sse_decode_opt_String(deserializer);
+var var_suggestedGrade = sse_decode_u_8(deserializer);
+return ValidationResultDto(isCorrect: var_isCorrect, feedback: var_feedback, similarityScore: var_similarityScore, semanticGrade: var_semanticGrade, correctAnswer: var_correctAnswer, suggestedGrade: var_suggestedGrade); }
+
+@protected VerseAudioDto sse_decode_verse_audio_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_reciterId = sse_decode_i_32(deserializer);
+var var_verseKey = sse_decode_String(deserializer);
+var var_audioPath = sse_decode_opt_String(deserializer);
+var var_audioBytes = sse_decode_opt_list_prim_u_8_strict(deserializer);
+var var_mimeType = sse_decode_String(deserializer);
+var var_durationMs = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_segments = sse_decode_list_word_segment_dto(deserializer);
+return VerseAudioDto(reciterId: var_reciterId, verseKey: var_verseKey, audioPath: var_audioPath, audioBytes: var_audioBytes, mimeType: var_mimeType, durationMs: var_durationMs, segments: var_segments); }
+
+@protected VerseDto sse_decode_verse_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_key = sse_decode_String(deserializer);
+var var_textUthmani = sse_decode_String(deserializer);
+var var_chapterNumber = sse_decode_i_32(deserializer);
+var var_verseNumber = sse_decode_i_32(deserializer);
+return VerseDto(key: var_key, textUthmani: var_textUthmani, chapterNumber: var_chapterNumber, verseNumber: var_verseNumber); }
+
+@protected VerseWithTranslationDto sse_decode_verse_with_translation_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_key = sse_decode_String(deserializer);
+var var_textUthmani = sse_decode_String(deserializer);
+var var_translation = sse_decode_opt_String(deserializer);
+var var_number = sse_decode_i_32(deserializer);
+return VerseWithTranslationDto(key: var_key, textUthmani: var_textUthmani, translation: var_translation, number: var_number); }
+
+@protected WordAudioDto sse_decode_word_audio_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_reciterId = sse_decode_i_32(deserializer);
+var var_verseKey = sse_decode_String(deserializer);
+var var_position = sse_decode_i_32(deserializer);
+var var_audioPath = sse_decode_opt_String(deserializer);
+var var_audioBytes = sse_decode_opt_list_prim_u_8_strict(deserializer);
+var var_mimeType = sse_decode_String(deserializer);
+var var_durationMs = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_startMs = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_endMs = sse_decode_opt_box_autoadd_i_64(deserializer);
+return WordAudioDto(reciterId: var_reciterId, verseKey: var_verseKey, position: var_position, audioPath: var_audioPath, audioBytes: var_audioBytes, mimeType: var_mimeType, durationMs: var_durationMs, startMs: var_startMs, endMs: var_endMs); }
+
+@protected WordDto sse_decode_word_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_32(deserializer);
+var var_textUthmani = sse_decode_String(deserializer);
+var var_verseKey = sse_decode_String(deserializer);
+var var_position = sse_decode_i_32(deserializer);
+return WordDto(id: var_id, textUthmani: var_textUthmani, verseKey: var_verseKey, position: var_position); }
+
+@protected WordSegmentDto sse_decode_word_segment_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_position = sse_decode_i_32(deserializer);
+var var_startMs = sse_decode_i_64(deserializer);
+var var_endMs = sse_decode_i_64(deserializer);
+return WordSegmentDto(position: var_position, startMs: var_startMs, endMs: var_endMs); }
+
+@protected WordTimingDto sse_decode_word_timing_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_wordNodeId = sse_decode_String(deserializer);
+var var_durationMs = sse_decode_u_64(deserializer);
+return WordTimingDto(wordNodeId: var_wordNodeId, durationMs: var_durationMs); }
+
+@protected WordVisibilityDto sse_decode_word_visibility_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_visibilityType = sse_decode_String(deserializer);
+var var_hint = sse_decode_opt_box_autoadd_hint_dto(deserializer);
+var var_coverage = sse_decode_opt_box_autoadd_f_64(deserializer);
+return WordVisibilityDto(visibilityType: var_visibilityType, hint: var_hint, coverage: var_coverage); }
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.message, serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(AppState self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as AppStateImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseService(ArcExerciseService self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as ArcExerciseServiceImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningService(ArcLearningService self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as ArcLearningServiceImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionService(ArcSessionService self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as ArcSessionServiceImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepository(ArcContentRepository self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as ArcContentRepositoryImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepository(ArcUserRepository self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as ArcUserRepositoryImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(AppState self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as AppStateImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(AppState self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as AppStateImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Map_String_String_None(Map<String, String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_record_string_string(self.entries.map((e) => (e.key, e.value)).toList(), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(AppState self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as AppStateImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseService(ArcExerciseService self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as ArcExerciseServiceImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningService(ArcLearningService self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as ArcLearningServiceImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionService(ArcSessionService self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as ArcSessionServiceImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepository(ArcContentRepository self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as ArcContentRepositoryImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepository(ArcUserRepository self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as ArcUserRepositoryImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_String(String self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer); }
+
+@protected void sse_encode_activity_point_dto(ActivityPointDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.date, serializer);
+sse_encode_i_32(self.count, serializer);
+ }
+
+@protected void sse_encode_ayah_chain_state_dto(AyahChainStateDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.nodeId, serializer);
+sse_encode_opt_String(self.currentVerseKey, serializer);
+sse_encode_u_32(self.completedCount, serializer);
+sse_encode_u_32(self.totalVerses, serializer);
+sse_encode_bool(self.isComplete, serializer);
+sse_encode_bool(self.mistakeMade, serializer);
+sse_encode_opt_box_autoadd_u_8(self.lastGrade, serializer);
+ }
+
+@protected void sse_encode_ayah_junction_dto(AyahJunctionDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.fromVerse, serializer);
+sse_encode_String(self.toVerse, serializer);
+sse_encode_String(self.closingWord, serializer);
+sse_encode_String(self.openingWord, serializer);
+sse_encode_f_64(self.strength, serializer);
+ }
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self ? 1 : 0); }
+
+@protected void sse_encode_box_autoadd_ayah_chain_state_dto(AyahChainStateDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_ayah_chain_state_dto(self, serializer); }
+
+@protected void sse_encode_box_autoadd_distractor_strategy_dto(DistractorStrategyDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_distractor_strategy_dto(self, serializer); }
+
+@protected void sse_encode_box_autoadd_echo_recall_metrics_dto(EchoRecallMetricsDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_echo_recall_metrics_dto(self, serializer); }
+
+@protected void sse_encode_box_autoadd_echo_recall_state_dto(EchoRecallStateDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_echo_recall_state_dto(self, serializer); }
+
+@protected void sse_encode_box_autoadd_exercise_data_dto(ExerciseDataDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_exercise_data_dto(self, serializer); }
+
+@protected void sse_encode_box_autoadd_exercise_hint_dto(ExerciseHintDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_exercise_hint_dto(self, serializer); }
+
+@protected void sse_encode_box_autoadd_f_32(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_32(self, serializer); }
+
+@protected void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_hint_dto(HintDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_hint_dto(self, serializer); }
+
+@protected void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self, serializer); }
+
+@protected void sse_encode_box_autoadd_i_64(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_node_data(NodeData self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_node_data(self, serializer); }
+
+@protected void sse_encode_box_autoadd_node_filter_dto(NodeFilterDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_node_filter_dto(self, serializer); }
+
+@protected void sse_encode_box_autoadd_session_dto(SessionDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_session_dto(self, serializer); }
+
+@protected void sse_encode_box_autoadd_session_item_dto(SessionItemDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_session_item_dto(self, serializer); }
+
+@protected void sse_encode_box_autoadd_tafsir_passage_dto(TafsirPassageDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_tafsir_passage_dto(self, serializer); }
+
+@protected void sse_encode_box_autoadd_translator_dto(TranslatorDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_translator_dto(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_8(self, serializer); }
+
+@protected void sse_encode_box_autoadd_verse_audio_dto(VerseAudioDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_verse_audio_dto(self, serializer); }
+
+@protected void sse_encode_box_autoadd_verse_dto(VerseDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_verse_dto(self, serializer); }
+
+@protected void sse_encode_box_autoadd_word_audio_dto(WordAudioDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_word_audio_dto(self, serializer); }
+
+@protected void sse_encode_box_autoadd_word_dto(WordDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_word_dto(self, serializer); }
+
+@protected void sse_encode_comprehension_dto(ComprehensionDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self.memorization, serializer);
+sse_encode_f_64(self.understanding, serializer);
+sse_encode_f_64(self.context, serializer);
+ }
+
+@protected void sse_encode_content_package_dto(ContentPackageDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.packageId, serializer);
+sse_encode_String(self.packageType, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_opt_String(self.languageCode, serializer);
+sse_encode_opt_String(self.author, serializer);
+sse_encode_String(self.version, serializer);
+sse_encode_opt_String(self.description, serializer);
+sse_encode_opt_box_autoadd_i_64(self.fileSize, serializer);
+sse_encode_opt_String(self.downloadUrl, serializer);
+sse_encode_opt_String(self.checksum, serializer);
+sse_encode_opt_String(self.license, serializer);
+ }
+
+@protected void sse_encode_dashboard_stats_dto(DashboardStatsDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.reviewsToday, serializer);
+sse_encode_u_32(self.streakDays, serializer);
+sse_encode_u_32(self.dueCount, serializer);
+ }
+
+@protected void sse_encode_db_health_dto(DbHealthDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.chaptersCount, serializer);
+sse_encode_i_64(self.versesCount, serializer);
+sse_encode_i_64(self.wordsCount, serializer);
+sse_encode_i_64(self.nodesCount, serializer);
+sse_encode_i_64(self.edgesCount, serializer);
+sse_encode_i_64(self.userMemoryCount, serializer);
+sse_encode_bool(self.isHealthy, serializer);
+sse_encode_list_String(self.issues, serializer);
+ }
+
+@protected void sse_encode_db_query_result_dto(DbQueryResultDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_String(self.columns, serializer);
+sse_encode_list_list_String(self.rows, serializer);
+ }
+
+@protected void sse_encode_debug_stats_dto(DebugStatsDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.totalNodesCount, serializer);
+sse_encode_u_32(self.totalEdgesCount, serializer);
+sse_encode_u_32(self.dueCount, serializer);
+ }
+
+@protected void sse_encode_detailed_stats_dto(DetailedStatsDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_activity_point_dto(self.activityHistory, serializer);
+sse_encode_comprehension_dto(self.comprehension, serializer);
+ }
+
+@protected void sse_encode_distractor_strategy_dto(DistractorStrategyDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.difficulty, serializer);
+sse_encode_list_String(self.signals, serializer);
+ }
+
+@protected void sse_encode_echo_recall_metrics_dto(EchoRecallMetricsDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_word_timing_dto(self.wordTimings, serializer);
+sse_encode_u_64(self.totalDurationMs, serializer);
+sse_encode_u_32(self.struggles, serializer);
+ }
+
+@protected void sse_encode_echo_recall_result_dto(EchoRecallResultDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_energy_update_dto(self.energyUpdates, serializer);
+sse_encode_u_32(self.wordsProcessed, serializer);
+sse_encode_f_64(self.averageEnergy, serializer);
+ }
+
+@protected void sse_encode_echo_recall_state_dto(EchoRecallStateDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_echo_recall_word_dto(self.words, serializer);
+ }
+
+@protected void sse_encode_echo_recall_stats_dto(EchoRecallStatsDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.totalWords, serializer);
+sse_encode_u_32(self.visibleCount, serializer);
+sse_encode_u_32(self.obscuredCount, serializer);
+sse_encode_u_32(self.hiddenCount, serializer);
+sse_encode_f_64(self.averageEnergy, serializer);
+sse_encode_f_64(self.masteryPercentage, serializer);
+ }
+
+@protected void sse_encode_echo_recall_word_dto(EchoRecallWordDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.nodeId, serializer);
+sse_encode_String(self.text, serializer);
+sse_encode_word_visibility_dto(self.visibility, serializer);
+sse_encode_f_64(self.energy, serializer);
+ }
+
+@protected void sse_encode_energy_snapshot_dto(EnergySnapshotDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.nodeId, serializer);
+sse_encode_f_64(self.energy, serializer);
+sse_encode_opt_String(self.nodeType, serializer);
+sse_encode_opt_String(self.knowledgeAxis, serializer);
+sse_encode_list_node_energy_dto(self.neighbors, serializer);
+ }
+
+@protected void sse_encode_energy_update_dto(EnergyUpdateDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.nodeId, serializer);
+sse_encode_f_64(self.energy, serializer);
+ }
+
+@protected void sse_encode_exercise_data_dto(ExerciseDataDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case ExerciseDataDto_Memorization(nodeId: final nodeId,seed: final seed): sse_encode_i_32(0, serializer); sse_encode_String(nodeId, serializer);
+sse_encode_u_64(seed, serializer);
+case ExerciseDataDto_McqArToEn(nodeId: final nodeId,distractorNodeIds: final distractorNodeIds,distractorStrategy: final distractorStrategy,seed: final seed): sse_encode_i_32(1, serializer); sse_encode_String(nodeId, serializer);
+sse_encode_list_String(distractorNodeIds, serializer);
+sse_encode_distractor_strategy_dto(distractorStrategy, serializer);
+sse_encode_u_64(seed, serializer);
+case ExerciseDataDto_McqEnToAr(nodeId: final nodeId,distractorNodeIds: final distractorNodeIds,distractorStrategy: final distractorStrategy,seed: final seed): sse_encode_i_32(2, serializer); sse_encode_String(nodeId, serializer);
+sse_encode_list_String(distractorNodeIds, serializer);
+sse_encode_distractor_strategy_dto(distractorStrategy, serializer);
+sse_encode_u_64(seed, serializer);
+case ExerciseDataDto_Translation(nodeId: final nodeId,seed: final seed): sse_encode_i_32(3, serializer); sse_encode_String(nodeId, serializer);
+sse_encode_u_64(seed, serializer);
+case ExerciseDataDto_ContextualTranslation(nodeId: final nodeId,verseKey: final verseKey,seed: final seed): sse_encode_i_32(4, serializer); sse_encode_String(nodeId, serializer);
+sse_encode_String(verseKey, serializer);
+sse_encode_u_64(seed, serializer);
+case ExerciseDataDto_ClozeDeletion(nodeId: final nodeId,blankPosition: final blankPosition,seed: final seed): sse_encode_i_32(5, serializer); sse_encode_String(nodeId, serializer);
+sse_encode_i_32(blankPosition, serializer);
+sse_encode_u_64(seed, serializer);
+case ExerciseDataDto_FirstLetterHint(nodeId: final nodeId,wordPosition: final wordPosition,seed: final seed): sse_encode_i_32(6, serializer); sse_encode_String(nodeId, serializer);
+sse_encode_i_32(wordPosition, serializer);
+sse_encode_u_64(seed, serializer);
+case ExerciseDataDto_MissingWordMcq(nodeId: final nodeId,blankPosition: final blankPosition,distractorNodeIds: final distractorNodeIds,distractorStrategy: final distractorStrategy,seed: final seed): sse_encode_i_32(7, serializer); sse_encode_String(nodeId, serializer);
+sse_encode_i_32(blankPosition, serializer);
+sse_encode_list_String(distractorNodeIds, serializer);
+sse_encode_distractor_strategy_dto(distractorStrategy, serializer);
+sse_encode_u_64(seed, serializer);
+case ExerciseDataDto_NextWordMcq(nodeId: final nodeId,contextPosition: final contextPosition,distractorNodeIds: final distractorNodeIds,distractorStrategy: final distractorStrategy,seed: final seed): sse_encode_i_32(8, serializer); sse_encode_String(nodeId, serializer);
+sse_encode_i_32(contextPosition, serializer);
+sse_encode_list_String(distractorNodeIds, serializer);
+sse_encode_distractor_strategy_dto(distractorStrategy, serializer);
+sse_encode_u_64(seed, serializer);
+case ExerciseDataDto_FullVerseInput(nodeId: final nodeId,seed: final seed): sse_encode_i_32(9, serializer); sse_encode_String(nodeId, serializer);
+sse_encode_u_64(seed, serializer);
+case ExerciseDataDto_AyahChain(nodeId: final nodeId,verseKeys: final verseKeys,currentIndex: final currentIndex,completedCount: final completedCount,seed: final seed): sse_encode_i_32(10, serializer); sse_encode_String(nodeId, serializer);
+sse_encode_list_String(verseKeys, serializer);
+sse_encode_usize(currentIndex, serializer);
+sse_encode_usize(completedCount, serializer);
+sse_encode_u_64(seed, serializer);
+case ExerciseDataDto_FindMistake(nodeId: final nodeId,mistakePosition: final mistakePosition,correctWordNodeId: final correctWordNodeId,incorrectWordNodeId: final incorrectWordNodeId,seed: final seed): sse_encode_i_32(11, serializer); sse_encode_String(nodeId, serializer);
+sse_encode_i_32(mistakePosition, serializer);
+sse_encode_String(correctWordNodeId, serializer);
+sse_encode_String(incorrectWordNodeId, serializer);
+sse_encode_u_64(seed, serializer);
+case ExerciseDataDto_AyahSequence(nodeId: final nodeId,correctSequence: final correctSequence,seed: final seed): sse_encode_i_32(12, serializer); sse_encode_String(nodeId, serializer);
+sse_encode_list_String(correctSequence, serializer);
+sse_encode_u_64(seed, serializer);
+case ExerciseDataDto_SequenceRecall(nodeId: final nodeId,correctSequence: final correctSequence,options: final options,seed: final seed): sse_encode_i_32(13, serializer); sse_encode_String(nodeId, serializer);
+sse_encode_list_String(correctSequence, serializer);
+sse_encode_list_list_String(options, serializer);
+sse_encode_u_64(seed, serializer);
+case ExerciseDataDto_FirstWordRecall(nodeId: final nodeId,verseKey: final verseKey,seed: final seed): sse_encode_i_32(14, serializer); sse_encode_String(nodeId, serializer);
+sse_encode_String(verseKey, serializer);
+sse_encode_u_64(seed, serializer);
+case ExerciseDataDto_IdentifyRoot(nodeId: final nodeId,root: final root,seed: final seed): sse_encode_i_32(15, serializer); sse_encode_String(nodeId, serializer);
+sse_encode_String(root, serializer);
+sse_encode_u_64(seed, serializer);
+case ExerciseDataDto_ReverseCloze(nodeId: final nodeId,blankPosition: final blankPosition,seed: final seed): sse_encode_i_32(16, serializer); sse_encode_String(nodeId, serializer);
+sse_encode_i_32(blankPosition, serializer);
+sse_encode_u_64(seed, serializer);
+case ExerciseDataDto_TranslatePhrase(nodeId: final nodeId,translatorId: final translatorId,seed: final seed): sse_encode_i_32(17, serializer); sse_encode_String(nodeId, serializer);
+sse_encode_i_32(translatorId, serializer);
+sse_encode_u_64(seed, serializer);
+case ExerciseDataDto_PosTagging(nodeId: final nodeId,correctPos: final correctPos,options: final options,seed: final seed): sse_encode_i_32(18, serializer); sse_encode_String(nodeId, serializer);
+sse_encode_String(correctPos, serializer);
+sse_encode_list_String(options, serializer);
+sse_encode_u_64(seed, serializer);
+case ExerciseDataDto_GrammarFeature(nodeId: final nodeId,feature: final feature,prompt: final prompt,correctValue: final correctValue,options: final options,seed: final seed): sse_encode_i_32(19, serializer); sse_encode_String(nodeId, serializer);
+sse_encode_String(feature, serializer);
+sse_encode_String(prompt, serializer);
+sse_encode_String(correctValue, serializer);
+sse_encode_list_String(options, serializer);
+sse_encode_u_64(seed, serializer);
+case ExerciseDataDto_CrossVerseConnection(nodeId: final nodeId,relatedVerseIds: final relatedVerseIds,connectionTheme: final connectionTheme,seed: final seed): sse_encode_i_32(20, serializer); sse_encode_String(nodeId, serializer);
+sse_encode_list_String(relatedVerseIds, serializer);
+sse_encode_String(connectionTheme, serializer);
+sse_encode_u_64(seed, serializer);
+case ExerciseDataDto_EchoRecall(userId: final userId,ayahNodeIds: final ayahNodeIds,seed: final seed): sse_encode_i_32(21, serializer); sse_encode_String(userId, serializer);
+sse_encode_list_String(ayahNodeIds, serializer);
+sse_encode_u_64(seed, serializer);
+case ExerciseDataDto_Mutashabihat(nodeId: final nodeId,verseKey: final verseKey,promptWordCount: final promptWordCount,optionVerseKeys: final optionVerseKeys,differingPositions: final differingPositions,seed: final seed): sse_encode_i_32(22, serializer); sse_encode_String(nodeId, serializer);
+sse_encode_String(verseKey, serializer);
+sse_encode_i_32(promptWordCount, serializer);
+sse_encode_list_String(optionVerseKeys, serializer);
+sse_encode_list_prim_i_32_strict(differingPositions, serializer);
+sse_encode_u_64(seed, serializer);
+case ExerciseDataDto_WordOrder(nodeId: final nodeId,correctSequence: final correctSequence,shuffledWordNodeIds: final shuffledWordNodeIds,tileTexts: final tileTexts,seed: final seed): sse_encode_i_32(23, serializer); sse_encode_String(nodeId, serializer);
+sse_encode_list_String(correctSequence, serializer);
+sse_encode_list_String(shuffledWordNodeIds, serializer);
+sse_encode_list_String(tileTexts, serializer);
+sse_encode_u_64(seed, serializer);
+case ExerciseDataDto_IdentifySurah(nodeId: final nodeId,verseKey: final verseKey,correctChapter: final correctChapter,chapterOptions: final chapterOptions,seed: final seed): sse_encode_i_32(24, serializer); sse_encode_String(nodeId, serializer);
+sse_encode_String(verseKey, serializer);
+sse_encode_i_32(correctChapter, serializer);
+sse_encode_list_prim_i_32_strict(chapterOptions, serializer);
+sse_encode_u_64(seed, serializer);
+case ExerciseDataDto_IdentifyVerseNumber(nodeId: final nodeId,verseKey: final verseKey,correctVerseNumber: final correctVerseNumber,verseNumberOptions: final verseNumberOptions,seed: final seed): sse_encode_i_32(25, serializer); sse_encode_String(nodeId, serializer);
+sse_encode_String(verseKey, serializer);
+sse_encode_i_32(correctVerseNumber, serializer);
+sse_encode_list_prim_i_32_strict(verseNumberOptions, serializer);
+sse_encode_u_64(seed, serializer);
+case ExerciseDataDto_IdentifyPageHalf(nodeId: final nodeId,verseKey: final verseKey,page: final page,half: final half,seed: final seed): sse_encode_i_32(26, serializer); sse_encode_String(nodeId, serializer);
+sse_encode_String(verseKey, serializer);
+sse_encode_i_32(page, serializer);
+sse_encode_i_32(half, serializer);
+sse_encode_u_64(seed, serializer);
+case ExerciseDataDto_TransliterationToArabic(nodeId: final nodeId,distractorNodeIds: final distractorNodeIds,distractorStrategy: final distractorStrategy,seed: final seed): sse_encode_i_32(27, serializer); sse_encode_String(nodeId, serializer);
+sse_encode_list_String(distractorNodeIds, serializer);
+sse_encode_distractor_strategy_dto(distractorStrategy, serializer);
+sse_encode_u_64(seed, serializer);
+case ExerciseDataDto_ArabicToTransliteration(nodeId: final nodeId,verseKey: final verseKey,seed: final seed): sse_encode_i_32(28, serializer); sse_encode_String(nodeId, serializer);
+sse_encode_String(verseKey, serializer);
+sse_encode_u_64(seed, serializer);
+case ExerciseDataDto_RootFamily(nodeId: final nodeId,rootId: final rootId,verseKeys: final verseKeys,familyWordNodeIds: final familyWordNodeIds,seed: final seed): sse_encode_i_32(29, serializer); sse_encode_String(nodeId, serializer);
+sse_encode_String(rootId, serializer);
+sse_encode_list_String(verseKeys, serializer);
+sse_encode_list_String(familyWordNodeIds, serializer);
+sse_encode_u_64(seed, serializer);
+case ExerciseDataDto_LemmaMeaning(nodeId: final nodeId,rootId: final rootId,lemmaIds: final lemmaIds,meaningLemmaIds: final meaningLemmaIds,seed: final seed): sse_encode_i_32(30, serializer); sse_encode_String(nodeId, serializer);
+sse_encode_String(rootId, serializer);
+sse_encode_list_String(lemmaIds, serializer);
+sse_encode_list_String(meaningLemmaIds, serializer);
+sse_encode_u_64(seed, serializer);
+case ExerciseDataDto_RecitePage(nodeId: final nodeId,page: final page,verseKeys: final verseKeys,firstWordNodeId: final firstWordNodeId,lastWordNodeId: final lastWordNodeId,seed: final seed): sse_encode_i_32(31, serializer); sse_encode_String(nodeId, serializer);
+sse_encode_i_32(page, serializer);
+sse_encode_list_String(verseKeys, serializer);
+sse_encode_String(firstWordNodeId, serializer);
+sse_encode_String(lastWordNodeId, serializer);
+sse_encode_u_64(seed, serializer);
+case ExerciseDataDto_PageFirstWord(nodeId: final nodeId,page: final page,firstWordNodeId: final firstWordNodeId,distractorNodeIds: final distractorNodeIds,seed: final seed): sse_encode_i_32(32, serializer); sse_encode_String(nodeId, serializer);
+sse_encode_i_32(page, serializer);
+sse_encode_String(firstWordNodeId, serializer);
+sse_encode_list_String(distractorNodeIds, serializer);
+sse_encode_u_64(seed, serializer);
+case ExerciseDataDto_IdentifyVerseLine(nodeId: final nodeId,verseKey: final verseKey,page: final page,correctLine: final correctLine,lineOptions: final lineOptions,seed: final seed): sse_encode_i_32(33, serializer); sse_encode_String(nodeId, serializer);
+sse_encode_String(verseKey, serializer);
+sse_encode_i_32(page, serializer);
+sse_encode_i_32(correctLine, serializer);
+sse_encode_list_prim_i_32_strict(lineOptions, serializer);
+sse_encode_u_64(seed, serializer);
+case ExerciseDataDto_ListenIdentifyVerse(nodeId: final nodeId,reciterId: final reciterId,verseKey: final verseKey,firstPosition: final firstPosition,lastPosition: final lastPosition,startMs: final startMs,endMs: final endMs,optionVerseKeys: final optionVerseKeys,seed: final seed): sse_encode_i_32(34, serializer); sse_encode_String(nodeId, serializer);
+sse_encode_i_32(reciterId, serializer);
+sse_encode_String(verseKey, serializer);
+sse_encode_i_32(firstPosition, serializer);
+sse_encode_i_32(lastPosition, serializer);
+sse_encode_i_64(startMs, serializer);
+sse_encode_i_64(endMs, serializer);
+sse_encode_list_String(optionVerseKeys, serializer);
+sse_encode_u_64(seed, serializer);
+case ExerciseDataDto_ListenContinue(nodeId: final nodeId,reciterId: final reciterId,verseKey: final verseKey,firstPosition: final firstPosition,lastPosition: final lastPosition,startMs: final startMs,endMs: final endMs,distractorNodeIds: final distractorNodeIds,distractorStrategy: final distractorStrategy,seed: final seed): sse_encode_i_32(35, serializer); sse_encode_String(nodeId, serializer);
+sse_encode_i_32(reciterId, serializer);
+sse_encode_String(verseKey, serializer);
+sse_encode_i_32(firstPosition, serializer);
+sse_encode_i_32(lastPosition, serializer);
+sse_encode_i_64(startMs, serializer);
+sse_encode_i_64(endMs, serializer);
+sse_encode_list_String(distractorNodeIds, serializer);
+sse_encode_distractor_strategy_dto(distractorStrategy, serializer);
+sse_encode_u_64(seed, serializer);
+case ExerciseDataDto_ListenIdentifyWord(nodeId: final nodeId,reciterId: final reciterId,verseKey: final verseKey,position: final position,startMs: final startMs,endMs: final endMs,distractorNodeIds: final distractorNodeIds,distractorStrategy: final distractorStrategy,seed: final seed): sse_encode_i_32(36, serializer); sse_encode_String(nodeId, serializer);
+sse_encode_i_32(reciterId, serializer);
+sse_encode_String(verseKey, serializer);
+sse_encode_i_32(position, serializer);
+sse_encode_opt_box_autoadd_i_64(startMs, serializer);
+sse_encode_opt_box_autoadd_i_64(endMs, serializer);
+sse_encode_list_String(distractorNodeIds, serializer);
+sse_encode_distractor_strategy_dto(distractorStrategy, serializer);
+sse_encode_u_64(seed, serializer);
+case ExerciseDataDto_IdentifyTajweedRule(nodeId: final nodeId,wordNodeId: final wordNodeId,startChar: final startChar,endChar: final endChar,correctRule: final correctRule,ruleOptions: final ruleOptions,optionNames: final optionNames,seed: final seed): sse_encode_i_32(37, serializer); sse_encode_String(nodeId, serializer);
+sse_encode_String(wordNodeId, serializer);
+sse_encode_i_32(startChar, serializer);
+sse_encode_i_32(endChar, serializer);
+sse_encode_String(correctRule, serializer);
+sse_encode_list_String(ruleOptions, serializer);
+sse_encode_list_String(optionNames, serializer);
+sse_encode_u_64(seed, serializer);
+case ExerciseDataDto_FindTajweedRule(nodeId: final nodeId,rule: final rule,ruleName: final ruleName,ruleWordNodeIds: final ruleWordNodeIds,seed: final seed): sse_encode_i_32(38, serializer); sse_encode_String(nodeId, serializer);
+sse_encode_String(rule, serializer);
+sse_encode_String(ruleName, serializer);
+sse_encode_list_String(ruleWordNodeIds, serializer);
+sse_encode_u_64(seed, serializer);
+case ExerciseDataDto_TafsirKeyLesson(nodeId: final nodeId,tafsirId: final tafsirId,passageKey: final passageKey,optionPassageKeys: final optionPassageKeys,seed: final seed): sse_encode_i_32(39, serializer); sse_encode_String(nodeId, serializer);
+sse_encode_i_32(tafsirId, serializer);
+sse_encode_String(passageKey, serializer);
+sse_encode_list_String(optionPassageKeys, serializer);
+sse_encode_u_64(seed, serializer);
+case ExerciseDataDto_RevelationOccasion(nodeId: final nodeId,tafsirId: final tafsirId,passageKey: final passageKey,optionVerseKeys: final optionVerseKeys,seed: final seed): sse_encode_i_32(40, serializer); sse_encode_String(nodeId, serializer);
+sse_encode_i_32(tafsirId, serializer);
+sse_encode_String(passageKey, serializer);
+sse_encode_list_String(optionVerseKeys, serializer);
+sse_encode_u_64(seed, serializer);
+  } }
+
+@protected void sse_encode_exercise_hint_dto(ExerciseHintDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_8(self.level, serializer);
+sse_encode_String(self.levelName, serializer);
+sse_encode_String(self.text, serializer);
+sse_encode_u_8(self.maxGrade, serializer);
+ }
+
+@protected void sse_encode_f_32(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat32(self); }
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat64(self); }
+
+@protected void sse_encode_hint_dto(HintDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.hintType, serializer);
+sse_encode_opt_String(self.firstChar, serializer);
+sse_encode_opt_String(self.lastChar, serializer);
+ }
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putInt32(self); }
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putPlatformInt64(self); }
+
+@protected void sse_encode_installed_package_dto(InstalledPackageDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.packageId, serializer);
+sse_encode_i_64(self.installedAt, serializer);
+sse_encode_bool(self.enabled, serializer);
+ }
+
+@protected void sse_encode_language_dto(LanguageDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.code, serializer);
+sse_encode_String(self.englishName, serializer);
+sse_encode_String(self.nativeName, serializer);
+sse_encode_String(self.direction, serializer);
+ }
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_String(item, serializer); } }
+
+@protected void sse_encode_list_activity_point_dto(List<ActivityPointDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_activity_point_dto(item, serializer); } }
+
+@protected void sse_encode_list_ayah_junction_dto(List<AyahJunctionDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_ayah_junction_dto(item, serializer); } }
+
+@protected void sse_encode_list_content_package_dto(List<ContentPackageDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_content_package_dto(item, serializer); } }
+
+@protected void sse_encode_list_echo_recall_word_dto(List<EchoRecallWordDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_echo_recall_word_dto(item, serializer); } }
+
+@protected void sse_encode_list_energy_update_dto(List<EnergyUpdateDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_energy_update_dto(item, serializer); } }
+
+@protected void sse_encode_list_exercise_data_dto(List<ExerciseDataDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_exercise_data_dto(item, serializer); } }
+
+@protected void sse_encode_list_exercise_hint_dto(List<ExerciseHintDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_exercise_hint_dto(item, serializer); } }
+
+@protected void sse_encode_list_installed_package_dto(List<InstalledPackageDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_installed_package_dto(item, serializer); } }
+
+@protected void sse_encode_list_language_dto(List<LanguageDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_language_dto(item, serializer); } }
+
+@protected void sse_encode_list_list_String(List<List<String>> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_list_String(item, serializer); } }
+
+@protected void sse_encode_list_node_energy_dto(List<NodeEnergyDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_node_energy_dto(item, serializer); } }
+
+@protected void sse_encode_list_node_search_dto(List<NodeSearchDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_node_search_dto(item, serializer); } }
+
+@protected void sse_encode_list_prim_i_32_strict(Int32List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putInt32List(self); }
+
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self is Uint8List ? self : Uint8List.fromList(self)); }
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self); }
+
+@protected void sse_encode_list_recitation_jump_dto(List<RecitationJumpDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_recitation_jump_dto(item, serializer); } }
+
+@protected void sse_encode_list_recited_word_dto(List<RecitedWordDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_recited_word_dto(item, serializer); } }
+
+@protected void sse_encode_list_reciter_dto(List<ReciterDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_reciter_dto(item, serializer); } }
+
+@protected void sse_encode_list_record_string_string(List<(String,String)> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_record_string_string(item, serializer); } }
+
+@protected void sse_encode_list_session_preview_dto(List<SessionPreviewDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_session_preview_dto(item, serializer); } }
+
+@protected void sse_encode_list_surah_info(List<SurahInfo> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_surah_info(item, serializer); } }
+
+@protected void sse_encode_list_sync_memory_state_dto(List<SyncMemoryStateDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_sync_memory_state_dto(item, serializer); } }
+
+@protected void sse_encode_list_sync_session_dto(List<SyncSessionDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_sync_session_dto(item, serializer); } }
+
+@protected void sse_encode_list_sync_session_item_dto(List<SyncSessionItemDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_sync_session_item_dto(item, serializer); } }
+
+@protected void sse_encode_list_tafsir_dto(List<TafsirDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_tafsir_dto(item, serializer); } }
+
+@protected void sse_encode_list_tajweed_annotation_dto(List<TajweedAnnotationDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_tajweed_annotation_dto(item, serializer); } }
+
+@protected void sse_encode_list_transcript_word_dto(List<TranscriptWordDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_transcript_word_dto(item, serializer); } }
+
+@protected void sse_encode_list_translator_dto(List<TranslatorDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_translator_dto(item, serializer); } }
+
+@protected void sse_encode_list_verse_with_translation_dto(List<VerseWithTranslationDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_verse_with_translation_dto(item, serializer); } }
+
+@protected void sse_encode_list_word_dto(List<WordDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_word_dto(item, serializer); } }
+
+@protected void sse_encode_list_word_segment_dto(List<WordSegmentDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_word_segment_dto(item, serializer); } }
+
+@protected void sse_encode_list_word_timing_dto(List<WordTimingDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_word_timing_dto(item, serializer); } }
+
+@protected void sse_encode_node_data(NodeData self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.id, serializer);
+sse_encode_String(self.nodeType, serializer);
+sse_encode_Map_String_String_None(self.metadata, serializer);
+ }
+
+@protected void sse_encode_node_energy_dto(NodeEnergyDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.nodeId, serializer);
+sse_encode_f_64(self.energy, serializer);
+sse_encode_f_64(self.edgeWeight, serializer);
+ }
+
+@protected void sse_encode_node_filter_dto(NodeFilterDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.nodeType, serializer);
+sse_encode_opt_box_autoadd_f_64(self.minEnergy, serializer);
+sse_encode_opt_box_autoadd_f_64(self.maxEnergy, serializer);
+sse_encode_opt_String(self.range, serializer);
+ }
+
+@protected void sse_encode_node_search_dto(NodeSearchDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.nodeId, serializer);
+sse_encode_String(self.nodeType, serializer);
+sse_encode_opt_String(self.knowledgeAxis, serializer);
+sse_encode_String(self.preview, serializer);
+ }
+
+@protected void sse_encode_node_status_dto(NodeStatusDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.nodeId, serializer);
+sse_encode_String(self.status, serializer);
+sse_encode_opt_box_autoadd_i_64(self.buriedUntil, serializer);
+ }
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_String(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_ayah_chain_state_dto(AyahChainStateDto? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_ayah_chain_state_dto(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_distractor_strategy_dto(DistractorStrategyDto? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_distractor_strategy_dto(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_echo_recall_state_dto(EchoRecallStateDto? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_echo_recall_state_dto(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_exercise_hint_dto(ExerciseHintDto? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_exercise_hint_dto(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_f_32(double? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_f_32(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_f_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_hint_dto(HintDto? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_hint_dto(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_i_32(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_i_64(PlatformInt64? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_i_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_node_data(NodeData? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_node_data(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_session_dto(SessionDto? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_session_dto(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_session_item_dto(SessionItemDto? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_session_item_dto(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_tafsir_passage_dto(TafsirPassageDto? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_tafsir_passage_dto(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_translator_dto(TranslatorDto? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_translator_dto(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_8(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_8(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_verse_audio_dto(VerseAudioDto? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_verse_audio_dto(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_verse_dto(VerseDto? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_verse_dto(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_word_audio_dto(WordAudioDto? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_word_audio_dto(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_word_dto(WordDto? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_word_dto(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_list_prim_u_8_strict(Uint8List? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_list_prim_u_8_strict(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_propagation_diagnostics_dto(PropagationDiagnosticsDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.nodeFound, serializer);
+sse_encode_opt_String(self.nodeType, serializer);
+sse_encode_u_32(self.totalEdges, serializer);
+sse_encode_String(self.message, serializer);
+ }
+
+@protected void sse_encode_propagation_result_dto(PropagationResultDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_node_energy_dto(self.before, serializer);
+sse_encode_list_node_energy_dto(self.after, serializer);
+sse_encode_propagation_diagnostics_dto(self.diagnostics, serializer);
+ }
+
+@protected void sse_encode_recitation_jump_dto(RecitationJumpDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.verseKey, serializer);
+sse_encode_i_32(self.startPosition, serializer);
+sse_encode_u_32(self.wordCount, serializer);
+sse_encode_opt_String(self.afterWordNodeId, serializer);
+sse_encode_opt_box_autoadd_u_64(self.startMs, serializer);
+ }
+
+@protected void sse_encode_recitation_result_dto(RecitationResultDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_recited_word_dto(self.words, serializer);
+sse_encode_list_recitation_jump_dto(self.jumps, serializer);
+sse_encode_list_transcript_word_dto(self.extraWords, serializer);
+sse_encode_f_64(self.accuracy, serializer);
+sse_encode_u_8(self.grade, serializer);
+ }
+
+@protected void sse_encode_recited_word_dto(RecitedWordDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.wordNodeId, serializer);
+sse_encode_String(self.verseKey, serializer);
+sse_encode_i_32(self.position, serializer);
+sse_encode_String(self.text, serializer);
+sse_encode_String(self.kind, serializer);
+sse_encode_opt_String(self.heard, serializer);
+sse_encode_f_64(self.similarity, serializer);
+sse_encode_bool(self.repeated, serializer);
+sse_encode_opt_box_autoadd_u_64(self.startMs, serializer);
+sse_encode_opt_box_autoadd_u_64(self.endMs, serializer);
+sse_encode_u_8(self.grade, serializer);
+ }
+
+@protected void sse_encode_reciter_dto(ReciterDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.reciterId, serializer);
+sse_encode_String(self.slug, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_opt_String(self.style, serializer);
+sse_encode_opt_String(self.packageId, serializer);
+ }
+
+@protected void sse_encode_record_string_string((String,String) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.$1, serializer);
+sse_encode_String(self.$2, serializer);
+ }
+
+@protected void sse_encode_session_dto(SessionDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.id, serializer);
+sse_encode_String(self.userId, serializer);
+sse_encode_String(self.goalId, serializer);
+sse_encode_i_64(self.startedAt, serializer);
+sse_encode_opt_box_autoadd_i_64(self.completedAt, serializer);
+sse_encode_i_32(self.itemsCount, serializer);
+sse_encode_i_32(self.itemsCompleted, serializer);
+ }
+
+@protected void sse_encode_session_item_dto(SessionItemDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.sessionId, serializer);
+sse_encode_i_32(self.position, serializer);
+sse_encode_String(self.nodeId, serializer);
+sse_encode_String(self.exerciseType, serializer);
+sse_encode_exercise_data_dto(self.exercise, serializer);
+ }
+
+@protected void sse_encode_session_preview_dto(SessionPreviewDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.nodeId, serializer);
+sse_encode_String(self.nodeType, serializer);
+sse_encode_String(self.previewText, serializer);
+sse_encode_f_64(self.energy, serializer);
+sse_encode_f_64(self.priorityScore, serializer);
+ }
+
+@protected void sse_encode_session_summary_dto(SessionSummaryDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.sessionId, serializer);
+sse_encode_i_32(self.itemsCount, serializer);
+sse_encode_i_32(self.itemsCompleted, serializer);
+sse_encode_i_64(self.durationMs, serializer);
+sse_encode_i_32(self.againCount, serializer);
+sse_encode_i_32(self.hardCount, serializer);
+sse_encode_i_32(self.goodCount, serializer);
+sse_encode_i_32(self.easyCount, serializer);
+ }
+
+@protected void sse_encode_surah_info(SurahInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.number, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_String(self.nameArabic, serializer);
+sse_encode_String(self.nameTransliteration, serializer);
+sse_encode_String(self.nameTranslation, serializer);
+sse_encode_i_32(self.verseCount, serializer);
+sse_encode_opt_String(self.revelationPlace, serializer);
+ }
+
+@protected void sse_encode_sync_memory_state_dto(SyncMemoryStateDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.nodeId, serializer);
+sse_encode_f_64(self.energy, serializer);
+sse_encode_opt_box_autoadd_f_64(self.fsrsStability, serializer);
+sse_encode_opt_box_autoadd_f_64(self.fsrsDifficulty, serializer);
+sse_encode_opt_box_autoadd_i_64(self.lastReviewedAt, serializer);
+sse_encode_opt_box_autoadd_i_64(self.nextReviewAt, serializer);
+sse_encode_i_64(self.clientUpdatedAt, serializer);
+ }
+
+@protected void sse_encode_sync_session_dto(SyncSessionDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.id, serializer);
+sse_encode_opt_String(self.goalId, serializer);
+sse_encode_i_64(self.startedAt, serializer);
+sse_encode_opt_box_autoadd_i_64(self.completedAt, serializer);
+sse_encode_i_32(self.itemsCompleted, serializer);
+sse_encode_i_64(self.clientUpdatedAt, serializer);
+ }
+
+@protected void sse_encode_sync_session_item_dto(SyncSessionItemDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.id, serializer);
+sse_encode_String(self.sessionId, serializer);
+sse_encode_i_64(self.nodeId, serializer);
+sse_encode_String(self.exerciseType, serializer);
+sse_encode_opt_box_autoadd_i_32(self.grade, serializer);
+sse_encode_opt_box_autoadd_i_64(self.durationMs, serializer);
+sse_encode_opt_box_autoadd_i_64(self.completedAt, serializer);
+sse_encode_i_64(self.clientUpdatedAt, serializer);
+ }
+
+@protected void sse_encode_tafsir_dto(TafsirDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.tafsirId, serializer);
+sse_encode_String(self.slug, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_opt_String(self.author, serializer);
+sse_encode_opt_String(self.languageCode, serializer);
+ }
+
+@protected void sse_encode_tafsir_passage_dto(TafsirPassageDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.tafsirId, serializer);
+sse_encode_i_32(self.chapterNumber, serializer);
+sse_encode_i_32(self.startVerse, serializer);
+sse_encode_i_32(self.endVerse, serializer);
+sse_encode_String(self.text, serializer);
+sse_encode_opt_String(self.keyLesson, serializer);
+sse_encode_opt_String(self.revelationOccasion, serializer);
+sse_encode_list_String(self.themes, serializer);
+ }
+
+@protected void sse_encode_tajweed_annotation_dto(TajweedAnnotationDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.wordNodeId, serializer);
+sse_encode_i_32(self.position, serializer);
+sse_encode_String(self.rule, serializer);
+sse_encode_String(self.ruleName, serializer);
+sse_encode_i_32(self.startChar, serializer);
+sse_encode_i_32(self.endChar, serializer);
+ }
+
+@protected void sse_encode_transcript_word_dto(TranscriptWordDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.text, serializer);
+sse_encode_opt_box_autoadd_u_64(self.startMs, serializer);
+sse_encode_opt_box_autoadd_u_64(self.endMs, serializer);
+ }
+
+@protected void sse_encode_translator_dto(TranslatorDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.id, serializer);
+sse_encode_String(self.slug, serializer);
+sse_encode_String(self.fullName, serializer);
+sse_encode_String(self.languageCode, serializer);
+sse_encode_opt_String(self.description, serializer);
+sse_encode_opt_String(self.license, serializer);
+ }
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint32(self); }
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self); }
+
+@protected void sse_encode_unit(void self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_validation_result_dto(ValidationResultDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.isCorrect, serializer);
+sse_encode_opt_String(self.feedback, serializer);
+sse_encode_opt_box_autoadd_f_32(self.similarityScore, serializer);
+sse_encode_opt_String(self.semanticGrade, serializer);
+sse_encode_opt_String(self.correctAnswer, serializer);
+sse_encode_u_8(self.suggestedGrade, serializer);
+ }
+
+@protected void sse_encode_verse_audio_dto(VerseAudioDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.reciterId, serializer);
+sse_encode_String(self.verseKey, serializer);
+sse_encode_opt_String(self.audioPath, serializer);
+sse_encode_opt_list_prim_u_8_strict(self.audioBytes, serializer);
+sse_encode_String(self.mimeType, serializer);
+sse_encode_opt_box_autoadd_i_64(self.durationMs, serializer);
+sse_encode_list_word_segment_dto(self.segments, serializer);
+ }
+
+@protected void sse_encode_verse_dto(VerseDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.key, serializer);
+sse_encode_String(self.textUthmani, serializer);
+sse_encode_i_32(self.chapterNumber, serializer);
+sse_encode_i_32(self.verseNumber, serializer);
+ }
+
+@protected void sse_encode_verse_with_translation_dto(VerseWithTranslationDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.key, serializer);
+sse_encode_String(self.textUthmani, serializer);
+sse_encode_opt_String(self.translation, serializer);
+sse_encode_i_32(self.number, serializer);
+ }
+
+@protected void sse_encode_word_audio_dto(WordAudioDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.reciterId, serializer);
+sse_encode_String(self.verseKey, serializer);
+sse_encode_i_32(self.position, serializer);
+sse_encode_opt_String(self.audioPath, serializer);
+sse_encode_opt_list_prim_u_8_strict(self.audioBytes, serializer);
+sse_encode_String(self.mimeType, serializer);
+sse_encode_opt_box_autoadd_i_64(self.durationMs, serializer);
+sse_encode_opt_box_autoadd_i_64(self.startMs, serializer);
+sse_encode_opt_box_autoadd_i_64(self.endMs, serializer);
+ }
+
+@protected void sse_encode_word_dto(WordDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.id, serializer);
+sse_encode_String(self.textUthmani, serializer);
+sse_encode_String(self.verseKey, serializer);
+sse_encode_i_32(self.position, serializer);
+ }
+
+@protected void sse_encode_word_segment_dto(WordSegmentDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.position, serializer);
+sse_encode_i_64(self.startMs, serializer);
+sse_encode_i_64(self.endMs, serializer);
+ }
+
+@protected void sse_encode_word_timing_dto(WordTimingDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.wordNodeId, serializer);
+sse_encode_u_64(self.durationMs, serializer);
+ }
+
+@protected void sse_encode_word_visibility_dto(WordVisibilityDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.visibilityType, serializer);
+sse_encode_opt_box_autoadd_hint_dto(self.hint, serializer);
+sse_encode_opt_box_autoadd_f_64(self.coverage, serializer);
+ }
+                }
+                
+
+            @sealed class AppStateImpl extends RustOpaque implements AppState {
+                // Not to be used by end users
+                AppStateImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                AppStateImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_AppState,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_AppState,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_AppStatePtr,
+                );
+
+                 ArcContentRepository get contentRepo=>RustLib.instance.api.crateApiAppStateAutoAccessorGetContentRepo(that: this, );
+
+
+ ArcExerciseService get exerciseService=>RustLib.instance.api.crateApiAppStateAutoAccessorGetExerciseService(that: this, );
+
+
+ ArcLearningService get learningService=>RustLib.instance.api.crateApiAppStateAutoAccessorGetLearningService(that: this, );
+
+
+ ArcSessionService get sessionService=>RustLib.instance.api.crateApiAppStateAutoAccessorGetSessionService(that: this, );
+
+
+ ArcUserRepository get userRepo=>RustLib.instance.api.crateApiAppStateAutoAccessorGetUserRepo(that: this, );
+
+
+  set contentRepo(ArcContentRepository contentRepo)=>RustLib.instance.api.crateApiAppStateAutoAccessorSetContentRepo(that: this, contentRepo: contentRepo);
+
+
+  set exerciseService(ArcExerciseService exerciseService)=>RustLib.instance.api.crateApiAppStateAutoAccessorSetExerciseService(that: this, exerciseService: exerciseService);
+
+
+  set learningService(ArcLearningService learningService)=>RustLib.instance.api.crateApiAppStateAutoAccessorSetLearningService(that: this, learningService: learningService);
+
+
+  set sessionService(ArcSessionService sessionService)=>RustLib.instance.api.crateApiAppStateAutoAccessorSetSessionService(that: this, sessionService: sessionService);
+
+
+  set userRepo(ArcUserRepository userRepo)=>RustLib.instance.api.crateApiAppStateAutoAccessorSetUserRepo(that: this, userRepo: userRepo);
+
+
+            }
+            @sealed class ArcContentRepositoryImpl extends RustOpaque implements ArcContentRepository {
+                // Not to be used by end users
+                ArcContentRepositoryImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                ArcContentRepositoryImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_ArcContentRepository,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_ArcContentRepository,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_ArcContentRepositoryPtr,
+                );
+
+                
+            }
+            @sealed class ArcExerciseServiceImpl extends RustOpaque implements ArcExerciseService {
+                // Not to be used by end users
+                ArcExerciseServiceImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                ArcExerciseServiceImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_ArcExerciseService,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_ArcExerciseService,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_ArcExerciseServicePtr,
+                );
+
+                
+            }
+            @sealed class ArcLearningServiceImpl extends RustOpaque implements ArcLearningService {
+                // Not to be used by end users
+                ArcLearningServiceImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                ArcLearningServiceImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_ArcLearningService,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_ArcLearningService,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_ArcLearningServicePtr,
+                );
+
+                
+            }
+            @sealed class ArcSessionServiceImpl extends RustOpaque implements ArcSessionService {
+                // Not to be used by end users
+                ArcSessionServiceImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                ArcSessionServiceImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_ArcSessionService,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_ArcSessionService,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_ArcSessionServicePtr,
+                );
+
+                
+            }
+            @sealed class ArcUserRepositoryImpl extends RustOpaque implements ArcUserRepository {
+                // Not to be used by end users
+                ArcUserRepositoryImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                ArcUserRepositoryImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_ArcUserRepository,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_ArcUserRepository,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_ArcUserRepositoryPtr,
+                );
+
+                
+            }
\ No newline at end of file
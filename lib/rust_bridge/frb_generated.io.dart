@@ -10,1696 +10,1154 @@ import 'dart:ffi' as ffi;
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_io.dart';
 
-abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
-  RustLibApiImplPlatform({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
-
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_AppStatePtr => wire
-      ._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppStatePtr;
-
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_ArcExerciseServicePtr => wire
-      ._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseServicePtr;
-
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_ArcLearningServicePtr => wire
-      ._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningServicePtr;
-
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_ArcSessionServicePtr => wire
-      ._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionServicePtr;
-
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_ArcContentRepositoryPtr => wire
-      ._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepositoryPtr;
-
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_ArcUserRepositoryPtr => wire
-      ._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepositoryPtr;
-
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw);
-
-  @protected
-  AppState
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(
-    dynamic raw,
-  );
-
-  @protected
-  ArcExerciseService
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseService(
-    dynamic raw,
-  );
-
-  @protected
-  ArcLearningService
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningService(
-    dynamic raw,
-  );
-
-  @protected
-  ArcSessionService
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionService(
-    dynamic raw,
-  );
-
-  @protected
-  ArcContentRepository
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepository(
-    dynamic raw,
-  );
-
-  @protected
-  ArcUserRepository
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepository(
-    dynamic raw,
-  );
-
-  @protected
-  AppState
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(
-    dynamic raw,
-  );
-
-  @protected
-  AppState
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(
-    dynamic raw,
-  );
-
-  @protected
-  Map<String, String> dco_decode_Map_String_String_None(dynamic raw);
-
-  @protected
-  AppState
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(
-    dynamic raw,
-  );
-
-  @protected
-  ArcExerciseService
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseService(
-    dynamic raw,
-  );
-
-  @protected
-  ArcLearningService
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningService(
-    dynamic raw,
-  );
-
-  @protected
-  ArcSessionService
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionService(
-    dynamic raw,
-  );
 
-  @protected
-  ArcContentRepository
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepository(
-    dynamic raw,
-  );
 
-  @protected
-  ArcUserRepository
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepository(
-    dynamic raw,
-  );
 
-  @protected
-  String dco_decode_String(dynamic raw);
+                abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
+                  RustLibApiImplPlatform({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
 
-  @protected
-  ActivityPointDto dco_decode_activity_point_dto(dynamic raw);
+                  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_AppStatePtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppStatePtr;
 
-  @protected
-  bool dco_decode_bool(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ArcExerciseServicePtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseServicePtr;
 
-  @protected
-  EchoRecallMetricsDto dco_decode_box_autoadd_echo_recall_metrics_dto(
-    dynamic raw,
-  );
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ArcLearningServicePtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningServicePtr;
 
-  @protected
-  EchoRecallStateDto dco_decode_box_autoadd_echo_recall_state_dto(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ArcSessionServicePtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionServicePtr;
 
-  @protected
-  double dco_decode_box_autoadd_f_64(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ArcContentRepositoryPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepositoryPtr;
 
-  @protected
-  HintDto dco_decode_box_autoadd_hint_dto(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ArcUserRepositoryPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepositoryPtr;
 
-  @protected
-  int dco_decode_box_autoadd_i_32(dynamic raw);
 
-  @protected
-  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
 
-  @protected
-  NodeData dco_decode_box_autoadd_node_data(dynamic raw);
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw);
 
-  @protected
-  NodeFilterDto dco_decode_box_autoadd_node_filter_dto(dynamic raw);
+@protected AppState dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(dynamic raw);
 
-  @protected
-  SessionDto dco_decode_box_autoadd_session_dto(dynamic raw);
+@protected ArcExerciseService dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseService(dynamic raw);
 
-  @protected
-  SessionItemDto dco_decode_box_autoadd_session_item_dto(dynamic raw);
+@protected ArcLearningService dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningService(dynamic raw);
 
-  @protected
-  TranslatorDto dco_decode_box_autoadd_translator_dto(dynamic raw);
+@protected ArcSessionService dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionService(dynamic raw);
 
-  @protected
-  VerseDto dco_decode_box_autoadd_verse_dto(dynamic raw);
+@protected ArcContentRepository dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepository(dynamic raw);
 
-  @protected
-  WordDto dco_decode_box_autoadd_word_dto(dynamic raw);
+@protected ArcUserRepository dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepository(dynamic raw);
 
-  @protected
-  ComprehensionDto dco_decode_comprehension_dto(dynamic raw);
+@protected AppState dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(dynamic raw);
 
-  @protected
-  ContentPackageDto dco_decode_content_package_dto(dynamic raw);
+@protected AppState dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(dynamic raw);
 
-  @protected
-  DashboardStatsDto dco_decode_dashboard_stats_dto(dynamic raw);
+@protected Map<String, String> dco_decode_Map_String_String_None(dynamic raw);
 
-  @protected
-  DbHealthDto dco_decode_db_health_dto(dynamic raw);
+@protected AppState dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(dynamic raw);
 
-  @protected
-  DbQueryResultDto dco_decode_db_query_result_dto(dynamic raw);
+@protected ArcExerciseService dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseService(dynamic raw);
 
-  @protected
-  DebugStatsDto dco_decode_debug_stats_dto(dynamic raw);
+@protected ArcLearningService dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningService(dynamic raw);
 
-  @protected
-  DetailedStatsDto dco_decode_detailed_stats_dto(dynamic raw);
+@protected ArcSessionService dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionService(dynamic raw);
 
-  @protected
-  EchoRecallMetricsDto dco_decode_echo_recall_metrics_dto(dynamic raw);
+@protected ArcContentRepository dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepository(dynamic raw);
 
-  @protected
-  EchoRecallResultDto dco_decode_echo_recall_result_dto(dynamic raw);
+@protected ArcUserRepository dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepository(dynamic raw);
 
-  @protected
-  EchoRecallStateDto dco_decode_echo_recall_state_dto(dynamic raw);
+@protected String dco_decode_String(dynamic raw);
 
-  @protected
-  EchoRecallStatsDto dco_decode_echo_recall_stats_dto(dynamic raw);
+@protected ActivityPointDto dco_decode_activity_point_dto(dynamic raw);
 
-  @protected
-  EchoRecallWordDto dco_decode_echo_recall_word_dto(dynamic raw);
+@protected AyahChainStateDto dco_decode_ayah_chain_state_dto(dynamic raw);
 
-  @protected
-  EnergySnapshotDto dco_decode_energy_snapshot_dto(dynamic raw);
+@protected AyahJunctionDto dco_decode_ayah_junction_dto(dynamic raw);
 
-  @protected
-  EnergyUpdateDto dco_decode_energy_update_dto(dynamic raw);
+@protected bool dco_decode_bool(dynamic raw);
 
-  @protected
-  ExerciseDataDto dco_decode_exercise_data_dto(dynamic raw);
+@protected AyahChainStateDto dco_decode_box_autoadd_ayah_chain_state_dto(dynamic raw);
 
-  @protected
-  double dco_decode_f_64(dynamic raw);
+@protected DistractorStrategyDto dco_decode_box_autoadd_distractor_strategy_dto(dynamic raw);
 
-  @protected
-  HintDto dco_decode_hint_dto(dynamic raw);
+@protected EchoRecallMetricsDto dco_decode_box_autoadd_echo_recall_metrics_dto(dynamic raw);
 
-  @protected
-  int dco_decode_i_32(dynamic raw);
+@protected EchoRecallStateDto dco_decode_box_autoadd_echo_recall_state_dto(dynamic raw);
 
-  @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw);
+@protected ExerciseDataDto dco_decode_box_autoadd_exercise_data_dto(dynamic raw);
 
-  @protected
-  InstalledPackageDto dco_decode_installed_package_dto(dynamic raw);
+@protected ExerciseHintDto dco_decode_box_autoadd_exercise_hint_dto(dynamic raw);
 
-  @protected
-  LanguageDto dco_decode_language_dto(dynamic raw);
+@protected double dco_decode_box_autoadd_f_32(dynamic raw);
 
-  @protected
-  List<String> dco_decode_list_String(dynamic raw);
+@protected double dco_decode_box_autoadd_f_64(dynamic raw);
 
-  @protected
-  List<ActivityPointDto> dco_decode_list_activity_point_dto(dynamic raw);
+@protected HintDto dco_decode_box_autoadd_hint_dto(dynamic raw);
 
-  @protected
-  List<ContentPackageDto> dco_decode_list_content_package_dto(dynamic raw);
+@protected int dco_decode_box_autoadd_i_32(dynamic raw);
 
-  @protected
-  List<EchoRecallWordDto> dco_decode_list_echo_recall_word_dto(dynamic raw);
+@protected PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
 
-  @protected
-  List<EnergyUpdateDto> dco_decode_list_energy_update_dto(dynamic raw);
+@protected NodeData dco_decode_box_autoadd_node_data(dynamic raw);
 
-  @protected
-  List<ExerciseDataDto> dco_decode_list_exercise_data_dto(dynamic raw);
+@protected NodeFilterDto dco_decode_box_autoadd_node_filter_dto(dynamic raw);
 
-  @protected
-  List<InstalledPackageDto> dco_decode_list_installed_package_dto(dynamic raw);
+@protected SessionDto dco_decode_box_autoadd_session_dto(dynamic raw);
 
-  @protected
-  List<LanguageDto> dco_decode_list_language_dto(dynamic raw);
+@protected SessionItemDto dco_decode_box_autoadd_session_item_dto(dynamic raw);
 
-  @protected
-  List<List<String>> dco_decode_list_list_String(dynamic raw);
+@protected TafsirPassageDto dco_decode_box_autoadd_tafsir_passage_dto(dynamic raw);
 
-  @protected
-  List<NodeEnergyDto> dco_decode_list_node_energy_dto(dynamic raw);
+@protected TranslatorDto dco_decode_box_autoadd_translator_dto(dynamic raw);
 
-  @protected
-  List<NodeSearchDto> dco_decode_list_node_search_dto(dynamic raw);
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw);
 
-  @protected
-  List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+@protected int dco_decode_box_autoadd_u_8(dynamic raw);
 
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+@protected VerseAudioDto dco_decode_box_autoadd_verse_audio_dto(dynamic raw);
 
-  @protected
-  List<(String, String)> dco_decode_list_record_string_string(dynamic raw);
+@protected VerseDto dco_decode_box_autoadd_verse_dto(dynamic raw);
 
-  @protected
-  List<SessionPreviewDto> dco_decode_list_session_preview_dto(dynamic raw);
+@protected WordAudioDto dco_decode_box_autoadd_word_audio_dto(dynamic raw);
 
-  @protected
-  List<SurahInfo> dco_decode_list_surah_info(dynamic raw);
+@protected WordDto dco_decode_box_autoadd_word_dto(dynamic raw);
 
-  @protected
-  List<SyncMemoryStateDto> dco_decode_list_sync_memory_state_dto(dynamic raw);
+@protected ComprehensionDto dco_decode_comprehension_dto(dynamic raw);
 
-  @protected
-  List<SyncSessionDto> dco_decode_list_sync_session_dto(dynamic raw);
+@protected ContentPackageDto dco_decode_content_package_dto(dynamic raw);
 
-  @protected
-  List<SyncSessionItemDto> dco_decode_list_sync_session_item_dto(dynamic raw);
+@protected DashboardStatsDto dco_decode_dashboard_stats_dto(dynamic raw);
 
-  @protected
-  List<TranslatorDto> dco_decode_list_translator_dto(dynamic raw);
+@protected DbHealthDto dco_decode_db_health_dto(dynamic raw);
 
-  @protected
-  List<VerseWithTranslationDto> dco_decode_list_verse_with_translation_dto(
-    dynamic raw,
-  );
+@protected DbQueryResultDto dco_decode_db_query_result_dto(dynamic raw);
 
-  @protected
-  List<WordDto> dco_decode_list_word_dto(dynamic raw);
+@protected DebugStatsDto dco_decode_debug_stats_dto(dynamic raw);
 
-  @protected
-  List<WordTimingDto> dco_decode_list_word_timing_dto(dynamic raw);
+@protected DetailedStatsDto dco_decode_detailed_stats_dto(dynamic raw);
 
-  @protected
-  NodeData dco_decode_node_data(dynamic raw);
+@protected DistractorStrategyDto dco_decode_distractor_strategy_dto(dynamic raw);
 
-  @protected
-  NodeEnergyDto dco_decode_node_energy_dto(dynamic raw);
+@protected EchoRecallMetricsDto dco_decode_echo_recall_metrics_dto(dynamic raw);
 
-  @protected
-  NodeFilterDto dco_decode_node_filter_dto(dynamic raw);
+@protected EchoRecallResultDto dco_decode_echo_recall_result_dto(dynamic raw);
 
-  @protected
-  NodeSearchDto dco_decode_node_search_dto(dynamic raw);
+@protected EchoRecallStateDto dco_decode_echo_recall_state_dto(dynamic raw);
 
-  @protected
-  String? dco_decode_opt_String(dynamic raw);
+@protected EchoRecallStatsDto dco_decode_echo_recall_stats_dto(dynamic raw);
 
-  @protected
-  double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
+@protected EchoRecallWordDto dco_decode_echo_recall_word_dto(dynamic raw);
 
-  @protected
-  HintDto? dco_decode_opt_box_autoadd_hint_dto(dynamic raw);
+@protected EnergySnapshotDto dco_decode_energy_snapshot_dto(dynamic raw);
 
-  @protected
-  int? dco_decode_opt_box_autoadd_i_32(dynamic raw);
+@protected EnergyUpdateDto dco_decode_energy_update_dto(dynamic raw);
 
-  @protected
-  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
+@protected ExerciseDataDto dco_decode_exercise_data_dto(dynamic raw);
 
-  @protected
-  NodeData? dco_decode_opt_box_autoadd_node_data(dynamic raw);
+@protected ExerciseHintDto dco_decode_exercise_hint_dto(dynamic raw);
 
-  @protected
-  SessionDto? dco_decode_opt_box_autoadd_session_dto(dynamic raw);
+@protected double dco_decode_f_32(dynamic raw);
 
-  @protected
-  SessionItemDto? dco_decode_opt_box_autoadd_session_item_dto(dynamic raw);
+@protected double dco_decode_f_64(dynamic raw);
 
-  @protected
-  TranslatorDto? dco_decode_opt_box_autoadd_translator_dto(dynamic raw);
+@protected HintDto dco_decode_hint_dto(dynamic raw);
 
-  @protected
-  VerseDto? dco_decode_opt_box_autoadd_verse_dto(dynamic raw);
+@protected int dco_decode_i_32(dynamic raw);
 
-  @protected
-  WordDto? dco_decode_opt_box_autoadd_word_dto(dynamic raw);
+@protected PlatformInt64 dco_decode_i_64(dynamic raw);
 
-  @protected
-  PropagationDiagnosticsDto dco_decode_propagation_diagnostics_dto(dynamic raw);
+@protected InstalledPackageDto dco_decode_installed_package_dto(dynamic raw);
 
-  @protected
-  PropagationResultDto dco_decode_propagation_result_dto(dynamic raw);
+@protected LanguageDto dco_decode_language_dto(dynamic raw);
 
-  @protected
-  (String, String) dco_decode_record_string_string(dynamic raw);
+@protected List<String> dco_decode_list_String(dynamic raw);
 
-  @protected
-  SessionDto dco_decode_session_dto(dynamic raw);
+@protected List<ActivityPointDto> dco_decode_list_activity_point_dto(dynamic raw);
 
-  @protected
-  SessionItemDto dco_decode_session_item_dto(dynamic raw);
+@protected List<AyahJunctionDto> dco_decode_list_ayah_junction_dto(dynamic raw);
 
-  @protected
-  SessionPreviewDto dco_decode_session_preview_dto(dynamic raw);
+@protected List<ContentPackageDto> dco_decode_list_content_package_dto(dynamic raw);
 
-  @protected
-  SessionSummaryDto dco_decode_session_summary_dto(dynamic raw);
+@protected List<EchoRecallWordDto> dco_decode_list_echo_recall_word_dto(dynamic raw);
 
-  @protected
-  SurahInfo dco_decode_surah_info(dynamic raw);
+@protected List<EnergyUpdateDto> dco_decode_list_energy_update_dto(dynamic raw);
 
-  @protected
-  SyncMemoryStateDto dco_decode_sync_memory_state_dto(dynamic raw);
+@protected List<ExerciseDataDto> dco_decode_list_exercise_data_dto(dynamic raw);
 
-  @protected
-  SyncSessionDto dco_decode_sync_session_dto(dynamic raw);
+@protected List<ExerciseHintDto> dco_decode_list_exercise_hint_dto(dynamic raw);
 
-  @protected
-  SyncSessionItemDto dco_decode_sync_session_item_dto(dynamic raw);
+@protected List<InstalledPackageDto> dco_decode_list_installed_package_dto(dynamic raw);
 
-  @protected
-  TranslatorDto dco_decode_translator_dto(dynamic raw);
+@protected List<LanguageDto> dco_decode_list_language_dto(dynamic raw);
 
-  @protected
-  int dco_decode_u_32(dynamic raw);
+@protected List<List<String>> dco_decode_list_list_String(dynamic raw);
 
-  @protected
-  BigInt dco_decode_u_64(dynamic raw);
+@protected List<NodeEnergyDto> dco_decode_list_node_energy_dto(dynamic raw);
 
-  @protected
-  int dco_decode_u_8(dynamic raw);
+@protected List<NodeSearchDto> dco_decode_list_node_search_dto(dynamic raw);
 
-  @protected
-  void dco_decode_unit(dynamic raw);
+@protected Int32List dco_decode_list_prim_i_32_strict(dynamic raw);
 
-  @protected
-  BigInt dco_decode_usize(dynamic raw);
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
 
-  @protected
-  VerseDto dco_decode_verse_dto(dynamic raw);
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
-  @protected
-  VerseWithTranslationDto dco_decode_verse_with_translation_dto(dynamic raw);
+@protected List<RecitationJumpDto> dco_decode_list_recitation_jump_dto(dynamic raw);
 
-  @protected
-  WordDto dco_decode_word_dto(dynamic raw);
+@protected List<RecitedWordDto> dco_decode_list_recited_word_dto(dynamic raw);
 
-  @protected
-  WordTimingDto dco_decode_word_timing_dto(dynamic raw);
+@protected List<ReciterDto> dco_decode_list_reciter_dto(dynamic raw);
 
-  @protected
-  WordVisibilityDto dco_decode_word_visibility_dto(dynamic raw);
+@protected List<(String,String)> dco_decode_list_record_string_string(dynamic raw);
 
-  @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+@protected List<SessionPreviewDto> dco_decode_list_session_preview_dto(dynamic raw);
 
-  @protected
-  AppState
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(
-    SseDeserializer deserializer,
-  );
+@protected List<SurahInfo> dco_decode_list_surah_info(dynamic raw);
 
-  @protected
-  ArcExerciseService
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseService(
-    SseDeserializer deserializer,
-  );
+@protected List<SyncMemoryStateDto> dco_decode_list_sync_memory_state_dto(dynamic raw);
 
-  @protected
-  ArcLearningService
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningService(
-    SseDeserializer deserializer,
-  );
+@protected List<SyncSessionDto> dco_decode_list_sync_session_dto(dynamic raw);
 
-  @protected
-  ArcSessionService
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionService(
-    SseDeserializer deserializer,
-  );
+@protected List<SyncSessionItemDto> dco_decode_list_sync_session_item_dto(dynamic raw);
 
-  @protected
-  ArcContentRepository
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepository(
-    SseDeserializer deserializer,
-  );
+@protected List<TafsirDto> dco_decode_list_tafsir_dto(dynamic raw);
 
-  @protected
-  ArcUserRepository
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepository(
-    SseDeserializer deserializer,
-  );
+@protected List<TajweedAnnotationDto> dco_decode_list_tajweed_annotation_dto(dynamic raw);
 
-  @protected
-  AppState
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  AppState
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  Map<String, String> sse_decode_Map_String_String_None(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  AppState
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  ArcExerciseService
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseService(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  ArcLearningService
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningService(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  ArcSessionService
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionService(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  ArcContentRepository
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepository(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  ArcUserRepository
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepository(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  String sse_decode_String(SseDeserializer deserializer);
-
-  @protected
-  ActivityPointDto sse_decode_activity_point_dto(SseDeserializer deserializer);
-
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
-
-  @protected
-  EchoRecallMetricsDto sse_decode_box_autoadd_echo_recall_metrics_dto(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  EchoRecallStateDto sse_decode_box_autoadd_echo_recall_state_dto(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
-
-  @protected
-  HintDto sse_decode_box_autoadd_hint_dto(SseDeserializer deserializer);
-
-  @protected
-  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer);
-
-  @protected
-  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
-
-  @protected
-  NodeData sse_decode_box_autoadd_node_data(SseDeserializer deserializer);
-
-  @protected
-  NodeFilterDto sse_decode_box_autoadd_node_filter_dto(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  SessionDto sse_decode_box_autoadd_session_dto(SseDeserializer deserializer);
-
-  @protected
-  SessionItemDto sse_decode_box_autoadd_session_item_dto(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  TranslatorDto sse_decode_box_autoadd_translator_dto(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  VerseDto sse_decode_box_autoadd_verse_dto(SseDeserializer deserializer);
-
-  @protected
-  WordDto sse_decode_box_autoadd_word_dto(SseDeserializer deserializer);
-
-  @protected
-  ComprehensionDto sse_decode_comprehension_dto(SseDeserializer deserializer);
-
-  @protected
-  ContentPackageDto sse_decode_content_package_dto(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  DashboardStatsDto sse_decode_dashboard_stats_dto(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  DbHealthDto sse_decode_db_health_dto(SseDeserializer deserializer);
-
-  @protected
-  DbQueryResultDto sse_decode_db_query_result_dto(SseDeserializer deserializer);
-
-  @protected
-  DebugStatsDto sse_decode_debug_stats_dto(SseDeserializer deserializer);
+@protected List<TranscriptWordDto> dco_decode_list_transcript_word_dto(dynamic raw);
 
-  @protected
-  DetailedStatsDto sse_decode_detailed_stats_dto(SseDeserializer deserializer);
+@protected List<TranslatorDto> dco_decode_list_translator_dto(dynamic raw);
 
-  @protected
-  EchoRecallMetricsDto sse_decode_echo_recall_metrics_dto(
-    SseDeserializer deserializer,
-  );
+@protected List<VerseWithTranslationDto> dco_decode_list_verse_with_translation_dto(dynamic raw);
 
-  @protected
-  EchoRecallResultDto sse_decode_echo_recall_result_dto(
-    SseDeserializer deserializer,
-  );
+@protected List<WordDto> dco_decode_list_word_dto(dynamic raw);
 
-  @protected
-  EchoRecallStateDto sse_decode_echo_recall_state_dto(
-    SseDeserializer deserializer,
-  );
+@protected List<WordSegmentDto> dco_decode_list_word_segment_dto(dynamic raw);
 
-  @protected
-  EchoRecallStatsDto sse_decode_echo_recall_stats_dto(
-    SseDeserializer deserializer,
-  );
+@protected List<WordTimingDto> dco_decode_list_word_timing_dto(dynamic raw);
 
-  @protected
-  EchoRecallWordDto sse_decode_echo_recall_word_dto(
-    SseDeserializer deserializer,
-  );
+@protected NodeData dco_decode_node_data(dynamic raw);
 
-  @protected
-  EnergySnapshotDto sse_decode_energy_snapshot_dto(
-    SseDeserializer deserializer,
-  );
+@protected NodeEnergyDto dco_decode_node_energy_dto(dynamic raw);
 
-  @protected
-  EnergyUpdateDto sse_decode_energy_update_dto(SseDeserializer deserializer);
+@protected NodeFilterDto dco_decode_node_filter_dto(dynamic raw);
 
-  @protected
-  ExerciseDataDto sse_decode_exercise_data_dto(SseDeserializer deserializer);
+@protected NodeSearchDto dco_decode_node_search_dto(dynamic raw);
 
-  @protected
-  double sse_decode_f_64(SseDeserializer deserializer);
+@protected NodeStatusDto dco_decode_node_status_dto(dynamic raw);
 
-  @protected
-  HintDto sse_decode_hint_dto(SseDeserializer deserializer);
+@protected String? dco_decode_opt_String(dynamic raw);
 
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+@protected AyahChainStateDto? dco_decode_opt_box_autoadd_ayah_chain_state_dto(dynamic raw);
 
-  @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
-
-  @protected
-  InstalledPackageDto sse_decode_installed_package_dto(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  LanguageDto sse_decode_language_dto(SseDeserializer deserializer);
-
-  @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer);
-
-  @protected
-  List<ActivityPointDto> sse_decode_list_activity_point_dto(
-    SseDeserializer deserializer,
-  );
+@protected DistractorStrategyDto? dco_decode_opt_box_autoadd_distractor_strategy_dto(dynamic raw);
 
-  @protected
-  List<ContentPackageDto> sse_decode_list_content_package_dto(
-    SseDeserializer deserializer,
-  );
+@protected EchoRecallStateDto? dco_decode_opt_box_autoadd_echo_recall_state_dto(dynamic raw);
 
-  @protected
-  List<EchoRecallWordDto> sse_decode_list_echo_recall_word_dto(
-    SseDeserializer deserializer,
-  );
+@protected ExerciseHintDto? dco_decode_opt_box_autoadd_exercise_hint_dto(dynamic raw);
 
-  @protected
-  List<EnergyUpdateDto> sse_decode_list_energy_update_dto(
-    SseDeserializer deserializer,
-  );
+@protected double? dco_decode_opt_box_autoadd_f_32(dynamic raw);
 
-  @protected
-  List<ExerciseDataDto> sse_decode_list_exercise_data_dto(
-    SseDeserializer deserializer,
-  );
+@protected double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
 
-  @protected
-  List<InstalledPackageDto> sse_decode_list_installed_package_dto(
-    SseDeserializer deserializer,
-  );
+@protected HintDto? dco_decode_opt_box_autoadd_hint_dto(dynamic raw);
 
-  @protected
-  List<LanguageDto> sse_decode_list_language_dto(SseDeserializer deserializer);
+@protected int? dco_decode_opt_box_autoadd_i_32(dynamic raw);
 
-  @protected
-  List<List<String>> sse_decode_list_list_String(SseDeserializer deserializer);
+@protected PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
 
-  @protected
-  List<NodeEnergyDto> sse_decode_list_node_energy_dto(
-    SseDeserializer deserializer,
-  );
+@protected NodeData? dco_decode_opt_box_autoadd_node_data(dynamic raw);
 
-  @protected
-  List<NodeSearchDto> sse_decode_list_node_search_dto(
-    SseDeserializer deserializer,
-  );
+@protected SessionDto? dco_decode_opt_box_autoadd_session_dto(dynamic raw);
 
-  @protected
-  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+@protected SessionItemDto? dco_decode_opt_box_autoadd_session_item_dto(dynamic raw);
 
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+@protected TafsirPassageDto? dco_decode_opt_box_autoadd_tafsir_passage_dto(dynamic raw);
 
-  @protected
-  List<(String, String)> sse_decode_list_record_string_string(
-    SseDeserializer deserializer,
-  );
+@protected TranslatorDto? dco_decode_opt_box_autoadd_translator_dto(dynamic raw);
 
-  @protected
-  List<SessionPreviewDto> sse_decode_list_session_preview_dto(
-    SseDeserializer deserializer,
-  );
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
 
-  @protected
-  List<SurahInfo> sse_decode_list_surah_info(SseDeserializer deserializer);
+@protected int? dco_decode_opt_box_autoadd_u_8(dynamic raw);
 
-  @protected
-  List<SyncMemoryStateDto> sse_decode_list_sync_memory_state_dto(
-    SseDeserializer deserializer,
-  );
+@protected VerseAudioDto? dco_decode_opt_box_autoadd_verse_audio_dto(dynamic raw);
 
-  @protected
-  List<SyncSessionDto> sse_decode_list_sync_session_dto(
-    SseDeserializer deserializer,
-  );
+@protected VerseDto? dco_decode_opt_box_autoadd_verse_dto(dynamic raw);
 
-  @protected
-  List<SyncSessionItemDto> sse_decode_list_sync_session_item_dto(
-    SseDeserializer deserializer,
-  );
+@protected WordAudioDto? dco_decode_opt_box_autoadd_word_audio_dto(dynamic raw);
 
-  @protected
-  List<TranslatorDto> sse_decode_list_translator_dto(
-    SseDeserializer deserializer,
-  );
+@protected WordDto? dco_decode_opt_box_autoadd_word_dto(dynamic raw);
 
-  @protected
-  List<VerseWithTranslationDto> sse_decode_list_verse_with_translation_dto(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  List<WordDto> sse_decode_list_word_dto(SseDeserializer deserializer);
+@protected Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw);
 
-  @protected
-  List<WordTimingDto> sse_decode_list_word_timing_dto(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  NodeData sse_decode_node_data(SseDeserializer deserializer);
-
-  @protected
-  NodeEnergyDto sse_decode_node_energy_dto(SseDeserializer deserializer);
+@protected PropagationDiagnosticsDto dco_decode_propagation_diagnostics_dto(dynamic raw);
 
-  @protected
-  NodeFilterDto sse_decode_node_filter_dto(SseDeserializer deserializer);
+@protected PropagationResultDto dco_decode_propagation_result_dto(dynamic raw);
 
-  @protected
-  NodeSearchDto sse_decode_node_search_dto(SseDeserializer deserializer);
+@protected RecitationJumpDto dco_decode_recitation_jump_dto(dynamic raw);
 
-  @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
+@protected RecitationResultDto dco_decode_recitation_result_dto(dynamic raw);
 
-  @protected
-  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
+@protected RecitedWordDto dco_decode_recited_word_dto(dynamic raw);
 
-  @protected
-  HintDto? sse_decode_opt_box_autoadd_hint_dto(SseDeserializer deserializer);
+@protected ReciterDto dco_decode_reciter_dto(dynamic raw);
 
-  @protected
-  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer);
+@protected (String,String) dco_decode_record_string_string(dynamic raw);
 
-  @protected
-  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+@protected SessionDto dco_decode_session_dto(dynamic raw);
 
-  @protected
-  NodeData? sse_decode_opt_box_autoadd_node_data(SseDeserializer deserializer);
+@protected SessionItemDto dco_decode_session_item_dto(dynamic raw);
 
-  @protected
-  SessionDto? sse_decode_opt_box_autoadd_session_dto(
-    SseDeserializer deserializer,
-  );
+@protected SessionPreviewDto dco_decode_session_preview_dto(dynamic raw);
 
-  @protected
-  SessionItemDto? sse_decode_opt_box_autoadd_session_item_dto(
-    SseDeserializer deserializer,
-  );
+@protected SessionSummaryDto dco_decode_session_summary_dto(dynamic raw);
 
-  @protected
-  TranslatorDto? sse_decode_opt_box_autoadd_translator_dto(
-    SseDeserializer deserializer,
-  );
+@protected SurahInfo dco_decode_surah_info(dynamic raw);
 
-  @protected
-  VerseDto? sse_decode_opt_box_autoadd_verse_dto(SseDeserializer deserializer);
+@protected SyncMemoryStateDto dco_decode_sync_memory_state_dto(dynamic raw);
 
-  @protected
-  WordDto? sse_decode_opt_box_autoadd_word_dto(SseDeserializer deserializer);
+@protected SyncSessionDto dco_decode_sync_session_dto(dynamic raw);
 
-  @protected
-  PropagationDiagnosticsDto sse_decode_propagation_diagnostics_dto(
-    SseDeserializer deserializer,
-  );
+@protected SyncSessionItemDto dco_decode_sync_session_item_dto(dynamic raw);
+
+@protected TafsirDto dco_decode_tafsir_dto(dynamic raw);
+
+@protected TafsirPassageDto dco_decode_tafsir_passage_dto(dynamic raw);
+
+@protected TajweedAnnotationDto dco_decode_tajweed_annotation_dto(dynamic raw);
+
+@protected TranscriptWordDto dco_decode_transcript_word_dto(dynamic raw);
+
+@protected TranslatorDto dco_decode_translator_dto(dynamic raw);
+
+@protected int dco_decode_u_32(dynamic raw);
+
+@protected BigInt dco_decode_u_64(dynamic raw);
+
+@protected int dco_decode_u_8(dynamic raw);
+
+@protected void dco_decode_unit(dynamic raw);
+
+@protected BigInt dco_decode_usize(dynamic raw);
+
+@protected ValidationResultDto dco_decode_validation_result_dto(dynamic raw);
+
+@protected VerseAudioDto dco_decode_verse_audio_dto(dynamic raw);
+
+@protected VerseDto dco_decode_verse_dto(dynamic raw);
+
+@protected VerseWithTranslationDto dco_decode_verse_with_translation_dto(dynamic raw);
+
+@protected WordAudioDto dco_decode_word_audio_dto(dynamic raw);
+
+@protected WordDto dco_decode_word_dto(dynamic raw);
+
+@protected WordSegmentDto dco_decode_word_segment_dto(dynamic raw);
+
+@protected WordTimingDto dco_decode_word_timing_dto(dynamic raw);
+
+@protected WordVisibilityDto dco_decode_word_visibility_dto(dynamic raw);
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+@protected AppState sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(SseDeserializer deserializer);
+
+@protected ArcExerciseService sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseService(SseDeserializer deserializer);
+
+@protected ArcLearningService sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningService(SseDeserializer deserializer);
+
+@protected ArcSessionService sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionService(SseDeserializer deserializer);
+
+@protected ArcContentRepository sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepository(SseDeserializer deserializer);
+
+@protected ArcUserRepository sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepository(SseDeserializer deserializer);
+
+@protected AppState sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(SseDeserializer deserializer);
+
+@protected AppState sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(SseDeserializer deserializer);
+
+@protected Map<String, String> sse_decode_Map_String_String_None(SseDeserializer deserializer);
+
+@protected AppState sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(SseDeserializer deserializer);
+
+@protected ArcExerciseService sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseService(SseDeserializer deserializer);
+
+@protected ArcLearningService sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningService(SseDeserializer deserializer);
+
+@protected ArcSessionService sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionService(SseDeserializer deserializer);
+
+@protected ArcContentRepository sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepository(SseDeserializer deserializer);
+
+@protected ArcUserRepository sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepository(SseDeserializer deserializer);
+
+@protected String sse_decode_String(SseDeserializer deserializer);
+
+@protected ActivityPointDto sse_decode_activity_point_dto(SseDeserializer deserializer);
+
+@protected AyahChainStateDto sse_decode_ayah_chain_state_dto(SseDeserializer deserializer);
+
+@protected AyahJunctionDto sse_decode_ayah_junction_dto(SseDeserializer deserializer);
+
+@protected bool sse_decode_bool(SseDeserializer deserializer);
+
+@protected AyahChainStateDto sse_decode_box_autoadd_ayah_chain_state_dto(SseDeserializer deserializer);
+
+@protected DistractorStrategyDto sse_decode_box_autoadd_distractor_strategy_dto(SseDeserializer deserializer);
+
+@protected EchoRecallMetricsDto sse_decode_box_autoadd_echo_recall_metrics_dto(SseDeserializer deserializer);
+
+@protected EchoRecallStateDto sse_decode_box_autoadd_echo_recall_state_dto(SseDeserializer deserializer);
+
+@protected ExerciseDataDto sse_decode_box_autoadd_exercise_data_dto(SseDeserializer deserializer);
+
+@protected ExerciseHintDto sse_decode_box_autoadd_exercise_hint_dto(SseDeserializer deserializer);
+
+@protected double sse_decode_box_autoadd_f_32(SseDeserializer deserializer);
+
+@protected double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
+
+@protected HintDto sse_decode_box_autoadd_hint_dto(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_i_32(SseDeserializer deserializer);
+
+@protected PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
+
+@protected NodeData sse_decode_box_autoadd_node_data(SseDeserializer deserializer);
+
+@protected NodeFilterDto sse_decode_box_autoadd_node_filter_dto(SseDeserializer deserializer);
+
+@protected SessionDto sse_decode_box_autoadd_session_dto(SseDeserializer deserializer);
+
+@protected SessionItemDto sse_decode_box_autoadd_session_item_dto(SseDeserializer deserializer);
+
+@protected TafsirPassageDto sse_decode_box_autoadd_tafsir_passage_dto(SseDeserializer deserializer);
+
+@protected TranslatorDto sse_decode_box_autoadd_translator_dto(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_u_8(SseDeserializer deserializer);
+
+@protected VerseAudioDto sse_decode_box_autoadd_verse_audio_dto(SseDeserializer deserializer);
+
+@protected VerseDto sse_decode_box_autoadd_verse_dto(SseDeserializer deserializer);
+
+@protected WordAudioDto sse_decode_box_autoadd_word_audio_dto(SseDeserializer deserializer);
+
+@protected WordDto sse_decode_box_autoadd_word_dto(SseDeserializer deserializer);
+
+@protected ComprehensionDto sse_decode_comprehension_dto(SseDeserializer deserializer);
+
+@protected ContentPackageDto sse_decode_content_package_dto(SseDeserializer deserializer);
+
+@protected DashboardStatsDto sse_decode_dashboard_stats_dto(SseDeserializer deserializer);
+
+@protected DbHealthDto sse_decode_db_health_dto(SseDeserializer deserializer);
+
+@protected DbQueryResultDto sse_decode_db_query_result_dto(SseDeserializer deserializer);
+
+@protected DebugStatsDto sse_decode_debug_stats_dto(SseDeserializer deserializer);
+
+@protected DetailedStatsDto sse_decode_detailed_stats_dto(SseDeserializer deserializer);
+
+@protected DistractorStrategyDto sse_decode_distractor_strategy_dto(SseDeserializer deserializer);
+
+@protected EchoRecallMetricsDto sse_decode_echo_recall_metrics_dto(SseDeserializer deserializer);
+
+@protected EchoRecallResultDto sse_decode_echo_recall_result_dto(SseDeserializer deserializer);
+
+@protected EchoRecallStateDto sse_decode_echo_recall_state_dto(SseDeserializer deserializer);
+
+@protected EchoRecallStatsDto sse_decode_echo_recall_stats_dto(SseDeserializer deserializer);
+
+@protected EchoRecallWordDto sse_decode_echo_recall_word_dto(SseDeserializer deserializer);
+
+@protected EnergySnapshotDto sse_decode_energy_snapshot_dto(SseDeserializer deserializer);
+
+@protected EnergyUpdateDto sse_decode_energy_update_dto(SseDeserializer deserializer);
+
+@protected ExerciseDataDto sse_decode_exercise_data_dto(SseDeserializer deserializer);
+
+@protected ExerciseHintDto sse_decode_exercise_hint_dto(SseDeserializer deserializer);
+
+@protected double sse_decode_f_32(SseDeserializer deserializer);
+
+@protected double sse_decode_f_64(SseDeserializer deserializer);
+
+@protected HintDto sse_decode_hint_dto(SseDeserializer deserializer);
+
+@protected int sse_decode_i_32(SseDeserializer deserializer);
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+@protected InstalledPackageDto sse_decode_installed_package_dto(SseDeserializer deserializer);
+
+@protected LanguageDto sse_decode_language_dto(SseDeserializer deserializer);
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+@protected List<ActivityPointDto> sse_decode_list_activity_point_dto(SseDeserializer deserializer);
+
+@protected List<AyahJunctionDto> sse_decode_list_ayah_junction_dto(SseDeserializer deserializer);
+
+@protected List<ContentPackageDto> sse_decode_list_content_package_dto(SseDeserializer deserializer);
+
+@protected List<EchoRecallWordDto> sse_decode_list_echo_recall_word_dto(SseDeserializer deserializer);
+
+@protected List<EnergyUpdateDto> sse_decode_list_energy_update_dto(SseDeserializer deserializer);
+
+@protected List<ExerciseDataDto> sse_decode_list_exercise_data_dto(SseDeserializer deserializer);
+
+@protected List<ExerciseHintDto> sse_decode_list_exercise_hint_dto(SseDeserializer deserializer);
+
+@protected List<InstalledPackageDto> sse_decode_list_installed_package_dto(SseDeserializer deserializer);
+
+@protected List<LanguageDto> sse_decode_list_language_dto(SseDeserializer deserializer);
+
+@protected List<List<String>> sse_decode_list_list_String(SseDeserializer deserializer);
+
+@protected List<NodeEnergyDto> sse_decode_list_node_energy_dto(SseDeserializer deserializer);
+
+@protected List<NodeSearchDto> sse_decode_list_node_search_dto(SseDeserializer deserializer);
+
+@protected Int32List sse_decode_list_prim_i_32_strict(SseDeserializer deserializer);
+
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected List<RecitationJumpDto> sse_decode_list_recitation_jump_dto(SseDeserializer deserializer);
+
+@protected List<RecitedWordDto> sse_decode_list_recited_word_dto(SseDeserializer deserializer);
+
+@protected List<ReciterDto> sse_decode_list_reciter_dto(SseDeserializer deserializer);
+
+@protected List<(String,String)> sse_decode_list_record_string_string(SseDeserializer deserializer);
+
+@protected List<SessionPreviewDto> sse_decode_list_session_preview_dto(SseDeserializer deserializer);
+
+@protected List<SurahInfo> sse_decode_list_surah_info(SseDeserializer deserializer);
+
+@protected List<SyncMemoryStateDto> sse_decode_list_sync_memory_state_dto(SseDeserializer deserializer);
+
+@protected List<SyncSessionDto> sse_decode_list_sync_session_dto(SseDeserializer deserializer);
+
+@protected List<SyncSessionItemDto> sse_decode_list_sync_session_item_dto(SseDeserializer deserializer);
+
+@protected List<TafsirDto> sse_decode_list_tafsir_dto(SseDeserializer deserializer);
+
+@protected List<TajweedAnnotationDto> sse_decode_list_tajweed_annotation_dto(SseDeserializer deserializer);
+
+@protected List<TranscriptWordDto> sse_decode_list_transcript_word_dto(SseDeserializer deserializer);
+
+@protected List<TranslatorDto> sse_decode_list_translator_dto(SseDeserializer deserializer);
+
+@protected List<VerseWithTranslationDto> sse_decode_list_verse_with_translation_dto(SseDeserializer deserializer);
+
+@protected List<WordDto> sse_decode_list_word_dto(SseDeserializer deserializer);
+
+@protected List<WordSegmentDto> sse_decode_list_word_segment_dto(SseDeserializer deserializer);
+
+@protected List<WordTimingDto> sse_decode_list_word_timing_dto(SseDeserializer deserializer);
+
+@protected NodeData sse_decode_node_data(SseDeserializer deserializer);
+
+@protected NodeEnergyDto sse_decode_node_energy_dto(SseDeserializer deserializer);
+
+@protected NodeFilterDto sse_decode_node_filter_dto(SseDeserializer deserializer);
+
+@protected NodeSearchDto sse_decode_node_search_dto(SseDeserializer deserializer);
+
+@protected NodeStatusDto sse_decode_node_status_dto(SseDeserializer deserializer);
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer);
+
+@protected AyahChainStateDto? sse_decode_opt_box_autoadd_ayah_chain_state_dto(SseDeserializer deserializer);
+
+@protected DistractorStrategyDto? sse_decode_opt_box_autoadd_distractor_strategy_dto(SseDeserializer deserializer);
+
+@protected EchoRecallStateDto? sse_decode_opt_box_autoadd_echo_recall_state_dto(SseDeserializer deserializer);
+
+@protected ExerciseHintDto? sse_decode_opt_box_autoadd_exercise_hint_dto(SseDeserializer deserializer);
+
+@protected double? sse_decode_opt_box_autoadd_f_32(SseDeserializer deserializer);
+
+@protected double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
+
+@protected HintDto? sse_decode_opt_box_autoadd_hint_dto(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer);
+
+@protected PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+
+@protected NodeData? sse_decode_opt_box_autoadd_node_data(SseDeserializer deserializer);
+
+@protected SessionDto? sse_decode_opt_box_autoadd_session_dto(SseDeserializer deserializer);
+
+@protected SessionItemDto? sse_decode_opt_box_autoadd_session_item_dto(SseDeserializer deserializer);
+
+@protected TafsirPassageDto? sse_decode_opt_box_autoadd_tafsir_passage_dto(SseDeserializer deserializer);
+
+@protected TranslatorDto? sse_decode_opt_box_autoadd_translator_dto(SseDeserializer deserializer);
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_u_8(SseDeserializer deserializer);
+
+@protected VerseAudioDto? sse_decode_opt_box_autoadd_verse_audio_dto(SseDeserializer deserializer);
+
+@protected VerseDto? sse_decode_opt_box_autoadd_verse_dto(SseDeserializer deserializer);
+
+@protected WordAudioDto? sse_decode_opt_box_autoadd_word_audio_dto(SseDeserializer deserializer);
+
+@protected WordDto? sse_decode_opt_box_autoadd_word_dto(SseDeserializer deserializer);
+
+@protected Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected PropagationDiagnosticsDto sse_decode_propagation_diagnostics_dto(SseDeserializer deserializer);
+
+@protected PropagationResultDto sse_decode_propagation_result_dto(SseDeserializer deserializer);
+
+@protected RecitationJumpDto sse_decode_recitation_jump_dto(SseDeserializer deserializer);
+
+@protected RecitationResultDto sse_decode_recitation_result_dto(SseDeserializer deserializer);
+
+@protected RecitedWordDto sse_decode_recited_word_dto(SseDeserializer deserializer);
+
+@protected ReciterDto sse_decode_reciter_dto(SseDeserializer deserializer);
+
+@protected (String,String) sse_decode_record_string_string(SseDeserializer deserializer);
+
+@protected SessionDto sse_decode_session_dto(SseDeserializer deserializer);
+
+@protected SessionItemDto sse_decode_session_item_dto(SseDeserializer deserializer);
+
+@protected SessionPreviewDto sse_decode_session_preview_dto(SseDeserializer deserializer);
+
+@protected SessionSummaryDto sse_decode_session_summary_dto(SseDeserializer deserializer);
+
+@protected SurahInfo sse_decode_surah_info(SseDeserializer deserializer);
+
+@protected SyncMemoryStateDto sse_decode_sync_memory_state_dto(SseDeserializer deserializer);
+
+@protected SyncSessionDto sse_decode_sync_session_dto(SseDeserializer deserializer);
+
+@protected SyncSessionItemDto sse_decode_sync_session_item_dto(SseDeserializer deserializer);
+
+@protected TafsirDto sse_decode_tafsir_dto(SseDeserializer deserializer);
+
+@protected TafsirPassageDto sse_decode_tafsir_passage_dto(SseDeserializer deserializer);
+
+@protected TajweedAnnotationDto sse_decode_tajweed_annotation_dto(SseDeserializer deserializer);
+
+@protected TranscriptWordDto sse_decode_transcript_word_dto(SseDeserializer deserializer);
+
+@protected TranslatorDto sse_decode_translator_dto(SseDeserializer deserializer);
+
+@protected int sse_decode_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+@protected int sse_decode_u_8(SseDeserializer deserializer);
+
+@protected void sse_decode_unit(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer);
+
+@protected ValidationResultDto sse_decode_validation_result_dto(SseDeserializer deserializer);
+
+@protected VerseAudioDto sse_decode_verse_audio_dto(SseDeserializer deserializer);
+
+@protected VerseDto sse_decode_verse_dto(SseDeserializer deserializer);
+
+@protected VerseWithTranslationDto sse_decode_verse_with_translation_dto(SseDeserializer deserializer);
+
+@protected WordAudioDto sse_decode_word_audio_dto(SseDeserializer deserializer);
+
+@protected WordDto sse_decode_word_dto(SseDeserializer deserializer);
+
+@protected WordSegmentDto sse_decode_word_segment_dto(SseDeserializer deserializer);
+
+@protected WordTimingDto sse_decode_word_timing_dto(SseDeserializer deserializer);
+
+@protected WordVisibilityDto sse_decode_word_visibility_dto(SseDeserializer deserializer);
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(AppState self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseService(ArcExerciseService self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningService(ArcLearningService self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionService(ArcSessionService self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepository(ArcContentRepository self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepository(ArcUserRepository self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(AppState self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(AppState self, SseSerializer serializer);
+
+@protected void sse_encode_Map_String_String_None(Map<String, String> self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(AppState self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseService(ArcExerciseService self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningService(ArcLearningService self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionService(ArcSessionService self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepository(ArcContentRepository self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepository(ArcUserRepository self, SseSerializer serializer);
+
+@protected void sse_encode_String(String self, SseSerializer serializer);
+
+@protected void sse_encode_activity_point_dto(ActivityPointDto self, SseSerializer serializer);
+
+@protected void sse_encode_ayah_chain_state_dto(AyahChainStateDto self, SseSerializer serializer);
+
+@protected void sse_encode_ayah_junction_dto(AyahJunctionDto self, SseSerializer serializer);
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_ayah_chain_state_dto(AyahChainStateDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_distractor_strategy_dto(DistractorStrategyDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_echo_recall_metrics_dto(EchoRecallMetricsDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_echo_recall_state_dto(EchoRecallStateDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_exercise_data_dto(ExerciseDataDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_exercise_hint_dto(ExerciseHintDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_f_32(double self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_hint_dto(HintDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_i_64(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_node_data(NodeData self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_node_filter_dto(NodeFilterDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_session_dto(SessionDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_session_item_dto(SessionItemDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_tafsir_passage_dto(TafsirPassageDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_translator_dto(TranslatorDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_8(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_verse_audio_dto(VerseAudioDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_verse_dto(VerseDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_word_audio_dto(WordAudioDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_word_dto(WordDto self, SseSerializer serializer);
+
+@protected void sse_encode_comprehension_dto(ComprehensionDto self, SseSerializer serializer);
+
+@protected void sse_encode_content_package_dto(ContentPackageDto self, SseSerializer serializer);
+
+@protected void sse_encode_dashboard_stats_dto(DashboardStatsDto self, SseSerializer serializer);
+
+@protected void sse_encode_db_health_dto(DbHealthDto self, SseSerializer serializer);
+
+@protected void sse_encode_db_query_result_dto(DbQueryResultDto self, SseSerializer serializer);
+
+@protected void sse_encode_debug_stats_dto(DebugStatsDto self, SseSerializer serializer);
+
+@protected void sse_encode_detailed_stats_dto(DetailedStatsDto self, SseSerializer serializer);
+
+@protected void sse_encode_distractor_strategy_dto(DistractorStrategyDto self, SseSerializer serializer);
+
+@protected void sse_encode_echo_recall_metrics_dto(EchoRecallMetricsDto self, SseSerializer serializer);
+
+@protected void sse_encode_echo_recall_result_dto(EchoRecallResultDto self, SseSerializer serializer);
+
+@protected void sse_encode_echo_recall_state_dto(EchoRecallStateDto self, SseSerializer serializer);
+
+@protected void sse_encode_echo_recall_stats_dto(EchoRecallStatsDto self, SseSerializer serializer);
+
+@protected void sse_encode_echo_recall_word_dto(EchoRecallWordDto self, SseSerializer serializer);
+
+@protected void sse_encode_energy_snapshot_dto(EnergySnapshotDto self, SseSerializer serializer);
+
+@protected void sse_encode_energy_update_dto(EnergyUpdateDto self, SseSerializer serializer);
+
+@protected void sse_encode_exercise_data_dto(ExerciseDataDto self, SseSerializer serializer);
+
+@protected void sse_encode_exercise_hint_dto(ExerciseHintDto self, SseSerializer serializer);
+
+@protected void sse_encode_f_32(double self, SseSerializer serializer);
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer);
+
+@protected void sse_encode_hint_dto(HintDto self, SseSerializer serializer);
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_installed_package_dto(InstalledPackageDto self, SseSerializer serializer);
+
+@protected void sse_encode_language_dto(LanguageDto self, SseSerializer serializer);
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+@protected void sse_encode_list_activity_point_dto(List<ActivityPointDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_ayah_junction_dto(List<AyahJunctionDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_content_package_dto(List<ContentPackageDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_echo_recall_word_dto(List<EchoRecallWordDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_energy_update_dto(List<EnergyUpdateDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_exercise_data_dto(List<ExerciseDataDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_exercise_hint_dto(List<ExerciseHintDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_installed_package_dto(List<InstalledPackageDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_language_dto(List<LanguageDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_list_String(List<List<String>> self, SseSerializer serializer);
+
+@protected void sse_encode_list_node_energy_dto(List<NodeEnergyDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_node_search_dto(List<NodeSearchDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_i_32_strict(Int32List self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
+
+@protected void sse_encode_list_recitation_jump_dto(List<RecitationJumpDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_recited_word_dto(List<RecitedWordDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_reciter_dto(List<ReciterDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_record_string_string(List<(String,String)> self, SseSerializer serializer);
+
+@protected void sse_encode_list_session_preview_dto(List<SessionPreviewDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_surah_info(List<SurahInfo> self, SseSerializer serializer);
+
+@protected void sse_encode_list_sync_memory_state_dto(List<SyncMemoryStateDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_sync_session_dto(List<SyncSessionDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_sync_session_item_dto(List<SyncSessionItemDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_tafsir_dto(List<TafsirDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_tajweed_annotation_dto(List<TajweedAnnotationDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_transcript_word_dto(List<TranscriptWordDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_translator_dto(List<TranslatorDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_verse_with_translation_dto(List<VerseWithTranslationDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_word_dto(List<WordDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_word_segment_dto(List<WordSegmentDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_word_timing_dto(List<WordTimingDto> self, SseSerializer serializer);
+
+@protected void sse_encode_node_data(NodeData self, SseSerializer serializer);
+
+@protected void sse_encode_node_energy_dto(NodeEnergyDto self, SseSerializer serializer);
+
+@protected void sse_encode_node_filter_dto(NodeFilterDto self, SseSerializer serializer);
+
+@protected void sse_encode_node_search_dto(NodeSearchDto self, SseSerializer serializer);
+
+@protected void sse_encode_node_status_dto(NodeStatusDto self, SseSerializer serializer);
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_ayah_chain_state_dto(AyahChainStateDto? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_distractor_strategy_dto(DistractorStrategyDto? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_echo_recall_state_dto(EchoRecallStateDto? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_exercise_hint_dto(ExerciseHintDto? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_f_32(double? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_hint_dto(HintDto? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_i_64(PlatformInt64? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_node_data(NodeData? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_session_dto(SessionDto? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_session_item_dto(SessionItemDto? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_tafsir_passage_dto(TafsirPassageDto? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_translator_dto(TranslatorDto? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_8(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_verse_audio_dto(VerseAudioDto? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_verse_dto(VerseDto? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_word_audio_dto(WordAudioDto? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_word_dto(WordDto? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_list_prim_u_8_strict(Uint8List? self, SseSerializer serializer);
+
+@protected void sse_encode_propagation_diagnostics_dto(PropagationDiagnosticsDto self, SseSerializer serializer);
+
+@protected void sse_encode_propagation_result_dto(PropagationResultDto self, SseSerializer serializer);
+
+@protected void sse_encode_recitation_jump_dto(RecitationJumpDto self, SseSerializer serializer);
+
+@protected void sse_encode_recitation_result_dto(RecitationResultDto self, SseSerializer serializer);
+
+@protected void sse_encode_recited_word_dto(RecitedWordDto self, SseSerializer serializer);
+
+@protected void sse_encode_reciter_dto(ReciterDto self, SseSerializer serializer);
+
+@protected void sse_encode_record_string_string((String,String) self, SseSerializer serializer);
+
+@protected void sse_encode_session_dto(SessionDto self, SseSerializer serializer);
+
+@protected void sse_encode_session_item_dto(SessionItemDto self, SseSerializer serializer);
+
+@protected void sse_encode_session_preview_dto(SessionPreviewDto self, SseSerializer serializer);
+
+@protected void sse_encode_session_summary_dto(SessionSummaryDto self, SseSerializer serializer);
+
+@protected void sse_encode_surah_info(SurahInfo self, SseSerializer serializer);
+
+@protected void sse_encode_sync_memory_state_dto(SyncMemoryStateDto self, SseSerializer serializer);
+
+@protected void sse_encode_sync_session_dto(SyncSessionDto self, SseSerializer serializer);
+
+@protected void sse_encode_sync_session_item_dto(SyncSessionItemDto self, SseSerializer serializer);
+
+@protected void sse_encode_tafsir_dto(TafsirDto self, SseSerializer serializer);
+
+@protected void sse_encode_tafsir_passage_dto(TafsirPassageDto self, SseSerializer serializer);
+
+@protected void sse_encode_tajweed_annotation_dto(TajweedAnnotationDto self, SseSerializer serializer);
+
+@protected void sse_encode_transcript_word_dto(TranscriptWordDto self, SseSerializer serializer);
+
+@protected void sse_encode_translator_dto(TranslatorDto self, SseSerializer serializer);
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer);
+
+@protected void sse_encode_unit(void self, SseSerializer serializer);
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_validation_result_dto(ValidationResultDto self, SseSerializer serializer);
+
+@protected void sse_encode_verse_audio_dto(VerseAudioDto self, SseSerializer serializer);
+
+@protected void sse_encode_verse_dto(VerseDto self, SseSerializer serializer);
+
+@protected void sse_encode_verse_with_translation_dto(VerseWithTranslationDto self, SseSerializer serializer);
+
+@protected void sse_encode_word_audio_dto(WordAudioDto self, SseSerializer serializer);
+
+@protected void sse_encode_word_dto(WordDto self, SseSerializer serializer);
+
+@protected void sse_encode_word_segment_dto(WordSegmentDto self, SseSerializer serializer);
+
+@protected void sse_encode_word_timing_dto(WordTimingDto self, SseSerializer serializer);
+
+@protected void sse_encode_word_visibility_dto(WordVisibilityDto self, SseSerializer serializer);
+                }
+                
 
-  @protected
-  PropagationResultDto sse_decode_propagation_result_dto(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  (String, String) sse_decode_record_string_string(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  SessionDto sse_decode_session_dto(SseDeserializer deserializer);
-
-  @protected
-  SessionItemDto sse_decode_session_item_dto(SseDeserializer deserializer);
-
-  @protected
-  SessionPreviewDto sse_decode_session_preview_dto(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  SessionSummaryDto sse_decode_session_summary_dto(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  SurahInfo sse_decode_surah_info(SseDeserializer deserializer);
-
-  @protected
-  SyncMemoryStateDto sse_decode_sync_memory_state_dto(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  SyncSessionDto sse_decode_sync_session_dto(SseDeserializer deserializer);
-
-  @protected
-  SyncSessionItemDto sse_decode_sync_session_item_dto(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  TranslatorDto sse_decode_translator_dto(SseDeserializer deserializer);
-
-  @protected
-  int sse_decode_u_32(SseDeserializer deserializer);
-
-  @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer);
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
-
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer);
-
-  @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer);
-
-  @protected
-  VerseDto sse_decode_verse_dto(SseDeserializer deserializer);
-
-  @protected
-  VerseWithTranslationDto sse_decode_verse_with_translation_dto(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  WordDto sse_decode_word_dto(SseDeserializer deserializer);
-
-  @protected
-  WordTimingDto sse_decode_word_timing_dto(SseDeserializer deserializer);
-
-  @protected
-  WordVisibilityDto sse_decode_word_visibility_dto(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(
-    AppState self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseService(
-    ArcExerciseService self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningService(
-    ArcLearningService self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionService(
-    ArcSessionService self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepository(
-    ArcContentRepository self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepository(
-    ArcUserRepository self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(
-    AppState self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(
-    AppState self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_Map_String_String_None(
-    Map<String, String> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(
-    AppState self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseService(
-    ArcExerciseService self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningService(
-    ArcLearningService self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionService(
-    ArcSessionService self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepository(
-    ArcContentRepository self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepository(
-    ArcUserRepository self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_activity_point_dto(
-    ActivityPointDto self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_box_autoadd_echo_recall_metrics_dto(
-    EchoRecallMetricsDto self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_box_autoadd_echo_recall_state_dto(
-    EchoRecallStateDto self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_box_autoadd_hint_dto(HintDto self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_box_autoadd_i_64(
-    PlatformInt64 self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_box_autoadd_node_data(
-    NodeData self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_box_autoadd_node_filter_dto(
-    NodeFilterDto self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_box_autoadd_session_dto(
-    SessionDto self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_box_autoadd_session_item_dto(
-    SessionItemDto self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_box_autoadd_translator_dto(
-    TranslatorDto self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_box_autoadd_verse_dto(
-    VerseDto self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_box_autoadd_word_dto(WordDto self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_comprehension_dto(
-    ComprehensionDto self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_content_package_dto(
-    ContentPackageDto self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_dashboard_stats_dto(
-    DashboardStatsDto self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_db_health_dto(DbHealthDto self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_db_query_result_dto(
-    DbQueryResultDto self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_debug_stats_dto(DebugStatsDto self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_detailed_stats_dto(
-    DetailedStatsDto self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_echo_recall_metrics_dto(
-    EchoRecallMetricsDto self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_echo_recall_result_dto(
-    EchoRecallResultDto self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_echo_recall_state_dto(
-    EchoRecallStateDto self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_echo_recall_stats_dto(
-    EchoRecallStatsDto self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_echo_recall_word_dto(
-    EchoRecallWordDto self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_energy_snapshot_dto(
-    EnergySnapshotDto self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_energy_update_dto(
-    EnergyUpdateDto self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_exercise_data_dto(
-    ExerciseDataDto self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_f_64(double self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_hint_dto(HintDto self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_installed_package_dto(
-    InstalledPackageDto self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_language_dto(LanguageDto self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_list_activity_point_dto(
-    List<ActivityPointDto> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_content_package_dto(
-    List<ContentPackageDto> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_echo_recall_word_dto(
-    List<EchoRecallWordDto> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_energy_update_dto(
-    List<EnergyUpdateDto> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_exercise_data_dto(
-    List<ExerciseDataDto> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_installed_package_dto(
-    List<InstalledPackageDto> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_language_dto(
-    List<LanguageDto> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_list_String(
-    List<List<String>> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_node_energy_dto(
-    List<NodeEnergyDto> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_node_search_dto(
-    List<NodeSearchDto> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_record_string_string(
-    List<(String, String)> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_session_preview_dto(
-    List<SessionPreviewDto> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_surah_info(
-    List<SurahInfo> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_sync_memory_state_dto(
-    List<SyncMemoryStateDto> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_sync_session_dto(
-    List<SyncSessionDto> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_sync_session_item_dto(
-    List<SyncSessionItemDto> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_translator_dto(
-    List<TranslatorDto> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_verse_with_translation_dto(
-    List<VerseWithTranslationDto> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_word_dto(List<WordDto> self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_list_word_timing_dto(
-    List<WordTimingDto> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_node_data(NodeData self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_node_energy_dto(NodeEnergyDto self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_node_filter_dto(NodeFilterDto self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_node_search_dto(NodeSearchDto self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_opt_box_autoadd_hint_dto(
-    HintDto? self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_opt_box_autoadd_i_64(
-    PlatformInt64? self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_opt_box_autoadd_node_data(
-    NodeData? self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_opt_box_autoadd_session_dto(
-    SessionDto? self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_opt_box_autoadd_session_item_dto(
-    SessionItemDto? self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_opt_box_autoadd_translator_dto(
-    TranslatorDto? self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_opt_box_autoadd_verse_dto(
-    VerseDto? self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_opt_box_autoadd_word_dto(
-    WordDto? self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_propagation_diagnostics_dto(
-    PropagationDiagnosticsDto self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_propagation_result_dto(
-    PropagationResultDto self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_record_string_string(
-    (String, String) self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_session_dto(SessionDto self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_session_item_dto(
-    SessionItemDto self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_session_preview_dto(
-    SessionPreviewDto self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_session_summary_dto(
-    SessionSummaryDto self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_surah_info(SurahInfo self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_sync_memory_state_dto(
-    SyncMemoryStateDto self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_sync_session_dto(
-    SyncSessionDto self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_sync_session_item_dto(
-    SyncSessionItemDto self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_translator_dto(TranslatorDto self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_u_32(int self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_u_64(BigInt self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_verse_dto(VerseDto self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_verse_with_translation_dto(
-    VerseWithTranslationDto self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_word_dto(WordDto self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_word_timing_dto(WordTimingDto self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_word_visibility_dto(
-    WordVisibilityDto self,
-    SseSerializer serializer,
-  );
-}
 
 // Section: wire_class
 
-class RustLibWire implements BaseWire {
-  factory RustLibWire.fromExternalLibrary(ExternalLibrary lib) =>
-      RustLibWire(lib.ffiDynamicLibrary);
-
-  /// Holds the symbol lookup function.
-  final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
-  _lookup;
-
-  /// The symbols are looked up in [dynamicLibrary].
-  RustLibWire(ffi.DynamicLibrary dynamicLibrary)
-    : _lookup = dynamicLibrary.lookup;
-
-  void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppStatePtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_iqrah_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState',
-      );
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState =
-      _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppStatePtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppStatePtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_iqrah_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState',
-      );
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState =
-      _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppStatePtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseService(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseService(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseServicePtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_iqrah_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseService',
-      );
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseService =
-      _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseServicePtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseService(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseService(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseServicePtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_iqrah_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseService',
-      );
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseService =
-      _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseServicePtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningService(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningService(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningServicePtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_iqrah_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningService',
-      );
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningService =
-      _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningServicePtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningService(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningService(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningServicePtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_iqrah_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningService',
-      );
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningService =
-      _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningServicePtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionService(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionService(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionServicePtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_iqrah_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionService',
-      );
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionService =
-      _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionServicePtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionService(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionService(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionServicePtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_iqrah_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionService',
-      );
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionService =
-      _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionServicePtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepository(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepository(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepositoryPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_iqrah_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepository',
-      );
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepository =
-      _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepositoryPtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepository(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepository(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepositoryPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_iqrah_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepository',
-      );
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepository =
-      _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepositoryPtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepository(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepository(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepositoryPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_iqrah_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepository',
-      );
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepository =
-      _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepositoryPtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepository(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepository(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepositoryPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_iqrah_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepository',
-      );
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepository =
-      _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepositoryPtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-}
+
+        class RustLibWire implements BaseWire {
+
+            factory RustLibWire.fromExternalLibrary(ExternalLibrary lib) =>
+              RustLibWire(lib.ffiDynamicLibrary);
+        
+            /// Holds the symbol lookup function.
+            final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
+                _lookup;
+  
+            /// The symbols are looked up in [dynamicLibrary].
+            RustLibWire(ffi.DynamicLibrary dynamicLibrary)
+                : _lookup = dynamicLibrary.lookup;
+
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppStatePtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_iqrah_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppStatePtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppStatePtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_iqrah_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppState = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppStatePtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseService(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseService(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseServicePtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_iqrah_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseService');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseService = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseServicePtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseService(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseService(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseServicePtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_iqrah_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseService');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseService = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcExerciseServicePtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningService(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningService(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningServicePtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_iqrah_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningService');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningService = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningServicePtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningService(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningService(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningServicePtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_iqrah_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningService');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningService = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcLearningServicePtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionService(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionService(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionServicePtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_iqrah_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionService');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionService = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionServicePtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionService(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionService(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionServicePtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_iqrah_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionService');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionService = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSessionServicePtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepository(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepository(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepositoryPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_iqrah_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepository');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepository = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepositoryPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepository(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepository(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepositoryPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_iqrah_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepository');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepository = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynContentRepositoryPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepository(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepository(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepositoryPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_iqrah_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepository');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepository = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepositoryPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepository(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepository(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepositoryPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_iqrah_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepository');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepository = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcdynUserRepositoryPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+        }
+        
\ No newline at end of file
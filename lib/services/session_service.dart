@@ -18,17 +18,15 @@ class SessionService {
 
   Future<void> submitItem({
     required String sessionId,
-    required String nodeId,
-    required String exerciseType,
-    required int grade,
+    required bool isCorrect,
     required int durationMs,
+    int hintLevel = 0,
   }) async {
     await api.submitSessionItem(
       sessionId: sessionId,
-      nodeId: nodeId,
-      exerciseType: exerciseType,
-      grade: grade,
+      isCorrect: isCorrect,
       durationMs: BigInt.from(durationMs),
+      hintLevel: hintLevel,
     );
   }
 
@@ -1,9 +1,23 @@
 use anyhow::Result;
 // Re-exported for frb_generated access
 use iqrah_core::domain::node_id as nid;
+use iqrah_core::exercises::{
+    clear_checkpoint, expire_checkpoints, load_checkpoint, save_checkpoint, AnswerInput,
+    DistractorDifficulty, DistractorSignal, DistractorStrategy, GradeThresholds, LearnerContext,
+    SemanticScoring,
+};
 pub use iqrah_core::exercises::{ExerciseData, ExerciseService};
-use iqrah_core::{import_cbor_graph_from_bytes, KnowledgeNode, ReviewGrade};
-use iqrah_core::{ContentPackage, InstalledPackage, PackageService, PackageType};
+use iqrah_core::services::response_grading::{
+    ResponseBaseline, ResponseEvidence, ResponseGradingModel,
+};
+use iqrah_core::{
+    import_cbor_graph_from_bytes, ArabicLevel, HintLevel, KnowledgeNode, NodeStatus, ReviewGrade,
+    ScoredItem, Script,
+};
+use iqrah_core::{
+    AudioSource, ContentPackage, InstalledPackage, PackageService, PackageType, Reciter, Tafsir,
+    TafsirPassage, TajweedAnnotation, TajweedRule, VerseRecitation, WordAudioClip,
+};
 pub use iqrah_core::{ContentRepository, LearningService, SessionService, UserRepository};
 use iqrah_storage::{
     create_content_repository, init_content_db, init_user_db, SqliteUserRepository,
@@ -14,6 +28,32 @@ use std::sync::Arc;
 use uuid::Uuid;
 
 const SESSION_ITEM_LIMIT: u32 = 20;
+/// Recent attempts on a node considered when choosing its exercise type
+const RECENT_ITEMS_FOR_SELECTION: u32 = 5;
+/// Exercise types the Flutter app has a widget for (see `ExerciseContainer`)
+const APP_EXERCISE_TYPES: &[&str] = &[
+    "memorization",
+    "mcq_ar_to_en",
+    "mcq_en_to_ar",
+    "translation",
+    "contextual_translation",
+    "cloze_deletion",
+    "first_letter_hint",
+    "missing_word_mcq",
+    "next_word_mcq",
+    "full_verse_input",
+    "ayah_chain",
+    "find_mistake",
+    "ayah_sequence",
+    "sequence_recall",
+    "first_word_recall",
+    "identify_root",
+    "reverse_cloze",
+    "translate_phrase",
+    "pos_tagging",
+    "cross_verse_connection",
+    "echo_recall",
+];
 
 pub struct AppState {
     pub content_repo: Arc<dyn ContentRepository>,
@@ -185,7 +225,22 @@ pub async fn setup_database(
         Arc::clone(&user_repo),
     ));
 
-    let exercise_service = Arc::new(ExerciseService::new(Arc::clone(&content_repo)));
+    let script = user_repo
+        .get_setting(Script::SETTING_KEY)
+        .await?
+        .and_then(|slug| Script::from_slug(&slug))
+        .unwrap_or_default();
+    let grade_thresholds = user_repo
+        .get_setting(GradeThresholds::SETTING_KEY)
+        .await?
+        .and_then(|json| GradeThresholds::from_setting(&json))
+        .unwrap_or_default();
+    let exercise_service = Arc::new(
+        ExerciseService::new(Arc::clone(&content_repo))
+            .with_script(script)
+            .with_grade_thresholds(grade_thresholds)
+            .with_exercise_types(APP_EXERCISE_TYPES),
+    );
 
     // Store debug pool separately (debug builds only)
     #[cfg(debug_assertions)]
@@ -227,7 +282,9 @@ pub async fn get_exercises(
     let due_items = app
         .session_service
         .get_due_items(&user_id, chrono::Utc::now(), limit, is_high_yield, None)
-        .await?;
+        .await?
+        .into_iter()
+        .filter(|item| app_serves(app, item));
 
     let mut exercises = Vec::new();
     for item in due_items {
@@ -291,7 +348,10 @@ pub async fn start_session(user_id: String, goal_id: String) -> Result<SessionDt
             Some(&goal_id),
             None,
         )
-        .await?;
+        .await?
+        .into_iter()
+        .filter(|item| app_serves(app, item))
+        .collect::<Vec<_>>();
 
     let node_ids: Vec<i64> = due_items.iter().map(|item| item.node.id).collect();
     let continuity_count = due_items
@@ -308,6 +368,8 @@ pub async fn start_session(user_id: String, goal_id: String) -> Result<SessionDt
         .count() as u32;
 
     app.session_service.save_session_state(&node_ids).await?;
+    // Checkpoints of abandoned stateful exercises
+    let _ = expire_checkpoints(app.user_repo.as_ref(), chrono::Utc::now()).await;
 
     let session = iqrah_core::Session {
         id: Uuid::new_v4().to_string(),
@@ -355,6 +417,12 @@ pub async fn start_session(user_id: String, goal_id: String) -> Result<SessionDt
     })
 }
 
+/// Whether the app can render an exercise for a due item's axis
+fn app_serves(app: &AppState, item: &ScoredItem) -> bool {
+    item.knowledge_axis
+        .is_none_or(|axis| app.exercise_service.supports_axis(axis))
+}
+
 /// Get the active (incomplete) session for a user
 pub async fn get_active_session(user_id: String) -> Result<Option<SessionDto>> {
     let app = app();
@@ -387,10 +455,45 @@ pub async fn get_next_session_item(session_id: String) -> Result<Option<SessionI
 
     let node_id = node_ids[index];
     let ukey = nid::to_ukey(node_id).unwrap_or_default();
-    let data = app
-        .exercise_service
-        .generate_exercise_v2(node_id, &ukey)
+    let state = app
+        .user_repo
+        .get_memory_state(&session.user_id, node_id)
+        .await?;
+    let recent_items = app
+        .user_repo_sqlite
+        .get_recent_session_items(&session.user_id, node_id, RECENT_ITEMS_FOR_SELECTION)
         .await?;
+    let ctx = LearnerContext::from_history(state.as_ref(), &recent_items)
+        .with_arabic_level(stored_arabic_level().await?);
+    // The exercise served for the item is kept until it is answered, so that
+    // the answer is checked against it (see `submit_session_answer`)
+    let position = index as i32;
+    let data = match load_served_exercise(&session_id, position).await? {
+        Some(data) => data,
+        None => {
+            let data = app
+                .exercise_service
+                .generate_exercise_adaptive(node_id, &ukey, &ctx)
+                .await?;
+            // Ayah Chain progress is checkpointed in the same slot; keep it
+            if app
+                .user_repo
+                .get_exercise_checkpoint(&session_id, position)
+                .await?
+                .is_none()
+            {
+                save_checkpoint(
+                    app.user_repo.as_ref(),
+                    &session_id,
+                    position,
+                    &session.user_id,
+                    &data,
+                )
+                .await?;
+            }
+            data
+        }
+    };
     let exercise_type = data.type_name().to_string();
     let mut dto: ExerciseDataDto = data.into();
     if let ExerciseDataDto::EchoRecall {
@@ -403,54 +506,256 @@ pub async fn get_next_session_item(session_id: String) -> Result<Option<SessionI
 
     Ok(Some(SessionItemDto {
         session_id,
-        position: index as i32,
+        position,
         node_id: ukey,
         exercise_type,
         exercise: dto,
     }))
 }
 
-/// Submit a completed session item
+/// The exercise served for a session item, while it is unanswered
+///
+/// Once a recall is submitted, an Echo Recall item's checkpoint holds its
+/// progress instead (see `submit_session_echo_recall`); the served exercise
+/// is rebuilt from the progress's ayahs.
+async fn load_served_exercise(session_id: &str, position: i32) -> Result<Option<ExerciseData>> {
+    use iqrah_core::exercises::EchoRecallExercise;
+
+    let user_repo = app().user_repo.as_ref();
+    if let Some(data) = load_checkpoint::<ExerciseData>(user_repo, session_id, position).await? {
+        return Ok(Some(data));
+    }
+    let echo_recall: Option<EchoRecallExercise> =
+        load_checkpoint(user_repo, session_id, position).await?;
+    Ok(echo_recall.map(|exercise| ExerciseData::EchoRecall {
+        ayah_node_ids: exercise
+            .ayah_node_ids()
+            .iter()
+            .filter_map(|ukey| nid::from_ukey(ukey))
+            .collect(),
+    }))
+}
+
+/// Record the current session item from the app widget's own answer check
+///
+/// For exercises whose answer is checked on the device. The item is the
+/// exercise served by `get_next_session_item`, and its grade is worked out
+/// here from `is_correct`, response time and `hint_level` (0 for none), as
+/// for `submit_session_answer`. Prefer `submit_session_answer`, which also
+/// checks the answer.
 pub async fn submit_session_item(
     session_id: String,
-    node_id: String,
-    exercise_type: String,
-    grade: u8,
+    is_correct: bool,
     duration_ms: u64,
+    hint_level: u8,
 ) -> Result<String> {
+    let hint_level = parse_hint_level(hint_level)?;
+    let (exercise, node_id) = served_session_item(&session_id).await?;
+    // Graded like an all-or-nothing answer (see `ValidationResult::suggest_grade`)
+    let grade = if is_correct {
+        ReviewGrade::Good
+    } else {
+        ReviewGrade::Again
+    };
+    record_session_item(
+        &session_id,
+        &node_id,
+        exercise.type_name(),
+        grade,
+        duration_ms,
+        hint_level,
+    )
+    .await?;
+    Ok("Session item recorded".to_string())
+}
+
+/// Check the answer to the current session item's exercise and record the item.
+///
+/// The answer is checked against the exercise served by
+/// `get_next_session_item`. `answer_json` is a serialized `AnswerInput`
+/// (e.g. `{"type":"word_id","value":...}`); anything else is taken as a text
+/// answer. `hint_level` is the most revealing hint shown (see
+/// `get_exercise_hints`), 0 for none. The returned `suggested_grade` is the
+/// grade recorded after accounting for response time and hints.
+pub async fn submit_session_answer(
+    session_id: String,
+    answer_json: String,
+    duration_ms: u64,
+    hint_level: u8,
+) -> Result<ValidationResultDto> {
+    let hint_level = parse_hint_level(hint_level)?;
+    let (exercise, node_id) = served_session_item(&session_id).await?;
+    let mut result = validate_exercise_answer(&exercise, &answer_json, hint_level).await?;
+    result.suggested_grade = record_session_item(
+        &session_id,
+        &node_id,
+        exercise.type_name(),
+        result.suggested_grade,
+        duration_ms,
+        hint_level,
+    )
+    .await?;
+    Ok(result.into())
+}
+
+/// Progressive hints for an exercise, least revealing first. Reveal them one
+/// at a time; each caps the grade the answer can still earn.
+pub async fn get_exercise_hints(exercise: ExerciseDataDto) -> Result<Vec<ExerciseHintDto>> {
+    let exercise = ExerciseData::try_from(exercise)?;
+    let hints = app().exercise_service.get_hint_ladder(&exercise).await?;
+    Ok(hints.into_iter().map(ExerciseHintDto::from).collect())
+}
+
+/// The hint at `step` (0-indexed) of an exercise's hint ladder, or None once
+/// every hint has been revealed
+pub async fn get_exercise_hint(
+    exercise: ExerciseDataDto,
+    step: u32,
+) -> Result<Option<ExerciseHintDto>> {
+    let exercise = ExerciseData::try_from(exercise)?;
+    let hint = app()
+        .exercise_service
+        .get_hint_at(&exercise, step as usize)
+        .await?;
+    Ok(hint.map(ExerciseHintDto::from))
+}
+
+/// Check an answer to an exercise without recording it (see `submit_session_answer`)
+pub async fn check_exercise_answer(
+    exercise: ExerciseDataDto,
+    answer_json: String,
+) -> Result<ValidationResultDto> {
+    let exercise = ExerciseData::try_from(exercise)?;
+    Ok(validate_exercise_answer(&exercise, &answer_json, None)
+        .await?
+        .into())
+}
+
+async fn validate_exercise_answer(
+    exercise: &ExerciseData,
+    answer_json: &str,
+    hint_level: Option<HintLevel>,
+) -> Result<iqrah_core::exercises::ValidationResult> {
+    let answer =
+        serde_json::from_str::<AnswerInput>(answer_json).unwrap_or_else(|_| AnswerInput::Text {
+            value: answer_json.to_string(),
+        });
+    app()
+        .exercise_service
+        .validate_answer(exercise, &answer, hint_level)
+        .await
+}
+
+/// The exercise served for a session's current item and the item's node,
+/// which may be a knowledge node of the exercise's node
+async fn served_session_item(session_id: &str) -> Result<(ExerciseData, String)> {
     let app = app();
     let session = app
         .user_repo
-        .get_session(&session_id)
+        .get_session(session_id)
         .await?
         .ok_or_else(|| anyhow::anyhow!("Session not found"))?;
+    let exercise = load_served_exercise(session_id, session.items_completed)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("No exercise served for the current session item"))?;
+    let node_id = app
+        .session_service
+        .get_session_state()
+        .await?
+        .get(session.items_completed as usize)
+        .and_then(|&id| nid::to_ukey(id))
+        .ok_or_else(|| anyhow::anyhow!("No node for the current session item"))?;
+    Ok((exercise, node_id))
+}
 
-    let nid_val = nid::from_ukey(&node_id).ok_or_else(|| anyhow::anyhow!("Invalid node ID"))?;
+/// Hint level from its FFI value, 0 for no hint
+fn parse_hint_level(level: u8) -> Result<Option<HintLevel>> {
+    match level {
+        0 => Ok(None),
+        level => HintLevel::from_level(level)
+            .map(Some)
+            .ok_or_else(|| anyhow::anyhow!("Invalid hint level: {}", level)),
+    }
+}
+
+/// Record a session item and review its node. Except for Echo Recall (which
+/// grades itself from per-word timings), the correctness grade is adjusted for
+/// response time against the user's baseline for the exercise type and capped
+/// by the hint level used, which is stored with the item. Returns the recorded
+/// grade.
+async fn record_session_item(
+    session_id: &str,
+    node_id: &str,
+    exercise_type: &str,
+    grade: ReviewGrade,
+    duration_ms: u64,
+    hint_level: Option<HintLevel>,
+) -> Result<ReviewGrade> {
+    let app = app();
+    let session = app
+        .user_repo
+        .get_session(session_id)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("Session not found"))?;
+
+    let nid_val = nid::from_ukey(node_id).ok_or_else(|| anyhow::anyhow!("Invalid node ID"))?;
+
+    let grade = if exercise_type == "echo_recall" {
+        grade
+    } else {
+        let durations = app
+            .user_repo_sqlite
+            .get_response_durations(
+                &session.user_id,
+                exercise_type,
+                ResponseGradingModel::BASELINE_WINDOW,
+            )
+            .await?;
+        ResponseGradingModel::default().grade(
+            &ResponseEvidence {
+                base_grade: grade,
+                duration_ms,
+                hint_level,
+            },
+            ResponseBaseline::from_durations(&durations).as_ref(),
+        )
+    };
 
     let item = iqrah_core::SessionItem {
         id: 0,
-        session_id: session_id.clone(),
+        session_id: session_id.to_string(),
         node_id: nid_val,
-        exercise_type: exercise_type.clone(),
+        exercise_type: exercise_type.to_string(),
         grade: grade as i32,
         duration_ms: Some(duration_ms as i64),
         completed_at: Some(chrono::Utc::now()),
+        hint_level,
     };
 
     app.user_repo.insert_session_item(&item).await?;
     app.user_repo
-        .update_session_progress(&session_id, session.items_completed + 1)
+        .update_session_progress(session_id, session.items_completed + 1)
         .await?;
+    clear_checkpoint(app.user_repo.as_ref(), session_id, session.items_completed).await?;
 
     if exercise_type != "echo_recall" {
-        let review_grade = ReviewGrade::from(grade);
         app.learning_service
-            .process_review(&session.user_id, nid_val, review_grade)
+            .process_review(&session.user_id, nid_val, grade)
             .await?;
-        let _ = app.session_service.increment_stat("reviews_today").await;
+        count_review_today(app).await;
     }
 
-    Ok("Session item recorded".to_string())
+    Ok(grade)
+}
+
+/// Count a review towards today's stats
+///
+/// Best effort: the review itself is already recorded, so a failure is only
+/// logged.
+async fn count_review_today(app: &AppState) {
+    if let Err(e) = app.session_service.increment_stat("reviews_today").await {
+        tracing::warn!("Failed to count review in today's stats: {}", e);
+    }
 }
 
 /// Complete a session and return summary
@@ -696,6 +1001,7 @@ pub async fn upsert_session_items_from_remote(
             grade: item.grade.unwrap_or(0),
             duration_ms: item.duration_ms,
             completed_at: Some(completed_at),
+            hint_level: None,
         };
 
         app.user_repo_sqlite
@@ -722,6 +1028,69 @@ pub async fn set_last_sync_timestamp(user_id: String, timestamp: i64) -> Result<
     Ok("ok".to_string())
 }
 
+/// Get the page exercises for a mushaf page: recite the page and name its
+/// first word
+///
+/// The exercises are generated for the first verse of the page's memorization
+/// goal (`memorization:page-N`), so the content database must have been built
+/// with a mushaf layout.
+pub async fn get_page_exercises(page: i32) -> Result<Vec<ExerciseDataDto>> {
+    let app = app();
+    let goal_id = format!("memorization:page-{}", page);
+    let verse_node_id = app
+        .content_repo
+        .get_nodes_for_goal(&goal_id)
+        .await?
+        .into_iter()
+        .min()
+        .ok_or_else(|| anyhow::anyhow!("No verses start on page {}", page))?;
+    let verse_ukey = nid::to_ukey(verse_node_id)
+        .ok_or_else(|| anyhow::anyhow!("Invalid verse node ID: {}", verse_node_id))?;
+
+    let mut exercises = Vec::new();
+    for exercise_type in ["recite_page", "page_first_word"] {
+        let exercise = app
+            .exercise_service
+            .generate_by_type(exercise_type, verse_node_id, &verse_ukey)
+            .await?;
+        exercises.push(exercise.into());
+    }
+    Ok(exercises)
+}
+
+/// Rebuild an exercise from its node, type (`ExerciseData::type_name`) and
+/// the `seed` it was sent with
+///
+/// Pass an MCQ's `distractor_strategy` to pick the same distractors again;
+/// without it they are picked at the default difficulty.
+pub async fn regenerate_exercise(
+    node_id: String,
+    exercise_type: String,
+    seed: u64,
+    distractor_strategy: Option<DistractorStrategyDto>,
+) -> Result<ExerciseDataDto> {
+    let app = app();
+    let (difficulty, semantic) = match distractor_strategy {
+        Some(dto) => {
+            let strategy = DistractorStrategy::try_from(dto)?;
+            (strategy.difficulty, strategy.semantic_scoring())
+        }
+        None => (DistractorDifficulty::default(), SemanticScoring::IfLoaded),
+    };
+    let exercise = app
+        .exercise_service
+        .generate_by_type_seeded(
+            &exercise_type,
+            node_id_from_ukey(&node_id)?,
+            &node_id,
+            seed,
+            difficulty,
+            semantic,
+        )
+        .await?;
+    Ok(exercise.into())
+}
+
 /// Get exercises for a specific node (Sandbox/Preview)
 pub async fn get_exercises_for_node(node_id: String) -> Result<Vec<ExerciseDataDto>> {
     let app = app();
@@ -778,14 +1147,21 @@ pub async fn get_exercises_for_node(node_id: String) -> Result<Vec<ExerciseDataD
             push_unique(ex);
         }
 
-        if let Ok(ex) = iqrah_core::exercises::generate_pos_tagging(
-            base_node_id,
-            &base_ukey,
-            app.content_repo.as_ref(),
-        )
-        .await
-        {
-            push_unique(ex);
+        for type_name in [
+            "pos_tagging",
+            "grammar_feature",
+            "transliteration_to_arabic",
+            "arabic_to_transliteration",
+            "lemma_meaning",
+            "listen_identify_word",
+        ] {
+            if let Ok(ex) = app
+                .exercise_service
+                .generate_by_type(type_name, base_node_id, &base_ukey)
+                .await
+            {
+                push_unique(ex);
+            }
         }
     }
 
@@ -810,12 +1186,17 @@ pub async fn get_exercises_for_node(node_id: String) -> Result<Vec<ExerciseDataD
             push_unique(ex);
         }
 
-        if let Ok(ex) = iqrah_core::exercises::generate_find_mistake(
-            base_node_id,
-            &base_ukey,
-            app.content_repo.as_ref(),
-        )
-        .await
+        if let Ok(ex) = app
+            .exercise_service
+            .generate_by_type("find_mistake", base_node_id, &base_ukey)
+            .await
+        {
+            push_unique(ex);
+        }
+        if let Ok(ex) = app
+            .exercise_service
+            .generate_by_type("mutashabihat", base_node_id, &base_ukey)
+            .await
         {
             push_unique(ex);
         }
@@ -829,8 +1210,28 @@ pub async fn get_exercises_for_node(node_id: String) -> Result<Vec<ExerciseDataD
         {
             push_unique(ex);
         }
-
-        if let Ok(ex) = iqrah_core::exercises::generate_cloze_deletion(
+        if let Ok(ex) = app
+            .exercise_service
+            .generate_by_type("word_order", base_node_id, &base_ukey)
+            .await
+        {
+            push_unique(ex);
+        }
+        if let Ok(ex) = app
+            .exercise_service
+            .generate_by_type("identify_surah", base_node_id, &base_ukey)
+            .await
+        {
+            push_unique(ex);
+        }
+        if let Ok(ex) = app
+            .exercise_service
+            .generate_by_type("identify_verse_number", base_node_id, &base_ukey)
+            .await
+        {
+            push_unique(ex);
+        }
+        if let Ok(ex) = iqrah_core::exercises::generate_identify_page_half(
             base_node_id,
             &base_ukey,
             app.content_repo.as_ref(),
@@ -839,8 +1240,27 @@ pub async fn get_exercises_for_node(node_id: String) -> Result<Vec<ExerciseDataD
         {
             push_unique(ex);
         }
-
-        if let Ok(ex) = iqrah_core::exercises::generate_first_letter_hint(
+        for exercise_type in [
+            "identify_verse_line",
+            "page_first_word",
+            "recite_page",
+            "listen_identify_verse",
+            "listen_continue",
+            "identify_tajweed_rule",
+            "find_tajweed_rule",
+            "tafsir_key_lesson",
+            "revelation_occasion",
+            "tafsir_theme_connection",
+        ] {
+            if let Ok(ex) = app
+                .exercise_service
+                .generate_by_type(exercise_type, base_node_id, &base_ukey)
+                .await
+            {
+                push_unique(ex);
+            }
+        }
+        if let Ok(ex) = iqrah_core::exercises::generate_root_family(
             base_node_id,
             &base_ukey,
             app.content_repo.as_ref(),
@@ -850,32 +1270,42 @@ pub async fn get_exercises_for_node(node_id: String) -> Result<Vec<ExerciseDataD
             push_unique(ex);
         }
 
-        if let Ok(ex) = iqrah_core::exercises::generate_missing_word_mcq(
-            base_node_id,
-            &base_ukey,
-            app.content_repo.as_ref(),
-        )
-        .await
+        if let Ok(ex) = app
+            .exercise_service
+            .generate_by_type("cloze_deletion", base_node_id, &base_ukey)
+            .await
         {
             push_unique(ex);
         }
 
-        if let Ok(ex) = iqrah_core::exercises::generate_next_word_mcq(
-            base_node_id,
-            &base_ukey,
-            app.content_repo.as_ref(),
-        )
-        .await
+        if let Ok(ex) = app
+            .exercise_service
+            .generate_by_type("first_letter_hint", base_node_id, &base_ukey)
+            .await
         {
             push_unique(ex);
         }
 
-        if let Ok(ex) = iqrah_core::exercises::generate_reverse_cloze(
-            base_node_id,
-            &base_ukey,
-            app.content_repo.as_ref(),
-        )
-        .await
+        if let Ok(ex) = app
+            .exercise_service
+            .generate_by_type("missing_word_mcq", base_node_id, &base_ukey)
+            .await
+        {
+            push_unique(ex);
+        }
+
+        if let Ok(ex) = app
+            .exercise_service
+            .generate_by_type("next_word_mcq", base_node_id, &base_ukey)
+            .await
+        {
+            push_unique(ex);
+        }
+
+        if let Ok(ex) = app
+            .exercise_service
+            .generate_by_type("reverse_cloze", base_node_id, &base_ukey)
+            .await
         {
             push_unique(ex);
         }
@@ -1043,11 +1473,56 @@ pub async fn process_review(user_id: String, node_id: String, grade: u8) -> Resu
     app.learning_service
         .process_review(&user_id, nid_val, review_grade)
         .await?;
-    app.session_service.increment_stat("reviews_today").await?;
+    count_review_today(app).await;
 
     Ok("Review processed".to_string())
 }
 
+/// Grade a recitation of `verse_keys` (e.g., "1:1") from an ASR transcript
+///
+/// Each expected word is graded and reviewed; words the transcript skipped,
+/// substituted or repeated are reported along with jumps to other verses.
+pub async fn grade_recitation(
+    user_id: String,
+    verse_keys: Vec<String>,
+    transcript: Vec<TranscriptWordDto>,
+) -> Result<RecitationResultDto> {
+    use iqrah_core::exercises::{
+        expected_words, jump_candidate_verses, RecitationGrader, TranscriptWord,
+    };
+
+    let app = app();
+    let repo = app.content_repo.as_ref();
+
+    let expected = expected_words(&verse_keys, repo).await?;
+    if expected.is_empty() {
+        return Err(anyhow::anyhow!("No words found for {:?}", verse_keys));
+    }
+    let candidate_verses = jump_candidate_verses(&verse_keys, repo).await?;
+    let candidates = expected_words(&candidate_verses, repo).await?;
+
+    let transcript: Vec<TranscriptWord> = transcript
+        .into_iter()
+        .map(|w| TranscriptWord {
+            text: w.text,
+            start_ms: w.start_ms,
+            end_ms: w.end_ms,
+        })
+        .collect();
+    let recitation = RecitationGrader::default().grade(&expected, &transcript, &candidates);
+
+    let reviewed = app
+        .learning_service
+        .process_recitation(&user_id, &recitation)
+        .await?;
+    // A recitation counts as one review however many words it covered
+    if !reviewed.is_empty() {
+        count_review_today(app).await;
+    }
+
+    Ok(recitation.into())
+}
+
 /// Get dashboard stats
 pub async fn get_dashboard_stats(user_id: String) -> Result<DashboardStatsDto> {
     let app = app();
@@ -1241,7 +1716,9 @@ pub async fn get_session_preview(
     let items = app
         .session_service
         .get_due_items(&user_id, chrono::Utc::now(), limit, is_high_yield, None)
-        .await?;
+        .await?
+        .into_iter()
+        .filter(|item| app_serves(app, item));
 
     let mut preview = Vec::new();
     for item in items {
@@ -1532,6 +2009,52 @@ pub async fn set_preferred_translator_id(translator_id: i32) -> Result<String> {
     Ok(format!("Preferred translator set to ID: {}", translator_id))
 }
 
+/// Get the script exercises are shown in ("uthmani", "indopak" or "simple")
+pub async fn get_preferred_script() -> Result<String> {
+    Ok(app().exercise_service.script().as_str().to_string())
+}
+
+/// Set the script exercises are shown and checked in. Content missing in that
+/// script is shown in Uthmani.
+pub async fn set_preferred_script(script: String) -> Result<String> {
+    let app = app();
+    let parsed =
+        Script::from_slug(&script).ok_or_else(|| anyhow::anyhow!("Unknown script: {}", script))?;
+    app.user_repo
+        .set_setting(Script::SETTING_KEY, parsed.as_str())
+        .await?;
+    app.exercise_service.set_script(parsed);
+
+    Ok(format!("Preferred script set to: {}", parsed.as_str()))
+}
+
+/// Get the learner's Arabic reading level ("none", "basic_reading", ...), if known
+pub async fn get_arabic_level() -> Result<Option<String>> {
+    Ok(stored_arabic_level().await?.map(|l| l.as_str().to_string()))
+}
+
+/// Set the learner's Arabic reading level. Learners at "none" or
+/// "basic_reading" get transliteration exercises on new words.
+pub async fn set_arabic_level(level: String) -> Result<String> {
+    let parsed = ArabicLevel::from_slug(&level)
+        .ok_or_else(|| anyhow::anyhow!("Unknown Arabic level: {}", level))?;
+    app()
+        .user_repo
+        .set_setting(ArabicLevel::SETTING_KEY, parsed.as_str())
+        .await?;
+
+    Ok(format!("Arabic level set to: {}", parsed.as_str()))
+}
+
+async fn stored_arabic_level() -> Result<Option<ArabicLevel>> {
+    Ok(app()
+        .user_repo
+        .get_setting(ArabicLevel::SETTING_KEY)
+        .await?
+        .as_deref()
+        .and_then(ArabicLevel::from_slug))
+}
+
 /// Get verse translation for a specific translator
 pub async fn get_verse_translation_by_translator(
     verse_key: String,
@@ -1601,24 +2124,229 @@ pub async fn install_translation_pack_from_bytes(
     Ok(format!("Package installed: {}", package_id))
 }
 
-/// Initialize app (for Flutter bridge)
-#[allow(unexpected_cfgs)]
-#[flutter_rust_bridge::frb(init)]
-pub fn init_app() {
-    use once_cell::sync::OnceCell;
-    static LOG_INIT: OnceCell<()> = OnceCell::new();
+/// Install a package from a local SQLite or zip file
+///
+/// Audio files inside zip packages are extracted under `audio_dir` when given,
+/// and stored in content.db otherwise.
+pub async fn install_package_from_file(
+    package_id: String,
+    path: String,
+    audio_dir: Option<String>,
+) -> Result<String> {
+    let app = app();
+    let mut service = PackageService::new(Arc::clone(&app.content_repo));
+    if let Some(dir) = audio_dir {
+        service = service.with_audio_dir(dir);
+    }
+    service
+        .install_package_from_file(&package_id, &path)
+        .await?;
+    Ok(format!("Package installed: {}", package_id))
+}
 
-    LOG_INIT.get_or_init(|| {
-        if tracing_subscriber::fmt::try_init().is_err() {
-            tracing::debug!("tracing subscriber already initialized");
-        }
-    });
+// ========================================================================
+// Recitation Audio API
+// ========================================================================
 
-    flutter_rust_bridge::setup_default_user_utils();
+/// List reciters from installed and enabled audio packages
+pub async fn get_reciters() -> Result<Vec<ReciterDto>> {
+    let reciters = app().content_repo.get_reciters().await?;
+    Ok(reciters.into_iter().map(ReciterDto::from).collect())
 }
 
-// DTOs for API responses
-#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+/// Get a reciter's audio for a verse (e.g., "1:1") with word timings
+pub async fn get_verse_audio(verse_key: String, reciter_id: i32) -> Result<Option<VerseAudioDto>> {
+    let recitation = app()
+        .content_repo
+        .get_verse_recitation(&verse_key, reciter_id)
+        .await?;
+    Ok(recitation.map(VerseAudioDto::from))
+}
+
+/// Get a reciter's audio for the word at `position` (1-indexed) of a verse
+pub async fn get_word_audio(
+    verse_key: String,
+    position: i32,
+    reciter_id: i32,
+) -> Result<Option<WordAudioDto>> {
+    let clip = app()
+        .content_repo
+        .get_word_audio(&verse_key, position, reciter_id)
+        .await?;
+    Ok(clip.map(WordAudioDto::from))
+}
+
+/// Get the tajweed annotations of a verse (e.g., "1:1"), for coloring its
+/// words
+pub async fn get_tajweed_annotations(verse_key: String) -> Result<Vec<TajweedAnnotationDto>> {
+    let annotations = app()
+        .content_repo
+        .get_tajweed_annotations(&verse_key)
+        .await?;
+    Ok(annotations
+        .into_iter()
+        .map(TajweedAnnotationDto::from)
+        .collect())
+}
+
+// ========================================================================
+// Tafsir API
+// ========================================================================
+
+/// Get the tafsirs of installed and enabled tafsir packages
+pub async fn get_tafsirs() -> Result<Vec<TafsirDto>> {
+    let tafsirs = app().content_repo.get_tafsirs().await?;
+    Ok(tafsirs.into_iter().map(TafsirDto::from).collect())
+}
+
+/// Get a tafsir's passage commenting on a verse (e.g., "2:255")
+pub async fn get_tafsir_passage(
+    verse_key: String,
+    tafsir_id: i32,
+) -> Result<Option<TafsirPassageDto>> {
+    let passage = app()
+        .content_repo
+        .get_tafsir_passage(&verse_key, tafsir_id)
+        .await?;
+    Ok(passage.map(TafsirPassageDto::from))
+}
+
+// ========================================================================
+// Node Status API (Suspend / Bury / Reset)
+// ========================================================================
+
+/// Resolve a status scope into node IDs.
+///
+/// Accepted forms:
+/// - a single node ID: "VERSE:1:1", "WORD_INSTANCE:2:255:3", ...
+/// - a verse range: "1:1-7" (see `parse_node_range`)
+/// - a chapter: "CHAPTER:36" (chapter, its verses and word instances)
+/// - a goal: "goal:<goal_id>"
+async fn resolve_status_scope(app: &AppState, scope: &str) -> Result<Vec<i64>> {
+    let scope = scope.trim();
+
+    if let Some(goal_id) = scope.strip_prefix("goal:") {
+        let ids = app.session_service.get_goal_node_ids(goal_id).await?;
+        if ids.is_empty() {
+            return Err(anyhow::anyhow!("Goal has no nodes: {}", goal_id));
+        }
+        return Ok(ids);
+    }
+
+    if scope.starts_with(nid::PREFIX_CHAPTER) {
+        let chapter = nid::parse_chapter(scope)?;
+        return app.session_service.get_chapter_node_ids(chapter).await;
+    }
+
+    if let Some(node_id) = nid::from_ukey(scope) {
+        return Ok(vec![node_id]);
+    }
+
+    parse_node_range(scope.to_string())?
+        .iter()
+        .map(|ukey| {
+            nid::from_ukey(ukey).ok_or_else(|| anyhow::anyhow!("Invalid node ID: {}", ukey))
+        })
+        .collect()
+}
+
+/// Suspend nodes until explicitly unsuspended. Returns the number of nodes affected.
+pub async fn suspend_nodes(user_id: String, scope: String) -> Result<u32> {
+    let app = app();
+    let node_ids = resolve_status_scope(app, &scope).await?;
+    app.session_service
+        .set_node_status(&user_id, &node_ids, NodeStatus::Suspended)
+        .await?;
+    Ok(node_ids.len() as u32)
+}
+
+/// Bury nodes until the given instant (epoch milliseconds). Returns the number of nodes affected.
+pub async fn bury_nodes(user_id: String, scope: String, until_millis: i64) -> Result<u32> {
+    let app = app();
+    let until = chrono::DateTime::from_timestamp_millis(until_millis)
+        .ok_or_else(|| anyhow::anyhow!("Invalid timestamp: {}", until_millis))?;
+    let node_ids = resolve_status_scope(app, &scope).await?;
+    app.session_service
+        .set_node_status(&user_id, &node_ids, NodeStatus::BuriedUntil(until))
+        .await?;
+    Ok(node_ids.len() as u32)
+}
+
+/// Return suspended or buried nodes to normal rotation. Returns the number of nodes affected.
+pub async fn unsuspend_nodes(user_id: String, scope: String) -> Result<u32> {
+    let app = app();
+    let node_ids = resolve_status_scope(app, &scope).await?;
+    app.session_service
+        .set_node_status(&user_id, &node_ids, NodeStatus::Active)
+        .await?;
+    Ok(node_ids.len() as u32)
+}
+
+/// Clear FSRS/energy state for nodes while keeping their review history.
+/// Returns the number of memory states removed.
+pub async fn reset_nodes(user_id: String, scope: String) -> Result<u32> {
+    let app = app();
+    let node_ids = resolve_status_scope(app, &scope).await?;
+    let removed = app.session_service.reset_nodes(&user_id, &node_ids).await?;
+    Ok(removed as u32)
+}
+
+/// Get the scheduling status of a node
+pub async fn get_node_status(user_id: String, node_id: String) -> Result<NodeStatusDto> {
+    let app = app();
+    let nid_val = nid::from_ukey(&node_id).ok_or_else(|| anyhow::anyhow!("Invalid node ID"))?;
+    let status = app
+        .session_service
+        .get_node_status(&user_id, nid_val)
+        .await?;
+    Ok(NodeStatusDto::from_status(node_id, status))
+}
+
+/// Initialize app (for Flutter bridge)
+#[allow(unexpected_cfgs)]
+#[flutter_rust_bridge::frb(init)]
+pub fn init_app() {
+    use once_cell::sync::OnceCell;
+    static LOG_INIT: OnceCell<()> = OnceCell::new();
+
+    LOG_INIT.get_or_init(|| {
+        if tracing_subscriber::fmt::try_init().is_err() {
+            tracing::debug!("tracing subscriber already initialized");
+        }
+    });
+
+    flutter_rust_bridge::setup_default_user_utils();
+}
+
+/// Load the semantic grading model from a local model2vec folder
+/// (tokenizer.json, model.safetensors, config.json), e.g. one downloaded by
+/// the app. Until a model is loaded, typed answers are graded lexically.
+pub fn init_semantic_model_from_dir(model_dir: String) -> Result<()> {
+    ExerciseService::init_semantic_model_from_dir(&model_dir)
+}
+
+/// Load the semantic grading model from bundled tokenizer.json and
+/// model.safetensors asset bytes. They are staged as files in `staging_dir`
+/// (e.g. the app's cache directory) while the model loads.
+pub fn init_semantic_model_from_bytes(
+    tokenizer_json: Vec<u8>,
+    model_safetensors: Vec<u8>,
+    staging_dir: String,
+) -> Result<()> {
+    ExerciseService::init_semantic_model_from_bytes(
+        &tokenizer_json,
+        &model_safetensors,
+        &staging_dir,
+    )
+}
+
+/// Whether semantic grading is available (false: lexical grading)
+pub fn is_semantic_model_loaded() -> bool {
+    ExerciseService::is_semantic_model_loaded()
+}
+
+// DTOs for API responses
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct ExerciseDto {
     pub node_id: String,
     pub question: String,
@@ -1731,6 +2459,57 @@ pub struct SessionItemDto {
     pub exercise: ExerciseDataDto,
 }
 
+/// Result of checking an answer
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ValidationResultDto {
+    pub is_correct: bool,
+    pub feedback: Option<String>,
+    /// Partial credit (0.0 to 1.0)
+    pub similarity_score: Option<f32>,
+    /// Semantic grading label (Excellent/Partial/Incorrect)
+    pub semantic_grade: Option<String>,
+    /// Revealed when the answer was wrong
+    pub correct_answer: Option<String>,
+    /// Suggested review grade (1=Again, 2=Hard, 3=Good, 4=Easy)
+    pub suggested_grade: u8,
+}
+
+impl From<iqrah_core::exercises::ValidationResult> for ValidationResultDto {
+    fn from(result: iqrah_core::exercises::ValidationResult) -> Self {
+        Self {
+            is_correct: result.is_correct,
+            feedback: result.feedback,
+            similarity_score: result.similarity_score,
+            semantic_grade: result.semantic_grade,
+            correct_answer: result.correct_answer,
+            suggested_grade: result.suggested_grade as u8,
+        }
+    }
+}
+
+/// One rung of an exercise's progressive hint ladder
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ExerciseHintDto {
+    /// 1=first letter, 2=first word, 3=word count, 4=translation, 5=partial reveal
+    pub level: u8,
+    /// Level name ("first_letter", ...)
+    pub level_name: String,
+    pub text: String,
+    /// Highest grade still achievable once shown (1=Again ... 4=Easy)
+    pub max_grade: u8,
+}
+
+impl From<iqrah_core::exercises::ExerciseHint> for ExerciseHintDto {
+    fn from(hint: iqrah_core::exercises::ExerciseHint) -> Self {
+        Self {
+            level: hint.level as u8,
+            level_name: hint.level.as_str().to_string(),
+            text: hint.text,
+            max_grade: hint.max_grade as u8,
+        }
+    }
+}
+
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct SessionSummaryDto {
     pub session_id: String,
@@ -1743,6 +2522,30 @@ pub struct SessionSummaryDto {
     pub easy_count: i32,
 }
 
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct NodeStatusDto {
+    pub node_id: String,
+    /// "active", "suspended" or "buried"
+    pub status: String,
+    /// Epoch milliseconds, only set for buried nodes
+    pub buried_until: Option<i64>,
+}
+
+impl NodeStatusDto {
+    fn from_status(node_id: String, status: NodeStatus) -> Self {
+        let (status, buried_until) = match status {
+            NodeStatus::Active => ("active", None),
+            NodeStatus::Suspended => ("suspended", None),
+            NodeStatus::BuriedUntil(until) => ("buried", Some(until.timestamp_millis())),
+        };
+        Self {
+            node_id,
+            status: status.to_string(),
+            buried_until,
+        }
+    }
+}
+
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct SyncMemoryStateDto {
     pub node_id: i64,
@@ -1863,6 +2666,184 @@ impl From<InstalledPackage> for InstalledPackageDto {
     }
 }
 
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ReciterDto {
+    pub reciter_id: i32,
+    pub slug: String,
+    pub name: String,
+    pub style: Option<String>,
+    pub package_id: Option<String>,
+}
+
+/// Timing of one word within a verse recitation
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct WordSegmentDto {
+    pub position: i32,
+    pub start_ms: i64,
+    pub end_ms: i64,
+}
+
+/// Verse audio: a file path or inline bytes, plus word timings
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct VerseAudioDto {
+    pub reciter_id: i32,
+    pub verse_key: String,
+    pub audio_path: Option<String>,
+    pub audio_bytes: Option<Vec<u8>>,
+    pub mime_type: String,
+    pub duration_ms: Option<i64>,
+    pub segments: Vec<WordSegmentDto>,
+}
+
+/// Word audio: play the whole clip, or from `start_ms` to `end_ms` when the
+/// audio is the verse recitation
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct WordAudioDto {
+    pub reciter_id: i32,
+    pub verse_key: String,
+    pub position: i32,
+    pub audio_path: Option<String>,
+    pub audio_bytes: Option<Vec<u8>>,
+    pub mime_type: String,
+    pub duration_ms: Option<i64>,
+    pub start_ms: Option<i64>,
+    pub end_ms: Option<i64>,
+}
+
+impl From<Reciter> for ReciterDto {
+    fn from(value: Reciter) -> Self {
+        Self {
+            reciter_id: value.reciter_id,
+            slug: value.slug,
+            name: value.name,
+            style: value.style,
+            package_id: value.package_id,
+        }
+    }
+}
+
+fn audio_source_parts(source: AudioSource) -> (Option<String>, Option<Vec<u8>>) {
+    match source {
+        AudioSource::File(path) => (Some(path), None),
+        AudioSource::Blob(bytes) => (None, Some(bytes)),
+    }
+}
+
+impl From<VerseRecitation> for VerseAudioDto {
+    fn from(value: VerseRecitation) -> Self {
+        let (audio_path, audio_bytes) = audio_source_parts(value.source);
+        Self {
+            reciter_id: value.reciter_id,
+            verse_key: value.verse_key,
+            audio_path,
+            audio_bytes,
+            mime_type: value.mime_type,
+            duration_ms: value.duration_ms,
+            segments: value
+                .segments
+                .into_iter()
+                .map(|s| WordSegmentDto {
+                    position: s.position,
+                    start_ms: s.start_ms,
+                    end_ms: s.end_ms,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<WordAudioClip> for WordAudioDto {
+    fn from(value: WordAudioClip) -> Self {
+        let (audio_path, audio_bytes) = audio_source_parts(value.source);
+        Self {
+            reciter_id: value.reciter_id,
+            verse_key: value.verse_key,
+            position: value.position,
+            audio_path,
+            audio_bytes,
+            mime_type: value.mime_type,
+            duration_ms: value.duration_ms,
+            start_ms: value.start_ms,
+            end_ms: value.end_ms,
+        }
+    }
+}
+
+/// A tajweed rule on characters `start_char..end_char` of a word's Uthmani
+/// text
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct TajweedAnnotationDto {
+    pub word_node_id: String,
+    pub position: i32,
+    /// Rule slug ("ikhfa", "madd_lazim", ...)
+    pub rule: String,
+    pub rule_name: String,
+    pub start_char: i32,
+    pub end_char: i32,
+}
+
+impl From<TajweedAnnotation> for TajweedAnnotationDto {
+    fn from(value: TajweedAnnotation) -> Self {
+        Self {
+            word_node_id: nid::to_ukey(value.word_id).unwrap_or_default(),
+            position: value.position,
+            rule: value.rule.as_str().to_string(),
+            rule_name: value.rule.name().to_string(),
+            start_char: value.start_char,
+            end_char: value.end_char,
+        }
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct TafsirDto {
+    pub tafsir_id: i32,
+    pub slug: String,
+    pub name: String,
+    pub author: Option<String>,
+    pub language_code: Option<String>,
+}
+
+impl From<Tafsir> for TafsirDto {
+    fn from(value: Tafsir) -> Self {
+        Self {
+            tafsir_id: value.tafsir_id,
+            slug: value.slug,
+            name: value.name,
+            author: value.author,
+            language_code: value.language_code,
+        }
+    }
+}
+
+/// Commentary on verses `start_verse..=end_verse` of a chapter
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct TafsirPassageDto {
+    pub tafsir_id: i32,
+    pub chapter_number: i32,
+    pub start_verse: i32,
+    pub end_verse: i32,
+    pub text: String,
+    pub key_lesson: Option<String>,
+    pub revelation_occasion: Option<String>,
+    pub themes: Vec<String>,
+}
+
+impl From<TafsirPassage> for TafsirPassageDto {
+    fn from(value: TafsirPassage) -> Self {
+        Self {
+            tafsir_id: value.tafsir_id,
+            chapter_number: value.chapter_number,
+            start_verse: value.start_verse,
+            end_verse: value.end_verse,
+            text: value.text,
+            key_lesson: value.key_lesson,
+            revelation_occasion: value.revelation_occasion,
+            themes: value.themes,
+        }
+    }
+}
+
 // Lightweight node + metadata surface for sandbox / previews
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct NodeData {
@@ -1870,93 +2851,174 @@ pub struct NodeData {
     pub node_type: String,
     pub metadata: HashMap<String, String>,
 }
+/// How an MCQ's distractors were picked
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct DistractorStrategyDto {
+    /// "easy", "medium" or "hard"
+    pub difficulty: String,
+    /// Scoring signals ("part_of_speech", "word_family", "length", "semantic", "frequency")
+    pub signals: Vec<String>,
+}
+
+impl From<DistractorStrategy> for DistractorStrategyDto {
+    fn from(strategy: DistractorStrategy) -> Self {
+        Self {
+            difficulty: strategy.difficulty.as_str().to_string(),
+            signals: strategy
+                .signals
+                .iter()
+                .map(|signal| signal.as_str().to_string())
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<DistractorStrategyDto> for DistractorStrategy {
+    type Error = anyhow::Error;
+
+    fn try_from(dto: DistractorStrategyDto) -> Result<Self> {
+        Ok(Self {
+            difficulty: DistractorDifficulty::from_slug(&dto.difficulty).ok_or_else(|| {
+                anyhow::anyhow!("Unknown distractor difficulty: {}", dto.difficulty)
+            })?,
+            signals: dto
+                .signals
+                .iter()
+                .map(|slug| {
+                    DistractorSignal::from_slug(slug)
+                        .ok_or_else(|| anyhow::anyhow!("Unknown distractor signal: {}", slug))
+                })
+                .collect::<Result<_>>()?,
+        })
+    }
+}
+
+/// Exercise sent to the app
+///
+/// Every variant carries the generator `seed` (0 for exercises that are not
+/// randomized), so the exercise can be rebuilt with `regenerate_exercise`.
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub enum ExerciseDataDto {
     Memorization {
         node_id: String,
+        seed: u64,
     },
     McqArToEn {
         node_id: String,
         distractor_node_ids: Vec<String>,
+        distractor_strategy: DistractorStrategyDto,
+        seed: u64,
     },
     McqEnToAr {
         node_id: String,
         distractor_node_ids: Vec<String>,
+        distractor_strategy: DistractorStrategyDto,
+        seed: u64,
     },
     Translation {
         node_id: String,
+        seed: u64,
     },
     ContextualTranslation {
         node_id: String,
         verse_key: String,
+        seed: u64,
     },
     ClozeDeletion {
         node_id: String,
         blank_position: i32,
+        seed: u64,
     },
     FirstLetterHint {
         node_id: String,
         word_position: i32,
+        seed: u64,
     },
     MissingWordMcq {
         node_id: String,
         blank_position: i32,
         distractor_node_ids: Vec<String>,
+        distractor_strategy: DistractorStrategyDto,
+        seed: u64,
     },
     NextWordMcq {
         node_id: String,
         context_position: i32,
         distractor_node_ids: Vec<String>,
+        distractor_strategy: DistractorStrategyDto,
+        seed: u64,
     },
     FullVerseInput {
         node_id: String,
+        seed: u64,
     },
     AyahChain {
         node_id: String,
         verse_keys: Vec<String>,
         current_index: usize,
         completed_count: usize,
+        seed: u64,
     },
     FindMistake {
         node_id: String,
         mistake_position: i32,
         correct_word_node_id: String,
         incorrect_word_node_id: String,
+        seed: u64,
     },
     AyahSequence {
         node_id: String,
         correct_sequence: Vec<String>,
+        seed: u64,
     },
     SequenceRecall {
         node_id: String,
         correct_sequence: Vec<String>,
         options: Vec<Vec<String>>,
+        seed: u64,
     },
     FirstWordRecall {
         node_id: String,
         verse_key: String,
+        seed: u64,
     },
     IdentifyRoot {
         node_id: String,
         root: String,
+        seed: u64,
     },
     ReverseCloze {
         node_id: String,
         blank_position: i32,
+        seed: u64,
     },
     TranslatePhrase {
         node_id: String,
         translator_id: i32,
+        seed: u64,
     },
     PosTagging {
         node_id: String,
         correct_pos: String,
         options: Vec<String>,
+        seed: u64,
+    },
+    GrammarFeature {
+        node_id: String,
+        /// Feature slug ("verb_form", "tense", "mood", "person", "number",
+        /// "gender", "case" or "state")
+        feature: String,
+        /// Question to show, e.g. "Is this word singular, dual or plural?"
+        prompt: String,
+        correct_value: String,
+        options: Vec<String>,
+        seed: u64,
     },
     CrossVerseConnection {
         node_id: String,
         related_verse_ids: Vec<String>,
         connection_theme: String,
+        seed: u64,
     },
     /// Echo Recall exercise - progressive blur memorization
     EchoRecall {
@@ -1964,190 +3026,1209 @@ pub enum ExerciseDataDto {
         user_id: String,
         /// List of ayah node IDs to practice (e.g., ["VERSE:1:1", "VERSE:1:2"])
         ayah_node_ids: Vec<String>,
+        seed: u64,
     },
-}
-
-impl From<iqrah_core::exercises::ExerciseData> for ExerciseDataDto {
-    fn from(data: iqrah_core::exercises::ExerciseData) -> Self {
-        use iqrah_core::exercises::ExerciseData::*;
-        match data {
-            Memorization { node_id } => ExerciseDataDto::Memorization {
-                node_id: nid::to_ukey(node_id).unwrap_or_default(),
-            },
-            McqArToEn {
-                node_id,
-                distractor_node_ids,
-            } => ExerciseDataDto::McqArToEn {
-                node_id: nid::to_ukey(node_id).unwrap_or_default(),
-                distractor_node_ids: distractor_node_ids
-                    .into_iter()
-                    .map(|id| nid::to_ukey(id).unwrap_or_default())
-                    .collect(),
-            },
-            McqEnToAr {
-                node_id,
-                distractor_node_ids,
-            } => ExerciseDataDto::McqEnToAr {
-                node_id: nid::to_ukey(node_id).unwrap_or_default(),
-                distractor_node_ids: distractor_node_ids
-                    .into_iter()
-                    .map(|id| nid::to_ukey(id).unwrap_or_default())
-                    .collect(),
-            },
-            Translation { node_id } => ExerciseDataDto::Translation {
-                node_id: nid::to_ukey(node_id).unwrap_or_default(),
-            },
-            ContextualTranslation { node_id, verse_key } => {
-                ExerciseDataDto::ContextualTranslation {
-                    node_id: nid::to_ukey(node_id).unwrap_or_default(),
-                    verse_key,
-                }
-            }
-            ClozeDeletion {
-                node_id,
-                blank_position,
-            } => ExerciseDataDto::ClozeDeletion {
-                node_id: nid::to_ukey(node_id).unwrap_or_default(),
-                blank_position,
-            },
+    /// Mutashabihat exercise - pick the correct continuation among similar verses
+    Mutashabihat {
+        node_id: String,
+        verse_key: String,
+        /// Number of leading words shown as the prompt
+        prompt_word_count: i32,
+        option_verse_keys: Vec<String>,
+        /// Word position where each option diverges (0 for the correct option)
+        differing_positions: Vec<i32>,
+        seed: u64,
+    },
+    /// Word order exercise - rebuild a verse from shuffled tiles
+    WordOrder {
+        node_id: String,
+        correct_sequence: Vec<String>,
+        shuffled_word_node_ids: Vec<String>,
+        /// Tile text aligned with `shuffled_word_node_ids`
+        tile_texts: Vec<String>,
+        seed: u64,
+    },
+    IdentifySurah {
+        node_id: String,
+        verse_key: String,
+        correct_chapter: i32,
+        chapter_options: Vec<i32>,
+        seed: u64,
+    },
+    IdentifyVerseNumber {
+        node_id: String,
+        verse_key: String,
+        correct_verse_number: i32,
+        verse_number_options: Vec<i32>,
+        seed: u64,
+    },
+    /// Page half identification - `half` is 1 (first) or 2 (second)
+    IdentifyPageHalf {
+        node_id: String,
+        verse_key: String,
+        page: i32,
+        half: i32,
+        seed: u64,
+    },
+    /// Transliteration to Arabic - pick the Arabic word for the node's transliteration
+    TransliterationToArabic {
+        node_id: String,
+        distractor_node_ids: Vec<String>,
+        distractor_strategy: DistractorStrategyDto,
+        seed: u64,
+    },
+    /// Arabic to transliteration - type the transliteration of the highlighted word
+    ArabicToTransliteration {
+        node_id: String,
+        verse_key: String,
+        seed: u64,
+    },
+    /// Root family - pick every word in `verse_keys` derived from the root
+    RootFamily {
+        node_id: String,
+        root_id: String,
+        verse_keys: Vec<String>,
+        family_word_node_ids: Vec<String>,
+        seed: u64,
+    },
+    /// Lemma meaning - match the root's lemmas to the shuffled meanings
+    LemmaMeaning {
+        node_id: String,
+        root_id: String,
+        lemma_ids: Vec<String>,
+        meaning_lemma_ids: Vec<String>,
+        seed: u64,
+    },
+    /// Recite page - type the page's words, from `first_word_node_id` in the
+    /// first of `verse_keys` to `last_word_node_id` in the last
+    RecitePage {
+        node_id: String,
+        page: i32,
+        verse_keys: Vec<String>,
+        first_word_node_id: String,
+        last_word_node_id: String,
+        seed: u64,
+    },
+    /// Page first word - pick the first word of the page
+    PageFirstWord {
+        node_id: String,
+        page: i32,
+        first_word_node_id: String,
+        distractor_node_ids: Vec<String>,
+        seed: u64,
+    },
+    /// Verse line identification - which line of `page` the verse starts on
+    IdentifyVerseLine {
+        node_id: String,
+        verse_key: String,
+        page: i32,
+        correct_line: i32,
+        line_options: Vec<i32>,
+        seed: u64,
+    },
+    /// Listen and identify verse - which verse the clip (words
+    /// `first_position..=last_position` of the reciter's verse audio) is from
+    ListenIdentifyVerse {
+        node_id: String,
+        reciter_id: i32,
+        verse_key: String,
+        first_position: i32,
+        last_position: i32,
+        start_ms: i64,
+        end_ms: i64,
+        option_verse_keys: Vec<String>,
+        seed: u64,
+    },
+    /// Listen and continue - pick the word that follows the clip
+    ListenContinue {
+        node_id: String,
+        reciter_id: i32,
+        verse_key: String,
+        first_position: i32,
+        last_position: i32,
+        start_ms: i64,
+        end_ms: i64,
+        distractor_node_ids: Vec<String>,
+        distractor_strategy: DistractorStrategyDto,
+        seed: u64,
+    },
+    /// Listen and identify word - pick the word just heard. Without
+    /// `start_ms`/`end_ms` the word has its own clip (see `get_word_audio`)
+    ListenIdentifyWord {
+        node_id: String,
+        reciter_id: i32,
+        verse_key: String,
+        position: i32,
+        start_ms: Option<i64>,
+        end_ms: Option<i64>,
+        distractor_node_ids: Vec<String>,
+        distractor_strategy: DistractorStrategyDto,
+        seed: u64,
+    },
+    /// Identify tajweed rule - name the rule on characters
+    /// `start_char..end_char` of the word's Uthmani text. Rules are slugs
+    /// ("ikhfa", "madd_lazim", ...) with display names in `option_names`.
+    IdentifyTajweedRule {
+        node_id: String,
+        word_node_id: String,
+        start_char: i32,
+        end_char: i32,
+        correct_rule: String,
+        rule_options: Vec<String>,
+        option_names: Vec<String>,
+        seed: u64,
+    },
+    /// Find tajweed rule - pick every word of the verse where `rule` applies
+    FindTajweedRule {
+        node_id: String,
+        rule: String,
+        rule_name: String,
+        rule_word_node_ids: Vec<String>,
+        seed: u64,
+    },
+    /// Tafsir key lesson - pick the key lesson of the passage beginning at
+    /// `passage_key`; options are passages (fetch lessons with
+    /// `get_tafsir_passage`)
+    TafsirKeyLesson {
+        node_id: String,
+        tafsir_id: i32,
+        passage_key: String,
+        option_passage_keys: Vec<String>,
+        seed: u64,
+    },
+    /// Revelation occasion - pick the verse the occasion reported for the
+    /// passage beginning at `passage_key` is about
+    RevelationOccasion {
+        node_id: String,
+        tafsir_id: i32,
+        passage_key: String,
+        option_verse_keys: Vec<String>,
+        seed: u64,
+    },
+}
+
+impl From<iqrah_core::exercises::ExerciseData> for ExerciseDataDto {
+    fn from(data: iqrah_core::exercises::ExerciseData) -> Self {
+        use iqrah_core::exercises::ExerciseData::*;
+        let seed = data.seed().unwrap_or(0);
+        match data {
+            Memorization { node_id } => ExerciseDataDto::Memorization {
+                node_id: nid::to_ukey(node_id).unwrap_or_default(),
+                seed,
+            },
+            McqArToEn {
+                node_id,
+                distractor_node_ids,
+                distractor_strategy,
+                ..
+            } => ExerciseDataDto::McqArToEn {
+                node_id: nid::to_ukey(node_id).unwrap_or_default(),
+                distractor_node_ids: distractor_node_ids
+                    .into_iter()
+                    .map(|id| nid::to_ukey(id).unwrap_or_default())
+                    .collect(),
+                distractor_strategy: distractor_strategy.into(),
+                seed,
+            },
+            McqEnToAr {
+                node_id,
+                distractor_node_ids,
+                distractor_strategy,
+                ..
+            } => ExerciseDataDto::McqEnToAr {
+                node_id: nid::to_ukey(node_id).unwrap_or_default(),
+                distractor_node_ids: distractor_node_ids
+                    .into_iter()
+                    .map(|id| nid::to_ukey(id).unwrap_or_default())
+                    .collect(),
+                distractor_strategy: distractor_strategy.into(),
+                seed,
+            },
+            Translation { node_id } => ExerciseDataDto::Translation {
+                node_id: nid::to_ukey(node_id).unwrap_or_default(),
+                seed,
+            },
+            ContextualTranslation { node_id, verse_key } => {
+                ExerciseDataDto::ContextualTranslation {
+                    node_id: nid::to_ukey(node_id).unwrap_or_default(),
+                    verse_key,
+                    seed,
+                }
+            }
+            ClozeDeletion {
+                node_id,
+                blank_position,
+                ..
+            } => ExerciseDataDto::ClozeDeletion {
+                node_id: nid::to_ukey(node_id).unwrap_or_default(),
+                blank_position,
+                seed,
+            },
             FirstLetterHint {
                 node_id,
                 word_position,
+                ..
             } => ExerciseDataDto::FirstLetterHint {
                 node_id: nid::to_ukey(node_id).unwrap_or_default(),
                 word_position,
+                seed,
+            },
+            MissingWordMcq {
+                node_id,
+                blank_position,
+                distractor_node_ids,
+                distractor_strategy,
+                ..
+            } => ExerciseDataDto::MissingWordMcq {
+                node_id: nid::to_ukey(node_id).unwrap_or_default(),
+                blank_position,
+                distractor_node_ids: distractor_node_ids
+                    .into_iter()
+                    .map(|id| nid::to_ukey(id).unwrap_or_default())
+                    .collect(),
+                distractor_strategy: distractor_strategy.into(),
+                seed,
+            },
+            NextWordMcq {
+                node_id,
+                context_position,
+                distractor_node_ids,
+                distractor_strategy,
+                ..
+            } => ExerciseDataDto::NextWordMcq {
+                node_id: nid::to_ukey(node_id).unwrap_or_default(),
+                context_position,
+                distractor_node_ids: distractor_node_ids
+                    .into_iter()
+                    .map(|id| nid::to_ukey(id).unwrap_or_default())
+                    .collect(),
+                distractor_strategy: distractor_strategy.into(),
+                seed,
+            },
+            FullVerseInput { node_id } => ExerciseDataDto::FullVerseInput {
+                node_id: nid::to_ukey(node_id).unwrap_or_default(),
+                seed,
+            },
+            AyahChain {
+                node_id,
+                verse_keys,
+                current_index,
+                completed_count,
+            } => ExerciseDataDto::AyahChain {
+                node_id: nid::to_ukey(node_id).unwrap_or_default(),
+                verse_keys,
+                current_index,
+                completed_count,
+                seed,
+            },
+            FindMistake {
+                node_id,
+                mistake_position,
+                correct_word_node_id,
+                incorrect_word_node_id,
+                ..
+            } => ExerciseDataDto::FindMistake {
+                node_id: nid::to_ukey(node_id).unwrap_or_default(),
+                mistake_position,
+                correct_word_node_id: nid::to_ukey(correct_word_node_id).unwrap_or_default(),
+                incorrect_word_node_id: nid::to_ukey(incorrect_word_node_id).unwrap_or_default(),
+                seed,
+            },
+            AyahSequence {
+                node_id,
+                correct_sequence,
+            } => ExerciseDataDto::AyahSequence {
+                node_id: nid::to_ukey(node_id).unwrap_or_default(),
+                correct_sequence: correct_sequence
+                    .into_iter()
+                    .map(|id| nid::to_ukey(id).unwrap_or_default())
+                    .collect(),
+                seed,
+            },
+            SequenceRecall {
+                node_id,
+                correct_sequence,
+                options,
+            } => ExerciseDataDto::SequenceRecall {
+                node_id: nid::to_ukey(node_id).unwrap_or_default(),
+                correct_sequence: correct_sequence
+                    .into_iter()
+                    .map(|id| nid::to_ukey(id).unwrap_or_default())
+                    .collect(),
+                options: options
+                    .into_iter()
+                    .map(|sequence| {
+                        sequence
+                            .into_iter()
+                            .map(|id| nid::to_ukey(id).unwrap_or_default())
+                            .collect()
+                    })
+                    .collect(),
+                seed,
+            },
+            FirstWordRecall { node_id, verse_key } => ExerciseDataDto::FirstWordRecall {
+                node_id: nid::to_ukey(node_id).unwrap_or_default(),
+                verse_key,
+                seed,
+            },
+            IdentifyRoot { node_id, root } => ExerciseDataDto::IdentifyRoot {
+                node_id: nid::to_ukey(node_id).unwrap_or_default(),
+                root,
+                seed,
+            },
+            ReverseCloze {
+                node_id,
+                blank_position,
+                ..
+            } => ExerciseDataDto::ReverseCloze {
+                node_id: nid::to_ukey(node_id).unwrap_or_default(),
+                blank_position,
+                seed,
+            },
+            TranslatePhrase {
+                node_id,
+                translator_id,
+            } => ExerciseDataDto::TranslatePhrase {
+                node_id: nid::to_ukey(node_id).unwrap_or_default(),
+                translator_id,
+                seed,
+            },
+            PosTagging {
+                node_id,
+                correct_pos,
+                options,
+                ..
+            } => ExerciseDataDto::PosTagging {
+                node_id: nid::to_ukey(node_id).unwrap_or_default(),
+                correct_pos,
+                options,
+                seed,
+            },
+            GrammarFeature {
+                node_id,
+                feature,
+                correct_value,
+                options,
+                ..
+            } => ExerciseDataDto::GrammarFeature {
+                node_id: nid::to_ukey(node_id).unwrap_or_default(),
+                feature: feature.as_str().to_string(),
+                prompt: feature.prompt().to_string(),
+                correct_value,
+                options,
+                seed,
+            },
+            CrossVerseConnection {
+                node_id,
+                related_verse_ids,
+                connection_theme,
+            } => ExerciseDataDto::CrossVerseConnection {
+                node_id: nid::to_ukey(node_id).unwrap_or_default(),
+                related_verse_ids: related_verse_ids
+                    .into_iter()
+                    .map(|id| nid::to_ukey(id).unwrap_or_default())
+                    .collect(),
+                connection_theme,
+                seed,
+            },
+            EchoRecall { ayah_node_ids } => ExerciseDataDto::EchoRecall {
+                // user_id is not stored in core ExerciseData, injected at session level
+                user_id: String::new(),
+                ayah_node_ids: ayah_node_ids
+                    .into_iter()
+                    .map(|id| nid::to_ukey(id).unwrap_or_default())
+                    .collect(),
+                seed,
+            },
+            Mutashabihat {
+                node_id,
+                verse_key,
+                prompt_word_count,
+                option_verse_keys,
+                differing_positions,
+                ..
+            } => ExerciseDataDto::Mutashabihat {
+                node_id: nid::to_ukey(node_id).unwrap_or_default(),
+                verse_key,
+                prompt_word_count,
+                option_verse_keys,
+                differing_positions,
+                seed,
+            },
+            WordOrder {
+                node_id,
+                correct_sequence,
+                shuffled_word_node_ids,
+                tile_texts,
+                ..
+            } => ExerciseDataDto::WordOrder {
+                node_id: nid::to_ukey(node_id).unwrap_or_default(),
+                correct_sequence: correct_sequence
+                    .into_iter()
+                    .map(|id| nid::to_ukey(id).unwrap_or_default())
+                    .collect(),
+                shuffled_word_node_ids: shuffled_word_node_ids
+                    .into_iter()
+                    .map(|id| nid::to_ukey(id).unwrap_or_default())
+                    .collect(),
+                tile_texts,
+                seed,
+            },
+            IdentifySurah {
+                node_id,
+                verse_key,
+                correct_chapter,
+                chapter_options,
+                ..
+            } => ExerciseDataDto::IdentifySurah {
+                node_id: nid::to_ukey(node_id).unwrap_or_default(),
+                verse_key,
+                correct_chapter,
+                chapter_options,
+                seed,
+            },
+            IdentifyVerseNumber {
+                node_id,
+                verse_key,
+                correct_verse_number,
+                verse_number_options,
+                ..
+            } => ExerciseDataDto::IdentifyVerseNumber {
+                node_id: nid::to_ukey(node_id).unwrap_or_default(),
+                verse_key,
+                correct_verse_number,
+                verse_number_options,
+                seed,
+            },
+            IdentifyPageHalf {
+                node_id,
+                verse_key,
+                page,
+                half,
+            } => ExerciseDataDto::IdentifyPageHalf {
+                node_id: nid::to_ukey(node_id).unwrap_or_default(),
+                verse_key,
+                page,
+                half,
+                seed,
+            },
+            TransliterationToArabic {
+                node_id,
+                distractor_node_ids,
+                distractor_strategy,
+                ..
+            } => ExerciseDataDto::TransliterationToArabic {
+                node_id: nid::to_ukey(node_id).unwrap_or_default(),
+                distractor_node_ids: distractor_node_ids
+                    .into_iter()
+                    .map(|id| nid::to_ukey(id).unwrap_or_default())
+                    .collect(),
+                distractor_strategy: distractor_strategy.into(),
+                seed,
+            },
+            ArabicToTransliteration { node_id, verse_key } => {
+                ExerciseDataDto::ArabicToTransliteration {
+                    node_id: nid::to_ukey(node_id).unwrap_or_default(),
+                    verse_key,
+                    seed,
+                }
+            }
+            RootFamily {
+                node_id,
+                root_id,
+                verse_keys,
+                family_word_node_ids,
+            } => ExerciseDataDto::RootFamily {
+                node_id: nid::to_ukey(node_id).unwrap_or_default(),
+                root_id,
+                verse_keys,
+                family_word_node_ids: family_word_node_ids
+                    .into_iter()
+                    .map(|id| nid::to_ukey(id).unwrap_or_default())
+                    .collect(),
+                seed,
+            },
+            LemmaMeaning {
+                node_id,
+                root_id,
+                lemma_ids,
+                meaning_lemma_ids,
+                ..
+            } => ExerciseDataDto::LemmaMeaning {
+                node_id: nid::to_ukey(node_id).unwrap_or_default(),
+                root_id,
+                lemma_ids,
+                meaning_lemma_ids,
+                seed,
+            },
+            RecitePage {
+                node_id,
+                page,
+                verse_keys,
+                first_word_node_id,
+                last_word_node_id,
+            } => ExerciseDataDto::RecitePage {
+                node_id: nid::to_ukey(node_id).unwrap_or_default(),
+                page,
+                verse_keys,
+                first_word_node_id: nid::to_ukey(first_word_node_id).unwrap_or_default(),
+                last_word_node_id: nid::to_ukey(last_word_node_id).unwrap_or_default(),
+                seed,
+            },
+            PageFirstWord {
+                node_id,
+                page,
+                first_word_node_id,
+                distractor_node_ids,
+                ..
+            } => ExerciseDataDto::PageFirstWord {
+                node_id: nid::to_ukey(node_id).unwrap_or_default(),
+                page,
+                first_word_node_id: nid::to_ukey(first_word_node_id).unwrap_or_default(),
+                distractor_node_ids: distractor_node_ids
+                    .into_iter()
+                    .map(|id| nid::to_ukey(id).unwrap_or_default())
+                    .collect(),
+                seed,
+            },
+            IdentifyVerseLine {
+                node_id,
+                verse_key,
+                page,
+                correct_line,
+                line_options,
+                ..
+            } => ExerciseDataDto::IdentifyVerseLine {
+                node_id: nid::to_ukey(node_id).unwrap_or_default(),
+                verse_key,
+                page,
+                correct_line,
+                line_options,
+                seed,
+            },
+            ListenIdentifyVerse {
+                node_id,
+                reciter_id,
+                verse_key,
+                first_position,
+                last_position,
+                start_ms,
+                end_ms,
+                option_verse_keys,
+                ..
+            } => ExerciseDataDto::ListenIdentifyVerse {
+                node_id: nid::to_ukey(node_id).unwrap_or_default(),
+                reciter_id,
+                verse_key,
+                first_position,
+                last_position,
+                start_ms,
+                end_ms,
+                option_verse_keys,
+                seed,
+            },
+            ListenContinue {
+                node_id,
+                reciter_id,
+                verse_key,
+                first_position,
+                last_position,
+                start_ms,
+                end_ms,
+                distractor_node_ids,
+                distractor_strategy,
+                ..
+            } => ExerciseDataDto::ListenContinue {
+                node_id: nid::to_ukey(node_id).unwrap_or_default(),
+                reciter_id,
+                verse_key,
+                first_position,
+                last_position,
+                start_ms,
+                end_ms,
+                distractor_node_ids: distractor_node_ids
+                    .into_iter()
+                    .map(|id| nid::to_ukey(id).unwrap_or_default())
+                    .collect(),
+                distractor_strategy: distractor_strategy.into(),
+                seed,
+            },
+            ListenIdentifyWord {
+                node_id,
+                reciter_id,
+                verse_key,
+                position,
+                start_ms,
+                end_ms,
+                distractor_node_ids,
+                distractor_strategy,
+                ..
+            } => ExerciseDataDto::ListenIdentifyWord {
+                node_id: nid::to_ukey(node_id).unwrap_or_default(),
+                reciter_id,
+                verse_key,
+                position,
+                start_ms,
+                end_ms,
+                distractor_node_ids: distractor_node_ids
+                    .into_iter()
+                    .map(|id| nid::to_ukey(id).unwrap_or_default())
+                    .collect(),
+                distractor_strategy: distractor_strategy.into(),
+                seed,
+            },
+            IdentifyTajweedRule {
+                node_id,
+                word_node_id,
+                start_char,
+                end_char,
+                correct_rule,
+                rule_options,
+                ..
+            } => ExerciseDataDto::IdentifyTajweedRule {
+                node_id: nid::to_ukey(node_id).unwrap_or_default(),
+                word_node_id: nid::to_ukey(word_node_id).unwrap_or_default(),
+                start_char,
+                end_char,
+                correct_rule: correct_rule.as_str().to_string(),
+                option_names: rule_options.iter().map(|r| r.name().to_string()).collect(),
+                rule_options: rule_options
+                    .iter()
+                    .map(|r| r.as_str().to_string())
+                    .collect(),
+                seed,
+            },
+            FindTajweedRule {
+                node_id,
+                rule,
+                rule_word_node_ids,
+                ..
+            } => ExerciseDataDto::FindTajweedRule {
+                node_id: nid::to_ukey(node_id).unwrap_or_default(),
+                rule: rule.as_str().to_string(),
+                rule_name: rule.name().to_string(),
+                rule_word_node_ids: rule_word_node_ids
+                    .into_iter()
+                    .map(|id| nid::to_ukey(id).unwrap_or_default())
+                    .collect(),
+                seed,
+            },
+            TafsirKeyLesson {
+                node_id,
+                tafsir_id,
+                passage_key,
+                option_passage_keys,
+                ..
+            } => ExerciseDataDto::TafsirKeyLesson {
+                node_id: nid::to_ukey(node_id).unwrap_or_default(),
+                tafsir_id,
+                passage_key,
+                option_passage_keys,
+                seed,
+            },
+            RevelationOccasion {
+                node_id,
+                tafsir_id,
+                passage_key,
+                option_verse_keys,
+                ..
+            } => ExerciseDataDto::RevelationOccasion {
+                node_id: nid::to_ukey(node_id).unwrap_or_default(),
+                tafsir_id,
+                passage_key,
+                option_verse_keys,
+                seed,
+            },
+        }
+    }
+}
+
+fn node_id_from_ukey(ukey: &str) -> Result<i64> {
+    nid::from_ukey(ukey).ok_or_else(|| anyhow::anyhow!("Invalid node ID: {}", ukey))
+}
+
+fn node_ids_from_ukeys(ukeys: Vec<String>) -> Result<Vec<i64>> {
+    ukeys.iter().map(|ukey| node_id_from_ukey(ukey)).collect()
+}
+
+fn tajweed_rule_from_slug(slug: &str) -> Result<TajweedRule> {
+    TajweedRule::from_slug(slug).ok_or_else(|| anyhow::anyhow!("Unknown tajweed rule: {}", slug))
+}
+
+/// Rebuild exercise data sent back by the client (for answer validation)
+impl TryFrom<ExerciseDataDto> for ExerciseData {
+    type Error = anyhow::Error;
+
+    fn try_from(dto: ExerciseDataDto) -> Result<Self> {
+        Ok(match dto {
+            ExerciseDataDto::Memorization { node_id, .. } => ExerciseData::Memorization {
+                node_id: node_id_from_ukey(&node_id)?,
+            },
+            ExerciseDataDto::McqArToEn {
+                node_id,
+                distractor_node_ids,
+                distractor_strategy,
+                seed,
+            } => ExerciseData::McqArToEn {
+                node_id: node_id_from_ukey(&node_id)?,
+                distractor_node_ids: node_ids_from_ukeys(distractor_node_ids)?,
+                distractor_strategy: distractor_strategy.try_into()?,
+                seed,
+            },
+            ExerciseDataDto::McqEnToAr {
+                node_id,
+                distractor_node_ids,
+                distractor_strategy,
+                seed,
+            } => ExerciseData::McqEnToAr {
+                node_id: node_id_from_ukey(&node_id)?,
+                distractor_node_ids: node_ids_from_ukeys(distractor_node_ids)?,
+                distractor_strategy: distractor_strategy.try_into()?,
+                seed,
+            },
+            ExerciseDataDto::Translation { node_id, .. } => ExerciseData::Translation {
+                node_id: node_id_from_ukey(&node_id)?,
+            },
+            ExerciseDataDto::ContextualTranslation {
+                node_id, verse_key, ..
+            } => ExerciseData::ContextualTranslation {
+                node_id: node_id_from_ukey(&node_id)?,
+                verse_key,
+            },
+            ExerciseDataDto::ClozeDeletion {
+                node_id,
+                blank_position,
+                seed,
+            } => ExerciseData::ClozeDeletion {
+                node_id: node_id_from_ukey(&node_id)?,
+                blank_position,
+                seed,
+            },
+            ExerciseDataDto::FirstLetterHint {
+                node_id,
+                word_position,
+                seed,
+            } => ExerciseData::FirstLetterHint {
+                node_id: node_id_from_ukey(&node_id)?,
+                word_position,
+                seed,
             },
-            MissingWordMcq {
+            ExerciseDataDto::MissingWordMcq {
                 node_id,
                 blank_position,
                 distractor_node_ids,
-            } => ExerciseDataDto::MissingWordMcq {
-                node_id: nid::to_ukey(node_id).unwrap_or_default(),
+                distractor_strategy,
+                seed,
+            } => ExerciseData::MissingWordMcq {
+                node_id: node_id_from_ukey(&node_id)?,
                 blank_position,
-                distractor_node_ids: distractor_node_ids
-                    .into_iter()
-                    .map(|id| nid::to_ukey(id).unwrap_or_default())
-                    .collect(),
+                distractor_node_ids: node_ids_from_ukeys(distractor_node_ids)?,
+                distractor_strategy: distractor_strategy.try_into()?,
+                seed,
             },
-            NextWordMcq {
+            ExerciseDataDto::NextWordMcq {
                 node_id,
                 context_position,
                 distractor_node_ids,
-            } => ExerciseDataDto::NextWordMcq {
-                node_id: nid::to_ukey(node_id).unwrap_or_default(),
+                distractor_strategy,
+                seed,
+            } => ExerciseData::NextWordMcq {
+                node_id: node_id_from_ukey(&node_id)?,
                 context_position,
-                distractor_node_ids: distractor_node_ids
-                    .into_iter()
-                    .map(|id| nid::to_ukey(id).unwrap_or_default())
-                    .collect(),
+                distractor_node_ids: node_ids_from_ukeys(distractor_node_ids)?,
+                distractor_strategy: distractor_strategy.try_into()?,
+                seed,
             },
-            FullVerseInput { node_id } => ExerciseDataDto::FullVerseInput {
-                node_id: nid::to_ukey(node_id).unwrap_or_default(),
+            ExerciseDataDto::FullVerseInput { node_id, .. } => ExerciseData::FullVerseInput {
+                node_id: node_id_from_ukey(&node_id)?,
             },
-            AyahChain {
+            ExerciseDataDto::AyahChain {
                 node_id,
                 verse_keys,
                 current_index,
                 completed_count,
-            } => ExerciseDataDto::AyahChain {
-                node_id: nid::to_ukey(node_id).unwrap_or_default(),
+                ..
+            } => ExerciseData::AyahChain {
+                node_id: node_id_from_ukey(&node_id)?,
                 verse_keys,
                 current_index,
                 completed_count,
             },
-            FindMistake {
+            ExerciseDataDto::FindMistake {
                 node_id,
                 mistake_position,
                 correct_word_node_id,
                 incorrect_word_node_id,
-            } => ExerciseDataDto::FindMistake {
-                node_id: nid::to_ukey(node_id).unwrap_or_default(),
+                seed,
+            } => ExerciseData::FindMistake {
+                node_id: node_id_from_ukey(&node_id)?,
                 mistake_position,
-                correct_word_node_id: nid::to_ukey(correct_word_node_id).unwrap_or_default(),
-                incorrect_word_node_id: nid::to_ukey(incorrect_word_node_id).unwrap_or_default(),
+                correct_word_node_id: node_id_from_ukey(&correct_word_node_id)?,
+                incorrect_word_node_id: node_id_from_ukey(&incorrect_word_node_id)?,
+                seed,
             },
-            AyahSequence {
+            ExerciseDataDto::AyahSequence {
                 node_id,
                 correct_sequence,
-            } => ExerciseDataDto::AyahSequence {
-                node_id: nid::to_ukey(node_id).unwrap_or_default(),
-                correct_sequence: correct_sequence
-                    .into_iter()
-                    .map(|id| nid::to_ukey(id).unwrap_or_default())
-                    .collect(),
+                ..
+            } => ExerciseData::AyahSequence {
+                node_id: node_id_from_ukey(&node_id)?,
+                correct_sequence: node_ids_from_ukeys(correct_sequence)?,
             },
-            SequenceRecall {
+            ExerciseDataDto::SequenceRecall {
                 node_id,
                 correct_sequence,
                 options,
-            } => ExerciseDataDto::SequenceRecall {
-                node_id: nid::to_ukey(node_id).unwrap_or_default(),
-                correct_sequence: correct_sequence
-                    .into_iter()
-                    .map(|id| nid::to_ukey(id).unwrap_or_default())
-                    .collect(),
+                ..
+            } => ExerciseData::SequenceRecall {
+                node_id: node_id_from_ukey(&node_id)?,
+                correct_sequence: node_ids_from_ukeys(correct_sequence)?,
                 options: options
                     .into_iter()
-                    .map(|sequence| {
-                        sequence
-                            .into_iter()
-                            .map(|id| nid::to_ukey(id).unwrap_or_default())
-                            .collect()
-                    })
-                    .collect(),
+                    .map(node_ids_from_ukeys)
+                    .collect::<Result<_>>()?,
             },
-            FirstWordRecall { node_id, verse_key } => ExerciseDataDto::FirstWordRecall {
-                node_id: nid::to_ukey(node_id).unwrap_or_default(),
+            ExerciseDataDto::FirstWordRecall {
+                node_id, verse_key, ..
+            } => ExerciseData::FirstWordRecall {
+                node_id: node_id_from_ukey(&node_id)?,
                 verse_key,
             },
-            IdentifyRoot { node_id, root } => ExerciseDataDto::IdentifyRoot {
-                node_id: nid::to_ukey(node_id).unwrap_or_default(),
+            ExerciseDataDto::IdentifyRoot { node_id, root, .. } => ExerciseData::IdentifyRoot {
+                node_id: node_id_from_ukey(&node_id)?,
                 root,
             },
-            ReverseCloze {
+            ExerciseDataDto::ReverseCloze {
                 node_id,
                 blank_position,
-            } => ExerciseDataDto::ReverseCloze {
-                node_id: nid::to_ukey(node_id).unwrap_or_default(),
+                seed,
+            } => ExerciseData::ReverseCloze {
+                node_id: node_id_from_ukey(&node_id)?,
                 blank_position,
+                seed,
             },
-            TranslatePhrase {
+            ExerciseDataDto::TranslatePhrase {
                 node_id,
                 translator_id,
-            } => ExerciseDataDto::TranslatePhrase {
-                node_id: nid::to_ukey(node_id).unwrap_or_default(),
+                ..
+            } => ExerciseData::TranslatePhrase {
+                node_id: node_id_from_ukey(&node_id)?,
                 translator_id,
             },
-            PosTagging {
+            ExerciseDataDto::PosTagging {
                 node_id,
                 correct_pos,
                 options,
-            } => ExerciseDataDto::PosTagging {
-                node_id: nid::to_ukey(node_id).unwrap_or_default(),
+                seed,
+            } => ExerciseData::PosTagging {
+                node_id: node_id_from_ukey(&node_id)?,
                 correct_pos,
                 options,
+                seed,
             },
-            CrossVerseConnection {
+            ExerciseDataDto::GrammarFeature {
+                node_id,
+                feature,
+                correct_value,
+                options,
+                seed,
+                ..
+            } => ExerciseData::GrammarFeature {
+                node_id: node_id_from_ukey(&node_id)?,
+                feature: iqrah_core::GrammarFeature::from_slug(&feature)
+                    .ok_or_else(|| anyhow::anyhow!("Unknown grammar feature: {}", feature))?,
+                correct_value,
+                options,
+                seed,
+            },
+            ExerciseDataDto::CrossVerseConnection {
                 node_id,
                 related_verse_ids,
                 connection_theme,
-            } => ExerciseDataDto::CrossVerseConnection {
-                node_id: nid::to_ukey(node_id).unwrap_or_default(),
-                related_verse_ids: related_verse_ids
-                    .into_iter()
-                    .map(|id| nid::to_ukey(id).unwrap_or_default())
-                    .collect(),
+                ..
+            } => ExerciseData::CrossVerseConnection {
+                node_id: node_id_from_ukey(&node_id)?,
+                related_verse_ids: node_ids_from_ukeys(related_verse_ids)?,
                 connection_theme,
             },
-            EchoRecall { ayah_node_ids } => ExerciseDataDto::EchoRecall {
-                // user_id is not stored in core ExerciseData, injected at session level
-                user_id: String::new(),
-                ayah_node_ids: ayah_node_ids
-                    .into_iter()
-                    .map(|id| nid::to_ukey(id).unwrap_or_default())
-                    .collect(),
+            ExerciseDataDto::EchoRecall { ayah_node_ids, .. } => ExerciseData::EchoRecall {
+                ayah_node_ids: node_ids_from_ukeys(ayah_node_ids)?,
             },
-        }
+            ExerciseDataDto::Mutashabihat {
+                node_id,
+                verse_key,
+                prompt_word_count,
+                option_verse_keys,
+                differing_positions,
+                seed,
+            } => ExerciseData::Mutashabihat {
+                node_id: node_id_from_ukey(&node_id)?,
+                verse_key,
+                prompt_word_count,
+                option_verse_keys,
+                differing_positions,
+                seed,
+            },
+            ExerciseDataDto::WordOrder {
+                node_id,
+                correct_sequence,
+                shuffled_word_node_ids,
+                tile_texts,
+                seed,
+            } => ExerciseData::WordOrder {
+                node_id: node_id_from_ukey(&node_id)?,
+                correct_sequence: node_ids_from_ukeys(correct_sequence)?,
+                shuffled_word_node_ids: node_ids_from_ukeys(shuffled_word_node_ids)?,
+                tile_texts,
+                seed,
+            },
+            ExerciseDataDto::IdentifySurah {
+                node_id,
+                verse_key,
+                correct_chapter,
+                chapter_options,
+                seed,
+            } => ExerciseData::IdentifySurah {
+                node_id: node_id_from_ukey(&node_id)?,
+                verse_key,
+                correct_chapter,
+                chapter_options,
+                seed,
+            },
+            ExerciseDataDto::IdentifyVerseNumber {
+                node_id,
+                verse_key,
+                correct_verse_number,
+                verse_number_options,
+                seed,
+            } => ExerciseData::IdentifyVerseNumber {
+                node_id: node_id_from_ukey(&node_id)?,
+                verse_key,
+                correct_verse_number,
+                verse_number_options,
+                seed,
+            },
+            ExerciseDataDto::IdentifyPageHalf {
+                node_id,
+                verse_key,
+                page,
+                half,
+                ..
+            } => ExerciseData::IdentifyPageHalf {
+                node_id: node_id_from_ukey(&node_id)?,
+                verse_key,
+                page,
+                half,
+            },
+            ExerciseDataDto::TransliterationToArabic {
+                node_id,
+                distractor_node_ids,
+                distractor_strategy,
+                seed,
+            } => ExerciseData::TransliterationToArabic {
+                node_id: node_id_from_ukey(&node_id)?,
+                distractor_node_ids: node_ids_from_ukeys(distractor_node_ids)?,
+                distractor_strategy: distractor_strategy.try_into()?,
+                seed,
+            },
+            ExerciseDataDto::ArabicToTransliteration {
+                node_id, verse_key, ..
+            } => ExerciseData::ArabicToTransliteration {
+                node_id: node_id_from_ukey(&node_id)?,
+                verse_key,
+            },
+            ExerciseDataDto::RootFamily {
+                node_id,
+                root_id,
+                verse_keys,
+                family_word_node_ids,
+                ..
+            } => ExerciseData::RootFamily {
+                node_id: node_id_from_ukey(&node_id)?,
+                root_id,
+                verse_keys,
+                family_word_node_ids: node_ids_from_ukeys(family_word_node_ids)?,
+            },
+            ExerciseDataDto::LemmaMeaning {
+                node_id,
+                root_id,
+                lemma_ids,
+                meaning_lemma_ids,
+                seed,
+            } => ExerciseData::LemmaMeaning {
+                node_id: node_id_from_ukey(&node_id)?,
+                root_id,
+                lemma_ids,
+                meaning_lemma_ids,
+                seed,
+            },
+            ExerciseDataDto::RecitePage {
+                node_id,
+                page,
+                verse_keys,
+                first_word_node_id,
+                last_word_node_id,
+                ..
+            } => ExerciseData::RecitePage {
+                node_id: node_id_from_ukey(&node_id)?,
+                page,
+                verse_keys,
+                first_word_node_id: node_id_from_ukey(&first_word_node_id)?,
+                last_word_node_id: node_id_from_ukey(&last_word_node_id)?,
+            },
+            ExerciseDataDto::PageFirstWord {
+                node_id,
+                page,
+                first_word_node_id,
+                distractor_node_ids,
+                seed,
+            } => ExerciseData::PageFirstWord {
+                node_id: node_id_from_ukey(&node_id)?,
+                page,
+                first_word_node_id: node_id_from_ukey(&first_word_node_id)?,
+                distractor_node_ids: node_ids_from_ukeys(distractor_node_ids)?,
+                seed,
+            },
+            ExerciseDataDto::IdentifyVerseLine {
+                node_id,
+                verse_key,
+                page,
+                correct_line,
+                line_options,
+                seed,
+            } => ExerciseData::IdentifyVerseLine {
+                node_id: node_id_from_ukey(&node_id)?,
+                verse_key,
+                page,
+                correct_line,
+                line_options,
+                seed,
+            },
+            ExerciseDataDto::ListenIdentifyVerse {
+                node_id,
+                reciter_id,
+                verse_key,
+                first_position,
+                last_position,
+                start_ms,
+                end_ms,
+                option_verse_keys,
+                seed,
+            } => ExerciseData::ListenIdentifyVerse {
+                node_id: node_id_from_ukey(&node_id)?,
+                reciter_id,
+                verse_key,
+                first_position,
+                last_position,
+                start_ms,
+                end_ms,
+                option_verse_keys,
+                seed,
+            },
+            ExerciseDataDto::ListenContinue {
+                node_id,
+                reciter_id,
+                verse_key,
+                first_position,
+                last_position,
+                start_ms,
+                end_ms,
+                distractor_node_ids,
+                distractor_strategy,
+                seed,
+            } => ExerciseData::ListenContinue {
+                node_id: node_id_from_ukey(&node_id)?,
+                reciter_id,
+                verse_key,
+                first_position,
+                last_position,
+                start_ms,
+                end_ms,
+                distractor_node_ids: node_ids_from_ukeys(distractor_node_ids)?,
+                distractor_strategy: distractor_strategy.try_into()?,
+                seed,
+            },
+            ExerciseDataDto::ListenIdentifyWord {
+                node_id,
+                reciter_id,
+                verse_key,
+                position,
+                start_ms,
+                end_ms,
+                distractor_node_ids,
+                distractor_strategy,
+                seed,
+            } => ExerciseData::ListenIdentifyWord {
+                node_id: node_id_from_ukey(&node_id)?,
+                reciter_id,
+                verse_key,
+                position,
+                start_ms,
+                end_ms,
+                distractor_node_ids: node_ids_from_ukeys(distractor_node_ids)?,
+                distractor_strategy: distractor_strategy.try_into()?,
+                seed,
+            },
+            ExerciseDataDto::IdentifyTajweedRule {
+                node_id,
+                word_node_id,
+                start_char,
+                end_char,
+                correct_rule,
+                rule_options,
+                seed,
+                ..
+            } => ExerciseData::IdentifyTajweedRule {
+                node_id: node_id_from_ukey(&node_id)?,
+                word_node_id: node_id_from_ukey(&word_node_id)?,
+                start_char,
+                end_char,
+                correct_rule: tajweed_rule_from_slug(&correct_rule)?,
+                rule_options: rule_options
+                    .iter()
+                    .map(|slug| tajweed_rule_from_slug(slug))
+                    .collect::<Result<_>>()?,
+                seed,
+            },
+            ExerciseDataDto::FindTajweedRule {
+                node_id,
+                rule,
+                rule_word_node_ids,
+                seed,
+                ..
+            } => ExerciseData::FindTajweedRule {
+                node_id: node_id_from_ukey(&node_id)?,
+                rule: tajweed_rule_from_slug(&rule)?,
+                rule_word_node_ids: node_ids_from_ukeys(rule_word_node_ids)?,
+                seed,
+            },
+            ExerciseDataDto::TafsirKeyLesson {
+                node_id,
+                tafsir_id,
+                passage_key,
+                option_passage_keys,
+                seed,
+            } => ExerciseData::TafsirKeyLesson {
+                node_id: node_id_from_ukey(&node_id)?,
+                tafsir_id,
+                passage_key,
+                option_passage_keys,
+                seed,
+            },
+            ExerciseDataDto::RevelationOccasion {
+                node_id,
+                tafsir_id,
+                passage_key,
+                option_verse_keys,
+                seed,
+            } => ExerciseData::RevelationOccasion {
+                node_id: node_id_from_ukey(&node_id)?,
+                tafsir_id,
+                passage_key,
+                option_verse_keys,
+                seed,
+            },
+        })
     }
 }
 
@@ -2265,6 +4346,19 @@ pub struct EchoRecallMetricsDto {
     pub struggles: u32,
 }
 
+/// Transition between two consecutive ayahs of an Echo Recall session
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct AyahJunctionDto {
+    /// Ayah ending at the junction (e.g., "VERSE:1:1")
+    pub from_verse: String,
+    /// Ayah starting at the junction (e.g., "VERSE:1:2")
+    pub to_verse: String,
+    pub closing_word: String,
+    pub opening_word: String,
+    /// Energy of the weaker of the two words (0.0 to 1.0)
+    pub strength: f64,
+}
+
 /// Result from finalizing Echo Recall (energy updates + metrics acknowledgement)
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct EchoRecallResultDto {
@@ -2273,10 +4367,111 @@ pub struct EchoRecallResultDto {
     pub average_energy: f64,
 }
 
+/// One recognized word of a recitation transcript
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct TranscriptWordDto {
+    pub text: String,
+    pub start_ms: Option<u64>,
+    pub end_ms: Option<u64>,
+}
+
+/// How one expected word was recited
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct RecitedWordDto {
+    pub word_node_id: String,
+    pub verse_key: String,
+    pub position: i32,
+    pub text: String,
+    /// "correct", "close", "substituted" or "skipped"
+    pub kind: String,
+    pub heard: Option<String>,
+    pub similarity: f64,
+    pub repeated: bool,
+    pub start_ms: Option<u64>,
+    pub end_ms: Option<u64>,
+    /// Review grade recorded for the word (1-4)
+    pub grade: u8,
+}
+
+/// A run of the recitation taken from another verse
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct RecitationJumpDto {
+    pub verse_key: String,
+    pub start_position: i32,
+    pub word_count: u32,
+    /// Last expected word recited before the jump
+    pub after_word_node_id: Option<String>,
+    pub start_ms: Option<u64>,
+}
+
+/// Graded recitation of a verse range
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct RecitationResultDto {
+    pub words: Vec<RecitedWordDto>,
+    pub jumps: Vec<RecitationJumpDto>,
+    pub extra_words: Vec<TranscriptWordDto>,
+    pub accuracy: f64,
+    pub grade: u8,
+}
+
 // ========================================================================
 // Echo Recall Conversions
 // ========================================================================
 
+impl From<iqrah_core::exercises::RecitationGrade> for RecitationResultDto {
+    fn from(grade: iqrah_core::exercises::RecitationGrade) -> Self {
+        use iqrah_core::exercises::RecitedWordKind;
+
+        Self {
+            words: grade
+                .words
+                .into_iter()
+                .map(|w| RecitedWordDto {
+                    word_node_id: nid::to_ukey(w.expected.node_id).unwrap_or_default(),
+                    verse_key: w.expected.verse_key,
+                    position: w.expected.position,
+                    text: w.expected.text,
+                    kind: match w.kind {
+                        RecitedWordKind::Correct => "correct",
+                        RecitedWordKind::Close => "close",
+                        RecitedWordKind::Substituted => "substituted",
+                        RecitedWordKind::Skipped => "skipped",
+                    }
+                    .to_string(),
+                    heard: w.heard,
+                    similarity: w.similarity,
+                    repeated: w.repeated,
+                    start_ms: w.start_ms,
+                    end_ms: w.end_ms,
+                    grade: w.grade as u8,
+                })
+                .collect(),
+            jumps: grade
+                .jumps
+                .into_iter()
+                .map(|j| RecitationJumpDto {
+                    verse_key: j.verse_key,
+                    start_position: j.start_position,
+                    word_count: j.word_count as u32,
+                    after_word_node_id: j.after_node_id.and_then(nid::to_ukey),
+                    start_ms: j.start_ms,
+                })
+                .collect(),
+            extra_words: grade
+                .extra_words
+                .into_iter()
+                .map(|w| TranscriptWordDto {
+                    text: w.text,
+                    start_ms: w.start_ms,
+                    end_ms: w.end_ms,
+                })
+                .collect(),
+            accuracy: grade.accuracy,
+            grade: grade.grade as u8,
+        }
+    }
+}
+
 impl From<iqrah_core::domain::models::Hint> for HintDto {
     fn from(hint: iqrah_core::domain::models::Hint) -> Self {
         use iqrah_core::domain::models::Hint;
@@ -2342,6 +4537,18 @@ impl From<iqrah_core::domain::models::EchoRecallState> for EchoRecallStateDto {
     }
 }
 
+impl From<iqrah_core::AyahJunction> for AyahJunctionDto {
+    fn from(junction: iqrah_core::AyahJunction) -> Self {
+        AyahJunctionDto {
+            from_verse: junction.from_verse,
+            to_verse: junction.to_verse,
+            closing_word: junction.closing_word,
+            opening_word: junction.opening_word,
+            strength: junction.strength,
+        }
+    }
+}
+
 impl From<iqrah_core::domain::models::EchoRecallStats> for EchoRecallStatsDto {
     fn from(stats: iqrah_core::domain::models::EchoRecallStats) -> Self {
         EchoRecallStatsDto {
@@ -2451,6 +4658,7 @@ pub async fn start_echo_recall(
     let exercise = EchoRecallExercise::new(
         &user_id,
         ayah_node_ids,
+        app.exercise_service.script(),
         app.content_repo.as_ref(),
         app.user_repo.as_ref(),
     )
@@ -2488,6 +4696,54 @@ pub async fn submit_echo_recall(
     Ok(exercise.state().clone().into())
 }
 
+/// Submit a word recall for an Echo Recall session item and checkpoint it
+///
+/// Same as `submit_echo_recall`, but the updated state is also saved under the
+/// session item so it survives the app being killed (see
+/// `resume_session_echo_recall`). The checkpoint is cleared when the item is
+/// recorded.
+pub async fn submit_session_echo_recall(
+    session_id: String,
+    position: i32,
+    user_id: String,
+    ayah_node_ids: Vec<String>,
+    state: EchoRecallStateDto,
+    word_node_id: String,
+    recall_time_ms: u32,
+) -> Result<EchoRecallStateDto> {
+    use iqrah_core::exercises::EchoRecallExercise;
+
+    let app = app();
+
+    let mut exercise = EchoRecallExercise::from_state(&user_id, ayah_node_ids, state.into());
+    exercise.submit_recall(&word_node_id, recall_time_ms)?;
+    save_checkpoint(
+        app.user_repo.as_ref(),
+        &session_id,
+        position,
+        &user_id,
+        &exercise,
+    )
+    .await?;
+
+    Ok(exercise.state().clone().into())
+}
+
+/// Restore the checkpointed state of an Echo Recall session item
+///
+/// Returns None when the item has no unexpired checkpoint, in which case the
+/// exercise is started fresh with `start_echo_recall`.
+pub async fn resume_session_echo_recall(
+    session_id: String,
+    position: i32,
+) -> Result<Option<EchoRecallStateDto>> {
+    use iqrah_core::exercises::EchoRecallExercise;
+
+    let exercise: Option<EchoRecallExercise> =
+        load_checkpoint(app().user_repo.as_ref(), &session_id, position).await?;
+    Ok(exercise.map(|e| e.state().clone().into()))
+}
+
 /// Get statistics for an Echo Recall session
 pub fn echo_recall_stats(state: EchoRecallStateDto) -> EchoRecallStatsDto {
     // Convert to domain state and get stats
@@ -2495,9 +4751,22 @@ pub fn echo_recall_stats(state: EchoRecallStateDto) -> EchoRecallStatsDto {
     domain_state.get_stats().into()
 }
 
+/// Get the ayah junctions of an Echo Recall session and their strength
+pub fn echo_recall_junctions(state: EchoRecallStateDto) -> Vec<AyahJunctionDto> {
+    use iqrah_core::exercises::EchoRecallExercise;
+
+    EchoRecallExercise::from_state("", Vec::new(), state.into())
+        .finalize_junctions()
+        .into_iter()
+        .map(Into::into)
+        .collect()
+}
+
 /// Finalize an Echo Recall session
 ///
 /// Persists energy updates to the user's memory states and emits telemetry.
+/// Each ayah junction is reviewed on its transition node (the contextual
+/// memorization of the following ayah), so weak transitions come back sooner.
 /// Accepts per-word timing metrics for detailed analytics.
 pub async fn finalize_echo_recall(
     user_id: String,
@@ -2526,10 +4795,23 @@ pub async fn finalize_echo_recall(
         }
     }
 
+    for junction in exercise.finalize_junctions() {
+        let Some(transition_id) = junction.transition_node_id() else {
+            continue;
+        };
+        // Graphs without contextual memorization nodes have nothing to schedule
+        if !app.content_repo.node_exists(transition_id).await? {
+            continue;
+        }
+        app.learning_service
+            .process_review(&user_id, transition_id, junction.grade())
+            .await?;
+    }
+
     // Increment stats - each word in EchoRecall counts as a review
     let word_count = domain_state.words.len() as u32;
     for _ in 0..word_count {
-        let _ = app.session_service.increment_stat("reviews_today").await;
+        count_review_today(app).await;
     }
 
     // Emit telemetry with detailed metrics
@@ -2566,6 +4848,98 @@ pub async fn finalize_echo_recall(
     })
 }
 
+// ========================================================================
+// Ayah Chain FFI Functions
+// ========================================================================
+
+/// Progress of an Ayah Chain session item
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct AyahChainStateDto {
+    /// Chapter node ID (e.g., "CHAPTER:1")
+    pub node_id: String,
+    /// Verse to type next, None once the chain is complete or broken
+    pub current_verse_key: Option<String>,
+    pub completed_count: u32,
+    pub total_verses: u32,
+    pub is_complete: bool,
+    pub mistake_made: bool,
+    /// Grade of the last submission (1=Again, 2=Hard, 3=Good, 4=Easy)
+    pub last_grade: Option<u8>,
+}
+
+impl From<&iqrah_core::AyahChainExercise> for AyahChainStateDto {
+    fn from(exercise: &iqrah_core::AyahChainExercise) -> Self {
+        use iqrah_core::exercises::Exercise;
+
+        let stats = exercise.get_stats();
+        AyahChainStateDto {
+            node_id: nid::to_ukey(exercise.get_node_id()).unwrap_or_default(),
+            current_verse_key: stats.current_verse_key,
+            completed_count: stats.completed_count as u32,
+            total_verses: stats.total_verses as u32,
+            is_complete: stats.is_complete,
+            mistake_made: stats.mistake_made,
+            last_grade: exercise.last_grade().map(|g| g as u8),
+        }
+    }
+}
+
+/// Submit the next verse of an Ayah Chain session item
+///
+/// The chain is resumed from the item's checkpoint (or started for
+/// `chapter_node_id` if there is none), graded against `user_input`, and
+/// checkpointed again.
+pub async fn submit_session_ayah_chain(
+    session_id: String,
+    position: i32,
+    user_id: String,
+    chapter_node_id: String,
+    user_input: String,
+) -> Result<AyahChainStateDto> {
+    use iqrah_core::AyahChainExercise;
+
+    let app = app();
+    let checkpoint: Option<AyahChainExercise> =
+        load_checkpoint(app.user_repo.as_ref(), &session_id, position).await?;
+    let mut exercise = match checkpoint {
+        Some(exercise) => exercise,
+        None => {
+            AyahChainExercise::new(
+                node_id_from_ukey(&chapter_node_id)?,
+                app.exercise_service.script(),
+                app.content_repo.as_ref(),
+            )
+            .await?
+        }
+    };
+
+    exercise.submit_answer(&user_input)?;
+    save_checkpoint(
+        app.user_repo.as_ref(),
+        &session_id,
+        position,
+        &user_id,
+        &exercise,
+    )
+    .await?;
+
+    Ok((&exercise).into())
+}
+
+/// Restore the checkpointed progress of an Ayah Chain session item
+///
+/// Returns None when the item has no unexpired checkpoint.
+pub async fn resume_session_ayah_chain(
+    session_id: String,
+    position: i32,
+) -> Result<Option<AyahChainStateDto>> {
+    use iqrah_core::AyahChainExercise;
+
+    let exercise: Option<AyahChainExercise> =
+        load_checkpoint(app().user_repo.as_ref(), &session_id, position).await?;
+    Ok(exercise.as_ref().map(AyahChainStateDto::from))
+}
+
 // ========================================================================
 // Telemetry API v1 (Polling-based, Rust→Dart)
 // ========================================================================
@@ -38,7 +38,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -1706751684;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -1226003283;
 
 // Section: executor
 
@@ -531,6 +531,85 @@ fn wire__crate__api__AppState_auto_accessor_set_user_repo_impl(
         },
     )
 }
+fn wire__crate__api__bury_nodes_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "bury_nodes",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_user_id = <String>::sse_decode(&mut deserializer);
+            let api_scope = <String>::sse_decode(&mut deserializer);
+            let api_until_millis = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::bury_nodes(api_user_id, api_scope, api_until_millis)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__check_exercise_answer_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "check_exercise_answer",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_exercise = <crate::api::ExerciseDataDto>::sse_decode(&mut deserializer);
+            let api_answer_json = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::check_exercise_answer(api_exercise, api_answer_json)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
 fn wire__crate__api__clear_session_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -741,6 +820,40 @@ fn wire__crate__api__drain_telemetry_events_impl(
         },
     )
 }
+fn wire__crate__api__echo_recall_junctions_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "echo_recall_junctions",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_state = <crate::api::EchoRecallStateDto>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::echo_recall_junctions(api_state))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
 fn wire__crate__api__echo_recall_stats_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -994,6 +1107,41 @@ fn wire__crate__api__get_active_session_impl(
         },
     )
 }
+fn wire__crate__api__get_arabic_level_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_arabic_level",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::get_arabic_level().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
 fn wire__crate__api__get_available_packages_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -1249,6 +1397,80 @@ fn wire__crate__api__get_energy_snapshot_impl(
         },
     )
 }
+fn wire__crate__api__get_exercise_hint_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_exercise_hint",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_exercise = <crate::api::ExerciseDataDto>::sse_decode(&mut deserializer);
+            let api_step = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::get_exercise_hint(api_exercise, api_step).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__get_exercise_hints_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_exercise_hints",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_exercise = <crate::api::ExerciseDataDto>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::get_exercise_hints(api_exercise).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
 fn wire__crate__api__get_exercises_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -1511,7 +1733,7 @@ fn wire__crate__api__get_next_session_item_impl(
         },
     )
 }
-fn wire__crate__api__get_preferred_translator_id_impl(
+fn wire__crate__api__get_node_status_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1519,7 +1741,7 @@ fn wire__crate__api__get_preferred_translator_id_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_preferred_translator_id",
+            debug_name: "get_node_status",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1533,11 +1755,14 @@ fn wire__crate__api__get_preferred_translator_id_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_user_id = <String>::sse_decode(&mut deserializer);
+            let api_node_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::get_preferred_translator_id().await?;
+                        let output_ok =
+                            crate::api::get_node_status(api_user_id, api_node_id).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1546,7 +1771,7 @@ fn wire__crate__api__get_preferred_translator_id_impl(
         },
     )
 }
-fn wire__crate__api__get_session_items_since_impl(
+fn wire__crate__api__get_page_exercises_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1554,7 +1779,7 @@ fn wire__crate__api__get_session_items_since_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_session_items_since",
+            debug_name: "get_page_exercises",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1568,15 +1793,12 @@ fn wire__crate__api__get_session_items_since_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_user_id = <String>::sse_decode(&mut deserializer);
-            let api_since_millis = <i64>::sse_decode(&mut deserializer);
+            let api_page = <i32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::get_session_items_since(api_user_id, api_since_millis)
-                                .await?;
+                        let output_ok = crate::api::get_page_exercises(api_page).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1585,7 +1807,7 @@ fn wire__crate__api__get_session_items_since_impl(
         },
     )
 }
-fn wire__crate__api__get_session_preview_impl(
+fn wire__crate__api__get_preferred_script_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1593,7 +1815,7 @@ fn wire__crate__api__get_session_preview_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_session_preview",
+            debug_name: "get_preferred_script",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1607,19 +1829,11 @@ fn wire__crate__api__get_session_preview_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_user_id = <String>::sse_decode(&mut deserializer);
-            let api_limit = <u32>::sse_decode(&mut deserializer);
-            let api_is_high_yield = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::get_session_preview(
-                            api_user_id,
-                            api_limit,
-                            api_is_high_yield,
-                        )
-                        .await?;
+                        let output_ok = crate::api::get_preferred_script().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1628,7 +1842,7 @@ fn wire__crate__api__get_session_preview_impl(
         },
     )
 }
-fn wire__crate__api__get_sessions_since_impl(
+fn wire__crate__api__get_preferred_translator_id_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1636,7 +1850,7 @@ fn wire__crate__api__get_sessions_since_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_sessions_since",
+            debug_name: "get_preferred_translator_id",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1650,14 +1864,11 @@ fn wire__crate__api__get_sessions_since_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_user_id = <String>::sse_decode(&mut deserializer);
-            let api_since_millis = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::get_sessions_since(api_user_id, api_since_millis).await?;
+                        let output_ok = crate::api::get_preferred_translator_id().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1666,7 +1877,7 @@ fn wire__crate__api__get_sessions_since_impl(
         },
     )
 }
-fn wire__crate__api__get_surah_verses_impl(
+fn wire__crate__api__get_reciters_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1674,7 +1885,7 @@ fn wire__crate__api__get_surah_verses_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_surah_verses",
+            debug_name: "get_reciters",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1688,12 +1899,11 @@ fn wire__crate__api__get_surah_verses_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_chapter_number = <i32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::get_surah_verses(api_chapter_number).await?;
+                        let output_ok = crate::api::get_reciters().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1702,15 +1912,15 @@ fn wire__crate__api__get_surah_verses_impl(
         },
     )
 }
-fn wire__crate__api__get_telemetry_event_count_impl(
+fn wire__crate__api__get_session_items_since_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_telemetry_event_count",
+            debug_name: "get_session_items_since",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1724,19 +1934,24 @@ fn wire__crate__api__get_telemetry_event_count_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_user_id = <String>::sse_decode(&mut deserializer);
+            let api_since_millis = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
+            move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::get_telemetry_event_count()?;
+                    (move || async move {
+                        let output_ok =
+                            crate::api::get_session_items_since(api_user_id, api_since_millis)
+                                .await?;
                         Ok(output_ok)
-                    })(),
+                    })()
+                    .await,
                 )
             }
         },
     )
 }
-fn wire__crate__api__get_translator_impl(
+fn wire__crate__api__get_session_preview_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1744,7 +1959,7 @@ fn wire__crate__api__get_translator_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_translator",
+            debug_name: "get_session_preview",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1758,12 +1973,19 @@ fn wire__crate__api__get_translator_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_translator_id = <i32>::sse_decode(&mut deserializer);
+            let api_user_id = <String>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
+            let api_is_high_yield = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::get_translator(api_translator_id).await?;
+                        let output_ok = crate::api::get_session_preview(
+                            api_user_id,
+                            api_limit,
+                            api_is_high_yield,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1772,7 +1994,7 @@ fn wire__crate__api__get_translator_impl(
         },
     )
 }
-fn wire__crate__api__get_translators_for_language_impl(
+fn wire__crate__api__get_sessions_since_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1780,7 +2002,7 @@ fn wire__crate__api__get_translators_for_language_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_translators_for_language",
+            debug_name: "get_sessions_since",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1794,13 +2016,14 @@ fn wire__crate__api__get_translators_for_language_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_language_code = <String>::sse_decode(&mut deserializer);
+            let api_user_id = <String>::sse_decode(&mut deserializer);
+            let api_since_millis = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
                         let output_ok =
-                            crate::api::get_translators_for_language(api_language_code).await?;
+                            crate::api::get_sessions_since(api_user_id, api_since_millis).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1809,7 +2032,7 @@ fn wire__crate__api__get_translators_for_language_impl(
         },
     )
 }
-fn wire__crate__api__get_verse_impl(
+fn wire__crate__api__get_surah_verses_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1817,7 +2040,7 @@ fn wire__crate__api__get_verse_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_verse",
+            debug_name: "get_surah_verses",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1831,12 +2054,12 @@ fn wire__crate__api__get_verse_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_verse_key = <String>::sse_decode(&mut deserializer);
+            let api_chapter_number = <i32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::get_verse(api_verse_key).await?;
+                        let output_ok = crate::api::get_surah_verses(api_chapter_number).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1845,7 +2068,7 @@ fn wire__crate__api__get_verse_impl(
         },
     )
 }
-fn wire__crate__api__get_verse_translation_by_translator_impl(
+fn wire__crate__api__get_tafsir_passage_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1853,7 +2076,7 @@ fn wire__crate__api__get_verse_translation_by_translator_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_verse_translation_by_translator",
+            debug_name: "get_tafsir_passage",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1868,16 +2091,13 @@ fn wire__crate__api__get_verse_translation_by_translator_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_verse_key = <String>::sse_decode(&mut deserializer);
-            let api_translator_id = <i32>::sse_decode(&mut deserializer);
+            let api_tafsir_id = <i32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::get_verse_translation_by_translator(
-                            api_verse_key,
-                            api_translator_id,
-                        )
-                        .await?;
+                        let output_ok =
+                            crate::api::get_tafsir_passage(api_verse_key, api_tafsir_id).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1886,7 +2106,7 @@ fn wire__crate__api__get_verse_translation_by_translator_impl(
         },
     )
 }
-fn wire__crate__api__get_word_impl(
+fn wire__crate__api__get_tafsirs_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1894,7 +2114,7 @@ fn wire__crate__api__get_word_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_word",
+            debug_name: "get_tafsirs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1908,12 +2128,11 @@ fn wire__crate__api__get_word_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_word_id = <i32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::get_word(api_word_id).await?;
+                        let output_ok = crate::api::get_tafsirs().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1922,7 +2141,7 @@ fn wire__crate__api__get_word_impl(
         },
     )
 }
-fn wire__crate__api__get_word_at_position_impl(
+fn wire__crate__api__get_tajweed_annotations_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1930,7 +2149,7 @@ fn wire__crate__api__get_word_at_position_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_word_at_position",
+            debug_name: "get_tajweed_annotations",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1944,16 +2163,12 @@ fn wire__crate__api__get_word_at_position_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_chapter = <i32>::sse_decode(&mut deserializer);
-            let api_verse = <i32>::sse_decode(&mut deserializer);
-            let api_position = <i32>::sse_decode(&mut deserializer);
+            let api_verse_key = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::get_word_at_position(api_chapter, api_verse, api_position)
-                                .await?;
+                        let output_ok = crate::api::get_tajweed_annotations(api_verse_key).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1962,15 +2177,15 @@ fn wire__crate__api__get_word_at_position_impl(
         },
     )
 }
-fn wire__crate__api__get_word_translation_impl(
+fn wire__crate__api__get_telemetry_event_count_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_word_translation",
+            debug_name: "get_telemetry_event_count",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1984,24 +2199,19 @@ fn wire__crate__api__get_word_translation_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_word_id = <i32>::sse_decode(&mut deserializer);
-            let api_translator_id = <i32>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
+            move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || async move {
-                        let output_ok =
-                            crate::api::get_word_translation(api_word_id, api_translator_id)
-                                .await?;
+                    (move || {
+                        let output_ok = crate::api::get_telemetry_event_count()?;
                         Ok(output_ok)
-                    })()
-                    .await,
+                    })(),
                 )
             }
         },
     )
 }
-fn wire__crate__api__get_words_for_verse_impl(
+fn wire__crate__api__get_translator_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2009,7 +2219,7 @@ fn wire__crate__api__get_words_for_verse_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_words_for_verse",
+            debug_name: "get_translator",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2023,12 +2233,12 @@ fn wire__crate__api__get_words_for_verse_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_verse_key = <String>::sse_decode(&mut deserializer);
+            let api_translator_id = <i32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::get_words_for_verse(api_verse_key).await?;
+                        let output_ok = crate::api::get_translator(api_translator_id).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2037,15 +2247,15 @@ fn wire__crate__api__get_words_for_verse_impl(
         },
     )
 }
-fn wire__crate__api__init_app_impl(
+fn wire__crate__api__get_translators_for_language_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_app",
+            debug_name: "get_translators_for_language",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2059,19 +2269,22 @@ fn wire__crate__api__init_app_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_language_code = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::init_app();
-                    })?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::get_translators_for_language(api_language_code).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__install_translation_pack_from_bytes_impl(
+fn wire__crate__api__get_verse_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2079,7 +2292,7 @@ fn wire__crate__api__install_translation_pack_from_bytes_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "install_translation_pack_from_bytes",
+            debug_name: "get_verse",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2093,17 +2306,12 @@ fn wire__crate__api__install_translation_pack_from_bytes_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_package_id = <String>::sse_decode(&mut deserializer);
-            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_verse_key = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::install_translation_pack_from_bytes(
-                            api_package_id,
-                            api_bytes,
-                        )
-                        .await?;
+                        let output_ok = crate::api::get_verse(api_verse_key).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2112,15 +2320,15 @@ fn wire__crate__api__install_translation_pack_from_bytes_impl(
         },
     )
 }
-fn wire__crate__api__parse_node_range_impl(
+fn wire__crate__api__get_verse_audio_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "parse_node_range",
+            debug_name: "get_verse_audio",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2134,20 +2342,23 @@ fn wire__crate__api__parse_node_range_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_range = <String>::sse_decode(&mut deserializer);
+            let api_verse_key = <String>::sse_decode(&mut deserializer);
+            let api_reciter_id = <i32>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
+            move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::parse_node_range(api_range)?;
+                    (move || async move {
+                        let output_ok =
+                            crate::api::get_verse_audio(api_verse_key, api_reciter_id).await?;
                         Ok(output_ok)
-                    })(),
+                    })()
+                    .await,
                 )
             }
         },
     )
 }
-fn wire__crate__api__process_review_impl(
+fn wire__crate__api__get_verse_translation_by_translator_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2155,7 +2366,7 @@ fn wire__crate__api__process_review_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "process_review",
+            debug_name: "get_verse_translation_by_translator",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2169,15 +2380,17 @@ fn wire__crate__api__process_review_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_user_id = <String>::sse_decode(&mut deserializer);
-            let api_node_id = <String>::sse_decode(&mut deserializer);
-            let api_grade = <u8>::sse_decode(&mut deserializer);
+            let api_verse_key = <String>::sse_decode(&mut deserializer);
+            let api_translator_id = <i32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::process_review(api_user_id, api_node_id, api_grade).await?;
+                        let output_ok = crate::api::get_verse_translation_by_translator(
+                            api_verse_key,
+                            api_translator_id,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2186,7 +2399,7 @@ fn wire__crate__api__process_review_impl(
         },
     )
 }
-fn wire__crate__api__query_nodes_filtered_impl(
+fn wire__crate__api__get_word_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2194,7 +2407,7 @@ fn wire__crate__api__query_nodes_filtered_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "query_nodes_filtered",
+            debug_name: "get_word",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2208,16 +2421,12 @@ fn wire__crate__api__query_nodes_filtered_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_user_id = <String>::sse_decode(&mut deserializer);
-            let api_filter = <crate::api::NodeFilterDto>::sse_decode(&mut deserializer);
-            let api_limit = <u32>::sse_decode(&mut deserializer);
+            let api_word_id = <i32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::query_nodes_filtered(api_user_id, api_filter, api_limit)
-                                .await?;
+                        let output_ok = crate::api::get_word(api_word_id).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2226,7 +2435,7 @@ fn wire__crate__api__query_nodes_filtered_impl(
         },
     )
 }
-fn wire__crate__api__reseed_database_impl(
+fn wire__crate__api__get_word_at_position_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2234,7 +2443,7 @@ fn wire__crate__api__reseed_database_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "reseed_database",
+            debug_name: "get_word_at_position",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2248,12 +2457,16 @@ fn wire__crate__api__reseed_database_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_user_id = <String>::sse_decode(&mut deserializer);
+            let api_chapter = <i32>::sse_decode(&mut deserializer);
+            let api_verse = <i32>::sse_decode(&mut deserializer);
+            let api_position = <i32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::reseed_database(api_user_id).await?;
+                        let output_ok =
+                            crate::api::get_word_at_position(api_chapter, api_verse, api_position)
+                                .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2262,7 +2475,7 @@ fn wire__crate__api__reseed_database_impl(
         },
     )
 }
-fn wire__crate__api__search_nodes_impl(
+fn wire__crate__api__get_word_audio_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2270,7 +2483,7 @@ fn wire__crate__api__search_nodes_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "search_nodes",
+            debug_name: "get_word_audio",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2284,13 +2497,16 @@ fn wire__crate__api__search_nodes_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_query = <String>::sse_decode(&mut deserializer);
-            let api_limit = <u32>::sse_decode(&mut deserializer);
+            let api_verse_key = <String>::sse_decode(&mut deserializer);
+            let api_position = <i32>::sse_decode(&mut deserializer);
+            let api_reciter_id = <i32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::search_nodes(api_query, api_limit).await?;
+                        let output_ok =
+                            crate::api::get_word_audio(api_verse_key, api_position, api_reciter_id)
+                                .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2299,7 +2515,7 @@ fn wire__crate__api__search_nodes_impl(
         },
     )
 }
-fn wire__crate__api__set_last_sync_timestamp_impl(
+fn wire__crate__api__get_word_translation_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2307,7 +2523,7 @@ fn wire__crate__api__set_last_sync_timestamp_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_last_sync_timestamp",
+            debug_name: "get_word_translation",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2321,14 +2537,15 @@ fn wire__crate__api__set_last_sync_timestamp_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_user_id = <String>::sse_decode(&mut deserializer);
-            let api_timestamp = <i64>::sse_decode(&mut deserializer);
+            let api_word_id = <i32>::sse_decode(&mut deserializer);
+            let api_translator_id = <i32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
                         let output_ok =
-                            crate::api::set_last_sync_timestamp(api_user_id, api_timestamp).await?;
+                            crate::api::get_word_translation(api_word_id, api_translator_id)
+                                .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2337,7 +2554,7 @@ fn wire__crate__api__set_last_sync_timestamp_impl(
         },
     )
 }
-fn wire__crate__api__set_preferred_translator_id_impl(
+fn wire__crate__api__get_words_for_verse_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2345,7 +2562,7 @@ fn wire__crate__api__set_preferred_translator_id_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_preferred_translator_id",
+            debug_name: "get_words_for_verse",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2359,13 +2576,12 @@ fn wire__crate__api__set_preferred_translator_id_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_translator_id = <i32>::sse_decode(&mut deserializer);
+            let api_verse_key = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::set_preferred_translator_id(api_translator_id).await?;
+                        let output_ok = crate::api::get_words_for_verse(api_verse_key).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2374,7 +2590,7 @@ fn wire__crate__api__set_preferred_translator_id_impl(
         },
     )
 }
-fn wire__crate__api__setup_database_impl(
+fn wire__crate__api__grade_recitation_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2382,7 +2598,7 @@ fn wire__crate__api__setup_database_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "setup_database",
+            debug_name: "grade_recitation",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2396,17 +2612,18 @@ fn wire__crate__api__setup_database_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_content_db_path = <String>::sse_decode(&mut deserializer);
-            let api_user_db_path = <String>::sse_decode(&mut deserializer);
-            let api_kg_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_user_id = <String>::sse_decode(&mut deserializer);
+            let api_verse_keys = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_transcript =
+                <Vec<crate::api::TranscriptWordDto>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::setup_database(
-                            api_content_db_path,
-                            api_user_db_path,
-                            api_kg_bytes,
+                        let output_ok = crate::api::grade_recitation(
+                            api_user_id,
+                            api_verse_keys,
+                            api_transcript,
                         )
                         .await?;
                         Ok(output_ok)
@@ -2417,15 +2634,15 @@ fn wire__crate__api__setup_database_impl(
         },
     )
 }
-fn wire__crate__api__setup_database_in_memory_impl(
+fn wire__crate__api__init_app_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "setup_database_in_memory",
+            debug_name: "init_app",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2439,29 +2656,27 @@ fn wire__crate__api__setup_database_in_memory_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_kg_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || async move {
-                        let output_ok = crate::api::setup_database_in_memory(api_kg_bytes).await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::init_app();
+                    })?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__simulate_propagation_impl(
+fn wire__crate__api__init_semantic_model_from_bytes_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "simulate_propagation",
+            debug_name: "init_semantic_model_from_bytes",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2475,36 +2690,34 @@ fn wire__crate__api__simulate_propagation_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_user_id = <String>::sse_decode(&mut deserializer);
-            let api_node_id = <String>::sse_decode(&mut deserializer);
-            let api_energy_delta = <f64>::sse_decode(&mut deserializer);
+            let api_tokenizer_json = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_model_safetensors = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_staging_dir = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
+            move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || async move {
-                        let output_ok = crate::api::simulate_propagation(
-                            api_user_id,
-                            api_node_id,
-                            api_energy_delta,
-                        )
-                        .await?;
+                    (move || {
+                        let output_ok = crate::api::init_semantic_model_from_bytes(
+                            api_tokenizer_json,
+                            api_model_safetensors,
+                            api_staging_dir,
+                        )?;
                         Ok(output_ok)
-                    })()
-                    .await,
+                    })(),
                 )
             }
         },
     )
 }
-fn wire__crate__api__start_echo_recall_impl(
+fn wire__crate__api__init_semantic_model_from_dir_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "start_echo_recall",
+            debug_name: "init_semantic_model_from_dir",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2518,14 +2731,1009 @@ fn wire__crate__api__start_echo_recall_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_user_id = <String>::sse_decode(&mut deserializer);
-            let api_ayah_node_ids = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_model_dir = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::init_semantic_model_from_dir(api_model_dir)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__install_package_from_file_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "install_package_from_file",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_package_id = <String>::sse_decode(&mut deserializer);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_audio_dir = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::install_package_from_file(
+                            api_package_id,
+                            api_path,
+                            api_audio_dir,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__install_translation_pack_from_bytes_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "install_translation_pack_from_bytes",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_package_id = <String>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::install_translation_pack_from_bytes(
+                            api_package_id,
+                            api_bytes,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__is_semantic_model_loaded_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_semantic_model_loaded",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::is_semantic_model_loaded())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__parse_node_range_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_node_range",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_range = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::parse_node_range(api_range)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__process_review_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "process_review",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_user_id = <String>::sse_decode(&mut deserializer);
+            let api_node_id = <String>::sse_decode(&mut deserializer);
+            let api_grade = <u8>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::process_review(api_user_id, api_node_id, api_grade).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__query_nodes_filtered_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "query_nodes_filtered",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_user_id = <String>::sse_decode(&mut deserializer);
+            let api_filter = <crate::api::NodeFilterDto>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::query_nodes_filtered(api_user_id, api_filter, api_limit)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__regenerate_exercise_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "regenerate_exercise",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_node_id = <String>::sse_decode(&mut deserializer);
+            let api_exercise_type = <String>::sse_decode(&mut deserializer);
+            let api_seed = <u64>::sse_decode(&mut deserializer);
+            let api_distractor_strategy =
+                <Option<crate::api::DistractorStrategyDto>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::regenerate_exercise(
+                            api_node_id,
+                            api_exercise_type,
+                            api_seed,
+                            api_distractor_strategy,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__reseed_database_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reseed_database",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_user_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::reseed_database(api_user_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__reset_nodes_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reset_nodes",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_user_id = <String>::sse_decode(&mut deserializer);
+            let api_scope = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::reset_nodes(api_user_id, api_scope).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__resume_session_ayah_chain_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "resume_session_ayah_chain",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_session_id = <String>::sse_decode(&mut deserializer);
+            let api_position = <i32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::resume_session_ayah_chain(api_session_id, api_position)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__resume_session_echo_recall_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "resume_session_echo_recall",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_session_id = <String>::sse_decode(&mut deserializer);
+            let api_position = <i32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::resume_session_echo_recall(api_session_id, api_position)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__search_nodes_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_nodes",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::search_nodes(api_query, api_limit).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__set_arabic_level_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_arabic_level",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_level = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::set_arabic_level(api_level).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__set_last_sync_timestamp_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_last_sync_timestamp",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_user_id = <String>::sse_decode(&mut deserializer);
+            let api_timestamp = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::set_last_sync_timestamp(api_user_id, api_timestamp).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__set_preferred_script_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_preferred_script",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_script = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::set_preferred_script(api_script).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__set_preferred_translator_id_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_preferred_translator_id",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_translator_id = <i32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::set_preferred_translator_id(api_translator_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__setup_database_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "setup_database",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_content_db_path = <String>::sse_decode(&mut deserializer);
+            let api_user_db_path = <String>::sse_decode(&mut deserializer);
+            let api_kg_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::setup_database(
+                            api_content_db_path,
+                            api_user_db_path,
+                            api_kg_bytes,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__setup_database_in_memory_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "setup_database_in_memory",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_kg_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::setup_database_in_memory(api_kg_bytes).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simulate_propagation_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "simulate_propagation",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_user_id = <String>::sse_decode(&mut deserializer);
+            let api_node_id = <String>::sse_decode(&mut deserializer);
+            let api_energy_delta = <f64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::simulate_propagation(
+                            api_user_id,
+                            api_node_id,
+                            api_energy_delta,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__start_echo_recall_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_echo_recall",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_user_id = <String>::sse_decode(&mut deserializer);
+            let api_ayah_node_ids = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::start_echo_recall(api_user_id, api_ayah_node_ids).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__start_session_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_session",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_user_id = <String>::sse_decode(&mut deserializer);
+            let api_goal_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::start_session(api_user_id, api_goal_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__submit_echo_recall_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "submit_echo_recall",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_user_id = <String>::sse_decode(&mut deserializer);
+            let api_ayah_node_ids = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_state = <crate::api::EchoRecallStateDto>::sse_decode(&mut deserializer);
+            let api_word_node_id = <String>::sse_decode(&mut deserializer);
+            let api_recall_time_ms = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::submit_echo_recall(
+                            api_user_id,
+                            api_ayah_node_ids,
+                            api_state,
+                            api_word_node_id,
+                            api_recall_time_ms,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__submit_session_answer_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "submit_session_answer",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_session_id = <String>::sse_decode(&mut deserializer);
+            let api_answer_json = <String>::sse_decode(&mut deserializer);
+            let api_duration_ms = <u64>::sse_decode(&mut deserializer);
+            let api_hint_level = <u8>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::submit_session_answer(
+                            api_session_id,
+                            api_answer_json,
+                            api_duration_ms,
+                            api_hint_level,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__submit_session_ayah_chain_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "submit_session_ayah_chain",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_session_id = <String>::sse_decode(&mut deserializer);
+            let api_position = <i32>::sse_decode(&mut deserializer);
+            let api_user_id = <String>::sse_decode(&mut deserializer);
+            let api_chapter_node_id = <String>::sse_decode(&mut deserializer);
+            let api_user_input = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::submit_session_ayah_chain(
+                            api_session_id,
+                            api_position,
+                            api_user_id,
+                            api_chapter_node_id,
+                            api_user_input,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__submit_session_echo_recall_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "submit_session_echo_recall",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_session_id = <String>::sse_decode(&mut deserializer);
+            let api_position = <i32>::sse_decode(&mut deserializer);
+            let api_user_id = <String>::sse_decode(&mut deserializer);
+            let api_ayah_node_ids = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_state = <crate::api::EchoRecallStateDto>::sse_decode(&mut deserializer);
+            let api_word_node_id = <String>::sse_decode(&mut deserializer);
+            let api_recall_time_ms = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::start_echo_recall(api_user_id, api_ayah_node_ids).await?;
+                        let output_ok = crate::api::submit_session_echo_recall(
+                            api_session_id,
+                            api_position,
+                            api_user_id,
+                            api_ayah_node_ids,
+                            api_state,
+                            api_word_node_id,
+                            api_recall_time_ms,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2534,7 +3742,7 @@ fn wire__crate__api__start_echo_recall_impl(
         },
     )
 }
-fn wire__crate__api__start_session_impl(
+fn wire__crate__api__submit_session_item_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2542,7 +3750,7 @@ fn wire__crate__api__start_session_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "start_session",
+            debug_name: "submit_session_item",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2556,13 +3764,21 @@ fn wire__crate__api__start_session_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_user_id = <String>::sse_decode(&mut deserializer);
-            let api_goal_id = <String>::sse_decode(&mut deserializer);
+            let api_session_id = <String>::sse_decode(&mut deserializer);
+            let api_is_correct = <bool>::sse_decode(&mut deserializer);
+            let api_duration_ms = <u64>::sse_decode(&mut deserializer);
+            let api_hint_level = <u8>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::start_session(api_user_id, api_goal_id).await?;
+                        let output_ok = crate::api::submit_session_item(
+                            api_session_id,
+                            api_is_correct,
+                            api_duration_ms,
+                            api_hint_level,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2571,7 +3787,7 @@ fn wire__crate__api__start_session_impl(
         },
     )
 }
-fn wire__crate__api__submit_echo_recall_impl(
+fn wire__crate__api__suspend_nodes_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2579,7 +3795,7 @@ fn wire__crate__api__submit_echo_recall_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "submit_echo_recall",
+            debug_name: "suspend_nodes",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2594,22 +3810,12 @@ fn wire__crate__api__submit_echo_recall_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_user_id = <String>::sse_decode(&mut deserializer);
-            let api_ayah_node_ids = <Vec<String>>::sse_decode(&mut deserializer);
-            let api_state = <crate::api::EchoRecallStateDto>::sse_decode(&mut deserializer);
-            let api_word_node_id = <String>::sse_decode(&mut deserializer);
-            let api_recall_time_ms = <u32>::sse_decode(&mut deserializer);
+            let api_scope = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::submit_echo_recall(
-                            api_user_id,
-                            api_ayah_node_ids,
-                            api_state,
-                            api_word_node_id,
-                            api_recall_time_ms,
-                        )
-                        .await?;
+                        let output_ok = crate::api::suspend_nodes(api_user_id, api_scope).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2618,7 +3824,7 @@ fn wire__crate__api__submit_echo_recall_impl(
         },
     )
 }
-fn wire__crate__api__submit_session_item_impl(
+fn wire__crate__api__unsuspend_nodes_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2626,7 +3832,7 @@ fn wire__crate__api__submit_session_item_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "submit_session_item",
+            debug_name: "unsuspend_nodes",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2640,23 +3846,13 @@ fn wire__crate__api__submit_session_item_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_session_id = <String>::sse_decode(&mut deserializer);
-            let api_node_id = <String>::sse_decode(&mut deserializer);
-            let api_exercise_type = <String>::sse_decode(&mut deserializer);
-            let api_grade = <u8>::sse_decode(&mut deserializer);
-            let api_duration_ms = <u64>::sse_decode(&mut deserializer);
+            let api_user_id = <String>::sse_decode(&mut deserializer);
+            let api_scope = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::submit_session_item(
-                            api_session_id,
-                            api_node_id,
-                            api_exercise_type,
-                            api_grade,
-                            api_duration_ms,
-                        )
-                        .await?;
+                        let output_ok = crate::api::unsuspend_nodes(api_user_id, api_scope).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2966,6 +4162,46 @@ impl SseDecode for crate::api::ActivityPointDto {
     }
 }
 
+impl SseDecode for crate::api::AyahChainStateDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_nodeId = <String>::sse_decode(deserializer);
+        let mut var_currentVerseKey = <Option<String>>::sse_decode(deserializer);
+        let mut var_completedCount = <u32>::sse_decode(deserializer);
+        let mut var_totalVerses = <u32>::sse_decode(deserializer);
+        let mut var_isComplete = <bool>::sse_decode(deserializer);
+        let mut var_mistakeMade = <bool>::sse_decode(deserializer);
+        let mut var_lastGrade = <Option<u8>>::sse_decode(deserializer);
+        return crate::api::AyahChainStateDto {
+            node_id: var_nodeId,
+            current_verse_key: var_currentVerseKey,
+            completed_count: var_completedCount,
+            total_verses: var_totalVerses,
+            is_complete: var_isComplete,
+            mistake_made: var_mistakeMade,
+            last_grade: var_lastGrade,
+        };
+    }
+}
+
+impl SseDecode for crate::api::AyahJunctionDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_fromVerse = <String>::sse_decode(deserializer);
+        let mut var_toVerse = <String>::sse_decode(deserializer);
+        let mut var_closingWord = <String>::sse_decode(deserializer);
+        let mut var_openingWord = <String>::sse_decode(deserializer);
+        let mut var_strength = <f64>::sse_decode(deserializer);
+        return crate::api::AyahJunctionDto {
+            from_verse: var_fromVerse,
+            to_verse: var_toVerse,
+            closing_word: var_closingWord,
+            opening_word: var_openingWord,
+            strength: var_strength,
+        };
+    }
+}
+
 impl SseDecode for bool {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -3093,6 +4329,18 @@ impl SseDecode for crate::api::DetailedStatsDto {
     }
 }
 
+impl SseDecode for crate::api::DistractorStrategyDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_difficulty = <String>::sse_decode(deserializer);
+        let mut var_signals = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::DistractorStrategyDto {
+            difficulty: var_difficulty,
+            signals: var_signals,
+        };
+    }
+}
+
 impl SseDecode for crate::api::EchoRecallMetricsDto {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -3202,182 +4450,553 @@ impl SseDecode for crate::api::ExerciseDataDto {
         match tag_ {
             0 => {
                 let mut var_nodeId = <String>::sse_decode(deserializer);
+                let mut var_seed = <u64>::sse_decode(deserializer);
                 return crate::api::ExerciseDataDto::Memorization {
                     node_id: var_nodeId,
+                    seed: var_seed,
                 };
             }
             1 => {
                 let mut var_nodeId = <String>::sse_decode(deserializer);
                 let mut var_distractorNodeIds = <Vec<String>>::sse_decode(deserializer);
+                let mut var_distractorStrategy =
+                    <crate::api::DistractorStrategyDto>::sse_decode(deserializer);
+                let mut var_seed = <u64>::sse_decode(deserializer);
                 return crate::api::ExerciseDataDto::McqArToEn {
                     node_id: var_nodeId,
                     distractor_node_ids: var_distractorNodeIds,
+                    distractor_strategy: var_distractorStrategy,
+                    seed: var_seed,
                 };
             }
             2 => {
                 let mut var_nodeId = <String>::sse_decode(deserializer);
                 let mut var_distractorNodeIds = <Vec<String>>::sse_decode(deserializer);
+                let mut var_distractorStrategy =
+                    <crate::api::DistractorStrategyDto>::sse_decode(deserializer);
+                let mut var_seed = <u64>::sse_decode(deserializer);
                 return crate::api::ExerciseDataDto::McqEnToAr {
                     node_id: var_nodeId,
                     distractor_node_ids: var_distractorNodeIds,
+                    distractor_strategy: var_distractorStrategy,
+                    seed: var_seed,
                 };
             }
             3 => {
                 let mut var_nodeId = <String>::sse_decode(deserializer);
+                let mut var_seed = <u64>::sse_decode(deserializer);
                 return crate::api::ExerciseDataDto::Translation {
                     node_id: var_nodeId,
+                    seed: var_seed,
                 };
             }
             4 => {
                 let mut var_nodeId = <String>::sse_decode(deserializer);
                 let mut var_verseKey = <String>::sse_decode(deserializer);
+                let mut var_seed = <u64>::sse_decode(deserializer);
                 return crate::api::ExerciseDataDto::ContextualTranslation {
                     node_id: var_nodeId,
                     verse_key: var_verseKey,
+                    seed: var_seed,
                 };
             }
             5 => {
                 let mut var_nodeId = <String>::sse_decode(deserializer);
                 let mut var_blankPosition = <i32>::sse_decode(deserializer);
+                let mut var_seed = <u64>::sse_decode(deserializer);
                 return crate::api::ExerciseDataDto::ClozeDeletion {
                     node_id: var_nodeId,
                     blank_position: var_blankPosition,
+                    seed: var_seed,
                 };
             }
             6 => {
                 let mut var_nodeId = <String>::sse_decode(deserializer);
                 let mut var_wordPosition = <i32>::sse_decode(deserializer);
+                let mut var_seed = <u64>::sse_decode(deserializer);
                 return crate::api::ExerciseDataDto::FirstLetterHint {
                     node_id: var_nodeId,
                     word_position: var_wordPosition,
+                    seed: var_seed,
                 };
             }
             7 => {
                 let mut var_nodeId = <String>::sse_decode(deserializer);
                 let mut var_blankPosition = <i32>::sse_decode(deserializer);
                 let mut var_distractorNodeIds = <Vec<String>>::sse_decode(deserializer);
+                let mut var_distractorStrategy =
+                    <crate::api::DistractorStrategyDto>::sse_decode(deserializer);
+                let mut var_seed = <u64>::sse_decode(deserializer);
                 return crate::api::ExerciseDataDto::MissingWordMcq {
                     node_id: var_nodeId,
                     blank_position: var_blankPosition,
                     distractor_node_ids: var_distractorNodeIds,
+                    distractor_strategy: var_distractorStrategy,
+                    seed: var_seed,
                 };
             }
             8 => {
                 let mut var_nodeId = <String>::sse_decode(deserializer);
                 let mut var_contextPosition = <i32>::sse_decode(deserializer);
                 let mut var_distractorNodeIds = <Vec<String>>::sse_decode(deserializer);
+                let mut var_distractorStrategy =
+                    <crate::api::DistractorStrategyDto>::sse_decode(deserializer);
+                let mut var_seed = <u64>::sse_decode(deserializer);
                 return crate::api::ExerciseDataDto::NextWordMcq {
                     node_id: var_nodeId,
                     context_position: var_contextPosition,
                     distractor_node_ids: var_distractorNodeIds,
+                    distractor_strategy: var_distractorStrategy,
+                    seed: var_seed,
+                };
+            }
+            9 => {
+                let mut var_nodeId = <String>::sse_decode(deserializer);
+                let mut var_seed = <u64>::sse_decode(deserializer);
+                return crate::api::ExerciseDataDto::FullVerseInput {
+                    node_id: var_nodeId,
+                    seed: var_seed,
+                };
+            }
+            10 => {
+                let mut var_nodeId = <String>::sse_decode(deserializer);
+                let mut var_verseKeys = <Vec<String>>::sse_decode(deserializer);
+                let mut var_currentIndex = <usize>::sse_decode(deserializer);
+                let mut var_completedCount = <usize>::sse_decode(deserializer);
+                let mut var_seed = <u64>::sse_decode(deserializer);
+                return crate::api::ExerciseDataDto::AyahChain {
+                    node_id: var_nodeId,
+                    verse_keys: var_verseKeys,
+                    current_index: var_currentIndex,
+                    completed_count: var_completedCount,
+                    seed: var_seed,
+                };
+            }
+            11 => {
+                let mut var_nodeId = <String>::sse_decode(deserializer);
+                let mut var_mistakePosition = <i32>::sse_decode(deserializer);
+                let mut var_correctWordNodeId = <String>::sse_decode(deserializer);
+                let mut var_incorrectWordNodeId = <String>::sse_decode(deserializer);
+                let mut var_seed = <u64>::sse_decode(deserializer);
+                return crate::api::ExerciseDataDto::FindMistake {
+                    node_id: var_nodeId,
+                    mistake_position: var_mistakePosition,
+                    correct_word_node_id: var_correctWordNodeId,
+                    incorrect_word_node_id: var_incorrectWordNodeId,
+                    seed: var_seed,
+                };
+            }
+            12 => {
+                let mut var_nodeId = <String>::sse_decode(deserializer);
+                let mut var_correctSequence = <Vec<String>>::sse_decode(deserializer);
+                let mut var_seed = <u64>::sse_decode(deserializer);
+                return crate::api::ExerciseDataDto::AyahSequence {
+                    node_id: var_nodeId,
+                    correct_sequence: var_correctSequence,
+                    seed: var_seed,
+                };
+            }
+            13 => {
+                let mut var_nodeId = <String>::sse_decode(deserializer);
+                let mut var_correctSequence = <Vec<String>>::sse_decode(deserializer);
+                let mut var_options = <Vec<Vec<String>>>::sse_decode(deserializer);
+                let mut var_seed = <u64>::sse_decode(deserializer);
+                return crate::api::ExerciseDataDto::SequenceRecall {
+                    node_id: var_nodeId,
+                    correct_sequence: var_correctSequence,
+                    options: var_options,
+                    seed: var_seed,
+                };
+            }
+            14 => {
+                let mut var_nodeId = <String>::sse_decode(deserializer);
+                let mut var_verseKey = <String>::sse_decode(deserializer);
+                let mut var_seed = <u64>::sse_decode(deserializer);
+                return crate::api::ExerciseDataDto::FirstWordRecall {
+                    node_id: var_nodeId,
+                    verse_key: var_verseKey,
+                    seed: var_seed,
+                };
+            }
+            15 => {
+                let mut var_nodeId = <String>::sse_decode(deserializer);
+                let mut var_root = <String>::sse_decode(deserializer);
+                let mut var_seed = <u64>::sse_decode(deserializer);
+                return crate::api::ExerciseDataDto::IdentifyRoot {
+                    node_id: var_nodeId,
+                    root: var_root,
+                    seed: var_seed,
+                };
+            }
+            16 => {
+                let mut var_nodeId = <String>::sse_decode(deserializer);
+                let mut var_blankPosition = <i32>::sse_decode(deserializer);
+                let mut var_seed = <u64>::sse_decode(deserializer);
+                return crate::api::ExerciseDataDto::ReverseCloze {
+                    node_id: var_nodeId,
+                    blank_position: var_blankPosition,
+                    seed: var_seed,
+                };
+            }
+            17 => {
+                let mut var_nodeId = <String>::sse_decode(deserializer);
+                let mut var_translatorId = <i32>::sse_decode(deserializer);
+                let mut var_seed = <u64>::sse_decode(deserializer);
+                return crate::api::ExerciseDataDto::TranslatePhrase {
+                    node_id: var_nodeId,
+                    translator_id: var_translatorId,
+                    seed: var_seed,
+                };
+            }
+            18 => {
+                let mut var_nodeId = <String>::sse_decode(deserializer);
+                let mut var_correctPos = <String>::sse_decode(deserializer);
+                let mut var_options = <Vec<String>>::sse_decode(deserializer);
+                let mut var_seed = <u64>::sse_decode(deserializer);
+                return crate::api::ExerciseDataDto::PosTagging {
+                    node_id: var_nodeId,
+                    correct_pos: var_correctPos,
+                    options: var_options,
+                    seed: var_seed,
+                };
+            }
+            19 => {
+                let mut var_nodeId = <String>::sse_decode(deserializer);
+                let mut var_feature = <String>::sse_decode(deserializer);
+                let mut var_prompt = <String>::sse_decode(deserializer);
+                let mut var_correctValue = <String>::sse_decode(deserializer);
+                let mut var_options = <Vec<String>>::sse_decode(deserializer);
+                let mut var_seed = <u64>::sse_decode(deserializer);
+                return crate::api::ExerciseDataDto::GrammarFeature {
+                    node_id: var_nodeId,
+                    feature: var_feature,
+                    prompt: var_prompt,
+                    correct_value: var_correctValue,
+                    options: var_options,
+                    seed: var_seed,
+                };
+            }
+            20 => {
+                let mut var_nodeId = <String>::sse_decode(deserializer);
+                let mut var_relatedVerseIds = <Vec<String>>::sse_decode(deserializer);
+                let mut var_connectionTheme = <String>::sse_decode(deserializer);
+                let mut var_seed = <u64>::sse_decode(deserializer);
+                return crate::api::ExerciseDataDto::CrossVerseConnection {
+                    node_id: var_nodeId,
+                    related_verse_ids: var_relatedVerseIds,
+                    connection_theme: var_connectionTheme,
+                    seed: var_seed,
+                };
+            }
+            21 => {
+                let mut var_userId = <String>::sse_decode(deserializer);
+                let mut var_ayahNodeIds = <Vec<String>>::sse_decode(deserializer);
+                let mut var_seed = <u64>::sse_decode(deserializer);
+                return crate::api::ExerciseDataDto::EchoRecall {
+                    user_id: var_userId,
+                    ayah_node_ids: var_ayahNodeIds,
+                    seed: var_seed,
+                };
+            }
+            22 => {
+                let mut var_nodeId = <String>::sse_decode(deserializer);
+                let mut var_verseKey = <String>::sse_decode(deserializer);
+                let mut var_promptWordCount = <i32>::sse_decode(deserializer);
+                let mut var_optionVerseKeys = <Vec<String>>::sse_decode(deserializer);
+                let mut var_differingPositions = <Vec<i32>>::sse_decode(deserializer);
+                let mut var_seed = <u64>::sse_decode(deserializer);
+                return crate::api::ExerciseDataDto::Mutashabihat {
+                    node_id: var_nodeId,
+                    verse_key: var_verseKey,
+                    prompt_word_count: var_promptWordCount,
+                    option_verse_keys: var_optionVerseKeys,
+                    differing_positions: var_differingPositions,
+                    seed: var_seed,
+                };
+            }
+            23 => {
+                let mut var_nodeId = <String>::sse_decode(deserializer);
+                let mut var_correctSequence = <Vec<String>>::sse_decode(deserializer);
+                let mut var_shuffledWordNodeIds = <Vec<String>>::sse_decode(deserializer);
+                let mut var_tileTexts = <Vec<String>>::sse_decode(deserializer);
+                let mut var_seed = <u64>::sse_decode(deserializer);
+                return crate::api::ExerciseDataDto::WordOrder {
+                    node_id: var_nodeId,
+                    correct_sequence: var_correctSequence,
+                    shuffled_word_node_ids: var_shuffledWordNodeIds,
+                    tile_texts: var_tileTexts,
+                    seed: var_seed,
+                };
+            }
+            24 => {
+                let mut var_nodeId = <String>::sse_decode(deserializer);
+                let mut var_verseKey = <String>::sse_decode(deserializer);
+                let mut var_correctChapter = <i32>::sse_decode(deserializer);
+                let mut var_chapterOptions = <Vec<i32>>::sse_decode(deserializer);
+                let mut var_seed = <u64>::sse_decode(deserializer);
+                return crate::api::ExerciseDataDto::IdentifySurah {
+                    node_id: var_nodeId,
+                    verse_key: var_verseKey,
+                    correct_chapter: var_correctChapter,
+                    chapter_options: var_chapterOptions,
+                    seed: var_seed,
+                };
+            }
+            25 => {
+                let mut var_nodeId = <String>::sse_decode(deserializer);
+                let mut var_verseKey = <String>::sse_decode(deserializer);
+                let mut var_correctVerseNumber = <i32>::sse_decode(deserializer);
+                let mut var_verseNumberOptions = <Vec<i32>>::sse_decode(deserializer);
+                let mut var_seed = <u64>::sse_decode(deserializer);
+                return crate::api::ExerciseDataDto::IdentifyVerseNumber {
+                    node_id: var_nodeId,
+                    verse_key: var_verseKey,
+                    correct_verse_number: var_correctVerseNumber,
+                    verse_number_options: var_verseNumberOptions,
+                    seed: var_seed,
+                };
+            }
+            26 => {
+                let mut var_nodeId = <String>::sse_decode(deserializer);
+                let mut var_verseKey = <String>::sse_decode(deserializer);
+                let mut var_page = <i32>::sse_decode(deserializer);
+                let mut var_half = <i32>::sse_decode(deserializer);
+                let mut var_seed = <u64>::sse_decode(deserializer);
+                return crate::api::ExerciseDataDto::IdentifyPageHalf {
+                    node_id: var_nodeId,
+                    verse_key: var_verseKey,
+                    page: var_page,
+                    half: var_half,
+                    seed: var_seed,
+                };
+            }
+            27 => {
+                let mut var_nodeId = <String>::sse_decode(deserializer);
+                let mut var_distractorNodeIds = <Vec<String>>::sse_decode(deserializer);
+                let mut var_distractorStrategy =
+                    <crate::api::DistractorStrategyDto>::sse_decode(deserializer);
+                let mut var_seed = <u64>::sse_decode(deserializer);
+                return crate::api::ExerciseDataDto::TransliterationToArabic {
+                    node_id: var_nodeId,
+                    distractor_node_ids: var_distractorNodeIds,
+                    distractor_strategy: var_distractorStrategy,
+                    seed: var_seed,
+                };
+            }
+            28 => {
+                let mut var_nodeId = <String>::sse_decode(deserializer);
+                let mut var_verseKey = <String>::sse_decode(deserializer);
+                let mut var_seed = <u64>::sse_decode(deserializer);
+                return crate::api::ExerciseDataDto::ArabicToTransliteration {
+                    node_id: var_nodeId,
+                    verse_key: var_verseKey,
+                    seed: var_seed,
+                };
+            }
+            29 => {
+                let mut var_nodeId = <String>::sse_decode(deserializer);
+                let mut var_rootId = <String>::sse_decode(deserializer);
+                let mut var_verseKeys = <Vec<String>>::sse_decode(deserializer);
+                let mut var_familyWordNodeIds = <Vec<String>>::sse_decode(deserializer);
+                let mut var_seed = <u64>::sse_decode(deserializer);
+                return crate::api::ExerciseDataDto::RootFamily {
+                    node_id: var_nodeId,
+                    root_id: var_rootId,
+                    verse_keys: var_verseKeys,
+                    family_word_node_ids: var_familyWordNodeIds,
+                    seed: var_seed,
                 };
             }
-            9 => {
+            30 => {
                 let mut var_nodeId = <String>::sse_decode(deserializer);
-                return crate::api::ExerciseDataDto::FullVerseInput {
+                let mut var_rootId = <String>::sse_decode(deserializer);
+                let mut var_lemmaIds = <Vec<String>>::sse_decode(deserializer);
+                let mut var_meaningLemmaIds = <Vec<String>>::sse_decode(deserializer);
+                let mut var_seed = <u64>::sse_decode(deserializer);
+                return crate::api::ExerciseDataDto::LemmaMeaning {
                     node_id: var_nodeId,
+                    root_id: var_rootId,
+                    lemma_ids: var_lemmaIds,
+                    meaning_lemma_ids: var_meaningLemmaIds,
+                    seed: var_seed,
                 };
             }
-            10 => {
+            31 => {
                 let mut var_nodeId = <String>::sse_decode(deserializer);
+                let mut var_page = <i32>::sse_decode(deserializer);
                 let mut var_verseKeys = <Vec<String>>::sse_decode(deserializer);
-                let mut var_currentIndex = <usize>::sse_decode(deserializer);
-                let mut var_completedCount = <usize>::sse_decode(deserializer);
-                return crate::api::ExerciseDataDto::AyahChain {
+                let mut var_firstWordNodeId = <String>::sse_decode(deserializer);
+                let mut var_lastWordNodeId = <String>::sse_decode(deserializer);
+                let mut var_seed = <u64>::sse_decode(deserializer);
+                return crate::api::ExerciseDataDto::RecitePage {
                     node_id: var_nodeId,
+                    page: var_page,
                     verse_keys: var_verseKeys,
-                    current_index: var_currentIndex,
-                    completed_count: var_completedCount,
+                    first_word_node_id: var_firstWordNodeId,
+                    last_word_node_id: var_lastWordNodeId,
+                    seed: var_seed,
                 };
             }
-            11 => {
+            32 => {
                 let mut var_nodeId = <String>::sse_decode(deserializer);
-                let mut var_mistakePosition = <i32>::sse_decode(deserializer);
-                let mut var_correctWordNodeId = <String>::sse_decode(deserializer);
-                let mut var_incorrectWordNodeId = <String>::sse_decode(deserializer);
-                return crate::api::ExerciseDataDto::FindMistake {
+                let mut var_page = <i32>::sse_decode(deserializer);
+                let mut var_firstWordNodeId = <String>::sse_decode(deserializer);
+                let mut var_distractorNodeIds = <Vec<String>>::sse_decode(deserializer);
+                let mut var_seed = <u64>::sse_decode(deserializer);
+                return crate::api::ExerciseDataDto::PageFirstWord {
                     node_id: var_nodeId,
-                    mistake_position: var_mistakePosition,
-                    correct_word_node_id: var_correctWordNodeId,
-                    incorrect_word_node_id: var_incorrectWordNodeId,
+                    page: var_page,
+                    first_word_node_id: var_firstWordNodeId,
+                    distractor_node_ids: var_distractorNodeIds,
+                    seed: var_seed,
                 };
             }
-            12 => {
+            33 => {
                 let mut var_nodeId = <String>::sse_decode(deserializer);
-                let mut var_correctSequence = <Vec<String>>::sse_decode(deserializer);
-                return crate::api::ExerciseDataDto::AyahSequence {
+                let mut var_verseKey = <String>::sse_decode(deserializer);
+                let mut var_page = <i32>::sse_decode(deserializer);
+                let mut var_correctLine = <i32>::sse_decode(deserializer);
+                let mut var_lineOptions = <Vec<i32>>::sse_decode(deserializer);
+                let mut var_seed = <u64>::sse_decode(deserializer);
+                return crate::api::ExerciseDataDto::IdentifyVerseLine {
                     node_id: var_nodeId,
-                    correct_sequence: var_correctSequence,
+                    verse_key: var_verseKey,
+                    page: var_page,
+                    correct_line: var_correctLine,
+                    line_options: var_lineOptions,
+                    seed: var_seed,
                 };
             }
-            13 => {
+            34 => {
                 let mut var_nodeId = <String>::sse_decode(deserializer);
-                let mut var_correctSequence = <Vec<String>>::sse_decode(deserializer);
-                let mut var_options = <Vec<Vec<String>>>::sse_decode(deserializer);
-                return crate::api::ExerciseDataDto::SequenceRecall {
+                let mut var_reciterId = <i32>::sse_decode(deserializer);
+                let mut var_verseKey = <String>::sse_decode(deserializer);
+                let mut var_firstPosition = <i32>::sse_decode(deserializer);
+                let mut var_lastPosition = <i32>::sse_decode(deserializer);
+                let mut var_startMs = <i64>::sse_decode(deserializer);
+                let mut var_endMs = <i64>::sse_decode(deserializer);
+                let mut var_optionVerseKeys = <Vec<String>>::sse_decode(deserializer);
+                let mut var_seed = <u64>::sse_decode(deserializer);
+                return crate::api::ExerciseDataDto::ListenIdentifyVerse {
                     node_id: var_nodeId,
-                    correct_sequence: var_correctSequence,
-                    options: var_options,
+                    reciter_id: var_reciterId,
+                    verse_key: var_verseKey,
+                    first_position: var_firstPosition,
+                    last_position: var_lastPosition,
+                    start_ms: var_startMs,
+                    end_ms: var_endMs,
+                    option_verse_keys: var_optionVerseKeys,
+                    seed: var_seed,
                 };
             }
-            14 => {
+            35 => {
                 let mut var_nodeId = <String>::sse_decode(deserializer);
+                let mut var_reciterId = <i32>::sse_decode(deserializer);
                 let mut var_verseKey = <String>::sse_decode(deserializer);
-                return crate::api::ExerciseDataDto::FirstWordRecall {
+                let mut var_firstPosition = <i32>::sse_decode(deserializer);
+                let mut var_lastPosition = <i32>::sse_decode(deserializer);
+                let mut var_startMs = <i64>::sse_decode(deserializer);
+                let mut var_endMs = <i64>::sse_decode(deserializer);
+                let mut var_distractorNodeIds = <Vec<String>>::sse_decode(deserializer);
+                let mut var_distractorStrategy =
+                    <crate::api::DistractorStrategyDto>::sse_decode(deserializer);
+                let mut var_seed = <u64>::sse_decode(deserializer);
+                return crate::api::ExerciseDataDto::ListenContinue {
                     node_id: var_nodeId,
+                    reciter_id: var_reciterId,
                     verse_key: var_verseKey,
+                    first_position: var_firstPosition,
+                    last_position: var_lastPosition,
+                    start_ms: var_startMs,
+                    end_ms: var_endMs,
+                    distractor_node_ids: var_distractorNodeIds,
+                    distractor_strategy: var_distractorStrategy,
+                    seed: var_seed,
                 };
             }
-            15 => {
+            36 => {
                 let mut var_nodeId = <String>::sse_decode(deserializer);
-                let mut var_root = <String>::sse_decode(deserializer);
-                return crate::api::ExerciseDataDto::IdentifyRoot {
+                let mut var_reciterId = <i32>::sse_decode(deserializer);
+                let mut var_verseKey = <String>::sse_decode(deserializer);
+                let mut var_position = <i32>::sse_decode(deserializer);
+                let mut var_startMs = <Option<i64>>::sse_decode(deserializer);
+                let mut var_endMs = <Option<i64>>::sse_decode(deserializer);
+                let mut var_distractorNodeIds = <Vec<String>>::sse_decode(deserializer);
+                let mut var_distractorStrategy =
+                    <crate::api::DistractorStrategyDto>::sse_decode(deserializer);
+                let mut var_seed = <u64>::sse_decode(deserializer);
+                return crate::api::ExerciseDataDto::ListenIdentifyWord {
                     node_id: var_nodeId,
-                    root: var_root,
+                    reciter_id: var_reciterId,
+                    verse_key: var_verseKey,
+                    position: var_position,
+                    start_ms: var_startMs,
+                    end_ms: var_endMs,
+                    distractor_node_ids: var_distractorNodeIds,
+                    distractor_strategy: var_distractorStrategy,
+                    seed: var_seed,
                 };
             }
-            16 => {
+            37 => {
                 let mut var_nodeId = <String>::sse_decode(deserializer);
-                let mut var_blankPosition = <i32>::sse_decode(deserializer);
-                return crate::api::ExerciseDataDto::ReverseCloze {
+                let mut var_wordNodeId = <String>::sse_decode(deserializer);
+                let mut var_startChar = <i32>::sse_decode(deserializer);
+                let mut var_endChar = <i32>::sse_decode(deserializer);
+                let mut var_correctRule = <String>::sse_decode(deserializer);
+                let mut var_ruleOptions = <Vec<String>>::sse_decode(deserializer);
+                let mut var_optionNames = <Vec<String>>::sse_decode(deserializer);
+                let mut var_seed = <u64>::sse_decode(deserializer);
+                return crate::api::ExerciseDataDto::IdentifyTajweedRule {
                     node_id: var_nodeId,
-                    blank_position: var_blankPosition,
+                    word_node_id: var_wordNodeId,
+                    start_char: var_startChar,
+                    end_char: var_endChar,
+                    correct_rule: var_correctRule,
+                    rule_options: var_ruleOptions,
+                    option_names: var_optionNames,
+                    seed: var_seed,
                 };
             }
-            17 => {
+            38 => {
                 let mut var_nodeId = <String>::sse_decode(deserializer);
-                let mut var_translatorId = <i32>::sse_decode(deserializer);
-                return crate::api::ExerciseDataDto::TranslatePhrase {
+                let mut var_rule = <String>::sse_decode(deserializer);
+                let mut var_ruleName = <String>::sse_decode(deserializer);
+                let mut var_ruleWordNodeIds = <Vec<String>>::sse_decode(deserializer);
+                let mut var_seed = <u64>::sse_decode(deserializer);
+                return crate::api::ExerciseDataDto::FindTajweedRule {
                     node_id: var_nodeId,
-                    translator_id: var_translatorId,
+                    rule: var_rule,
+                    rule_name: var_ruleName,
+                    rule_word_node_ids: var_ruleWordNodeIds,
+                    seed: var_seed,
                 };
             }
-            18 => {
+            39 => {
                 let mut var_nodeId = <String>::sse_decode(deserializer);
-                let mut var_correctPos = <String>::sse_decode(deserializer);
-                let mut var_options = <Vec<String>>::sse_decode(deserializer);
-                return crate::api::ExerciseDataDto::PosTagging {
+                let mut var_tafsirId = <i32>::sse_decode(deserializer);
+                let mut var_passageKey = <String>::sse_decode(deserializer);
+                let mut var_optionPassageKeys = <Vec<String>>::sse_decode(deserializer);
+                let mut var_seed = <u64>::sse_decode(deserializer);
+                return crate::api::ExerciseDataDto::TafsirKeyLesson {
                     node_id: var_nodeId,
-                    correct_pos: var_correctPos,
-                    options: var_options,
+                    tafsir_id: var_tafsirId,
+                    passage_key: var_passageKey,
+                    option_passage_keys: var_optionPassageKeys,
+                    seed: var_seed,
                 };
             }
-            19 => {
+            40 => {
                 let mut var_nodeId = <String>::sse_decode(deserializer);
-                let mut var_relatedVerseIds = <Vec<String>>::sse_decode(deserializer);
-                let mut var_connectionTheme = <String>::sse_decode(deserializer);
-                return crate::api::ExerciseDataDto::CrossVerseConnection {
+                let mut var_tafsirId = <i32>::sse_decode(deserializer);
+                let mut var_passageKey = <String>::sse_decode(deserializer);
+                let mut var_optionVerseKeys = <Vec<String>>::sse_decode(deserializer);
+                let mut var_seed = <u64>::sse_decode(deserializer);
+                return crate::api::ExerciseDataDto::RevelationOccasion {
                     node_id: var_nodeId,
-                    related_verse_ids: var_relatedVerseIds,
-                    connection_theme: var_connectionTheme,
-                };
-            }
-            20 => {
-                let mut var_userId = <String>::sse_decode(deserializer);
-                let mut var_ayahNodeIds = <Vec<String>>::sse_decode(deserializer);
-                return crate::api::ExerciseDataDto::EchoRecall {
-                    user_id: var_userId,
-                    ayah_node_ids: var_ayahNodeIds,
+                    tafsir_id: var_tafsirId,
+                    passage_key: var_passageKey,
+                    option_verse_keys: var_optionVerseKeys,
+                    seed: var_seed,
                 };
             }
             _ => {
@@ -3387,6 +5006,29 @@ impl SseDecode for crate::api::ExerciseDataDto {
     }
 }
 
+impl SseDecode for crate::api::ExerciseHintDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_level = <u8>::sse_decode(deserializer);
+        let mut var_levelName = <String>::sse_decode(deserializer);
+        let mut var_text = <String>::sse_decode(deserializer);
+        let mut var_maxGrade = <u8>::sse_decode(deserializer);
+        return crate::api::ExerciseHintDto {
+            level: var_level,
+            level_name: var_levelName,
+            text: var_text,
+            max_grade: var_maxGrade,
+        };
+    }
+}
+
+impl SseDecode for f32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f32::<NativeEndian>().unwrap()
+    }
+}
+
 impl SseDecode for f64 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -3476,6 +5118,18 @@ impl SseDecode for Vec<crate::api::ActivityPointDto> {
     }
 }
 
+impl SseDecode for Vec<crate::api::AyahJunctionDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::AyahJunctionDto>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<crate::api::ContentPackageDto> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -3524,6 +5178,18 @@ impl SseDecode for Vec<crate::api::ExerciseDataDto> {
     }
 }
 
+impl SseDecode for Vec<crate::api::ExerciseHintDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::ExerciseHintDto>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<crate::api::InstalledPackageDto> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -3584,6 +5250,18 @@ impl SseDecode for Vec<crate::api::NodeSearchDto> {
     }
 }
 
+impl SseDecode for Vec<i32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<i32>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -3596,6 +5274,42 @@ impl SseDecode for Vec<u8> {
     }
 }
 
+impl SseDecode for Vec<crate::api::RecitationJumpDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::RecitationJumpDto>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::RecitedWordDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::RecitedWordDto>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::ReciterDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::ReciterDto>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<(String, String)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -3668,6 +5382,42 @@ impl SseDecode for Vec<crate::api::SyncSessionItemDto> {
     }
 }
 
+impl SseDecode for Vec<crate::api::TafsirDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::TafsirDto>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::TajweedAnnotationDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::TajweedAnnotationDto>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::TranscriptWordDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::TranscriptWordDto>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<crate::api::TranslatorDto> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -3706,6 +5456,18 @@ impl SseDecode for Vec<crate::api::WordDto> {
     }
 }
 
+impl SseDecode for Vec<crate::api::WordSegmentDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::WordSegmentDto>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<crate::api::WordTimingDto> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -3733,57 +5495,128 @@ impl SseDecode for crate::api::NodeData {
     }
 }
 
-impl SseDecode for crate::api::NodeEnergyDto {
+impl SseDecode for crate::api::NodeEnergyDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_nodeId = <String>::sse_decode(deserializer);
+        let mut var_energy = <f64>::sse_decode(deserializer);
+        let mut var_edgeWeight = <f64>::sse_decode(deserializer);
+        return crate::api::NodeEnergyDto {
+            node_id: var_nodeId,
+            energy: var_energy,
+            edge_weight: var_edgeWeight,
+        };
+    }
+}
+
+impl SseDecode for crate::api::NodeFilterDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_nodeType = <Option<String>>::sse_decode(deserializer);
+        let mut var_minEnergy = <Option<f64>>::sse_decode(deserializer);
+        let mut var_maxEnergy = <Option<f64>>::sse_decode(deserializer);
+        let mut var_range = <Option<String>>::sse_decode(deserializer);
+        return crate::api::NodeFilterDto {
+            node_type: var_nodeType,
+            min_energy: var_minEnergy,
+            max_energy: var_maxEnergy,
+            range: var_range,
+        };
+    }
+}
+
+impl SseDecode for crate::api::NodeSearchDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_nodeId = <String>::sse_decode(deserializer);
+        let mut var_nodeType = <String>::sse_decode(deserializer);
+        let mut var_knowledgeAxis = <Option<String>>::sse_decode(deserializer);
+        let mut var_preview = <String>::sse_decode(deserializer);
+        return crate::api::NodeSearchDto {
+            node_id: var_nodeId,
+            node_type: var_nodeType,
+            knowledge_axis: var_knowledgeAxis,
+            preview: var_preview,
+        };
+    }
+}
+
+impl SseDecode for crate::api::NodeStatusDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_nodeId = <String>::sse_decode(deserializer);
+        let mut var_status = <String>::sse_decode(deserializer);
+        let mut var_buriedUntil = <Option<i64>>::sse_decode(deserializer);
+        return crate::api::NodeStatusDto {
+            node_id: var_nodeId,
+            status: var_status,
+            buried_until: var_buriedUntil,
+        };
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::AyahChainStateDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::AyahChainStateDto>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::DistractorStrategyDto> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_nodeId = <String>::sse_decode(deserializer);
-        let mut var_energy = <f64>::sse_decode(deserializer);
-        let mut var_edgeWeight = <f64>::sse_decode(deserializer);
-        return crate::api::NodeEnergyDto {
-            node_id: var_nodeId,
-            energy: var_energy,
-            edge_weight: var_edgeWeight,
-        };
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::DistractorStrategyDto>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
     }
 }
 
-impl SseDecode for crate::api::NodeFilterDto {
+impl SseDecode for Option<crate::api::EchoRecallStateDto> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_nodeType = <Option<String>>::sse_decode(deserializer);
-        let mut var_minEnergy = <Option<f64>>::sse_decode(deserializer);
-        let mut var_maxEnergy = <Option<f64>>::sse_decode(deserializer);
-        let mut var_range = <Option<String>>::sse_decode(deserializer);
-        return crate::api::NodeFilterDto {
-            node_type: var_nodeType,
-            min_energy: var_minEnergy,
-            max_energy: var_maxEnergy,
-            range: var_range,
-        };
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::EchoRecallStateDto>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
     }
 }
 
-impl SseDecode for crate::api::NodeSearchDto {
+impl SseDecode for Option<crate::api::ExerciseHintDto> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_nodeId = <String>::sse_decode(deserializer);
-        let mut var_nodeType = <String>::sse_decode(deserializer);
-        let mut var_knowledgeAxis = <Option<String>>::sse_decode(deserializer);
-        let mut var_preview = <String>::sse_decode(deserializer);
-        return crate::api::NodeSearchDto {
-            node_id: var_nodeId,
-            node_type: var_nodeType,
-            knowledge_axis: var_knowledgeAxis,
-            preview: var_preview,
-        };
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::ExerciseHintDto>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
     }
 }
 
-impl SseDecode for Option<String> {
+impl SseDecode for Option<f32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         if (<bool>::sse_decode(deserializer)) {
-            return Some(<String>::sse_decode(deserializer));
+            return Some(<f32>::sse_decode(deserializer));
         } else {
             return None;
         }
@@ -3867,6 +5700,17 @@ impl SseDecode for Option<crate::api::SessionItemDto> {
     }
 }
 
+impl SseDecode for Option<crate::api::TafsirPassageDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::TafsirPassageDto>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
 impl SseDecode for Option<crate::api::TranslatorDto> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -3878,6 +5722,39 @@ impl SseDecode for Option<crate::api::TranslatorDto> {
     }
 }
 
+impl SseDecode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u8>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::VerseAudioDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::VerseAudioDto>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
 impl SseDecode for Option<crate::api::VerseDto> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -3889,6 +5766,17 @@ impl SseDecode for Option<crate::api::VerseDto> {
     }
 }
 
+impl SseDecode for Option<crate::api::WordAudioDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::WordAudioDto>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
 impl SseDecode for Option<crate::api::WordDto> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -3900,6 +5788,17 @@ impl SseDecode for Option<crate::api::WordDto> {
     }
 }
 
+impl SseDecode for Option<Vec<u8>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<u8>>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
 impl SseDecode for crate::api::PropagationDiagnosticsDto {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -3930,6 +5829,90 @@ impl SseDecode for crate::api::PropagationResultDto {
     }
 }
 
+impl SseDecode for crate::api::RecitationJumpDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_verseKey = <String>::sse_decode(deserializer);
+        let mut var_startPosition = <i32>::sse_decode(deserializer);
+        let mut var_wordCount = <u32>::sse_decode(deserializer);
+        let mut var_afterWordNodeId = <Option<String>>::sse_decode(deserializer);
+        let mut var_startMs = <Option<u64>>::sse_decode(deserializer);
+        return crate::api::RecitationJumpDto {
+            verse_key: var_verseKey,
+            start_position: var_startPosition,
+            word_count: var_wordCount,
+            after_word_node_id: var_afterWordNodeId,
+            start_ms: var_startMs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::RecitationResultDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_words = <Vec<crate::api::RecitedWordDto>>::sse_decode(deserializer);
+        let mut var_jumps = <Vec<crate::api::RecitationJumpDto>>::sse_decode(deserializer);
+        let mut var_extraWords = <Vec<crate::api::TranscriptWordDto>>::sse_decode(deserializer);
+        let mut var_accuracy = <f64>::sse_decode(deserializer);
+        let mut var_grade = <u8>::sse_decode(deserializer);
+        return crate::api::RecitationResultDto {
+            words: var_words,
+            jumps: var_jumps,
+            extra_words: var_extraWords,
+            accuracy: var_accuracy,
+            grade: var_grade,
+        };
+    }
+}
+
+impl SseDecode for crate::api::RecitedWordDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_wordNodeId = <String>::sse_decode(deserializer);
+        let mut var_verseKey = <String>::sse_decode(deserializer);
+        let mut var_position = <i32>::sse_decode(deserializer);
+        let mut var_text = <String>::sse_decode(deserializer);
+        let mut var_kind = <String>::sse_decode(deserializer);
+        let mut var_heard = <Option<String>>::sse_decode(deserializer);
+        let mut var_similarity = <f64>::sse_decode(deserializer);
+        let mut var_repeated = <bool>::sse_decode(deserializer);
+        let mut var_startMs = <Option<u64>>::sse_decode(deserializer);
+        let mut var_endMs = <Option<u64>>::sse_decode(deserializer);
+        let mut var_grade = <u8>::sse_decode(deserializer);
+        return crate::api::RecitedWordDto {
+            word_node_id: var_wordNodeId,
+            verse_key: var_verseKey,
+            position: var_position,
+            text: var_text,
+            kind: var_kind,
+            heard: var_heard,
+            similarity: var_similarity,
+            repeated: var_repeated,
+            start_ms: var_startMs,
+            end_ms: var_endMs,
+            grade: var_grade,
+        };
+    }
+}
+
+impl SseDecode for crate::api::ReciterDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_reciterId = <i32>::sse_decode(deserializer);
+        let mut var_slug = <String>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_style = <Option<String>>::sse_decode(deserializer);
+        let mut var_packageId = <Option<String>>::sse_decode(deserializer);
+        return crate::api::ReciterDto {
+            reciter_id: var_reciterId,
+            slug: var_slug,
+            name: var_name,
+            style: var_style,
+            package_id: var_packageId,
+        };
+    }
+}
+
 impl SseDecode for (String, String) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -4109,6 +6092,82 @@ impl SseDecode for crate::api::SyncSessionItemDto {
     }
 }
 
+impl SseDecode for crate::api::TafsirDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_tafsirId = <i32>::sse_decode(deserializer);
+        let mut var_slug = <String>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_author = <Option<String>>::sse_decode(deserializer);
+        let mut var_languageCode = <Option<String>>::sse_decode(deserializer);
+        return crate::api::TafsirDto {
+            tafsir_id: var_tafsirId,
+            slug: var_slug,
+            name: var_name,
+            author: var_author,
+            language_code: var_languageCode,
+        };
+    }
+}
+
+impl SseDecode for crate::api::TafsirPassageDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_tafsirId = <i32>::sse_decode(deserializer);
+        let mut var_chapterNumber = <i32>::sse_decode(deserializer);
+        let mut var_startVerse = <i32>::sse_decode(deserializer);
+        let mut var_endVerse = <i32>::sse_decode(deserializer);
+        let mut var_text = <String>::sse_decode(deserializer);
+        let mut var_keyLesson = <Option<String>>::sse_decode(deserializer);
+        let mut var_revelationOccasion = <Option<String>>::sse_decode(deserializer);
+        let mut var_themes = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::TafsirPassageDto {
+            tafsir_id: var_tafsirId,
+            chapter_number: var_chapterNumber,
+            start_verse: var_startVerse,
+            end_verse: var_endVerse,
+            text: var_text,
+            key_lesson: var_keyLesson,
+            revelation_occasion: var_revelationOccasion,
+            themes: var_themes,
+        };
+    }
+}
+
+impl SseDecode for crate::api::TajweedAnnotationDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_wordNodeId = <String>::sse_decode(deserializer);
+        let mut var_position = <i32>::sse_decode(deserializer);
+        let mut var_rule = <String>::sse_decode(deserializer);
+        let mut var_ruleName = <String>::sse_decode(deserializer);
+        let mut var_startChar = <i32>::sse_decode(deserializer);
+        let mut var_endChar = <i32>::sse_decode(deserializer);
+        return crate::api::TajweedAnnotationDto {
+            word_node_id: var_wordNodeId,
+            position: var_position,
+            rule: var_rule,
+            rule_name: var_ruleName,
+            start_char: var_startChar,
+            end_char: var_endChar,
+        };
+    }
+}
+
+impl SseDecode for crate::api::TranscriptWordDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_text = <String>::sse_decode(deserializer);
+        let mut var_startMs = <Option<u64>>::sse_decode(deserializer);
+        let mut var_endMs = <Option<u64>>::sse_decode(deserializer);
+        return crate::api::TranscriptWordDto {
+            text: var_text,
+            start_ms: var_startMs,
+            end_ms: var_endMs,
+        };
+    }
+}
+
 impl SseDecode for crate::api::TranslatorDto {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -4162,6 +6221,48 @@ impl SseDecode for usize {
     }
 }
 
+impl SseDecode for crate::api::ValidationResultDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_isCorrect = <bool>::sse_decode(deserializer);
+        let mut var_feedback = <Option<String>>::sse_decode(deserializer);
+        let mut var_similarityScore = <Option<f32>>::sse_decode(deserializer);
+        let mut var_semanticGrade = <Option<String>>::sse_decode(deserializer);
+        let mut var_correctAnswer = <Option<String>>::sse_decode(deserializer);
+        let mut var_suggestedGrade = <u8>::sse_decode(deserializer);
+        return crate::api::ValidationResultDto {
+            is_correct: var_isCorrect,
+            feedback: var_feedback,
+            similarity_score: var_similarityScore,
+            semantic_grade: var_semanticGrade,
+            correct_answer: var_correctAnswer,
+            suggested_grade: var_suggestedGrade,
+        };
+    }
+}
+
+impl SseDecode for crate::api::VerseAudioDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_reciterId = <i32>::sse_decode(deserializer);
+        let mut var_verseKey = <String>::sse_decode(deserializer);
+        let mut var_audioPath = <Option<String>>::sse_decode(deserializer);
+        let mut var_audioBytes = <Option<Vec<u8>>>::sse_decode(deserializer);
+        let mut var_mimeType = <String>::sse_decode(deserializer);
+        let mut var_durationMs = <Option<i64>>::sse_decode(deserializer);
+        let mut var_segments = <Vec<crate::api::WordSegmentDto>>::sse_decode(deserializer);
+        return crate::api::VerseAudioDto {
+            reciter_id: var_reciterId,
+            verse_key: var_verseKey,
+            audio_path: var_audioPath,
+            audio_bytes: var_audioBytes,
+            mime_type: var_mimeType,
+            duration_ms: var_durationMs,
+            segments: var_segments,
+        };
+    }
+}
+
 impl SseDecode for crate::api::VerseDto {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -4194,6 +6295,32 @@ impl SseDecode for crate::api::VerseWithTranslationDto {
     }
 }
 
+impl SseDecode for crate::api::WordAudioDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_reciterId = <i32>::sse_decode(deserializer);
+        let mut var_verseKey = <String>::sse_decode(deserializer);
+        let mut var_position = <i32>::sse_decode(deserializer);
+        let mut var_audioPath = <Option<String>>::sse_decode(deserializer);
+        let mut var_audioBytes = <Option<Vec<u8>>>::sse_decode(deserializer);
+        let mut var_mimeType = <String>::sse_decode(deserializer);
+        let mut var_durationMs = <Option<i64>>::sse_decode(deserializer);
+        let mut var_startMs = <Option<i64>>::sse_decode(deserializer);
+        let mut var_endMs = <Option<i64>>::sse_decode(deserializer);
+        return crate::api::WordAudioDto {
+            reciter_id: var_reciterId,
+            verse_key: var_verseKey,
+            position: var_position,
+            audio_path: var_audioPath,
+            audio_bytes: var_audioBytes,
+            mime_type: var_mimeType,
+            duration_ms: var_durationMs,
+            start_ms: var_startMs,
+            end_ms: var_endMs,
+        };
+    }
+}
+
 impl SseDecode for crate::api::WordDto {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -4210,6 +6337,20 @@ impl SseDecode for crate::api::WordDto {
     }
 }
 
+impl SseDecode for crate::api::WordSegmentDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_position = <i32>::sse_decode(deserializer);
+        let mut var_startMs = <i64>::sse_decode(deserializer);
+        let mut var_endMs = <i64>::sse_decode(deserializer);
+        return crate::api::WordSegmentDto {
+            position: var_position,
+            start_ms: var_startMs,
+            end_ms: var_endMs,
+        };
+    }
+}
+
 impl SseDecode for crate::api::WordTimingDto {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -4245,88 +6386,125 @@ fn pde_ffi_dispatcher_primary_impl(
 ) {
     // Codec=Pde (Serialization + dispatch), see doc to use other codecs
     match func_id {
-        11 => wire__crate__api__clear_session_impl(port, ptr, rust_vec_len, data_len),
-        12 => wire__crate__api__close_databases_impl(port, ptr, rust_vec_len, data_len),
-        13 => wire__crate__api__complete_session_impl(port, ptr, rust_vec_len, data_len),
-        14 => wire__crate__api__debug_emit_test_event_impl(port, ptr, rust_vec_len, data_len),
-        15 => wire__crate__api__disable_package_impl(port, ptr, rust_vec_len, data_len),
-        16 => wire__crate__api__drain_telemetry_events_impl(port, ptr, rust_vec_len, data_len),
-        17 => wire__crate__api__echo_recall_stats_impl(port, ptr, rust_vec_len, data_len),
-        18 => wire__crate__api__enable_package_impl(port, ptr, rust_vec_len, data_len),
-        19 => wire__crate__api__execute_debug_query_impl(port, ptr, rust_vec_len, data_len),
-        20 => wire__crate__api__fetch_node_with_metadata_impl(port, ptr, rust_vec_len, data_len),
-        21 => wire__crate__api__finalize_echo_recall_impl(port, ptr, rust_vec_len, data_len),
-        22 => wire__crate__api__generate_exercise_v2_impl(port, ptr, rust_vec_len, data_len),
-        23 => wire__crate__api__get_active_session_impl(port, ptr, rust_vec_len, data_len),
-        24 => wire__crate__api__get_available_packages_impl(port, ptr, rust_vec_len, data_len),
-        25 => wire__crate__api__get_available_surahs_impl(port, ptr, rust_vec_len, data_len),
-        26 => wire__crate__api__get_dashboard_stats_impl(port, ptr, rust_vec_len, data_len),
-        27 => wire__crate__api__get_db_health_impl(port, ptr, rust_vec_len, data_len),
-        28 => wire__crate__api__get_debug_stats_impl(port, ptr, rust_vec_len, data_len),
-        29 => wire__crate__api__get_detailed_stats_impl(port, ptr, rust_vec_len, data_len),
-        30 => wire__crate__api__get_energy_snapshot_impl(port, ptr, rust_vec_len, data_len),
-        31 => wire__crate__api__get_exercises_impl(port, ptr, rust_vec_len, data_len),
-        32 => wire__crate__api__get_exercises_for_node_impl(port, ptr, rust_vec_len, data_len),
-        33 => wire__crate__api__get_installed_packages_impl(port, ptr, rust_vec_len, data_len),
-        34 => wire__crate__api__get_languages_impl(port, ptr, rust_vec_len, data_len),
-        35 => wire__crate__api__get_last_sync_timestamp_impl(port, ptr, rust_vec_len, data_len),
-        36 => wire__crate__api__get_memory_states_since_impl(port, ptr, rust_vec_len, data_len),
-        37 => wire__crate__api__get_next_session_item_impl(port, ptr, rust_vec_len, data_len),
-        38 => wire__crate__api__get_preferred_translator_id_impl(port, ptr, rust_vec_len, data_len),
-        39 => wire__crate__api__get_session_items_since_impl(port, ptr, rust_vec_len, data_len),
-        40 => wire__crate__api__get_session_preview_impl(port, ptr, rust_vec_len, data_len),
-        41 => wire__crate__api__get_sessions_since_impl(port, ptr, rust_vec_len, data_len),
-        42 => wire__crate__api__get_surah_verses_impl(port, ptr, rust_vec_len, data_len),
-        43 => wire__crate__api__get_telemetry_event_count_impl(port, ptr, rust_vec_len, data_len),
-        44 => wire__crate__api__get_translator_impl(port, ptr, rust_vec_len, data_len),
-        45 => {
+        11 => wire__crate__api__bury_nodes_impl(port, ptr, rust_vec_len, data_len),
+        12 => wire__crate__api__check_exercise_answer_impl(port, ptr, rust_vec_len, data_len),
+        13 => wire__crate__api__clear_session_impl(port, ptr, rust_vec_len, data_len),
+        14 => wire__crate__api__close_databases_impl(port, ptr, rust_vec_len, data_len),
+        15 => wire__crate__api__complete_session_impl(port, ptr, rust_vec_len, data_len),
+        16 => wire__crate__api__debug_emit_test_event_impl(port, ptr, rust_vec_len, data_len),
+        17 => wire__crate__api__disable_package_impl(port, ptr, rust_vec_len, data_len),
+        18 => wire__crate__api__drain_telemetry_events_impl(port, ptr, rust_vec_len, data_len),
+        19 => wire__crate__api__echo_recall_junctions_impl(port, ptr, rust_vec_len, data_len),
+        20 => wire__crate__api__echo_recall_stats_impl(port, ptr, rust_vec_len, data_len),
+        21 => wire__crate__api__enable_package_impl(port, ptr, rust_vec_len, data_len),
+        22 => wire__crate__api__execute_debug_query_impl(port, ptr, rust_vec_len, data_len),
+        23 => wire__crate__api__fetch_node_with_metadata_impl(port, ptr, rust_vec_len, data_len),
+        24 => wire__crate__api__finalize_echo_recall_impl(port, ptr, rust_vec_len, data_len),
+        25 => wire__crate__api__generate_exercise_v2_impl(port, ptr, rust_vec_len, data_len),
+        26 => wire__crate__api__get_active_session_impl(port, ptr, rust_vec_len, data_len),
+        27 => wire__crate__api__get_arabic_level_impl(port, ptr, rust_vec_len, data_len),
+        28 => wire__crate__api__get_available_packages_impl(port, ptr, rust_vec_len, data_len),
+        29 => wire__crate__api__get_available_surahs_impl(port, ptr, rust_vec_len, data_len),
+        30 => wire__crate__api__get_dashboard_stats_impl(port, ptr, rust_vec_len, data_len),
+        31 => wire__crate__api__get_db_health_impl(port, ptr, rust_vec_len, data_len),
+        32 => wire__crate__api__get_debug_stats_impl(port, ptr, rust_vec_len, data_len),
+        33 => wire__crate__api__get_detailed_stats_impl(port, ptr, rust_vec_len, data_len),
+        34 => wire__crate__api__get_energy_snapshot_impl(port, ptr, rust_vec_len, data_len),
+        35 => wire__crate__api__get_exercise_hint_impl(port, ptr, rust_vec_len, data_len),
+        36 => wire__crate__api__get_exercise_hints_impl(port, ptr, rust_vec_len, data_len),
+        37 => wire__crate__api__get_exercises_impl(port, ptr, rust_vec_len, data_len),
+        38 => wire__crate__api__get_exercises_for_node_impl(port, ptr, rust_vec_len, data_len),
+        39 => wire__crate__api__get_installed_packages_impl(port, ptr, rust_vec_len, data_len),
+        40 => wire__crate__api__get_languages_impl(port, ptr, rust_vec_len, data_len),
+        41 => wire__crate__api__get_last_sync_timestamp_impl(port, ptr, rust_vec_len, data_len),
+        42 => wire__crate__api__get_memory_states_since_impl(port, ptr, rust_vec_len, data_len),
+        43 => wire__crate__api__get_next_session_item_impl(port, ptr, rust_vec_len, data_len),
+        44 => wire__crate__api__get_node_status_impl(port, ptr, rust_vec_len, data_len),
+        45 => wire__crate__api__get_page_exercises_impl(port, ptr, rust_vec_len, data_len),
+        46 => wire__crate__api__get_preferred_script_impl(port, ptr, rust_vec_len, data_len),
+        47 => wire__crate__api__get_preferred_translator_id_impl(port, ptr, rust_vec_len, data_len),
+        48 => wire__crate__api__get_reciters_impl(port, ptr, rust_vec_len, data_len),
+        49 => wire__crate__api__get_session_items_since_impl(port, ptr, rust_vec_len, data_len),
+        50 => wire__crate__api__get_session_preview_impl(port, ptr, rust_vec_len, data_len),
+        51 => wire__crate__api__get_sessions_since_impl(port, ptr, rust_vec_len, data_len),
+        52 => wire__crate__api__get_surah_verses_impl(port, ptr, rust_vec_len, data_len),
+        53 => wire__crate__api__get_tafsir_passage_impl(port, ptr, rust_vec_len, data_len),
+        54 => wire__crate__api__get_tafsirs_impl(port, ptr, rust_vec_len, data_len),
+        55 => wire__crate__api__get_tajweed_annotations_impl(port, ptr, rust_vec_len, data_len),
+        56 => wire__crate__api__get_telemetry_event_count_impl(port, ptr, rust_vec_len, data_len),
+        57 => wire__crate__api__get_translator_impl(port, ptr, rust_vec_len, data_len),
+        58 => {
             wire__crate__api__get_translators_for_language_impl(port, ptr, rust_vec_len, data_len)
         }
-        46 => wire__crate__api__get_verse_impl(port, ptr, rust_vec_len, data_len),
-        47 => wire__crate__api__get_verse_translation_by_translator_impl(
+        59 => wire__crate__api__get_verse_impl(port, ptr, rust_vec_len, data_len),
+        60 => wire__crate__api__get_verse_audio_impl(port, ptr, rust_vec_len, data_len),
+        61 => wire__crate__api__get_verse_translation_by_translator_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        48 => wire__crate__api__get_word_impl(port, ptr, rust_vec_len, data_len),
-        49 => wire__crate__api__get_word_at_position_impl(port, ptr, rust_vec_len, data_len),
-        50 => wire__crate__api__get_word_translation_impl(port, ptr, rust_vec_len, data_len),
-        51 => wire__crate__api__get_words_for_verse_impl(port, ptr, rust_vec_len, data_len),
-        52 => wire__crate__api__init_app_impl(port, ptr, rust_vec_len, data_len),
-        53 => wire__crate__api__install_translation_pack_from_bytes_impl(
+        62 => wire__crate__api__get_word_impl(port, ptr, rust_vec_len, data_len),
+        63 => wire__crate__api__get_word_at_position_impl(port, ptr, rust_vec_len, data_len),
+        64 => wire__crate__api__get_word_audio_impl(port, ptr, rust_vec_len, data_len),
+        65 => wire__crate__api__get_word_translation_impl(port, ptr, rust_vec_len, data_len),
+        66 => wire__crate__api__get_words_for_verse_impl(port, ptr, rust_vec_len, data_len),
+        67 => wire__crate__api__grade_recitation_impl(port, ptr, rust_vec_len, data_len),
+        68 => wire__crate__api__init_app_impl(port, ptr, rust_vec_len, data_len),
+        69 => {
+            wire__crate__api__init_semantic_model_from_bytes_impl(port, ptr, rust_vec_len, data_len)
+        }
+        70 => {
+            wire__crate__api__init_semantic_model_from_dir_impl(port, ptr, rust_vec_len, data_len)
+        }
+        71 => wire__crate__api__install_package_from_file_impl(port, ptr, rust_vec_len, data_len),
+        72 => wire__crate__api__install_translation_pack_from_bytes_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        54 => wire__crate__api__parse_node_range_impl(port, ptr, rust_vec_len, data_len),
-        55 => wire__crate__api__process_review_impl(port, ptr, rust_vec_len, data_len),
-        56 => wire__crate__api__query_nodes_filtered_impl(port, ptr, rust_vec_len, data_len),
-        57 => wire__crate__api__reseed_database_impl(port, ptr, rust_vec_len, data_len),
-        58 => wire__crate__api__search_nodes_impl(port, ptr, rust_vec_len, data_len),
-        59 => wire__crate__api__set_last_sync_timestamp_impl(port, ptr, rust_vec_len, data_len),
-        60 => wire__crate__api__set_preferred_translator_id_impl(port, ptr, rust_vec_len, data_len),
-        61 => wire__crate__api__setup_database_impl(port, ptr, rust_vec_len, data_len),
-        62 => wire__crate__api__setup_database_in_memory_impl(port, ptr, rust_vec_len, data_len),
-        63 => wire__crate__api__simulate_propagation_impl(port, ptr, rust_vec_len, data_len),
-        64 => wire__crate__api__start_echo_recall_impl(port, ptr, rust_vec_len, data_len),
-        65 => wire__crate__api__start_session_impl(port, ptr, rust_vec_len, data_len),
-        66 => wire__crate__api__submit_echo_recall_impl(port, ptr, rust_vec_len, data_len),
-        67 => wire__crate__api__submit_session_item_impl(port, ptr, rust_vec_len, data_len),
-        68 => wire__crate__api__upsert_memory_states_from_remote_impl(
+        73 => wire__crate__api__is_semantic_model_loaded_impl(port, ptr, rust_vec_len, data_len),
+        74 => wire__crate__api__parse_node_range_impl(port, ptr, rust_vec_len, data_len),
+        75 => wire__crate__api__process_review_impl(port, ptr, rust_vec_len, data_len),
+        76 => wire__crate__api__query_nodes_filtered_impl(port, ptr, rust_vec_len, data_len),
+        77 => wire__crate__api__regenerate_exercise_impl(port, ptr, rust_vec_len, data_len),
+        78 => wire__crate__api__reseed_database_impl(port, ptr, rust_vec_len, data_len),
+        79 => wire__crate__api__reset_nodes_impl(port, ptr, rust_vec_len, data_len),
+        80 => wire__crate__api__resume_session_ayah_chain_impl(port, ptr, rust_vec_len, data_len),
+        81 => wire__crate__api__resume_session_echo_recall_impl(port, ptr, rust_vec_len, data_len),
+        82 => wire__crate__api__search_nodes_impl(port, ptr, rust_vec_len, data_len),
+        83 => wire__crate__api__set_arabic_level_impl(port, ptr, rust_vec_len, data_len),
+        84 => wire__crate__api__set_last_sync_timestamp_impl(port, ptr, rust_vec_len, data_len),
+        85 => wire__crate__api__set_preferred_script_impl(port, ptr, rust_vec_len, data_len),
+        86 => wire__crate__api__set_preferred_translator_id_impl(port, ptr, rust_vec_len, data_len),
+        87 => wire__crate__api__setup_database_impl(port, ptr, rust_vec_len, data_len),
+        88 => wire__crate__api__setup_database_in_memory_impl(port, ptr, rust_vec_len, data_len),
+        89 => wire__crate__api__simulate_propagation_impl(port, ptr, rust_vec_len, data_len),
+        90 => wire__crate__api__start_echo_recall_impl(port, ptr, rust_vec_len, data_len),
+        91 => wire__crate__api__start_session_impl(port, ptr, rust_vec_len, data_len),
+        92 => wire__crate__api__submit_echo_recall_impl(port, ptr, rust_vec_len, data_len),
+        93 => wire__crate__api__submit_session_answer_impl(port, ptr, rust_vec_len, data_len),
+        94 => wire__crate__api__submit_session_ayah_chain_impl(port, ptr, rust_vec_len, data_len),
+        95 => wire__crate__api__submit_session_echo_recall_impl(port, ptr, rust_vec_len, data_len),
+        96 => wire__crate__api__submit_session_item_impl(port, ptr, rust_vec_len, data_len),
+        97 => wire__crate__api__suspend_nodes_impl(port, ptr, rust_vec_len, data_len),
+        98 => wire__crate__api__unsuspend_nodes_impl(port, ptr, rust_vec_len, data_len),
+        99 => wire__crate__api__upsert_memory_states_from_remote_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        69 => wire__crate__api__upsert_session_items_from_remote_impl(
+        100 => wire__crate__api__upsert_session_items_from_remote_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        70 => wire__crate__api__upsert_sessions_from_remote_impl(port, ptr, rust_vec_len, data_len),
+        101 => {
+            wire__crate__api__upsert_sessions_from_remote_impl(port, ptr, rust_vec_len, data_len)
+        }
         _ => unreachable!(),
     }
 }
@@ -4499,22 +6677,66 @@ impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<Arc<dyn UserRepository>>>
         self.into()
     }
 }
-
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::ActivityPointDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.date.into_into_dart().into_dart(),
+            self.count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::ActivityPointDto {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::ActivityPointDto>
+    for crate::api::ActivityPointDto
+{
+    fn into_into_dart(self) -> crate::api::ActivityPointDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::AyahChainStateDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.node_id.into_into_dart().into_dart(),
+            self.current_verse_key.into_into_dart().into_dart(),
+            self.completed_count.into_into_dart().into_dart(),
+            self.total_verses.into_into_dart().into_dart(),
+            self.is_complete.into_into_dart().into_dart(),
+            self.mistake_made.into_into_dart().into_dart(),
+            self.last_grade.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::AyahChainStateDto {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::AyahChainStateDto>
+    for crate::api::AyahChainStateDto
+{
+    fn into_into_dart(self) -> crate::api::AyahChainStateDto {
+        self
+    }
+}
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::ActivityPointDto {
+impl flutter_rust_bridge::IntoDart for crate::api::AyahJunctionDto {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.date.into_into_dart().into_dart(),
-            self.count.into_into_dart().into_dart(),
+            self.from_verse.into_into_dart().into_dart(),
+            self.to_verse.into_into_dart().into_dart(),
+            self.closing_word.into_into_dart().into_dart(),
+            self.opening_word.into_into_dart().into_dart(),
+            self.strength.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::ActivityPointDto {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::ActivityPointDto>
-    for crate::api::ActivityPointDto
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::AyahJunctionDto {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::AyahJunctionDto>
+    for crate::api::AyahJunctionDto
 {
-    fn into_into_dart(self) -> crate::api::ActivityPointDto {
+    fn into_into_dart(self) -> crate::api::AyahJunctionDto {
         self
     }
 }
@@ -4659,6 +6881,27 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::DetailedStatsDto>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::DistractorStrategyDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.difficulty.into_into_dart().into_dart(),
+            self.signals.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::DistractorStrategyDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::DistractorStrategyDto>
+    for crate::api::DistractorStrategyDto
+{
+    fn into_into_dart(self) -> crate::api::DistractorStrategyDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::EchoRecallMetricsDto {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -4807,90 +7050,126 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::EnergyUpdateDto>
 impl flutter_rust_bridge::IntoDart for crate::api::ExerciseDataDto {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         match self {
-            crate::api::ExerciseDataDto::Memorization { node_id } => {
-                [0.into_dart(), node_id.into_into_dart().into_dart()].into_dart()
-            }
+            crate::api::ExerciseDataDto::Memorization { node_id, seed } => [
+                0.into_dart(),
+                node_id.into_into_dart().into_dart(),
+                seed.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
             crate::api::ExerciseDataDto::McqArToEn {
                 node_id,
                 distractor_node_ids,
+                distractor_strategy,
+                seed,
             } => [
                 1.into_dart(),
                 node_id.into_into_dart().into_dart(),
                 distractor_node_ids.into_into_dart().into_dart(),
+                distractor_strategy.into_into_dart().into_dart(),
+                seed.into_into_dart().into_dart(),
             ]
             .into_dart(),
             crate::api::ExerciseDataDto::McqEnToAr {
                 node_id,
                 distractor_node_ids,
+                distractor_strategy,
+                seed,
             } => [
                 2.into_dart(),
                 node_id.into_into_dart().into_dart(),
                 distractor_node_ids.into_into_dart().into_dart(),
+                distractor_strategy.into_into_dart().into_dart(),
+                seed.into_into_dart().into_dart(),
             ]
             .into_dart(),
-            crate::api::ExerciseDataDto::Translation { node_id } => {
-                [3.into_dart(), node_id.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::ExerciseDataDto::ContextualTranslation { node_id, verse_key } => [
+            crate::api::ExerciseDataDto::Translation { node_id, seed } => [
+                3.into_dart(),
+                node_id.into_into_dart().into_dart(),
+                seed.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::ExerciseDataDto::ContextualTranslation {
+                node_id,
+                verse_key,
+                seed,
+            } => [
                 4.into_dart(),
                 node_id.into_into_dart().into_dart(),
                 verse_key.into_into_dart().into_dart(),
+                seed.into_into_dart().into_dart(),
             ]
             .into_dart(),
             crate::api::ExerciseDataDto::ClozeDeletion {
                 node_id,
                 blank_position,
+                seed,
             } => [
                 5.into_dart(),
                 node_id.into_into_dart().into_dart(),
                 blank_position.into_into_dart().into_dart(),
+                seed.into_into_dart().into_dart(),
             ]
             .into_dart(),
             crate::api::ExerciseDataDto::FirstLetterHint {
                 node_id,
                 word_position,
+                seed,
             } => [
                 6.into_dart(),
                 node_id.into_into_dart().into_dart(),
                 word_position.into_into_dart().into_dart(),
+                seed.into_into_dart().into_dart(),
             ]
             .into_dart(),
             crate::api::ExerciseDataDto::MissingWordMcq {
                 node_id,
                 blank_position,
                 distractor_node_ids,
+                distractor_strategy,
+                seed,
             } => [
                 7.into_dart(),
                 node_id.into_into_dart().into_dart(),
                 blank_position.into_into_dart().into_dart(),
                 distractor_node_ids.into_into_dart().into_dart(),
+                distractor_strategy.into_into_dart().into_dart(),
+                seed.into_into_dart().into_dart(),
             ]
             .into_dart(),
             crate::api::ExerciseDataDto::NextWordMcq {
                 node_id,
                 context_position,
                 distractor_node_ids,
+                distractor_strategy,
+                seed,
             } => [
                 8.into_dart(),
                 node_id.into_into_dart().into_dart(),
                 context_position.into_into_dart().into_dart(),
                 distractor_node_ids.into_into_dart().into_dart(),
+                distractor_strategy.into_into_dart().into_dart(),
+                seed.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::ExerciseDataDto::FullVerseInput { node_id, seed } => [
+                9.into_dart(),
+                node_id.into_into_dart().into_dart(),
+                seed.into_into_dart().into_dart(),
             ]
             .into_dart(),
-            crate::api::ExerciseDataDto::FullVerseInput { node_id } => {
-                [9.into_dart(), node_id.into_into_dart().into_dart()].into_dart()
-            }
             crate::api::ExerciseDataDto::AyahChain {
                 node_id,
                 verse_keys,
                 current_index,
                 completed_count,
+                seed,
             } => [
                 10.into_dart(),
                 node_id.into_into_dart().into_dart(),
                 verse_keys.into_into_dart().into_dart(),
                 current_index.into_into_dart().into_dart(),
                 completed_count.into_into_dart().into_dart(),
+                seed.into_into_dart().into_dart(),
             ]
             .into_dart(),
             crate::api::ExerciseDataDto::FindMistake {
@@ -4898,93 +7177,453 @@ impl flutter_rust_bridge::IntoDart for crate::api::ExerciseDataDto {
                 mistake_position,
                 correct_word_node_id,
                 incorrect_word_node_id,
+                seed,
             } => [
                 11.into_dart(),
                 node_id.into_into_dart().into_dart(),
                 mistake_position.into_into_dart().into_dart(),
                 correct_word_node_id.into_into_dart().into_dart(),
                 incorrect_word_node_id.into_into_dart().into_dart(),
+                seed.into_into_dart().into_dart(),
             ]
             .into_dart(),
             crate::api::ExerciseDataDto::AyahSequence {
                 node_id,
                 correct_sequence,
+                seed,
             } => [
                 12.into_dart(),
                 node_id.into_into_dart().into_dart(),
                 correct_sequence.into_into_dart().into_dart(),
+                seed.into_into_dart().into_dart(),
             ]
             .into_dart(),
             crate::api::ExerciseDataDto::SequenceRecall {
                 node_id,
                 correct_sequence,
                 options,
+                seed,
             } => [
                 13.into_dart(),
                 node_id.into_into_dart().into_dart(),
                 correct_sequence.into_into_dart().into_dart(),
                 options.into_into_dart().into_dart(),
+                seed.into_into_dart().into_dart(),
             ]
             .into_dart(),
-            crate::api::ExerciseDataDto::FirstWordRecall { node_id, verse_key } => [
+            crate::api::ExerciseDataDto::FirstWordRecall {
+                node_id,
+                verse_key,
+                seed,
+            } => [
                 14.into_dart(),
                 node_id.into_into_dart().into_dart(),
                 verse_key.into_into_dart().into_dart(),
+                seed.into_into_dart().into_dart(),
             ]
             .into_dart(),
-            crate::api::ExerciseDataDto::IdentifyRoot { node_id, root } => [
+            crate::api::ExerciseDataDto::IdentifyRoot {
+                node_id,
+                root,
+                seed,
+            } => [
                 15.into_dart(),
                 node_id.into_into_dart().into_dart(),
                 root.into_into_dart().into_dart(),
+                seed.into_into_dart().into_dart(),
             ]
             .into_dart(),
             crate::api::ExerciseDataDto::ReverseCloze {
                 node_id,
                 blank_position,
+                seed,
             } => [
                 16.into_dart(),
                 node_id.into_into_dart().into_dart(),
                 blank_position.into_into_dart().into_dart(),
+                seed.into_into_dart().into_dart(),
             ]
             .into_dart(),
             crate::api::ExerciseDataDto::TranslatePhrase {
                 node_id,
                 translator_id,
+                seed,
             } => [
                 17.into_dart(),
                 node_id.into_into_dart().into_dart(),
                 translator_id.into_into_dart().into_dart(),
+                seed.into_into_dart().into_dart(),
             ]
             .into_dart(),
             crate::api::ExerciseDataDto::PosTagging {
                 node_id,
                 correct_pos,
                 options,
+                seed,
             } => [
                 18.into_dart(),
                 node_id.into_into_dart().into_dart(),
                 correct_pos.into_into_dart().into_dart(),
                 options.into_into_dart().into_dart(),
+                seed.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::ExerciseDataDto::GrammarFeature {
+                node_id,
+                feature,
+                prompt,
+                correct_value,
+                options,
+                seed,
+            } => [
+                19.into_dart(),
+                node_id.into_into_dart().into_dart(),
+                feature.into_into_dart().into_dart(),
+                prompt.into_into_dart().into_dart(),
+                correct_value.into_into_dart().into_dart(),
+                options.into_into_dart().into_dart(),
+                seed.into_into_dart().into_dart(),
             ]
             .into_dart(),
             crate::api::ExerciseDataDto::CrossVerseConnection {
                 node_id,
                 related_verse_ids,
                 connection_theme,
+                seed,
             } => [
-                19.into_dart(),
+                20.into_dart(),
                 node_id.into_into_dart().into_dart(),
                 related_verse_ids.into_into_dart().into_dart(),
                 connection_theme.into_into_dart().into_dart(),
+                seed.into_into_dart().into_dart(),
             ]
             .into_dart(),
             crate::api::ExerciseDataDto::EchoRecall {
                 user_id,
                 ayah_node_ids,
+                seed,
             } => [
-                20.into_dart(),
+                21.into_dart(),
                 user_id.into_into_dart().into_dart(),
                 ayah_node_ids.into_into_dart().into_dart(),
+                seed.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::ExerciseDataDto::Mutashabihat {
+                node_id,
+                verse_key,
+                prompt_word_count,
+                option_verse_keys,
+                differing_positions,
+                seed,
+            } => [
+                22.into_dart(),
+                node_id.into_into_dart().into_dart(),
+                verse_key.into_into_dart().into_dart(),
+                prompt_word_count.into_into_dart().into_dart(),
+                option_verse_keys.into_into_dart().into_dart(),
+                differing_positions.into_into_dart().into_dart(),
+                seed.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::ExerciseDataDto::WordOrder {
+                node_id,
+                correct_sequence,
+                shuffled_word_node_ids,
+                tile_texts,
+                seed,
+            } => [
+                23.into_dart(),
+                node_id.into_into_dart().into_dart(),
+                correct_sequence.into_into_dart().into_dart(),
+                shuffled_word_node_ids.into_into_dart().into_dart(),
+                tile_texts.into_into_dart().into_dart(),
+                seed.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::ExerciseDataDto::IdentifySurah {
+                node_id,
+                verse_key,
+                correct_chapter,
+                chapter_options,
+                seed,
+            } => [
+                24.into_dart(),
+                node_id.into_into_dart().into_dart(),
+                verse_key.into_into_dart().into_dart(),
+                correct_chapter.into_into_dart().into_dart(),
+                chapter_options.into_into_dart().into_dart(),
+                seed.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::ExerciseDataDto::IdentifyVerseNumber {
+                node_id,
+                verse_key,
+                correct_verse_number,
+                verse_number_options,
+                seed,
+            } => [
+                25.into_dart(),
+                node_id.into_into_dart().into_dart(),
+                verse_key.into_into_dart().into_dart(),
+                correct_verse_number.into_into_dart().into_dart(),
+                verse_number_options.into_into_dart().into_dart(),
+                seed.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::ExerciseDataDto::IdentifyPageHalf {
+                node_id,
+                verse_key,
+                page,
+                half,
+                seed,
+            } => [
+                26.into_dart(),
+                node_id.into_into_dart().into_dart(),
+                verse_key.into_into_dart().into_dart(),
+                page.into_into_dart().into_dart(),
+                half.into_into_dart().into_dart(),
+                seed.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::ExerciseDataDto::TransliterationToArabic {
+                node_id,
+                distractor_node_ids,
+                distractor_strategy,
+                seed,
+            } => [
+                27.into_dart(),
+                node_id.into_into_dart().into_dart(),
+                distractor_node_ids.into_into_dart().into_dart(),
+                distractor_strategy.into_into_dart().into_dart(),
+                seed.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::ExerciseDataDto::ArabicToTransliteration {
+                node_id,
+                verse_key,
+                seed,
+            } => [
+                28.into_dart(),
+                node_id.into_into_dart().into_dart(),
+                verse_key.into_into_dart().into_dart(),
+                seed.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::ExerciseDataDto::RootFamily {
+                node_id,
+                root_id,
+                verse_keys,
+                family_word_node_ids,
+                seed,
+            } => [
+                29.into_dart(),
+                node_id.into_into_dart().into_dart(),
+                root_id.into_into_dart().into_dart(),
+                verse_keys.into_into_dart().into_dart(),
+                family_word_node_ids.into_into_dart().into_dart(),
+                seed.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::ExerciseDataDto::LemmaMeaning {
+                node_id,
+                root_id,
+                lemma_ids,
+                meaning_lemma_ids,
+                seed,
+            } => [
+                30.into_dart(),
+                node_id.into_into_dart().into_dart(),
+                root_id.into_into_dart().into_dart(),
+                lemma_ids.into_into_dart().into_dart(),
+                meaning_lemma_ids.into_into_dart().into_dart(),
+                seed.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::ExerciseDataDto::RecitePage {
+                node_id,
+                page,
+                verse_keys,
+                first_word_node_id,
+                last_word_node_id,
+                seed,
+            } => [
+                31.into_dart(),
+                node_id.into_into_dart().into_dart(),
+                page.into_into_dart().into_dart(),
+                verse_keys.into_into_dart().into_dart(),
+                first_word_node_id.into_into_dart().into_dart(),
+                last_word_node_id.into_into_dart().into_dart(),
+                seed.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::ExerciseDataDto::PageFirstWord {
+                node_id,
+                page,
+                first_word_node_id,
+                distractor_node_ids,
+                seed,
+            } => [
+                32.into_dart(),
+                node_id.into_into_dart().into_dart(),
+                page.into_into_dart().into_dart(),
+                first_word_node_id.into_into_dart().into_dart(),
+                distractor_node_ids.into_into_dart().into_dart(),
+                seed.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::ExerciseDataDto::IdentifyVerseLine {
+                node_id,
+                verse_key,
+                page,
+                correct_line,
+                line_options,
+                seed,
+            } => [
+                33.into_dart(),
+                node_id.into_into_dart().into_dart(),
+                verse_key.into_into_dart().into_dart(),
+                page.into_into_dart().into_dart(),
+                correct_line.into_into_dart().into_dart(),
+                line_options.into_into_dart().into_dart(),
+                seed.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::ExerciseDataDto::ListenIdentifyVerse {
+                node_id,
+                reciter_id,
+                verse_key,
+                first_position,
+                last_position,
+                start_ms,
+                end_ms,
+                option_verse_keys,
+                seed,
+            } => [
+                34.into_dart(),
+                node_id.into_into_dart().into_dart(),
+                reciter_id.into_into_dart().into_dart(),
+                verse_key.into_into_dart().into_dart(),
+                first_position.into_into_dart().into_dart(),
+                last_position.into_into_dart().into_dart(),
+                start_ms.into_into_dart().into_dart(),
+                end_ms.into_into_dart().into_dart(),
+                option_verse_keys.into_into_dart().into_dart(),
+                seed.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::ExerciseDataDto::ListenContinue {
+                node_id,
+                reciter_id,
+                verse_key,
+                first_position,
+                last_position,
+                start_ms,
+                end_ms,
+                distractor_node_ids,
+                distractor_strategy,
+                seed,
+            } => [
+                35.into_dart(),
+                node_id.into_into_dart().into_dart(),
+                reciter_id.into_into_dart().into_dart(),
+                verse_key.into_into_dart().into_dart(),
+                first_position.into_into_dart().into_dart(),
+                last_position.into_into_dart().into_dart(),
+                start_ms.into_into_dart().into_dart(),
+                end_ms.into_into_dart().into_dart(),
+                distractor_node_ids.into_into_dart().into_dart(),
+                distractor_strategy.into_into_dart().into_dart(),
+                seed.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::ExerciseDataDto::ListenIdentifyWord {
+                node_id,
+                reciter_id,
+                verse_key,
+                position,
+                start_ms,
+                end_ms,
+                distractor_node_ids,
+                distractor_strategy,
+                seed,
+            } => [
+                36.into_dart(),
+                node_id.into_into_dart().into_dart(),
+                reciter_id.into_into_dart().into_dart(),
+                verse_key.into_into_dart().into_dart(),
+                position.into_into_dart().into_dart(),
+                start_ms.into_into_dart().into_dart(),
+                end_ms.into_into_dart().into_dart(),
+                distractor_node_ids.into_into_dart().into_dart(),
+                distractor_strategy.into_into_dart().into_dart(),
+                seed.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::ExerciseDataDto::IdentifyTajweedRule {
+                node_id,
+                word_node_id,
+                start_char,
+                end_char,
+                correct_rule,
+                rule_options,
+                option_names,
+                seed,
+            } => [
+                37.into_dart(),
+                node_id.into_into_dart().into_dart(),
+                word_node_id.into_into_dart().into_dart(),
+                start_char.into_into_dart().into_dart(),
+                end_char.into_into_dart().into_dart(),
+                correct_rule.into_into_dart().into_dart(),
+                rule_options.into_into_dart().into_dart(),
+                option_names.into_into_dart().into_dart(),
+                seed.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::ExerciseDataDto::FindTajweedRule {
+                node_id,
+                rule,
+                rule_name,
+                rule_word_node_ids,
+                seed,
+            } => [
+                38.into_dart(),
+                node_id.into_into_dart().into_dart(),
+                rule.into_into_dart().into_dart(),
+                rule_name.into_into_dart().into_dart(),
+                rule_word_node_ids.into_into_dart().into_dart(),
+                seed.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::ExerciseDataDto::TafsirKeyLesson {
+                node_id,
+                tafsir_id,
+                passage_key,
+                option_passage_keys,
+                seed,
+            } => [
+                39.into_dart(),
+                node_id.into_into_dart().into_dart(),
+                tafsir_id.into_into_dart().into_dart(),
+                passage_key.into_into_dart().into_dart(),
+                option_passage_keys.into_into_dart().into_dart(),
+                seed.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::ExerciseDataDto::RevelationOccasion {
+                node_id,
+                tafsir_id,
+                passage_key,
+                option_verse_keys,
+                seed,
+            } => [
+                40.into_dart(),
+                node_id.into_into_dart().into_dart(),
+                tafsir_id.into_into_dart().into_dart(),
+                passage_key.into_into_dart().into_dart(),
+                option_verse_keys.into_into_dart().into_dart(),
+                seed.into_into_dart().into_dart(),
             ]
             .into_dart(),
             _ => {
@@ -5002,6 +7641,26 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::ExerciseDataDto>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::ExerciseHintDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.level.into_into_dart().into_dart(),
+            self.level_name.into_into_dart().into_dart(),
+            self.text.into_into_dart().into_dart(),
+            self.max_grade.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::ExerciseHintDto {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::ExerciseHintDto>
+    for crate::api::ExerciseHintDto
+{
+    fn into_into_dart(self) -> crate::api::ExerciseHintDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::HintDto {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -5129,6 +7788,23 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::NodeSearchDto> for crate::api
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::NodeStatusDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.node_id.into_into_dart().into_dart(),
+            self.status.into_into_dart().into_dart(),
+            self.buried_until.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::NodeStatusDto {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::NodeStatusDto> for crate::api::NodeStatusDto {
+    fn into_into_dart(self) -> crate::api::NodeStatusDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::PropagationDiagnosticsDto {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -5162,14 +7838,103 @@ impl flutter_rust_bridge::IntoDart for crate::api::PropagationResultDto {
         .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::PropagationResultDto
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::PropagationResultDto>
-    for crate::api::PropagationResultDto
-{
-    fn into_into_dart(self) -> crate::api::PropagationResultDto {
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::PropagationResultDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::PropagationResultDto>
+    for crate::api::PropagationResultDto
+{
+    fn into_into_dart(self) -> crate::api::PropagationResultDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::RecitationJumpDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.verse_key.into_into_dart().into_dart(),
+            self.start_position.into_into_dart().into_dart(),
+            self.word_count.into_into_dart().into_dart(),
+            self.after_word_node_id.into_into_dart().into_dart(),
+            self.start_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::RecitationJumpDto {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::RecitationJumpDto>
+    for crate::api::RecitationJumpDto
+{
+    fn into_into_dart(self) -> crate::api::RecitationJumpDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::RecitationResultDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.words.into_into_dart().into_dart(),
+            self.jumps.into_into_dart().into_dart(),
+            self.extra_words.into_into_dart().into_dart(),
+            self.accuracy.into_into_dart().into_dart(),
+            self.grade.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::RecitationResultDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::RecitationResultDto>
+    for crate::api::RecitationResultDto
+{
+    fn into_into_dart(self) -> crate::api::RecitationResultDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::RecitedWordDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.word_node_id.into_into_dart().into_dart(),
+            self.verse_key.into_into_dart().into_dart(),
+            self.position.into_into_dart().into_dart(),
+            self.text.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.heard.into_into_dart().into_dart(),
+            self.similarity.into_into_dart().into_dart(),
+            self.repeated.into_into_dart().into_dart(),
+            self.start_ms.into_into_dart().into_dart(),
+            self.end_ms.into_into_dart().into_dart(),
+            self.grade.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::RecitedWordDto {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::RecitedWordDto> for crate::api::RecitedWordDto {
+    fn into_into_dart(self) -> crate::api::RecitedWordDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::ReciterDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.reciter_id.into_into_dart().into_dart(),
+            self.slug.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.style.into_into_dart().into_dart(),
+            self.package_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::ReciterDto {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::ReciterDto> for crate::api::ReciterDto {
+    fn into_into_dart(self) -> crate::api::ReciterDto {
         self
     }
 }
@@ -5353,6 +8118,93 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::SyncSessionItemDto>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::TafsirDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.tafsir_id.into_into_dart().into_dart(),
+            self.slug.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.author.into_into_dart().into_dart(),
+            self.language_code.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::TafsirDto {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::TafsirDto> for crate::api::TafsirDto {
+    fn into_into_dart(self) -> crate::api::TafsirDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::TafsirPassageDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.tafsir_id.into_into_dart().into_dart(),
+            self.chapter_number.into_into_dart().into_dart(),
+            self.start_verse.into_into_dart().into_dart(),
+            self.end_verse.into_into_dart().into_dart(),
+            self.text.into_into_dart().into_dart(),
+            self.key_lesson.into_into_dart().into_dart(),
+            self.revelation_occasion.into_into_dart().into_dart(),
+            self.themes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::TafsirPassageDto {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::TafsirPassageDto>
+    for crate::api::TafsirPassageDto
+{
+    fn into_into_dart(self) -> crate::api::TafsirPassageDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::TajweedAnnotationDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.word_node_id.into_into_dart().into_dart(),
+            self.position.into_into_dart().into_dart(),
+            self.rule.into_into_dart().into_dart(),
+            self.rule_name.into_into_dart().into_dart(),
+            self.start_char.into_into_dart().into_dart(),
+            self.end_char.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::TajweedAnnotationDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::TajweedAnnotationDto>
+    for crate::api::TajweedAnnotationDto
+{
+    fn into_into_dart(self) -> crate::api::TajweedAnnotationDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::TranscriptWordDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.text.into_into_dart().into_dart(),
+            self.start_ms.into_into_dart().into_dart(),
+            self.end_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::TranscriptWordDto {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::TranscriptWordDto>
+    for crate::api::TranscriptWordDto
+{
+    fn into_into_dart(self) -> crate::api::TranscriptWordDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::TranslatorDto {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -5373,6 +8225,52 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::TranslatorDto> for crate::api
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::ValidationResultDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.is_correct.into_into_dart().into_dart(),
+            self.feedback.into_into_dart().into_dart(),
+            self.similarity_score.into_into_dart().into_dart(),
+            self.semantic_grade.into_into_dart().into_dart(),
+            self.correct_answer.into_into_dart().into_dart(),
+            self.suggested_grade.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::ValidationResultDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::ValidationResultDto>
+    for crate::api::ValidationResultDto
+{
+    fn into_into_dart(self) -> crate::api::ValidationResultDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::VerseAudioDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.reciter_id.into_into_dart().into_dart(),
+            self.verse_key.into_into_dart().into_dart(),
+            self.audio_path.into_into_dart().into_dart(),
+            self.audio_bytes.into_into_dart().into_dart(),
+            self.mime_type.into_into_dart().into_dart(),
+            self.duration_ms.into_into_dart().into_dart(),
+            self.segments.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::VerseAudioDto {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::VerseAudioDto> for crate::api::VerseAudioDto {
+    fn into_into_dart(self) -> crate::api::VerseAudioDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::VerseDto {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -5414,6 +8312,29 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::VerseWithTranslationDto>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::WordAudioDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.reciter_id.into_into_dart().into_dart(),
+            self.verse_key.into_into_dart().into_dart(),
+            self.position.into_into_dart().into_dart(),
+            self.audio_path.into_into_dart().into_dart(),
+            self.audio_bytes.into_into_dart().into_dart(),
+            self.mime_type.into_into_dart().into_dart(),
+            self.duration_ms.into_into_dart().into_dart(),
+            self.start_ms.into_into_dart().into_dart(),
+            self.end_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::WordAudioDto {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WordAudioDto> for crate::api::WordAudioDto {
+    fn into_into_dart(self) -> crate::api::WordAudioDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::WordDto {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -5432,6 +8353,23 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::WordDto> for crate::api::Word
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::WordSegmentDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.position.into_into_dart().into_dart(),
+            self.start_ms.into_into_dart().into_dart(),
+            self.end_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::WordSegmentDto {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WordSegmentDto> for crate::api::WordSegmentDto {
+    fn into_into_dart(self) -> crate::api::WordSegmentDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::WordTimingDto {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -5628,6 +8566,30 @@ impl SseEncode for crate::api::ActivityPointDto {
     }
 }
 
+impl SseEncode for crate::api::AyahChainStateDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.node_id, serializer);
+        <Option<String>>::sse_encode(self.current_verse_key, serializer);
+        <u32>::sse_encode(self.completed_count, serializer);
+        <u32>::sse_encode(self.total_verses, serializer);
+        <bool>::sse_encode(self.is_complete, serializer);
+        <bool>::sse_encode(self.mistake_made, serializer);
+        <Option<u8>>::sse_encode(self.last_grade, serializer);
+    }
+}
+
+impl SseEncode for crate::api::AyahJunctionDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.from_verse, serializer);
+        <String>::sse_encode(self.to_verse, serializer);
+        <String>::sse_encode(self.closing_word, serializer);
+        <String>::sse_encode(self.opening_word, serializer);
+        <f64>::sse_encode(self.strength, serializer);
+    }
+}
+
 impl SseEncode for bool {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -5709,6 +8671,14 @@ impl SseEncode for crate::api::DetailedStatsDto {
     }
 }
 
+impl SseEncode for crate::api::DistractorStrategyDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.difficulty, serializer);
+        <Vec<String>>::sse_encode(self.signals, serializer);
+    }
+}
+
 impl SseEncode for crate::api::EchoRecallMetricsDto {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -5779,170 +8749,540 @@ impl SseEncode for crate::api::ExerciseDataDto {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         match self {
-            crate::api::ExerciseDataDto::Memorization { node_id } => {
+            crate::api::ExerciseDataDto::Memorization { node_id, seed } => {
                 <i32>::sse_encode(0, serializer);
                 <String>::sse_encode(node_id, serializer);
+                <u64>::sse_encode(seed, serializer);
             }
             crate::api::ExerciseDataDto::McqArToEn {
                 node_id,
                 distractor_node_ids,
+                distractor_strategy,
+                seed,
             } => {
                 <i32>::sse_encode(1, serializer);
                 <String>::sse_encode(node_id, serializer);
                 <Vec<String>>::sse_encode(distractor_node_ids, serializer);
+                <crate::api::DistractorStrategyDto>::sse_encode(distractor_strategy, serializer);
+                <u64>::sse_encode(seed, serializer);
             }
             crate::api::ExerciseDataDto::McqEnToAr {
                 node_id,
                 distractor_node_ids,
+                distractor_strategy,
+                seed,
             } => {
                 <i32>::sse_encode(2, serializer);
                 <String>::sse_encode(node_id, serializer);
                 <Vec<String>>::sse_encode(distractor_node_ids, serializer);
+                <crate::api::DistractorStrategyDto>::sse_encode(distractor_strategy, serializer);
+                <u64>::sse_encode(seed, serializer);
             }
-            crate::api::ExerciseDataDto::Translation { node_id } => {
+            crate::api::ExerciseDataDto::Translation { node_id, seed } => {
                 <i32>::sse_encode(3, serializer);
                 <String>::sse_encode(node_id, serializer);
+                <u64>::sse_encode(seed, serializer);
             }
-            crate::api::ExerciseDataDto::ContextualTranslation { node_id, verse_key } => {
+            crate::api::ExerciseDataDto::ContextualTranslation {
+                node_id,
+                verse_key,
+                seed,
+            } => {
                 <i32>::sse_encode(4, serializer);
                 <String>::sse_encode(node_id, serializer);
                 <String>::sse_encode(verse_key, serializer);
+                <u64>::sse_encode(seed, serializer);
             }
             crate::api::ExerciseDataDto::ClozeDeletion {
                 node_id,
                 blank_position,
+                seed,
             } => {
                 <i32>::sse_encode(5, serializer);
                 <String>::sse_encode(node_id, serializer);
                 <i32>::sse_encode(blank_position, serializer);
+                <u64>::sse_encode(seed, serializer);
             }
             crate::api::ExerciseDataDto::FirstLetterHint {
                 node_id,
                 word_position,
+                seed,
             } => {
                 <i32>::sse_encode(6, serializer);
                 <String>::sse_encode(node_id, serializer);
                 <i32>::sse_encode(word_position, serializer);
+                <u64>::sse_encode(seed, serializer);
             }
             crate::api::ExerciseDataDto::MissingWordMcq {
                 node_id,
                 blank_position,
                 distractor_node_ids,
+                distractor_strategy,
+                seed,
             } => {
                 <i32>::sse_encode(7, serializer);
                 <String>::sse_encode(node_id, serializer);
                 <i32>::sse_encode(blank_position, serializer);
                 <Vec<String>>::sse_encode(distractor_node_ids, serializer);
+                <crate::api::DistractorStrategyDto>::sse_encode(distractor_strategy, serializer);
+                <u64>::sse_encode(seed, serializer);
             }
             crate::api::ExerciseDataDto::NextWordMcq {
                 node_id,
                 context_position,
                 distractor_node_ids,
+                distractor_strategy,
+                seed,
             } => {
                 <i32>::sse_encode(8, serializer);
                 <String>::sse_encode(node_id, serializer);
                 <i32>::sse_encode(context_position, serializer);
                 <Vec<String>>::sse_encode(distractor_node_ids, serializer);
+                <crate::api::DistractorStrategyDto>::sse_encode(distractor_strategy, serializer);
+                <u64>::sse_encode(seed, serializer);
             }
-            crate::api::ExerciseDataDto::FullVerseInput { node_id } => {
+            crate::api::ExerciseDataDto::FullVerseInput { node_id, seed } => {
                 <i32>::sse_encode(9, serializer);
                 <String>::sse_encode(node_id, serializer);
+                <u64>::sse_encode(seed, serializer);
             }
             crate::api::ExerciseDataDto::AyahChain {
                 node_id,
                 verse_keys,
                 current_index,
                 completed_count,
+                seed,
             } => {
                 <i32>::sse_encode(10, serializer);
                 <String>::sse_encode(node_id, serializer);
                 <Vec<String>>::sse_encode(verse_keys, serializer);
                 <usize>::sse_encode(current_index, serializer);
                 <usize>::sse_encode(completed_count, serializer);
+                <u64>::sse_encode(seed, serializer);
             }
             crate::api::ExerciseDataDto::FindMistake {
                 node_id,
                 mistake_position,
                 correct_word_node_id,
                 incorrect_word_node_id,
+                seed,
             } => {
                 <i32>::sse_encode(11, serializer);
                 <String>::sse_encode(node_id, serializer);
                 <i32>::sse_encode(mistake_position, serializer);
                 <String>::sse_encode(correct_word_node_id, serializer);
                 <String>::sse_encode(incorrect_word_node_id, serializer);
+                <u64>::sse_encode(seed, serializer);
             }
             crate::api::ExerciseDataDto::AyahSequence {
                 node_id,
                 correct_sequence,
+                seed,
             } => {
                 <i32>::sse_encode(12, serializer);
                 <String>::sse_encode(node_id, serializer);
                 <Vec<String>>::sse_encode(correct_sequence, serializer);
+                <u64>::sse_encode(seed, serializer);
             }
             crate::api::ExerciseDataDto::SequenceRecall {
                 node_id,
                 correct_sequence,
                 options,
+                seed,
             } => {
                 <i32>::sse_encode(13, serializer);
                 <String>::sse_encode(node_id, serializer);
                 <Vec<String>>::sse_encode(correct_sequence, serializer);
                 <Vec<Vec<String>>>::sse_encode(options, serializer);
+                <u64>::sse_encode(seed, serializer);
             }
-            crate::api::ExerciseDataDto::FirstWordRecall { node_id, verse_key } => {
+            crate::api::ExerciseDataDto::FirstWordRecall {
+                node_id,
+                verse_key,
+                seed,
+            } => {
                 <i32>::sse_encode(14, serializer);
                 <String>::sse_encode(node_id, serializer);
                 <String>::sse_encode(verse_key, serializer);
+                <u64>::sse_encode(seed, serializer);
             }
-            crate::api::ExerciseDataDto::IdentifyRoot { node_id, root } => {
+            crate::api::ExerciseDataDto::IdentifyRoot {
+                node_id,
+                root,
+                seed,
+            } => {
                 <i32>::sse_encode(15, serializer);
                 <String>::sse_encode(node_id, serializer);
                 <String>::sse_encode(root, serializer);
+                <u64>::sse_encode(seed, serializer);
             }
             crate::api::ExerciseDataDto::ReverseCloze {
                 node_id,
                 blank_position,
+                seed,
             } => {
                 <i32>::sse_encode(16, serializer);
                 <String>::sse_encode(node_id, serializer);
                 <i32>::sse_encode(blank_position, serializer);
+                <u64>::sse_encode(seed, serializer);
             }
             crate::api::ExerciseDataDto::TranslatePhrase {
                 node_id,
                 translator_id,
+                seed,
             } => {
                 <i32>::sse_encode(17, serializer);
                 <String>::sse_encode(node_id, serializer);
                 <i32>::sse_encode(translator_id, serializer);
+                <u64>::sse_encode(seed, serializer);
             }
             crate::api::ExerciseDataDto::PosTagging {
                 node_id,
                 correct_pos,
                 options,
+                seed,
             } => {
                 <i32>::sse_encode(18, serializer);
                 <String>::sse_encode(node_id, serializer);
                 <String>::sse_encode(correct_pos, serializer);
                 <Vec<String>>::sse_encode(options, serializer);
+                <u64>::sse_encode(seed, serializer);
+            }
+            crate::api::ExerciseDataDto::GrammarFeature {
+                node_id,
+                feature,
+                prompt,
+                correct_value,
+                options,
+                seed,
+            } => {
+                <i32>::sse_encode(19, serializer);
+                <String>::sse_encode(node_id, serializer);
+                <String>::sse_encode(feature, serializer);
+                <String>::sse_encode(prompt, serializer);
+                <String>::sse_encode(correct_value, serializer);
+                <Vec<String>>::sse_encode(options, serializer);
+                <u64>::sse_encode(seed, serializer);
             }
             crate::api::ExerciseDataDto::CrossVerseConnection {
                 node_id,
                 related_verse_ids,
                 connection_theme,
+                seed,
             } => {
-                <i32>::sse_encode(19, serializer);
+                <i32>::sse_encode(20, serializer);
                 <String>::sse_encode(node_id, serializer);
                 <Vec<String>>::sse_encode(related_verse_ids, serializer);
                 <String>::sse_encode(connection_theme, serializer);
+                <u64>::sse_encode(seed, serializer);
             }
             crate::api::ExerciseDataDto::EchoRecall {
                 user_id,
                 ayah_node_ids,
+                seed,
             } => {
-                <i32>::sse_encode(20, serializer);
+                <i32>::sse_encode(21, serializer);
                 <String>::sse_encode(user_id, serializer);
                 <Vec<String>>::sse_encode(ayah_node_ids, serializer);
+                <u64>::sse_encode(seed, serializer);
+            }
+            crate::api::ExerciseDataDto::Mutashabihat {
+                node_id,
+                verse_key,
+                prompt_word_count,
+                option_verse_keys,
+                differing_positions,
+                seed,
+            } => {
+                <i32>::sse_encode(22, serializer);
+                <String>::sse_encode(node_id, serializer);
+                <String>::sse_encode(verse_key, serializer);
+                <i32>::sse_encode(prompt_word_count, serializer);
+                <Vec<String>>::sse_encode(option_verse_keys, serializer);
+                <Vec<i32>>::sse_encode(differing_positions, serializer);
+                <u64>::sse_encode(seed, serializer);
+            }
+            crate::api::ExerciseDataDto::WordOrder {
+                node_id,
+                correct_sequence,
+                shuffled_word_node_ids,
+                tile_texts,
+                seed,
+            } => {
+                <i32>::sse_encode(23, serializer);
+                <String>::sse_encode(node_id, serializer);
+                <Vec<String>>::sse_encode(correct_sequence, serializer);
+                <Vec<String>>::sse_encode(shuffled_word_node_ids, serializer);
+                <Vec<String>>::sse_encode(tile_texts, serializer);
+                <u64>::sse_encode(seed, serializer);
+            }
+            crate::api::ExerciseDataDto::IdentifySurah {
+                node_id,
+                verse_key,
+                correct_chapter,
+                chapter_options,
+                seed,
+            } => {
+                <i32>::sse_encode(24, serializer);
+                <String>::sse_encode(node_id, serializer);
+                <String>::sse_encode(verse_key, serializer);
+                <i32>::sse_encode(correct_chapter, serializer);
+                <Vec<i32>>::sse_encode(chapter_options, serializer);
+                <u64>::sse_encode(seed, serializer);
+            }
+            crate::api::ExerciseDataDto::IdentifyVerseNumber {
+                node_id,
+                verse_key,
+                correct_verse_number,
+                verse_number_options,
+                seed,
+            } => {
+                <i32>::sse_encode(25, serializer);
+                <String>::sse_encode(node_id, serializer);
+                <String>::sse_encode(verse_key, serializer);
+                <i32>::sse_encode(correct_verse_number, serializer);
+                <Vec<i32>>::sse_encode(verse_number_options, serializer);
+                <u64>::sse_encode(seed, serializer);
+            }
+            crate::api::ExerciseDataDto::IdentifyPageHalf {
+                node_id,
+                verse_key,
+                page,
+                half,
+                seed,
+            } => {
+                <i32>::sse_encode(26, serializer);
+                <String>::sse_encode(node_id, serializer);
+                <String>::sse_encode(verse_key, serializer);
+                <i32>::sse_encode(page, serializer);
+                <i32>::sse_encode(half, serializer);
+                <u64>::sse_encode(seed, serializer);
+            }
+            crate::api::ExerciseDataDto::TransliterationToArabic {
+                node_id,
+                distractor_node_ids,
+                distractor_strategy,
+                seed,
+            } => {
+                <i32>::sse_encode(27, serializer);
+                <String>::sse_encode(node_id, serializer);
+                <Vec<String>>::sse_encode(distractor_node_ids, serializer);
+                <crate::api::DistractorStrategyDto>::sse_encode(distractor_strategy, serializer);
+                <u64>::sse_encode(seed, serializer);
+            }
+            crate::api::ExerciseDataDto::ArabicToTransliteration {
+                node_id,
+                verse_key,
+                seed,
+            } => {
+                <i32>::sse_encode(28, serializer);
+                <String>::sse_encode(node_id, serializer);
+                <String>::sse_encode(verse_key, serializer);
+                <u64>::sse_encode(seed, serializer);
+            }
+            crate::api::ExerciseDataDto::RootFamily {
+                node_id,
+                root_id,
+                verse_keys,
+                family_word_node_ids,
+                seed,
+            } => {
+                <i32>::sse_encode(29, serializer);
+                <String>::sse_encode(node_id, serializer);
+                <String>::sse_encode(root_id, serializer);
+                <Vec<String>>::sse_encode(verse_keys, serializer);
+                <Vec<String>>::sse_encode(family_word_node_ids, serializer);
+                <u64>::sse_encode(seed, serializer);
+            }
+            crate::api::ExerciseDataDto::LemmaMeaning {
+                node_id,
+                root_id,
+                lemma_ids,
+                meaning_lemma_ids,
+                seed,
+            } => {
+                <i32>::sse_encode(30, serializer);
+                <String>::sse_encode(node_id, serializer);
+                <String>::sse_encode(root_id, serializer);
+                <Vec<String>>::sse_encode(lemma_ids, serializer);
+                <Vec<String>>::sse_encode(meaning_lemma_ids, serializer);
+                <u64>::sse_encode(seed, serializer);
+            }
+            crate::api::ExerciseDataDto::RecitePage {
+                node_id,
+                page,
+                verse_keys,
+                first_word_node_id,
+                last_word_node_id,
+                seed,
+            } => {
+                <i32>::sse_encode(31, serializer);
+                <String>::sse_encode(node_id, serializer);
+                <i32>::sse_encode(page, serializer);
+                <Vec<String>>::sse_encode(verse_keys, serializer);
+                <String>::sse_encode(first_word_node_id, serializer);
+                <String>::sse_encode(last_word_node_id, serializer);
+                <u64>::sse_encode(seed, serializer);
+            }
+            crate::api::ExerciseDataDto::PageFirstWord {
+                node_id,
+                page,
+                first_word_node_id,
+                distractor_node_ids,
+                seed,
+            } => {
+                <i32>::sse_encode(32, serializer);
+                <String>::sse_encode(node_id, serializer);
+                <i32>::sse_encode(page, serializer);
+                <String>::sse_encode(first_word_node_id, serializer);
+                <Vec<String>>::sse_encode(distractor_node_ids, serializer);
+                <u64>::sse_encode(seed, serializer);
+            }
+            crate::api::ExerciseDataDto::IdentifyVerseLine {
+                node_id,
+                verse_key,
+                page,
+                correct_line,
+                line_options,
+                seed,
+            } => {
+                <i32>::sse_encode(33, serializer);
+                <String>::sse_encode(node_id, serializer);
+                <String>::sse_encode(verse_key, serializer);
+                <i32>::sse_encode(page, serializer);
+                <i32>::sse_encode(correct_line, serializer);
+                <Vec<i32>>::sse_encode(line_options, serializer);
+                <u64>::sse_encode(seed, serializer);
+            }
+            crate::api::ExerciseDataDto::ListenIdentifyVerse {
+                node_id,
+                reciter_id,
+                verse_key,
+                first_position,
+                last_position,
+                start_ms,
+                end_ms,
+                option_verse_keys,
+                seed,
+            } => {
+                <i32>::sse_encode(34, serializer);
+                <String>::sse_encode(node_id, serializer);
+                <i32>::sse_encode(reciter_id, serializer);
+                <String>::sse_encode(verse_key, serializer);
+                <i32>::sse_encode(first_position, serializer);
+                <i32>::sse_encode(last_position, serializer);
+                <i64>::sse_encode(start_ms, serializer);
+                <i64>::sse_encode(end_ms, serializer);
+                <Vec<String>>::sse_encode(option_verse_keys, serializer);
+                <u64>::sse_encode(seed, serializer);
+            }
+            crate::api::ExerciseDataDto::ListenContinue {
+                node_id,
+                reciter_id,
+                verse_key,
+                first_position,
+                last_position,
+                start_ms,
+                end_ms,
+                distractor_node_ids,
+                distractor_strategy,
+                seed,
+            } => {
+                <i32>::sse_encode(35, serializer);
+                <String>::sse_encode(node_id, serializer);
+                <i32>::sse_encode(reciter_id, serializer);
+                <String>::sse_encode(verse_key, serializer);
+                <i32>::sse_encode(first_position, serializer);
+                <i32>::sse_encode(last_position, serializer);
+                <i64>::sse_encode(start_ms, serializer);
+                <i64>::sse_encode(end_ms, serializer);
+                <Vec<String>>::sse_encode(distractor_node_ids, serializer);
+                <crate::api::DistractorStrategyDto>::sse_encode(distractor_strategy, serializer);
+                <u64>::sse_encode(seed, serializer);
+            }
+            crate::api::ExerciseDataDto::ListenIdentifyWord {
+                node_id,
+                reciter_id,
+                verse_key,
+                position,
+                start_ms,
+                end_ms,
+                distractor_node_ids,
+                distractor_strategy,
+                seed,
+            } => {
+                <i32>::sse_encode(36, serializer);
+                <String>::sse_encode(node_id, serializer);
+                <i32>::sse_encode(reciter_id, serializer);
+                <String>::sse_encode(verse_key, serializer);
+                <i32>::sse_encode(position, serializer);
+                <Option<i64>>::sse_encode(start_ms, serializer);
+                <Option<i64>>::sse_encode(end_ms, serializer);
+                <Vec<String>>::sse_encode(distractor_node_ids, serializer);
+                <crate::api::DistractorStrategyDto>::sse_encode(distractor_strategy, serializer);
+                <u64>::sse_encode(seed, serializer);
+            }
+            crate::api::ExerciseDataDto::IdentifyTajweedRule {
+                node_id,
+                word_node_id,
+                start_char,
+                end_char,
+                correct_rule,
+                rule_options,
+                option_names,
+                seed,
+            } => {
+                <i32>::sse_encode(37, serializer);
+                <String>::sse_encode(node_id, serializer);
+                <String>::sse_encode(word_node_id, serializer);
+                <i32>::sse_encode(start_char, serializer);
+                <i32>::sse_encode(end_char, serializer);
+                <String>::sse_encode(correct_rule, serializer);
+                <Vec<String>>::sse_encode(rule_options, serializer);
+                <Vec<String>>::sse_encode(option_names, serializer);
+                <u64>::sse_encode(seed, serializer);
+            }
+            crate::api::ExerciseDataDto::FindTajweedRule {
+                node_id,
+                rule,
+                rule_name,
+                rule_word_node_ids,
+                seed,
+            } => {
+                <i32>::sse_encode(38, serializer);
+                <String>::sse_encode(node_id, serializer);
+                <String>::sse_encode(rule, serializer);
+                <String>::sse_encode(rule_name, serializer);
+                <Vec<String>>::sse_encode(rule_word_node_ids, serializer);
+                <u64>::sse_encode(seed, serializer);
+            }
+            crate::api::ExerciseDataDto::TafsirKeyLesson {
+                node_id,
+                tafsir_id,
+                passage_key,
+                option_passage_keys,
+                seed,
+            } => {
+                <i32>::sse_encode(39, serializer);
+                <String>::sse_encode(node_id, serializer);
+                <i32>::sse_encode(tafsir_id, serializer);
+                <String>::sse_encode(passage_key, serializer);
+                <Vec<String>>::sse_encode(option_passage_keys, serializer);
+                <u64>::sse_encode(seed, serializer);
+            }
+            crate::api::ExerciseDataDto::RevelationOccasion {
+                node_id,
+                tafsir_id,
+                passage_key,
+                option_verse_keys,
+                seed,
+            } => {
+                <i32>::sse_encode(40, serializer);
+                <String>::sse_encode(node_id, serializer);
+                <i32>::sse_encode(tafsir_id, serializer);
+                <String>::sse_encode(passage_key, serializer);
+                <Vec<String>>::sse_encode(option_verse_keys, serializer);
+                <u64>::sse_encode(seed, serializer);
             }
             _ => {
                 unimplemented!("");
@@ -5951,6 +9291,23 @@ impl SseEncode for crate::api::ExerciseDataDto {
     }
 }
 
+impl SseEncode for crate::api::ExerciseHintDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u8>::sse_encode(self.level, serializer);
+        <String>::sse_encode(self.level_name, serializer);
+        <String>::sse_encode(self.text, serializer);
+        <u8>::sse_encode(self.max_grade, serializer);
+    }
+}
+
+impl SseEncode for f32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f32::<NativeEndian>(self).unwrap();
+    }
+}
+
 impl SseEncode for f64 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6020,6 +9377,16 @@ impl SseEncode for Vec<crate::api::ActivityPointDto> {
     }
 }
 
+impl SseEncode for Vec<crate::api::AyahJunctionDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::AyahJunctionDto>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::api::ContentPackageDto> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6060,6 +9427,16 @@ impl SseEncode for Vec<crate::api::ExerciseDataDto> {
     }
 }
 
+impl SseEncode for Vec<crate::api::ExerciseHintDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::ExerciseHintDto>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::api::InstalledPackageDto> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6110,6 +9487,16 @@ impl SseEncode for Vec<crate::api::NodeSearchDto> {
     }
 }
 
+impl SseEncode for Vec<i32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <i32>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6120,6 +9507,36 @@ impl SseEncode for Vec<u8> {
     }
 }
 
+impl SseEncode for Vec<crate::api::RecitationJumpDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::RecitationJumpDto>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::RecitedWordDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::RecitedWordDto>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::ReciterDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::ReciterDto>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<(String, String)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6180,6 +9597,36 @@ impl SseEncode for Vec<crate::api::SyncSessionItemDto> {
     }
 }
 
+impl SseEncode for Vec<crate::api::TafsirDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::TafsirDto>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::TajweedAnnotationDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::TajweedAnnotationDto>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::TranscriptWordDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::TranscriptWordDto>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::api::TranslatorDto> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6210,6 +9657,16 @@ impl SseEncode for Vec<crate::api::WordDto> {
     }
 }
 
+impl SseEncode for Vec<crate::api::WordSegmentDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::WordSegmentDto>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::api::WordTimingDto> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6258,6 +9715,15 @@ impl SseEncode for crate::api::NodeSearchDto {
     }
 }
 
+impl SseEncode for crate::api::NodeStatusDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.node_id, serializer);
+        <String>::sse_encode(self.status, serializer);
+        <Option<i64>>::sse_encode(self.buried_until, serializer);
+    }
+}
+
 impl SseEncode for Option<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6268,6 +9734,56 @@ impl SseEncode for Option<String> {
     }
 }
 
+impl SseEncode for Option<crate::api::AyahChainStateDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::AyahChainStateDto>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::DistractorStrategyDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::DistractorStrategyDto>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::EchoRecallStateDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::EchoRecallStateDto>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::ExerciseHintDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::ExerciseHintDto>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<f32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <f32>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<f64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6338,6 +9854,16 @@ impl SseEncode for Option<crate::api::SessionItemDto> {
     }
 }
 
+impl SseEncode for Option<crate::api::TafsirPassageDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::TafsirPassageDto>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<crate::api::TranslatorDto> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6348,6 +9874,36 @@ impl SseEncode for Option<crate::api::TranslatorDto> {
     }
 }
 
+impl SseEncode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u64>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u8>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::VerseAudioDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::VerseAudioDto>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<crate::api::VerseDto> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6358,6 +9914,16 @@ impl SseEncode for Option<crate::api::VerseDto> {
     }
 }
 
+impl SseEncode for Option<crate::api::WordAudioDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::WordAudioDto>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<crate::api::WordDto> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6368,6 +9934,16 @@ impl SseEncode for Option<crate::api::WordDto> {
     }
 }
 
+impl SseEncode for Option<Vec<u8>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <Vec<u8>>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for crate::api::PropagationDiagnosticsDto {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6387,6 +9963,56 @@ impl SseEncode for crate::api::PropagationResultDto {
     }
 }
 
+impl SseEncode for crate::api::RecitationJumpDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.verse_key, serializer);
+        <i32>::sse_encode(self.start_position, serializer);
+        <u32>::sse_encode(self.word_count, serializer);
+        <Option<String>>::sse_encode(self.after_word_node_id, serializer);
+        <Option<u64>>::sse_encode(self.start_ms, serializer);
+    }
+}
+
+impl SseEncode for crate::api::RecitationResultDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::RecitedWordDto>>::sse_encode(self.words, serializer);
+        <Vec<crate::api::RecitationJumpDto>>::sse_encode(self.jumps, serializer);
+        <Vec<crate::api::TranscriptWordDto>>::sse_encode(self.extra_words, serializer);
+        <f64>::sse_encode(self.accuracy, serializer);
+        <u8>::sse_encode(self.grade, serializer);
+    }
+}
+
+impl SseEncode for crate::api::RecitedWordDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.word_node_id, serializer);
+        <String>::sse_encode(self.verse_key, serializer);
+        <i32>::sse_encode(self.position, serializer);
+        <String>::sse_encode(self.text, serializer);
+        <String>::sse_encode(self.kind, serializer);
+        <Option<String>>::sse_encode(self.heard, serializer);
+        <f64>::sse_encode(self.similarity, serializer);
+        <bool>::sse_encode(self.repeated, serializer);
+        <Option<u64>>::sse_encode(self.start_ms, serializer);
+        <Option<u64>>::sse_encode(self.end_ms, serializer);
+        <u8>::sse_encode(self.grade, serializer);
+    }
+}
+
+impl SseEncode for crate::api::ReciterDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.reciter_id, serializer);
+        <String>::sse_encode(self.slug, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <Option<String>>::sse_encode(self.style, serializer);
+        <Option<String>>::sse_encode(self.package_id, serializer);
+    }
+}
+
 impl SseEncode for (String, String) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6496,6 +10122,52 @@ impl SseEncode for crate::api::SyncSessionItemDto {
     }
 }
 
+impl SseEncode for crate::api::TafsirDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.tafsir_id, serializer);
+        <String>::sse_encode(self.slug, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <Option<String>>::sse_encode(self.author, serializer);
+        <Option<String>>::sse_encode(self.language_code, serializer);
+    }
+}
+
+impl SseEncode for crate::api::TafsirPassageDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.tafsir_id, serializer);
+        <i32>::sse_encode(self.chapter_number, serializer);
+        <i32>::sse_encode(self.start_verse, serializer);
+        <i32>::sse_encode(self.end_verse, serializer);
+        <String>::sse_encode(self.text, serializer);
+        <Option<String>>::sse_encode(self.key_lesson, serializer);
+        <Option<String>>::sse_encode(self.revelation_occasion, serializer);
+        <Vec<String>>::sse_encode(self.themes, serializer);
+    }
+}
+
+impl SseEncode for crate::api::TajweedAnnotationDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.word_node_id, serializer);
+        <i32>::sse_encode(self.position, serializer);
+        <String>::sse_encode(self.rule, serializer);
+        <String>::sse_encode(self.rule_name, serializer);
+        <i32>::sse_encode(self.start_char, serializer);
+        <i32>::sse_encode(self.end_char, serializer);
+    }
+}
+
+impl SseEncode for crate::api::TranscriptWordDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.text, serializer);
+        <Option<u64>>::sse_encode(self.start_ms, serializer);
+        <Option<u64>>::sse_encode(self.end_ms, serializer);
+    }
+}
+
 impl SseEncode for crate::api::TranslatorDto {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6544,6 +10216,31 @@ impl SseEncode for usize {
     }
 }
 
+impl SseEncode for crate::api::ValidationResultDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_correct, serializer);
+        <Option<String>>::sse_encode(self.feedback, serializer);
+        <Option<f32>>::sse_encode(self.similarity_score, serializer);
+        <Option<String>>::sse_encode(self.semantic_grade, serializer);
+        <Option<String>>::sse_encode(self.correct_answer, serializer);
+        <u8>::sse_encode(self.suggested_grade, serializer);
+    }
+}
+
+impl SseEncode for crate::api::VerseAudioDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.reciter_id, serializer);
+        <String>::sse_encode(self.verse_key, serializer);
+        <Option<String>>::sse_encode(self.audio_path, serializer);
+        <Option<Vec<u8>>>::sse_encode(self.audio_bytes, serializer);
+        <String>::sse_encode(self.mime_type, serializer);
+        <Option<i64>>::sse_encode(self.duration_ms, serializer);
+        <Vec<crate::api::WordSegmentDto>>::sse_encode(self.segments, serializer);
+    }
+}
+
 impl SseEncode for crate::api::VerseDto {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6564,6 +10261,21 @@ impl SseEncode for crate::api::VerseWithTranslationDto {
     }
 }
 
+impl SseEncode for crate::api::WordAudioDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.reciter_id, serializer);
+        <String>::sse_encode(self.verse_key, serializer);
+        <i32>::sse_encode(self.position, serializer);
+        <Option<String>>::sse_encode(self.audio_path, serializer);
+        <Option<Vec<u8>>>::sse_encode(self.audio_bytes, serializer);
+        <String>::sse_encode(self.mime_type, serializer);
+        <Option<i64>>::sse_encode(self.duration_ms, serializer);
+        <Option<i64>>::sse_encode(self.start_ms, serializer);
+        <Option<i64>>::sse_encode(self.end_ms, serializer);
+    }
+}
+
 impl SseEncode for crate::api::WordDto {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6574,6 +10286,15 @@ impl SseEncode for crate::api::WordDto {
     }
 }
 
+impl SseEncode for crate::api::WordSegmentDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.position, serializer);
+        <i64>::sse_encode(self.start_ms, serializer);
+        <i64>::sse_encode(self.end_ms, serializer);
+    }
+}
+
 impl SseEncode for crate::api::WordTimingDto {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -0,0 +1,205 @@
+//! Flutter-facing API tests against the sample content database
+
+use iqrah_api::api::{self, DistractorStrategyDto, ExerciseDataDto, SyncMemoryStateDto};
+use iqrah_core::domain::node_id;
+use once_cell::sync::Lazy;
+use std::future::Future;
+use std::sync::Mutex;
+use tokio::runtime::Runtime;
+
+/// The API keeps its state in a process-wide `OnceCell`, so the tests share
+/// one runtime (the pools are bound to it) and one pair of databases.
+static RUNTIME: Lazy<Runtime> = Lazy::new(|| {
+    let runtime = Runtime::new().expect("tokio runtime");
+    runtime.block_on(async {
+        let dir = tempfile::tempdir().expect("temp dir").keep();
+        let content_db = dir.join("content.db").to_string_lossy().into_owned();
+        let user_db = dir.join("user.db").to_string_lossy().into_owned();
+        iqrah_storage::init_test_content_db(&content_db)
+            .await
+            .expect("seed content db");
+        api::setup_database(content_db, user_db, vec![])
+            .await
+            .expect("setup database");
+    });
+    runtime
+});
+
+fn run<F: Future>(future: F) -> F::Output {
+    RUNTIME.block_on(future)
+}
+
+fn to_json(exercise: &ExerciseDataDto) -> serde_json::Value {
+    serde_json::to_value(exercise).unwrap()
+}
+
+/// The API tracks one active session at a time
+static SESSION: Mutex<()> = Mutex::new(());
+
+/// Start a session for a user with a new verse (VERSE:1:1) due now
+async fn start_new_verse_session(user_id: &str) -> api::SessionDto {
+    let now = chrono::Utc::now().timestamp_millis();
+    api::upsert_memory_states_from_remote(
+        user_id.to_string(),
+        vec![SyncMemoryStateDto {
+            node_id: node_id::from_ukey("VERSE:1:1:memorization").unwrap(),
+            energy: 0.0,
+            fsrs_stability: None,
+            fsrs_difficulty: None,
+            last_reviewed_at: None,
+            next_review_at: Some(now - 1_000),
+            client_updated_at: now - 1_000,
+        }],
+    )
+    .await
+    .unwrap();
+    api::start_session(user_id.to_string(), "memorization:chapters-1-3".into())
+        .await
+        .unwrap()
+}
+
+#[test]
+fn test_regenerate_exercise_from_seed() {
+    run(async {
+        for exercise_type in ["word_order", "cloze_deletion", "missing_word_mcq"] {
+            let regenerate =
+                || api::regenerate_exercise("VERSE:1:1".into(), exercise_type.into(), 42, None);
+            let first = to_json(&regenerate().await.unwrap());
+            let second = to_json(&regenerate().await.unwrap());
+            assert_eq!(first, second, "{exercise_type} differs between generations");
+
+            let (_, fields) = first.as_object().unwrap().iter().next().unwrap();
+            assert_eq!(fields["seed"], 42, "{exercise_type} lost its seed");
+        }
+    });
+}
+
+#[test]
+fn test_regenerate_mcq_from_its_distractor_strategy() {
+    run(async {
+        let hard = DistractorStrategyDto {
+            difficulty: "hard".into(),
+            signals: vec![],
+        };
+        let served =
+            api::regenerate_exercise("VERSE:1:1".into(), "missing_word_mcq".into(), 7, Some(hard))
+                .await
+                .unwrap();
+        let ExerciseDataDto::MissingWordMcq {
+            distractor_strategy,
+            ..
+        } = &served
+        else {
+            panic!("expected a missing word MCQ, got {served:?}");
+        };
+        assert_eq!(distractor_strategy.difficulty, "hard");
+
+        let rebuilt = api::regenerate_exercise(
+            "VERSE:1:1".into(),
+            "missing_word_mcq".into(),
+            7,
+            Some(distractor_strategy.clone()),
+        )
+        .await
+        .unwrap();
+        assert_eq!(to_json(&served), to_json(&rebuilt));
+
+        let unknown = DistractorStrategyDto {
+            difficulty: "extreme".into(),
+            signals: vec![],
+        };
+        assert!(api::regenerate_exercise(
+            "VERSE:1:1".into(),
+            "missing_word_mcq".into(),
+            7,
+            Some(unknown),
+        )
+        .await
+        .is_err());
+    });
+}
+
+#[test]
+fn test_serve_and_submit_echo_recall_session_item() {
+    let _session = SESSION.lock().unwrap_or_else(|e| e.into_inner());
+    run(async {
+        let user_id = "echo_recall_user".to_string();
+        let session = start_new_verse_session(&user_id).await;
+        // New verses are practiced with Echo Recall first
+        let item = api::get_next_session_item(session.id.clone())
+            .await
+            .unwrap()
+            .expect("session item");
+        assert_eq!(item.exercise_type, "echo_recall");
+        let ExerciseDataDto::EchoRecall { ayah_node_ids, .. } = item.exercise.clone() else {
+            panic!("expected Echo Recall, got {:?}", item.exercise);
+        };
+
+        // Recalling a word checkpoints the progress over the served exercise
+        let state = api::start_echo_recall(user_id.clone(), ayah_node_ids.clone())
+            .await
+            .unwrap();
+        let first_word = state.words[0].node_id.clone();
+        api::submit_session_echo_recall(
+            session.id.clone(),
+            item.position,
+            user_id,
+            ayah_node_ids,
+            state,
+            first_word,
+            800,
+        )
+        .await
+        .unwrap();
+
+        let served_again = api::get_next_session_item(session.id.clone())
+            .await
+            .unwrap()
+            .expect("session item");
+        assert_eq!(to_json(&served_again.exercise), to_json(&item.exercise));
+
+        let text = api::get_verse("1:1".into())
+            .await
+            .unwrap()
+            .unwrap()
+            .text_uthmani;
+        let result = api::submit_session_answer(session.id.clone(), text, 5_000, 0)
+            .await
+            .unwrap();
+        assert!(result.is_correct, "{result:?}");
+    });
+}
+
+#[test]
+fn test_submit_session_item_grades_the_served_exercise() {
+    let _session = SESSION.lock().unwrap_or_else(|e| e.into_inner());
+    run(async {
+        let user_id = "self_checked_user".to_string();
+        let session = start_new_verse_session(&user_id).await;
+
+        // Nothing served yet, so there is nothing to grade
+        let err = api::submit_session_item(session.id.clone(), true, 4_000, 0)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("No exercise served"), "{err}");
+
+        api::get_next_session_item(session.id.clone())
+            .await
+            .unwrap()
+            .expect("session item");
+        api::submit_session_item(session.id.clone(), true, 4_000, 0)
+            .await
+            .unwrap();
+        let session = api::get_active_session(user_id)
+            .await
+            .unwrap()
+            .expect("active session");
+        assert_eq!(session.items_completed, 1);
+
+        // An invalid hint level is rejected
+        let err = api::submit_session_item(session.id, true, 4_000, 9)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("Invalid hint level"), "{err}");
+    });
+}
@@ -94,6 +94,16 @@ impl ExerciseClient {
         self.send_command(command).await
     }
 
+    /// Resume a session from its last checkpoint
+    pub async fn resume_session(&self, session_id: &str) -> Result<serde_json::Value> {
+        let command = json!({
+            "type": "ResumeExercise",
+            "session_id": session_id,
+        });
+
+        self.send_command(command).await
+    }
+
     /// End a session
     pub async fn end_session(&self, session_id: &str) -> Result<serde_json::Value> {
         let command = json!({
@@ -215,6 +225,14 @@ pub async fn action(
     Ok(())
 }
 
+/// Resume an exercise session from its last checkpoint
+pub async fn resume(config: &ServerConfig, session_id: &str) -> Result<()> {
+    let client = ExerciseClient::new(config.clone());
+    let response = client.resume_session(session_id).await?;
+    println!("{}", serde_json::to_string_pretty(&response)?);
+    Ok(())
+}
+
 /// End an exercise session
 pub async fn end(config: &ServerConfig, session_id: &str) -> Result<()> {
     let client = ExerciseClient::new(config.clone());
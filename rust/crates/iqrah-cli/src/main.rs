@@ -231,6 +231,11 @@ enum ExerciseCommands {
         /// Recall time in milliseconds
         recall_time_ms: u32,
     },
+    /// Resume an exercise session from its last checkpoint
+    Resume {
+        /// Session ID
+        session_id: String,
+    },
     /// End an exercise session
     End {
         /// Session ID
@@ -306,6 +311,9 @@ async fn main() -> Result<()> {
                     )
                     .await?;
                 }
+                ExerciseCommands::Resume { session_id } => {
+                    exercise::resume(&config, &session_id).await?;
+                }
                 ExerciseCommands::End { session_id } => {
                     exercise::end(&config, &session_id).await?;
                 }
@@ -4,8 +4,8 @@ use colored::*;
 use iqrah_core::domain::node_id as nid;
 use iqrah_core::{
     scheduler_v2::{
-        blend_profile, generate_session, BanditOptimizer, ProfileName, SessionMode, UserProfile,
-        BLEND_RATIO, DEFAULT_SAFE_PROFILE,
+        blend_profile, exclude_inactive_candidates, generate_session, BanditOptimizer, ProfileName,
+        SessionMode, UserProfile, BLEND_RATIO, DEFAULT_SAFE_PROFILE,
     },
     ContentRepository, UserRepository,
 };
@@ -64,6 +64,10 @@ pub async fn generate(
     println!("   Fetching candidates for goal...");
     let mut candidates = content_repo.get_scheduler_candidates(goal_id).await?;
 
+    // Drop suspended/buried nodes before any scoring
+    let inactive_ids = user_repo.get_inactive_node_ids(user_id, Utc::now()).await?;
+    exclude_inactive_candidates(&mut candidates, &inactive_ids);
+
     if candidates.is_empty() {
         println!();
         println!(
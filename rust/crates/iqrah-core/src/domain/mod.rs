@@ -1,7 +1,11 @@
 pub mod error;
 pub mod errors;
 pub mod models;
+pub mod morphology;
 pub mod node_id;
+pub mod tajweed;
 
 pub use errors::*;
 pub use models::*;
+pub use morphology::{GrammarFeature, MorphologyFeatures};
+pub use tajweed::{parse_tajweed_markup, TajweedRule, TajweedSpan};
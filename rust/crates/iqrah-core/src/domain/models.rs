@@ -2,6 +2,9 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use super::morphology::MorphologyFeatures;
+use super::tajweed::TajweedRule;
+
 // Node types
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Hash, Copy)]
 #[serde(rename_all = "snake_case")]
@@ -262,6 +265,8 @@ pub struct SessionItem {
     pub grade: i32,
     pub duration_ms: Option<i64>,
     pub completed_at: Option<DateTime<Utc>>,
+    /// Most revealing hint shown before answering
+    pub hint_level: Option<HintLevel>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -276,6 +281,42 @@ pub struct SessionSummary {
     pub easy_count: i32,
 }
 
+/// Saved in-progress state of a stateful exercise, keyed by session item
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExerciseCheckpoint {
+    pub session_id: String,
+    /// Position of the item in the session
+    pub position: i32,
+    pub user_id: String,
+    pub exercise_type: String,
+    pub node_id: i64,
+    /// The exercise serialized as JSON
+    pub state: String,
+    pub updated_at: DateTime<Utc>,
+}
+
+// Scheduling status (suspend / bury)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NodeStatus {
+    /// Node participates in scheduling normally
+    Active,
+    /// Node is excluded from scheduling until explicitly unsuspended
+    Suspended,
+    /// Node is excluded from scheduling until the given instant
+    BuriedUntil(DateTime<Utc>),
+}
+
+impl NodeStatus {
+    /// Whether the node may be scheduled at `now`.
+    pub fn is_active_at(&self, now: DateTime<Utc>) -> bool {
+        match self {
+            NodeStatus::Active => true,
+            NodeStatus::Suspended => false,
+            NodeStatus::BuriedUntil(until) => *until <= now,
+        }
+    }
+}
+
 // Review grades
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ReviewGrade {
@@ -392,6 +433,57 @@ pub struct Verse {
     pub page: i32,
 }
 
+/// A verse that is easily confused with another (mutashabih)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SimilarVerse {
+    /// Key of the similar verse (e.g., "7:54")
+    pub verse_key: String,
+    /// Number of leading words shared with the source verse
+    pub shared_prefix_words: i32,
+    /// Similarity score (0.0 to 1.0)
+    pub similarity: f64,
+}
+
+/// One line of the 15-line Madani mushaf
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MushafLine {
+    /// Mushaf page number (1 to 604)
+    pub page: i32,
+    /// Line number on the page (1 to 15)
+    pub line_number: i32,
+    /// "ayah", "surah_name" or "basmallah"
+    pub line_type: String,
+    /// First word-instance node ID on the line (None for header lines)
+    pub first_word_id: Option<i64>,
+    /// Last word-instance node ID on the line (None for header lines)
+    pub last_word_id: Option<i64>,
+}
+
+impl MushafLine {
+    /// Whether the word-instance node ID falls on this line
+    pub fn contains_word(&self, word_id: i64) -> bool {
+        matches!(
+            (self.first_word_id, self.last_word_id),
+            (Some(first), Some(last)) if (first..=last).contains(&word_id)
+        )
+    }
+}
+
+/// A tajweed rule on a span of a word's Uthmani text
+#[derive(Debug, Clone, PartialEq)]
+pub struct TajweedAnnotation {
+    /// Word-instance node ID
+    pub word_id: i64,
+    pub verse_key: String,
+    /// Word position in the verse (1-indexed)
+    pub position: i32,
+    pub rule: TajweedRule,
+    /// First character of the span (0-indexed)
+    pub start_char: i32,
+    /// End of the span (exclusive)
+    pub end_char: i32,
+}
+
 /// Represents a word instance within a verse
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Word {
@@ -403,6 +495,41 @@ pub struct Word {
     pub transliteration: Option<String>,
 }
 
+/// Arabic script the Quranic text is rendered in
+///
+/// Matches a `script_resources` slug. Content missing in a script falls back
+/// to Uthmani.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum Script {
+    #[default]
+    Uthmani,
+    Indopak,
+    Simple,
+}
+
+impl Script {
+    /// User setting holding the preferred script's slug
+    pub const SETTING_KEY: &'static str = "preferred_script";
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Script::Uthmani => "uthmani",
+            Script::Indopak => "indopak",
+            Script::Simple => "simple",
+        }
+    }
+
+    pub fn from_slug(slug: &str) -> Option<Self> {
+        match slug {
+            "uthmani" => Some(Script::Uthmani),
+            "indopak" => Some(Script::Indopak),
+            "simple" => Some(Script::Simple),
+            _ => None,
+        }
+    }
+}
+
 /// Represents a language
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Language {
@@ -435,6 +562,60 @@ pub enum Hint {
     Both { first: char, last: char },
 }
 
+// ===== Progressive Hints =====
+
+/// A rung of the progressive hint ladder, least revealing first.
+///
+/// Revealing a level caps the grade an answer can earn (see `max_grade`).
+/// Stored on session items as its numeric value.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum HintLevel {
+    FirstLetter = 1,
+    FirstWord = 2,
+    WordCount = 3,
+    Translation = 4,
+    PartialReveal = 5,
+}
+
+impl HintLevel {
+    /// All levels in ladder order
+    pub const LADDER: [HintLevel; 5] = [
+        HintLevel::FirstLetter,
+        HintLevel::FirstWord,
+        HintLevel::WordCount,
+        HintLevel::Translation,
+        HintLevel::PartialReveal,
+    ];
+
+    /// Level from its stored value (1-5)
+    pub fn from_level(level: u8) -> Option<Self> {
+        Self::LADDER.get((level as usize).checked_sub(1)?).copied()
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HintLevel::FirstLetter => "first_letter",
+            HintLevel::FirstWord => "first_word",
+            HintLevel::WordCount => "word_count",
+            HintLevel::Translation => "translation",
+            HintLevel::PartialReveal => "partial_reveal",
+        }
+    }
+
+    /// Highest grade an answer can earn once this level has been revealed
+    pub fn max_grade(&self) -> ReviewGrade {
+        match self {
+            HintLevel::FirstLetter => ReviewGrade::Good,
+            HintLevel::FirstWord | HintLevel::WordCount | HintLevel::Translation => {
+                ReviewGrade::Hard
+            }
+            // Most of the answer was shown, so it was not really recalled
+            HintLevel::PartialReveal => ReviewGrade::Again,
+        }
+    }
+}
+
 /// Represents how a single word should be displayed.
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(tag = "type")]
@@ -528,6 +709,44 @@ pub struct EchoRecallStats {
     pub mastery_percentage: f64,
 }
 
+/// Transition between two consecutive ayahs in an Echo Recall session
+///
+/// The closing word of one ayah cues the opening word of the next. The
+/// junction is only as strong as the weaker of the two.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct AyahJunction {
+    /// Ayah ending at the junction (e.g., "VERSE:1:1")
+    pub from_verse: String,
+    /// Ayah starting at the junction (e.g., "VERSE:1:2")
+    pub to_verse: String,
+    /// Last word of `from_verse`
+    pub closing_word: String,
+    /// First word of `to_verse`
+    pub opening_word: String,
+    /// Energy of the weaker of the two words (0.0 to 1.0)
+    pub strength: f64,
+}
+
+impl AyahJunction {
+    /// Review grade of the transition
+    pub fn grade(&self) -> ReviewGrade {
+        match self.strength {
+            s if s < 0.3 => ReviewGrade::Again,
+            s if s < 0.6 => ReviewGrade::Hard,
+            s if s < 0.85 => ReviewGrade::Good,
+            _ => ReviewGrade::Easy,
+        }
+    }
+
+    /// Node the transition is scheduled on: the contextual memorization of
+    /// the ayah it leads into
+    pub fn transition_node_id(&self) -> Option<i64> {
+        super::node_id::from_ukey(&self.to_verse).map(|verse_id| {
+            super::node_id::encode_knowledge(verse_id, KnowledgeAxis::ContextualMemorization)
+        })
+    }
+}
+
 // ===== Package Management Models =====
 
 /// Package types for downloadable content
@@ -540,6 +759,7 @@ pub enum PackageType {
     VerseRecitation,
     WordAudio,
     Transliteration,
+    Tafsir,
 }
 
 impl std::fmt::Display for PackageType {
@@ -551,6 +771,7 @@ impl std::fmt::Display for PackageType {
             PackageType::VerseRecitation => write!(f, "verse_recitation"),
             PackageType::WordAudio => write!(f, "word_audio"),
             PackageType::Transliteration => write!(f, "transliteration"),
+            PackageType::Tafsir => write!(f, "tafsir"),
         }
     }
 }
@@ -566,6 +787,7 @@ impl std::str::FromStr for PackageType {
             "verse_recitation" => Ok(PackageType::VerseRecitation),
             "word_audio" => Ok(PackageType::WordAudio),
             "transliteration" => Ok(PackageType::Transliteration),
+            "tafsir" => Ok(PackageType::Tafsir),
             _ => Err(anyhow::anyhow!("Invalid package type: {}", s)),
         }
     }
@@ -595,6 +817,143 @@ pub struct InstalledPackage {
     pub enabled: bool,
 }
 
+/// Rows a package installs, written in one transaction by
+/// `ContentRepository::install_package_content`
+///
+/// The rows belong to a translator, reciter or tafsir created for the
+/// package. Its id is assigned on insert, so the ids set here are ignored.
+#[derive(Debug, Clone)]
+pub enum PackageContent {
+    /// Verse translations as (verse_key, translation)
+    VerseTranslations {
+        translator: Translator,
+        translations: Vec<(String, String)>,
+    },
+    VerseRecitations {
+        reciter: Reciter,
+        recitations: Vec<VerseRecitation>,
+    },
+    WordAudio {
+        reciter: Reciter,
+        clips: Vec<WordAudioClip>,
+    },
+    Tafsir {
+        tafsir: Tafsir,
+        passages: Vec<TafsirPassage>,
+    },
+}
+
+// ===== Recitation Audio Models =====
+
+/// A reciter installed from a recitation or word audio package
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Reciter {
+    pub reciter_id: i32,
+    pub slug: String,
+    pub name: String,
+    /// e.g., "murattal", "mujawwad"
+    pub style: Option<String>,
+    pub package_id: Option<String>,
+}
+
+/// Where the bytes of an audio clip are stored
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum AudioSource {
+    /// Path to an audio file on disk
+    File(String),
+    /// Audio bytes stored in the database
+    Blob(Vec<u8>),
+}
+
+/// Timing of one word within a verse recitation
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct WordSegment {
+    /// 1-indexed word position in the verse
+    pub position: i32,
+    pub start_ms: i64,
+    pub end_ms: i64,
+}
+
+/// Recitation of a whole verse, with word timings when the package has them
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct VerseRecitation {
+    pub reciter_id: i32,
+    pub verse_key: String,
+    pub source: AudioSource,
+    /// e.g., "audio/mpeg"
+    pub mime_type: String,
+    pub duration_ms: Option<i64>,
+    /// Word segments ordered by position
+    pub segments: Vec<WordSegment>,
+}
+
+impl VerseRecitation {
+    /// Timing of the word at `position`
+    pub fn segment(&self, position: i32) -> Option<&WordSegment> {
+        self.segments.iter().find(|s| s.position == position)
+    }
+}
+
+/// Audio of a single word
+///
+/// Either a standalone clip (no start/end: play it whole) or a segment of the
+/// verse recitation (play `source` from `start_ms` to `end_ms`).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WordAudioClip {
+    pub reciter_id: i32,
+    pub verse_key: String,
+    pub position: i32,
+    pub source: AudioSource,
+    pub mime_type: String,
+    pub duration_ms: Option<i64>,
+    pub start_ms: Option<i64>,
+    pub end_ms: Option<i64>,
+}
+
+// ===== Tafsir Models =====
+
+/// A tafsir (commentary) installed from a tafsir package
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Tafsir {
+    pub tafsir_id: i32,
+    pub slug: String,
+    pub name: String,
+    pub author: Option<String>,
+    pub language_code: Option<String>,
+    pub package_id: Option<String>,
+}
+
+/// Commentary on a range of verses of one chapter
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TafsirPassage {
+    pub tafsir_id: i32,
+    pub chapter_number: i32,
+    pub start_verse: i32,
+    /// Last verse commented on (inclusive)
+    pub end_verse: i32,
+    pub text: String,
+    pub key_lesson: Option<String>,
+    /// Occasion of revelation (asbab al-nuzul), when reported
+    pub revelation_occasion: Option<String>,
+    /// e.g., "patience", "tawhid"
+    pub themes: Vec<String>,
+}
+
+impl TafsirPassage {
+    /// Key of the first verse commented on (e.g., "2:153"), which identifies
+    /// the passage within its tafsir
+    pub fn start_verse_key(&self) -> String {
+        format!("{}:{}", self.chapter_number, self.start_verse)
+    }
+
+    /// Whether the passage comments on a verse
+    pub fn covers(&self, chapter_number: i32, verse_number: i32) -> bool {
+        self.chapter_number == chapter_number
+            && (self.start_verse..=self.end_verse).contains(&verse_number)
+    }
+}
+
 // ===== Morphology Models =====
 
 /// Represents an Arabic root (جذر)
@@ -613,6 +972,7 @@ pub struct Lemma {
     pub arabic: String,          // Arabic text of the lemma
     pub root_id: Option<String>, // Reference to the root
     pub transliteration: Option<String>,
+    pub description: Option<String>, // Short meaning, e.g., "to know"
 }
 
 /// Represents a morphological segment of a word
@@ -624,4 +984,23 @@ pub struct MorphologySegment {
     pub lemma_id: Option<String>,
     pub root_id: Option<String>,
     pub pos_tag: Option<String>, // Part of speech (noun, verb, particle, etc.)
+    #[serde(default)]
+    pub features: MorphologyFeatures,
+}
+
+/// Words derived from a root within a set of verses
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RootOccurrences {
+    pub root_id: String,
+    /// Words derived from the root across the whole Quran
+    pub occurrences: u32,
+    /// Derived words within the requested verses, in mushaf order
+    pub word_ids: Vec<i64>,
+}
+
+/// A lemma with the number of words derived from it across the Quran
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LemmaOccurrences {
+    pub lemma: Lemma,
+    pub occurrences: u32,
 }
@@ -0,0 +1,283 @@
+// domain/morphology.rs
+// Grammatical features of morphology segments
+//
+// Features are parsed from the Quranic Arabic Corpus v0.5 FEATURES column
+// (e.g. "V|IMPF|VF:10|LEM:...|ROOT:...|1P|MOOD:IND") and stored as
+// lowercase English labels ("imperfect", "plural", ...). Verb forms are
+// stored as Roman numerals ("I" to "XII").
+
+use serde::{Deserialize, Serialize};
+
+/// Grammatical features of a morphology segment
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MorphologyFeatures {
+    pub verb_form: Option<String>,
+    pub tense: Option<String>,
+    pub mood: Option<String>,
+    pub person: Option<String>,
+    pub number: Option<String>,
+    pub gender: Option<String>,
+    pub case: Option<String>,
+    pub state: Option<String>,
+}
+
+const VERB_FORMS: &[&str] = &[
+    "I", "II", "III", "IV", "V", "VI", "VII", "VIII", "IX", "X", "XI", "XII",
+];
+
+impl MorphologyFeatures {
+    /// Parse the corpus FEATURES column of one segment
+    ///
+    /// The first tag is the part of speech: a "DET" segment (the prefix al-)
+    /// makes the word definite, and the accusative particle "ACC" (inna and
+    /// its sisters) is not a case. Form I verbs carry no "VF:" tag, so any
+    /// verb with a tense and no form is recorded as form I.
+    pub fn from_corpus_features(features: &str) -> Self {
+        let mut parsed = Self::default();
+        let mut tags = features.split('|');
+        if tags.next() == Some("DET") {
+            parsed.state = Some("definite".to_string());
+        }
+        for tag in tags {
+            let (slot, label) = match tag {
+                "PERF" => (&mut parsed.tense, "perfect"),
+                "IMPF" => (&mut parsed.tense, "imperfect"),
+                "IMPV" => (&mut parsed.tense, "imperative"),
+                "MOOD:IND" => (&mut parsed.mood, "indicative"),
+                "MOOD:SUBJ" => (&mut parsed.mood, "subjunctive"),
+                "MOOD:JUS" => (&mut parsed.mood, "jussive"),
+                "NOM" => (&mut parsed.case, "nominative"),
+                "ACC" => (&mut parsed.case, "accusative"),
+                "GEN" => (&mut parsed.case, "genitive"),
+                "INDEF" => (&mut parsed.state, "indefinite"),
+                _ => {
+                    if let Some(form) = tag
+                        .strip_prefix("VF:")
+                        .and_then(|n| n.parse::<usize>().ok())
+                        .and_then(|n| VERB_FORMS.get(n.checked_sub(1)?))
+                    {
+                        parsed.verb_form = Some(form.to_string());
+                    } else {
+                        parsed.parse_person_gender_number(tag);
+                    }
+                    continue;
+                }
+            };
+            *slot = Some(label.to_string());
+        }
+        if parsed.tense.is_some() && parsed.verb_form.is_none() {
+            parsed.verb_form = Some("I".to_string());
+        }
+        parsed
+    }
+
+    /// Parse a combined person/gender/number tag such as "3MS", "2D" or "FP"
+    ///
+    /// A tag must carry a person or a gender so that tags like "P" are not
+    /// mistaken for a number.
+    fn parse_person_gender_number(&mut self, tag: &str) {
+        let mut chars = tag.chars().peekable();
+        let person = chars.next_if(|c| matches!(c, '1'..='3'));
+        let gender = chars.next_if(|c| matches!(c, 'M' | 'F'));
+        let number = chars.next_if(|c| matches!(c, 'S' | 'D' | 'P'));
+        if chars.next().is_some() || (person.is_none() && gender.is_none()) {
+            return;
+        }
+        let label = |value: &str| Some(value.to_string());
+        if let Some(person) = person {
+            self.person = label(match person {
+                '1' => "first",
+                '2' => "second",
+                _ => "third",
+            });
+        }
+        if let Some(gender) = gender {
+            self.gender = label(if gender == 'M' {
+                "masculine"
+            } else {
+                "feminine"
+            });
+        }
+        if let Some(number) = number {
+            self.number = label(match number {
+                'S' => "singular",
+                'D' => "dual",
+                _ => "plural",
+            });
+        }
+    }
+
+    /// Combine the features of a word's segments, the first segment
+    /// carrying a feature wins
+    pub fn merge(segments: impl IntoIterator<Item = Self>) -> Self {
+        let mut merged = Self::default();
+        for segment in segments {
+            for feature in GrammarFeature::ALL {
+                let slot = feature.value_mut(&mut merged);
+                if slot.is_none() {
+                    *slot = feature.value(&segment).map(str::to_string);
+                }
+            }
+        }
+        merged
+    }
+}
+
+/// A grammatical feature a learner can be asked to identify
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GrammarFeature {
+    VerbForm,
+    Tense,
+    Mood,
+    Person,
+    Number,
+    Gender,
+    Case,
+    State,
+}
+
+impl GrammarFeature {
+    pub const ALL: [GrammarFeature; 8] = [
+        Self::VerbForm,
+        Self::Tense,
+        Self::Mood,
+        Self::Person,
+        Self::Number,
+        Self::Gender,
+        Self::Case,
+        Self::State,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::VerbForm => "verb_form",
+            Self::Tense => "tense",
+            Self::Mood => "mood",
+            Self::Person => "person",
+            Self::Number => "number",
+            Self::Gender => "gender",
+            Self::Case => "case",
+            Self::State => "state",
+        }
+    }
+
+    pub fn from_slug(slug: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|f| f.as_str() == slug)
+    }
+
+    /// Every label this feature can take
+    pub fn values(&self) -> &'static [&'static str] {
+        match self {
+            Self::VerbForm => VERB_FORMS,
+            Self::Tense => &["perfect", "imperfect", "imperative"],
+            Self::Mood => &["indicative", "subjunctive", "jussive"],
+            Self::Person => &["first", "second", "third"],
+            Self::Number => &["singular", "dual", "plural"],
+            Self::Gender => &["masculine", "feminine"],
+            Self::Case => &["nominative", "accusative", "genitive"],
+            Self::State => &["definite", "indefinite"],
+        }
+    }
+
+    /// Question shown to the learner
+    pub fn prompt(&self) -> &'static str {
+        match self {
+            Self::VerbForm => "Which verb form is this word?",
+            Self::Tense => "Which tense is this verb?",
+            Self::Mood => "Which mood is this verb?",
+            Self::Person => "Which person is this word?",
+            Self::Number => "Is this word singular, dual or plural?",
+            Self::Gender => "Is this word masculine or feminine?",
+            Self::Case => "Which case is this word in?",
+            Self::State => "Is this word definite or indefinite?",
+        }
+    }
+
+    pub fn value<'a>(&self, features: &'a MorphologyFeatures) -> Option<&'a str> {
+        match self {
+            Self::VerbForm => features.verb_form.as_deref(),
+            Self::Tense => features.tense.as_deref(),
+            Self::Mood => features.mood.as_deref(),
+            Self::Person => features.person.as_deref(),
+            Self::Number => features.number.as_deref(),
+            Self::Gender => features.gender.as_deref(),
+            Self::Case => features.case.as_deref(),
+            Self::State => features.state.as_deref(),
+        }
+    }
+
+    fn value_mut<'a>(&self, features: &'a mut MorphologyFeatures) -> &'a mut Option<String> {
+        match self {
+            Self::VerbForm => &mut features.verb_form,
+            Self::Tense => &mut features.tense,
+            Self::Mood => &mut features.mood,
+            Self::Person => &mut features.person,
+            Self::Number => &mut features.number,
+            Self::Gender => &mut features.gender,
+            Self::Case => &mut features.case,
+            Self::State => &mut features.state,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_verb_features() {
+        // nasta'inu (1:5)
+        let features = MorphologyFeatures::from_corpus_features(
+            "V|IMPF|VF:10|LEM:ٱسْتَعَانَ|ROOT:عون|1P|MOOD:IND",
+        );
+        assert_eq!(features.verb_form.as_deref(), Some("X"));
+        assert_eq!(features.tense.as_deref(), Some("imperfect"));
+        assert_eq!(features.mood.as_deref(), Some("indicative"));
+        assert_eq!(features.person.as_deref(), Some("first"));
+        assert_eq!(features.number.as_deref(), Some("plural"));
+        assert_eq!(features.gender, None);
+        assert_eq!(features.case, None);
+
+        // Form I is implicit: na'budu (1:5)
+        let features =
+            MorphologyFeatures::from_corpus_features("V|IMPF|LEM:عَبَدَ|ROOT:عبد|1P|MOOD:IND");
+        assert_eq!(features.verb_form.as_deref(), Some("I"));
+    }
+
+    #[test]
+    fn test_parse_noun_features() {
+        let features = MorphologyFeatures::from_corpus_features("N|LEM:ٱسْم|ROOT:سمو|M|GEN");
+        assert_eq!(features.gender.as_deref(), Some("masculine"));
+        assert_eq!(features.case.as_deref(), Some("genitive"));
+        assert_eq!(features.number, None);
+        assert_eq!(features.verb_form, None);
+
+        let features = MorphologyFeatures::from_corpus_features("N|LEM:صِرَٰط|ROOT:صرط|M|ACC");
+        assert_eq!(features.case.as_deref(), Some("accusative"));
+
+        // "P" is the particle tag, not a number
+        let features = MorphologyFeatures::from_corpus_features("P|PREF|LEM:ب");
+        assert_eq!(features, MorphologyFeatures::default());
+
+        // The accusative particle inna (2:6) has no case
+        let features = MorphologyFeatures::from_corpus_features("ACC|LEM:إِنّ|FAM:إِنّ");
+        assert_eq!(features, MorphologyFeatures::default());
+
+        // al-hamdu (1:2)
+        let merged = MorphologyFeatures::merge([
+            MorphologyFeatures::from_corpus_features("DET|PREF|LEM:ال"),
+            MorphologyFeatures::from_corpus_features("N|LEM:حَمْد|ROOT:حمد|M|NOM"),
+        ]);
+        assert_eq!(merged.state.as_deref(), Some("definite"));
+        assert_eq!(merged.gender.as_deref(), Some("masculine"));
+        assert_eq!(merged.case.as_deref(), Some("nominative"));
+    }
+
+    #[test]
+    fn test_feature_slug_roundtrip() {
+        for feature in GrammarFeature::ALL {
+            assert_eq!(GrammarFeature::from_slug(feature.as_str()), Some(feature));
+        }
+    }
+}
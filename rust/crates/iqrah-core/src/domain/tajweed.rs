@@ -0,0 +1,286 @@
+// domain/tajweed.rs
+// Tajweed rules annotated on spans of words
+//
+// Annotations come from QUL's tajweed-tagged Uthmani text, where each rule
+// wraps the letters it applies to: `<tajweed class=ikhafa>نْ</tajweed>` (older
+// exports use `<rule class=...>`). Spans are stored as character offsets into
+// the word's text with the tags removed.
+
+use serde::{Deserialize, Serialize};
+
+/// A tajweed rule
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TajweedRule {
+    HamzatWasl,
+    Silent,
+    LamShamsiyyah,
+    MaddTabii,
+    MaddJaiz,
+    MaddWajib,
+    MaddLazim,
+    Qalqalah,
+    IkhfaShafawi,
+    Ikhfa,
+    Iqlab,
+    IdghamShafawi,
+    IdghamGhunnah,
+    IdghamWithoutGhunnah,
+    IdghamMutajanisayn,
+    IdghamMutaqaribayn,
+    Ghunnah,
+}
+
+impl TajweedRule {
+    pub const ALL: [TajweedRule; 17] = [
+        Self::HamzatWasl,
+        Self::Silent,
+        Self::LamShamsiyyah,
+        Self::MaddTabii,
+        Self::MaddJaiz,
+        Self::MaddWajib,
+        Self::MaddLazim,
+        Self::Qalqalah,
+        Self::IkhfaShafawi,
+        Self::Ikhfa,
+        Self::Iqlab,
+        Self::IdghamShafawi,
+        Self::IdghamGhunnah,
+        Self::IdghamWithoutGhunnah,
+        Self::IdghamMutajanisayn,
+        Self::IdghamMutaqaribayn,
+        Self::Ghunnah,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::HamzatWasl => "hamzat_wasl",
+            Self::Silent => "silent",
+            Self::LamShamsiyyah => "lam_shamsiyyah",
+            Self::MaddTabii => "madd_tabii",
+            Self::MaddJaiz => "madd_jaiz",
+            Self::MaddWajib => "madd_wajib",
+            Self::MaddLazim => "madd_lazim",
+            Self::Qalqalah => "qalqalah",
+            Self::IkhfaShafawi => "ikhfa_shafawi",
+            Self::Ikhfa => "ikhfa",
+            Self::Iqlab => "iqlab",
+            Self::IdghamShafawi => "idgham_shafawi",
+            Self::IdghamGhunnah => "idgham_ghunnah",
+            Self::IdghamWithoutGhunnah => "idgham_without_ghunnah",
+            Self::IdghamMutajanisayn => "idgham_mutajanisayn",
+            Self::IdghamMutaqaribayn => "idgham_mutaqaribayn",
+            Self::Ghunnah => "ghunnah",
+        }
+    }
+
+    pub fn from_slug(slug: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|r| r.as_str() == slug)
+    }
+
+    /// Rule for a QUL tajweed class name ("ikhafa", "madda_necessary", ...)
+    pub fn from_qul_class(class: &str) -> Option<Self> {
+        Some(match class {
+            "ham_wasl" => Self::HamzatWasl,
+            "slnt" => Self::Silent,
+            "laam_shamsiyah" => Self::LamShamsiyyah,
+            "madda_normal" => Self::MaddTabii,
+            "madda_permissible" => Self::MaddJaiz,
+            "madda_obligatory" | "madda_obligatory_mottasel" | "madda_obligatory_monfasel" => {
+                Self::MaddWajib
+            }
+            "madda_necessary" => Self::MaddLazim,
+            "qalaqah" | "qalqalah" => Self::Qalqalah,
+            "ikhafa_shafawi" => Self::IkhfaShafawi,
+            "ikhafa" => Self::Ikhfa,
+            "iqlab" => Self::Iqlab,
+            "idgham_shafawi" => Self::IdghamShafawi,
+            "idgham_ghunnah" => Self::IdghamGhunnah,
+            "idgham_wo_ghunnah" => Self::IdghamWithoutGhunnah,
+            "idgham_mutajanisayn" => Self::IdghamMutajanisayn,
+            "idgham_mutaqaribayn" => Self::IdghamMutaqaribayn,
+            "ghunnah" => Self::Ghunnah,
+            _ => return None,
+        })
+    }
+
+    /// Name shown to the learner
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::HamzatWasl => "Hamzat al-wasl",
+            Self::Silent => "Silent letter",
+            Self::LamShamsiyyah => "Lam shamsiyyah",
+            Self::MaddTabii => "Natural madd",
+            Self::MaddJaiz => "Permissible madd",
+            Self::MaddWajib => "Obligatory madd",
+            Self::MaddLazim => "Necessary madd",
+            Self::Qalqalah => "Qalqalah",
+            Self::IkhfaShafawi => "Ikhfa shafawi",
+            Self::Ikhfa => "Ikhfa",
+            Self::Iqlab => "Iqlab",
+            Self::IdghamShafawi => "Idgham shafawi",
+            Self::IdghamGhunnah => "Idgham with ghunnah",
+            Self::IdghamWithoutGhunnah => "Idgham without ghunnah",
+            Self::IdghamMutajanisayn => "Idgham mutajanisayn",
+            Self::IdghamMutaqaribayn => "Idgham mutaqaribayn",
+            Self::Ghunnah => "Ghunnah",
+        }
+    }
+
+    /// Group of closely related rules ("madd", "idgham", ...); rules of the
+    /// same family make the hardest distractors for each other
+    pub fn family(&self) -> &'static str {
+        match self {
+            Self::HamzatWasl | Self::Silent | Self::LamShamsiyyah => "letters",
+            Self::MaddTabii | Self::MaddJaiz | Self::MaddWajib | Self::MaddLazim => "madd",
+            Self::Qalqalah => "qalqalah",
+            Self::IkhfaShafawi | Self::Ikhfa | Self::Iqlab | Self::Ghunnah => "nasal",
+            Self::IdghamShafawi
+            | Self::IdghamGhunnah
+            | Self::IdghamWithoutGhunnah
+            | Self::IdghamMutajanisayn
+            | Self::IdghamMutaqaribayn => "idgham",
+        }
+    }
+
+    /// Match a learner's answer: the slug or the name, ignoring case,
+    /// spaces, dashes and underscores
+    pub fn matches_answer(&self, answer: &str) -> bool {
+        let simplify = |s: &str| -> String {
+            s.chars()
+                .filter(|c| c.is_alphanumeric())
+                .flat_map(char::to_lowercase)
+                .collect()
+        };
+        let answer = simplify(answer);
+        !answer.is_empty() && (answer == simplify(self.as_str()) || answer == simplify(self.name()))
+    }
+}
+
+/// A rule applying to characters `start..end` of a word
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TajweedSpan {
+    pub rule: TajweedRule,
+    pub start: i32,
+    pub end: i32,
+}
+
+/// Split tajweed-tagged text into the plain text and the rule spans in it
+///
+/// Offsets count characters of the plain text. Tags with unknown classes
+/// (such as verse-end markers) are dropped along with their spans, keeping
+/// their text.
+pub fn parse_tajweed_markup(markup: &str) -> (String, Vec<TajweedSpan>) {
+    let mut text = String::new();
+    let mut len = 0;
+    let mut open: Vec<(Option<TajweedRule>, i32)> = Vec::new();
+    let mut spans = Vec::new();
+
+    let mut rest = markup;
+    while let Some(tag_start) = rest.find('<') {
+        let before = &rest[..tag_start];
+        text.push_str(before);
+        len += before.chars().count() as i32;
+
+        let Some(tag_len) = rest[tag_start..].find('>') else {
+            rest = &rest[tag_start..];
+            break;
+        };
+        let tag = &rest[tag_start + 1..tag_start + tag_len];
+        rest = &rest[tag_start + tag_len + 1..];
+
+        if tag.starts_with('/') {
+            if let Some((Some(rule), start)) = open.pop() {
+                if len > start {
+                    spans.push(TajweedSpan {
+                        rule,
+                        start,
+                        end: len,
+                    });
+                }
+            }
+        } else if !tag.ends_with('/') {
+            let class = tag
+                .split_once("class=")
+                .map(|(_, value)| value.trim_start_matches(['"', '\'']))
+                .and_then(|value| value.split(['"', '\'', ' ']).next());
+            open.push((class.and_then(TajweedRule::from_qul_class), len));
+        }
+    }
+    text.push_str(rest);
+
+    spans.sort_by_key(|s| (s.start, s.end));
+    (text, spans)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_tajweed_markup() {
+        let (text, spans) = parse_tajweed_markup(
+            "<tajweed class=ham_wasl>ٱ</tajweed>لرَّحْمَ<tajweed class=madda_normal>ـٰ</tajweed>نِ",
+        );
+        assert_eq!(text, "ٱلرَّحْمَـٰنِ");
+        assert_eq!(
+            spans,
+            vec![
+                TajweedSpan {
+                    rule: TajweedRule::HamzatWasl,
+                    start: 0,
+                    end: 1
+                },
+                TajweedSpan {
+                    rule: TajweedRule::MaddTabii,
+                    start: 9,
+                    end: 11
+                },
+            ]
+        );
+
+        // Quoted classes, nested tags and unknown classes
+        let (text, spans) = parse_tajweed_markup(
+            r#"مِ<rule class="ikhafa">نْ <rule class=ghunnah>ت</rule></rule><span class=end>١</span>"#,
+        );
+        assert_eq!(text, "مِنْ ت١");
+        let rules: Vec<_> = spans.iter().map(|s| (s.rule, s.start, s.end)).collect();
+        assert_eq!(
+            rules,
+            vec![(TajweedRule::Ikhfa, 2, 6), (TajweedRule::Ghunnah, 5, 6)]
+        );
+    }
+
+    #[test]
+    fn test_parse_qul_obligatory_madd() {
+        // ٱلسَّمَآءِ (2:19), connected madd
+        let (text, spans) = parse_tajweed_markup("<rule class=ham_wasl>ٱ</rule><rule class=laam_shamsiyah>ل</rule>سَّمَ<rule class=madda_obligatory_mottasel>آ</rule>ءِ");
+        assert_eq!(text, "ٱلسَّمَآءِ");
+        let rules: Vec<_> = spans.iter().map(|s| (s.rule, s.start, s.end)).collect();
+        assert_eq!(
+            rules,
+            vec![
+                (TajweedRule::HamzatWasl, 0, 1),
+                (TajweedRule::LamShamsiyyah, 1, 2),
+                (TajweedRule::MaddWajib, 7, 9),
+            ]
+        );
+
+        // بِمَآ (2:4), separated madd
+        let (_, spans) = parse_tajweed_markup("بِمَ<rule class=madda_obligatory_monfasel>آ</rule>");
+        let rules: Vec<_> = spans.iter().map(|s| (s.rule, s.start, s.end)).collect();
+        assert_eq!(rules, vec![(TajweedRule::MaddWajib, 4, 6)]);
+    }
+
+    #[test]
+    fn test_rule_slugs_and_answers() {
+        for rule in TajweedRule::ALL {
+            assert_eq!(TajweedRule::from_slug(rule.as_str()), Some(rule));
+            assert!(rule.matches_answer(rule.name()));
+        }
+        assert!(TajweedRule::IdghamWithoutGhunnah.matches_answer("idgham without ghunnah"));
+        assert!(TajweedRule::IdghamWithoutGhunnah.matches_answer("idgham_without_ghunnah"));
+        assert!(!TajweedRule::IdghamGhunnah.matches_answer("idgham without ghunnah"));
+        assert!(!TajweedRule::Ikhfa.matches_answer(""));
+    }
+}
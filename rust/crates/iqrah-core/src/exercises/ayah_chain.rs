@@ -2,9 +2,11 @@
 // Exercise 10: Ayah Chain - Continuous verse typing until mistake or completion
 
 use super::memorization::MemorizationExercise;
+use super::script;
 use super::types::Exercise;
+use super::word_diff::{diff_words, GradeThresholds, WordDiff};
 use crate::domain::node_id::{self, PREFIX_CHAPTER};
-use crate::{ContentRepository, Verse};
+use crate::{ContentRepository, ReviewGrade, Script, Verse};
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -16,16 +18,20 @@ use serde::{Deserialize, Serialize};
 /// Stateful exercise for continuous verse typing
 /// User types verses in sequence until making a mistake or completing the chain
 /// Tracks current position and progress through a chapter or range
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct AyahChainExercise {
     node_id: i64,
     verses: Vec<Verse>,
+    /// Text of each verse in the chosen script
+    texts: Vec<String>,
     current_index: usize,
     completed_count: usize,
     is_complete: bool,
     mistake_made: bool,
     started_at: DateTime<Utc>,
     last_mistake: Option<MistakeDetails>,
+    last_grade: Option<ReviewGrade>,
+    thresholds: GradeThresholds,
 }
 
 /// Details about the most recent mistake
@@ -35,14 +41,22 @@ pub struct MistakeDetails {
     pub user_input: String,
     pub expected: String,
     pub occurred_at: DateTime<Utc>,
+    /// Word-aligned diff between the input and the expected verse
+    #[serde(default)]
+    pub diff: WordDiff,
 }
 
 impl AyahChainExercise {
     /// Create a new Ayah Chain exercise
     ///
     /// Queries the database for all verses in the specified chapter
-    /// User will type verses in sequence starting from the first verse
-    pub async fn new(chapter_node_id: i64, content_repo: &dyn ContentRepository) -> Result<Self> {
+    /// User will type verses in sequence starting from the first verse,
+    /// written in `script`
+    pub async fn new(
+        chapter_node_id: i64,
+        script: Script,
+        content_repo: &dyn ContentRepository,
+    ) -> Result<Self> {
         // Get the node to access its ukey
         let node = content_repo
             .get_node(chapter_node_id)
@@ -66,16 +80,7 @@ impl AyahChainExercise {
             ));
         }
 
-        Ok(Self {
-            node_id: chapter_node_id,
-            verses,
-            current_index: 0,
-            completed_count: 0,
-            is_complete: false,
-            mistake_made: false,
-            started_at: Utc::now(),
-            last_mistake: None,
-        })
+        Self::from_verses(chapter_node_id, verses, script, content_repo).await
     }
 
     /// Create an Ayah Chain for a specific verse range within a chapter
@@ -83,6 +88,7 @@ impl AyahChainExercise {
         chapter_num: i32,
         start_verse: i32,
         end_verse: i32,
+        script: Script,
         content_repo: &dyn ContentRepository,
     ) -> Result<Self> {
         // Get all verses for the chapter
@@ -109,17 +115,32 @@ impl AyahChainExercise {
             .get_node_by_ukey(&chapter_ukey)
             .await?
             .ok_or_else(|| anyhow::anyhow!("Chapter node not found: {}", chapter_ukey))?;
-        let node_id = node.id;
+        Self::from_verses(node.id, verses, script, content_repo).await
+    }
+
+    async fn from_verses(
+        node_id: i64,
+        verses: Vec<Verse>,
+        script: Script,
+        content_repo: &dyn ContentRepository,
+    ) -> Result<Self> {
+        let mut texts = Vec::with_capacity(verses.len());
+        for verse in &verses {
+            texts.push(script::verse_text(verse, script, content_repo).await?);
+        }
 
         Ok(Self {
             node_id,
             verses,
+            texts,
             current_index: 0,
             completed_count: 0,
             is_complete: false,
             mistake_made: false,
             started_at: Utc::now(),
             last_mistake: None,
+            last_grade: None,
+            thresholds: GradeThresholds::default(),
         })
     }
 
@@ -131,14 +152,31 @@ impl AyahChainExercise {
         self.verses.get(self.current_index)
     }
 
+    /// Text of the current verse in the chain's script
+    pub fn current_text(&self) -> Option<&str> {
+        self.current_verse()
+            .and_then(|_| self.texts.get(self.current_index))
+            .map(String::as_str)
+    }
+
     /// Get the current verse reference (e.g., "1:1")
     pub fn current_verse_ref(&self) -> Option<String> {
         self.current_verse().map(|v| v.key.clone())
     }
 
+    /// Use custom accuracy thresholds for grading submitted verses
+    pub fn with_grade_thresholds(mut self, thresholds: GradeThresholds) -> Self {
+        self.thresholds = thresholds;
+        self
+    }
+
     /// Submit an answer for the current verse and advance
     ///
-    /// Returns Ok(true) if answer was correct and chain continues
+    /// The input is aligned word by word against the verse and graded with the
+    /// chain's thresholds. Minor slips (graded Hard or better) are recorded as
+    /// the last mistake but keep the chain going; anything graded Again breaks it.
+    ///
+    /// Returns Ok(true) if the chain continues
     /// Returns Ok(false) if answer was wrong (chain ends)
     /// Returns Err if exercise is already complete or invalid state
     pub fn submit_answer(&mut self, user_input: &str) -> Result<bool> {
@@ -156,37 +194,44 @@ impl AyahChainExercise {
 
         // Clone necessary data before mutation
         let verse_key = current_verse.key.clone();
-        let verse_text = current_verse.text_uthmani.clone();
-
-        // Check answer using Arabic normalization
-        let normalized_input = MemorizationExercise::normalize_arabic(user_input);
-        let normalized_correct = MemorizationExercise::normalize_arabic(&verse_text);
+        let verse_text = self.texts[self.current_index].clone();
 
-        if normalized_input == normalized_correct {
-            // Correct! Advance to next verse
-            self.completed_count += 1;
-            self.current_index += 1;
-
-            // Check if we've completed all verses
-            if self.current_index >= self.verses.len() {
-                self.is_complete = true;
-            }
-
-            Ok(true)
-        } else {
-            // Mistake! Chain broken
-            self.mistake_made = true;
+        let diff = diff_words(&verse_text, user_input);
+        let grade = self.thresholds.grade(diff.accuracy);
+        self.last_grade = Some(grade);
 
+        if !diff.is_exact() {
             // Capture mistake details for feedback
             self.last_mistake = Some(MistakeDetails {
                 verse_key,
                 user_input: user_input.to_string(),
                 expected: verse_text,
                 occurred_at: Utc::now(),
+                diff,
             });
+        }
 
-            Ok(false)
+        if grade == ReviewGrade::Again {
+            // Mistake! Chain broken
+            self.mistake_made = true;
+            return Ok(false);
+        }
+
+        // Advance to next verse
+        self.completed_count += 1;
+        self.current_index += 1;
+
+        // Check if we've completed all verses
+        if self.current_index >= self.verses.len() {
+            self.is_complete = true;
         }
+
+        Ok(true)
+    }
+
+    /// Grade of the most recent submission (if any)
+    pub fn last_grade(&self) -> Option<ReviewGrade> {
+        self.last_grade
     }
 
     /// Get completion stats
@@ -226,6 +271,7 @@ impl AyahChainExercise {
         self.mistake_made = false;
         self.started_at = Utc::now();
         self.last_mistake = None;
+        self.last_grade = None;
     }
 }
 
@@ -276,9 +322,9 @@ impl Exercise for AyahChainExercise {
     }
 
     fn check_answer(&self, answer: &str) -> bool {
-        if let Some(verse) = self.current_verse() {
+        if let Some(text) = self.current_text() {
             let normalized_input = MemorizationExercise::normalize_arabic(answer);
-            let normalized_correct = MemorizationExercise::normalize_arabic(&verse.text_uthmani);
+            let normalized_correct = MemorizationExercise::normalize_arabic(text);
             normalized_input == normalized_correct
         } else {
             false
@@ -286,9 +332,9 @@ impl Exercise for AyahChainExercise {
     }
 
     fn get_hint(&self) -> Option<String> {
-        self.current_verse().map(|verse| {
+        self.current_text().map(|text| {
             // Show first word and provide context
-            let words: Vec<&str> = verse.text_uthmani.split_whitespace().collect();
+            let words: Vec<&str> = text.split_whitespace().collect();
             let first_word = words.first().copied().unwrap_or("");
             let word_count = words.len();
 
@@ -417,7 +463,9 @@ mod tests {
     #[tokio::test]
     async fn test_ayah_chain_creation() {
         let mock = create_mock_with_fatihah();
-        let exercise = AyahChainExercise::new(1, &mock).await.unwrap();
+        let exercise = AyahChainExercise::new(1, Script::Uthmani, &mock)
+            .await
+            .unwrap();
 
         let stats = exercise.get_stats();
         assert_eq!(stats.total_verses, 7); // Al-Fatihah has 7 verses
@@ -429,7 +477,9 @@ mod tests {
     #[tokio::test]
     async fn test_ayah_chain_first_verse() {
         let mock = create_mock_with_fatihah();
-        let exercise = AyahChainExercise::new(1, &mock).await.unwrap();
+        let exercise = AyahChainExercise::new(1, Script::Uthmani, &mock)
+            .await
+            .unwrap();
 
         let current = exercise.current_verse().unwrap();
         assert_eq!(current.key, "1:1");
@@ -439,7 +489,9 @@ mod tests {
     #[tokio::test]
     async fn test_ayah_chain_correct_answer_advances() {
         let mock = create_mock_with_fatihah();
-        let mut exercise = AyahChainExercise::new(1, &mock).await.unwrap();
+        let mut exercise = AyahChainExercise::new(1, Script::Uthmani, &mock)
+            .await
+            .unwrap();
 
         // Submit correct answer for verse 1:1
         let result = exercise.submit_answer("بِسْمِ ٱللَّهِ ٱلرَّحْمَٰنِ ٱلرَّحِيمِ").unwrap();
@@ -457,7 +509,9 @@ mod tests {
     #[tokio::test]
     async fn test_ayah_chain_incorrect_answer_breaks_chain() {
         let mock = create_mock_with_fatihah();
-        let mut exercise = AyahChainExercise::new(1, &mock).await.unwrap();
+        let mut exercise = AyahChainExercise::new(1, Script::Uthmani, &mock)
+            .await
+            .unwrap();
 
         // Submit incorrect answer
         let result = exercise.submit_answer("wrong answer").unwrap();
@@ -472,10 +526,44 @@ mod tests {
         assert!(exercise.current_verse().is_none());
     }
 
+    #[tokio::test]
+    async fn test_ayah_chain_minor_slip_keeps_chain_with_diff() {
+        let mock = create_mock_with_fatihah();
+        let mut exercise = AyahChainExercise::new(1, Script::Uthmani, &mock)
+            .await
+            .unwrap();
+
+        // One missing word out of four: graded Hard, chain continues
+        let result = exercise.submit_answer("بسم الله الرحيم").unwrap();
+        assert!(result);
+        assert_eq!(exercise.last_grade(), Some(ReviewGrade::Hard));
+        assert_eq!(exercise.get_stats().completed_count, 1);
+
+        let mistake = exercise.get_last_mistake().unwrap();
+        assert_eq!(mistake.verse_key, "1:1");
+        let missing = mistake.diff.mistakes().next().unwrap();
+        assert_eq!(missing.kind, crate::exercises::WordEditKind::Deletion);
+        assert_eq!(missing.expected_position, 3);
+
+        // Strict thresholds break the chain on the same slip
+        let mut strict = AyahChainExercise::new(1, Script::Uthmani, &mock)
+            .await
+            .unwrap()
+            .with_grade_thresholds(GradeThresholds {
+                easy: 1.0,
+                good: 1.0,
+                hard: 1.0,
+            });
+        assert!(!strict.submit_answer("بسم الله الرحيم").unwrap());
+        assert_eq!(strict.last_grade(), Some(ReviewGrade::Again));
+    }
+
     #[tokio::test]
     async fn test_ayah_chain_normalization() {
         let mock = create_mock_with_fatihah();
-        let mut exercise = AyahChainExercise::new(1, &mock).await.unwrap();
+        let mut exercise = AyahChainExercise::new(1, Script::Uthmani, &mock)
+            .await
+            .unwrap();
 
         // Submit answer without tashkeel - should still be correct
         let result = exercise.submit_answer("بسم الله الرحمان الرحيم").unwrap();
@@ -485,7 +573,9 @@ mod tests {
     #[tokio::test]
     async fn test_ayah_chain_complete_all_verses() {
         let mock = create_mock_with_fatihah();
-        let mut exercise = AyahChainExercise::new(1, &mock).await.unwrap();
+        let mut exercise = AyahChainExercise::new(1, Script::Uthmani, &mock)
+            .await
+            .unwrap();
 
         // Submit all 7 verses correctly
         let verses = [
@@ -515,7 +605,9 @@ mod tests {
     #[tokio::test]
     async fn test_ayah_chain_partial_completion() {
         let mock = create_mock_with_fatihah();
-        let mut exercise = AyahChainExercise::new(1, &mock).await.unwrap();
+        let mut exercise = AyahChainExercise::new(1, Script::Uthmani, &mock)
+            .await
+            .unwrap();
 
         // Complete first 3 verses
         exercise.submit_answer("بِسْمِ ٱللَّهِ ٱلرَّحْمَٰنِ ٱلرَّحِيمِ").unwrap();
@@ -535,7 +627,9 @@ mod tests {
     #[tokio::test]
     async fn test_ayah_chain_reset() {
         let mock = create_mock_with_fatihah();
-        let mut exercise = AyahChainExercise::new(1, &mock).await.unwrap();
+        let mut exercise = AyahChainExercise::new(1, Script::Uthmani, &mock)
+            .await
+            .unwrap();
 
         // Complete first verse and make mistake on second
         exercise.submit_answer("بِسْمِ ٱللَّهِ ٱلرَّحْمَٰنِ ٱلرَّحِيمِ").unwrap();
@@ -561,7 +655,9 @@ mod tests {
     #[tokio::test]
     async fn test_ayah_chain_range() {
         let mock = create_mock_with_fatihah();
-        let exercise = AyahChainExercise::new_range(1, 1, 3, &mock).await.unwrap();
+        let exercise = AyahChainExercise::new_range(1, 1, 3, Script::Uthmani, &mock)
+            .await
+            .unwrap();
 
         let stats = exercise.get_stats();
         assert_eq!(stats.total_verses, 3); // Only verses 1-3
@@ -573,7 +669,9 @@ mod tests {
     #[tokio::test]
     async fn test_ayah_chain_question_format() {
         let mock = create_mock_with_fatihah();
-        let exercise = AyahChainExercise::new(1, &mock).await.unwrap();
+        let exercise = AyahChainExercise::new(1, Script::Uthmani, &mock)
+            .await
+            .unwrap();
 
         let question = exercise.generate_question();
         assert!(question.contains("1/7")); // Progress indicator
@@ -583,7 +681,9 @@ mod tests {
     #[tokio::test]
     async fn test_ayah_chain_hint() {
         let mock = create_mock_with_fatihah();
-        let exercise = AyahChainExercise::new(1, &mock).await.unwrap();
+        let exercise = AyahChainExercise::new(1, Script::Uthmani, &mock)
+            .await
+            .unwrap();
 
         let hint = exercise.get_hint().unwrap();
         // Shows first word + word count
@@ -594,7 +694,9 @@ mod tests {
     #[tokio::test]
     async fn test_ayah_chain_check_answer_method() {
         let mock = create_mock_with_fatihah();
-        let exercise = AyahChainExercise::new(1, &mock).await.unwrap();
+        let exercise = AyahChainExercise::new(1, Script::Uthmani, &mock)
+            .await
+            .unwrap();
 
         // Test Exercise trait check_answer method
         assert!(exercise.check_answer("بِسْمِ ٱللَّهِ ٱلرَّحْمَٰنِ ٱلرَّحِيمِ"));
@@ -604,7 +706,9 @@ mod tests {
     #[tokio::test]
     async fn test_ayah_chain_cannot_submit_after_complete() {
         let mock = create_mock_with_fatihah();
-        let mut exercise = AyahChainExercise::new(1, &mock).await.unwrap();
+        let mut exercise = AyahChainExercise::new(1, Script::Uthmani, &mock)
+            .await
+            .unwrap();
 
         // Complete all verses
         let verses = vec![
@@ -626,4 +730,21 @@ mod tests {
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("already complete"));
     }
+
+    #[tokio::test]
+    async fn test_ayah_chain_in_preferred_script() {
+        let mut mock = create_mock_with_fatihah();
+        mock.expect_get_script_content()
+            .with(eq(node_id::encode_verse(1, 1)), eq("indopak"))
+            .returning(|_, _| Ok(Some("بِسۡمِ اللّٰہِ الرَّحۡمٰنِ الرَّحِیۡمِ".to_string())));
+        // Other verses have no Indopak text and fall back to Uthmani
+        mock.expect_get_script_content().returning(|_, _| Ok(None));
+
+        let mut exercise = AyahChainExercise::new(1, Script::Indopak, &mock)
+            .await
+            .unwrap();
+        assert_eq!(exercise.current_text(), Some("بِسۡمِ اللّٰہِ الرَّحۡمٰنِ الرَّحِیۡمِ"));
+        assert!(exercise.submit_answer("بِسۡمِ اللّٰہِ الرَّحۡمٰنِ الرَّحِیۡمِ").unwrap());
+        assert_eq!(exercise.current_text(), Some("ٱلْحَمْدُ لِلَّهِ رَبِّ ٱلْعَٰلَمِينَ"));
+    }
 }
@@ -5,7 +5,9 @@ use super::types::Exercise;
 use crate::domain::node_id::{self, PREFIX_VERSE};
 use crate::{ContentRepository, KnowledgeNode};
 use anyhow::Result;
+use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
+use rand::SeedableRng;
 
 // ============================================================================
 // Exercise 4: Ayah Sequence (MCQ)
@@ -31,7 +33,13 @@ impl AyahSequenceExercise {
     /// - Current verse text
     /// - Next verse in sequence (correct answer)
     /// - Other verses from same chapter (distractors)
-    pub async fn new(verse_node_id: i64, content_repo: &dyn ContentRepository) -> Result<Self> {
+    ///
+    /// Distractor choice and option order are drawn from an RNG seeded with `seed`.
+    pub async fn new(
+        verse_node_id: i64,
+        seed: u64,
+        content_repo: &dyn ContentRepository,
+    ) -> Result<Self> {
         // Get the node to access its ukey
         let node = content_repo
             .get_node(verse_node_id)
@@ -106,10 +114,12 @@ impl AyahSequenceExercise {
             })?;
 
         // Generate distractors from same chapter (but not current or next verse)
+        let mut rng = StdRng::seed_from_u64(seed);
         let distractors = Self::generate_distractors(
             &current_verse_key,
             &correct_next_verse_key,
             &verses,
+            &mut rng,
             content_repo,
         )
         .await?;
@@ -120,7 +130,7 @@ impl AyahSequenceExercise {
             correct_next_verse_text.clone(),
         )];
         options.extend(distractors);
-        options.shuffle(&mut rand::thread_rng());
+        options.shuffle(&mut rng);
 
         Ok(Self {
             node_id: verse_node_id,
@@ -137,6 +147,7 @@ impl AyahSequenceExercise {
         current_verse_key: &str,
         correct_next_verse_key: &str,
         all_verses: &[crate::Verse],
+        rng: &mut StdRng,
         content_repo: &dyn ContentRepository,
     ) -> Result<Vec<(String, String)>> {
         let mut distractors = Vec::new();
@@ -147,7 +158,7 @@ impl AyahSequenceExercise {
             .filter(|v| v.key != current_verse_key && v.key != correct_next_verse_key)
             .collect();
 
-        candidate_verses.shuffle(&mut rand::thread_rng());
+        candidate_verses.shuffle(rng);
 
         // Take first 3 candidates
         for verse in candidate_verses.iter().take(3) {
@@ -362,7 +373,7 @@ mod tests {
     #[tokio::test]
     async fn test_ayah_sequence_basic() {
         let mock = create_mock_for_ayah_sequence();
-        let exercise = AyahSequenceExercise::new(11, &mock).await.unwrap();
+        let exercise = AyahSequenceExercise::new(11, 0, &mock).await.unwrap();
 
         assert_eq!(exercise.get_node_id(), 11);
         assert_eq!(exercise.get_type_name(), "ayah_sequence");
@@ -375,7 +386,7 @@ mod tests {
     #[tokio::test]
     async fn test_ayah_sequence_finds_correct_next_verse() {
         let mock = create_mock_for_ayah_sequence();
-        let exercise = AyahSequenceExercise::new(11, &mock).await.unwrap();
+        let exercise = AyahSequenceExercise::new(11, 0, &mock).await.unwrap();
 
         assert_eq!(exercise.get_correct_verse_key(), "1:2");
         assert!(exercise.correct_next_verse_text.contains("ٱلْحَمْدُ لِلَّهِ"));
@@ -384,7 +395,7 @@ mod tests {
     #[tokio::test]
     async fn test_ayah_sequence_has_four_options() {
         let mock = create_mock_for_ayah_sequence();
-        let exercise = AyahSequenceExercise::new(11, &mock).await.unwrap();
+        let exercise = AyahSequenceExercise::new(11, 0, &mock).await.unwrap();
 
         assert_eq!(exercise.get_options().len(), 4);
     }
@@ -392,7 +403,7 @@ mod tests {
     #[tokio::test]
     async fn test_ayah_sequence_options_contain_correct() {
         let mock = create_mock_for_ayah_sequence();
-        let exercise = AyahSequenceExercise::new(11, &mock).await.unwrap();
+        let exercise = AyahSequenceExercise::new(11, 0, &mock).await.unwrap();
 
         let correct_key = exercise.get_correct_verse_key();
         let options = exercise.get_options();
@@ -403,7 +414,7 @@ mod tests {
     #[tokio::test]
     async fn test_ayah_sequence_check_answer_by_key() {
         let mock = create_mock_for_ayah_sequence();
-        let exercise = AyahSequenceExercise::new(11, &mock).await.unwrap();
+        let exercise = AyahSequenceExercise::new(11, 0, &mock).await.unwrap();
 
         assert!(exercise.check_answer("1:2"));
         assert!(!exercise.check_answer("1:3"));
@@ -413,7 +424,7 @@ mod tests {
     #[tokio::test]
     async fn test_ayah_sequence_check_answer_by_text() {
         let mock = create_mock_for_ayah_sequence();
-        let exercise = AyahSequenceExercise::new(11, &mock).await.unwrap();
+        let exercise = AyahSequenceExercise::new(11, 0, &mock).await.unwrap();
 
         assert!(exercise.check_answer("ٱلْحَمْدُ لِلَّهِ رَبِّ ٱلْعَٰلَمِينَ"));
         assert!(!exercise.check_answer("ٱلرَّحْمَٰنِ ٱلرَّحِيمِ"));
@@ -422,7 +433,7 @@ mod tests {
     #[tokio::test]
     async fn test_ayah_sequence_hint() {
         let mock = create_mock_for_ayah_sequence();
-        let exercise = AyahSequenceExercise::new(11, &mock).await.unwrap();
+        let exercise = AyahSequenceExercise::new(11, 0, &mock).await.unwrap();
 
         let hint = exercise.get_hint();
         assert!(hint.is_some());
@@ -432,7 +443,7 @@ mod tests {
     #[tokio::test]
     async fn test_ayah_sequence_middle_verse() {
         let mock = create_mock_for_ayah_sequence();
-        let exercise = AyahSequenceExercise::new(13, &mock).await.unwrap();
+        let exercise = AyahSequenceExercise::new(13, 0, &mock).await.unwrap();
 
         assert_eq!(exercise.get_correct_verse_key(), "1:4");
         assert!(exercise.check_answer("1:4"));
@@ -441,7 +452,7 @@ mod tests {
     #[tokio::test]
     async fn test_ayah_sequence_last_verse_fails() {
         let mock = create_mock_for_ayah_sequence();
-        let result = AyahSequenceExercise::new(17, &mock).await;
+        let result = AyahSequenceExercise::new(17, 0, &mock).await;
 
         assert!(result.is_err());
         assert!(result
@@ -453,7 +464,7 @@ mod tests {
     #[tokio::test]
     async fn test_ayah_sequence_distractors_are_different() {
         let mock = create_mock_for_ayah_sequence();
-        let exercise = AyahSequenceExercise::new(11, &mock).await.unwrap();
+        let exercise = AyahSequenceExercise::new(11, 0, &mock).await.unwrap();
 
         let options = exercise.get_options();
         let correct_key = exercise.get_correct_verse_key();
@@ -0,0 +1,281 @@
+// exercises/checkpoint.rs
+// Persistence of in-progress stateful exercises
+//
+// Echo Recall, Memorization Ayah and Ayah Chain keep their state between
+// interactions. Callers checkpoint the exercise after every interaction
+// (`submit_recall`, `update_word`, `submit_answer`) under its session item, so
+// the item can be resumed after the app is killed. Checkpoints older than
+// `CHECKPOINT_TTL_DAYS` are treated as abandoned.
+//
+// The exercise served for a session item is checkpointed the same way, so
+// that its answer is checked against what the server generated rather than
+// what the client sends back.
+
+use super::ayah_chain::AyahChainExercise;
+use super::echo_recall::EchoRecallExercise;
+use super::exercise_data::ExerciseData;
+use super::memorization_ayah::MemorizationAyahExercise;
+use super::types::Exercise;
+use crate::domain::node_id as nid;
+use crate::domain::ExerciseCheckpoint;
+use crate::ports::UserRepository;
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Days after its last interaction before a checkpoint expires
+pub const CHECKPOINT_TTL_DAYS: i64 = 7;
+
+/// A stateful exercise whose state can be checkpointed
+pub trait Checkpointable: Serialize + DeserializeOwned {
+    /// Exercise type stored with the checkpoint
+    const EXERCISE_TYPE: &'static str;
+
+    /// Node the exercise is practicing
+    fn checkpoint_node_id(&self) -> i64;
+}
+
+impl Checkpointable for EchoRecallExercise {
+    const EXERCISE_TYPE: &'static str = "echo_recall";
+
+    /// First ayah of the session
+    fn checkpoint_node_id(&self) -> i64 {
+        self.ayah_node_ids()
+            .first()
+            .and_then(|ukey| nid::from_ukey(ukey))
+            .unwrap_or_default()
+    }
+}
+
+/// An exercise as served for a session item, before it is answered
+impl Checkpointable for ExerciseData {
+    const EXERCISE_TYPE: &'static str = "served_exercise";
+
+    fn checkpoint_node_id(&self) -> i64 {
+        self.node_id()
+    }
+}
+
+impl Checkpointable for MemorizationAyahExercise {
+    const EXERCISE_TYPE: &'static str = "memorization_ayah";
+
+    fn checkpoint_node_id(&self) -> i64 {
+        nid::from_ukey(self.verse_node_id()).unwrap_or_default()
+    }
+}
+
+impl Checkpointable for AyahChainExercise {
+    const EXERCISE_TYPE: &'static str = "ayah_chain";
+
+    fn checkpoint_node_id(&self) -> i64 {
+        self.get_node_id()
+    }
+}
+
+/// Save the current state of an exercise for a session item
+pub async fn save_checkpoint<E: Checkpointable>(
+    user_repo: &dyn UserRepository,
+    session_id: &str,
+    position: i32,
+    user_id: &str,
+    exercise: &E,
+) -> Result<()> {
+    let checkpoint = ExerciseCheckpoint {
+        session_id: session_id.to_string(),
+        position,
+        user_id: user_id.to_string(),
+        exercise_type: E::EXERCISE_TYPE.to_string(),
+        node_id: exercise.checkpoint_node_id(),
+        state: serde_json::to_string(exercise)?,
+        updated_at: Utc::now(),
+    };
+    user_repo.save_exercise_checkpoint(&checkpoint).await
+}
+
+/// Load the saved state of an exercise for a session item
+///
+/// Returns `None` when nothing is saved or the saved exercise is of another
+/// type. Expired checkpoints are deleted and also return `None`.
+pub async fn load_checkpoint<E: Checkpointable>(
+    user_repo: &dyn UserRepository,
+    session_id: &str,
+    position: i32,
+) -> Result<Option<E>> {
+    let Some(checkpoint) = user_repo
+        .get_exercise_checkpoint(session_id, position)
+        .await?
+    else {
+        return Ok(None);
+    };
+
+    if checkpoint.updated_at < expiry_cutoff(Utc::now()) {
+        user_repo
+            .delete_exercise_checkpoint(session_id, position)
+            .await?;
+        return Ok(None);
+    }
+    if checkpoint.exercise_type != E::EXERCISE_TYPE {
+        return Ok(None);
+    }
+
+    Ok(Some(serde_json::from_str(&checkpoint.state)?))
+}
+
+/// Delete the checkpoint of a finished session item
+pub async fn clear_checkpoint(
+    user_repo: &dyn UserRepository,
+    session_id: &str,
+    position: i32,
+) -> Result<()> {
+    user_repo
+        .delete_exercise_checkpoint(session_id, position)
+        .await
+}
+
+/// Delete every checkpoint that expired by `now`
+///
+/// Returns the number of checkpoints removed.
+pub async fn expire_checkpoints(user_repo: &dyn UserRepository, now: DateTime<Utc>) -> Result<u64> {
+    user_repo
+        .delete_exercise_checkpoints_before(expiry_cutoff(now))
+        .await
+}
+
+fn expiry_cutoff(now: DateTime<Utc>) -> DateTime<Utc> {
+    now - Duration::days(CHECKPOINT_TTL_DAYS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exercises::{MemorizationAyahState, MemorizationWord};
+    use crate::testing::MockUserRepository;
+    use mockall::predicate::*;
+    use std::sync::{Arc, Mutex};
+
+    fn memorization_exercise() -> MemorizationAyahExercise {
+        MemorizationAyahExercise::from_state(
+            "user1",
+            MemorizationAyahState {
+                verse_node_id: "VERSE:1:1".to_string(),
+                words: vec![MemorizationWord {
+                    node_id: "WORD:101".to_string(),
+                    text: "بِسْمِ".to_string(),
+                    energy: 0.2,
+                }],
+            },
+        )
+    }
+
+    /// Mock repository backed by a single checkpoint slot
+    fn slot_repo(slot: Arc<Mutex<Option<ExerciseCheckpoint>>>) -> MockUserRepository {
+        let mut repo = MockUserRepository::new();
+        let saved = slot.clone();
+        repo.expect_save_exercise_checkpoint().returning(move |c| {
+            *saved.lock().unwrap() = Some(c.clone());
+            Ok(())
+        });
+        let loaded = slot.clone();
+        repo.expect_get_exercise_checkpoint()
+            .returning(move |_, _| Ok(loaded.lock().unwrap().clone()));
+        repo.expect_delete_exercise_checkpoint()
+            .with(eq("session1"), eq(0))
+            .returning(move |_, _| {
+                *slot.lock().unwrap() = None;
+                Ok(())
+            });
+        repo
+    }
+
+    #[tokio::test]
+    async fn test_checkpoint_roundtrip() {
+        let slot = Arc::new(Mutex::new(None));
+        let repo = slot_repo(slot.clone());
+
+        let mut exercise = memorization_exercise();
+        exercise
+            .update_word("WORD:101", crate::exercises::MemorizationAction::Tap)
+            .unwrap();
+        save_checkpoint(&repo, "session1", 0, "user1", &exercise)
+            .await
+            .unwrap();
+
+        let saved = slot.lock().unwrap().clone().unwrap();
+        assert_eq!(saved.exercise_type, "memorization_ayah");
+        assert_eq!(saved.node_id, nid::from_ukey("VERSE:1:1").unwrap());
+
+        let resumed: MemorizationAyahExercise = load_checkpoint(&repo, "session1", 0)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(resumed.user_id(), "user1");
+        assert_eq!(
+            resumed.state().words[0].energy,
+            exercise.state().words[0].energy
+        );
+
+        // A checkpoint of another exercise type is not resumed as this one
+        let other: Option<EchoRecallExercise> =
+            load_checkpoint(&repo, "session1", 0).await.unwrap();
+        assert!(other.is_none());
+
+        clear_checkpoint(&repo, "session1", 0).await.unwrap();
+        assert!(slot.lock().unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_served_exercise_roundtrip() {
+        let slot = Arc::new(Mutex::new(None));
+        let repo = slot_repo(slot.clone());
+
+        let served = ExerciseData::McqArToEn {
+            node_id: 111,
+            distractor_node_ids: vec![112, 113, 114],
+            distractor_strategy: Default::default(),
+            seed: 42,
+        };
+        save_checkpoint(&repo, "session1", 0, "user1", &served)
+            .await
+            .unwrap();
+        assert_eq!(
+            slot.lock().unwrap().as_ref().unwrap().exercise_type,
+            "served_exercise"
+        );
+
+        let loaded: Option<ExerciseData> = load_checkpoint(&repo, "session1", 0).await.unwrap();
+        assert_eq!(loaded, Some(served));
+        let other: Option<MemorizationAyahExercise> =
+            load_checkpoint(&repo, "session1", 0).await.unwrap();
+        assert!(other.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_expired_checkpoint_is_dropped() {
+        let slot = Arc::new(Mutex::new(None));
+        let repo = slot_repo(slot.clone());
+
+        save_checkpoint(&repo, "session1", 0, "user1", &memorization_exercise())
+            .await
+            .unwrap();
+        slot.lock().unwrap().as_mut().unwrap().updated_at =
+            Utc::now() - Duration::days(CHECKPOINT_TTL_DAYS + 1);
+
+        let resumed: Option<MemorizationAyahExercise> =
+            load_checkpoint(&repo, "session1", 0).await.unwrap();
+        assert!(resumed.is_none());
+        assert!(slot.lock().unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_expire_checkpoints_uses_ttl() {
+        let now = Utc::now();
+        let mut repo = MockUserRepository::new();
+        repo.expect_delete_exercise_checkpoints_before()
+            .with(eq(now - Duration::days(CHECKPOINT_TTL_DAYS)))
+            .times(1)
+            .returning(|_| Ok(3));
+
+        assert_eq!(expire_checkpoints(&repo, now).await.unwrap(), 3);
+    }
+}
@@ -0,0 +1,570 @@
+// exercises/distractors.rs
+// Morphology-aware distractor selection shared by MCQ generators
+
+use super::memorization::MemorizationExercise;
+use crate::domain::node_id;
+use crate::semantic::grader::SEMANTIC_EMBEDDER;
+use crate::semantic::SemanticEmbedder;
+use crate::{ContentRepository, Word};
+use anyhow::Result;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Maximum number of same-root words added to the candidate pool
+const ROOT_FAMILY_LIMIT: u32 = 12;
+
+/// Signal weights; a candidate's similarity is the weighted mean over the
+/// signals available for both words.
+const WEIGHT_POS: f64 = 0.25;
+const WEIGHT_FAMILY: f64 = 0.25;
+const WEIGHT_LENGTH: f64 = 0.2;
+const WEIGHT_SEMANTIC: f64 = 0.2;
+const WEIGHT_FREQUENCY: f64 = 0.1;
+
+/// How close distractors should be to the correct answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DistractorDifficulty {
+    /// Clearly different words (new or fragile items)
+    Easy,
+    #[default]
+    Medium,
+    /// Same family, part of speech, length and meaning where possible
+    Hard,
+}
+
+impl DistractorDifficulty {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Easy => "easy",
+            Self::Medium => "medium",
+            Self::Hard => "hard",
+        }
+    }
+
+    pub fn from_slug(slug: &str) -> Option<Self> {
+        match slug {
+            "easy" => Some(Self::Easy),
+            "medium" => Some(Self::Medium),
+            "hard" => Some(Self::Hard),
+            _ => None,
+        }
+    }
+
+    /// Map the learner's energy on the node (0.0-1.0) to a difficulty
+    pub fn from_energy(energy: f64) -> Self {
+        if energy < 0.3 {
+            Self::Easy
+        } else if energy < 0.7 {
+            Self::Medium
+        } else {
+            Self::Hard
+        }
+    }
+
+    /// Similarity score the picked distractors should be closest to
+    fn target_similarity(self) -> f64 {
+        match self {
+            Self::Easy => 0.2,
+            Self::Medium => 0.5,
+            Self::Hard => 1.0,
+        }
+    }
+}
+
+/// Whether distractor candidates are scored by translation embeddings
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SemanticScoring {
+    /// When the semantic model is loaded
+    #[default]
+    IfLoaded,
+    /// Never, as when the exercise was generated without the model
+    Off,
+    /// Always; fails when the semantic model is not loaded
+    Required,
+}
+
+/// Signal used to score distractor candidates
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DistractorSignal {
+    /// Same part of speech
+    PartOfSpeech,
+    /// Same lemma or root
+    WordFamily,
+    /// Similar letter count
+    Length,
+    /// Similar translation embedding
+    Semantic,
+    /// Similar corpus frequency of the lemma
+    Frequency,
+}
+
+impl DistractorSignal {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::PartOfSpeech => "part_of_speech",
+            Self::WordFamily => "word_family",
+            Self::Length => "length",
+            Self::Semantic => "semantic",
+            Self::Frequency => "frequency",
+        }
+    }
+
+    pub fn from_slug(slug: &str) -> Option<Self> {
+        match slug {
+            "part_of_speech" => Some(Self::PartOfSpeech),
+            "word_family" => Some(Self::WordFamily),
+            "length" => Some(Self::Length),
+            "semantic" => Some(Self::Semantic),
+            "frequency" => Some(Self::Frequency),
+            _ => None,
+        }
+    }
+}
+
+/// How an exercise's distractors were picked
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DistractorStrategy {
+    pub difficulty: DistractorDifficulty,
+    /// Signals that contributed to scoring; empty when no candidate could be scored
+    pub signals: Vec<DistractorSignal>,
+}
+
+impl DistractorStrategy {
+    /// Semantic scoring that picks the same distractors again from the seed,
+    /// whether or not the semantic model is loaded now
+    pub fn semantic_scoring(&self) -> SemanticScoring {
+        if self.signals.contains(&DistractorSignal::Semantic) {
+            SemanticScoring::Required
+        } else {
+            SemanticScoring::Off
+        }
+    }
+}
+
+/// Features of a word used for distractor scoring
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WordProfile {
+    /// Normalized Arabic text
+    pub text: String,
+    pub pos: Option<String>,
+    pub root: Option<String>,
+    pub lemma: Option<String>,
+    /// English translation
+    pub translation: Option<String>,
+    /// Corpus occurrences of the lemma
+    pub frequency: Option<u32>,
+}
+
+impl WordProfile {
+    /// Similarity to `other` in [0, 1] and the signals it was computed from.
+    /// `semantic` is the translation similarity, when embeddings are available.
+    pub fn similarity(
+        &self,
+        other: &WordProfile,
+        semantic: Option<f64>,
+    ) -> (f64, Vec<DistractorSignal>) {
+        let mut scores = Vec::with_capacity(5);
+
+        if let (Some(a), Some(b)) = (&self.pos, &other.pos) {
+            let same = a.eq_ignore_ascii_case(b);
+            scores.push((DistractorSignal::PartOfSpeech, WEIGHT_POS, f64::from(same)));
+        }
+        if self.lemma.is_some() || self.root.is_some() {
+            let family = if self.lemma.is_some() && self.lemma == other.lemma {
+                1.0
+            } else if self.root.is_some() && self.root == other.root {
+                0.7
+            } else {
+                0.0
+            };
+            scores.push((DistractorSignal::WordFamily, WEIGHT_FAMILY, family));
+        }
+        let (len_a, len_b) = (self.text.chars().count(), other.text.chars().count());
+        if len_a.max(len_b) > 0 {
+            let diff = len_a.abs_diff(len_b) as f64 / len_a.max(len_b) as f64;
+            scores.push((DistractorSignal::Length, WEIGHT_LENGTH, 1.0 - diff));
+        }
+        if let Some(s) = semantic {
+            scores.push((
+                DistractorSignal::Semantic,
+                WEIGHT_SEMANTIC,
+                s.clamp(0.0, 1.0),
+            ));
+        }
+        if let (Some(a), Some(b)) = (self.frequency, other.frequency) {
+            let ratio = a.min(b) as f64 / a.max(b).max(1) as f64;
+            scores.push((DistractorSignal::Frequency, WEIGHT_FREQUENCY, ratio));
+        }
+
+        let total_weight: f64 = scores.iter().map(|(_, w, _)| w).sum();
+        if total_weight == 0.0 {
+            return (0.0, Vec::new());
+        }
+        let score = scores.iter().map(|(_, w, s)| w * s).sum::<f64>() / total_weight;
+        (
+            score,
+            scores.into_iter().map(|(signal, _, _)| signal).collect(),
+        )
+    }
+
+    /// Whether the candidate would be a second correct answer
+    fn is_equivalent(&self, other: &WordProfile) -> bool {
+        self.text == other.text
+            || matches!(
+                (&self.translation, &other.translation),
+                (Some(a), Some(b)) if a.trim().eq_ignore_ascii_case(b.trim())
+            )
+    }
+}
+
+/// A scored candidate
+#[derive(Debug, Clone)]
+pub struct ScoredCandidate {
+    pub node_id: i64,
+    pub profile: WordProfile,
+    /// Translation similarity to the target, if embeddings are available
+    pub semantic: Option<f64>,
+}
+
+/// Pick `count` distractors whose similarity to `target` is closest to the
+/// difficulty's target. Candidates equivalent to the target (same text or
+/// translation) and duplicate texts are skipped; ties are broken by `rng`.
+pub fn pick_distractors(
+    target: &WordProfile,
+    mut candidates: Vec<ScoredCandidate>,
+    count: usize,
+    difficulty: DistractorDifficulty,
+    rng: &mut StdRng,
+) -> (Vec<i64>, DistractorStrategy) {
+    candidates.shuffle(rng);
+
+    let goal = difficulty.target_similarity();
+    let mut signals = Vec::new();
+    let mut scored: Vec<(f64, ScoredCandidate)> = candidates
+        .into_iter()
+        .filter(|c| !target.is_equivalent(&c.profile))
+        .map(|c| {
+            let (score, used) = target.similarity(&c.profile, c.semantic);
+            for signal in used {
+                if !signals.contains(&signal) {
+                    signals.push(signal);
+                }
+            }
+            ((score - goal).abs(), c)
+        })
+        .collect();
+    scored.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let mut seen_texts = HashSet::new();
+    let picked = scored
+        .into_iter()
+        .filter(|(_, c)| seen_texts.insert(c.profile.text.clone()))
+        .map(|(_, c)| c.node_id)
+        .take(count)
+        .collect();
+
+    (
+        picked,
+        DistractorStrategy {
+            difficulty,
+            signals,
+        },
+    )
+}
+
+/// Rank candidate roots for an Identify Root MCQ. Hard distractors share more
+/// radicals with the correct root; candidate order breaks ties.
+pub fn rank_root_distractors(
+    correct: &str,
+    candidates: &[&str],
+    count: usize,
+    difficulty: DistractorDifficulty,
+) -> Vec<String> {
+    let radicals = |root: &str| -> Vec<char> {
+        root.chars()
+            .filter(|c| *c != '-' && !c.is_whitespace())
+            .collect()
+    };
+    let correct_radicals = radicals(correct);
+    let goal = difficulty.target_similarity();
+
+    let mut ranked: Vec<(f64, &str)> = candidates
+        .iter()
+        .filter(|r| radicals(r) != correct_radicals)
+        .map(|r| {
+            let candidate = radicals(r);
+            let shared = correct_radicals
+                .iter()
+                .filter(|c| candidate.contains(c))
+                .count();
+            let similarity = shared as f64 / correct_radicals.len().max(1) as f64;
+            ((similarity - goal).abs(), *r)
+        })
+        .collect();
+    ranked.sort_by(|a, b| a.0.total_cmp(&b.0));
+    ranked
+        .into_iter()
+        .take(count)
+        .map(|(_, r)| r.to_string())
+        .collect()
+}
+
+/// Builds word profiles from morphology, translations, frequency data and
+/// (when the semantic model is loaded) translation embeddings.
+pub struct DistractorEngine<'a> {
+    content_repo: &'a dyn ContentRepository,
+    embedder: Option<&'a SemanticEmbedder>,
+}
+
+impl<'a> DistractorEngine<'a> {
+    /// Fails when `semantic` requires the semantic model and it is not loaded
+    pub fn new(content_repo: &'a dyn ContentRepository, semantic: SemanticScoring) -> Result<Self> {
+        let embedder = match semantic {
+            SemanticScoring::IfLoaded => SEMANTIC_EMBEDDER.get(),
+            SemanticScoring::Off => None,
+            SemanticScoring::Required => Some(SEMANTIC_EMBEDDER.get().ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Distractors were scored by the semantic model, which is not loaded"
+                )
+            })?),
+        };
+        Ok(Self {
+            content_repo,
+            embedder,
+        })
+    }
+
+    /// Pick `count` distractor word node IDs for `target` among the `nearby`
+    /// (node ID, word) candidates, widened with words sharing the target's root.
+    pub async fn word_distractors(
+        &self,
+        target: &Word,
+        nearby: Vec<(i64, Word)>,
+        count: usize,
+        difficulty: DistractorDifficulty,
+        rng: &mut StdRng,
+    ) -> Result<(Vec<i64>, DistractorStrategy)> {
+        let target_node_id = word_instance_node_id(target, self.content_repo).await?;
+        let mut target_profile = self.profile(target_node_id, target).await?;
+
+        let mut pool = nearby;
+        if let Some(root) = &target_profile.root {
+            for word in self
+                .content_repo
+                .get_words_for_root(root, ROOT_FAMILY_LIMIT)
+                .await?
+            {
+                if word.id == target.id || pool.iter().any(|(_, w)| w.id == word.id) {
+                    continue;
+                }
+                let id = word_instance_node_id(&word, self.content_repo).await?;
+                pool.push((id, word));
+            }
+        }
+
+        let mut candidates = Vec::with_capacity(pool.len());
+        for (node_id, word) in pool {
+            if word.id == target.id {
+                continue;
+            }
+            let profile = self.profile(node_id, &word).await?;
+            candidates.push(ScoredCandidate {
+                node_id,
+                profile,
+                semantic: None,
+            });
+        }
+
+        let lemmas: Vec<String> = std::iter::once(&target_profile)
+            .chain(candidates.iter().map(|c| &c.profile))
+            .filter_map(|p| p.lemma.clone())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let frequencies = self.content_repo.get_lemma_frequencies(&lemmas).await?;
+        let frequency_of =
+            |p: &WordProfile| p.lemma.as_ref().and_then(|l| frequencies.get(l)).copied();
+        target_profile.frequency = frequency_of(&target_profile);
+        for candidate in &mut candidates {
+            candidate.profile.frequency = frequency_of(&candidate.profile);
+        }
+
+        if let (Some(embedder), Some(target_translation)) =
+            (self.embedder, &target_profile.translation)
+        {
+            for candidate in &mut candidates {
+                if let Some(translation) = &candidate.profile.translation {
+                    candidate.semantic = embedder
+                        .similarity(target_translation, translation)
+                        .ok()
+                        .map(f64::from);
+                }
+            }
+        }
+
+        Ok(pick_distractors(
+            &target_profile,
+            candidates,
+            count,
+            difficulty,
+            rng,
+        ))
+    }
+
+    async fn profile(&self, node_id: i64, word: &Word) -> Result<WordProfile> {
+        let morphology = self.content_repo.get_morphology_for_word(word.id).await?;
+        Ok(WordProfile {
+            text: MemorizationExercise::normalize_arabic(&word.text_uthmani),
+            pos: morphology.iter().find_map(|s| s.pos_tag.clone()),
+            root: morphology.iter().find_map(|s| s.root_id.clone()),
+            lemma: morphology.iter().find_map(|s| s.lemma_id.clone()),
+            translation: self.content_repo.get_translation(node_id, "en").await?,
+            frequency: None,
+        })
+    }
+}
+
+/// Node ID of a word instance, falling back to the encoded ID when the node
+/// is not registered.
+async fn word_instance_node_id(word: &Word, content_repo: &dyn ContentRepository) -> Result<i64> {
+    let (chapter, verse) = node_id::parse_verse(&node_id::verse_from_key(&word.verse_key))
+        .map_err(|e| anyhow::anyhow!(e))?;
+    let ukey = node_id::word_instance(chapter, verse, word.position as u8);
+    Ok(match content_repo.get_node_by_ukey(&ukey).await? {
+        Some(node) => node.id,
+        None => node_id::encode_word_instance(chapter, verse, word.position as u8),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    fn profile(text: &str, pos: &str, root: Option<&str>, translation: &str) -> WordProfile {
+        WordProfile {
+            text: text.to_string(),
+            pos: Some(pos.to_string()),
+            root: root.map(str::to_string),
+            lemma: None,
+            translation: Some(translation.to_string()),
+            frequency: None,
+        }
+    }
+
+    fn candidate(node_id: i64, profile: WordProfile) -> ScoredCandidate {
+        ScoredCandidate {
+            node_id,
+            profile,
+            semantic: None,
+        }
+    }
+
+    #[test]
+    fn test_difficulty_from_energy() {
+        assert_eq!(
+            DistractorDifficulty::from_energy(0.1),
+            DistractorDifficulty::Easy
+        );
+        assert_eq!(
+            DistractorDifficulty::from_energy(0.5),
+            DistractorDifficulty::Medium
+        );
+        assert_eq!(
+            DistractorDifficulty::from_energy(0.9),
+            DistractorDifficulty::Hard
+        );
+    }
+
+    #[test]
+    fn test_similarity_uses_available_signals() {
+        let target = profile("يعلمون", "V", Some("علم"), "they know");
+        let family = profile("يعلمون", "V", Some("علم"), "they know");
+        let (score, signals) = target.similarity(&family, None);
+        assert!((score - (0.25 + 0.25 * 0.7 + 0.2) / 0.7).abs() < 1e-9);
+        assert_eq!(
+            signals,
+            vec![
+                DistractorSignal::PartOfSpeech,
+                DistractorSignal::WordFamily,
+                DistractorSignal::Length
+            ]
+        );
+
+        let (_, signals) = WordProfile::default().similarity(&WordProfile::default(), Some(0.5));
+        assert_eq!(signals, vec![DistractorSignal::Semantic]);
+    }
+
+    #[test]
+    fn test_pick_follows_difficulty() {
+        let target = profile("يعلمون", "V", Some("علم"), "they know");
+        let candidates = vec![
+            candidate(1, profile("علم", "N", Some("علم"), "knowledge")),
+            candidate(2, profile("يعملون", "V", Some("عمل"), "they do")),
+            candidate(3, profile("في", "P", None, "in")),
+            candidate(4, profile("تعلمون", "V", Some("علم"), "you know")),
+        ];
+        let mut rng = StdRng::seed_from_u64(1);
+
+        let (hard, strategy) = pick_distractors(
+            &target,
+            candidates.clone(),
+            1,
+            DistractorDifficulty::Hard,
+            &mut rng,
+        );
+        assert_eq!(hard, vec![4], "Same root, POS and length");
+        assert_eq!(strategy.difficulty, DistractorDifficulty::Hard);
+        assert!(strategy.signals.contains(&DistractorSignal::WordFamily));
+
+        let (easy, _) =
+            pick_distractors(&target, candidates, 1, DistractorDifficulty::Easy, &mut rng);
+        assert_eq!(easy, vec![3], "Different POS, family and length");
+    }
+
+    #[test]
+    fn test_pick_skips_equivalent_answers() {
+        let target = profile("الله", "PN", None, "Allah");
+        let candidates = vec![
+            candidate(1, profile("الله", "PN", None, "God")),
+            candidate(2, profile("اللهم", "PN", None, "allah ")),
+            candidate(3, profile("رب", "N", None, "Lord")),
+            candidate(4, profile("رب", "N", None, "Lord")),
+        ];
+        let (picked, _) = pick_distractors(
+            &target,
+            candidates,
+            3,
+            DistractorDifficulty::Medium,
+            &mut StdRng::seed_from_u64(0),
+        );
+        assert_eq!(picked.len(), 1);
+        assert!(picked[0] == 3 || picked[0] == 4);
+    }
+
+    #[test]
+    fn test_semantic_scoring_follows_recorded_signals() {
+        let mut strategy = DistractorStrategy {
+            difficulty: DistractorDifficulty::Hard,
+            signals: vec![DistractorSignal::PartOfSpeech, DistractorSignal::Length],
+        };
+        assert_eq!(strategy.semantic_scoring(), SemanticScoring::Off);
+        strategy.signals.push(DistractorSignal::Semantic);
+        assert_eq!(strategy.semantic_scoring(), SemanticScoring::Required);
+    }
+
+    #[test]
+    fn test_rank_root_distractors() {
+        let roots = ["ك-ت-ب", "ع-م-ل", "ع-ل-م", "ق-و-ل", "ع-ل-ن"];
+        let hard = rank_root_distractors("ع-ل-م", &roots, 2, DistractorDifficulty::Hard);
+        assert_eq!(hard, vec!["ع-م-ل", "ع-ل-ن"]);
+
+        let easy = rank_root_distractors("ع-ل-م", &roots, 2, DistractorDifficulty::Easy);
+        assert!(!easy.contains(&"ع-ل-م".to_string()));
+        assert!(easy.contains(&"ق-و-ل".to_string()) || easy.contains(&"ك-ت-ب".to_string()));
+    }
+}
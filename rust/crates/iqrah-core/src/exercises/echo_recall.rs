@@ -4,10 +4,14 @@
 // This is the core memorization exercise for Quran learning. Words are displayed
 // with varying visibility based on learner's energy/mastery level.
 
-use crate::domain::models::{EchoRecallState, EchoRecallStats, EchoRecallWord, WordVisibility};
+use super::script;
+use crate::domain::models::{
+    AyahJunction, EchoRecallState, EchoRecallStats, EchoRecallWord, WordVisibility,
+};
 use crate::domain::node_id as nid;
 use crate::ports::{ContentRepository, UserRepository};
 use crate::services::{energy_service, recall_model};
+use crate::Script;
 use anyhow::{anyhow, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
@@ -32,10 +36,12 @@ impl EchoRecallExercise {
     ///
     /// Fetches all words from the specified ayahs, retrieves their current
     /// energy levels from the user repository, and calculates initial visibility.
+    /// Energies and texts are loaded in batches, not per word.
     ///
     /// # Arguments
     /// * `user_id` - The user ID for fetching/saving memory states
     /// * `ayah_node_ids` - List of ayah node IDs (e.g., ["VERSE:1:1", "VERSE:1:2"])
+    /// * `script` - Script the words are shown in
     /// * `content_repo` - Repository for fetching word data
     /// * `user_repo` - Repository for fetching user memory states
     ///
@@ -44,6 +50,7 @@ impl EchoRecallExercise {
     pub async fn new(
         user_id: &str,
         ayah_node_ids: Vec<String>,
+        script: Script,
         content_repo: &dyn ContentRepository,
         user_repo: &dyn UserRepository,
     ) -> Result<Self> {
@@ -64,57 +71,39 @@ impl EchoRecallExercise {
             return Err(anyhow!("No words found in specified ayahs"));
         }
 
-        // Build energy map for all words
-        let mut energy_map: HashMap<i64, f64> = HashMap::new();
-        for word in &words {
-            let memory_state = user_repo
-                .get_memory_state(user_id, word.id)
-                .await
-                .ok()
-                .flatten();
-            let energy = memory_state.map(|s| s.energy).unwrap_or(0.0);
-            energy_map.insert(word.id, energy);
-        }
+        // Energies and texts for all words, batched so that long sessions
+        // cost a fixed number of queries
+        let word_ids: Vec<i64> = words.iter().map(|w| w.id).collect();
+        let energy_map: HashMap<i64, f64> = user_repo
+            .get_memory_basics(user_id, &word_ids)
+            .await
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(id, basics)| (id, basics.energy as f64))
+            .collect();
+        let mut texts = script::node_texts(&word_ids, script, content_repo)
+            .await
+            .unwrap_or_default();
 
-        // Calculate visibility for each word with neighbor context
-        let mut echo_recall_words = Vec::with_capacity(words.len());
-        for (i, word) in words.iter().enumerate() {
-            let word_text = content_repo
-                .get_quran_text(word.id)
-                .await
-                .ok()
-                .flatten()
-                .unwrap_or_default();
-
-            let energy = *energy_map.get(&word.id).unwrap_or(&0.0);
-
-            // Get neighbor energies for context-aware hint selection
-            let prev_energy = if i > 0 {
-                energy_map.get(&words[i - 1].id).copied()
-            } else {
-                None
-            };
-
-            let next_energy = if i < words.len() - 1 {
-                energy_map.get(&words[i + 1].id).copied()
-            } else {
-                None
-            };
-
-            // Calculate context-aware visibility
-            let visibility = energy_service::map_energy_to_visibility(
-                energy,
-                &word_text,
-                prev_energy,
-                next_energy,
-            );
-
-            echo_recall_words.push(EchoRecallWord {
+        let mut echo_recall_words: Vec<EchoRecallWord> = words
+            .iter()
+            .map(|word| EchoRecallWord {
                 node_id: nid::to_ukey(word.id).unwrap_or_default(),
-                text: word_text,
-                visibility,
-                energy,
-            });
+                text: texts.remove(&word.id).unwrap_or_default(),
+                visibility: WordVisibility::Visible,
+                energy: *energy_map.get(&word.id).unwrap_or(&0.0),
+            })
+            .collect();
+
+        // Calculate visibility for each word with neighbor context. Neighbors
+        // without a memory state count as anchors.
+        let known: Vec<bool> = words
+            .iter()
+            .map(|w| energy_map.contains_key(&w.id))
+            .collect();
+        for i in 0..echo_recall_words.len() {
+            let visibility = word_visibility(&echo_recall_words, i, |j| known[j]);
+            echo_recall_words[i].visibility = visibility;
         }
 
         Ok(Self {
@@ -196,24 +185,7 @@ impl EchoRecallExercise {
         }
 
         for &i in &indices_to_update {
-            let prev_energy = if i > 0 {
-                Some(self.state.words[i - 1].energy)
-            } else {
-                None
-            };
-
-            let next_energy = if i < self.state.words.len() - 1 {
-                Some(self.state.words[i + 1].energy)
-            } else {
-                None
-            };
-
-            self.state.words[i].visibility = energy_service::map_energy_to_visibility(
-                self.state.words[i].energy,
-                &self.state.words[i].text,
-                prev_energy,
-                next_energy,
-            );
+            self.state.words[i].visibility = word_visibility(&self.state.words, i, |_| true);
         }
     }
 
@@ -234,6 +206,15 @@ impl EchoRecallExercise {
             .collect()
     }
 
+    /// Get the ayah junctions of the session for persistence
+    ///
+    /// Reported separately from `finalize`: one entry per pair of consecutive
+    /// ayahs, with the strength of the transition between them, so weak
+    /// transitions can be scheduled on their own.
+    pub fn finalize_junctions(&self) -> Vec<AyahJunction> {
+        ayah_junctions(&self.state.words)
+    }
+
     /// Check if all words are fully mastered (hidden)
     pub fn is_complete(&self) -> bool {
         self.state
@@ -260,12 +241,75 @@ impl EchoRecallExercise {
     }
 }
 
+/// Chapter and verse of a word, known for word-instance node IDs
+fn word_verse(word: &EchoRecallWord) -> Option<(u8, u16)> {
+    nid::parse_word_instance(&word.node_id)
+        .ok()
+        .map(|(chapter, verse, _)| (chapter, verse))
+}
+
+/// Whether the word at `i` opens an ayah that follows another ayah of the session
+fn opens_ayah(words: &[EchoRecallWord], i: usize) -> bool {
+    i > 0
+        && matches!(
+            (word_verse(&words[i - 1]), word_verse(&words[i])),
+            (Some(prev), Some(current)) if prev != current
+        )
+}
+
+/// Visibility of the word at `i` given its neighbors
+///
+/// Opening words of an ayah are masked by the strength of the junction with
+/// the previous ayah. `has_energy` tells whether a neighbor's energy is known;
+/// unknown neighbors count as anchors.
+fn word_visibility(
+    words: &[EchoRecallWord],
+    i: usize,
+    has_energy: impl Fn(usize) -> bool,
+) -> WordVisibility {
+    let word = &words[i];
+    if opens_ayah(words, i) {
+        return energy_service::map_junction_visibility(
+            word.energy,
+            &word.text,
+            words[i - 1].energy,
+        );
+    }
+
+    let neighbor_energy = |j: usize| has_energy(j).then(|| words[j].energy);
+    let prev_energy = i.checked_sub(1).and_then(neighbor_energy);
+    let next_energy = (i + 1 < words.len())
+        .then_some(i + 1)
+        .and_then(neighbor_energy);
+    energy_service::map_energy_to_visibility(word.energy, &word.text, prev_energy, next_energy)
+}
+
+/// Junctions between consecutive ayahs, in session order
+fn ayah_junctions(words: &[EchoRecallWord]) -> Vec<AyahJunction> {
+    (1..words.len())
+        .filter(|&i| opens_ayah(words, i))
+        .filter_map(|i| {
+            let (closing, opening) = (&words[i - 1], &words[i]);
+            let (from_chapter, from_verse) = word_verse(closing)?;
+            let (to_chapter, to_verse) = word_verse(opening)?;
+            Some(AyahJunction {
+                from_verse: nid::verse(from_chapter, from_verse),
+                to_verse: nid::verse(to_chapter, to_verse),
+                closing_word: closing.node_id.clone(),
+                opening_word: opening.node_id.clone(),
+                strength: closing.energy.min(opening.energy),
+            })
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::scheduler_v2::MemoryBasics;
     use crate::testing::{MockContentRepository, MockUserRepository};
-    use crate::{MemoryState, Node, NodeType};
-    use chrono::Utc;
+    use crate::{KnowledgeAxis, Node, NodeType, ReviewGrade};
+
     use mockall::predicate::*;
 
     /// Helper to create test nodes for words in Bismillah (1:1)
@@ -314,13 +358,17 @@ mod tests {
         mock.expect_get_words_in_ayahs()
             .returning(move |_| Ok(nodes.clone()));
 
-        // Setup get_quran_text for each word
+        // Word texts come from one batched script lookup
         let texts = get_word_texts();
-        for (id, text) in texts {
-            mock.expect_get_quran_text()
-                .with(eq(id))
-                .returning(move |_| Ok(Some(text.clone())));
-        }
+        mock.expect_get_script_contents_batch()
+            .with(always(), eq("uthmani"))
+            .times(1)
+            .returning(move |ids, _| {
+                Ok(ids
+                    .iter()
+                    .filter_map(|id| Some((*id, texts.get(id)?.clone())))
+                    .collect())
+            });
 
         mock
     }
@@ -329,24 +377,25 @@ mod tests {
     fn create_mock_user_repo(energies: HashMap<i64, f64>) -> MockUserRepository {
         let mut mock = MockUserRepository::new();
 
-        let energies_for_closure = energies;
-        mock.expect_get_memory_state()
-            .returning(move |user_id, node_id| {
-                if let Some(&energy) = energies_for_closure.get(&node_id) {
-                    Ok(Some(MemoryState {
-                        user_id: user_id.to_string(),
-                        node_id,
-                        energy,
-                        stability: 1.0,
-                        difficulty: 0.3,
-                        last_reviewed: Utc::now(),
-                        due_at: Utc::now(),
-                        review_count: 1,
-                    }))
-                } else {
-                    Ok(None)
-                }
+        // Energies come from one batched lookup, never per word
+        mock.expect_get_memory_basics()
+            .times(1)
+            .returning(move |_, node_ids| {
+                Ok(node_ids
+                    .iter()
+                    .filter_map(|id| {
+                        let energy = *energies.get(id)?;
+                        Some((
+                            *id,
+                            MemoryBasics {
+                                energy: energy as f32,
+                                next_due_ts: 0,
+                            },
+                        ))
+                    })
+                    .collect())
             });
+        mock.expect_get_memory_state().never();
 
         mock
     }
@@ -360,6 +409,7 @@ mod tests {
         let exercise = EchoRecallExercise::new(
             "test_user",
             vec!["VERSE:1:1".to_string()],
+            Script::Uthmani,
             &content_repo,
             &user_repo,
         )
@@ -380,6 +430,7 @@ mod tests {
         let exercise = EchoRecallExercise::new(
             "test_user",
             vec!["VERSE:1:1".to_string()],
+            Script::Uthmani,
             &content_repo,
             &user_repo,
         )
@@ -408,6 +459,7 @@ mod tests {
         let exercise = EchoRecallExercise::new(
             "test_user",
             vec!["VERSE:1:1".to_string()],
+            Script::Uthmani,
             &content_repo,
             &user_repo,
         )
@@ -429,6 +481,7 @@ mod tests {
         let mut exercise = EchoRecallExercise::new(
             "test_user",
             vec!["VERSE:1:1".to_string()],
+            Script::Uthmani,
             &content_repo,
             &user_repo,
         )
@@ -457,6 +510,7 @@ mod tests {
         let mut exercise = EchoRecallExercise::new(
             "test_user",
             vec!["VERSE:1:1".to_string()],
+            Script::Uthmani,
             &content_repo,
             &user_repo,
         )
@@ -480,6 +534,7 @@ mod tests {
         let mut exercise = EchoRecallExercise::new(
             "test_user",
             vec!["VERSE:1:1".to_string()],
+            Script::Uthmani,
             &content_repo,
             &user_repo,
         )
@@ -500,6 +555,7 @@ mod tests {
         let mut exercise = EchoRecallExercise::new(
             "test_user",
             vec!["VERSE:1:1".to_string()],
+            Script::Uthmani,
             &content_repo,
             &user_repo,
         )
@@ -531,6 +587,7 @@ mod tests {
         let exercise = EchoRecallExercise::new(
             "test_user",
             vec!["VERSE:1:1".to_string()],
+            Script::Uthmani,
             &content_repo,
             &user_repo,
         )
@@ -561,6 +618,7 @@ mod tests {
         let exercise = EchoRecallExercise::new(
             "test_user",
             vec!["VERSE:1:1".to_string()],
+            Script::Uthmani,
             &content_repo,
             &user_repo,
         )
@@ -588,6 +646,7 @@ mod tests {
         let exercise = EchoRecallExercise::new(
             "test_user",
             vec!["VERSE:1:1".to_string()],
+            Script::Uthmani,
             &content_repo,
             &user_repo,
         )
@@ -606,6 +665,7 @@ mod tests {
         let exercise = EchoRecallExercise::new(
             "test_user",
             vec!["VERSE:1:1".to_string()],
+            Script::Uthmani,
             &content_repo,
             &user_repo,
         )
@@ -648,6 +708,7 @@ mod tests {
         let mut exercise = EchoRecallExercise::new(
             "test_user",
             vec!["VERSE:1:1".to_string()],
+            Script::Uthmani,
             &content_repo,
             &user_repo,
         )
@@ -667,6 +728,7 @@ mod tests {
         let result = EchoRecallExercise::new(
             "test_user",
             vec![], // No ayahs
+            Script::Uthmani,
             &mock,
             &user_mock,
         )
@@ -675,4 +737,76 @@ mod tests {
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("No valid"));
     }
+
+    /// Two-ayah state (1:1 and 1:2) with word-instance node IDs
+    fn two_ayah_state(energies: [f64; 4]) -> EchoRecallState {
+        let positions = [(1, 1), (1, 2), (2, 1), (2, 2)];
+        EchoRecallState {
+            words: positions
+                .iter()
+                .zip(energies)
+                .map(|(&(verse, position), energy)| EchoRecallWord {
+                    node_id: nid::word_instance(1, verse, position),
+                    text: "كلمة".to_string(),
+                    visibility: WordVisibility::Visible,
+                    energy,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_finalize_junctions() {
+        let exercise = EchoRecallExercise::from_state(
+            "test_user",
+            vec!["VERSE:1:1".to_string(), "VERSE:1:2".to_string()],
+            two_ayah_state([0.5, 0.2, 0.9, 0.5]),
+        );
+
+        let junctions = exercise.finalize_junctions();
+        assert_eq!(junctions.len(), 1);
+        let junction = &junctions[0];
+        assert_eq!(junction.from_verse, "VERSE:1:1");
+        assert_eq!(junction.to_verse, "VERSE:1:2");
+        assert_eq!(junction.closing_word, nid::word_instance(1, 1, 2));
+        assert_eq!(junction.opening_word, nid::word_instance(1, 2, 1));
+        assert_eq!(junction.strength, 0.2);
+        assert_eq!(junction.grade(), ReviewGrade::Again);
+        assert_eq!(
+            junction.transition_node_id(),
+            Some(nid::encode_knowledge(
+                nid::encode_verse(1, 2),
+                KnowledgeAxis::ContextualMemorization
+            ))
+        );
+
+        // Words without verse information have no junctions
+        let single =
+            EchoRecallExercise::from_state("test_user", vec![], EchoRecallState { words: vec![] });
+        assert!(single.finalize_junctions().is_empty());
+    }
+
+    #[test]
+    fn test_opening_word_masked_by_junction() {
+        let mut exercise = EchoRecallExercise::from_state(
+            "test_user",
+            vec!["VERSE:1:1".to_string(), "VERSE:1:2".to_string()],
+            two_ayah_state([0.9, 0.1, 0.9, 0.9]),
+        );
+
+        // A fast recall of the opening word keeps it strong on its own...
+        let opening = nid::word_instance(1, 2, 1);
+        exercise.submit_recall(&opening, 500).unwrap();
+        let words = &exercise.state().words;
+        assert!(words[2].energy >= 0.85);
+        // ...but it stays visible while the closing word of 1:1 is weak
+        assert_eq!(words[2].visibility, WordVisibility::Visible);
+
+        // Once the junction is strong, the opening word is hidden
+        let closing = nid::word_instance(1, 1, 2);
+        for _ in 0..20 {
+            exercise.submit_recall(&closing, 500).unwrap();
+        }
+        assert_eq!(exercise.state().words[2].visibility, WordVisibility::Hidden);
+    }
 }
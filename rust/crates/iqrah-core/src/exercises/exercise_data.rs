@@ -4,6 +4,8 @@
 // This module defines the core ExerciseData enum that represents all exercise types
 // using a lightweight, key-based approach (no full text storage).
 
+use super::distractors::DistractorStrategy;
+use crate::domain::{GrammarFeature, TajweedRule};
 use serde::{Deserialize, Serialize};
 
 /// Core exercise data enum - stores only keys/IDs, no full text
@@ -31,6 +33,12 @@ pub enum ExerciseData {
         node_id: i64,
         /// Indices of distractor words (to fetch from database)
         distractor_node_ids: Vec<i64>,
+        /// How the distractors were picked
+        #[serde(default)]
+        distractor_strategy: DistractorStrategy,
+        /// Seed for the random choices made by the generator
+        #[serde(default)]
+        seed: u64,
     },
 
     /// Exercise 2b: MCQ English to Arabic - Multiple choice memorization
@@ -40,6 +48,12 @@ pub enum ExerciseData {
         node_id: i64,
         /// Indices of distractor words (to fetch from database)
         distractor_node_ids: Vec<i64>,
+        /// How the distractors were picked
+        #[serde(default)]
+        distractor_strategy: DistractorStrategy,
+        /// Seed for the random choices made by the generator
+        #[serde(default)]
+        seed: u64,
     },
 
     /// Exercise 3: Translation - Type English translation of Arabic word
@@ -62,6 +76,9 @@ pub enum ExerciseData {
         node_id: i64,
         /// Position of the word to blank out (1-indexed)
         blank_position: i32,
+        /// Seed for the random choices made by the generator
+        #[serde(default)]
+        seed: u64,
     },
 
     /// Exercise 6: First Letter Hint - Memorization with first letter hint
@@ -70,6 +87,9 @@ pub enum ExerciseData {
         node_id: i64,
         /// Position of the word to test (1-indexed)
         word_position: i32,
+        /// Seed for the random choices made by the generator
+        #[serde(default)]
+        seed: u64,
     },
 
     /// Exercise 7: Missing Word MCQ - MCQ for missing word in verse
@@ -80,6 +100,12 @@ pub enum ExerciseData {
         blank_position: i32,
         /// Node IDs of distractor words
         distractor_node_ids: Vec<i64>,
+        /// How the distractors were picked
+        #[serde(default)]
+        distractor_strategy: DistractorStrategy,
+        /// Seed for the random choices made by the generator
+        #[serde(default)]
+        seed: u64,
     },
 
     /// Exercise 8: Next Word MCQ - Predict next word in sequence
@@ -90,6 +116,12 @@ pub enum ExerciseData {
         context_position: i32,
         /// Node IDs of distractor words
         distractor_node_ids: Vec<i64>,
+        /// How the distractors were picked
+        #[serde(default)]
+        distractor_strategy: DistractorStrategy,
+        /// Seed for the random choices made by the generator
+        #[serde(default)]
+        seed: u64,
     },
 
     /// Exercise 9: Full Verse Input - Type entire verse from memory
@@ -120,6 +152,9 @@ pub enum ExerciseData {
         correct_word_node_id: i64,
         /// Node ID of the incorrect (substituted) word
         incorrect_word_node_id: i64,
+        /// Seed for the random choices made by the generator
+        #[serde(default)]
+        seed: u64,
     },
 
     /// Exercise 12: Ayah Sequence - Put verses/words in correct order
@@ -162,6 +197,9 @@ pub enum ExerciseData {
         node_id: i64,
         /// Position of the word to blank out (1-indexed)
         blank_position: i32,
+        /// Seed for the random choices made by the generator
+        #[serde(default)]
+        seed: u64,
     },
 
     /// Exercise 17: Translate Phrase - Type English translation of verse/phrase
@@ -180,6 +218,25 @@ pub enum ExerciseData {
         correct_pos: String,
         /// Options for MCQ
         options: Vec<String>,
+        /// Seed for the random choices made by the generator
+        #[serde(default)]
+        seed: u64,
+    },
+
+    /// Grammar Feature - Identify one morphological feature of a word
+    /// (verb form, tense, mood, person, number, gender, case or state)
+    GrammarFeature {
+        /// Word node ID
+        node_id: i64,
+        /// Feature being asked about
+        feature: GrammarFeature,
+        /// Correct label (e.g., "dual", "IV")
+        correct_value: String,
+        /// Options for MCQ
+        options: Vec<String>,
+        /// Seed for the random choices made by the generator
+        #[serde(default)]
+        seed: u64,
     },
 
     /// Exercise 17: Cross-Verse Connection - Identify thematic connections
@@ -199,6 +256,323 @@ pub enum ExerciseData {
         /// Node IDs of ayahs to practice (verse nodes)
         ayah_node_ids: Vec<i64>,
     },
+
+    /// Exercise 19: Mutashabihat - Pick the correct continuation among similar verses
+    /// Shows the opening shared with the verse's mutashabih siblings; after answering,
+    /// the word where each sibling diverges is highlighted.
+    Mutashabihat {
+        /// Verse node ID
+        node_id: i64,
+        /// Verse key of the correct continuation (e.g., "2:58")
+        verse_key: String,
+        /// Number of leading words shown as the prompt
+        prompt_word_count: i32,
+        /// Candidate verse keys (shuffled, includes `verse_key`)
+        option_verse_keys: Vec<String>,
+        /// First word position (1-indexed) where each option differs from
+        /// `verse_key`; 0 for the correct option
+        differing_positions: Vec<i32>,
+        /// Seed for the random choices made by the generator
+        #[serde(default)]
+        seed: u64,
+    },
+
+    /// Exercise 20: Word Order - Rebuild a verse from shuffled word tiles
+    WordOrder {
+        /// Verse node ID
+        node_id: i64,
+        /// Word node IDs in verse order (for validation)
+        correct_sequence: Vec<i64>,
+        /// Word node IDs in tile (shuffled) order
+        shuffled_word_node_ids: Vec<i64>,
+        /// Tile text for each entry of `shuffled_word_node_ids`, in the chosen script
+        tile_texts: Vec<String>,
+        /// Seed for the random choices made by the generator
+        #[serde(default)]
+        seed: u64,
+    },
+
+    /// Exercise 21: Identify Surah - Which surah does this verse belong to?
+    IdentifySurah {
+        /// Verse node ID
+        node_id: i64,
+        /// Verse key (e.g., "2:255")
+        verse_key: String,
+        /// Correct chapter number
+        correct_chapter: i32,
+        /// Chapter numbers offered (shuffled, neighbors of the correct one)
+        chapter_options: Vec<i32>,
+        /// Seed for the random choices made by the generator
+        #[serde(default)]
+        seed: u64,
+    },
+
+    /// Exercise 22: Identify Verse Number - Which verse number is this?
+    IdentifyVerseNumber {
+        /// Verse node ID
+        node_id: i64,
+        /// Verse key (e.g., "2:255")
+        verse_key: String,
+        /// Correct verse number
+        correct_verse_number: i32,
+        /// Verse numbers offered (shuffled, neighbors of the correct one)
+        verse_number_options: Vec<i32>,
+        /// Seed for the random choices made by the generator
+        #[serde(default)]
+        seed: u64,
+    },
+
+    /// Exercise 23: Identify Page Half - Is the verse in the first or second half of its page?
+    IdentifyPageHalf {
+        /// Verse node ID
+        node_id: i64,
+        /// Verse key (e.g., "2:255")
+        verse_key: String,
+        /// Mushaf page number
+        page: i32,
+        /// 1 for the first half of the page, 2 for the second
+        half: i32,
+    },
+
+    /// Exercise 24: Transliteration to Arabic - Pick the Arabic word for a transliteration
+    /// Reading bridge for learners who can't yet read Arabic fluently
+    TransliterationToArabic {
+        /// Word node ID
+        node_id: i64,
+        /// Node IDs of distractor words (Arabic options)
+        distractor_node_ids: Vec<i64>,
+        /// How the distractors were picked
+        #[serde(default)]
+        distractor_strategy: DistractorStrategy,
+        /// Seed for the random choices made by the generator
+        #[serde(default)]
+        seed: u64,
+    },
+
+    /// Exercise 25: Arabic to Transliteration - Type the transliteration of a highlighted word
+    /// Graded with lenient Latin normalization (see `normalize_transliteration`)
+    ArabicToTransliteration {
+        /// Word node ID
+        node_id: i64,
+        /// Verse key the word is highlighted in (e.g., "1:1")
+        verse_key: String,
+    },
+
+    /// Exercise 26: Root Family - Pick every word in the passage derived from a root
+    RootFamily {
+        /// Verse node ID
+        node_id: i64,
+        /// Root ID (e.g., "ع-ل-م")
+        root_id: String,
+        /// Verses shown: the verse itself, or its page when the verse holds
+        /// too few words of one root
+        verse_keys: Vec<String>,
+        /// Word-instance node IDs derived from the root, in mushaf order (for validation)
+        family_word_node_ids: Vec<i64>,
+    },
+
+    /// Exercise 27: Lemma Meaning - Match the lemmas of a word's root to their meanings
+    LemmaMeaning {
+        /// Word node ID
+        node_id: i64,
+        /// Root ID the lemmas derive from
+        root_id: String,
+        /// Lemma IDs shown, most frequent first
+        lemma_ids: Vec<String>,
+        /// Meanings offered, each given as the lemma it belongs to (shuffled)
+        meaning_lemma_ids: Vec<String>,
+        /// Seed for the random choices made by the generator
+        #[serde(default)]
+        seed: u64,
+    },
+
+    /// Exercise 28: Recite Page - Type a whole mushaf page from memory
+    RecitePage {
+        /// Verse node ID (a verse starting on the page)
+        node_id: i64,
+        /// Mushaf page number
+        page: i32,
+        /// Verses with words on the page, in mushaf order
+        verse_keys: Vec<String>,
+        /// First word-instance node ID on the page
+        first_word_node_id: i64,
+        /// Last word-instance node ID on the page
+        last_word_node_id: i64,
+    },
+
+    /// Exercise 29: Page First Word - What is the first word of page N?
+    PageFirstWord {
+        /// Verse node ID (a verse starting on the page)
+        node_id: i64,
+        /// Mushaf page number
+        page: i32,
+        /// First word-instance node ID on the page (the answer)
+        first_word_node_id: i64,
+        /// First words of nearby pages
+        distractor_node_ids: Vec<i64>,
+        /// Seed for the random choices made by the generator
+        #[serde(default)]
+        seed: u64,
+    },
+
+    /// Exercise 30: Identify Verse Line - Which line of its page does this verse start on?
+    IdentifyVerseLine {
+        /// Verse node ID
+        node_id: i64,
+        /// Verse key (e.g., "2:255")
+        verse_key: String,
+        /// Mushaf page number
+        page: i32,
+        /// Line the verse starts on (1 to 15)
+        correct_line: i32,
+        /// Line numbers offered (shuffled, neighbors of the correct one)
+        line_options: Vec<i32>,
+        /// Seed for the random choices made by the generator
+        #[serde(default)]
+        seed: u64,
+    },
+
+    /// Exercise 31: Listen Identify Verse - Which verse is this recitation from?
+    /// The clip is cut from the reciter's verse recitation by word timings
+    ListenIdentifyVerse {
+        /// Verse node ID
+        node_id: i64,
+        /// Reciter of the clip
+        reciter_id: i32,
+        /// Verse recited (the answer)
+        verse_key: String,
+        /// First word recited (1-indexed)
+        first_position: i32,
+        /// Last word recited (inclusive)
+        last_position: i32,
+        /// Start of the clip in the verse recitation
+        start_ms: i64,
+        /// End of the clip in the verse recitation
+        end_ms: i64,
+        /// Candidate verse keys (shuffled, includes `verse_key`)
+        option_verse_keys: Vec<String>,
+        /// Seed for the random choices made by the generator
+        #[serde(default)]
+        seed: u64,
+    },
+
+    /// Exercise 32: Listen Continue - Which word comes right after this clip?
+    ListenContinue {
+        /// Verse node ID
+        node_id: i64,
+        /// Reciter of the clip
+        reciter_id: i32,
+        /// Verse recited
+        verse_key: String,
+        /// First word recited (1-indexed)
+        first_position: i32,
+        /// Last word recited (inclusive); the answer is the word after it
+        last_position: i32,
+        /// Start of the clip in the verse recitation
+        start_ms: i64,
+        /// End of the clip in the verse recitation
+        end_ms: i64,
+        /// Node IDs of distractor words
+        distractor_node_ids: Vec<i64>,
+        /// How the distractors were picked
+        #[serde(default)]
+        distractor_strategy: DistractorStrategy,
+        /// Seed for the random choices made by the generator
+        #[serde(default)]
+        seed: u64,
+    },
+
+    /// Exercise 33: Listen Identify Word - Which word was just recited?
+    ListenIdentifyWord {
+        /// Word node ID (the answer)
+        node_id: i64,
+        /// Reciter of the clip
+        reciter_id: i32,
+        /// Verse the word is in
+        verse_key: String,
+        /// Word position in the verse (1-indexed)
+        position: i32,
+        /// Start of the word in the verse recitation (None: the word has its
+        /// own clip, see `ContentRepository::get_word_audio`)
+        start_ms: Option<i64>,
+        /// End of the word in the verse recitation
+        end_ms: Option<i64>,
+        /// Node IDs of distractor words
+        distractor_node_ids: Vec<i64>,
+        /// How the distractors were picked
+        #[serde(default)]
+        distractor_strategy: DistractorStrategy,
+        /// Seed for the random choices made by the generator
+        #[serde(default)]
+        seed: u64,
+    },
+
+    /// Exercise 34: Identify Tajweed Rule - Which rule applies to the
+    /// highlighted letters?
+    IdentifyTajweedRule {
+        /// Verse node ID
+        node_id: i64,
+        /// Word-instance node ID of the highlighted word
+        word_node_id: i64,
+        /// First highlighted character of the word's Uthmani text (0-indexed)
+        start_char: i32,
+        /// End of the highlight (exclusive)
+        end_char: i32,
+        /// Rule annotated on the span (the answer)
+        correct_rule: TajweedRule,
+        /// Options for MCQ (shuffled, includes `correct_rule`)
+        rule_options: Vec<TajweedRule>,
+        /// Seed for the random choices made by the generator
+        #[serde(default)]
+        seed: u64,
+    },
+
+    /// Exercise 35: Find Tajweed Rule - Pick every word of the verse where a
+    /// rule applies
+    FindTajweedRule {
+        /// Verse node ID
+        node_id: i64,
+        /// Rule to find
+        rule: TajweedRule,
+        /// Word-instance node IDs where the rule applies, in reading order (for validation)
+        rule_word_node_ids: Vec<i64>,
+        /// Seed for the random choices made by the generator
+        #[serde(default)]
+        seed: u64,
+    },
+
+    /// Exercise 36: Tafsir Key Lesson - Which lesson does the commentary draw
+    /// from this verse?
+    TafsirKeyLesson {
+        /// Verse node ID
+        node_id: i64,
+        /// Tafsir the lessons are taken from
+        tafsir_id: i32,
+        /// Start verse key of the passage commenting on the verse (the answer)
+        passage_key: String,
+        /// Start verse keys of the passages whose key lessons are offered
+        /// (shuffled, includes `passage_key`)
+        option_passage_keys: Vec<String>,
+        /// Seed for the random choices made by the generator
+        #[serde(default)]
+        seed: u64,
+    },
+
+    /// Exercise 37: Revelation Occasion - Which verse was revealed on this
+    /// occasion?
+    RevelationOccasion {
+        /// Verse node ID (the answer)
+        node_id: i64,
+        /// Tafsir reporting the occasion
+        tafsir_id: i32,
+        /// Start verse key of the passage reporting the occasion
+        passage_key: String,
+        /// Candidate verse keys (shuffled, includes the verse's key)
+        option_verse_keys: Vec<String>,
+        /// Seed for the random choices made by the generator
+        #[serde(default)]
+        seed: u64,
+    },
 }
 
 impl ExerciseData {
@@ -224,7 +598,27 @@ impl ExerciseData {
             | Self::ReverseCloze { node_id, .. }
             | Self::TranslatePhrase { node_id, .. }
             | Self::PosTagging { node_id, .. }
-            | Self::CrossVerseConnection { node_id, .. } => *node_id,
+            | Self::GrammarFeature { node_id, .. }
+            | Self::CrossVerseConnection { node_id, .. }
+            | Self::Mutashabihat { node_id, .. }
+            | Self::WordOrder { node_id, .. }
+            | Self::IdentifySurah { node_id, .. }
+            | Self::IdentifyVerseNumber { node_id, .. }
+            | Self::IdentifyPageHalf { node_id, .. }
+            | Self::TransliterationToArabic { node_id, .. }
+            | Self::ArabicToTransliteration { node_id, .. }
+            | Self::RootFamily { node_id, .. }
+            | Self::LemmaMeaning { node_id, .. }
+            | Self::RecitePage { node_id, .. }
+            | Self::PageFirstWord { node_id, .. }
+            | Self::IdentifyVerseLine { node_id, .. }
+            | Self::ListenIdentifyVerse { node_id, .. }
+            | Self::ListenContinue { node_id, .. }
+            | Self::ListenIdentifyWord { node_id, .. }
+            | Self::IdentifyTajweedRule { node_id, .. }
+            | Self::FindTajweedRule { node_id, .. }
+            | Self::TafsirKeyLesson { node_id, .. }
+            | Self::RevelationOccasion { node_id, .. } => *node_id,
             // EchoRecall uses first ayah as representative node for scheduling
             Self::EchoRecall { ayah_node_ids } => ayah_node_ids.first().copied().unwrap_or(0),
         }
@@ -252,8 +646,103 @@ impl ExerciseData {
             Self::ReverseCloze { .. } => "reverse_cloze",
             Self::TranslatePhrase { .. } => "translate_phrase",
             Self::PosTagging { .. } => "pos_tagging",
+            Self::GrammarFeature { .. } => "grammar_feature",
             Self::CrossVerseConnection { .. } => "cross_verse_connection",
             Self::EchoRecall { .. } => "echo_recall",
+            Self::Mutashabihat { .. } => "mutashabihat",
+            Self::WordOrder { .. } => "word_order",
+            Self::IdentifySurah { .. } => "identify_surah",
+            Self::IdentifyVerseNumber { .. } => "identify_verse_number",
+            Self::IdentifyPageHalf { .. } => "identify_page_half",
+            Self::TransliterationToArabic { .. } => "transliteration_to_arabic",
+            Self::ArabicToTransliteration { .. } => "arabic_to_transliteration",
+            Self::RootFamily { .. } => "root_family",
+            Self::LemmaMeaning { .. } => "lemma_meaning",
+            Self::RecitePage { .. } => "recite_page",
+            Self::PageFirstWord { .. } => "page_first_word",
+            Self::IdentifyVerseLine { .. } => "identify_verse_line",
+            Self::ListenIdentifyVerse { .. } => "listen_identify_verse",
+            Self::ListenContinue { .. } => "listen_continue",
+            Self::ListenIdentifyWord { .. } => "listen_identify_word",
+            Self::IdentifyTajweedRule { .. } => "identify_tajweed_rule",
+            Self::FindTajweedRule { .. } => "find_tajweed_rule",
+            Self::TafsirKeyLesson { .. } => "tafsir_key_lesson",
+            Self::RevelationOccasion { .. } => "revelation_occasion",
+        }
+    }
+
+    /// Get the RNG seed for exercise types with randomized content
+    ///
+    /// Passing it back to the generator (see `ExerciseService::generate_by_type_seeded`)
+    /// with the same node ID regenerates the exercise identically. Returns None
+    /// for exercise types whose generation is fully deterministic.
+    pub fn seed(&self) -> Option<u64> {
+        match self {
+            Self::McqArToEn { seed, .. }
+            | Self::McqEnToAr { seed, .. }
+            | Self::ClozeDeletion { seed, .. }
+            | Self::FirstLetterHint { seed, .. }
+            | Self::MissingWordMcq { seed, .. }
+            | Self::NextWordMcq { seed, .. }
+            | Self::FindMistake { seed, .. }
+            | Self::ReverseCloze { seed, .. }
+            | Self::PosTagging { seed, .. }
+            | Self::GrammarFeature { seed, .. }
+            | Self::Mutashabihat { seed, .. }
+            | Self::WordOrder { seed, .. }
+            | Self::IdentifySurah { seed, .. }
+            | Self::IdentifyVerseNumber { seed, .. }
+            | Self::TransliterationToArabic { seed, .. }
+            | Self::LemmaMeaning { seed, .. }
+            | Self::PageFirstWord { seed, .. }
+            | Self::IdentifyVerseLine { seed, .. }
+            | Self::ListenIdentifyVerse { seed, .. }
+            | Self::ListenContinue { seed, .. }
+            | Self::ListenIdentifyWord { seed, .. }
+            | Self::IdentifyTajweedRule { seed, .. }
+            | Self::FindTajweedRule { seed, .. }
+            | Self::TafsirKeyLesson { seed, .. }
+            | Self::RevelationOccasion { seed, .. } => Some(*seed),
+            _ => None,
+        }
+    }
+
+    /// How the exercise's distractors were picked, for exercise types with
+    /// scored distractors
+    ///
+    /// Regenerating from `seed` picks the same distractors when given its
+    /// difficulty and `DistractorStrategy::semantic_scoring`.
+    pub fn distractor_strategy(&self) -> Option<&DistractorStrategy> {
+        match self {
+            Self::McqArToEn {
+                distractor_strategy,
+                ..
+            }
+            | Self::McqEnToAr {
+                distractor_strategy,
+                ..
+            }
+            | Self::MissingWordMcq {
+                distractor_strategy,
+                ..
+            }
+            | Self::NextWordMcq {
+                distractor_strategy,
+                ..
+            }
+            | Self::TransliterationToArabic {
+                distractor_strategy,
+                ..
+            }
+            | Self::ListenContinue {
+                distractor_strategy,
+                ..
+            }
+            | Self::ListenIdentifyWord {
+                distractor_strategy,
+                ..
+            } => Some(distractor_strategy),
+            _ => None,
         }
     }
 
@@ -272,6 +761,20 @@ impl ExerciseData {
                 | Self::NextWordMcq { .. }
                 | Self::SequenceRecall { .. }
                 | Self::PosTagging { .. }
+                | Self::GrammarFeature { .. }
+                | Self::Mutashabihat { .. }
+                | Self::IdentifySurah { .. }
+                | Self::IdentifyVerseNumber { .. }
+                | Self::IdentifyPageHalf { .. }
+                | Self::TransliterationToArabic { .. }
+                | Self::PageFirstWord { .. }
+                | Self::IdentifyVerseLine { .. }
+                | Self::ListenIdentifyVerse { .. }
+                | Self::ListenContinue { .. }
+                | Self::ListenIdentifyWord { .. }
+                | Self::IdentifyTajweedRule { .. }
+                | Self::TafsirKeyLesson { .. }
+                | Self::RevelationOccasion { .. }
         )
     }
 
@@ -288,6 +791,7 @@ impl ExerciseData {
                 | Self::AyahChain { .. }
                 | Self::ReverseCloze { .. }
                 | Self::EchoRecall { .. }
+                | Self::RecitePage { .. }
         )
     }
 
@@ -306,6 +810,7 @@ impl ExerciseData {
 mod tests {
     use super::*;
     use crate::exercises::{AnswerInput, ValidationResult};
+    use crate::ReviewGrade;
 
     // ========================================================================
     // ExerciseData Enum Tests
@@ -328,6 +833,8 @@ mod tests {
         let exercise = ExerciseData::McqArToEn {
             node_id: 1,
             distractor_node_ids: vec![2, 3, 4],
+            seed: 0,
+            distractor_strategy: Default::default(),
         };
 
         assert_eq!(exercise.type_name(), "mcq_ar_to_en");
@@ -341,6 +848,8 @@ mod tests {
         let exercise = ExerciseData::McqEnToAr {
             node_id: 1,
             distractor_node_ids: vec![2, 3],
+            seed: 0,
+            distractor_strategy: Default::default(),
         };
 
         assert_eq!(exercise.type_name(), "mcq_en_to_ar");
@@ -373,6 +882,7 @@ mod tests {
         let exercise = ExerciseData::ClozeDeletion {
             node_id: 1,
             blank_position: 3,
+            seed: 0,
         };
 
         assert_eq!(exercise.type_name(), "cloze_deletion");
@@ -384,6 +894,7 @@ mod tests {
         let exercise = ExerciseData::FirstLetterHint {
             node_id: 1,
             word_position: 2,
+            seed: 0,
         };
 
         assert_eq!(exercise.type_name(), "first_letter_hint");
@@ -396,6 +907,8 @@ mod tests {
             node_id: 1,
             blank_position: 3,
             distractor_node_ids: vec![2, 3],
+            seed: 0,
+            distractor_strategy: Default::default(),
         };
 
         assert_eq!(exercise.type_name(), "missing_word_mcq");
@@ -408,6 +921,8 @@ mod tests {
             node_id: 1,
             context_position: 2,
             distractor_node_ids: vec![2],
+            seed: 0,
+            distractor_strategy: Default::default(),
         };
 
         assert_eq!(exercise.type_name(), "next_word_mcq");
@@ -443,6 +958,7 @@ mod tests {
             mistake_position: 3,
             correct_word_node_id: 2,
             incorrect_word_node_id: 3,
+            seed: 0,
         };
 
         assert_eq!(exercise.type_name(), "find_mistake");
@@ -499,6 +1015,7 @@ mod tests {
         let exercise = ExerciseData::ReverseCloze {
             node_id: 1,
             blank_position: 3,
+            seed: 0,
         };
 
         assert_eq!(exercise.type_name(), "reverse_cloze");
@@ -526,6 +1043,7 @@ mod tests {
                 "verb".to_string(),
                 "particle".to_string(),
             ],
+            seed: 0,
         };
 
         assert_eq!(exercise.type_name(), "pos_tagging");
@@ -562,6 +1080,8 @@ mod tests {
         let exercise = ExerciseData::McqArToEn {
             node_id: 1,
             distractor_node_ids: vec![2, 3],
+            seed: 0,
+            distractor_strategy: Default::default(),
         };
 
         let json = serde_json::to_string(&exercise).unwrap();
@@ -588,6 +1108,64 @@ mod tests {
         assert!(json.contains("\"type\":\"ayah_chain\""));
     }
 
+    #[test]
+    fn test_mutashabihat_serialization() {
+        let exercise = ExerciseData::Mutashabihat {
+            node_id: 1,
+            verse_key: "2:58".to_string(),
+            prompt_word_count: 2,
+            option_verse_keys: vec!["7:161".to_string(), "2:58".to_string()],
+            differing_positions: vec![3, 0],
+            seed: 0,
+        };
+
+        let json = serde_json::to_string(&exercise).unwrap();
+        let deserialized: ExerciseData = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(exercise, deserialized);
+        assert!(json.contains("\"type\":\"mutashabihat\""));
+        assert!(exercise.is_mcq());
+    }
+
+    #[test]
+    fn test_identify_tajweed_rule_serialization() {
+        let exercise = ExerciseData::IdentifyTajweedRule {
+            node_id: 1,
+            word_node_id: 2,
+            start_char: 0,
+            end_char: 1,
+            correct_rule: TajweedRule::HamzatWasl,
+            rule_options: vec![TajweedRule::LamShamsiyyah, TajweedRule::HamzatWasl],
+            seed: 0,
+        };
+
+        let json = serde_json::to_string(&exercise).unwrap();
+        let deserialized: ExerciseData = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(exercise, deserialized);
+        assert!(json.contains("\"type\":\"identify_tajweed_rule\""));
+        assert!(json.contains("\"correct_rule\":\"hamzat_wasl\""));
+        assert!(exercise.is_mcq());
+    }
+
+    #[test]
+    fn test_tafsir_key_lesson_serialization() {
+        let exercise = ExerciseData::TafsirKeyLesson {
+            node_id: 1,
+            tafsir_id: 2,
+            passage_key: "2:153".to_string(),
+            option_passage_keys: vec!["2:255".to_string(), "2:153".to_string()],
+            seed: 0,
+        };
+
+        let json = serde_json::to_string(&exercise).unwrap();
+        let deserialized: ExerciseData = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(exercise, deserialized);
+        assert!(json.contains("\"type\":\"tafsir_key_lesson\""));
+        assert!(exercise.is_mcq());
+    }
+
     #[test]
     fn test_find_mistake_serialization() {
         let exercise = ExerciseData::FindMistake {
@@ -595,6 +1173,7 @@ mod tests {
             mistake_position: 3,
             correct_word_node_id: 2,
             incorrect_word_node_id: 3,
+            seed: 0,
         };
 
         let json = serde_json::to_string(&exercise).unwrap();
@@ -609,6 +1188,7 @@ mod tests {
             node_id: 1,
             correct_pos: "noun".to_string(),
             options: vec!["noun".to_string(), "verb".to_string()],
+            seed: 0,
         };
 
         let json = serde_json::to_string(&exercise).unwrap();
@@ -699,6 +1279,7 @@ mod tests {
             similarity_score: Some(0.98),
             semantic_grade: Some("Excellent".to_string()),
             correct_answer: None,
+            suggested_grade: ReviewGrade::Good,
         };
 
         let json = serde_json::to_string(&result).unwrap();
@@ -713,6 +1294,7 @@ mod tests {
             similarity_score: Some(0.45),
             semantic_grade: Some("Incorrect".to_string()),
             correct_answer: Some("بِسْمِ ٱللَّهِ".to_string()),
+            suggested_grade: ReviewGrade::Again,
         };
 
         let json = serde_json::to_string(&result).unwrap();
@@ -730,10 +1312,14 @@ mod tests {
             ExerciseData::McqArToEn {
                 node_id: 1,
                 distractor_node_ids: vec![],
+                seed: 0,
+                distractor_strategy: Default::default(),
             },
             ExerciseData::McqEnToAr {
                 node_id: 1,
                 distractor_node_ids: vec![],
+                seed: 0,
+                distractor_strategy: Default::default(),
             },
             ExerciseData::Translation { node_id: 1 },
             ExerciseData::ContextualTranslation {
@@ -743,20 +1329,26 @@ mod tests {
             ExerciseData::ClozeDeletion {
                 node_id: 1,
                 blank_position: 1,
+                seed: 0,
             },
             ExerciseData::FirstLetterHint {
                 node_id: 1,
                 word_position: 1,
+                seed: 0,
             },
             ExerciseData::MissingWordMcq {
                 node_id: 1,
                 blank_position: 1,
                 distractor_node_ids: vec![],
+                seed: 0,
+                distractor_strategy: Default::default(),
             },
             ExerciseData::NextWordMcq {
                 node_id: 1,
                 context_position: 1,
                 distractor_node_ids: vec![],
+                seed: 0,
+                distractor_strategy: Default::default(),
             },
             ExerciseData::FullVerseInput { node_id: 1 },
             ExerciseData::AyahChain {
@@ -770,6 +1362,7 @@ mod tests {
                 mistake_position: 1,
                 correct_word_node_id: 2,
                 incorrect_word_node_id: 3,
+                seed: 0,
             },
             ExerciseData::AyahSequence {
                 node_id: 1,
@@ -791,6 +1384,7 @@ mod tests {
             ExerciseData::ReverseCloze {
                 node_id: 1,
                 blank_position: 1,
+                seed: 0,
             },
             ExerciseData::TranslatePhrase {
                 node_id: 1,
@@ -800,6 +1394,7 @@ mod tests {
                 node_id: 1,
                 correct_pos: "noun".to_string(),
                 options: vec![],
+                seed: 0,
             },
             ExerciseData::CrossVerseConnection {
                 node_id: 1,
@@ -809,6 +1404,156 @@ mod tests {
             ExerciseData::EchoRecall {
                 ayah_node_ids: vec![1, 2, 3],
             },
+            ExerciseData::Mutashabihat {
+                node_id: 1,
+                verse_key: "2:58".to_string(),
+                prompt_word_count: 2,
+                option_verse_keys: vec![],
+                differing_positions: vec![],
+                seed: 0,
+            },
+            ExerciseData::WordOrder {
+                node_id: 1,
+                correct_sequence: vec![],
+                shuffled_word_node_ids: vec![],
+                tile_texts: vec![],
+                seed: 0,
+            },
+            ExerciseData::IdentifySurah {
+                node_id: 1,
+                verse_key: "2:255".to_string(),
+                correct_chapter: 2,
+                chapter_options: vec![1, 2, 3, 4],
+                seed: 0,
+            },
+            ExerciseData::IdentifyVerseNumber {
+                node_id: 1,
+                verse_key: "2:255".to_string(),
+                correct_verse_number: 255,
+                verse_number_options: vec![254, 255, 256, 257],
+                seed: 0,
+            },
+            ExerciseData::IdentifyPageHalf {
+                node_id: 1,
+                verse_key: "2:255".to_string(),
+                page: 42,
+                half: 2,
+            },
+            ExerciseData::TransliterationToArabic {
+                node_id: 1,
+                distractor_node_ids: vec![2, 3, 4],
+                distractor_strategy: Default::default(),
+                seed: 0,
+            },
+            ExerciseData::ArabicToTransliteration {
+                node_id: 1,
+                verse_key: "1:1".to_string(),
+            },
+            ExerciseData::RootFamily {
+                node_id: 1,
+                root_id: "r-ḥ-m".to_string(),
+                verse_keys: vec!["1:1".to_string()],
+                family_word_node_ids: vec![3, 4],
+            },
+            ExerciseData::LemmaMeaning {
+                node_id: 1,
+                root_id: "r-ḥ-m".to_string(),
+                lemma_ids: vec!["raḥmān".to_string(), "raḥīm".to_string()],
+                meaning_lemma_ids: vec!["raḥīm".to_string(), "raḥmān".to_string()],
+                seed: 0,
+            },
+            ExerciseData::GrammarFeature {
+                node_id: 1,
+                feature: GrammarFeature::Number,
+                correct_value: "dual".to_string(),
+                options: vec!["singular".to_string(), "dual".to_string()],
+                seed: 0,
+            },
+            ExerciseData::RecitePage {
+                node_id: 1,
+                page: 1,
+                verse_keys: vec!["1:1".to_string()],
+                first_word_node_id: 2,
+                last_word_node_id: 3,
+            },
+            ExerciseData::PageFirstWord {
+                node_id: 1,
+                page: 2,
+                first_word_node_id: 2,
+                distractor_node_ids: vec![3, 4],
+                seed: 0,
+            },
+            ExerciseData::IdentifyVerseLine {
+                node_id: 1,
+                verse_key: "2:255".to_string(),
+                page: 42,
+                correct_line: 7,
+                line_options: vec![6, 7, 8],
+                seed: 0,
+            },
+            ExerciseData::ListenIdentifyVerse {
+                node_id: 1,
+                reciter_id: 1,
+                verse_key: "1:1".to_string(),
+                first_position: 1,
+                last_position: 4,
+                start_ms: 0,
+                end_ms: 4000,
+                option_verse_keys: vec!["1:1".to_string(), "27:30".to_string()],
+                seed: 0,
+            },
+            ExerciseData::ListenContinue {
+                node_id: 1,
+                reciter_id: 1,
+                verse_key: "1:1".to_string(),
+                first_position: 1,
+                last_position: 2,
+                start_ms: 0,
+                end_ms: 1500,
+                distractor_node_ids: vec![2, 3],
+                distractor_strategy: Default::default(),
+                seed: 0,
+            },
+            ExerciseData::ListenIdentifyWord {
+                node_id: 1,
+                reciter_id: 1,
+                verse_key: "1:1".to_string(),
+                position: 2,
+                start_ms: Some(900),
+                end_ms: Some(1500),
+                distractor_node_ids: vec![2, 3],
+                distractor_strategy: Default::default(),
+                seed: 0,
+            },
+            ExerciseData::IdentifyTajweedRule {
+                node_id: 1,
+                word_node_id: 2,
+                start_char: 0,
+                end_char: 1,
+                correct_rule: TajweedRule::HamzatWasl,
+                rule_options: vec![TajweedRule::HamzatWasl, TajweedRule::Silent],
+                seed: 0,
+            },
+            ExerciseData::FindTajweedRule {
+                node_id: 1,
+                rule: TajweedRule::MaddTabii,
+                rule_word_node_ids: vec![2, 3],
+                seed: 0,
+            },
+            ExerciseData::TafsirKeyLesson {
+                node_id: 1,
+                tafsir_id: 1,
+                passage_key: "2:153".to_string(),
+                option_passage_keys: vec!["2:153".to_string(), "2:255".to_string()],
+                seed: 0,
+            },
+            ExerciseData::RevelationOccasion {
+                node_id: 1,
+                tafsir_id: 1,
+                passage_key: "2:142".to_string(),
+                option_verse_keys: vec!["2:144".to_string(), "2:150".to_string()],
+                seed: 0,
+            },
         ];
 
         let mut type_names: Vec<&str> = exercises.iter().map(|e| e.type_name()).collect();
@@ -821,7 +1566,7 @@ mod tests {
             original_len,
             "All exercise types should have unique type names"
         );
-        assert_eq!(original_len, 21, "Expected 21 exercise types");
+        assert_eq!(original_len, 41, "Expected 41 exercise types");
     }
 
     #[test]
@@ -850,20 +1595,28 @@ mod tests {
             ExerciseData::McqArToEn {
                 node_id: 1,
                 distractor_node_ids: vec![],
+                seed: 0,
+                distractor_strategy: Default::default(),
             },
             ExerciseData::McqEnToAr {
                 node_id: 1,
                 distractor_node_ids: vec![],
+                seed: 0,
+                distractor_strategy: Default::default(),
             },
             ExerciseData::MissingWordMcq {
                 node_id: 1,
                 blank_position: 1,
                 distractor_node_ids: vec![],
+                seed: 0,
+                distractor_strategy: Default::default(),
             },
             ExerciseData::NextWordMcq {
                 node_id: 1,
                 context_position: 1,
                 distractor_node_ids: vec![],
+                seed: 0,
+                distractor_strategy: Default::default(),
             },
             ExerciseData::SequenceRecall {
                 node_id: 1,
@@ -874,6 +1627,7 @@ mod tests {
                 node_id: 1,
                 correct_pos: "noun".to_string(),
                 options: vec![],
+                seed: 0,
             },
         ];
 
@@ -1,12 +1,14 @@
 // exercises/find_mistake.rs
 // Exercise 11: Find the Mistake - Verse with one subtle word substitution
 
+use super::script;
 use super::types::Exercise;
 use crate::domain::node_id::PREFIX_VERSE;
-use crate::{ContentRepository, Word};
+use crate::{ContentRepository, Script};
 use anyhow::Result;
+use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
-use rand::Rng;
+use rand::{Rng, SeedableRng};
 
 // ============================================================================
 // Exercise 11: Find the Mistake
@@ -30,13 +32,20 @@ pub struct FindMistakeExercise {
 }
 
 impl FindMistakeExercise {
-    /// Create a new Find the Mistake exercise
+    /// Create a new Find the Mistake exercise in `script`
     ///
     /// Queries the database for:
     /// - The target verse and its words
     /// - Words from other verses in the same chapter (for substitution)
     /// - Randomly selects a word to replace (avoiding first/last words)
-    pub async fn new(verse_node_id: i64, content_repo: &dyn ContentRepository) -> Result<Self> {
+    ///
+    /// Random choices are drawn from an RNG seeded with `seed`.
+    pub async fn new(
+        verse_node_id: i64,
+        seed: u64,
+        script: Script,
+        content_repo: &dyn ContentRepository,
+    ) -> Result<Self> {
         // Get the node to access its ukey
         let node = content_repo
             .get_node(verse_node_id)
@@ -58,8 +67,7 @@ impl FindMistakeExercise {
         let chapter_num: i32 = parts[0].parse()?;
 
         // Get the correct verse text
-        let correct_verse_text = content_repo
-            .get_quran_text(verse_node_id)
+        let correct_verse_text = script::node_text(verse_node_id, script, content_repo)
             .await?
             .ok_or_else(|| anyhow::anyhow!("Verse text not found: {}", verse_node_id))?;
 
@@ -73,7 +81,7 @@ impl FindMistakeExercise {
         }
 
         // Select a random position to substitute (avoid first and last words)
-        let mut rng = rand::thread_rng();
+        let mut rng = StdRng::seed_from_u64(seed);
         let mistake_position: i32 = if words.len() > 3 {
             // For longer verses, choose from middle words (not first or last)
             (rng.gen_range(1..words.len() - 1) + 1) as i32
@@ -83,21 +91,28 @@ impl FindMistakeExercise {
         };
 
         // Get the word at that position
-        let word_at_position = words
+        let mistake_index = words
             .iter()
-            .find(|w| w.position == mistake_position)
+            .position(|w| w.position == mistake_position)
             .ok_or_else(|| anyhow::anyhow!("Word not found at position {}", mistake_position))?;
 
-        let correct_word = word_at_position.text_uthmani.clone();
+        let texts = script::word_texts(&words, script, content_repo).await?;
+        let correct_word = texts[mistake_index].clone();
 
         // Get a replacement word from another verse in the same chapter
-        let incorrect_word =
-            Self::get_replacement_word(chapter_num, &verse_key, &correct_word, content_repo)
-                .await?;
+        let incorrect_word = Self::get_replacement_word(
+            chapter_num,
+            &verse_key,
+            &correct_word,
+            script,
+            &mut rng,
+            content_repo,
+        )
+        .await?;
 
         // Build modified verse with the mistake
         let modified_verse_text =
-            Self::build_modified_verse(&words, mistake_position, &incorrect_word)?;
+            Self::build_modified_verse(&texts, mistake_index, &incorrect_word)?;
 
         Ok(Self {
             node_id: verse_node_id,
@@ -115,6 +130,8 @@ impl FindMistakeExercise {
         chapter_num: i32,
         current_verse_key: &str,
         original_word: &str,
+        script: Script,
+        rng: &mut StdRng,
         content_repo: &dyn ContentRepository,
     ) -> Result<String> {
         // Get all verses in the chapter
@@ -125,10 +142,10 @@ impl FindMistakeExercise {
         for verse in &verses {
             if verse.key != current_verse_key {
                 let words = content_repo.get_words_for_verse(&verse.key).await?;
-                for word in words {
+                for text in script::word_texts(&words, script, content_repo).await? {
                     // Only include words that are different from the original
-                    if word.text_uthmani != original_word {
-                        candidate_words.push(word.text_uthmani);
+                    if text != original_word {
+                        candidate_words.push(text);
                     }
                 }
             }
@@ -142,26 +159,25 @@ impl FindMistakeExercise {
         }
 
         // Select a random word
-        let mut rng = rand::thread_rng();
         Ok(candidate_words
-            .choose(&mut rng)
+            .choose(rng)
             .ok_or_else(|| anyhow::anyhow!("Failed to select random word"))?
             .clone())
     }
 
-    /// Build the modified verse with the incorrect word at the specified position
+    /// Build the modified verse with the incorrect word at the specified index
     fn build_modified_verse(
-        words: &[Word],
-        mistake_position: i32,
+        texts: &[String],
+        mistake_index: usize,
         incorrect_word: &str,
     ) -> Result<String> {
         let mut modified_words = Vec::new();
 
-        for word in words {
-            if word.position == mistake_position {
+        for (i, text) in texts.iter().enumerate() {
+            if i == mistake_index {
                 modified_words.push(incorrect_word.to_string());
             } else {
-                modified_words.push(word.text_uthmani.clone());
+                modified_words.push(text.clone());
             }
         }
 
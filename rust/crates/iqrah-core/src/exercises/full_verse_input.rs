@@ -3,6 +3,7 @@
 
 use super::memorization::MemorizationExercise;
 use super::types::Exercise;
+use super::word_diff::{diff_words, WordDiff};
 use crate::domain::node_id;
 use crate::{ContentRepository, KnowledgeNode};
 use anyhow::Result;
@@ -93,6 +94,11 @@ impl FullVerseInputExercise {
     pub fn get_verse_key(&self) -> &str {
         &self.verse_key
     }
+
+    /// Word-aligned diff of an answer against the verse
+    pub fn diff_answer(&self, answer: &str) -> WordDiff {
+        diff_words(&self.correct_verse_text, answer)
+    }
 }
 
 impl Exercise for FullVerseInputExercise {
@@ -6,13 +6,26 @@
 // 2. Queries database for minimal metadata (IDs, positions, keys)
 // 3. Returns ExerciseData with ONLY keys/IDs (no full text)
 // 4. Full text is fetched later during question generation
+//
+// Generators that make random choices take a `seed`, draw everything from an
+// RNG seeded with it, and store it in the returned ExerciseData, so the same
+// (node_id, type, seed) always yields the same exercise.
 
+use super::distractors::{
+    DistractorDifficulty, DistractorEngine, DistractorStrategy, SemanticScoring,
+};
 use super::exercise_data::ExerciseData;
+use super::script;
+use super::validator::DefaultValidator;
 use crate::domain::node_id::{self, PREFIX_CHAPTER, PREFIX_VERSE, PREFIX_WORD};
-use crate::{ContentRepository, KnowledgeNode, Node};
+use crate::{
+    ContentRepository, GrammarFeature, KnowledgeNode, MorphologyFeatures, Script, TafsirPassage,
+    TajweedAnnotation, TajweedRule, Verse, VerseRecitation, Word,
+};
 use anyhow::Result;
+use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
-use rand::Rng;
+use rand::{Rng, SeedableRng};
 
 async fn resolve_word_location(
     base_ukey: &str,
@@ -69,63 +82,50 @@ pub async fn generate_memorization(
 // MCQ Exercises
 // ============================================================================
 
+/// Number of distractors offered by word MCQs
+const MCQ_DISTRACTORS: usize = 3;
+
+/// Registered word-instance nodes for the words of a verse, skipping `exclude_position`
+async fn word_candidates(
+    chapter: i32,
+    verse: i32,
+    words: Vec<Word>,
+    exclude_position: Option<i32>,
+    content_repo: &dyn ContentRepository,
+) -> Result<Vec<(i64, Word)>> {
+    let mut candidates = Vec::new();
+    for word in words
+        .into_iter()
+        .filter(|w| Some(w.position) != exclude_position)
+    {
+        let ukey = node_id::word_instance(chapter as u8, verse as u16, word.position as u8);
+        if let Some(node) = content_repo.get_node_by_ukey(&ukey).await? {
+            candidates.push((node.id, word));
+        }
+    }
+    Ok(candidates)
+}
+
 /// Generate MCQ Arabic to English exercise
 ///
-/// Stores word node_id and distractor node_ids
+/// Stores word node_id and distractor node_ids. Distractors are drawn from the
+/// same and next verse plus the word's root family (see `DistractorEngine`).
 pub async fn generate_mcq_ar_to_en(
     node_id: i64,
     ukey: &str,
+    seed: u64,
+    difficulty: DistractorDifficulty,
+    semantic: SemanticScoring,
     content_repo: &dyn ContentRepository,
 ) -> Result<ExerciseData> {
-    // Parse ukey to get base content
-    let base_ukey = if let Some(kn) = KnowledgeNode::parse(ukey) {
-        kn.base_node_id
-    } else {
-        ukey.to_string()
-    };
-
-    let (chapter, verse, position) = resolve_word_location(&base_ukey, content_repo).await?;
-
-    // Get other words from the same verse or nearby verses as distractors
-    let verse_key = format!("{}:{}", chapter, verse);
-    let words = content_repo.get_words_for_verse(&verse_key).await?;
-
-    // Collect distractor node IDs (exclude the target word)
-    let mut distractor_nodes: Vec<Node> = Vec::new();
-
-    for word in words.iter().filter(|w| w.position != position) {
-        let distractor_ukey =
-            node_id::word_instance(chapter as u8, verse as u16, word.position as u8);
-        if let Some(node) = content_repo.get_node_by_ukey(&distractor_ukey).await? {
-            distractor_nodes.push(node);
-        }
-    }
-
-    // If not enough distractors, get from adjacent verses
-    if distractor_nodes.len() < 3 {
-        if let Ok(next_words) = content_repo
-            .get_words_for_verse(&format!("{}:{}", chapter, verse + 1))
-            .await
-        {
-            for word in next_words.iter().take(3 - distractor_nodes.len()) {
-                let distractor_ukey =
-                    node_id::word_instance(chapter as u8, (verse + 1) as u16, word.position as u8);
-                if let Some(node) = content_repo.get_node_by_ukey(&distractor_ukey).await? {
-                    distractor_nodes.push(node);
-                }
-            }
-        }
-    }
-
-    // Randomly select 3 distractors
-    let mut rng = rand::thread_rng();
-    distractor_nodes.shuffle(&mut rng);
-    let distractor_node_ids: Vec<i64> =
-        distractor_nodes.into_iter().map(|n| n.id).take(3).collect();
+    let (distractor_node_ids, distractor_strategy) =
+        word_mcq_distractors(ukey, true, seed, difficulty, semantic, content_repo).await?;
 
     Ok(ExerciseData::McqArToEn {
         node_id,
         distractor_node_ids,
+        distractor_strategy,
+        seed,
     })
 }
 
@@ -133,10 +133,32 @@ pub async fn generate_mcq_ar_to_en(
 pub async fn generate_mcq_en_to_ar(
     node_id: i64,
     ukey: &str,
+    seed: u64,
+    difficulty: DistractorDifficulty,
+    semantic: SemanticScoring,
     content_repo: &dyn ContentRepository,
 ) -> Result<ExerciseData> {
     // Similar to ar_to_en, but question shows English, answer is Arabic
-    // For now, use same distractor generation logic
+    let (distractor_node_ids, distractor_strategy) =
+        word_mcq_distractors(ukey, false, seed, difficulty, semantic, content_repo).await?;
+
+    Ok(ExerciseData::McqEnToAr {
+        node_id,
+        distractor_node_ids,
+        distractor_strategy,
+        seed,
+    })
+}
+
+async fn word_mcq_distractors(
+    ukey: &str,
+    include_next_verse: bool,
+    seed: u64,
+    difficulty: DistractorDifficulty,
+    semantic: SemanticScoring,
+    content_repo: &dyn ContentRepository,
+) -> Result<(Vec<i64>, DistractorStrategy)> {
+    // Parse ukey to get base content
     let base_ukey = if let Some(kn) = KnowledgeNode::parse(ukey) {
         kn.base_node_id
     } else {
@@ -147,25 +169,36 @@ pub async fn generate_mcq_en_to_ar(
 
     let verse_key = format!("{}:{}", chapter, verse);
     let words = content_repo.get_words_for_verse(&verse_key).await?;
-
-    let mut distractor_nodes: Vec<Node> = Vec::new();
-    for word in words.iter().filter(|w| w.position != position) {
-        let distractor_ukey =
-            node_id::word_instance(chapter as u8, verse as u16, word.position as u8);
-        if let Some(node) = content_repo.get_node_by_ukey(&distractor_ukey).await? {
-            distractor_nodes.push(node);
+    let target = words
+        .iter()
+        .find(|w| w.position == position)
+        .cloned()
+        .ok_or_else(|| {
+            anyhow::anyhow!("Word not found at position {} in {}", position, verse_key)
+        })?;
+
+    // Other words from the same verse, then the next verse
+    let mut candidates =
+        word_candidates(chapter, verse, words, Some(position), content_repo).await?;
+    if include_next_verse {
+        if let Ok(next_words) = content_repo
+            .get_words_for_verse(&format!("{}:{}", chapter, verse + 1))
+            .await
+        {
+            candidates
+                .extend(word_candidates(chapter, verse + 1, next_words, None, content_repo).await?);
         }
     }
 
-    let mut rng = rand::thread_rng();
-    distractor_nodes.shuffle(&mut rng);
-    let distractor_node_ids: Vec<i64> =
-        distractor_nodes.into_iter().map(|n| n.id).take(3).collect();
-
-    Ok(ExerciseData::McqEnToAr {
-        node_id,
-        distractor_node_ids,
-    })
+    DistractorEngine::new(content_repo, semantic)?
+        .word_distractors(
+            &target,
+            candidates,
+            MCQ_DISTRACTORS,
+            difficulty,
+            &mut StdRng::seed_from_u64(seed),
+        )
+        .await
 }
 
 // ============================================================================
@@ -207,6 +240,7 @@ pub async fn generate_contextual_translation(
 pub async fn generate_cloze_deletion(
     node_id: i64,
     ukey: &str,
+    seed: u64,
     content_repo: &dyn ContentRepository,
 ) -> Result<ExerciseData> {
     let base_ukey = if let Some(kn) = KnowledgeNode::parse(ukey) {
@@ -233,7 +267,7 @@ pub async fn generate_cloze_deletion(
     }
 
     // Randomly select a word position to blank (avoid first and last)
-    let mut rng = rand::thread_rng();
+    let mut rng = StdRng::seed_from_u64(seed);
     let blank_position = if words.len() > 3 {
         rng.gen_range(2..words.len()) as i32
     } else {
@@ -243,6 +277,7 @@ pub async fn generate_cloze_deletion(
     Ok(ExerciseData::ClozeDeletion {
         node_id,
         blank_position,
+        seed,
     })
 }
 
@@ -250,6 +285,7 @@ pub async fn generate_cloze_deletion(
 pub async fn generate_first_letter_hint(
     node_id: i64,
     ukey: &str,
+    seed: u64,
     content_repo: &dyn ContentRepository,
 ) -> Result<ExerciseData> {
     let base_ukey = if let Some(kn) = KnowledgeNode::parse(ukey) {
@@ -273,12 +309,13 @@ pub async fn generate_first_letter_hint(
     }
 
     // Randomly select a word position
-    let mut rng = rand::thread_rng();
+    let mut rng = StdRng::seed_from_u64(seed);
     let word_position = rng.gen_range(1..=words.len()) as i32;
 
     Ok(ExerciseData::FirstLetterHint {
         node_id,
         word_position,
+        seed,
     })
 }
 
@@ -286,6 +323,9 @@ pub async fn generate_first_letter_hint(
 pub async fn generate_missing_word_mcq(
     node_id: i64,
     ukey: &str,
+    seed: u64,
+    difficulty: DistractorDifficulty,
+    semantic: SemanticScoring,
     content_repo: &dyn ContentRepository,
 ) -> Result<ExerciseData> {
     let base_ukey = if let Some(kn) = KnowledgeNode::parse(ukey) {
@@ -310,33 +350,31 @@ pub async fn generate_missing_word_mcq(
         ));
     }
 
-    let blank_position = {
-        let mut rng = rand::thread_rng();
-        rng.gen_range(2..words.len()) as i32
-    };
+    let mut rng = StdRng::seed_from_u64(seed);
+    let blank_position = rng.gen_range(2..words.len()) as i32;
 
     // Get distractors from the same or nearby verses
     let parts: Vec<&str> = verse_key.split(':').collect();
     let chapter: i32 = parts[0].parse()?;
     let verse: i32 = parts[1].parse()?;
 
-    let mut distractor_nodes: Vec<Node> = Vec::new();
-    for w in words.iter().filter(|w| w.position != blank_position) {
-        let ukey = node_id::word_instance(chapter as u8, verse as u16, w.position as u8);
-        if let Some(node) = content_repo.get_node_by_ukey(&ukey).await? {
-            distractor_nodes.push(node);
-        }
-    }
-
-    let mut distractor_node_ids: Vec<i64> = distractor_nodes.into_iter().map(|n| n.id).collect();
-    let mut rng = rand::thread_rng();
-    distractor_node_ids.shuffle(&mut rng);
-    distractor_node_ids.truncate(3);
+    let target = words
+        .iter()
+        .find(|w| w.position == blank_position)
+        .cloned()
+        .ok_or_else(|| anyhow::anyhow!("Word not found at position {}", blank_position))?;
+    let candidates =
+        word_candidates(chapter, verse, words, Some(blank_position), content_repo).await?;
+    let (distractor_node_ids, distractor_strategy) = DistractorEngine::new(content_repo, semantic)?
+        .word_distractors(&target, candidates, MCQ_DISTRACTORS, difficulty, &mut rng)
+        .await?;
 
     Ok(ExerciseData::MissingWordMcq {
         node_id,
         blank_position,
         distractor_node_ids,
+        distractor_strategy,
+        seed,
     })
 }
 
@@ -344,6 +382,9 @@ pub async fn generate_missing_word_mcq(
 pub async fn generate_next_word_mcq(
     node_id: i64,
     ukey: &str,
+    seed: u64,
+    difficulty: DistractorDifficulty,
+    semantic: SemanticScoring,
     content_repo: &dyn ContentRepository,
 ) -> Result<ExerciseData> {
     let base_ukey = if let Some(kn) = KnowledgeNode::parse(ukey) {
@@ -369,33 +410,32 @@ pub async fn generate_next_word_mcq(
     }
 
     // Context position is before the target word
-    let context_position = {
-        let mut rng = rand::thread_rng();
-        rng.gen_range(1..words.len()) as i32
-    };
+    let mut rng = StdRng::seed_from_u64(seed);
+    let context_position = rng.gen_range(1..words.len()) as i32;
 
     // Get distractors
     let parts: Vec<&str> = verse_key.split(':').collect();
     let chapter: i32 = parts[0].parse()?;
     let verse: i32 = parts[1].parse()?;
 
-    let mut distractor_nodes: Vec<Node> = Vec::new();
-    for w in words.iter().filter(|w| w.position != context_position + 1) {
-        let ukey = node_id::word_instance(chapter as u8, verse as u16, w.position as u8);
-        if let Some(node) = content_repo.get_node_by_ukey(&ukey).await? {
-            distractor_nodes.push(node);
-        }
-    }
-
-    let mut distractor_node_ids: Vec<i64> = distractor_nodes.into_iter().map(|n| n.id).collect();
-    let mut rng = rand::thread_rng();
-    distractor_node_ids.shuffle(&mut rng);
-    distractor_node_ids.truncate(3);
+    let target_position = context_position + 1;
+    let target = words
+        .iter()
+        .find(|w| w.position == target_position)
+        .cloned()
+        .ok_or_else(|| anyhow::anyhow!("Word not found at position {}", target_position))?;
+    let candidates =
+        word_candidates(chapter, verse, words, Some(target_position), content_repo).await?;
+    let (distractor_node_ids, distractor_strategy) = DistractorEngine::new(content_repo, semantic)?
+        .word_distractors(&target, candidates, MCQ_DISTRACTORS, difficulty, &mut rng)
+        .await?;
 
     Ok(ExerciseData::NextWordMcq {
         node_id,
         context_position,
         distractor_node_ids,
+        distractor_strategy,
+        seed,
     })
 }
 
@@ -603,6 +643,7 @@ pub async fn generate_ayah_chain_range(
 pub async fn generate_find_mistake(
     node_id: i64,
     ukey: &str,
+    seed: u64,
     content_repo: &dyn ContentRepository,
 ) -> Result<ExerciseData> {
     let base_ukey = if let Some(kn) = KnowledgeNode::parse(ukey) {
@@ -633,13 +674,11 @@ pub async fn generate_find_mistake(
     }
 
     // Select random position (avoid first and last)
-    let mistake_position: i32 = {
-        let mut rng = rand::thread_rng();
-        if words.len() > 3 {
-            (rng.gen_range(1..words.len() - 1) + 1) as i32
-        } else {
-            2
-        }
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mistake_position: i32 = if words.len() > 3 {
+        (rng.gen_range(1..words.len() - 1) + 1) as i32
+    } else {
+        2
     };
 
     // Get correct word node ID
@@ -689,18 +728,16 @@ pub async fn generate_find_mistake(
         ));
     }
 
-    let incorrect_word_node = {
-        let mut rng = rand::thread_rng();
-        candidate_nodes
-            .choose(&mut rng)
-            .ok_or_else(|| anyhow::anyhow!("Failed to select random word"))?
-    };
+    let incorrect_word_node = candidate_nodes
+        .choose(&mut rng)
+        .ok_or_else(|| anyhow::anyhow!("Failed to select random word"))?;
 
     Ok(ExerciseData::FindMistake {
         node_id,
         mistake_position,
         correct_word_node_id,
         incorrect_word_node_id: incorrect_word_node.id,
+        seed,
     })
 }
 
@@ -861,6 +898,7 @@ pub async fn generate_identify_root(
 pub async fn generate_reverse_cloze(
     node_id: i64,
     ukey: &str,
+    seed: u64,
     content_repo: &dyn ContentRepository,
 ) -> Result<ExerciseData> {
     let base_ukey = if let Some(kn) = KnowledgeNode::parse(ukey) {
@@ -882,12 +920,13 @@ pub async fn generate_reverse_cloze(
         ));
     }
 
-    let mut rng = rand::thread_rng();
+    let mut rng = StdRng::seed_from_u64(seed);
     let blank_position = rng.gen_range(2..=words.len()) as i32;
 
     Ok(ExerciseData::ReverseCloze {
         node_id,
         blank_position,
+        seed,
     })
 }
 
@@ -909,6 +948,7 @@ pub async fn generate_translate_phrase(
 pub async fn generate_pos_tagging(
     word_node_id: i64,
     ukey: &str,
+    seed: u64,
     content_repo: &dyn ContentRepository,
 ) -> Result<ExerciseData> {
     let base_ukey = if let Some(kn) = KnowledgeNode::parse(ukey) {
@@ -976,13 +1016,63 @@ pub async fn generate_pos_tagging(
         }
     }
 
-    let mut rng = rand::thread_rng();
-    options.shuffle(&mut rng);
+    options.shuffle(&mut StdRng::seed_from_u64(seed));
 
     Ok(ExerciseData::PosTagging {
         node_id: word_node_id,
         correct_pos,
         options,
+        seed,
+    })
+}
+
+/// Options offered for a grammar feature exercise (verb forms have twelve)
+const GRAMMAR_FEATURE_OPTIONS: usize = 4;
+
+/// Generate Grammar Feature exercise
+///
+/// Asks about one feature the word's morphology records, picked with the
+/// seed. Features of all the word's segments are combined so that e.g. the
+/// definite article prefix marks the word as definite.
+pub async fn generate_grammar_feature(
+    word_node_id: i64,
+    ukey: &str,
+    seed: u64,
+    content_repo: &dyn ContentRepository,
+) -> Result<ExerciseData> {
+    let (_, word) = resolve_word(ukey, content_repo).await?;
+    let morphology = content_repo.get_morphology_for_word(word.id).await?;
+    let features = MorphologyFeatures::merge(morphology.into_iter().map(|seg| seg.features));
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let available: Vec<GrammarFeature> = GrammarFeature::ALL
+        .into_iter()
+        .filter(|f| f.value(&features).is_some())
+        .collect();
+    let feature = *available
+        .choose(&mut rng)
+        .ok_or_else(|| anyhow::anyhow!("No grammatical features found for word ID {}", word.id))?;
+    let correct_value = feature.value(&features).unwrap_or_default().to_string();
+
+    let mut others: Vec<&str> = feature
+        .values()
+        .iter()
+        .copied()
+        .filter(|v| *v != correct_value)
+        .collect();
+    others.shuffle(&mut rng);
+    let mut options: Vec<String> = std::iter::once(correct_value.as_str())
+        .chain(others.into_iter().take(GRAMMAR_FEATURE_OPTIONS - 1))
+        .map(str::to_string)
+        .collect();
+    options.shuffle(&mut rng);
+
+    Ok(ExerciseData::GrammarFeature {
+        node_id: word_node_id,
+        feature,
+        correct_value,
+        options,
+        seed,
     })
 }
 
@@ -1077,7 +1167,23 @@ pub async fn generate_cross_verse_connection(
 
     let correct_id = related_verse_ids[0];
     let mut option_ids = vec![correct_id];
+    option_ids.extend(cross_verse_distractors(chapter_num, &[correct_id], content_repo).await?);
+
+    Ok(ExerciseData::CrossVerseConnection {
+        node_id,
+        related_verse_ids: option_ids,
+        connection_theme: connection_theme.unwrap_or_else(|| "Graph connection".to_string()),
+    })
+}
 
+/// Up to three verses offered against the connected verse of a Cross-Verse
+/// Connection exercise: opening verses of other chapters first, then the
+/// last verses of the source chapter. Verses in `exclude` are never offered.
+async fn cross_verse_distractors(
+    chapter_num: i32,
+    exclude: &[i64],
+    content_repo: &dyn ContentRepository,
+) -> Result<Vec<i64>> {
     let mut distractors = Vec::new();
     for chapter in [1, 2, 3, 112, 113, 114] {
         if chapter == chapter_num {
@@ -1087,7 +1193,7 @@ pub async fn generate_cross_verse_connection(
         for verse in verses.iter().take(3) {
             let verse_id =
                 node_id::encode_verse(verse.chapter_number as u8, verse.verse_number as u16);
-            if verse_id != correct_id {
+            if !exclude.contains(&verse_id) {
                 distractors.push(verse_id);
                 if distractors.len() >= 3 {
                     break;
@@ -1104,7 +1210,7 @@ pub async fn generate_cross_verse_connection(
         for verse in verses.iter().rev().take(5) {
             let verse_id =
                 node_id::encode_verse(verse.chapter_number as u8, verse.verse_number as u16);
-            if verse_id != correct_id && !distractors.contains(&verse_id) {
+            if !exclude.contains(&verse_id) && !distractors.contains(&verse_id) {
                 distractors.push(verse_id);
                 if distractors.len() >= 3 {
                     break;
@@ -1113,20 +1219,1574 @@ pub async fn generate_cross_verse_connection(
         }
     }
 
-    for id in distractors.into_iter().take(3) {
-        option_ids.push(id);
+    distractors.truncate(3);
+    Ok(distractors)
+}
+
+/// Maximum number of similar verses offered alongside the correct one
+const MUTASHABIHAT_MAX_SIBLINGS: usize = 3;
+
+/// Generate Mutashabihat (similar-verse discrimination) exercise
+pub async fn generate_mutashabihat(
+    node_id: i64,
+    ukey: &str,
+    seed: u64,
+    content_repo: &dyn ContentRepository,
+) -> Result<ExerciseData> {
+    let base_ukey = if let Some(kn) = KnowledgeNode::parse(ukey) {
+        kn.base_node_id
+    } else {
+        ukey.to_string()
+    };
+
+    let verse_key = base_ukey
+        .strip_prefix(PREFIX_VERSE)
+        .ok_or_else(|| anyhow::anyhow!("Invalid verse node ID: {}", base_ukey))?
+        .to_string();
+
+    let words = content_repo.get_words_for_verse(&verse_key).await?;
+    if words.len() < 2 {
+        return Err(anyhow::anyhow!(
+            "Verse too short for Mutashabihat (need at least 2 words)"
+        ));
     }
 
-    Ok(ExerciseData::CrossVerseConnection {
+    // Over-fetch: identical verses are skipped below
+    let similar = content_repo
+        .get_similar_verses(&verse_key, (MUTASHABIHAT_MAX_SIBLINGS * 2) as u32)
+        .await?;
+
+    let mut siblings = Vec::new();
+    for candidate in similar {
+        if candidate.verse_key == verse_key {
+            continue;
+        }
+        let other_words = content_repo
+            .get_words_for_verse(&candidate.verse_key)
+            .await?;
+        // Identical verses (e.g. the refrain of Ar-Rahman) can only be told
+        // apart by their surroundings, not by their continuation.
+        let Some(position) = first_differing_position(&words, &other_words) else {
+            continue;
+        };
+        // A verse that already differs at its first word shares no opening
+        // to prompt with.
+        if position <= 1 {
+            continue;
+        }
+        siblings.push((candidate.verse_key, position));
+        if siblings.len() >= MUTASHABIHAT_MAX_SIBLINGS {
+            break;
+        }
+    }
+
+    if siblings.is_empty() {
+        return Err(anyhow::anyhow!(
+            "No distinguishable similar verses for {}",
+            verse_key
+        ));
+    }
+
+    // The prompt is the opening shared by every option, keeping at least one
+    // word of the correct verse hidden.
+    let prompt_word_count = siblings
+        .iter()
+        .map(|(_, position)| position - 1)
+        .min()
+        .unwrap_or(0)
+        .min(words.len() as i32 - 1);
+    if prompt_word_count < 1 {
+        return Err(anyhow::anyhow!(
+            "No shared opening to prompt with for {}",
+            verse_key
+        ));
+    }
+
+    let mut options = siblings;
+    options.push((verse_key.clone(), 0));
+    options.shuffle(&mut StdRng::seed_from_u64(seed));
+    let (option_verse_keys, differing_positions) = options.into_iter().unzip();
+
+    Ok(ExerciseData::Mutashabihat {
         node_id,
-        related_verse_ids: option_ids,
-        connection_theme: connection_theme.unwrap_or_else(|| "Graph connection".to_string()),
+        verse_key,
+        prompt_word_count,
+        option_verse_keys,
+        differing_positions,
+        seed,
     })
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Generate Word Order (scramble) exercise with tiles in `script`. Words
+/// without content in that script fall back to their Uthmani text.
+pub async fn generate_word_order(
+    node_id: i64,
+    ukey: &str,
+    script: Script,
+    seed: u64,
+    content_repo: &dyn ContentRepository,
+) -> Result<ExerciseData> {
+    let base_ukey = if let Some(kn) = KnowledgeNode::parse(ukey) {
+        kn.base_node_id
+    } else {
+        ukey.to_string()
+    };
+
+    let verse_key = base_ukey
+        .strip_prefix(PREFIX_VERSE)
+        .ok_or_else(|| anyhow::anyhow!("Invalid verse node ID: {}", base_ukey))?;
+    let (chapter, verse) = node_id::parse_verse(&base_ukey).map_err(|e| anyhow::anyhow!(e))?;
+
+    let words = content_repo.get_words_for_verse(verse_key).await?;
+    if words.len() < 3 {
+        return Err(anyhow::anyhow!("Verse too short for word order exercise"));
+    }
+
+    let texts = script::word_texts(&words, script, content_repo).await?;
+    let mut tiles = Vec::with_capacity(words.len());
+    for (w, text) in words.iter().zip(texts) {
+        let word_ukey = node_id::word_instance(chapter, verse, w.position as u8);
+        let word_id = if let Some(node) = content_repo.get_node_by_ukey(&word_ukey).await? {
+            node.id
+        } else {
+            node_id::encode_word_instance(chapter, verse, w.position as u8)
+        };
+        tiles.push((word_id, text));
+    }
+
+    let correct_sequence: Vec<i64> = tiles.iter().map(|(id, _)| *id).collect();
+    tiles.shuffle(&mut StdRng::seed_from_u64(seed));
+    // Never hand out the tiles already solved
+    if tiles
+        .iter()
+        .map(|(id, _)| *id)
+        .eq(correct_sequence.iter().copied())
+    {
+        tiles.rotate_left(1);
+    }
+    let (shuffled_word_node_ids, tile_texts) = tiles.into_iter().unzip();
+
+    Ok(ExerciseData::WordOrder {
+        node_id,
+        correct_sequence,
+        shuffled_word_node_ids,
+        tile_texts,
+        seed,
+    })
+}
+
+// ============================================================================
+// Location Exercises ("where is this verse?")
+// ============================================================================
+
+/// Number of distractors offered by location MCQs
+const LOCATION_DISTRACTORS: usize = 3;
+
+async fn resolve_verse(ukey: &str, content_repo: &dyn ContentRepository) -> Result<Verse> {
+    let base_ukey = if let Some(kn) = KnowledgeNode::parse(ukey) {
+        kn.base_node_id
+    } else {
+        ukey.to_string()
+    };
+
+    let verse_key = base_ukey
+        .strip_prefix(PREFIX_VERSE)
+        .ok_or_else(|| anyhow::anyhow!("Invalid verse node ID: {}", base_ukey))?;
+
+    content_repo
+        .get_verse(verse_key)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("Verse not found: {}", verse_key))
+}
+
+/// Pick `count` distractors among the values nearest to `correct` and shuffle
+/// them together with `correct`. Sampling from twice as many neighbors keeps
+/// the correct answer from always sitting in the middle of the options.
+fn neighbor_options(
+    correct: i32,
+    candidates: &[i32],
+    count: usize,
+    rng: &mut impl Rng,
+) -> Vec<i32> {
+    let mut others: Vec<i32> = candidates
+        .iter()
+        .copied()
+        .filter(|&c| c != correct)
+        .collect();
+    others.sort_by_key(|&c| ((c - correct).abs(), c < correct));
+    others.truncate(count * 2);
+
+    others.shuffle(rng);
+    let mut options: Vec<i32> = std::iter::once(correct)
+        .chain(others.into_iter().take(count))
+        .collect();
+    options.shuffle(rng);
+    options
+}
+
+/// Generate Identify Surah exercise with neighboring surahs as distractors
+pub async fn generate_identify_surah(
+    node_id: i64,
+    ukey: &str,
+    seed: u64,
+    content_repo: &dyn ContentRepository,
+) -> Result<ExerciseData> {
+    let verse = resolve_verse(ukey, content_repo).await?;
+    let chapters: Vec<i32> = content_repo
+        .get_chapters()
+        .await?
+        .into_iter()
+        .map(|c| c.number)
+        .collect();
+
+    let chapter_options = neighbor_options(
+        verse.chapter_number,
+        &chapters,
+        LOCATION_DISTRACTORS,
+        &mut StdRng::seed_from_u64(seed),
+    );
+    if chapter_options.len() < 2 {
+        return Err(anyhow::anyhow!(
+            "Not enough surahs for Identify Surah distractors"
+        ));
+    }
+
+    Ok(ExerciseData::IdentifySurah {
+        node_id,
+        verse_key: verse.key,
+        correct_chapter: verse.chapter_number,
+        chapter_options,
+        seed,
+    })
+}
+
+/// Generate Identify Verse Number exercise with neighboring verse numbers as distractors
+pub async fn generate_identify_verse_number(
+    node_id: i64,
+    ukey: &str,
+    seed: u64,
+    content_repo: &dyn ContentRepository,
+) -> Result<ExerciseData> {
+    let verse = resolve_verse(ukey, content_repo).await?;
+    let chapter = content_repo
+        .get_chapter(verse.chapter_number)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("Chapter not found: {}", verse.chapter_number))?;
+
+    let verse_numbers: Vec<i32> = (1..=chapter.verse_count).collect();
+    let verse_number_options = neighbor_options(
+        verse.verse_number,
+        &verse_numbers,
+        LOCATION_DISTRACTORS,
+        &mut StdRng::seed_from_u64(seed),
+    );
+    if verse_number_options.len() < 2 {
+        return Err(anyhow::anyhow!(
+            "Chapter {} too short for Identify Verse Number",
+            chapter.number
+        ));
+    }
+
+    Ok(ExerciseData::IdentifyVerseNumber {
+        node_id,
+        verse_key: verse.key,
+        correct_verse_number: verse.verse_number,
+        verse_number_options,
+        seed,
+    })
+}
+
+/// Verses on the same mushaf page as `verse`, in mushaf order (pages can span
+/// two surahs)
+async fn verses_on_page(verse: &Verse, content_repo: &dyn ContentRepository) -> Result<Vec<Verse>> {
+    let mut page_verses = Vec::new();
+    for chapter in [
+        verse.chapter_number - 1,
+        verse.chapter_number,
+        verse.chapter_number + 1,
+    ] {
+        if chapter < 1 {
+            continue;
+        }
+        for v in content_repo.get_verses_for_chapter(chapter).await? {
+            if v.page == verse.page {
+                page_verses.push(v);
+            }
+        }
+    }
+    page_verses.sort_by_key(|v| (v.chapter_number, v.verse_number));
+    Ok(page_verses)
+}
+
+/// Generate Identify Page Half exercise
+///
+/// The half comes from the mushaf line the verse starts on (lines 1-7 are the
+/// top half). Without a loaded layout it is approximated by the verse's index
+/// among the verses that start on the same page (pages can span two surahs).
+pub async fn generate_identify_page_half(
+    node_id: i64,
+    ukey: &str,
+    content_repo: &dyn ContentRepository,
+) -> Result<ExerciseData> {
+    let verse = resolve_verse(ukey, content_repo).await?;
+
+    // With a mushaf layout, the half is where the verse starts on the page
+    let first_word =
+        node_id::encode_word_instance(verse.chapter_number as u8, verse.verse_number as u16, 1);
+    if let Some(line) = content_repo.get_mushaf_line_for_word(first_word).await? {
+        let half = if line.line_number * 2 <= MUSHAF_LINES {
+            1
+        } else {
+            2
+        };
+        return Ok(ExerciseData::IdentifyPageHalf {
+            node_id,
+            verse_key: verse.key,
+            page: line.page,
+            half,
+        });
+    }
+
+    // Otherwise estimate it from the verse's index among the page's verses
+    let page_verses: Vec<(i32, i32)> = verses_on_page(&verse, content_repo)
+        .await?
+        .iter()
+        .map(|v| (v.chapter_number, v.verse_number))
+        .collect();
+
+    if page_verses.len() < 2 {
+        return Err(anyhow::anyhow!(
+            "Page {} has too few verses for Identify Page Half",
+            verse.page
+        ));
+    }
+
+    let index = page_verses
+        .iter()
+        .position(|&(c, v)| c == verse.chapter_number && v == verse.verse_number)
+        .ok_or_else(|| anyhow::anyhow!("Verse {} not found on page {}", verse.key, verse.page))?;
+    let half = if index * 2 < page_verses.len() { 1 } else { 2 };
+
+    Ok(ExerciseData::IdentifyPageHalf {
+        node_id,
+        verse_key: verse.key,
+        page: verse.page,
+        half,
+    })
+}
+
+// ============================================================================
+// Mushaf Page Exercises (15-line layout)
+// ============================================================================
+
+/// Pages in the 15-line Madani mushaf
+const MUSHAF_PAGES: i32 = 604;
+/// Lines on a page of the 15-line Madani mushaf
+const MUSHAF_LINES: i32 = 15;
+
+/// First and last word-instance node IDs of a mushaf page; fails when no
+/// layout is loaded for the page
+async fn page_word_range(page: i32, content_repo: &dyn ContentRepository) -> Result<(i64, i64)> {
+    let lines = content_repo.get_mushaf_page(page).await?;
+    let first = lines.iter().find_map(|l| l.first_word_id);
+    let last = lines.iter().rev().find_map(|l| l.last_word_id);
+    first
+        .zip(last)
+        .ok_or_else(|| anyhow::anyhow!("No mushaf layout for page {}", page))
+}
+
+/// Keys of the verses with words between two word-instance node IDs, in
+/// mushaf order
+async fn verses_between(
+    first_word_node_id: i64,
+    last_word_node_id: i64,
+    content_repo: &dyn ContentRepository,
+) -> Result<Vec<String>> {
+    let (first_chapter, first_verse, _) = node_id::decode_word_instance(first_word_node_id)
+        .ok_or_else(|| anyhow::anyhow!("Invalid word instance ID: {}", first_word_node_id))?;
+    let (last_chapter, last_verse, _) = node_id::decode_word_instance(last_word_node_id)
+        .ok_or_else(|| anyhow::anyhow!("Invalid word instance ID: {}", last_word_node_id))?;
+
+    let first = (first_chapter as i32, first_verse as i32);
+    let last = (last_chapter as i32, last_verse as i32);
+    let mut verse_keys = Vec::new();
+    for chapter in first.0..=last.0 {
+        let mut verses = content_repo.get_verses_for_chapter(chapter).await?;
+        verses.sort_by_key(|v| v.verse_number);
+        verse_keys.extend(
+            verses
+                .into_iter()
+                .filter(|v| (first..=last).contains(&(v.chapter_number, v.verse_number)))
+                .map(|v| v.key),
+        );
+    }
+    Ok(verse_keys)
+}
+
+/// Generate Recite Page exercise for the page the verse starts on
+///
+/// The page runs from its first to its last word, so verses continuing from
+/// the previous page or onto the next are cut at the page edges.
+pub async fn generate_recite_page(
+    node_id: i64,
+    ukey: &str,
+    content_repo: &dyn ContentRepository,
+) -> Result<ExerciseData> {
+    let verse = resolve_verse(ukey, content_repo).await?;
+    let (first_word_node_id, last_word_node_id) = page_word_range(verse.page, content_repo).await?;
+    let verse_keys = verses_between(first_word_node_id, last_word_node_id, content_repo).await?;
+
+    Ok(ExerciseData::RecitePage {
+        node_id,
+        page: verse.page,
+        verse_keys,
+        first_word_node_id,
+        last_word_node_id,
+    })
+}
+
+/// Generate Page First Word exercise with the first words of nearby pages as
+/// distractors
+pub async fn generate_page_first_word(
+    node_id: i64,
+    ukey: &str,
+    seed: u64,
+    content_repo: &dyn ContentRepository,
+) -> Result<ExerciseData> {
+    let verse = resolve_verse(ukey, content_repo).await?;
+    let (first_word_node_id, _) = page_word_range(verse.page, content_repo).await?;
+
+    let pages: Vec<i32> = (1..=MUSHAF_PAGES).collect();
+    let mut distractor_node_ids = Vec::new();
+    for page in neighbor_options(
+        verse.page,
+        &pages,
+        LOCATION_DISTRACTORS,
+        &mut StdRng::seed_from_u64(seed),
+    ) {
+        if page == verse.page {
+            continue;
+        }
+        if let Ok((first_word, _)) = page_word_range(page, content_repo).await {
+            distractor_node_ids.push(first_word);
+        }
+    }
+    if distractor_node_ids.is_empty() {
+        return Err(anyhow::anyhow!(
+            "No nearby pages for Page First Word distractors (page {})",
+            verse.page
+        ));
+    }
+
+    Ok(ExerciseData::PageFirstWord {
+        node_id,
+        page: verse.page,
+        first_word_node_id,
+        distractor_node_ids,
+        seed,
+    })
+}
+
+/// Generate Identify Verse Line exercise with neighboring lines as distractors
+pub async fn generate_identify_verse_line(
+    node_id: i64,
+    ukey: &str,
+    seed: u64,
+    content_repo: &dyn ContentRepository,
+) -> Result<ExerciseData> {
+    let verse = resolve_verse(ukey, content_repo).await?;
+    let first_word =
+        node_id::encode_word_instance(verse.chapter_number as u8, verse.verse_number as u16, 1);
+    let line = content_repo
+        .get_mushaf_line_for_word(first_word)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("No mushaf layout for verse {}", verse.key))?;
+
+    let lines: Vec<i32> = (1..=MUSHAF_LINES).collect();
+    let line_options = neighbor_options(
+        line.line_number,
+        &lines,
+        LOCATION_DISTRACTORS,
+        &mut StdRng::seed_from_u64(seed),
+    );
+
+    Ok(ExerciseData::IdentifyVerseLine {
+        node_id,
+        verse_key: verse.key,
+        page: line.page,
+        correct_line: line.line_number,
+        line_options,
+        seed,
+    })
+}
+
+// ============================================================================
+// Reading Bridge Exercises (transliteration)
+// ============================================================================
+
+/// The word behind a word ukey, with its verse key
+async fn resolve_word(ukey: &str, content_repo: &dyn ContentRepository) -> Result<(String, Word)> {
+    let base_ukey = if let Some(kn) = KnowledgeNode::parse(ukey) {
+        kn.base_node_id
+    } else {
+        ukey.to_string()
+    };
+    let (chapter, verse, position) = resolve_word_location(&base_ukey, content_repo).await?;
+    let verse_key = format!("{}:{}", chapter, verse);
+
+    let word = content_repo
+        .get_words_for_verse(&verse_key)
+        .await?
+        .into_iter()
+        .find(|w| w.position == position)
+        .ok_or_else(|| {
+            anyhow::anyhow!("Word not found at position {} in {}", position, verse_key)
+        })?;
+    Ok((verse_key, word))
+}
+
+/// The word behind a word ukey, with its verse key; fails when the word has
+/// no transliteration to practise with
+async fn transliterated_word(
+    ukey: &str,
+    content_repo: &dyn ContentRepository,
+) -> Result<(String, Word)> {
+    let (verse_key, word) = resolve_word(ukey, content_repo).await?;
+    if word
+        .transliteration
+        .as_deref()
+        .is_none_or(|t| t.trim().is_empty())
+    {
+        return Err(anyhow::anyhow!("No transliteration for word {}", ukey));
+    }
+    Ok((verse_key, word))
+}
+
+/// Generate Transliteration to Arabic exercise
+///
+/// Arabic options are drawn like the word MCQs' (see `generate_mcq_ar_to_en`).
+pub async fn generate_transliteration_to_arabic(
+    node_id: i64,
+    ukey: &str,
+    seed: u64,
+    difficulty: DistractorDifficulty,
+    semantic: SemanticScoring,
+    content_repo: &dyn ContentRepository,
+) -> Result<ExerciseData> {
+    transliterated_word(ukey, content_repo).await?;
+    let (distractor_node_ids, distractor_strategy) =
+        word_mcq_distractors(ukey, true, seed, difficulty, semantic, content_repo).await?;
+
+    Ok(ExerciseData::TransliterationToArabic {
+        node_id,
+        distractor_node_ids,
+        distractor_strategy,
+        seed,
+    })
+}
+
+/// Generate Arabic to Transliteration exercise
+pub async fn generate_arabic_to_transliteration(
+    node_id: i64,
+    ukey: &str,
+    content_repo: &dyn ContentRepository,
+) -> Result<ExerciseData> {
+    let (verse_key, _) = transliterated_word(ukey, content_repo).await?;
+    Ok(ExerciseData::ArabicToTransliteration { node_id, verse_key })
+}
+
+// ============================================================================
+// Vocabulary Family Exercises (roots and lemmas)
+// ============================================================================
+
+/// Number of lemmas matched to meanings in a Lemma Meaning exercise
+const LEMMA_MEANING_OPTIONS: usize = 4;
+
+/// Generate Root Family exercise
+///
+/// Picks the most frequent Quranic root with at least two words in the verse,
+/// or failing that on the verse's page (keeping a root the verse itself uses).
+pub async fn generate_root_family(
+    node_id: i64,
+    ukey: &str,
+    content_repo: &dyn ContentRepository,
+) -> Result<ExerciseData> {
+    let verse = resolve_verse(ukey, content_repo).await?;
+    let verse_words: Vec<i64> = content_repo
+        .get_words_for_verse(&verse.key)
+        .await?
+        .iter()
+        .map(|w| w.id)
+        .collect();
+
+    let mut verse_keys = vec![verse.key.clone()];
+    let mut family = content_repo
+        .get_root_occurrences_in_verses(&verse_keys)
+        .await?
+        .into_iter()
+        .find(|root| root.word_ids.len() >= 2);
+    if family.is_none() {
+        verse_keys = verses_on_page(&verse, content_repo)
+            .await?
+            .into_iter()
+            .map(|v| v.key)
+            .collect();
+        family = content_repo
+            .get_root_occurrences_in_verses(&verse_keys)
+            .await?
+            .into_iter()
+            .find(|root| {
+                root.word_ids.len() >= 2 && root.word_ids.iter().any(|id| verse_words.contains(id))
+            });
+    }
+    let family =
+        family.ok_or_else(|| anyhow::anyhow!("No root family to practise around {}", verse.key))?;
+
+    let words = content_repo.get_words_batch(&family.word_ids).await?;
+    let mut family_word_node_ids = Vec::with_capacity(family.word_ids.len());
+    for word_id in &family.word_ids {
+        let word = words
+            .get(word_id)
+            .ok_or_else(|| anyhow::anyhow!("Word not found: {}", word_id))?;
+        let (chapter, verse_number) = node_id::from_ukey(&node_id::verse_from_key(&word.verse_key))
+            .and_then(node_id::decode_verse)
+            .ok_or_else(|| anyhow::anyhow!("Invalid verse key: {}", word.verse_key))?;
+        family_word_node_ids.push(node_id::encode_word_instance(
+            chapter,
+            verse_number,
+            word.position as u8,
+        ));
+    }
+
+    Ok(ExerciseData::RootFamily {
+        node_id,
+        root_id: family.root_id,
+        verse_keys,
+        family_word_node_ids,
+    })
+}
+
+/// Generate Lemma Meaning exercise
+///
+/// Uses the most frequent lemmas of the word's root that have a meaning.
+pub async fn generate_lemma_meaning(
+    node_id: i64,
+    ukey: &str,
+    seed: u64,
+    content_repo: &dyn ContentRepository,
+) -> Result<ExerciseData> {
+    let (_, word) = resolve_word(ukey, content_repo).await?;
+    let root_id = content_repo
+        .get_morphology_for_word(word.id)
+        .await?
+        .into_iter()
+        .find_map(|seg| seg.root_id)
+        .ok_or_else(|| anyhow::anyhow!("No root_id found for word ID {}", word.id))?;
+
+    let lemma_ids: Vec<String> = content_repo
+        .get_lemmas_for_root(&root_id)
+        .await?
+        .into_iter()
+        .filter(|l| {
+            l.lemma
+                .description
+                .as_deref()
+                .is_some_and(|d| !d.trim().is_empty())
+        })
+        .take(LEMMA_MEANING_OPTIONS)
+        .map(|l| l.lemma.lemma_id)
+        .collect();
+    if lemma_ids.len() < 2 {
+        return Err(anyhow::anyhow!(
+            "Root {} has too few lemmas with meanings",
+            root_id
+        ));
+    }
+
+    let mut meaning_lemma_ids = lemma_ids.clone();
+    meaning_lemma_ids.shuffle(&mut StdRng::seed_from_u64(seed));
+    // Never hand out the meanings already matched
+    if meaning_lemma_ids == lemma_ids {
+        meaning_lemma_ids.rotate_left(1);
+    }
+
+    Ok(ExerciseData::LemmaMeaning {
+        node_id,
+        root_id,
+        lemma_ids,
+        meaning_lemma_ids,
+        seed,
+    })
+}
+
+// ============================================================================
+// Audio Exercises (recitation clips)
+// ============================================================================
+
+/// Most words recited in a Listen Identify Verse clip (more when similar
+/// verses only differ later)
+const LISTEN_VERSE_WORDS: usize = 6;
+/// Most words recited before the word to continue with
+const LISTEN_CONTEXT_WORDS: i32 = 5;
+
+/// A verse recitation with word timings, from a reciter drawn with `rng`
+/// among those who have one
+async fn timed_recitation(
+    verse_key: &str,
+    rng: &mut impl Rng,
+    content_repo: &dyn ContentRepository,
+) -> Result<VerseRecitation> {
+    let mut recitations = Vec::new();
+    for reciter in content_repo.get_reciters().await? {
+        if let Some(recitation) = content_repo
+            .get_verse_recitation(verse_key, reciter.reciter_id)
+            .await?
+        {
+            if !recitation.segments.is_empty() {
+                recitations.push(recitation);
+            }
+        }
+    }
+    recitations
+        .choose(rng)
+        .cloned()
+        .ok_or_else(|| anyhow::anyhow!("No recitation with word timings for {}", verse_key))
+}
+
+/// Clip bounds (start_ms, end_ms) of words `first..=last` of a recitation
+fn clip_bounds(recitation: &VerseRecitation, first: i32, last: i32) -> Result<(i64, i64)> {
+    let missing = |position| {
+        anyhow::anyhow!(
+            "No timing for word {} of {} (reciter {})",
+            position,
+            recitation.verse_key,
+            recitation.reciter_id
+        )
+    };
+    let start = recitation.segment(first).ok_or_else(|| missing(first))?;
+    let end = recitation.segment(last).ok_or_else(|| missing(last))?;
+    Ok((start.start_ms, end.end_ms))
+}
+
+/// Generate Listen Identify Verse exercise
+///
+/// The clip is the opening of the verse, long enough to tell it apart from
+/// the similar verses offered as options. Neighboring verses fill the
+/// remaining options.
+pub async fn generate_listen_identify_verse(
+    node_id: i64,
+    ukey: &str,
+    seed: u64,
+    content_repo: &dyn ContentRepository,
+) -> Result<ExerciseData> {
+    let verse = resolve_verse(ukey, content_repo).await?;
+    let mut rng = StdRng::seed_from_u64(seed);
+    let recitation = timed_recitation(&verse.key, &mut rng, content_repo).await?;
+    let words = content_repo.get_words_for_verse(&verse.key).await?;
+    if words.is_empty() {
+        return Err(anyhow::anyhow!("No words for verse {}", verse.key));
+    }
+
+    let mut last_position = words.len().min(LISTEN_VERSE_WORDS) as i32;
+    let mut option_verse_keys = vec![verse.key.clone()];
+    for similar in content_repo
+        .get_similar_verses(&verse.key, (LOCATION_DISTRACTORS * 2) as u32)
+        .await?
+    {
+        if option_verse_keys.len() > LOCATION_DISTRACTORS {
+            break;
+        }
+        let other_words = content_repo.get_words_for_verse(&similar.verse_key).await?;
+        // Identical verses can't be told apart by ear
+        let Some(position) = first_differing_position(&words, &other_words) else {
+            continue;
+        };
+        if position as usize > words.len() {
+            continue;
+        }
+        last_position = last_position.max(position);
+        option_verse_keys.push(similar.verse_key);
+    }
+
+    let neighbors: Vec<i32> = content_repo
+        .get_verses_for_chapter(verse.chapter_number)
+        .await?
+        .into_iter()
+        .map(|v| v.verse_number)
+        .collect();
+    for number in neighbor_options(
+        verse.verse_number,
+        &neighbors,
+        LOCATION_DISTRACTORS,
+        &mut rng,
+    ) {
+        let key = format!("{}:{}", verse.chapter_number, number);
+        if option_verse_keys.len() <= LOCATION_DISTRACTORS && !option_verse_keys.contains(&key) {
+            option_verse_keys.push(key);
+        }
+    }
+    if option_verse_keys.len() < 2 {
+        return Err(anyhow::anyhow!(
+            "No verses to offer alongside {} for Listen Identify Verse",
+            verse.key
+        ));
+    }
+    option_verse_keys.shuffle(&mut rng);
+
+    let (start_ms, end_ms) = clip_bounds(&recitation, 1, last_position)?;
+
+    Ok(ExerciseData::ListenIdentifyVerse {
+        node_id,
+        reciter_id: recitation.reciter_id,
+        verse_key: verse.key,
+        first_position: 1,
+        last_position,
+        start_ms,
+        end_ms,
+        option_verse_keys,
+        seed,
+    })
+}
+
+/// Generate Listen Continue exercise: a clip of a few words, then pick the
+/// word that follows
+pub async fn generate_listen_continue(
+    node_id: i64,
+    ukey: &str,
+    seed: u64,
+    difficulty: DistractorDifficulty,
+    semantic: SemanticScoring,
+    content_repo: &dyn ContentRepository,
+) -> Result<ExerciseData> {
+    let verse = resolve_verse(ukey, content_repo).await?;
+    let mut rng = StdRng::seed_from_u64(seed);
+    let recitation = timed_recitation(&verse.key, &mut rng, content_repo).await?;
+    let words = content_repo.get_words_for_verse(&verse.key).await?;
+    if words.len() < 3 {
+        return Err(anyhow::anyhow!(
+            "Verse too short for Listen Continue (need at least 3 words)"
+        ));
+    }
+
+    let last_position = rng.gen_range(1..words.len()) as i32;
+    let first_position = (last_position - LISTEN_CONTEXT_WORDS + 1).max(1);
+    let (start_ms, end_ms) = clip_bounds(&recitation, first_position, last_position)?;
+
+    let target_position = last_position + 1;
+    let target = words
+        .iter()
+        .find(|w| w.position == target_position)
+        .cloned()
+        .ok_or_else(|| anyhow::anyhow!("Word not found at position {}", target_position))?;
+    let candidates = word_candidates(
+        verse.chapter_number,
+        verse.verse_number,
+        words,
+        Some(target_position),
+        content_repo,
+    )
+    .await?;
+    let (distractor_node_ids, distractor_strategy) = DistractorEngine::new(content_repo, semantic)?
+        .word_distractors(&target, candidates, MCQ_DISTRACTORS, difficulty, &mut rng)
+        .await?;
+
+    Ok(ExerciseData::ListenContinue {
+        node_id,
+        reciter_id: recitation.reciter_id,
+        verse_key: verse.key,
+        first_position,
+        last_position,
+        start_ms,
+        end_ms,
+        distractor_node_ids,
+        distractor_strategy,
+        seed,
+    })
+}
+
+/// Generate Listen Identify Word exercise, playing the word's own clip when
+/// installed and its segment of the verse recitation otherwise
+pub async fn generate_listen_identify_word(
+    node_id: i64,
+    ukey: &str,
+    seed: u64,
+    difficulty: DistractorDifficulty,
+    semantic: SemanticScoring,
+    content_repo: &dyn ContentRepository,
+) -> Result<ExerciseData> {
+    let (verse_key, word) = resolve_word(ukey, content_repo).await?;
+
+    let mut clips = Vec::new();
+    for reciter in content_repo.get_reciters().await? {
+        if let Some(clip) = content_repo
+            .get_word_audio(&verse_key, word.position, reciter.reciter_id)
+            .await?
+        {
+            clips.push(clip);
+        }
+    }
+    let clip = clips
+        .choose(&mut StdRng::seed_from_u64(seed))
+        .cloned()
+        .ok_or_else(|| anyhow::anyhow!("No audio for word {} of {}", word.position, verse_key))?;
+
+    let (distractor_node_ids, distractor_strategy) =
+        word_mcq_distractors(ukey, true, seed, difficulty, semantic, content_repo).await?;
+
+    Ok(ExerciseData::ListenIdentifyWord {
+        node_id,
+        reciter_id: clip.reciter_id,
+        verse_key,
+        position: word.position,
+        start_ms: clip.start_ms,
+        end_ms: clip.end_ms,
+        distractor_node_ids,
+        distractor_strategy,
+        seed,
+    })
+}
+
+// ============================================================================
+// Tajweed Exercises
+// ============================================================================
+
+/// Rules offered by Identify Tajweed Rule, the answer included
+const TAJWEED_RULE_OPTIONS: usize = 4;
+
+/// Tajweed annotations of a verse, failing when it has none
+async fn verse_tajweed(
+    verse_key: &str,
+    content_repo: &dyn ContentRepository,
+) -> Result<Vec<TajweedAnnotation>> {
+    let annotations = content_repo.get_tajweed_annotations(verse_key).await?;
+    if annotations.is_empty() {
+        return Err(anyhow::anyhow!(
+            "No tajweed annotations for verse {}",
+            verse_key
+        ));
+    }
+    Ok(annotations)
+}
+
+/// Word-instance node ID of the word at `position` in a verse
+fn verse_word_node_id(verse: &Verse, position: i32) -> i64 {
+    node_id::encode_word_instance(
+        verse.chapter_number as u8,
+        verse.verse_number as u16,
+        position as u8,
+    )
+}
+
+/// Generate Identify Tajweed Rule exercise
+///
+/// Highlights one annotated span of the verse, picked with the seed.
+/// Distractors favor rules of the same family (the kinds of madd, of
+/// idgham, ...), then rules found elsewhere in the verse. Rules annotated on
+/// the same span are never offered as distractors.
+pub async fn generate_identify_tajweed_rule(
+    node_id: i64,
+    ukey: &str,
+    seed: u64,
+    content_repo: &dyn ContentRepository,
+) -> Result<ExerciseData> {
+    let verse = resolve_verse(ukey, content_repo).await?;
+    let annotations = verse_tajweed(&verse.key, content_repo).await?;
+    let mut rng = StdRng::seed_from_u64(seed);
+    let target = annotations
+        .choose(&mut rng)
+        .cloned()
+        .ok_or_else(|| anyhow::anyhow!("No tajweed annotations for verse {}", verse.key))?;
+
+    let on_span: Vec<TajweedRule> = annotations
+        .iter()
+        .filter(|a| {
+            a.word_id == target.word_id
+                && a.start_char == target.start_char
+                && a.end_char == target.end_char
+        })
+        .map(|a| a.rule)
+        .collect();
+    let mut candidates: Vec<TajweedRule> = TajweedRule::ALL
+        .into_iter()
+        .filter(|r| !on_span.contains(r))
+        .collect();
+    candidates.shuffle(&mut rng);
+    candidates.sort_by_key(|r| {
+        (
+            r.family() != target.rule.family(),
+            !annotations.iter().any(|a| a.rule == *r),
+        )
+    });
+
+    let mut rule_options: Vec<TajweedRule> = std::iter::once(target.rule)
+        .chain(candidates.into_iter().take(TAJWEED_RULE_OPTIONS - 1))
+        .collect();
+    rule_options.shuffle(&mut rng);
+
+    Ok(ExerciseData::IdentifyTajweedRule {
+        node_id,
+        word_node_id: verse_word_node_id(&verse, target.position),
+        start_char: target.start_char,
+        end_char: target.end_char,
+        correct_rule: target.rule,
+        rule_options,
+        seed,
+    })
+}
+
+/// Generate Find Tajweed Rule exercise: pick every word of the verse where
+/// a rule applies. The rule is picked with the seed among those in the verse.
+pub async fn generate_find_tajweed_rule(
+    node_id: i64,
+    ukey: &str,
+    seed: u64,
+    content_repo: &dyn ContentRepository,
+) -> Result<ExerciseData> {
+    let verse = resolve_verse(ukey, content_repo).await?;
+    let annotations = verse_tajweed(&verse.key, content_repo).await?;
+    let rules: Vec<TajweedRule> = TajweedRule::ALL
+        .into_iter()
+        .filter(|r| annotations.iter().any(|a| a.rule == *r))
+        .collect();
+    let rule = *rules
+        .choose(&mut StdRng::seed_from_u64(seed))
+        .ok_or_else(|| anyhow::anyhow!("No tajweed annotations for verse {}", verse.key))?;
+
+    let mut rule_word_node_ids: Vec<i64> = annotations
+        .iter()
+        .filter(|a| a.rule == rule)
+        .map(|a| verse_word_node_id(&verse, a.position))
+        .collect();
+    rule_word_node_ids.dedup();
+
+    Ok(ExerciseData::FindTajweedRule {
+        node_id,
+        rule,
+        rule_word_node_ids,
+        seed,
+    })
+}
+
+// ============================================================================
+// Tafsir Exercises
+// ============================================================================
+
+/// Key lessons offered by Tafsir Key Lesson, the answer included
+const TAFSIR_LESSON_OPTIONS: usize = 4;
+
+/// Verses offered by Revelation Occasion, the answer included
+const REVELATION_OCCASION_OPTIONS: usize = 4;
+
+/// A verse and the passage of the first installed tafsir commenting on it,
+/// failing when no tafsir covers the verse
+async fn verse_tafsir(
+    ukey: &str,
+    content_repo: &dyn ContentRepository,
+) -> Result<(Verse, TafsirPassage)> {
+    let verse = resolve_verse(ukey, content_repo).await?;
+    let tafsir = content_repo
+        .get_tafsirs()
+        .await?
+        .into_iter()
+        .next()
+        .ok_or_else(|| anyhow::anyhow!("No tafsir installed"))?;
+    let passage = content_repo
+        .get_tafsir_passage(&verse.key, tafsir.tafsir_id)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("No tafsir for verse {}", verse.key))?;
+    Ok((verse, passage))
+}
+
+/// Generate Tafsir Key Lesson exercise
+///
+/// Offers the key lesson of the passage on the verse against the lessons of
+/// other passages of the chapter (picked with the seed), then of passages
+/// sharing its themes.
+pub async fn generate_tafsir_key_lesson(
+    node_id: i64,
+    ukey: &str,
+    seed: u64,
+    content_repo: &dyn ContentRepository,
+) -> Result<ExerciseData> {
+    let (verse, passage) = verse_tafsir(ukey, content_repo).await?;
+    let lesson = passage
+        .key_lesson
+        .clone()
+        .ok_or_else(|| anyhow::anyhow!("No key lesson in the tafsir of verse {}", verse.key))?;
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let mut candidates = content_repo
+        .get_tafsir_passages_for_chapter(verse.chapter_number, passage.tafsir_id)
+        .await?;
+    candidates.shuffle(&mut rng);
+    if candidates.len() <= TAFSIR_LESSON_OPTIONS {
+        for theme in &passage.themes {
+            candidates.extend(
+                content_repo
+                    .get_tafsir_passages_by_theme(theme, passage.tafsir_id)
+                    .await?,
+            );
+        }
+    }
+
+    let passage_key = passage.start_verse_key();
+    let mut lessons = vec![lesson];
+    let mut option_passage_keys = vec![passage_key.clone()];
+    for candidate in candidates {
+        if option_passage_keys.len() >= TAFSIR_LESSON_OPTIONS {
+            break;
+        }
+        let key = candidate.start_verse_key();
+        let Some(candidate_lesson) = candidate.key_lesson else {
+            continue;
+        };
+        if option_passage_keys.contains(&key) || lessons.contains(&candidate_lesson) {
+            continue;
+        }
+        lessons.push(candidate_lesson);
+        option_passage_keys.push(key);
+    }
+    if option_passage_keys.len() < 2 {
+        return Err(anyhow::anyhow!(
+            "Not enough tafsir key lessons for verse {}",
+            verse.key
+        ));
+    }
+    option_passage_keys.shuffle(&mut rng);
+
+    Ok(ExerciseData::TafsirKeyLesson {
+        node_id,
+        tafsir_id: passage.tafsir_id,
+        passage_key,
+        option_passage_keys,
+        seed,
+    })
+}
+
+/// Generate Revelation Occasion exercise
+///
+/// Shows the occasion of revelation reported for the passage on the verse.
+/// The other candidates are verses of the chapter outside the passage,
+/// openings of passages with an occasion of their own first.
+pub async fn generate_revelation_occasion(
+    node_id: i64,
+    ukey: &str,
+    seed: u64,
+    content_repo: &dyn ContentRepository,
+) -> Result<ExerciseData> {
+    let (verse, passage) = verse_tafsir(ukey, content_repo).await?;
+    if passage.revelation_occasion.is_none() {
+        return Err(anyhow::anyhow!(
+            "No occasion of revelation reported for verse {}",
+            verse.key
+        ));
+    }
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let mut with_occasion: Vec<String> = content_repo
+        .get_tafsir_passages_for_chapter(verse.chapter_number, passage.tafsir_id)
+        .await?
+        .iter()
+        .filter(|p| p.start_verse != passage.start_verse && p.revelation_occasion.is_some())
+        .map(TafsirPassage::start_verse_key)
+        .collect();
+    with_occasion.shuffle(&mut rng);
+    let mut others: Vec<String> = content_repo
+        .get_verses_for_chapter(verse.chapter_number)
+        .await?
+        .into_iter()
+        .filter(|v| !passage.covers(v.chapter_number, v.verse_number))
+        .map(|v| v.key)
+        .filter(|key| !with_occasion.contains(key))
+        .collect();
+    others.shuffle(&mut rng);
+
+    let mut option_verse_keys = vec![verse.key.clone()];
+    option_verse_keys.extend(
+        with_occasion
+            .into_iter()
+            .chain(others)
+            .take(REVELATION_OCCASION_OPTIONS - 1),
+    );
+    if option_verse_keys.len() < 2 {
+        return Err(anyhow::anyhow!(
+            "Not enough verses outside the tafsir passage of verse {}",
+            verse.key
+        ));
+    }
+    option_verse_keys.shuffle(&mut rng);
+
+    Ok(ExerciseData::RevelationOccasion {
+        node_id,
+        tafsir_id: passage.tafsir_id,
+        passage_key: passage.start_verse_key(),
+        option_verse_keys,
+        seed,
+    })
+}
+
+/// Generate a Cross-Verse Connection exercise from tafsir themes
+///
+/// The connected verse opens another passage sharing a theme with the
+/// passage on the verse (theme and passage picked with the seed). Distractors
+/// are picked as in `generate_cross_verse_connection`, skipping every verse
+/// commented on under that theme.
+pub async fn generate_tafsir_theme_connection(
+    node_id: i64,
+    ukey: &str,
+    seed: u64,
+    content_repo: &dyn ContentRepository,
+) -> Result<ExerciseData> {
+    let (verse, passage) = verse_tafsir(ukey, content_repo).await?;
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut themes = passage.themes.clone();
+    themes.shuffle(&mut rng);
+
+    for theme in themes {
+        let sharing = content_repo
+            .get_tafsir_passages_by_theme(&theme, passage.tafsir_id)
+            .await?;
+        let others: Vec<&TafsirPassage> = sharing
+            .iter()
+            .filter(|p| {
+                (p.chapter_number, p.start_verse) != (passage.chapter_number, passage.start_verse)
+            })
+            .collect();
+        let Some(connected) = others.choose(&mut rng) else {
+            continue;
+        };
+
+        let correct_id =
+            node_id::encode_verse(connected.chapter_number as u8, connected.start_verse as u16);
+        let mut exclude: Vec<i64> = sharing
+            .iter()
+            .flat_map(|p| {
+                (p.start_verse..=p.end_verse)
+                    .map(|v| node_id::encode_verse(p.chapter_number as u8, v as u16))
+            })
+            .collect();
+        exclude.push(node_id::encode_verse(
+            verse.chapter_number as u8,
+            verse.verse_number as u16,
+        ));
+
+        let mut related_verse_ids = vec![correct_id];
+        related_verse_ids
+            .extend(cross_verse_distractors(verse.chapter_number, &exclude, content_repo).await?);
+        return Ok(ExerciseData::CrossVerseConnection {
+            node_id,
+            related_verse_ids,
+            connection_theme: theme,
+        });
+    }
+
+    Err(anyhow::anyhow!(
+        "No other tafsir passage shares a theme with verse {}",
+        verse.key
+    ))
+}
+
+/// First word position (1-indexed) where two verses differ, ignoring
+/// diacritics. Returns None when the verses are identical.
+fn first_differing_position(a: &[Word], b: &[Word]) -> Option<i32> {
+    let differs_at = a
+        .iter()
+        .zip(b)
+        .position(|(x, y)| {
+            DefaultValidator::normalize_arabic(&x.text_uthmani)
+                != DefaultValidator::normalize_arabic(&y.text_uthmani)
+        })
+        .or_else(|| (a.len() != b.len()).then(|| a.len().min(b.len())))?;
+    Some(differs_at as i32 + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn verse_words(verse_key: &str, text: &str) -> Vec<Word> {
+        text.split_whitespace()
+            .enumerate()
+            .map(|(i, w)| Word {
+                id: i as i64,
+                verse_key: verse_key.to_string(),
+                position: i as i32 + 1,
+                text_uthmani: w.to_string(),
+                text_simple: None,
+                transliteration: None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_neighbor_options() {
+        let chapters: Vec<i32> = (1..=114).collect();
+        let mut rng = StdRng::seed_from_u64(7);
+
+        let options = neighbor_options(50, &chapters, 3, &mut rng);
+        assert_eq!(options.len(), 4);
+        assert!(options.contains(&50));
+        assert!(options.iter().all(|c| (47..=53).contains(c)));
+
+        // Edges only have neighbors on one side
+        let options = neighbor_options(114, &chapters, 3, &mut rng);
+        assert!(options.iter().all(|c| (108..=114).contains(c)));
+
+        // Fewer candidates than requested
+        let mut options = neighbor_options(1, &[1, 2], 3, &mut rng);
+        options.sort();
+        assert_eq!(options, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_neighbor_options_reproducible_from_seed() {
+        let chapters: Vec<i32> = (1..=114).collect();
+        let draw = |seed| neighbor_options(50, &chapters, 3, &mut StdRng::seed_from_u64(seed));
+
+        assert_eq!(draw(42), draw(42));
+        assert!((0..20).any(|seed| draw(seed) != draw(42)));
+    }
+
+    #[test]
+    fn test_first_differing_position() {
+        // 2:58 vs 7:161: "وَإِذْ قُلْنَا ٱدْخُلُوا۟" / "وَإِذْ قِيلَ لَهُمُ ٱسْكُنُوا۟"
+        let a = verse_words("2:58", "وَإِذْ قُلْنَا ٱدْخُلُوا۟ هَٰذِهِ");
+        let b = verse_words("7:161", "وَإِذْ قِيلَ لَهُمُ ٱسْكُنُوا۟");
+        assert_eq!(first_differing_position(&a, &b), Some(2));
+
+        // Diacritics alone do not count as a difference
+        let c = verse_words("1:1", "بِسْمِ ٱللَّهِ");
+        let d = verse_words("1:1", "بسم الله");
+        assert_eq!(first_differing_position(&c, &d), None);
+
+        // A strict prefix differs right after its last word
+        let e = verse_words("1:1", "بِسْمِ ٱللَّهِ ٱلرَّحْمَٰنِ");
+        assert_eq!(first_differing_position(&c, &e), Some(3));
+    }
+
+    #[tokio::test]
+    async fn test_grammar_feature() {
+        use crate::exercises::{AnswerInput, DefaultValidator, ExerciseValidator};
+        use crate::testing::MockContentRepository;
+        use crate::MorphologySegment;
+
+        let words = verse_words("2:6", "إِنَّ ٱلَّذِينَ كَفَرُوا۟");
+        let mut repo = MockContentRepository::new();
+        repo.expect_get_words_for_verse()
+            .returning(move |_| Ok(words.clone()));
+        // كَفَرُوا۟: perfect form I verb, third person masculine plural
+        repo.expect_get_morphology_for_word().returning(|word_id| {
+            Ok(vec![MorphologySegment {
+                segment_id: 1,
+                word_id,
+                position: 1,
+                lemma_id: None,
+                root_id: None,
+                pos_tag: Some("V".to_string()),
+                features: MorphologyFeatures::from_corpus_features("V|PERF|LEM:كَفَرَ|ROOT:كفر|3MP"),
+            }])
+        });
+
+        let validator = DefaultValidator::new();
+        let mut asked = std::collections::HashSet::new();
+        for seed in 0..40 {
+            let exercise = generate_grammar_feature(3, "WORD_INSTANCE:2:6:3", seed, &repo)
+                .await
+                .unwrap();
+            let ExerciseData::GrammarFeature {
+                feature,
+                correct_value,
+                options,
+                ..
+            } = &exercise
+            else {
+                panic!("Expected GrammarFeature exercise");
+            };
+            let expected = match feature {
+                GrammarFeature::VerbForm => "I",
+                GrammarFeature::Tense => "perfect",
+                GrammarFeature::Person => "third",
+                GrammarFeature::Gender => "masculine",
+                GrammarFeature::Number => "plural",
+                other => panic!("{:?} is not recorded for this word", other),
+            };
+            assert_eq!(correct_value, expected);
+            assert!(options.len() <= 4);
+            assert!(options.contains(correct_value));
+            asked.insert(*feature);
+
+            let index = options.iter().position(|o| o == correct_value).unwrap();
+            let result = validator
+                .validate(&exercise, &AnswerInput::OptionIndex { value: index }, &repo)
+                .await
+                .unwrap();
+            assert!(result.is_correct);
+            let wrong = options.iter().find(|o| *o != correct_value).unwrap();
+            let result = validator
+                .validate(
+                    &exercise,
+                    &AnswerInput::Text {
+                        value: wrong.clone(),
+                    },
+                    &repo,
+                )
+                .await
+                .unwrap();
+            assert!(!result.is_correct);
+        }
+        assert!(asked.contains(&GrammarFeature::VerbForm));
+        assert!(asked.contains(&GrammarFeature::Number));
+    }
+
+    #[tokio::test]
+    async fn test_root_family_and_lemma_meaning() {
+        use crate::exercises::{AnswerInput, DefaultValidator, ExerciseValidator};
+        use crate::testing::MockContentRepository;
+        use crate::{Lemma, LemmaOccurrences, MorphologySegment, RootOccurrences};
+
+        let words = verse_words("1:1", "بِسْمِ ٱللَّهِ ٱلرَّحْمَٰنِ ٱلرَّحِيمِ");
+        let mut repo = MockContentRepository::new();
+        let verse_words_ = words.clone();
+        repo.expect_get_verse().returning(|key| {
+            Ok(Some(Verse {
+                key: key.to_string(),
+                chapter_number: 1,
+                verse_number: 1,
+                text_uthmani: String::new(),
+                text_simple: None,
+                juz: 1,
+                page: 1,
+            }))
+        });
+        repo.expect_get_words_for_verse()
+            .returning(move |_| Ok(verse_words_.clone()));
+        repo.expect_get_root_occurrences_in_verses().returning(|_| {
+            Ok(vec![
+                RootOccurrences {
+                    root_id: "s-m-w".to_string(),
+                    occurrences: 381,
+                    word_ids: vec![0],
+                },
+                RootOccurrences {
+                    root_id: "r-ḥ-m".to_string(),
+                    occurrences: 339,
+                    word_ids: vec![2, 3],
+                },
+            ])
+        });
+        let batch = words.clone();
+        repo.expect_get_words_batch().returning(move |ids| {
+            Ok(batch
+                .iter()
+                .filter(|w| ids.contains(&w.id))
+                .map(|w| (w.id, w.clone()))
+                .collect())
+        });
+        repo.expect_get_morphology_for_word().returning(|word_id| {
+            Ok(vec![MorphologySegment {
+                segment_id: 1,
+                word_id,
+                position: 1,
+                lemma_id: None,
+                root_id: Some("r-ḥ-m".to_string()),
+                pos_tag: None,
+                features: Default::default(),
+            }])
+        });
+        let lemma = |id: &str, description: Option<&str>| Lemma {
+            lemma_id: id.to_string(),
+            arabic: id.to_string(),
+            root_id: Some("r-ḥ-m".to_string()),
+            transliteration: None,
+            description: description.map(str::to_string),
+        };
+        let lemmas = vec![
+            lemma("raḥma", Some("mercy")),
+            lemma("raḥmān", Some("Most Gracious")),
+            lemma("arḥām", None),
+            lemma("raḥīm", Some("Most Merciful")),
+        ];
+        let family = lemmas.clone();
+        repo.expect_get_lemmas_for_root().returning(move |_| {
+            Ok(family
+                .iter()
+                .map(|l| LemmaOccurrences {
+                    lemma: l.clone(),
+                    occurrences: 1,
+                })
+                .collect())
+        });
+        repo.expect_get_lemma_by_id()
+            .returning(move |id| Ok(lemmas.iter().find(|l| l.lemma_id == id).cloned()));
+        repo.expect_get_script_content().returning(|_, _| Ok(None));
+
+        // The most frequent root with two words in the verse
+        let exercise = generate_root_family(11, "VERSE:1:1", &repo).await.unwrap();
+        let family_ids = vec![
+            node_id::encode_word_instance(1, 1, 3),
+            node_id::encode_word_instance(1, 1, 4),
+        ];
+        assert_eq!(
+            exercise,
+            ExerciseData::RootFamily {
+                node_id: 11,
+                root_id: "r-ḥ-m".to_string(),
+                verse_keys: vec!["1:1".to_string()],
+                family_word_node_ids: family_ids.clone(),
+            }
+        );
+
+        let validator = DefaultValidator::new();
+        let mut reversed = family_ids.clone();
+        reversed.reverse();
+        let result = validator
+            .validate(
+                &exercise,
+                &AnswerInput::Sequence { values: reversed },
+                &repo,
+            )
+            .await
+            .unwrap();
+        assert!(result.is_correct);
+        let extra = vec![family_ids[0], node_id::encode_word_instance(1, 1, 1)];
+        let result = validator
+            .validate(&exercise, &AnswerInput::Sequence { values: extra }, &repo)
+            .await
+            .unwrap();
+        assert!(!result.is_correct);
+        assert_eq!(result.similarity_score, Some(1.0 / 3.0));
+
+        // Lemmas without a meaning are skipped
+        let exercise = generate_lemma_meaning(3, "WORD_INSTANCE:1:1:3", 5, &repo)
+            .await
+            .unwrap();
+        let ExerciseData::LemmaMeaning {
+            lemma_ids,
+            meaning_lemma_ids,
+            ..
+        } = &exercise
+        else {
+            panic!("Expected LemmaMeaning exercise");
+        };
+        assert_eq!(lemma_ids, &["raḥma", "raḥmān", "raḥīm"]);
+        assert_ne!(meaning_lemma_ids, lemma_ids);
+
+        let matches: Vec<i64> = lemma_ids
+            .iter()
+            .map(|id| meaning_lemma_ids.iter().position(|m| m == id).unwrap() as i64)
+            .collect();
+        let result = validator
+            .validate(&exercise, &AnswerInput::Sequence { values: matches }, &repo)
+            .await
+            .unwrap();
+        assert!(result.is_correct);
+        assert_eq!(
+            generate_lemma_meaning(3, "WORD_INSTANCE:1:1:3", 5, &repo)
+                .await
+                .unwrap(),
+            exercise
+        );
+    }
 
     #[test]
     fn test_generate_memorization() {
@@ -1,10 +1,13 @@
 // exercises/grammar.rs
 // Grammar and etymology exercises (Nahw & Sarf)
 
+use super::distractors::{rank_root_distractors, DistractorDifficulty};
 use super::types::Exercise;
 use crate::{ContentRepository, KnowledgeNode};
 use anyhow::Result;
+use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
+use rand::SeedableRng;
 
 // ============================================================================
 // Exercise 21: Identify the Root (MCQ)
@@ -23,10 +26,12 @@ pub struct IdentifyRootExercise {
 impl IdentifyRootExercise {
     /// Create a new Identify Root exercise
     ///
-    /// Queries the morphology database to get the actual root for the word
+    /// Queries the morphology database to get the actual root for the word.
+    /// Options are shuffled with `seed`.
     pub async fn new(
         word_node_id: i64,
         ukey: &str,
+        seed: u64,
         content_repo: &dyn ContentRepository,
     ) -> Result<Self> {
         // Parse knowledge node
@@ -95,7 +100,7 @@ impl IdentifyRootExercise {
         // Combine correct + distractors and shuffle
         let mut options = vec![correct_root.clone()];
         options.extend(distractors);
-        options.shuffle(&mut rand::thread_rng());
+        options.shuffle(&mut StdRng::seed_from_u64(seed));
 
         Ok(Self {
             node_id: word_node_id,
@@ -141,12 +146,13 @@ impl IdentifyRootExercise {
             "ص-ل-ي", // pray
         ];
 
-        common_roots
-            .iter()
-            .filter(|&r| *r != correct)
-            .take(count)
-            .map(|s| s.to_string())
-            .collect()
+        // Prefer roots sharing some radicals with the correct one
+        rank_root_distractors(
+            correct,
+            &common_roots,
+            count,
+            DistractorDifficulty::default(),
+        )
     }
 
     /// Normalize root for comparison (remove spaces and dashes)
@@ -5,7 +5,9 @@ use super::types::Exercise;
 use crate::domain::node_id::{self, PREFIX_VERSE};
 use crate::{ContentRepository, KnowledgeNode};
 use anyhow::Result;
+use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
+use rand::SeedableRng;
 
 // ============================================================================
 // Exercise 19: Cross-Verse Connection (Graph-based!)
@@ -31,7 +33,13 @@ impl CrossVerseConnectionExercise {
     /// Queries the Knowledge Graph to find:
     /// - Connected verses via graph edges
     /// - Disconnected verses for distractors
-    pub async fn new(verse_node_id: i64, content_repo: &dyn ContentRepository) -> Result<Self> {
+    ///
+    /// Options are shuffled with `seed`.
+    pub async fn new(
+        verse_node_id: i64,
+        seed: u64,
+        content_repo: &dyn ContentRepository,
+    ) -> Result<Self> {
         // Get the node to access its ukey
         let node = content_repo
             .get_node(verse_node_id)
@@ -107,7 +115,7 @@ impl CrossVerseConnectionExercise {
         // Combine correct answer with distractors
         let mut options = vec![(correct_verse_key.clone(), correct_verse_text.clone())];
         options.extend(distractors);
-        options.shuffle(&mut rand::thread_rng());
+        options.shuffle(&mut StdRng::seed_from_u64(seed));
 
         Ok(Self {
             node_id: verse_node_id,
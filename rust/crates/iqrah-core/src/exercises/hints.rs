@@ -0,0 +1,397 @@
+// exercises/hints.rs
+// Progressive hint ladder for enum-based exercises
+//
+// Hints are revealed one rung at a time, least revealing first:
+// first letter -> first word -> word count -> translation -> partial reveal.
+// Each exercise type offers the rungs that make sense for its answer, and
+// rungs whose content is unavailable (a one-word answer has no "first word")
+// are skipped. The most revealing rung shown caps the grade the answer can
+// earn (`HintLevel::max_grade`).
+
+use super::exercise_data::ExerciseData;
+use super::script;
+use super::validator::{verse_word, word_of_node, AnswerKeys, DefaultValidator, ExerciseValidator};
+use crate::domain::node_id;
+use crate::{ContentRepository, HintLevel, ReviewGrade, Script};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+/// One rung of an exercise's hint ladder
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExerciseHint {
+    pub level: HintLevel,
+    /// Hint content to display
+    pub text: String,
+    /// Highest grade still achievable once this hint is shown
+    pub max_grade: ReviewGrade,
+}
+
+/// Typed recall of one or more verses
+const RECALL_LADDER: &[HintLevel] = &HintLevel::LADDER;
+/// Recall of a single Arabic word
+const WORD_LADDER: &[HintLevel] = &[
+    HintLevel::FirstLetter,
+    HintLevel::Translation,
+    HintLevel::PartialReveal,
+];
+/// English answers (the translation rung would be the answer itself)
+const ENGLISH_LADDER: &[HintLevel] = &[
+    HintLevel::FirstLetter,
+    HintLevel::FirstWord,
+    HintLevel::WordCount,
+    HintLevel::PartialReveal,
+];
+/// Exercises whose answer is not text: only the meaning can be hinted
+const CONTEXT_LADDER: &[HintLevel] = &[HintLevel::Translation];
+
+/// Hint levels an exercise offers, least revealing first
+pub fn hint_levels(exercise: &ExerciseData) -> &'static [HintLevel] {
+    match exercise {
+        ExerciseData::Memorization { .. }
+        | ExerciseData::FullVerseInput { .. }
+        | ExerciseData::AyahChain { .. }
+        | ExerciseData::EchoRecall { .. }
+        | ExerciseData::RecitePage { .. } => RECALL_LADDER,
+
+        ExerciseData::ClozeDeletion { .. }
+        | ExerciseData::FirstLetterHint { .. }
+        | ExerciseData::ReverseCloze { .. }
+        | ExerciseData::FirstWordRecall { .. }
+        | ExerciseData::MissingWordMcq { .. }
+        | ExerciseData::NextWordMcq { .. }
+        | ExerciseData::TransliterationToArabic { .. }
+        | ExerciseData::ArabicToTransliteration { .. }
+        | ExerciseData::PageFirstWord { .. }
+        | ExerciseData::ListenContinue { .. }
+        | ExerciseData::ListenIdentifyWord { .. } => WORD_LADDER,
+
+        ExerciseData::Translation { .. }
+        | ExerciseData::ContextualTranslation { .. }
+        | ExerciseData::TranslatePhrase { .. }
+        | ExerciseData::McqArToEn { .. } => ENGLISH_LADDER,
+
+        // The prompt already is the translation
+        ExerciseData::McqEnToAr { .. } | ExerciseData::IdentifyRoot { .. } => {
+            &[HintLevel::FirstLetter, HintLevel::PartialReveal]
+        }
+
+        ExerciseData::WordOrder { .. } => &[HintLevel::FirstWord, HintLevel::Translation],
+
+        // How many words to find
+        ExerciseData::FindTajweedRule { .. } => &[HintLevel::WordCount],
+
+        ExerciseData::FindMistake { .. }
+        | ExerciseData::PosTagging { .. }
+        | ExerciseData::GrammarFeature { .. }
+        | ExerciseData::CrossVerseConnection { .. }
+        | ExerciseData::IdentifySurah { .. }
+        | ExerciseData::IdentifyVerseNumber { .. }
+        | ExerciseData::IdentifyPageHalf { .. }
+        | ExerciseData::IdentifyVerseLine { .. }
+        | ExerciseData::RootFamily { .. }
+        | ExerciseData::ListenIdentifyVerse { .. }
+        | ExerciseData::TafsirKeyLesson { .. } => CONTEXT_LADDER,
+
+        // Any hint would give away the order or the distinction being tested
+        ExerciseData::AyahSequence { .. }
+        | ExerciseData::SequenceRecall { .. }
+        | ExerciseData::Mutashabihat { .. }
+        | ExerciseData::LemmaMeaning { .. }
+        | ExerciseData::IdentifyTajweedRule { .. }
+        // The verse's meaning would point at the answer
+        | ExerciseData::RevelationOccasion { .. } => &[],
+    }
+}
+
+/// All hints available for an exercise, least revealing first, with Arabic
+/// text in `script`
+pub async fn hint_ladder(
+    exercise: &ExerciseData,
+    script: Script,
+    content_repo: &dyn ContentRepository,
+) -> Result<Vec<ExerciseHint>> {
+    let levels = hint_levels(exercise);
+    if levels.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let answer = answer_text(exercise, script, content_repo).await?;
+    let translation = if levels.contains(&HintLevel::Translation) {
+        translation_text(exercise, content_repo).await?
+    } else {
+        None
+    };
+
+    Ok(levels
+        .iter()
+        .filter_map(|&level| {
+            hint_text(level, answer.as_deref(), translation.as_deref()).map(|text| ExerciseHint {
+                level,
+                text,
+                max_grade: level.max_grade(),
+            })
+        })
+        .collect())
+}
+
+/// Content of a single rung, or None when it cannot be built
+fn hint_text(level: HintLevel, answer: Option<&str>, translation: Option<&str>) -> Option<String> {
+    match level {
+        HintLevel::FirstLetter => answer?.chars().find(|c| is_letter(*c)).map(String::from),
+        HintLevel::FirstWord => {
+            let words: Vec<&str> = answer?.split_whitespace().collect();
+            (words.len() > 1).then(|| words[0].to_string())
+        }
+        HintLevel::WordCount => {
+            let count = answer?.split_whitespace().count();
+            (count > 1).then(|| format!("{} words", count))
+        }
+        HintLevel::Translation => translation
+            .filter(|t| !t.trim().is_empty())
+            .map(str::to_string),
+        HintLevel::PartialReveal => partial_reveal(answer?),
+    }
+}
+
+/// Every other word of a multi-word answer, or the first half of the letters
+/// (with their diacritics) of a single word
+fn partial_reveal(answer: &str) -> Option<String> {
+    let words: Vec<&str> = answer.split_whitespace().collect();
+    match words.as_slice() {
+        [] => None,
+        [word] => {
+            let letters = word.chars().filter(|c| is_letter(*c)).count();
+            if letters < 2 {
+                return None;
+            }
+            let shown = letters.div_ceil(2);
+            let mut seen = 0;
+            let prefix: String = word
+                .chars()
+                .take_while(|c| {
+                    if is_letter(*c) {
+                        seen += 1;
+                    }
+                    seen <= shown
+                })
+                .collect();
+            Some(format!("{}…", prefix))
+        }
+        _ => Some(
+            words
+                .iter()
+                .enumerate()
+                .map(|(i, w)| if i % 2 == 0 { w } else { "…" })
+                .collect::<Vec<_>>()
+                .join(" "),
+        ),
+    }
+}
+
+/// A base letter: Arabic harakat and Quranic annotation marks count as
+/// alphabetic in Unicode but belong to the preceding letter
+fn is_letter(c: char) -> bool {
+    c.is_alphabetic()
+        && !matches!(c, '\u{064B}'..='\u{065F}' | '\u{0670}' | '\u{06D6}'..='\u{06ED}')
+}
+
+/// The expected answer as text, when it has one
+async fn answer_text(
+    exercise: &ExerciseData,
+    script: Script,
+    content_repo: &dyn ContentRepository,
+) -> Result<Option<String>> {
+    match exercise {
+        ExerciseData::McqArToEn { node_id, .. } => {
+            content_repo.get_translation(*node_id, "en").await
+        }
+        ExerciseData::McqEnToAr { node_id, .. } => {
+            script::node_text(*node_id, script, content_repo).await
+        }
+        ExerciseData::TransliterationToArabic { node_id, .. }
+        | ExerciseData::PageFirstWord {
+            first_word_node_id: node_id,
+            ..
+        }
+        | ExerciseData::ListenIdentifyWord { node_id, .. } => {
+            let word = word_of_node(*node_id, content_repo).await?;
+            Ok(Some(script::word_text(&word, script, content_repo).await?))
+        }
+        ExerciseData::MissingWordMcq {
+            node_id,
+            blank_position,
+            ..
+        } => {
+            let (_, word) = verse_word(*node_id, *blank_position, content_repo).await?;
+            Ok(Some(script::word_text(&word, script, content_repo).await?))
+        }
+        ExerciseData::NextWordMcq {
+            node_id,
+            context_position,
+            ..
+        }
+        | ExerciseData::ListenContinue {
+            node_id,
+            last_position: context_position,
+            ..
+        } => {
+            let (_, word) = verse_word(*node_id, context_position + 1, content_repo).await?;
+            Ok(Some(script::word_text(&word, script, content_repo).await?))
+        }
+        ExerciseData::FindTajweedRule {
+            rule_word_node_ids, ..
+        } => {
+            let mut texts = Vec::with_capacity(rule_word_node_ids.len());
+            for id in rule_word_node_ids {
+                let word = word_of_node(*id, content_repo).await?;
+                texts.push(script::word_text(&word, script, content_repo).await?);
+            }
+            Ok(Some(texts.join(" ")))
+        }
+        ExerciseData::WordOrder {
+            correct_sequence,
+            shuffled_word_node_ids,
+            tile_texts,
+            ..
+        } => {
+            let words: Vec<&str> = correct_sequence
+                .iter()
+                .filter_map(|id| {
+                    let tile = shuffled_word_node_ids.iter().position(|s| s == id)?;
+                    tile_texts.get(tile).map(String::as_str)
+                })
+                .collect();
+            Ok(Some(words.join(" ")))
+        }
+        _ => match DefaultValidator::with_script(script)
+            .get_answer_keys(exercise, content_repo)
+            .await?
+        {
+            AnswerKeys::Text { value } => Ok(Some(value)),
+            AnswerKeys::Root { letters } => Ok(Some(letters)),
+            _ => Ok(None),
+        },
+    }
+}
+
+/// English translation of the content the answer is about
+async fn translation_text(
+    exercise: &ExerciseData,
+    content_repo: &dyn ContentRepository,
+) -> Result<Option<String>> {
+    let node_ids = match exercise {
+        ExerciseData::ClozeDeletion {
+            node_id,
+            blank_position: position,
+            ..
+        }
+        | ExerciseData::FirstLetterHint {
+            node_id,
+            word_position: position,
+            ..
+        }
+        | ExerciseData::ReverseCloze {
+            node_id,
+            blank_position: position,
+            ..
+        }
+        | ExerciseData::MissingWordMcq {
+            node_id,
+            blank_position: position,
+            ..
+        } => vec![verse_word(*node_id, *position, content_repo).await?.0],
+        ExerciseData::NextWordMcq {
+            node_id,
+            context_position,
+            ..
+        }
+        | ExerciseData::ListenContinue {
+            node_id,
+            last_position: context_position,
+            ..
+        } => vec![
+            verse_word(*node_id, context_position + 1, content_repo)
+                .await?
+                .0,
+        ],
+        ExerciseData::FirstWordRecall { node_id, .. } => {
+            vec![verse_word(*node_id, 1, content_repo).await?.0]
+        }
+        ExerciseData::AyahChain {
+            verse_keys,
+            current_index,
+            ..
+        } => verse_keys
+            .get(*current_index)
+            .and_then(|key| node_id::from_ukey(&node_id::verse_from_key(key)))
+            .into_iter()
+            .collect(),
+        ExerciseData::EchoRecall { ayah_node_ids } => ayah_node_ids.clone(),
+        ExerciseData::RecitePage { verse_keys, .. } => verse_keys
+            .iter()
+            .filter_map(|key| node_id::from_ukey(&node_id::verse_from_key(key)))
+            .collect(),
+        ExerciseData::PageFirstWord {
+            first_word_node_id, ..
+        } => vec![*first_word_node_id],
+        other => vec![other.node_id()],
+    };
+
+    let mut parts = Vec::with_capacity(node_ids.len());
+    for id in node_ids {
+        if let Some(translation) = content_repo.get_translation(id, "en").await? {
+            parts.push(translation);
+        }
+    }
+    Ok((!parts.is_empty()).then(|| parts.join(" ")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hint_text_rungs() {
+        let answer = Some("بسم الله الرحمن الرحيم");
+        let text = |level| hint_text(level, answer, Some("In the name of Allah"));
+
+        assert_eq!(text(HintLevel::FirstLetter).as_deref(), Some("ب"));
+        assert_eq!(text(HintLevel::FirstWord).as_deref(), Some("بسم"));
+        assert_eq!(text(HintLevel::WordCount).as_deref(), Some("4 words"));
+        assert_eq!(
+            text(HintLevel::Translation).as_deref(),
+            Some("In the name of Allah")
+        );
+        assert_eq!(
+            text(HintLevel::PartialReveal).as_deref(),
+            Some("بسم … الرحمن …")
+        );
+    }
+
+    #[test]
+    fn test_single_word_skips_word_rungs() {
+        let answer = Some("الرحيم");
+        assert_eq!(hint_text(HintLevel::FirstWord, answer, None), None);
+        assert_eq!(hint_text(HintLevel::WordCount, answer, None), None);
+        assert_eq!(hint_text(HintLevel::Translation, answer, None), None);
+        assert_eq!(
+            hint_text(HintLevel::PartialReveal, answer, None).as_deref(),
+            Some("الر…")
+        );
+    }
+
+    #[test]
+    fn test_partial_reveal_keeps_diacritics() {
+        assert_eq!(partial_reveal("بِسْمِ").as_deref(), Some("بِسْ…"));
+        assert_eq!(partial_reveal("و"), None);
+    }
+
+    #[test]
+    fn test_ordering_exercises_have_no_hints() {
+        let exercise = ExerciseData::AyahSequence {
+            node_id: 1,
+            correct_sequence: vec![1, 2],
+        };
+        assert!(hint_levels(&exercise).is_empty());
+    }
+}
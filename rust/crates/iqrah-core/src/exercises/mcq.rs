@@ -1,10 +1,13 @@
 // exercises/mcq.rs
 // Multiple Choice Question exercises
 
+use super::script;
 use super::types::Exercise;
-use crate::ContentRepository;
+use crate::{ContentRepository, Script};
 use anyhow::Result;
+use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
+use rand::SeedableRng;
 
 /// Multiple Choice Question exercise (MCQ)
 /// Supports both Arabic (memorization) and Translation questions
@@ -27,10 +30,17 @@ pub enum McqType {
 
 impl McqExercise {
     /// Create a new MCQ exercise (Arabic to English)
-    pub async fn new_ar_to_en(node_id: i64, content_repo: &dyn ContentRepository) -> Result<Self> {
+    ///
+    /// Options are shuffled with an RNG seeded from `seed`; the word is shown
+    /// in `script`.
+    pub async fn new_ar_to_en(
+        node_id: i64,
+        seed: u64,
+        script: Script,
+        content_repo: &dyn ContentRepository,
+    ) -> Result<Self> {
         // Get the word text (Arabic) using the i64 ID
-        let word_text = content_repo
-            .get_quran_text(node_id)
+        let word_text = script::node_text(node_id, script, content_repo)
             .await?
             .ok_or_else(|| anyhow::anyhow!("Word text not found for node: {}", node_id))?;
 
@@ -47,7 +57,7 @@ impl McqExercise {
         // Combine correct + distractors and shuffle
         let mut options = vec![correct_answer.clone()];
         options.extend(distractors);
-        options.shuffle(&mut rand::thread_rng());
+        options.shuffle(&mut StdRng::seed_from_u64(seed));
 
         let question = format!("What does '{}' mean?", word_text);
 
@@ -61,10 +71,17 @@ impl McqExercise {
     }
 
     /// Create a new MCQ exercise (English to Arabic)
-    pub async fn new_en_to_ar(node_id: i64, content_repo: &dyn ContentRepository) -> Result<Self> {
+    ///
+    /// Options are shuffled with an RNG seeded from `seed`; the answer is
+    /// written in `script`.
+    pub async fn new_en_to_ar(
+        node_id: i64,
+        seed: u64,
+        script: Script,
+        content_repo: &dyn ContentRepository,
+    ) -> Result<Self> {
         // Get the word text (Arabic) - this is the correct answer
-        let correct_answer = content_repo
-            .get_quran_text(node_id)
+        let correct_answer = script::node_text(node_id, script, content_repo)
             .await?
             .ok_or_else(|| anyhow::anyhow!("Word text not found for node: {}", node_id))?;
 
@@ -81,7 +98,7 @@ impl McqExercise {
         // Combine correct + distractors and shuffle
         let mut options = vec![correct_answer.clone()];
         options.extend(distractors);
-        options.shuffle(&mut rand::thread_rng());
+        options.shuffle(&mut StdRng::seed_from_u64(seed));
 
         let question = format!("Which Arabic word means '{}'?", translation);
 
@@ -1,12 +1,16 @@
 // exercises/memorization.rs
 // Memorization exercises for Quranic learning
 
+use super::script;
 use super::types::Exercise;
+use super::validator::DefaultValidator;
 use crate::domain::node_id::{self, PREFIX_VERSE, PREFIX_WORD};
-use crate::semantic::grader::{SemanticGradeLabel, SemanticGrader, SEMANTIC_EMBEDDER};
-use crate::{ContentRepository, KnowledgeNode};
+use crate::semantic::grader::{SemanticGradeLabel, SemanticGrader};
+use crate::{ContentRepository, KnowledgeNode, Script};
 use anyhow::Result;
+use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
 
 /// Exercise for memorizing Quranic words
 /// Tests the user's ability to recall the exact Arabic text using semantic similarity
@@ -18,10 +22,11 @@ pub struct MemorizationExercise {
 }
 
 impl MemorizationExercise {
-    /// Create a new memorization exercise
+    /// Create a new memorization exercise with the text in `script`
     pub async fn new(
         node_id: i64,
         ukey: &str,
+        script: Script,
         content_repo: &dyn ContentRepository,
     ) -> Result<Self> {
         // Resolve base ID if this is a knowledge node
@@ -32,8 +37,7 @@ impl MemorizationExercise {
         };
 
         // Get the word text from the repository using the integer ID
-        let word_text = content_repo
-            .get_quran_text(base_id)
+        let word_text = script::node_text(base_id, script, content_repo)
             .await?
             .ok_or_else(|| anyhow::anyhow!("Word text not found for node ID: {}", base_id))?;
 
@@ -50,8 +54,7 @@ impl MemorizationExercise {
                 // need to be adapted or that `get_quran_text` could also accept a ukey.
                 // Let's assume a lookup is needed.
                 if let Some(verse_node) = content_repo.get_node_by_ukey(&verse_ukey).await? {
-                    content_repo
-                        .get_quran_text(verse_node.id)
+                    script::node_text(verse_node.id, script, content_repo)
                         .await
                         .ok()
                         .flatten()
@@ -73,49 +76,10 @@ impl MemorizationExercise {
     }
 
     /// Normalize Arabic text for comparison (remove diacritics/tashkeel and normalize letters)
+    ///
+    /// Same normalization as `DefaultValidator::normalize_arabic`.
     pub fn normalize_arabic(text: &str) -> String {
-        let normalized = text
-            .chars()
-            // First, remove all diacritical marks
-            .filter(|c| {
-                !matches!(
-                    *c,
-                    '\u{064B}' | // Fathatan
-                    '\u{064C}' | // Dammatan
-                    '\u{064D}' | // Kasratan
-                    '\u{064E}' | // Fatha
-                    '\u{064F}' | // Damma
-                    '\u{0650}' | // Kasra
-                    '\u{0651}' | // Shadda
-                    '\u{0652}' | // Sukun
-                    '\u{0653}' | // Maddah
-                    '\u{0654}' | // Hamza above
-                    '\u{0655}' | // Hamza below
-                    '\u{0656}' | // Subscript alef
-                    '\u{0657}' | // Inverted damma
-                    '\u{0658}' // Mark noon ghunna
-                )
-            })
-            // Normalize letter variants
-            .map(|c| match c {
-                'ٱ' => 'ا', // Alif with wasla -> regular Alif
-                'أ' => 'ا', // Alif with hamza above -> regular Alif
-                'إ' => 'ا', // Alif with hamza below -> regular Alif
-                'آ' => 'ا', // Alif with madda -> regular Alif
-                'ٰ' => 'ا',  // Alif khanjariyyah -> regular Alif
-                'ى' => 'ي', // Alif maqsurah -> Ya
-                'ة' => 'ه', // Ta marbuta -> Ha
-                'ۀ' => 'ه', // Hamza on Ha -> Ha
-                _ => c,
-            })
-            .collect::<String>();
-
-        // Normalize whitespace: split by whitespace and rejoin with single spaces
-        // This handles multiple spaces, tabs, newlines, etc.
-        normalized
-            .split_whitespace()
-            .collect::<Vec<&str>>()
-            .join(" ")
+        DefaultValidator::normalize_arabic(text)
     }
 
     /// Get the correct word text (used by ExerciseService for semantic grading)
@@ -134,16 +98,8 @@ impl Exercise for MemorizationExercise {
         let normalized_answer = Self::normalize_arabic(answer);
         let normalized_correct = Self::normalize_arabic(&self.word_text);
 
-        // Get embedder, return false if not initialized
-        let embedder = match SEMANTIC_EMBEDDER.get() {
-            Some(e) => e,
-            None => {
-                tracing::error!("Semantic embedder not initialized! Call ExerciseService::init_semantic_model() first");
-                return false;
-            }
-        };
-
-        let grader = SemanticGrader::new(embedder);
+        // Lexical grading when no semantic model is loaded
+        let grader = SemanticGrader::global();
 
         // Use semantic grading on normalized Arabic text
         let grade = match grader.grade_answer(&normalized_answer, &normalized_correct) {
@@ -212,12 +168,16 @@ pub enum NextWordDifficulty {
 }
 
 impl NextWordMcqExercise {
-    /// Create a new Next Word MCQ exercise
+    /// Create a new Next Word MCQ exercise in `script`, drawing distractors
+    /// and option order from `seed`
     pub async fn new(
         verse_node_id: i64,
         difficulty: NextWordDifficulty,
+        seed: u64,
+        script: Script,
         content_repo: &dyn ContentRepository,
     ) -> Result<Self> {
+        let mut rng = StdRng::seed_from_u64(seed);
         // Get the node to access its ukey
         let node = content_repo
             .get_node(verse_node_id)
@@ -244,22 +204,19 @@ impl NextWordMcqExercise {
             return Err(anyhow::anyhow!("No words found for verse: {}", verse_key));
         }
 
+        let texts = script::word_texts(&words, script, content_repo).await?;
+
         // Last word is the correct answer
-        let last_word = words.last().unwrap();
-        let correct_answer = last_word.text_uthmani.clone();
+        let correct_answer = texts.last().unwrap().clone();
 
         // Build verse prefix (all words except last)
-        let prefix_words: Vec<&str> = words[..words.len() - 1]
-            .iter()
-            .map(|w| w.text_uthmani.as_str())
-            .collect();
-        let verse_prefix = prefix_words.join(" ");
+        let verse_prefix = texts[..texts.len() - 1].join(" ");
 
         // Generate distractors based on difficulty
         let distractors = match difficulty {
             NextWordDifficulty::Easy => {
                 // Use other words from the same verse
-                Self::generate_distractors_from_verse(&words, &correct_answer, 3)
+                Self::generate_distractors_from_verse(&texts, &correct_answer, 3)
             }
             NextWordDifficulty::Medium => {
                 // Use words from same chapter
@@ -268,6 +225,8 @@ impl NextWordMcqExercise {
                     &verse_key,
                     &correct_answer,
                     3,
+                    script,
+                    &mut rng,
                 )
                 .await?
             }
@@ -279,6 +238,8 @@ impl NextWordMcqExercise {
                     &verse_key,
                     &correct_answer,
                     3,
+                    script,
+                    &mut rng,
                 )
                 .await?
             }
@@ -287,7 +248,7 @@ impl NextWordMcqExercise {
         // Combine correct + distractors and shuffle
         let mut options = vec![correct_answer.clone()];
         options.extend(distractors);
-        options.shuffle(&mut rand::thread_rng());
+        options.shuffle(&mut rng);
 
         Ok(Self {
             node_id: verse_node_id,
@@ -301,16 +262,15 @@ impl NextWordMcqExercise {
 
     /// Generate distractors from the same verse
     fn generate_distractors_from_verse(
-        words: &[crate::Word],
+        texts: &[String],
         correct: &str,
         count: usize,
     ) -> Vec<String> {
-        words
+        texts
             .iter()
-            .map(|w| w.text_uthmani.as_str())
             .filter(|&w| w != correct)
             .take(count)
-            .map(|s| s.to_string())
+            .cloned()
             .collect()
     }
 
@@ -320,6 +280,8 @@ impl NextWordMcqExercise {
         verse_key: &str,
         correct: &str,
         count: usize,
+        script: Script,
+        rng: &mut impl Rng,
     ) -> Result<Vec<String>> {
         // Extract chapter number from verse key (e.g., "1:1" -> 1)
         let chapter_number: i32 = verse_key
@@ -337,7 +299,7 @@ impl NextWordMcqExercise {
             // Limit to first 10 verses for performance
             if verse.key != verse_key {
                 let words = content_repo.get_words_for_verse(&verse.key).await?;
-                distractor_words.extend(words.into_iter().map(|w| w.text_uthmani));
+                distractor_words.extend(script::word_texts(&words, script, content_repo).await?);
             }
         }
 
@@ -347,7 +309,7 @@ impl NextWordMcqExercise {
             .filter(|w| w != correct)
             .collect();
 
-        distractors.shuffle(&mut rand::thread_rng());
+        distractors.shuffle(rng);
         distractors.truncate(count);
 
         // If we don't have enough, pad with duplicates (shouldn't happen in practice)
@@ -412,9 +374,15 @@ pub struct MissingWordMcqExercise {
 }
 
 impl MissingWordMcqExercise {
-    /// Create a new Missing Word MCQ exercise
-    /// `word_node_id` should be the i64 ID of the word
-    pub async fn new(word_node_id: i64, content_repo: &dyn ContentRepository) -> Result<Self> {
+    /// Create a new Missing Word MCQ exercise in `script`
+    /// `word_node_id` should be the i64 ID of the word; `seed` picks the
+    /// distractors and the option order
+    pub async fn new(
+        word_node_id: i64,
+        seed: u64,
+        script: Script,
+        content_repo: &dyn ContentRepository,
+    ) -> Result<Self> {
         // Get the node to access its ukey
         let node = content_repo
             .get_node(word_node_id)
@@ -451,34 +419,38 @@ impl MissingWordMcqExercise {
         }
 
         // Find the target word
-        let target_word = words
+        let target_index = words
             .iter()
-            .find(|w| w.position == target_position)
+            .position(|w| w.position == target_position)
             .ok_or_else(|| anyhow::anyhow!("Word at position {} not found", target_position))?;
 
-        let correct_answer = target_word.text_uthmani.clone();
+        let texts = script::word_texts(&words, script, content_repo).await?;
+        let correct_answer = texts[target_index].clone();
 
         // Build verse with blank
-        let verse_with_blank = words
+        let verse_with_blank = texts
             .iter()
-            .map(|w| {
-                if w.position == target_position {
+            .enumerate()
+            .map(|(i, text)| {
+                if i == target_index {
                     "_____"
                 } else {
-                    w.text_uthmani.as_str()
+                    text.as_str()
                 }
             })
             .collect::<Vec<&str>>()
             .join(" ");
 
         // Generate distractors from other words in the same verse
-        let mut distractors: Vec<String> = words
+        let mut distractors: Vec<String> = texts
             .iter()
-            .filter(|w| w.position != target_position)
-            .map(|w| w.text_uthmani.clone())
+            .enumerate()
+            .filter(|&(i, _)| i != target_index)
+            .map(|(_, text)| text.clone())
             .collect();
 
-        distractors.shuffle(&mut rand::thread_rng());
+        let mut rng = StdRng::seed_from_u64(seed);
+        distractors.shuffle(&mut rng);
         distractors.truncate(3);
 
         // If we don't have enough distractors, pad with placeholders
@@ -489,7 +461,7 @@ impl MissingWordMcqExercise {
         // Combine correct + distractors and shuffle
         let mut options = vec![correct_answer.clone()];
         options.extend(distractors);
-        options.shuffle(&mut rand::thread_rng());
+        options.shuffle(&mut rng);
 
         Ok(Self {
             node_id: word_node_id,
@@ -548,10 +520,13 @@ pub struct ClozeDeletionExercise {
 }
 
 impl ClozeDeletionExercise {
-    /// Create a new Cloze Deletion exercise
+    /// Create a new Cloze Deletion exercise in `script`; the hint letters are
+    /// jumbled with `seed`
     pub async fn new(
         word_node_id: i64,
         show_letters_hint: bool,
+        seed: u64,
+        script: Script,
         content_repo: &dyn ContentRepository,
     ) -> Result<Self> {
         // Get the node to access its ukey
@@ -589,21 +564,23 @@ impl ClozeDeletionExercise {
         }
 
         // Find the target word
-        let target_word = words
+        let target_index = words
             .iter()
-            .find(|w| w.position == target_position)
+            .position(|w| w.position == target_position)
             .ok_or_else(|| anyhow::anyhow!("Word at position {} not found", target_position))?;
 
-        let correct_answer = target_word.text_uthmani.clone();
+        let texts = script::word_texts(&words, script, content_repo).await?;
+        let correct_answer = texts[target_index].clone();
 
         // Build verse with blank
-        let verse_with_blank = words
+        let verse_with_blank = texts
             .iter()
-            .map(|w| {
-                if w.position == target_position {
+            .enumerate()
+            .map(|(i, text)| {
+                if i == target_index {
                     "_____"
                 } else {
-                    w.text_uthmani.as_str()
+                    text.as_str()
                 }
             })
             .collect::<Vec<&str>>()
@@ -612,7 +589,7 @@ impl ClozeDeletionExercise {
         // Generate jumbled letters hint if requested
         let hint_letters = if show_letters_hint {
             let mut chars: Vec<char> = correct_answer.chars().collect();
-            chars.shuffle(&mut rand::thread_rng());
+            chars.shuffle(&mut StdRng::seed_from_u64(seed));
             Some(chars)
         } else {
             None
@@ -689,8 +666,12 @@ pub struct FirstLetterHintExercise {
 }
 
 impl FirstLetterHintExercise {
-    /// Create a new First Letter Hint exercise
-    pub async fn new(word_node_id: i64, content_repo: &dyn ContentRepository) -> Result<Self> {
+    /// Create a new First Letter Hint exercise in `script`
+    pub async fn new(
+        word_node_id: i64,
+        script: Script,
+        content_repo: &dyn ContentRepository,
+    ) -> Result<Self> {
         // Get the node to access its ukey
         let node = content_repo
             .get_node(word_node_id)
@@ -726,12 +707,13 @@ impl FirstLetterHintExercise {
         }
 
         // Find the target word
-        let target_word = words
+        let target_index = words
             .iter()
-            .find(|w| w.position == target_position)
+            .position(|w| w.position == target_position)
             .ok_or_else(|| anyhow::anyhow!("Word at position {} not found", target_position))?;
 
-        let correct_answer = target_word.text_uthmani.clone();
+        let texts = script::word_texts(&words, script, content_repo).await?;
+        let correct_answer = texts[target_index].clone();
 
         // Get first letter
         let first_letter = correct_answer
@@ -740,13 +722,14 @@ impl FirstLetterHintExercise {
             .ok_or_else(|| anyhow::anyhow!("Empty word text"))?;
 
         // Build verse with first letter hint
-        let verse_with_hint = words
+        let verse_with_hint = texts
             .iter()
-            .map(|w| {
-                if w.position == target_position {
+            .enumerate()
+            .map(|(i, text)| {
+                if i == target_index {
                     format!("{}ـ_____", first_letter)
                 } else {
-                    w.text_uthmani.clone()
+                    text.clone()
                 }
             })
             .collect::<Vec<String>>()
@@ -802,6 +785,78 @@ impl Exercise for FirstLetterHintExercise {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::MockContentRepository;
+    use crate::{Node, NodeType, Word};
+    use mockall::predicate::*;
+    use std::collections::HashMap;
+
+    /// Al-Fatihah 1:1 with Indopak text for its first three words only
+    fn create_mock_with_indopak() -> MockContentRepository {
+        let mut mock = MockContentRepository::new();
+        let word_node_id = node_id::encode_word_instance(1, 1, 2);
+        mock.expect_get_node()
+            .with(eq(word_node_id))
+            .returning(move |_| {
+                Ok(Some(Node {
+                    id: word_node_id,
+                    ukey: "WORD_INSTANCE:1:1:2".to_string(),
+                    node_type: NodeType::WordInstance,
+                }))
+            });
+        mock.expect_get_words_for_verse()
+            .with(eq("1:1"))
+            .returning(|_| {
+                Ok(["بِسْمِ", "ٱللَّهِ", "ٱلرَّحْمَٰنِ", "ٱلرَّحِيمِ"]
+                    .iter()
+                    .enumerate()
+                    .map(|(i, text)| Word {
+                        id: i as i64 + 1,
+                        verse_key: "1:1".to_string(),
+                        position: i as i32 + 1,
+                        text_uthmani: text.to_string(),
+                        text_simple: None,
+                        transliteration: None,
+                    })
+                    .collect())
+            });
+        let indopak = HashMap::from([
+            (node_id::encode_word_instance(1, 1, 1), "بِسۡمِ".to_string()),
+            (node_id::encode_word_instance(1, 1, 2), "اللّٰهِ".to_string()),
+            (node_id::encode_word_instance(1, 1, 3), "الرَّحۡمٰنِ".to_string()),
+        ]);
+        mock.expect_get_script_contents_batch()
+            .with(always(), eq("indopak"))
+            .returning(move |ids, _| {
+                Ok(ids
+                    .iter()
+                    .filter_map(|id| Some((*id, indopak.get(id)?.clone())))
+                    .collect())
+            });
+        mock
+    }
+
+    #[tokio::test]
+    async fn test_word_exercises_use_script() {
+        let mock = create_mock_with_indopak();
+        let word_node_id = node_id::encode_word_instance(1, 1, 2);
+
+        let mcq = MissingWordMcqExercise::new(word_node_id, 7, Script::Indopak, &mock)
+            .await
+            .unwrap();
+        // The last word has no Indopak text and falls back to Uthmani
+        assert_eq!(mcq.generate_question(), "بِسۡمِ _____ الرَّحۡمٰنِ ٱلرَّحِيمِ");
+        assert!(mcq.check_answer("اللّٰهِ"));
+
+        let cloze = ClozeDeletionExercise::new(word_node_id, false, 7, Script::Indopak, &mock)
+            .await
+            .unwrap();
+        assert_eq!(cloze.generate_question(), "بِسۡمِ _____ الرَّحۡمٰنِ ٱلرَّحِيمِ");
+
+        let hint = FirstLetterHintExercise::new(word_node_id, Script::Indopak, &mock)
+            .await
+            .unwrap();
+        assert_eq!(hint.get_first_letter(), 'ا');
+    }
 
     #[test]
     fn test_normalize_arabic() {
@@ -4,8 +4,10 @@
 // This is a simpler stateful exercise where users tap/long-press words
 // to increase their energy levels.
 
+use super::script;
 use crate::domain::node_id as nid;
 use crate::ports::{ContentRepository, UserRepository};
+use crate::Script;
 use anyhow::{anyhow, Result};
 use serde::{Deserialize, Serialize};
 
@@ -71,10 +73,12 @@ pub struct MemorizationAyahExercise {
 impl MemorizationAyahExercise {
     /// Create a new Memorization exercise from a verse node ID
     ///
-    /// Fetches all words in the verse and initializes their energy levels.
+    /// Fetches all words in the verse (in `script`) and initializes their
+    /// energy levels.
     pub async fn new(
         user_id: &str,
         verse_node_id: &str,
+        script: Script,
         content_repo: &dyn ContentRepository,
         user_repo: &dyn UserRepository,
     ) -> Result<Self> {
@@ -83,30 +87,27 @@ impl MemorizationAyahExercise {
         // Get edges from verse to find word children
         let edges = content_repo.get_edges_from(verse_id).await?;
 
-        let mut words = Vec::new();
-        for edge in edges {
-            let target_ukey = nid::to_ukey(edge.target_id).unwrap_or_default();
-            if target_ukey.starts_with(nid::PREFIX_WORD) {
-                let word_text = content_repo
-                    .get_quran_text(edge.target_id)
-                    .await?
-                    .unwrap_or_default();
-
-                // Get current energy from user state (default to 0.0)
-                let memory_state = user_repo
-                    .get_memory_state(user_id, edge.target_id)
-                    .await
-                    .ok()
-                    .flatten();
-                let energy = memory_state.map(|s| s.energy).unwrap_or(0.0);
-
-                words.push(MemorizationWord {
-                    node_id: target_ukey,
-                    text: word_text,
-                    energy,
-                });
-            }
-        }
+        let word_ids: Vec<i64> = edges
+            .iter()
+            .map(|edge| edge.target_id)
+            .filter(|&id| nid::to_ukey(id).is_some_and(|ukey| ukey.starts_with(nid::PREFIX_WORD)))
+            .collect();
+
+        // Texts and current energies (default 0.0) in one batch each
+        let mut texts = script::node_texts(&word_ids, script, content_repo).await?;
+        let energies = user_repo
+            .get_memory_basics(user_id, &word_ids)
+            .await
+            .unwrap_or_default();
+
+        let words: Vec<MemorizationWord> = word_ids
+            .into_iter()
+            .map(|id| MemorizationWord {
+                node_id: nid::to_ukey(id).unwrap_or_default(),
+                text: texts.remove(&id).unwrap_or_default(),
+                energy: energies.get(&id).map_or(0.0, |b| b.energy as f64),
+            })
+            .collect();
 
         if words.is_empty() {
             return Err(anyhow!("No words found in verse: {}", verse_node_id));
@@ -230,9 +231,8 @@ impl MemorizationAyahState {
 mod tests {
     use super::*;
     use crate::domain::{DistributionType, Edge, EdgeType};
+    use crate::scheduler_v2::MemoryBasics;
     use crate::testing::{MockContentRepository, MockUserRepository};
-    use crate::MemoryState;
-    use chrono::Utc;
     use mockall::predicate::*;
     use std::collections::HashMap;
 
@@ -269,16 +269,20 @@ mod tests {
             ])
         });
 
-        // Setup get_quran_text
-        mock.expect_get_quran_text()
-            .with(eq(nid::encode_word(101)))
-            .returning(|_| Ok(Some("بِسْمِ".to_string())));
-        mock.expect_get_quran_text()
-            .with(eq(nid::encode_word(102)))
-            .returning(|_| Ok(Some("ٱللَّهِ".to_string())));
-        mock.expect_get_quran_text()
-            .with(eq(nid::encode_word(103)))
-            .returning(|_| Ok(Some("ٱلرَّحْمَٰنِ".to_string())));
+        let texts = HashMap::from([
+            (nid::encode_word(101), "بِسْمِ".to_string()),
+            (nid::encode_word(102), "ٱللَّهِ".to_string()),
+            (nid::encode_word(103), "ٱلرَّحْمَٰنِ".to_string()),
+        ]);
+        mock.expect_get_script_contents_batch()
+            .with(always(), eq("uthmani"))
+            .times(1)
+            .returning(move |ids, _| {
+                Ok(ids
+                    .iter()
+                    .filter_map(|id| Some((*id, texts.get(id)?.clone())))
+                    .collect())
+            });
 
         mock
     }
@@ -286,22 +290,22 @@ mod tests {
     fn create_mock_user_repo(energies: HashMap<i64, f64>) -> MockUserRepository {
         let mut mock = MockUserRepository::new();
 
-        mock.expect_get_memory_state()
-            .returning(move |user_id, node_id| {
-                if let Some(&energy) = energies.get(&node_id) {
-                    Ok(Some(MemoryState {
-                        user_id: user_id.to_string(),
-                        node_id,
-                        energy,
-                        stability: 1.0,
-                        difficulty: 0.3,
-                        last_reviewed: Utc::now(),
-                        due_at: Utc::now(),
-                        review_count: 1,
-                    }))
-                } else {
-                    Ok(None)
-                }
+        mock.expect_get_memory_basics()
+            .times(1)
+            .returning(move |_, node_ids| {
+                Ok(node_ids
+                    .iter()
+                    .filter_map(|id| {
+                        let energy = *energies.get(id)?;
+                        Some((
+                            *id,
+                            MemoryBasics {
+                                energy: energy as f32,
+                                next_due_ts: 0,
+                            },
+                        ))
+                    })
+                    .collect())
             });
 
         mock
@@ -312,10 +316,15 @@ mod tests {
         let content_repo = create_mock_content_repo();
         let user_repo = create_mock_user_repo(HashMap::new());
 
-        let exercise =
-            MemorizationAyahExercise::new("test_user", "VERSE:1:1", &content_repo, &user_repo)
-                .await
-                .unwrap();
+        let exercise = MemorizationAyahExercise::new(
+            "test_user",
+            "VERSE:1:1",
+            Script::Uthmani,
+            &content_repo,
+            &user_repo,
+        )
+        .await
+        .unwrap();
 
         assert_eq!(exercise.state().words.len(), 3);
         assert_eq!(exercise.verse_node_id(), "VERSE:1:1");
@@ -326,10 +335,15 @@ mod tests {
         let content_repo = create_mock_content_repo();
         let user_repo = create_mock_user_repo(HashMap::new());
 
-        let mut exercise =
-            MemorizationAyahExercise::new("test_user", "VERSE:1:1", &content_repo, &user_repo)
-                .await
-                .unwrap();
+        let mut exercise = MemorizationAyahExercise::new(
+            "test_user",
+            "VERSE:1:1",
+            Script::Uthmani,
+            &content_repo,
+            &user_repo,
+        )
+        .await
+        .unwrap();
 
         // Initial energy is 0
         assert_eq!(exercise.state().words[0].energy, 0.0);
@@ -346,10 +360,15 @@ mod tests {
         let content_repo = create_mock_content_repo();
         let user_repo = create_mock_user_repo(HashMap::new());
 
-        let mut exercise =
-            MemorizationAyahExercise::new("test_user", "VERSE:1:1", &content_repo, &user_repo)
-                .await
-                .unwrap();
+        let mut exercise = MemorizationAyahExercise::new(
+            "test_user",
+            "VERSE:1:1",
+            Script::Uthmani,
+            &content_repo,
+            &user_repo,
+        )
+        .await
+        .unwrap();
 
         // LongPress adds 0.10
         let new_energy = exercise
@@ -366,10 +385,15 @@ mod tests {
         energies.insert(nid::encode_word(101), 0.98);
         let user_repo = create_mock_user_repo(energies);
 
-        let mut exercise =
-            MemorizationAyahExercise::new("test_user", "VERSE:1:1", &content_repo, &user_repo)
-                .await
-                .unwrap();
+        let mut exercise = MemorizationAyahExercise::new(
+            "test_user",
+            "VERSE:1:1",
+            Script::Uthmani,
+            &content_repo,
+            &user_repo,
+        )
+        .await
+        .unwrap();
 
         // LongPress at 0.98 should cap at 1.0
         let new_energy = exercise
@@ -383,10 +407,15 @@ mod tests {
         let content_repo = create_mock_content_repo();
         let user_repo = create_mock_user_repo(HashMap::new());
 
-        let mut exercise =
-            MemorizationAyahExercise::new("test_user", "VERSE:1:1", &content_repo, &user_repo)
-                .await
-                .unwrap();
+        let mut exercise = MemorizationAyahExercise::new(
+            "test_user",
+            "VERSE:1:1",
+            Script::Uthmani,
+            &content_repo,
+            &user_repo,
+        )
+        .await
+        .unwrap();
 
         // Test string-based API
         exercise.update_word_str("WORD:101", "Tap").unwrap();
@@ -398,10 +427,15 @@ mod tests {
         let content_repo = create_mock_content_repo();
         let user_repo = create_mock_user_repo(HashMap::new());
 
-        let mut exercise =
-            MemorizationAyahExercise::new("test_user", "VERSE:1:1", &content_repo, &user_repo)
-                .await
-                .unwrap();
+        let mut exercise = MemorizationAyahExercise::new(
+            "test_user",
+            "VERSE:1:1",
+            Script::Uthmani,
+            &content_repo,
+            &user_repo,
+        )
+        .await
+        .unwrap();
 
         let result = exercise.update_word("WORD:999", MemorizationAction::Tap);
         assert!(result.is_err());
@@ -417,10 +451,15 @@ mod tests {
         energies.insert(nid::encode_word(103), 0.8);
         let user_repo = create_mock_user_repo(energies);
 
-        let exercise =
-            MemorizationAyahExercise::new("test_user", "VERSE:1:1", &content_repo, &user_repo)
-                .await
-                .unwrap();
+        let exercise = MemorizationAyahExercise::new(
+            "test_user",
+            "VERSE:1:1",
+            Script::Uthmani,
+            &content_repo,
+            &user_repo,
+        )
+        .await
+        .unwrap();
 
         assert!(exercise.is_complete());
     }
@@ -435,10 +474,15 @@ mod tests {
         energies.insert(nid::encode_word(103), 0.9);
         let user_repo = create_mock_user_repo(energies);
 
-        let exercise =
-            MemorizationAyahExercise::new("test_user", "VERSE:1:1", &content_repo, &user_repo)
-                .await
-                .unwrap();
+        let exercise = MemorizationAyahExercise::new(
+            "test_user",
+            "VERSE:1:1",
+            Script::Uthmani,
+            &content_repo,
+            &user_repo,
+        )
+        .await
+        .unwrap();
 
         let avg = exercise.average_energy();
         assert!((avg - 0.6).abs() < 0.001);
@@ -454,10 +498,15 @@ mod tests {
         energies.insert(nid::encode_word(103), 0.9); // High
         let user_repo = create_mock_user_repo(energies);
 
-        let exercise =
-            MemorizationAyahExercise::new("test_user", "VERSE:1:1", &content_repo, &user_repo)
-                .await
-                .unwrap();
+        let exercise = MemorizationAyahExercise::new(
+            "test_user",
+            "VERSE:1:1",
+            Script::Uthmani,
+            &content_repo,
+            &user_repo,
+        )
+        .await
+        .unwrap();
 
         let (low, medium, high) = exercise.mastery_counts();
         assert_eq!(low, 1);
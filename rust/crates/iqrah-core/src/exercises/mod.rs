@@ -3,20 +3,30 @@
 
 mod ayah_chain;
 mod ayah_sequence;
+mod checkpoint;
+mod distractors;
 mod echo_recall;
 mod find_mistake;
 mod full_verse_input;
 mod grammar;
 mod graph;
+mod hints;
 mod mcq;
 mod memorization;
 mod memorization_ayah;
 mod pos_tagging;
+mod recitation;
 mod reverse_cloze;
+mod script;
+mod selection;
 mod service;
+mod tafsir;
+mod tajweed;
 mod translate_phrase;
 mod translation;
+mod transliteration;
 mod types;
+mod word_diff;
 
 // Modern enum-based exercise architecture
 mod exercise_data;
@@ -25,13 +35,22 @@ mod validator;
 
 // Tests are now inline in exercise_data.rs
 
-pub use ayah_chain::{AyahChainExercise, AyahChainStats};
+pub use ayah_chain::{AyahChainExercise, AyahChainStats, MistakeDetails};
 pub use ayah_sequence::AyahSequenceExercise;
+pub use checkpoint::{
+    clear_checkpoint, expire_checkpoints, load_checkpoint, save_checkpoint, Checkpointable,
+    CHECKPOINT_TTL_DAYS,
+};
+pub use distractors::{
+    pick_distractors, rank_root_distractors, DistractorDifficulty, DistractorEngine,
+    DistractorSignal, DistractorStrategy, ScoredCandidate, SemanticScoring, WordProfile,
+};
 pub use echo_recall::EchoRecallExercise;
 pub use find_mistake::FindMistakeExercise;
 pub use full_verse_input::FullVerseInputExercise;
 pub use grammar::IdentifyRootExercise;
 pub use graph::CrossVerseConnectionExercise;
+pub use hints::{hint_ladder, hint_levels, ExerciseHint};
 pub use mcq::{McqExercise, McqType};
 pub use memorization::{
     ClozeDeletionExercise, FirstLetterHintExercise, MemorizationExercise, MissingWordMcqExercise,
@@ -41,11 +60,24 @@ pub use memorization_ayah::{
     MemorizationAction, MemorizationAyahExercise, MemorizationAyahState, MemorizationWord,
 };
 pub use pos_tagging::PosTaggingExercise;
+pub use recitation::{
+    expected_words, jump_candidate_verses, word_similarity, ExpectedWord, RecitationGrade,
+    RecitationGrader, RecitationJump, RecitedWord, RecitedWordKind, TranscriptWord,
+};
 pub use reverse_cloze::ReverseClozeExercise;
+pub use script::{node_text, verse_text, word_text};
+pub use selection::{
+    rank_tafsir, rank_tajweed, AdaptivePolicy, DeterministicPolicy, ExerciseSelectionPolicy,
+    LearnerContext, LearningStage, SelectionTarget,
+};
 pub use service::ExerciseService;
+pub use tafsir::TafsirKeyLessonExercise;
+pub use tajweed::IdentifyTajweedRuleExercise;
 pub use translate_phrase::TranslatePhraseExercise;
 pub use translation::{ContextualTranslationExercise, TranslationExercise};
+pub use transliteration::{normalize_transliteration, transliteration_similarity};
 pub use types::{Exercise, ExerciseResponse, ExerciseType};
+pub use word_diff::{diff_words, GradeThresholds, WordDiff, WordEdit, WordEditKind};
 
 // Export modern enum-based architecture
 pub use exercise_data::ExerciseData;
@@ -5,7 +5,9 @@ use super::types::Exercise;
 use crate::domain::node_id;
 use crate::{ContentRepository, KnowledgeNode};
 use anyhow::Result;
+use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
+use rand::SeedableRng;
 
 // ============================================================================
 // Exercise 22: Part of Speech Tagging
@@ -30,7 +32,13 @@ impl PosTaggingExercise {
     /// - Word text
     /// - Morphology segments with POS tags
     /// - Verse context
-    pub async fn new(word_node_id: i64, content_repo: &dyn ContentRepository) -> Result<Self> {
+    ///
+    /// Options are shuffled with `seed`.
+    pub async fn new(
+        word_node_id: i64,
+        seed: u64,
+        content_repo: &dyn ContentRepository,
+    ) -> Result<Self> {
         // Get the node to access its ukey
         let node = content_repo
             .get_node(word_node_id)
@@ -100,7 +108,7 @@ impl PosTaggingExercise {
             .ok_or_else(|| anyhow::anyhow!("Verse text not found: {}", verse_ukey))?;
 
         // Generate MCQ options
-        let options = Self::generate_options(&correct_pos);
+        let options = Self::generate_options(&correct_pos, seed);
 
         Ok(Self {
             node_id: word_node_id,
@@ -113,7 +121,7 @@ impl PosTaggingExercise {
 
     /// Generate MCQ options for part of speech
     /// Returns shuffled options including the correct answer
-    fn generate_options(correct_pos: &str) -> Vec<String> {
+    fn generate_options(correct_pos: &str, seed: u64) -> Vec<String> {
         let all_pos = [
             "noun".to_string(),
             "verb".to_string(),
@@ -121,7 +129,6 @@ impl PosTaggingExercise {
             "pronoun".to_string(),
         ];
 
-        let mut rng = rand::thread_rng();
         let mut options = Vec::new();
 
         // Add correct answer
@@ -140,7 +147,7 @@ impl PosTaggingExercise {
         }
 
         // Shuffle options
-        options.shuffle(&mut rng);
+        options.shuffle(&mut StdRng::seed_from_u64(seed));
         options
     }
 
@@ -206,8 +213,18 @@ impl Exercise for PosTaggingExercise {
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+
     #[test]
     fn test_placeholder() {
         // POS tagging tests require database setup
     }
+
+    #[test]
+    fn test_generate_options_is_seeded() {
+        let options = PosTaggingExercise::generate_options("verb", 7);
+        assert_eq!(options, PosTaggingExercise::generate_options("verb", 7));
+        assert_eq!(options.len(), 4);
+        assert!(options.contains(&"verb".to_string()));
+    }
 }
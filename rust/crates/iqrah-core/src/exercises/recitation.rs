@@ -0,0 +1,616 @@
+// exercises/recitation.rs
+// Grading of a recitation from its speech-recognition transcript
+//
+// Any ASR can plug in: the grader only needs the recognized words, with
+// per-word timestamps when the recognizer provides them. The transcript is
+// aligned against the expected words of a verse range using the normalized
+// character edit distance of each word pair, so recognition noise on a
+// single word still aligns as a close match. Transcript words left over by
+// the alignment are classified as repetitions (the reciter went back over
+// words), jumps into another verse, or extra words.
+
+use super::memorization::MemorizationExercise;
+use super::word_diff::{char_similarity, GradeThresholds};
+use crate::domain::node_id;
+use crate::{ContentRepository, ReviewGrade};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+/// One recognized word of a transcript
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TranscriptWord {
+    pub text: String,
+    /// Start of the word in the audio, when the recognizer reports timings
+    pub start_ms: Option<u64>,
+    /// End of the word in the audio
+    pub end_ms: Option<u64>,
+}
+
+impl TranscriptWord {
+    /// Words of a plain-text transcript, without timings
+    pub fn from_text(transcript: &str) -> Vec<Self> {
+        transcript
+            .split_whitespace()
+            .map(|text| Self {
+                text: text.to_string(),
+                start_ms: None,
+                end_ms: None,
+            })
+            .collect()
+    }
+}
+
+/// A word the reciter was expected to say
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExpectedWord {
+    /// Word-instance node ID
+    pub node_id: i64,
+    pub verse_key: String,
+    /// 1-indexed position in the verse
+    pub position: i32,
+    pub text: String,
+}
+
+/// How an expected word was recited
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecitedWordKind {
+    /// Heard exactly (after normalization)
+    Correct,
+    /// Heard with small differences (mispronounced or misrecognized)
+    Close,
+    /// Another word was said in its place
+    Substituted,
+    /// Not said
+    Skipped,
+}
+
+/// Outcome for one expected word
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecitedWord {
+    pub expected: ExpectedWord,
+    pub kind: RecitedWordKind,
+    /// Transcript word aligned with it
+    pub heard: Option<String>,
+    /// Similarity of the heard word (0.0 to 1.0)
+    pub similarity: f64,
+    /// Whether the reciter went back and said the word again
+    pub repeated: bool,
+    pub start_ms: Option<u64>,
+    pub end_ms: Option<u64>,
+    /// Grade to record for the word
+    pub grade: ReviewGrade,
+}
+
+/// A run of the transcript recited from another verse
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecitationJump {
+    /// Verse the run starts in
+    pub verse_key: String,
+    /// Position in that verse of the first word of the run
+    pub start_position: i32,
+    /// Number of words recited from the other verse
+    pub word_count: usize,
+    /// Last expected word recited before the jump
+    pub after_node_id: Option<i64>,
+    pub start_ms: Option<u64>,
+}
+
+/// Graded recitation of a verse range
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecitationGrade {
+    /// One entry per expected word, in order
+    pub words: Vec<RecitedWord>,
+    pub jumps: Vec<RecitationJump>,
+    /// Transcript words matching nothing expected
+    pub extra_words: Vec<TranscriptWord>,
+    /// Mean word credit: 1.0 for correct, the similarity for close words,
+    /// 0.0 for substituted and skipped words
+    pub accuracy: f64,
+    /// Grade for the whole range
+    pub grade: ReviewGrade,
+}
+
+/// Aligns a transcript against the expected words and grades each word
+///
+/// Per-word grades: correct words are Good (Hard when the reciter had to go
+/// back over them), close words Hard, substituted and skipped words Again.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RecitationGrader {
+    /// Minimum similarity for a heard word to count as the expected word
+    pub match_similarity: f64,
+    /// Minimum run of words from another verse to report as a jump
+    pub min_jump_words: usize,
+    /// Accuracy thresholds for the range grade
+    pub thresholds: GradeThresholds,
+}
+
+impl Default for RecitationGrader {
+    fn default() -> Self {
+        Self {
+            match_similarity: 0.75,
+            min_jump_words: 3,
+            thresholds: GradeThresholds::default(),
+        }
+    }
+}
+
+/// One step of the alignment
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Step {
+    /// Expected word i aligned with transcript word j
+    Pair(usize, usize),
+    /// Expected word i not heard
+    Skip(usize),
+    /// Transcript word j not expected
+    Extra(usize),
+}
+
+impl RecitationGrader {
+    /// Grade `transcript` against `expected`
+    ///
+    /// `jump_candidates` are the words of verses the reciter may drift into
+    /// (similar verses, the verses after the range), in mushaf order.
+    pub fn grade(
+        &self,
+        expected: &[ExpectedWord],
+        transcript: &[TranscriptWord],
+        jump_candidates: &[ExpectedWord],
+    ) -> RecitationGrade {
+        let exp_norm: Vec<Vec<char>> = expected.iter().map(|w| normalized(&w.text)).collect();
+        let heard_norm: Vec<Vec<char>> = transcript.iter().map(|w| normalized(&w.text)).collect();
+        let similarity: Vec<Vec<f64>> = exp_norm
+            .iter()
+            .map(|e| heard_norm.iter().map(|h| char_similarity(e, h)).collect())
+            .collect();
+
+        let steps = self.align(&similarity, expected.len(), transcript.len());
+
+        let mut words: Vec<RecitedWord> = expected
+            .iter()
+            .map(|w| RecitedWord {
+                expected: w.clone(),
+                kind: RecitedWordKind::Skipped,
+                heard: None,
+                similarity: 0.0,
+                repeated: false,
+                start_ms: None,
+                end_ms: None,
+                grade: ReviewGrade::Again,
+            })
+            .collect();
+        // Expected word each transcript word is aligned with
+        let mut aligned_to: Vec<Option<usize>> = vec![None; transcript.len()];
+        for step in &steps {
+            if let Step::Pair(i, j) = *step {
+                let sim = similarity[i][j];
+                let heard = &transcript[j];
+                let word = &mut words[i];
+                word.kind = if sim >= 1.0 {
+                    RecitedWordKind::Correct
+                } else if sim >= self.match_similarity {
+                    RecitedWordKind::Close
+                } else {
+                    RecitedWordKind::Substituted
+                };
+                word.heard = Some(heard.text.clone());
+                word.similarity = sim;
+                word.start_ms = heard.start_ms;
+                word.end_ms = heard.end_ms;
+                aligned_to[j] = Some(i);
+            }
+        }
+
+        // Classify runs of transcript words that match nothing expected
+        let mut jumps = Vec::new();
+        let mut extra_words = Vec::new();
+        let is_unmatched = |aligned: &[Option<usize>], words: &[RecitedWord], j: usize| {
+            aligned[j].is_none_or(|i| words[i].kind == RecitedWordKind::Substituted)
+        };
+        let mut j = 0;
+        while j < transcript.len() {
+            if !is_unmatched(&aligned_to, &words, j) {
+                j += 1;
+                continue;
+            }
+            let start = j;
+            while j < transcript.len() && is_unmatched(&aligned_to, &words, j) {
+                j += 1;
+            }
+            let run = start..j;
+
+            let before = aligned_to[..start].iter().rev().flatten().next().copied();
+            let after = aligned_to[j..].iter().flatten().next().copied();
+
+            if run.clone().all(|t| aligned_to[t].is_none()) {
+                if let Some(repeated) =
+                    self.repeated_words(&heard_norm[run.clone()], &exp_norm, before, after)
+                {
+                    for i in repeated {
+                        words[i].repeated = true;
+                    }
+                    continue;
+                }
+            }
+
+            let run_words = &heard_norm[run.clone()];
+            if let Some((candidate, length)) = self.jump_source(run_words, jump_candidates) {
+                let source = &jump_candidates[candidate];
+                jumps.push(RecitationJump {
+                    verse_key: source.verse_key.clone(),
+                    start_position: source.position,
+                    word_count: length,
+                    after_node_id: before.map(|i| expected[i].node_id),
+                    start_ms: transcript[start].start_ms,
+                });
+                // Expected words the jumped-to words were aligned with were skipped
+                for aligned in &mut aligned_to[start..start + length] {
+                    if let Some(i) = aligned.take() {
+                        let word = &mut words[i];
+                        word.kind = RecitedWordKind::Skipped;
+                        word.heard = None;
+                        word.similarity = 0.0;
+                        word.start_ms = None;
+                        word.end_ms = None;
+                    }
+                }
+                extra_words.extend(
+                    (start + length..j)
+                        .filter(|t| aligned_to[*t].is_none())
+                        .map(|t| transcript[t].clone()),
+                );
+            } else {
+                extra_words.extend(
+                    run.filter(|t| aligned_to[*t].is_none())
+                        .map(|t| transcript[t].clone()),
+                );
+            }
+        }
+
+        for word in &mut words {
+            word.grade = match word.kind {
+                RecitedWordKind::Correct if !word.repeated => ReviewGrade::Good,
+                RecitedWordKind::Correct | RecitedWordKind::Close => ReviewGrade::Hard,
+                RecitedWordKind::Substituted | RecitedWordKind::Skipped => ReviewGrade::Again,
+            };
+        }
+
+        let accuracy = if words.is_empty() {
+            1.0
+        } else {
+            words
+                .iter()
+                .map(|w| match w.kind {
+                    RecitedWordKind::Correct => 1.0,
+                    RecitedWordKind::Close => w.similarity,
+                    RecitedWordKind::Substituted | RecitedWordKind::Skipped => 0.0,
+                })
+                .sum::<f64>()
+                / words.len() as f64
+        };
+
+        RecitationGrade {
+            words,
+            jumps,
+            extra_words,
+            accuracy,
+            grade: self.thresholds.grade(accuracy),
+        }
+    }
+
+    /// Minimum-cost alignment: pairing costs `1 - similarity` for close words
+    /// and 1 for a substitution; skipping or adding a word costs 1
+    fn align(&self, similarity: &[Vec<f64>], n: usize, m: usize) -> Vec<Step> {
+        let pair_cost = |i: usize, j: usize| {
+            let sim = similarity[i][j];
+            if sim >= self.match_similarity {
+                1.0 - sim
+            } else {
+                1.0
+            }
+        };
+
+        let mut cost = vec![vec![0.0f64; m + 1]; n + 1];
+        for (i, row) in cost.iter_mut().enumerate() {
+            row[0] = i as f64;
+        }
+        for (j, cell) in cost[0].iter_mut().enumerate() {
+            *cell = j as f64;
+        }
+        for i in 1..=n {
+            for j in 1..=m {
+                cost[i][j] = (cost[i - 1][j - 1] + pair_cost(i - 1, j - 1))
+                    .min(cost[i - 1][j] + 1.0)
+                    .min(cost[i][j - 1] + 1.0);
+            }
+        }
+
+        let mut steps = Vec::with_capacity(n.max(m));
+        let (mut i, mut j) = (n, m);
+        while i > 0 || j > 0 {
+            if i > 0 && j > 0 && same(cost[i][j], cost[i - 1][j - 1] + pair_cost(i - 1, j - 1)) {
+                steps.push(Step::Pair(i - 1, j - 1));
+                i -= 1;
+                j -= 1;
+            } else if i > 0 && same(cost[i][j], cost[i - 1][j] + 1.0) {
+                steps.push(Step::Skip(i - 1));
+                i -= 1;
+            } else {
+                steps.push(Step::Extra(j - 1));
+                j -= 1;
+            }
+        }
+        steps.reverse();
+        steps
+    }
+
+    /// Expected words a run of added words repeats: the words just before the
+    /// run (going back) or just after it (a false start)
+    fn repeated_words(
+        &self,
+        run: &[Vec<char>],
+        expected: &[Vec<char>],
+        before: Option<usize>,
+        after: Option<usize>,
+    ) -> Option<std::ops::Range<usize>> {
+        let matches = |range: &std::ops::Range<usize>| {
+            range.end <= expected.len()
+                && run
+                    .iter()
+                    .zip(&expected[range.clone()])
+                    .all(|(h, e)| char_similarity(e, h) >= self.match_similarity)
+        };
+        let k = run.len();
+        let going_back = before
+            .filter(|&i| i + 1 >= k)
+            .map(|i| i + 1 - k..i + 1)
+            .filter(matches);
+        going_back.or_else(|| after.map(|i| i..i + k).filter(matches))
+    }
+
+    /// Longest stretch of `run`, from its first word, recited from the
+    /// candidate words: (index of the first candidate word, length)
+    fn jump_source(
+        &self,
+        run: &[Vec<char>],
+        candidates: &[ExpectedWord],
+    ) -> Option<(usize, usize)> {
+        let candidates: Vec<Vec<char>> = candidates.iter().map(|w| normalized(&w.text)).collect();
+        (0..candidates.len())
+            .map(|start| {
+                let length = run
+                    .iter()
+                    .zip(&candidates[start..])
+                    .take_while(|(h, c)| char_similarity(c, h) >= self.match_similarity)
+                    .count();
+                (start, length)
+            })
+            .filter(|&(_, length)| length >= self.min_jump_words)
+            .max_by_key(|&(start, length)| (length, std::cmp::Reverse(start)))
+    }
+}
+
+/// Similarity of two words (0.0 to 1.0) from the character edit distance of
+/// their normalized forms
+pub fn word_similarity(expected: &str, heard: &str) -> f64 {
+    char_similarity(&normalized(expected), &normalized(heard))
+}
+
+/// The expected words of a verse range, in order
+pub async fn expected_words(
+    verse_keys: &[String],
+    content_repo: &dyn ContentRepository,
+) -> Result<Vec<ExpectedWord>> {
+    let mut expected = Vec::new();
+    for verse_key in verse_keys {
+        let (chapter, verse) = verse_key
+            .split_once(':')
+            .and_then(|(c, v)| Some((c.parse().ok()?, v.parse().ok()?)))
+            .ok_or_else(|| anyhow::anyhow!("Invalid verse key: {}", verse_key))?;
+        let mut words = content_repo.get_words_for_verse(verse_key).await?;
+        words.sort_by_key(|w| w.position);
+        expected.extend(words.into_iter().map(|w| ExpectedWord {
+            node_id: node_id::encode_word_instance(chapter, verse, w.position as u8),
+            verse_key: verse_key.clone(),
+            position: w.position,
+            text: w.text_uthmani,
+        }));
+    }
+    Ok(expected)
+}
+
+/// Verses a reciter of `verse_keys` may jump into: the verses similar to
+/// each one (mutashabihat) and the two verses after the range
+pub async fn jump_candidate_verses(
+    verse_keys: &[String],
+    content_repo: &dyn ContentRepository,
+) -> Result<Vec<String>> {
+    let mut candidates: Vec<String> = Vec::new();
+    let mut push = |key: String| {
+        if !verse_keys.contains(&key) && !candidates.contains(&key) {
+            candidates.push(key);
+        }
+    };
+
+    if let Some(last) = verse_keys.last() {
+        if let Some(verse) = content_repo.get_verse(last).await? {
+            let following = content_repo
+                .get_verses_for_chapter(verse.chapter_number)
+                .await?
+                .into_iter()
+                .filter(|v| v.verse_number > verse.verse_number)
+                .take(2);
+            for v in following {
+                push(v.key);
+            }
+        }
+    }
+    for verse_key in verse_keys {
+        for similar in content_repo.get_similar_verses(verse_key, 5).await? {
+            push(similar.verse_key);
+        }
+    }
+    Ok(candidates)
+}
+
+fn normalized(text: &str) -> Vec<char> {
+    MemorizationExercise::normalize_arabic(text)
+        .chars()
+        .collect()
+}
+
+fn same(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-9
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(verse_key: &str, text: &str) -> Vec<ExpectedWord> {
+        let (chapter, verse) = verse_key.split_once(':').unwrap();
+        text.split_whitespace()
+            .enumerate()
+            .map(|(i, w)| ExpectedWord {
+                node_id: node_id::encode_word_instance(
+                    chapter.parse().unwrap(),
+                    verse.parse().unwrap(),
+                    i as u8 + 1,
+                ),
+                verse_key: verse_key.to_string(),
+                position: i as i32 + 1,
+                text: w.to_string(),
+            })
+            .collect()
+    }
+
+    fn kinds(grade: &RecitationGrade) -> Vec<RecitedWordKind> {
+        grade.words.iter().map(|w| w.kind).collect()
+    }
+
+    const IKHLAS_1: &str = "قُلْ هُوَ ٱللَّهُ أَحَدٌ";
+
+    #[test]
+    fn test_exact_and_noisy_recitation() {
+        let grader = RecitationGrader::default();
+        let expected = words("112:1", IKHLAS_1);
+
+        let grade = grader.grade(&expected, &TranscriptWord::from_text("قل هو الله احد"), &[]);
+        assert!(grade
+            .words
+            .iter()
+            .all(|w| w.kind == RecitedWordKind::Correct));
+        assert_eq!(grade.accuracy, 1.0);
+        assert_eq!(grade.grade, ReviewGrade::Easy);
+        assert!(grade.words.iter().all(|w| w.grade == ReviewGrade::Good));
+
+        // One letter misrecognized: a close match, not a substitution
+        let grade = grader.grade(&expected, &TranscriptWord::from_text("قل هو اللاه احد"), &[]);
+        assert_eq!(grade.words[2].kind, RecitedWordKind::Close);
+        assert_eq!(grade.words[2].grade, ReviewGrade::Hard);
+        assert!(grade.accuracy > 0.9 && grade.accuracy < 1.0);
+    }
+
+    #[test]
+    fn test_skipped_and_substituted_words() {
+        let grader = RecitationGrader::default();
+        let expected = words("112:1", IKHLAS_1);
+
+        let grade = grader.grade(&expected, &TranscriptWord::from_text("قل الله احد"), &[]);
+        assert_eq!(
+            kinds(&grade),
+            vec![
+                RecitedWordKind::Correct,
+                RecitedWordKind::Skipped,
+                RecitedWordKind::Correct,
+                RecitedWordKind::Correct
+            ]
+        );
+        assert_eq!(grade.words[1].grade, ReviewGrade::Again);
+
+        let grade = grader.grade(&expected, &TranscriptWord::from_text("قل هو الرب احد"), &[]);
+        assert_eq!(grade.words[2].kind, RecitedWordKind::Substituted);
+        assert_eq!(grade.words[2].heard.as_deref(), Some("الرب"));
+        assert_eq!(grade.accuracy, 0.75);
+        assert_eq!(grade.grade, ReviewGrade::Hard);
+    }
+
+    #[test]
+    fn test_repetition_is_not_an_error() {
+        let grader = RecitationGrader::default();
+        let expected = words("112:1", IKHLAS_1);
+        let transcript = vec![
+            ("قل", 0),
+            ("هو", 400),
+            ("الله", 800),
+            ("هو", 1500),
+            ("الله", 1900),
+            ("احد", 2400),
+        ]
+        .into_iter()
+        .map(|(text, start)| TranscriptWord {
+            text: text.to_string(),
+            start_ms: Some(start),
+            end_ms: Some(start + 350),
+        })
+        .collect::<Vec<_>>();
+
+        let grade = grader.grade(&expected, &transcript, &[]);
+        assert!(grade.extra_words.is_empty());
+        assert!(grade
+            .words
+            .iter()
+            .all(|w| w.kind == RecitedWordKind::Correct));
+        let repeated: Vec<bool> = grade.words.iter().map(|w| w.repeated).collect();
+        assert_eq!(repeated, vec![false, true, true, false]);
+        assert_eq!(grade.words[1].grade, ReviewGrade::Hard);
+        assert_eq!(grade.words[3].start_ms, Some(2400));
+    }
+
+    #[test]
+    fn test_jump_to_another_verse() {
+        let grader = RecitationGrader::default();
+        let mut expected = words("112:1", IKHLAS_1);
+        expected.extend(words("112:2", "ٱللَّهُ ٱلصَّمَدُ"));
+        let mut candidates = words("112:3", "لَمْ يَلِدْ وَلَمْ يُولَدْ");
+        candidates.extend(words("112:4", "وَلَمْ يَكُن لَّهُۥ كُفُوًا أَحَدٌۢ"));
+
+        // 112:2 is skipped: the reciter goes straight on to 112:3
+        let transcript = TranscriptWord::from_text("قل هو الله احد لم يلد ولم يولد");
+        let grade = grader.grade(&expected, &transcript, &candidates);
+
+        assert_eq!(grade.jumps.len(), 1);
+        let jump = &grade.jumps[0];
+        assert_eq!(jump.verse_key, "112:3");
+        assert_eq!(jump.start_position, 1);
+        assert_eq!(jump.word_count, 4);
+        assert_eq!(jump.after_node_id, Some(expected[3].node_id));
+        assert_eq!(
+            kinds(&grade)[4..],
+            [RecitedWordKind::Skipped; 2],
+            "Words replaced by the jump were not recited"
+        );
+        assert!(grade.extra_words.is_empty());
+
+        // Without candidates the same words are substitutions and extras
+        let grade = grader.grade(&expected, &transcript, &[]);
+        assert!(grade.jumps.is_empty());
+        assert_eq!(grade.words[4].kind, RecitedWordKind::Substituted);
+        assert_eq!(grade.extra_words.len(), 2);
+    }
+
+    #[test]
+    fn test_unrelated_extra_words() {
+        let grader = RecitationGrader::default();
+        let expected = words("112:1", IKHLAS_1);
+        let grade = grader.grade(
+            &expected,
+            &TranscriptWord::from_text("قل هو الله احد صدق"),
+            &[],
+        );
+        assert_eq!(grade.extra_words.len(), 1);
+        assert_eq!(grade.extra_words[0].text, "صدق");
+        assert_eq!(grade.grade, ReviewGrade::Easy);
+        assert!(word_similarity("ٱللَّهُ", "الله") == 1.0);
+    }
+}
@@ -0,0 +1,210 @@
+// exercises/script.rs
+// Quranic text in the user's preferred script
+//
+// Exercises read verse and word text through these helpers so prompts, tiles
+// and answer keys follow the chosen `Script`. Text missing in that script
+// falls back to Uthmani (and Simple falls back to the `text_simple` column
+// first).
+
+use crate::domain::node_id;
+use crate::{ContentRepository, Script, Verse, Word};
+use anyhow::Result;
+use std::collections::HashMap;
+
+/// Text of a Quranic node (verse, word or word instance) in `script`
+pub async fn node_text(
+    node_id: i64,
+    script: Script,
+    content_repo: &dyn ContentRepository,
+) -> Result<Option<String>> {
+    if let Some(text) = script_content(&[node_id], script, content_repo).await? {
+        return Ok(Some(text));
+    }
+    content_repo.get_quran_text(node_id).await
+}
+
+/// Texts of many Quranic nodes in `script`, batched
+///
+/// One query for the script, one for the Uthmani fallback; only nodes missing
+/// from both are looked up one by one. Nodes without any text are left out.
+pub async fn node_texts(
+    node_ids: &[i64],
+    script: Script,
+    content_repo: &dyn ContentRepository,
+) -> Result<HashMap<i64, String>> {
+    let mut texts = if script == Script::Uthmani {
+        HashMap::new()
+    } else {
+        content_repo
+            .get_script_contents_batch(node_ids, script.as_str())
+            .await?
+    };
+
+    let missing: Vec<i64> = missing_ids(node_ids, &texts);
+    if !missing.is_empty() {
+        texts.extend(
+            content_repo
+                .get_script_contents_batch(&missing, Script::Uthmani.as_str())
+                .await?,
+        );
+    }
+    for node_id in missing_ids(node_ids, &texts) {
+        if let Some(text) = content_repo.get_quran_text(node_id).await? {
+            texts.insert(node_id, text);
+        }
+    }
+    Ok(texts)
+}
+
+fn missing_ids(node_ids: &[i64], texts: &HashMap<i64, String>) -> Vec<i64> {
+    node_ids
+        .iter()
+        .copied()
+        .filter(|id| !texts.contains_key(id))
+        .collect()
+}
+
+/// Text of a verse in `script`
+pub async fn verse_text(
+    verse: &Verse,
+    script: Script,
+    content_repo: &dyn ContentRepository,
+) -> Result<String> {
+    let id = node_id::encode_verse(verse.chapter_number as u8, verse.verse_number as u16);
+    Ok(script_content(&[id], script, content_repo)
+        .await?
+        .or_else(|| simple_column(script, verse.text_simple.as_ref()))
+        .unwrap_or_else(|| verse.text_uthmani.clone()))
+}
+
+/// Text of a word in `script`
+///
+/// Script content may be keyed by the word-instance node or the word node.
+pub async fn word_text(
+    word: &Word,
+    script: Script,
+    content_repo: &dyn ContentRepository,
+) -> Result<String> {
+    let ids = word_content_ids(word);
+    Ok(script_content(&ids, script, content_repo)
+        .await?
+        .or_else(|| simple_column(script, word.text_simple.as_ref()))
+        .unwrap_or_else(|| word.text_uthmani.clone()))
+}
+
+/// Texts of many words in `script`, in order, with a single lookup
+///
+/// Same fallbacks as `word_text`.
+pub async fn word_texts(
+    words: &[Word],
+    script: Script,
+    content_repo: &dyn ContentRepository,
+) -> Result<Vec<String>> {
+    let ids: Vec<Vec<i64>> = words.iter().map(word_content_ids).collect();
+    let contents = if script == Script::Uthmani {
+        HashMap::new()
+    } else {
+        let all_ids: Vec<i64> = ids.iter().flatten().copied().collect();
+        content_repo
+            .get_script_contents_batch(&all_ids, script.as_str())
+            .await?
+    };
+
+    Ok(words
+        .iter()
+        .zip(&ids)
+        .map(|(word, ids)| {
+            ids.iter()
+                .find_map(|id| contents.get(id).cloned())
+                .or_else(|| simple_column(script, word.text_simple.as_ref()))
+                .unwrap_or_else(|| word.text_uthmani.clone())
+        })
+        .collect())
+}
+
+/// Node IDs script content may be keyed by for a word: its word-instance
+/// node, then its word node
+fn word_content_ids(word: &Word) -> Vec<i64> {
+    let mut ids = Vec::with_capacity(2);
+    if let Some((chapter, verse)) = node_id::from_ukey(&node_id::verse_from_key(&word.verse_key))
+        .and_then(node_id::decode_verse)
+    {
+        ids.push(node_id::encode_word_instance(
+            chapter,
+            verse,
+            word.position as u8,
+        ));
+    }
+    ids.push(node_id::encode_word(word.id));
+    ids
+}
+
+/// First `script_contents` text found for `ids`; None for Uthmani, which the
+/// callers already have
+async fn script_content(
+    ids: &[i64],
+    script: Script,
+    content_repo: &dyn ContentRepository,
+) -> Result<Option<String>> {
+    if script == Script::Uthmani {
+        return Ok(None);
+    }
+    for &id in ids {
+        if let Some(text) = content_repo.get_script_content(id, script.as_str()).await? {
+            return Ok(Some(text));
+        }
+    }
+    Ok(None)
+}
+
+fn simple_column(script: Script, text_simple: Option<&String>) -> Option<String> {
+    text_simple.filter(|_| script == Script::Simple).cloned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockContentRepository;
+    use mockall::predicate::eq;
+
+    fn word() -> Word {
+        Word {
+            id: 1,
+            verse_key: "1:1".to_string(),
+            position: 1,
+            text_uthmani: "بِسْمِ".to_string(),
+            text_simple: Some("بسم".to_string()),
+            transliteration: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_uthmani_needs_no_lookup() {
+        let repo = MockContentRepository::new();
+        let text = word_text(&word(), Script::Uthmani, &repo).await.unwrap();
+        assert_eq!(text, "بِسْمِ");
+    }
+
+    #[tokio::test]
+    async fn test_script_content_preferred() {
+        let mut repo = MockContentRepository::new();
+        let instance_id = node_id::encode_word_instance(1, 1, 1);
+        repo.expect_get_script_content()
+            .with(eq(instance_id), eq("indopak"))
+            .returning(|_, _| Ok(Some("بِسۡمِ".to_string())));
+
+        let text = word_text(&word(), Script::Indopak, &repo).await.unwrap();
+        assert_eq!(text, "بِسۡمِ");
+    }
+
+    #[tokio::test]
+    async fn test_missing_script_falls_back() {
+        let mut repo = MockContentRepository::new();
+        repo.expect_get_script_content().returning(|_, _| Ok(None));
+
+        let simple = word_text(&word(), Script::Simple, &repo).await.unwrap();
+        assert_eq!(simple, "بسم");
+        let indopak = word_text(&word(), Script::Indopak, &repo).await.unwrap();
+        assert_eq!(indopak, "بِسْمِ");
+    }
+}
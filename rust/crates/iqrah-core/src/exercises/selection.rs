@@ -0,0 +1,426 @@
+// exercises/selection.rs
+// Exercise type selection policies driven by learning state
+
+use crate::domain::node_id::{PREFIX_CHAPTER, PREFIX_VERSE, PREFIX_WORD, PREFIX_WORD_INSTANCE};
+use crate::{ArabicLevel, MemoryState, ReviewGrade, SessionItem};
+
+/// Granularity of the node an exercise is generated for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectionTarget {
+    Word,
+    Verse,
+    Chapter,
+}
+
+impl SelectionTarget {
+    /// Classify a base (axis-stripped) ukey
+    pub fn from_ukey(base_ukey: &str) -> Option<Self> {
+        if base_ukey.starts_with(PREFIX_WORD) || base_ukey.starts_with(PREFIX_WORD_INSTANCE) {
+            Some(Self::Word)
+        } else if base_ukey.starts_with(PREFIX_VERSE) {
+            Some(Self::Verse)
+        } else if base_ukey.starts_with(PREFIX_CHAPTER) {
+            Some(Self::Chapter)
+        } else {
+            None
+        }
+    }
+}
+
+/// Coarse learning stage derived from a `LearnerContext`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LearningStage {
+    /// Never reviewed
+    New,
+    /// Low energy or repeated recent failures
+    Fragile,
+    /// Partially consolidated
+    Developing,
+    /// Solid recall, long intervals
+    Mature,
+    /// Very high energy and stability; verification only
+    NearMastery,
+}
+
+/// Snapshot of the learner's state on a single node
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LearnerContext {
+    pub energy: f64,
+    pub stability: f64,
+    pub review_count: u32,
+    /// Number of failed attempts (grade Again) among recent attempts
+    pub recent_failures: u32,
+    /// Exercise type names used on this node, most recent first
+    pub recent_exercise_types: Vec<String>,
+    /// Learner's Arabic reading level, when known (from initial placement)
+    pub arabic_level: Option<ArabicLevel>,
+}
+
+impl LearnerContext {
+    /// Build a context from the node's memory state and its recent session items
+    /// (most recent first).
+    pub fn from_history(state: Option<&MemoryState>, recent_items: &[SessionItem]) -> Self {
+        let (energy, stability, review_count) = state
+            .map(|s| (s.energy, s.stability, s.review_count))
+            .unwrap_or_default();
+
+        Self {
+            energy,
+            stability,
+            review_count,
+            recent_failures: recent_items
+                .iter()
+                .filter(|item| item.grade == ReviewGrade::Again as i32)
+                .count() as u32,
+            recent_exercise_types: recent_items
+                .iter()
+                .map(|item| item.exercise_type.clone())
+                .collect(),
+            arabic_level: None,
+        }
+    }
+
+    /// Set the learner's Arabic reading level
+    pub fn with_arabic_level(mut self, level: Option<ArabicLevel>) -> Self {
+        self.arabic_level = level;
+        self
+    }
+
+    /// Whether word exercises should bridge through transliteration
+    pub fn needs_transliteration(&self) -> bool {
+        self.arabic_level
+            .is_some_and(|level| level.needs_transliteration())
+    }
+
+    /// Classify the learner's stage on this node
+    pub fn stage(&self) -> LearningStage {
+        if self.review_count == 0 {
+            return LearningStage::New;
+        }
+        if self.recent_failures >= 2 || self.energy < 0.3 {
+            return LearningStage::Fragile;
+        }
+        if self.recent_failures == 1 || self.energy < 0.6 || self.stability < 7.0 {
+            return LearningStage::Developing;
+        }
+        if self.energy >= 0.9 && self.stability >= 30.0 {
+            return LearningStage::NearMastery;
+        }
+        LearningStage::Mature
+    }
+}
+
+/// Policy that ranks exercise types for a node
+///
+/// The service tries the ranked types in order and keeps the first one that
+/// generates successfully and passes the axis guardrails. Policies are pure so
+/// they can be evaluated offline (e.g. by ISS) without a content database.
+pub trait ExerciseSelectionPolicy: Send + Sync {
+    /// Short identifier for logging and evaluation reports
+    fn name(&self) -> &'static str;
+
+    /// Exercise type names (see `ExerciseData::type_name`), best first
+    fn rank(
+        &self,
+        node_id: i64,
+        target: SelectionTarget,
+        ctx: &LearnerContext,
+    ) -> Vec<&'static str>;
+}
+
+/// Node-type routing with stable per-node variety; ignores learning state.
+///
+/// This is the routing used by `ExerciseService::generate_exercise_v2`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DeterministicPolicy;
+
+impl ExerciseSelectionPolicy for DeterministicPolicy {
+    fn name(&self) -> &'static str {
+        "deterministic"
+    }
+
+    fn rank(
+        &self,
+        node_id: i64,
+        target: SelectionTarget,
+        _ctx: &LearnerContext,
+    ) -> Vec<&'static str> {
+        match target {
+            // C-007: promote lexical core exercises in scheduled default pool.
+            SelectionTarget::Word => match deterministic_slot(node_id, 3) {
+                0 => vec!["mcq_ar_to_en"],
+                1 => vec!["contextual_translation"],
+                _ => vec!["identify_root", "mcq_ar_to_en"],
+            },
+            // C-008: prefer continuity MCQ family over full-verse typing.
+            SelectionTarget::Verse => {
+                let primary = if deterministic_slot(node_id, 2) == 0 {
+                    "missing_word_mcq"
+                } else {
+                    "next_word_mcq"
+                };
+                vec![primary, "echo_recall", "full_verse_input"]
+            }
+            SelectionTarget::Chapter => vec!["ayah_chain"],
+        }
+    }
+}
+
+/// Learning-state driven selection.
+///
+/// Recognition formats (MCQ, cloze) while a node is fragile, production
+/// formats (full verse input) once mature (root, lemma family and grammar
+/// feature work for words), and `find_mistake` for near-mastery verification.
+/// `mutashabihat` discrimination is offered once a verse is mature (it is
+/// skipped for verses without similar siblings). Types used in the last
+/// `variety_window` attempts are demoted so consecutive reviews vary.
+///
+/// Learners who can't yet read Arabic well (`ArabicLevel::None` or
+/// `BasicReading`) get transliteration exercises first on words they have not
+/// consolidated: matching a transliteration while new, typing one while
+/// developing.
+#[derive(Debug, Clone, Copy)]
+pub struct AdaptivePolicy {
+    pub variety_window: usize,
+}
+
+impl Default for AdaptivePolicy {
+    fn default() -> Self {
+        Self { variety_window: 2 }
+    }
+}
+
+impl ExerciseSelectionPolicy for AdaptivePolicy {
+    fn name(&self) -> &'static str {
+        "adaptive"
+    }
+
+    fn rank(
+        &self,
+        _node_id: i64,
+        target: SelectionTarget,
+        ctx: &LearnerContext,
+    ) -> Vec<&'static str> {
+        let stage = ctx.stage();
+        let mut ranked: Vec<&'static str> = match target {
+            SelectionTarget::Word => match stage {
+                LearningStage::New | LearningStage::Fragile => {
+                    vec!["mcq_ar_to_en", "contextual_translation"]
+                }
+                LearningStage::Developing => {
+                    vec!["contextual_translation", "mcq_ar_to_en", "identify_root"]
+                }
+                LearningStage::Mature | LearningStage::NearMastery => vec![
+                    "identify_root",
+                    "lemma_meaning",
+                    "grammar_feature",
+                    "contextual_translation",
+                    "mcq_ar_to_en",
+                ],
+            },
+            SelectionTarget::Verse => match stage {
+                LearningStage::New => vec!["echo_recall", "missing_word_mcq", "next_word_mcq"],
+                LearningStage::Fragile => vec![
+                    "missing_word_mcq",
+                    "next_word_mcq",
+                    "first_letter_hint",
+                    "echo_recall",
+                ],
+                LearningStage::Developing => vec![
+                    "cloze_deletion",
+                    "word_order",
+                    "first_letter_hint",
+                    "next_word_mcq",
+                    "missing_word_mcq",
+                ],
+                LearningStage::Mature => vec![
+                    "full_verse_input",
+                    "mutashabihat",
+                    "cloze_deletion",
+                    "echo_recall",
+                ],
+                LearningStage::NearMastery => vec![
+                    "find_mistake",
+                    "mutashabihat",
+                    "full_verse_input",
+                    "echo_recall",
+                ],
+            },
+            SelectionTarget::Chapter => vec!["ayah_chain"],
+        };
+
+        if target == SelectionTarget::Word && ctx.needs_transliteration() {
+            match stage {
+                LearningStage::New | LearningStage::Fragile => {
+                    ranked.insert(0, "transliteration_to_arabic")
+                }
+                LearningStage::Developing => ranked.insert(0, "arabic_to_transliteration"),
+                LearningStage::Mature | LearningStage::NearMastery => {}
+            }
+        }
+
+        demote_recent(ranked, &ctx.recent_exercise_types, self.variety_window)
+    }
+}
+
+/// Exercise types for a verse's tajweed knowledge node, best first
+///
+/// Naming the rule on highlighted letters comes first while the node is
+/// being learned; finding every word where a rule applies once it is mature.
+/// Shared by all policies, which rank by node type only.
+pub fn rank_tajweed(ctx: &LearnerContext) -> Vec<&'static str> {
+    match ctx.stage() {
+        LearningStage::Mature | LearningStage::NearMastery => {
+            vec!["find_tajweed_rule", "identify_tajweed_rule"]
+        }
+        _ => vec!["identify_tajweed_rule", "find_tajweed_rule"],
+    }
+}
+
+/// Exercise types for a verse's tafsir knowledge node, best first
+///
+/// The passage's key lesson and occasion of revelation come first while the
+/// node is being learned; connecting verses by shared theme once it is mature.
+pub fn rank_tafsir(ctx: &LearnerContext) -> Vec<&'static str> {
+    match ctx.stage() {
+        LearningStage::Mature | LearningStage::NearMastery => vec![
+            "tafsir_theme_connection",
+            "tafsir_key_lesson",
+            "revelation_occasion",
+        ],
+        _ => vec![
+            "tafsir_key_lesson",
+            "revelation_occasion",
+            "tafsir_theme_connection",
+        ],
+    }
+}
+
+/// Stable-partition `ranked` so types used within the last `window` attempts
+/// move to the back (they remain available as fallbacks).
+fn demote_recent(ranked: Vec<&'static str>, recent: &[String], window: usize) -> Vec<&'static str> {
+    let recent = &recent[..recent.len().min(window)];
+    let (stale, fresh): (Vec<_>, Vec<_>) = ranked
+        .into_iter()
+        .partition(|name| recent.iter().any(|r| r == name));
+    fresh.into_iter().chain(stale).collect()
+}
+
+pub(crate) fn deterministic_slot(node_id: i64, modulo: u32) -> u32 {
+    if modulo == 0 {
+        return 0;
+    }
+    (node_id.unsigned_abs() % modulo as u64) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ctx(energy: f64, stability: f64, review_count: u32, failures: u32) -> LearnerContext {
+        LearnerContext {
+            energy,
+            stability,
+            review_count,
+            recent_failures: failures,
+            recent_exercise_types: vec![],
+            arabic_level: None,
+        }
+    }
+
+    #[test]
+    fn test_stage_classification() {
+        assert_eq!(ctx(0.0, 0.0, 0, 0).stage(), LearningStage::New);
+        assert_eq!(ctx(0.2, 2.0, 3, 0).stage(), LearningStage::Fragile);
+        assert_eq!(ctx(0.8, 20.0, 5, 2).stage(), LearningStage::Fragile);
+        assert_eq!(ctx(0.5, 10.0, 5, 0).stage(), LearningStage::Developing);
+        assert_eq!(ctx(0.8, 20.0, 5, 0).stage(), LearningStage::Mature);
+        assert_eq!(ctx(0.95, 45.0, 12, 0).stage(), LearningStage::NearMastery);
+    }
+
+    #[test]
+    fn test_adaptive_verse_ranking_follows_stage() {
+        let policy = AdaptivePolicy::default();
+        let fragile = policy.rank(1, SelectionTarget::Verse, &ctx(0.2, 2.0, 3, 0));
+        let mature = policy.rank(1, SelectionTarget::Verse, &ctx(0.8, 20.0, 5, 0));
+        let mastered = policy.rank(1, SelectionTarget::Verse, &ctx(0.95, 45.0, 12, 0));
+
+        assert_eq!(fragile[0], "missing_word_mcq");
+        assert_eq!(mature[0], "full_verse_input");
+        assert_eq!(mastered[0], "find_mistake");
+    }
+
+    #[test]
+    fn test_tajweed_ranking_follows_stage() {
+        assert_eq!(
+            rank_tajweed(&ctx(0.0, 0.0, 0, 0))[0],
+            "identify_tajweed_rule"
+        );
+        assert_eq!(rank_tajweed(&ctx(0.8, 20.0, 5, 0))[0], "find_tajweed_rule");
+    }
+
+    #[test]
+    fn test_tafsir_ranking_follows_stage() {
+        assert_eq!(rank_tafsir(&ctx(0.2, 2.0, 3, 0))[0], "tafsir_key_lesson");
+        assert_eq!(
+            rank_tafsir(&ctx(0.95, 45.0, 12, 0))[0],
+            "tafsir_theme_connection"
+        );
+    }
+
+    #[test]
+    fn test_adaptive_demotes_recent_types() {
+        let policy = AdaptivePolicy::default();
+        let mut context = ctx(0.8, 20.0, 5, 0);
+        context.recent_exercise_types = vec!["full_verse_input".to_string()];
+
+        let ranked = policy.rank(1, SelectionTarget::Verse, &context);
+        assert_eq!(ranked[0], "mutashabihat");
+        assert_eq!(ranked.last(), Some(&"full_verse_input"));
+    }
+
+    #[test]
+    fn test_adaptive_bridges_weak_readers_through_transliteration() {
+        let policy = AdaptivePolicy::default();
+        let reader = |level| ctx(0.0, 0.0, 0, 0).with_arabic_level(Some(level));
+
+        let new_word = policy.rank(1, SelectionTarget::Word, &reader(ArabicLevel::None));
+        assert_eq!(new_word[0], "transliteration_to_arabic");
+
+        let developing = ctx(0.5, 10.0, 5, 0).with_arabic_level(Some(ArabicLevel::BasicReading));
+        let ranked = policy.rank(1, SelectionTarget::Word, &developing);
+        assert_eq!(ranked[0], "arabic_to_transliteration");
+
+        let fluent = policy.rank(
+            1,
+            SelectionTarget::Word,
+            &reader(ArabicLevel::FluentReading),
+        );
+        assert_eq!(fluent[0], "mcq_ar_to_en");
+        let unknown = policy.rank(1, SelectionTarget::Word, &ctx(0.0, 0.0, 0, 0));
+        assert_eq!(unknown[0], "mcq_ar_to_en");
+    }
+
+    #[test]
+    fn test_from_history_counts_failures() {
+        let item = |exercise_type: &str, grade: i32| SessionItem {
+            id: 0,
+            session_id: "s".to_string(),
+            node_id: 1,
+            exercise_type: exercise_type.to_string(),
+            grade,
+            duration_ms: None,
+            completed_at: None,
+            hint_level: None,
+        };
+        let items = vec![item("cloze_deletion", 1), item("next_word_mcq", 3)];
+
+        let context = LearnerContext::from_history(None, &items);
+        assert_eq!(context.recent_failures, 1);
+        assert_eq!(context.review_count, 0);
+        assert_eq!(
+            context.recent_exercise_types,
+            vec!["cloze_deletion", "next_word_mcq"]
+        );
+    }
+}
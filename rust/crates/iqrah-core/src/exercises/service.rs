@@ -1,21 +1,33 @@
 // exercises/service.rs
 // Exercise service for generating axis-specific exercises
 
+use super::ayah_chain::AyahChainExercise;
 use super::ayah_sequence::AyahSequenceExercise;
+use super::distractors::{DistractorDifficulty, SemanticScoring};
+use super::full_verse_input::FullVerseInputExercise;
 use super::grammar::IdentifyRootExercise;
 use super::graph::CrossVerseConnectionExercise;
+use super::hints::{hint_ladder, ExerciseHint};
 use super::mcq::McqExercise;
 use super::memorization::MemorizationExercise;
+use super::selection::{
+    rank_tafsir, rank_tajweed, AdaptivePolicy, DeterministicPolicy, ExerciseSelectionPolicy,
+    LearnerContext, SelectionTarget,
+};
+use super::tafsir::TafsirKeyLessonExercise;
+use super::tajweed::IdentifyTajweedRuleExercise;
 use super::translation::{ContextualTranslationExercise, TranslationExercise};
 use super::types::{Exercise, ExerciseResponse, ExerciseType};
-use crate::domain::node_id::{
-    self, PREFIX_CHAPTER, PREFIX_VERSE, PREFIX_WORD, PREFIX_WORD_INSTANCE,
-};
+use super::validator::{AnswerInput, DefaultValidator, ExerciseValidator, ValidationResult};
+use super::word_diff::{diff_words, GradeThresholds};
+use crate::domain::node_id::{self, PREFIX_VERSE, PREFIX_WORD, PREFIX_WORD_INSTANCE};
 use crate::semantic::grader::{SemanticGrader, SEMANTIC_EMBEDDER};
 use crate::semantic::SemanticEmbedder;
-use crate::{ContentRepository, KnowledgeAxis, KnowledgeNode};
+use crate::{ContentRepository, HintLevel, KnowledgeAxis, KnowledgeNode, Script};
 use anyhow::Result;
-use std::sync::Arc;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::sync::{Arc, Mutex, RwLock};
 
 // Modern enum-based architecture
 use super::exercise_data::ExerciseData;
@@ -24,11 +36,107 @@ use super::generators;
 /// Service for generating and managing exercises
 pub struct ExerciseService {
     content_repo: Arc<dyn ContentRepository>,
+    selection_policy: Arc<dyn ExerciseSelectionPolicy>,
+    /// Source of per-exercise generation seeds
+    seed_rng: Mutex<StdRng>,
+    /// Script exercises are rendered and checked in (the user's preference)
+    script: RwLock<Script>,
+    /// Exercise types the client can render; `None` allows every type
+    exercise_types: Option<&'static [&'static str]>,
+    /// Maps partial-credit scores to review grades when validating answers
+    grade_thresholds: GradeThresholds,
 }
 
 impl ExerciseService {
     pub fn new(content_repo: Arc<dyn ContentRepository>) -> Self {
-        Self { content_repo }
+        Self::with_selection_policy(content_repo, Arc::new(AdaptivePolicy::default()))
+    }
+
+    /// Create with a custom exercise selection policy (used by
+    /// `generate_exercise_adaptive`).
+    pub fn with_selection_policy(
+        content_repo: Arc<dyn ContentRepository>,
+        selection_policy: Arc<dyn ExerciseSelectionPolicy>,
+    ) -> Self {
+        Self {
+            content_repo,
+            selection_policy,
+            seed_rng: Mutex::new(StdRng::from_entropy()),
+            script: RwLock::new(Script::default()),
+            exercise_types: None,
+            grade_thresholds: GradeThresholds::default(),
+        }
+    }
+
+    /// Draw exercise seeds from an RNG seeded with `seed`, making every
+    /// generated exercise reproducible (snapshot tests, bug reports).
+    pub fn with_rng_seed(self, seed: u64) -> Self {
+        Self {
+            seed_rng: Mutex::new(StdRng::seed_from_u64(seed)),
+            ..self
+        }
+    }
+
+    /// Only generate the given exercise types (see `ExerciseData::type_name`),
+    /// for clients that cannot render every type. Selection falls through to
+    /// the next ranked type the client supports, then to the axis's text
+    /// exercise (see `text_exercise_for`).
+    pub fn with_exercise_types(self, exercise_types: &'static [&'static str]) -> Self {
+        Self {
+            exercise_types: Some(exercise_types),
+            ..self
+        }
+    }
+
+    /// Grade partial-credit answers with `thresholds` instead of the defaults
+    pub fn with_grade_thresholds(self, grade_thresholds: GradeThresholds) -> Self {
+        Self {
+            grade_thresholds,
+            ..self
+        }
+    }
+
+    /// Whether the client can render exercise type `type_name`
+    fn allows_type(&self, type_name: &str) -> bool {
+        self.exercise_types
+            .is_none_or(|types| types.contains(&type_name))
+    }
+
+    /// Whether the client can be served exercises for `axis`; nodes of other
+    /// axes should be left out of its sessions
+    pub fn supports_axis(&self, axis: KnowledgeAxis) -> bool {
+        self.allows_type(text_exercise_type(axis))
+    }
+
+    /// Render and check exercises in `script` instead of Uthmani
+    pub fn with_script(self, script: Script) -> Self {
+        self.set_script(script);
+        self
+    }
+
+    /// Change the script exercises are rendered and checked in (e.g. when
+    /// the user's preference changes)
+    pub fn set_script(&self, script: Script) {
+        *self
+            .script
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = script;
+    }
+
+    /// Script exercises are rendered and checked in
+    pub fn script(&self) -> Script {
+        *self
+            .script
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Draw the seed for the next generated exercise
+    fn next_seed(&self) -> u64 {
+        self.seed_rng
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .gen()
     }
 
     /// Initialize the semantic grading model
@@ -76,8 +184,40 @@ impl ExerciseService {
             );
         }
 
-        let embedder = SemanticEmbedder::new(model_path)?;
+        Self::install_semantic_embedder(SemanticEmbedder::new(model_path)?)
+    }
+
+    /// Initialize the semantic grading model from a local model2vec folder
+    /// (tokenizer.json, model.safetensors, config.json), without the
+    /// HuggingFace Hub
+    pub fn init_semantic_model_from_dir(model_dir: &str) -> Result<()> {
+        tracing::info!("Initializing semantic grading model from {}", model_dir);
+        Self::install_semantic_embedder(SemanticEmbedder::from_dir(model_dir)?)
+    }
+
+    /// Initialize the semantic grading model from in-memory tokenizer.json
+    /// and model.safetensors bytes (e.g., bundled app assets), staged as
+    /// files in `staging_dir` while the model loads
+    pub fn init_semantic_model_from_bytes(
+        tokenizer_json: &[u8],
+        model_safetensors: &[u8],
+        staging_dir: &str,
+    ) -> Result<()> {
+        tracing::info!("Initializing semantic grading model from bundled bytes");
+        Self::install_semantic_embedder(SemanticEmbedder::from_bytes(
+            tokenizer_json,
+            model_safetensors,
+            staging_dir,
+        )?)
+    }
 
+    /// Whether a semantic model is loaded. Without one, answers are graded by
+    /// lexical similarity (see `SemanticGrader::global`).
+    pub fn is_semantic_model_loaded() -> bool {
+        SEMANTIC_EMBEDDER.get().is_some()
+    }
+
+    fn install_semantic_embedder(embedder: SemanticEmbedder) -> Result<()> {
         SEMANTIC_EMBEDDER
             .set(embedder)
             .map_err(|_| anyhow::anyhow!("Semantic embedder already initialized"))?;
@@ -101,23 +241,24 @@ impl ExerciseService {
         match axis {
             KnowledgeAxis::Memorization | KnowledgeAxis::ContextualMemorization => {
                 let exercise =
-                    MemorizationExercise::new(node_id, ukey, &*self.content_repo).await?;
+                    MemorizationExercise::new(node_id, ukey, self.script(), &*self.content_repo)
+                        .await?;
                 Ok(ExerciseType::Memorization(Box::new(exercise)))
             }
             KnowledgeAxis::Translation | KnowledgeAxis::Meaning => {
                 let exercise = TranslationExercise::new(node_id, ukey, &*self.content_repo).await?;
                 Ok(ExerciseType::Translation(Box::new(exercise)))
             }
-            KnowledgeAxis::Tafsir => {
-                // For now, treat tafsir like translation
-                let exercise = TranslationExercise::new(node_id, ukey, &*self.content_repo).await?;
-                Ok(ExerciseType::Translation(Box::new(exercise)))
-            }
+            KnowledgeAxis::Tafsir => self.generate_tafsir_exercise(node_id, ukey).await,
             KnowledgeAxis::Tajweed => {
-                // Tajweed not implemented yet, fall back to memorization
-                let exercise =
-                    MemorizationExercise::new(node_id, ukey, &*self.content_repo).await?;
-                Ok(ExerciseType::Memorization(Box::new(exercise)))
+                let exercise = IdentifyTajweedRuleExercise::new(
+                    node_id,
+                    ukey,
+                    self.next_seed(),
+                    &*self.content_repo,
+                )
+                .await?;
+                Ok(ExerciseType::Tajweed(Box::new(exercise)))
             }
         }
     }
@@ -132,18 +273,39 @@ impl ExerciseService {
         match axis {
             KnowledgeAxis::Memorization | KnowledgeAxis::ContextualMemorization => {
                 let exercise =
-                    MemorizationExercise::new(node_id, ukey, &*self.content_repo).await?;
+                    MemorizationExercise::new(node_id, ukey, self.script(), &*self.content_repo)
+                        .await?;
                 Ok(ExerciseType::Memorization(Box::new(exercise)))
             }
-            KnowledgeAxis::Translation | KnowledgeAxis::Meaning | KnowledgeAxis::Tafsir => {
+            KnowledgeAxis::Translation | KnowledgeAxis::Meaning => {
                 let exercise = TranslationExercise::new(node_id, ukey, &*self.content_repo).await?;
                 Ok(ExerciseType::Translation(Box::new(exercise)))
             }
+            KnowledgeAxis::Tafsir => self.generate_tafsir_exercise(node_id, ukey).await,
             KnowledgeAxis::Tajweed => {
-                // Tajweed not implemented yet, fall back to memorization
-                let exercise =
-                    MemorizationExercise::new(node_id, ukey, &*self.content_repo).await?;
-                Ok(ExerciseType::Memorization(Box::new(exercise)))
+                let exercise = IdentifyTajweedRuleExercise::new(
+                    node_id,
+                    ukey,
+                    self.next_seed(),
+                    &*self.content_repo,
+                )
+                .await?;
+                Ok(ExerciseType::Tajweed(Box::new(exercise)))
+            }
+        }
+    }
+
+    /// Tafsir Key Lesson for tafsir axis nodes, or Translation when no
+    /// installed tafsir has a key lesson for the node
+    async fn generate_tafsir_exercise(&self, node_id: i64, ukey: &str) -> Result<ExerciseType> {
+        match TafsirKeyLessonExercise::new(node_id, ukey, self.next_seed(), &*self.content_repo)
+            .await
+        {
+            Ok(exercise) => Ok(ExerciseType::Tafsir(Box::new(exercise))),
+            Err(e) => {
+                tracing::debug!("No tafsir exercise for {}: {}", ukey, e);
+                let exercise = TranslationExercise::new(node_id, ukey, &*self.content_repo).await?;
+                Ok(ExerciseType::Translation(Box::new(exercise)))
             }
         }
     }
@@ -155,15 +317,58 @@ impl ExerciseService {
     /// Flutter can then fetch content based on user preferences (Tajweed, Indopak, etc.)
     ///
     /// # Routing Logic
-    /// - WORD nodes: Memorization exercises
-    /// - VERSE nodes: Full verse input exercises
+    /// Node-type routing via `DeterministicPolicy` (no learning state):
+    /// - WORD nodes: lexical exercises (MCQ, contextual translation, root)
+    /// - VERSE nodes: continuity MCQs, falling back to echo recall / full verse input
     /// - CHAPTER nodes: Ayah Chain exercises
     ///
-    /// TODO: Add more sophisticated routing based on:
-    /// - Learning state (FSRS energy levels)
-    /// - User preferences
-    /// - Exercise variety (randomization)
+    /// Use `generate_exercise_adaptive` when the learner's state is known.
     pub async fn generate_exercise_v2(&self, node_id: i64, ukey: &str) -> Result<ExerciseData> {
+        self.generate_ranked(
+            node_id,
+            ukey,
+            &DeterministicPolicy,
+            &LearnerContext::default(),
+            DistractorDifficulty::default(),
+        )
+        .await
+    }
+
+    /// Generate an exercise whose type is chosen by the service's selection
+    /// policy from the learner's state on the node (energy, stability,
+    /// review count, recent failures and recently used exercise types).
+    /// MCQ distractors get closer to the answer as the node's energy grows.
+    pub async fn generate_exercise_adaptive(
+        &self,
+        node_id: i64,
+        ukey: &str,
+        ctx: &LearnerContext,
+    ) -> Result<ExerciseData> {
+        self.generate_ranked(
+            node_id,
+            ukey,
+            self.selection_policy.as_ref(),
+            ctx,
+            DistractorDifficulty::from_energy(ctx.energy),
+        )
+        .await
+    }
+
+    /// Name of the active selection policy
+    pub fn selection_policy_name(&self) -> &'static str {
+        self.selection_policy.name()
+    }
+
+    /// Try the policy's ranked exercise types in order and return the first one
+    /// that generates and passes the axis guardrails.
+    async fn generate_ranked(
+        &self,
+        node_id: i64,
+        ukey: &str,
+        policy: &dyn ExerciseSelectionPolicy,
+        ctx: &LearnerContext,
+        difficulty: DistractorDifficulty,
+    ) -> Result<ExerciseData> {
         let (base_ukey, axis) = if let Some(kn) = KnowledgeNode::parse(ukey) {
             (kn.base_node_id, Some(kn.axis))
         } else {
@@ -182,97 +387,243 @@ impl ExerciseService {
             node_id
         };
 
-        let exercise = if base_ukey.starts_with(PREFIX_WORD)
-            || base_ukey.starts_with(PREFIX_WORD_INSTANCE)
-        {
-            // C-007: promote lexical core exercises in scheduled default pool.
-            // We keep deterministic variety per node for stable session resumes.
-            match deterministic_slot(base_node_id, 3) {
-                0 => {
-                    generators::generate_mcq_ar_to_en(base_node_id, &base_ukey, &*self.content_repo)
-                        .await
-                }
-                1 => {
-                    generators::generate_contextual_translation(
-                        base_node_id,
-                        &base_ukey,
-                        &*self.content_repo,
-                    )
-                    .await
-                }
-                _ => match generators::generate_identify_root(
+        let target = SelectionTarget::from_ukey(&base_ukey).ok_or_else(|| {
+            anyhow::anyhow!("Cannot determine exercise type for node: {}", base_ukey)
+        })?;
+
+        let ranked = match axis {
+            Some(KnowledgeAxis::Tajweed) => rank_tajweed(ctx),
+            // Translation until a tafsir covering the verse is installed
+            Some(KnowledgeAxis::Tafsir) if target == SelectionTarget::Verse => {
+                let mut ranked = rank_tafsir(ctx);
+                ranked.push("translation");
+                ranked
+            }
+            _ => policy.rank(base_node_id, target, ctx),
+        };
+
+        let mut last_error = None;
+        let supported = ranked
+            .into_iter()
+            .filter(|type_name| self.allows_type(type_name));
+        for type_name in supported {
+            match self
+                .generate_by_type_seeded(
+                    type_name,
                     base_node_id,
                     &base_ukey,
-                    &*self.content_repo,
+                    self.next_seed(),
+                    difficulty,
+                    SemanticScoring::IfLoaded,
                 )
                 .await
-                {
-                    Ok(ex) => Ok(ex),
-                    Err(_) => {
-                        generators::generate_mcq_ar_to_en(
-                            base_node_id,
-                            &base_ukey,
-                            &*self.content_repo,
-                        )
-                        .await
-                    }
-                },
-            }?
-        } else if base_ukey.starts_with(PREFIX_VERSE) {
-            // C-008: demote high-friction full-verse typing in default scheduled flow.
-            // Prefer continuity MCQ family by default.
-            let primary = if deterministic_slot(base_node_id, 2) == 0 {
-                generators::generate_missing_word_mcq(base_node_id, &base_ukey, &*self.content_repo)
-                    .await
-            } else {
-                generators::generate_next_word_mcq(base_node_id, &base_ukey, &*self.content_repo)
-                    .await
-            };
-
-            if let Ok(ex) = primary {
-                ex
-            } else if let Ok(ex) =
-                generators::generate_echo_recall(base_node_id, &base_ukey, &*self.content_repo)
-                    .await
             {
-                ex
-            } else {
-                generators::generate_full_verse_input(base_node_id, &base_ukey, &*self.content_repo)
-                    .await?
+                // C-009: axis-to-exercise guardrails.
+                Ok(exercise) if guardrail_allows(&base_ukey, axis, &exercise) => {
+                    return Ok(exercise)
+                }
+                Ok(exercise) => {
+                    last_error = Some(anyhow::anyhow!(
+                        "Guardrail violation for node `{}` axis {:?} -> exercise `{}`",
+                        base_ukey,
+                        axis,
+                        exercise.type_name()
+                    ));
+                }
+                Err(e) => last_error = Some(e),
             }
-        } else if base_ukey.starts_with(PREFIX_CHAPTER) {
-            generators::generate_ayah_chain(base_node_id, &base_ukey, &*self.content_repo).await?
-        } else {
-            return Err(anyhow::anyhow!(
-                "Cannot determine exercise type for node: {}",
-                base_ukey
-            ));
-        };
+        }
 
-        // C-009: axis-to-exercise guardrails.
-        if !guardrail_allows(&base_ukey, axis, &exercise) {
-            return Err(anyhow::anyhow!(
-                "Guardrail violation for node `{}` axis {:?} -> exercise `{}`",
-                base_ukey,
-                axis,
-                exercise.type_name()
-            ));
+        // A client that renders none of the axis's exercises (e.g. tajweed)
+        // still gets one it can render
+        if self.exercise_types.is_some() {
+            let fallback = text_exercise(base_node_id, axis.unwrap_or(KnowledgeAxis::Memorization));
+            if self.allows_type(fallback.type_name()) {
+                tracing::debug!(
+                    "No supported exercise for {}, falling back to {}",
+                    ukey,
+                    fallback.type_name()
+                );
+                return Ok(fallback);
+            }
         }
 
-        Ok(exercise)
+        Err(last_error.unwrap_or_else(|| {
+            anyhow::anyhow!("No exercise type available for node: {}", base_ukey)
+        }))
+    }
+
+    /// Generate a specific exercise type by name (see `ExerciseData::type_name`)
+    pub async fn generate_by_type(
+        &self,
+        type_name: &str,
+        node_id: i64,
+        ukey: &str,
+    ) -> Result<ExerciseData> {
+        let seed = self.next_seed();
+        self.generate_by_type_seeded(
+            type_name,
+            node_id,
+            ukey,
+            seed,
+            DistractorDifficulty::default(),
+            SemanticScoring::IfLoaded,
+        )
+        .await
+    }
+
+    /// Generate a specific exercise type from an explicit seed
+    ///
+    /// The same (node_id, type_name, seed, difficulty, semantic) always yields
+    /// the same exercise in a given script, so an exercise can be regenerated
+    /// from `ExerciseData::seed` and its distractor strategy (the difficulty
+    /// and `DistractorStrategy::semantic_scoring`).
+    pub async fn generate_by_type_seeded(
+        &self,
+        type_name: &str,
+        node_id: i64,
+        ukey: &str,
+        seed: u64,
+        difficulty: DistractorDifficulty,
+        semantic: SemanticScoring,
+    ) -> Result<ExerciseData> {
+        let repo = &*self.content_repo;
+        match type_name {
+            "memorization" => generators::generate_memorization(node_id, ukey, repo).await,
+            "mcq_ar_to_en" => {
+                generators::generate_mcq_ar_to_en(node_id, ukey, seed, difficulty, semantic, repo)
+                    .await
+            }
+            "mcq_en_to_ar" => {
+                generators::generate_mcq_en_to_ar(node_id, ukey, seed, difficulty, semantic, repo)
+                    .await
+            }
+            "translation" => generators::generate_translation(node_id, ukey, repo).await,
+            "contextual_translation" => {
+                generators::generate_contextual_translation(node_id, ukey, repo).await
+            }
+            "cloze_deletion" => {
+                generators::generate_cloze_deletion(node_id, ukey, seed, repo).await
+            }
+            "first_letter_hint" => {
+                generators::generate_first_letter_hint(node_id, ukey, seed, repo).await
+            }
+            "missing_word_mcq" => {
+                generators::generate_missing_word_mcq(
+                    node_id, ukey, seed, difficulty, semantic, repo,
+                )
+                .await
+            }
+            "next_word_mcq" => {
+                generators::generate_next_word_mcq(node_id, ukey, seed, difficulty, semantic, repo)
+                    .await
+            }
+            "full_verse_input" => generators::generate_full_verse_input(node_id, ukey, repo).await,
+            "sequence_recall" => generators::generate_sequence_recall(node_id, ukey, repo).await,
+            "first_word_recall" => {
+                generators::generate_first_word_recall(node_id, ukey, repo).await
+            }
+            "echo_recall" => generators::generate_echo_recall(node_id, ukey, repo).await,
+            "ayah_chain" => generators::generate_ayah_chain(node_id, ukey, repo).await,
+            "find_mistake" => generators::generate_find_mistake(node_id, ukey, seed, repo).await,
+            "ayah_sequence" => generators::generate_ayah_sequence(node_id, ukey, repo).await,
+            "identify_root" => generators::generate_identify_root(node_id, ukey, repo).await,
+            "reverse_cloze" => generators::generate_reverse_cloze(node_id, ukey, seed, repo).await,
+            "pos_tagging" => generators::generate_pos_tagging(node_id, ukey, seed, repo).await,
+            "grammar_feature" => {
+                generators::generate_grammar_feature(node_id, ukey, seed, repo).await
+            }
+            "cross_verse_connection" => {
+                generators::generate_cross_verse_connection(node_id, ukey, repo).await
+            }
+            "mutashabihat" => generators::generate_mutashabihat(node_id, ukey, seed, repo).await,
+            "word_order" => {
+                generators::generate_word_order(node_id, ukey, self.script(), seed, repo).await
+            }
+            "identify_surah" => {
+                generators::generate_identify_surah(node_id, ukey, seed, repo).await
+            }
+            "identify_verse_number" => {
+                generators::generate_identify_verse_number(node_id, ukey, seed, repo).await
+            }
+            "identify_page_half" => {
+                generators::generate_identify_page_half(node_id, ukey, repo).await
+            }
+            "transliteration_to_arabic" => {
+                generators::generate_transliteration_to_arabic(
+                    node_id, ukey, seed, difficulty, semantic, repo,
+                )
+                .await
+            }
+            "arabic_to_transliteration" => {
+                generators::generate_arabic_to_transliteration(node_id, ukey, repo).await
+            }
+            "root_family" => generators::generate_root_family(node_id, ukey, repo).await,
+            "lemma_meaning" => generators::generate_lemma_meaning(node_id, ukey, seed, repo).await,
+            "recite_page" => generators::generate_recite_page(node_id, ukey, repo).await,
+            "page_first_word" => {
+                generators::generate_page_first_word(node_id, ukey, seed, repo).await
+            }
+            "identify_verse_line" => {
+                generators::generate_identify_verse_line(node_id, ukey, seed, repo).await
+            }
+            "listen_identify_verse" => {
+                generators::generate_listen_identify_verse(node_id, ukey, seed, repo).await
+            }
+            "listen_continue" => {
+                generators::generate_listen_continue(
+                    node_id, ukey, seed, difficulty, semantic, repo,
+                )
+                .await
+            }
+            "listen_identify_word" => {
+                generators::generate_listen_identify_word(
+                    node_id, ukey, seed, difficulty, semantic, repo,
+                )
+                .await
+            }
+            "identify_tajweed_rule" => {
+                generators::generate_identify_tajweed_rule(node_id, ukey, seed, repo).await
+            }
+            "find_tajweed_rule" => {
+                generators::generate_find_tajweed_rule(node_id, ukey, seed, repo).await
+            }
+            "tafsir_key_lesson" => {
+                generators::generate_tafsir_key_lesson(node_id, ukey, seed, repo).await
+            }
+            "revelation_occasion" => {
+                generators::generate_revelation_occasion(node_id, ukey, seed, repo).await
+            }
+            "tafsir_theme_connection" => {
+                generators::generate_tafsir_theme_connection(node_id, ukey, seed, repo).await
+            }
+            _ => Err(anyhow::anyhow!("Unsupported exercise type: {}", type_name)),
+        }
     }
 
     /// Generate an MCQ exercise (Arabic to English)
     /// Tests translation understanding with multiple choice
     pub async fn generate_mcq_ar_to_en(&self, node_id: i64, _ukey: &str) -> Result<ExerciseType> {
-        let exercise = McqExercise::new_ar_to_en(node_id, &*self.content_repo).await?;
+        let exercise = McqExercise::new_ar_to_en(
+            node_id,
+            self.next_seed(),
+            self.script(),
+            &*self.content_repo,
+        )
+        .await?;
         Ok(ExerciseType::McqArToEn(Box::new(exercise)))
     }
 
     /// Generate an MCQ exercise (English to Arabic)
     /// Tests memorization with multiple choice
     pub async fn generate_mcq_en_to_ar(&self, node_id: i64, _ukey: &str) -> Result<ExerciseType> {
-        let exercise = McqExercise::new_en_to_ar(node_id, &*self.content_repo).await?;
+        let exercise = McqExercise::new_en_to_ar(
+            node_id,
+            self.next_seed(),
+            self.script(),
+            &*self.content_repo,
+        )
+        .await?;
         Ok(ExerciseType::McqEnToAr(Box::new(exercise)))
     }
 
@@ -321,49 +672,53 @@ impl ExerciseService {
             });
 
         // Get semantic grading metadata for TranslationExercise or MemorizationExercise
-        // Only if embedder is initialized (fail gracefully if not)
-        let (semantic_grade, similarity_score) = if let Some(embedder) = SEMANTIC_EMBEDDER.get() {
-            let grader = SemanticGrader::new(embedder);
-
-            if let Some(translation_ex) =
-                (exercise as &dyn std::any::Any).downcast_ref::<TranslationExercise>()
-            {
-                match grader.grade_answer(answer, translation_ex.get_translation()) {
-                    Ok(grade) => (
-                        Some(grade.label.to_str().to_string()),
-                        Some(grade.similarity),
-                    ),
-                    Err(e) => {
-                        tracing::error!("Semantic grading failed for TranslationExercise: {}", e);
-                        (None, None)
-                    }
+        // (lexical similarity when no semantic model is loaded)
+        let grader = SemanticGrader::global();
+        let (semantic_grade, similarity_score) = if let Some(translation_ex) =
+            (exercise as &dyn std::any::Any).downcast_ref::<TranslationExercise>()
+        {
+            match grader.grade_answer(answer, translation_ex.get_translation()) {
+                Ok(grade) => (
+                    Some(grade.label.to_str().to_string()),
+                    Some(grade.similarity),
+                ),
+                Err(e) => {
+                    tracing::error!("Semantic grading failed for TranslationExercise: {}", e);
+                    (None, None)
                 }
-            } else if let Some(memorization_ex) =
-                (exercise as &dyn std::any::Any).downcast_ref::<MemorizationExercise>()
-            {
-                // For memorization, grade the normalized Arabic text
-                let normalized_answer = MemorizationExercise::normalize_arabic(answer);
-                let normalized_correct =
-                    MemorizationExercise::normalize_arabic(memorization_ex.get_word_text());
-
-                match grader.grade_answer(&normalized_answer, &normalized_correct) {
-                    Ok(grade) => (
-                        Some(grade.label.to_str().to_string()),
-                        Some(grade.similarity),
-                    ),
-                    Err(e) => {
-                        tracing::error!("Semantic grading failed for MemorizationExercise: {}", e);
-                        (None, None)
-                    }
+            }
+        } else if let Some(memorization_ex) =
+            (exercise as &dyn std::any::Any).downcast_ref::<MemorizationExercise>()
+        {
+            // For memorization, grade the normalized Arabic text
+            let normalized_answer = MemorizationExercise::normalize_arabic(answer);
+            let normalized_correct =
+                MemorizationExercise::normalize_arabic(memorization_ex.get_word_text());
+
+            match grader.grade_answer(&normalized_answer, &normalized_correct) {
+                Ok(grade) => (
+                    Some(grade.label.to_str().to_string()),
+                    Some(grade.similarity),
+                ),
+                Err(e) => {
+                    tracing::error!("Semantic grading failed for MemorizationExercise: {}", e);
+                    (None, None)
                 }
-            } else {
-                (None, None)
             }
         } else {
-            tracing::warn!("Semantic embedder not initialized, skipping semantic grading metadata");
             (None, None)
         };
 
+        let word_diff = (exercise as &dyn std::any::Any)
+            .downcast_ref::<FullVerseInputExercise>()
+            .map(|verse_ex| verse_ex.diff_answer(answer))
+            .or_else(|| {
+                (exercise as &dyn std::any::Any)
+                    .downcast_ref::<AyahChainExercise>()
+                    .and_then(|chain| chain.current_text())
+                    .map(|text| diff_words(text, answer))
+            });
+
         ExerciseResponse {
             is_correct,
             correct_answer: if !is_correct {
@@ -380,6 +735,7 @@ impl ExerciseService {
             options,
             semantic_grade,
             similarity_score,
+            word_diff,
         }
     }
 
@@ -387,13 +743,76 @@ impl ExerciseService {
     pub fn get_hint(&self, exercise: &dyn Exercise) -> Option<String> {
         exercise.get_hint()
     }
+
+    /// Progressive hints for an `ExerciseData` exercise, least revealing first
+    pub async fn get_hint_ladder(&self, exercise: &ExerciseData) -> Result<Vec<ExerciseHint>> {
+        hint_ladder(exercise, self.script(), &*self.content_repo).await
+    }
+
+    /// The hint shown at `step` (0-indexed) of the exercise's ladder, or None
+    /// once every hint has been revealed
+    pub async fn get_hint_at(
+        &self,
+        exercise: &ExerciseData,
+        step: usize,
+    ) -> Result<Option<ExerciseHint>> {
+        Ok(self.get_hint_ladder(exercise).await?.into_iter().nth(step))
+    }
+
+    /// Validate an answer to an `ExerciseData` exercise
+    ///
+    /// This is the single validation path for the FFI and WebSocket APIs; the
+    /// result carries the review grade to record, capped by `hint_level`, the
+    /// most revealing hint shown before answering.
+    pub async fn validate_answer(
+        &self,
+        exercise: &ExerciseData,
+        answer: &AnswerInput,
+        hint_level: Option<HintLevel>,
+    ) -> Result<ValidationResult> {
+        let result = DefaultValidator::with_script(self.script())
+            .with_grade_thresholds(self.grade_thresholds)
+            .validate(exercise, answer, &*self.content_repo)
+            .await?;
+        Ok(result.capped_by_hint(hint_level))
+    }
+
+    /// Free-text exercise checked by `generate_exercise`'s axis routing:
+    /// translation axes expect the English meaning, all others the Arabic text.
+    pub fn text_exercise_for(node_id: i64, ukey: &str) -> ExerciseData {
+        let (node_id, axis) = match KnowledgeNode::parse(ukey) {
+            Some(kn) => (
+                node_id::decode_knowledge_id(node_id)
+                    .map(|(base_id, _)| base_id)
+                    .or_else(|| node_id::from_ukey(&kn.base_node_id))
+                    .unwrap_or(node_id),
+                kn.axis,
+            ),
+            None => (node_id, KnowledgeAxis::Memorization),
+        };
+
+        text_exercise(node_id, axis)
+    }
+}
+
+/// Free-text exercise checking `axis` on `node_id`
+fn text_exercise(node_id: i64, axis: KnowledgeAxis) -> ExerciseData {
+    match text_exercise_type(axis) {
+        "translation" => ExerciseData::Translation { node_id },
+        _ => ExerciseData::Memorization { node_id },
+    }
 }
 
-fn deterministic_slot(node_id: i64, modulo: u32) -> u32 {
-    if modulo == 0 {
-        return 0;
+/// Type of the free-text exercise checking `axis` (see `text_exercise_for`)
+fn text_exercise_type(axis: KnowledgeAxis) -> &'static str {
+    match axis {
+        KnowledgeAxis::Translation | KnowledgeAxis::Meaning | KnowledgeAxis::Tafsir => {
+            "translation"
+        }
+        KnowledgeAxis::Memorization
+        | KnowledgeAxis::ContextualMemorization
+        | KnowledgeAxis::Tajweed => "memorization",
     }
-    (node_id.unsigned_abs() % modulo as u64) as u32
 }
 
 fn is_lexical_exercise(exercise: &ExerciseData) -> bool {
@@ -402,6 +821,25 @@ fn is_lexical_exercise(exercise: &ExerciseData) -> bool {
         ExerciseData::McqArToEn { .. }
             | ExerciseData::ContextualTranslation { .. }
             | ExerciseData::IdentifyRoot { .. }
+            | ExerciseData::LemmaMeaning { .. }
+            | ExerciseData::GrammarFeature { .. }
+    )
+}
+
+fn is_tajweed_exercise(exercise: &ExerciseData) -> bool {
+    matches!(
+        exercise,
+        ExerciseData::IdentifyTajweedRule { .. } | ExerciseData::FindTajweedRule { .. }
+    )
+}
+
+fn is_tafsir_exercise(exercise: &ExerciseData) -> bool {
+    matches!(
+        exercise,
+        ExerciseData::TafsirKeyLesson { .. }
+            | ExerciseData::RevelationOccasion { .. }
+            | ExerciseData::CrossVerseConnection { .. }
+            | ExerciseData::Translation { .. }
     )
 }
 
@@ -415,6 +853,13 @@ fn is_continuity_exercise(exercise: &ExerciseData) -> bool {
             | ExerciseData::ClozeDeletion { .. }
             | ExerciseData::FirstLetterHint { .. }
             | ExerciseData::FullVerseInput { .. }
+            | ExerciseData::RecitePage { .. }
+            | ExerciseData::ListenContinue { .. }
+            | ExerciseData::ListenIdentifyVerse { .. }
+            // Verification of a memorized verse (near-mastery check)
+            | ExerciseData::FindMistake { .. }
+            | ExerciseData::Mutashabihat { .. }
+            | ExerciseData::WordOrder { .. }
     )
 }
 
@@ -428,6 +873,14 @@ fn guardrail_allows(base_ukey: &str, axis: Option<KnowledgeAxis>, exercise: &Exe
         return is_lexical_exercise(exercise);
     }
 
+    if axis == Some(KnowledgeAxis::Tajweed) {
+        return is_tajweed_exercise(exercise);
+    }
+
+    if axis == Some(KnowledgeAxis::Tafsir) && base_ukey.starts_with(PREFIX_VERSE) {
+        return is_tafsir_exercise(exercise);
+    }
+
     // Memorization verse axes must map to continuity exercises.
     if base_ukey.starts_with(PREFIX_VERSE)
         && matches!(
@@ -444,7 +897,7 @@ fn guardrail_allows(base_ukey: &str, axis: Option<KnowledgeAxis>, exercise: &Exe
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{Node, NodeType};
+    use crate::{ArabicLevel, Node, NodeType};
     use async_trait::async_trait;
     use std::collections::HashMap;
 
@@ -704,6 +1157,14 @@ mod tests {
             Ok(())
         }
 
+        async fn install_package_content(
+            &self,
+            _package: &crate::ContentPackage,
+            _content: &crate::PackageContent,
+        ) -> Result<()> {
+            Ok(())
+        }
+
         async fn mark_package_uninstalled(&self, _package_id: &str) -> Result<()> {
             Ok(())
         }
@@ -720,6 +1181,91 @@ mod tests {
             Ok(vec![])
         }
 
+        async fn get_reciters(&self) -> Result<Vec<crate::Reciter>> {
+            Ok(vec![])
+        }
+
+        async fn insert_reciter(
+            &self,
+            _slug: &str,
+            _name: &str,
+            _style: Option<String>,
+            _package_id: Option<String>,
+        ) -> Result<i32> {
+            Ok(1)
+        }
+
+        async fn insert_verse_recitation(
+            &self,
+            _recitation: &crate::VerseRecitation,
+        ) -> Result<()> {
+            Ok(())
+        }
+
+        async fn insert_word_audio(&self, _clip: &crate::WordAudioClip) -> Result<()> {
+            Ok(())
+        }
+
+        async fn get_verse_recitation(
+            &self,
+            _verse_key: &str,
+            _reciter_id: i32,
+        ) -> Result<Option<crate::VerseRecitation>> {
+            Ok(None)
+        }
+
+        async fn get_word_audio(
+            &self,
+            _verse_key: &str,
+            _position: i32,
+            _reciter_id: i32,
+        ) -> Result<Option<crate::WordAudioClip>> {
+            Ok(None)
+        }
+
+        async fn get_tafsirs(&self) -> Result<Vec<crate::Tafsir>> {
+            Ok(vec![])
+        }
+
+        async fn insert_tafsir(
+            &self,
+            _slug: &str,
+            _name: &str,
+            _author: Option<String>,
+            _language_code: Option<String>,
+            _package_id: Option<String>,
+        ) -> Result<i32> {
+            Ok(1)
+        }
+
+        async fn insert_tafsir_passage(&self, _passage: &crate::TafsirPassage) -> Result<()> {
+            Ok(())
+        }
+
+        async fn get_tafsir_passage(
+            &self,
+            _verse_key: &str,
+            _tafsir_id: i32,
+        ) -> Result<Option<crate::TafsirPassage>> {
+            Ok(None)
+        }
+
+        async fn get_tafsir_passages_for_chapter(
+            &self,
+            _chapter_number: i32,
+            _tafsir_id: i32,
+        ) -> Result<Vec<crate::TafsirPassage>> {
+            Ok(vec![])
+        }
+
+        async fn get_tafsir_passages_by_theme(
+            &self,
+            _theme: &str,
+            _tafsir_id: i32,
+        ) -> Result<Vec<crate::TafsirPassage>> {
+            Ok(vec![])
+        }
+
         async fn get_morphology_for_word(
             &self,
             _word_id: i64,
@@ -735,6 +1281,32 @@ mod tests {
             Ok(None)
         }
 
+        async fn get_similar_verses(
+            &self,
+            _verse_key: &str,
+            _limit: u32,
+        ) -> Result<Vec<crate::SimilarVerse>> {
+            Ok(vec![])
+        }
+
+        async fn get_mushaf_page(&self, _page: i32) -> Result<Vec<crate::MushafLine>> {
+            Ok(vec![])
+        }
+
+        async fn get_mushaf_line_for_word(
+            &self,
+            _word_id: i64,
+        ) -> Result<Option<crate::MushafLine>> {
+            Ok(None)
+        }
+
+        async fn get_tajweed_annotations(
+            &self,
+            _verse_key: &str,
+        ) -> Result<Vec<crate::TajweedAnnotation>> {
+            Ok(vec![])
+        }
+
         async fn get_scheduler_candidates(
             &self,
             _goal_id: &str,
@@ -903,7 +1475,7 @@ mod tests {
                     position: 1,
                     text_uthmani: "بِسْمِ".to_string(),
                     text_simple: None,
-                    transliteration: None,
+                    transliteration: Some("bismi".to_string()),
                 },
             );
             words.insert(
@@ -914,7 +1486,7 @@ mod tests {
                     position: 2,
                     text_uthmani: "ٱللَّهِ".to_string(),
                     text_simple: None,
-                    transliteration: None,
+                    transliteration: Some("allahi".to_string()),
                 },
             );
             words.insert(
@@ -925,7 +1497,7 @@ mod tests {
                     position: 3,
                     text_uthmani: "ٱلرَّحْمَٰنِ".to_string(),
                     text_simple: None,
-                    transliteration: None,
+                    transliteration: Some("ar-raḥmāni".to_string()),
                 },
             );
             words.insert(
@@ -936,7 +1508,7 @@ mod tests {
                     position: 4,
                     text_uthmani: "ٱلرَّحِيمِ".to_string(),
                     text_simple: None,
-                    transliteration: None,
+                    transliteration: Some("ar-raḥīmi".to_string()),
                 },
             );
 
@@ -1233,6 +1805,14 @@ mod tests {
             Ok(())
         }
 
+        async fn install_package_content(
+            &self,
+            _package: &crate::ContentPackage,
+            _content: &crate::PackageContent,
+        ) -> Result<()> {
+            Ok(())
+        }
+
         async fn mark_package_uninstalled(&self, _package_id: &str) -> Result<()> {
             Ok(())
         }
@@ -1249,6 +1829,91 @@ mod tests {
             Ok(vec![])
         }
 
+        async fn get_reciters(&self) -> Result<Vec<crate::Reciter>> {
+            Ok(vec![])
+        }
+
+        async fn insert_reciter(
+            &self,
+            _slug: &str,
+            _name: &str,
+            _style: Option<String>,
+            _package_id: Option<String>,
+        ) -> Result<i32> {
+            Ok(1)
+        }
+
+        async fn insert_verse_recitation(
+            &self,
+            _recitation: &crate::VerseRecitation,
+        ) -> Result<()> {
+            Ok(())
+        }
+
+        async fn insert_word_audio(&self, _clip: &crate::WordAudioClip) -> Result<()> {
+            Ok(())
+        }
+
+        async fn get_verse_recitation(
+            &self,
+            _verse_key: &str,
+            _reciter_id: i32,
+        ) -> Result<Option<crate::VerseRecitation>> {
+            Ok(None)
+        }
+
+        async fn get_word_audio(
+            &self,
+            _verse_key: &str,
+            _position: i32,
+            _reciter_id: i32,
+        ) -> Result<Option<crate::WordAudioClip>> {
+            Ok(None)
+        }
+
+        async fn get_tafsirs(&self) -> Result<Vec<crate::Tafsir>> {
+            Ok(vec![])
+        }
+
+        async fn insert_tafsir(
+            &self,
+            _slug: &str,
+            _name: &str,
+            _author: Option<String>,
+            _language_code: Option<String>,
+            _package_id: Option<String>,
+        ) -> Result<i32> {
+            Ok(1)
+        }
+
+        async fn insert_tafsir_passage(&self, _passage: &crate::TafsirPassage) -> Result<()> {
+            Ok(())
+        }
+
+        async fn get_tafsir_passage(
+            &self,
+            _verse_key: &str,
+            _tafsir_id: i32,
+        ) -> Result<Option<crate::TafsirPassage>> {
+            Ok(None)
+        }
+
+        async fn get_tafsir_passages_for_chapter(
+            &self,
+            _chapter_number: i32,
+            _tafsir_id: i32,
+        ) -> Result<Vec<crate::TafsirPassage>> {
+            Ok(vec![])
+        }
+
+        async fn get_tafsir_passages_by_theme(
+            &self,
+            _theme: &str,
+            _tafsir_id: i32,
+        ) -> Result<Vec<crate::TafsirPassage>> {
+            Ok(vec![])
+        }
+
         async fn get_morphology_for_word(
             &self,
             _word_id: i64,
@@ -1264,6 +1929,32 @@ mod tests {
             Ok(None)
         }
 
+        async fn get_similar_verses(
+            &self,
+            _verse_key: &str,
+            _limit: u32,
+        ) -> Result<Vec<crate::SimilarVerse>> {
+            Ok(vec![])
+        }
+
+        async fn get_mushaf_page(&self, _page: i32) -> Result<Vec<crate::MushafLine>> {
+            Ok(vec![])
+        }
+
+        async fn get_mushaf_line_for_word(
+            &self,
+            _word_id: i64,
+        ) -> Result<Option<crate::MushafLine>> {
+            Ok(None)
+        }
+
+        async fn get_tajweed_annotations(
+            &self,
+            _verse_key: &str,
+        ) -> Result<Vec<crate::TajweedAnnotation>> {
+            Ok(vec![])
+        }
+
         async fn get_scheduler_candidates(
             &self,
             _goal_id: &str,
@@ -1382,6 +2073,249 @@ mod tests {
             .contains("Cannot determine exercise type"));
     }
 
+    #[tokio::test]
+    async fn test_generate_exercise_adaptive_follows_learning_stage() {
+        let content_repo = Arc::new(MockContentRepoV2::new());
+        let service = ExerciseService::new(content_repo);
+
+        let fragile = LearnerContext {
+            energy: 0.2,
+            stability: 1.0,
+            review_count: 3,
+            recent_failures: 2,
+            recent_exercise_types: vec![],
+            arabic_level: None,
+        };
+        let exercise = service
+            .generate_exercise_adaptive(11, "VERSE:1:1", &fragile)
+            .await
+            .unwrap();
+        assert_eq!(exercise.type_name(), "missing_word_mcq");
+
+        let mature = LearnerContext {
+            energy: 0.8,
+            stability: 20.0,
+            review_count: 6,
+            recent_failures: 0,
+            recent_exercise_types: vec![],
+            arabic_level: None,
+        };
+        let exercise = service
+            .generate_exercise_adaptive(11, "VERSE:1:1", &mature)
+            .await
+            .unwrap();
+        assert_eq!(exercise.type_name(), "full_verse_input");
+        assert_eq!(service.selection_policy_name(), "adaptive");
+    }
+
+    #[tokio::test]
+    async fn test_generate_exercise_adaptive_skips_unsupported_types() {
+        let content_repo = Arc::new(MockContentRepoV2::new());
+        let service = ExerciseService::new(content_repo).with_exercise_types(&["cloze_deletion"]);
+
+        // Full verse input and mutashabihat rank first for a mature verse
+        let mature = LearnerContext {
+            energy: 0.8,
+            stability: 20.0,
+            review_count: 6,
+            recent_failures: 0,
+            recent_exercise_types: vec![],
+            arabic_level: None,
+        };
+        let exercise = service
+            .generate_exercise_adaptive(11, "VERSE:1:1", &mature)
+            .await
+            .unwrap();
+        assert_eq!(exercise.type_name(), "cloze_deletion");
+    }
+
+    #[tokio::test]
+    async fn test_generate_exercise_adaptive_falls_back_to_text_exercise() {
+        let content_repo = Arc::new(MockContentRepoV2::new());
+        let service = ExerciseService::new(content_repo).with_exercise_types(&[
+            "memorization",
+            "translation",
+            "cloze_deletion",
+        ]);
+        assert!(service.supports_axis(KnowledgeAxis::Tajweed));
+
+        // No tajweed exercise is supported
+        let ukey = node_id::knowledge("VERSE:1:1", KnowledgeAxis::Tajweed);
+        let exercise = service
+            .generate_exercise_adaptive(11, &ukey, &LearnerContext::default())
+            .await
+            .unwrap();
+        assert_eq!(exercise, ExerciseData::Memorization { node_id: 11 });
+
+        let service = ExerciseService::new(Arc::new(MockContentRepoV2::new()))
+            .with_exercise_types(&["cloze_deletion"]);
+        assert!(!service.supports_axis(KnowledgeAxis::Tajweed));
+        assert!(service
+            .generate_exercise_adaptive(11, &ukey, &LearnerContext::default())
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_generate_and_validate_word_order() {
+        let content_repo = Arc::new(MockContentRepoV2::new());
+        let service = ExerciseService::new(content_repo.clone());
+
+        let exercise = service
+            .generate_by_type("word_order", 11, "VERSE:1:1")
+            .await
+            .unwrap();
+        let ExerciseData::WordOrder {
+            correct_sequence,
+            shuffled_word_node_ids,
+            tile_texts,
+            ..
+        } = &exercise
+        else {
+            panic!("Expected WordOrder exercise");
+        };
+        assert_eq!(correct_sequence.len(), 4);
+        assert_ne!(shuffled_word_node_ids, correct_sequence);
+        assert_eq!(tile_texts.len(), 4);
+
+        let exact = AnswerInput::Sequence {
+            values: correct_sequence.clone(),
+        };
+        let result = service
+            .validate_answer(&exercise, &exact, None)
+            .await
+            .unwrap();
+        assert!(result.is_correct);
+        assert_eq!(result.similarity_score, Some(1.0));
+
+        // Hints cap the grade of a correct answer
+        let hinted = service
+            .validate_answer(&exercise, &exact, Some(crate::HintLevel::FirstWord))
+            .await
+            .unwrap();
+        assert!(hinted.is_correct);
+        assert_eq!(hinted.suggested_grade, crate::ReviewGrade::Hard);
+
+        let mut swapped = correct_sequence.clone();
+        swapped.swap(0, 1);
+        let partial = AnswerInput::Sequence { values: swapped };
+        let result = service
+            .validate_answer(&exercise, &partial, None)
+            .await
+            .unwrap();
+        assert!(!result.is_correct);
+        assert_eq!(result.similarity_score, Some(0.75));
+        assert_eq!(result.suggested_grade, crate::ReviewGrade::Hard);
+
+        // Answers must be a permutation of the tiles
+        let mut repeated = correct_sequence.clone();
+        repeated[3] = repeated[2];
+        let mut extra = correct_sequence.clone();
+        extra.push(correct_sequence[0]);
+        for values in [repeated, extra, correct_sequence[..3].to_vec()] {
+            let answer = AnswerInput::Sequence { values };
+            let result = service
+                .validate_answer(&exercise, &answer, None)
+                .await
+                .unwrap();
+            assert!(!result.is_correct);
+            assert_eq!(result.similarity_score, Some(0.0));
+            assert_eq!(result.suggested_grade, crate::ReviewGrade::Again);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_transliteration_exercises_for_weak_readers() {
+        let content_repo = Arc::new(MockContentRepoV2::new());
+        let service = ExerciseService::new(content_repo);
+        let word_id = node_id::encode_word_instance(1, 1, 4);
+        let ukey = "WORD_INSTANCE:1:1:4";
+
+        let beginner = LearnerContext::default().with_arabic_level(Some(ArabicLevel::None));
+        let exercise = service
+            .generate_exercise_adaptive(word_id, ukey, &beginner)
+            .await
+            .unwrap();
+        assert_eq!(exercise.type_name(), "transliteration_to_arabic");
+        let result = service
+            .validate_answer(&exercise, &AnswerInput::WordId { value: word_id }, None)
+            .await
+            .unwrap();
+        assert!(result.is_correct);
+
+        let exercise = service
+            .generate_by_type("arabic_to_transliteration", word_id, ukey)
+            .await
+            .unwrap();
+        assert_eq!(
+            exercise,
+            ExerciseData::ArabicToTransliteration {
+                node_id: word_id,
+                verse_key: "1:1".to_string(),
+            }
+        );
+        let lenient = AnswerInput::Text {
+            value: "Ar Raheemi".to_string(),
+        };
+        let result = service
+            .validate_answer(&exercise, &lenient, None)
+            .await
+            .unwrap();
+        assert!(result.is_correct);
+
+        let wrong = AnswerInput::Text {
+            value: "ar-rahmani".to_string(),
+        };
+        let result = service
+            .validate_answer(&exercise, &wrong, None)
+            .await
+            .unwrap();
+        assert!(!result.is_correct);
+        assert_eq!(result.correct_answer.as_deref(), Some("ar-raḥīmi"));
+    }
+
+    #[tokio::test]
+    async fn test_exercise_regenerates_from_seed() {
+        let content_repo = Arc::new(MockContentRepoV2::new());
+        let service = ExerciseService::new(content_repo.clone()).with_rng_seed(7);
+
+        for type_name in ["word_order", "missing_word_mcq", "cloze_deletion"] {
+            let exercise = service
+                .generate_by_type(type_name, 11, "VERSE:1:1")
+                .await
+                .unwrap();
+            let seed = exercise
+                .seed()
+                .expect("randomized exercise stores its seed");
+            let strategy = exercise.distractor_strategy().cloned().unwrap_or_default();
+
+            let regenerated = ExerciseService::new(content_repo.clone())
+                .generate_by_type_seeded(
+                    type_name,
+                    11,
+                    "VERSE:1:1",
+                    seed,
+                    strategy.difficulty,
+                    strategy.semantic_scoring(),
+                )
+                .await
+                .unwrap();
+            assert_eq!(regenerated, exercise);
+        }
+
+        // Services seeded the same way hand out the same exercises
+        let a = ExerciseService::new(content_repo.clone()).with_rng_seed(42);
+        let b = ExerciseService::new(content_repo).with_rng_seed(42);
+        assert_eq!(
+            a.generate_by_type("word_order", 11, "VERSE:1:1")
+                .await
+                .unwrap(),
+            b.generate_by_type("word_order", 11, "VERSE:1:1")
+                .await
+                .unwrap()
+        );
+    }
+
     #[tokio::test]
     async fn test_generate_exercise_v2_serialization() {
         let content_repo = Arc::new(MockContentRepoV2::new());
@@ -1411,6 +2345,8 @@ mod tests {
             node_id: 11,
             context_position: 1,
             distractor_node_ids: vec![2, 3, 4],
+            seed: 0,
+            distractor_strategy: Default::default(),
         };
         assert!(!guardrail_allows(
             "WORD:1:1:1",
@@ -1424,11 +2360,15 @@ mod tests {
         let bad = ExerciseData::McqArToEn {
             node_id: 11,
             distractor_node_ids: vec![2, 3, 4],
+            seed: 0,
+            distractor_strategy: Default::default(),
         };
         let good = ExerciseData::MissingWordMcq {
             node_id: 11,
             blank_position: 2,
             distractor_node_ids: vec![2, 3, 4],
+            seed: 0,
+            distractor_strategy: Default::default(),
         };
 
         assert!(!guardrail_allows(
@@ -0,0 +1,101 @@
+// exercises/tafsir.rs
+// Tafsir exercises
+
+use super::exercise_data::ExerciseData;
+use super::generators::generate_tafsir_key_lesson;
+use super::types::Exercise;
+use crate::ContentRepository;
+use anyhow::Result;
+
+// ============================================================================
+// Exercise 36: Tafsir Key Lesson
+// ============================================================================
+
+/// Exercise for picking the key lesson the installed tafsir draws from a verse
+#[derive(Debug)]
+pub struct TafsirKeyLessonExercise {
+    node_id: i64,
+    verse_key: String,
+    correct_lesson: String,
+    options: Vec<String>,
+}
+
+impl TafsirKeyLessonExercise {
+    /// Create a new Tafsir Key Lesson exercise for a verse (or its tafsir
+    /// knowledge node), with options picked with the seed
+    pub async fn new(
+        node_id: i64,
+        ukey: &str,
+        seed: u64,
+        content_repo: &dyn ContentRepository,
+    ) -> Result<Self> {
+        let ExerciseData::TafsirKeyLesson {
+            tafsir_id,
+            passage_key,
+            option_passage_keys,
+            ..
+        } = generate_tafsir_key_lesson(node_id, ukey, seed, content_repo).await?
+        else {
+            unreachable!("generate_tafsir_key_lesson returns TafsirKeyLesson");
+        };
+
+        let mut correct_lesson = None;
+        let mut options = Vec::with_capacity(option_passage_keys.len());
+        for key in &option_passage_keys {
+            let lesson = content_repo
+                .get_tafsir_passage(key, tafsir_id)
+                .await?
+                .and_then(|passage| passage.key_lesson)
+                .ok_or_else(|| anyhow::anyhow!("No key lesson in the tafsir of verse {}", key))?;
+            if *key == passage_key {
+                correct_lesson = Some(lesson.clone());
+            }
+            options.push(lesson);
+        }
+        let correct_lesson = correct_lesson
+            .ok_or_else(|| anyhow::anyhow!("Key lesson of verse {} not offered", passage_key))?;
+
+        Ok(Self {
+            node_id,
+            verse_key: passage_key,
+            correct_lesson,
+            options,
+        })
+    }
+
+    /// Key lessons offered
+    pub fn get_options(&self) -> &[String] {
+        &self.options
+    }
+
+    /// Get the correct key lesson
+    pub fn get_correct_lesson(&self) -> &str {
+        &self.correct_lesson
+    }
+}
+
+impl Exercise for TafsirKeyLessonExercise {
+    fn generate_question(&self) -> String {
+        format!(
+            "What is the key lesson of the passage beginning at verse {}?",
+            self.verse_key
+        )
+    }
+
+    fn check_answer(&self, answer: &str) -> bool {
+        let answer = answer.trim();
+        answer.eq_ignore_ascii_case(self.correct_lesson.trim()) || answer == self.verse_key
+    }
+
+    fn get_hint(&self) -> Option<String> {
+        Some(format!("One of: {}", self.options.join(" | ")))
+    }
+
+    fn get_node_id(&self) -> i64 {
+        self.node_id
+    }
+
+    fn get_type_name(&self) -> &'static str {
+        "tafsir_key_lesson"
+    }
+}
@@ -0,0 +1,103 @@
+// exercises/tajweed.rs
+// Tajweed rule exercises
+
+use super::exercise_data::ExerciseData;
+use super::generators::generate_identify_tajweed_rule;
+use super::types::Exercise;
+use super::validator::word_of_node;
+use crate::{ContentRepository, TajweedRule};
+use anyhow::Result;
+
+// ============================================================================
+// Exercise 34: Identify Tajweed Rule
+// ============================================================================
+
+/// Exercise for naming the tajweed rule on highlighted letters of a verse
+#[derive(Debug)]
+pub struct IdentifyTajweedRuleExercise {
+    node_id: i64,
+    word_text: String,
+    /// Highlighted letters of `word_text`
+    span_text: String,
+    correct_rule: TajweedRule,
+    options: Vec<TajweedRule>,
+}
+
+impl IdentifyTajweedRuleExercise {
+    /// Create a new Identify Tajweed Rule exercise for a verse (or its
+    /// tajweed knowledge node), highlighting an annotated span picked with
+    /// the seed
+    ///
+    /// The word is always shown in Uthmani script, which the highlighted
+    /// letters are cut from.
+    pub async fn new(
+        node_id: i64,
+        ukey: &str,
+        seed: u64,
+        content_repo: &dyn ContentRepository,
+    ) -> Result<Self> {
+        let ExerciseData::IdentifyTajweedRule {
+            word_node_id,
+            start_char,
+            end_char,
+            correct_rule,
+            rule_options,
+            ..
+        } = generate_identify_tajweed_rule(node_id, ukey, seed, content_repo).await?
+        else {
+            unreachable!("generate_identify_tajweed_rule returns IdentifyTajweedRule");
+        };
+
+        // Spans index the Uthmani text
+        let word = word_of_node(word_node_id, content_repo).await?;
+        let span_text: String = word
+            .text_uthmani
+            .chars()
+            .skip(start_char as usize)
+            .take((end_char - start_char) as usize)
+            .collect();
+
+        Ok(Self {
+            node_id,
+            word_text: word.text_uthmani,
+            span_text,
+            correct_rule,
+            options: rule_options,
+        })
+    }
+
+    /// Names of the rules offered
+    pub fn get_options(&self) -> Vec<&'static str> {
+        self.options.iter().map(TajweedRule::name).collect()
+    }
+
+    /// Get the correct rule
+    pub fn get_correct_rule(&self) -> TajweedRule {
+        self.correct_rule
+    }
+}
+
+impl Exercise for IdentifyTajweedRuleExercise {
+    fn generate_question(&self) -> String {
+        format!(
+            "Which tajweed rule applies to '{}' in '{}'?",
+            self.span_text, self.word_text
+        )
+    }
+
+    fn check_answer(&self, answer: &str) -> bool {
+        self.correct_rule.matches_answer(answer)
+    }
+
+    fn get_hint(&self) -> Option<String> {
+        Some(format!("One of: {}", self.get_options().join(", ")))
+    }
+
+    fn get_node_id(&self) -> i64 {
+        self.node_id
+    }
+
+    fn get_type_name(&self) -> &'static str {
+        "identify_tajweed_rule"
+    }
+}
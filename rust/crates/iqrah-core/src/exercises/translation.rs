@@ -3,10 +3,12 @@
 
 use super::types::Exercise;
 use crate::domain::node_id;
-use crate::semantic::grader::{SemanticGradeLabel, SemanticGrader, SEMANTIC_EMBEDDER};
+use crate::semantic::grader::{SemanticGradeLabel, SemanticGrader};
 use crate::{ContentRepository, KnowledgeNode};
 use anyhow::Result;
+use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
+use rand::SeedableRng;
 
 /// Exercise for testing understanding of word meanings
 /// Tests the user's knowledge of translation/meaning using semantic similarity
@@ -62,16 +64,8 @@ impl Exercise for TranslationExercise {
     }
 
     fn check_answer(&self, answer: &str) -> bool {
-        // Get embedder, return false if not initialized
-        let embedder = match SEMANTIC_EMBEDDER.get() {
-            Some(e) => e,
-            None => {
-                tracing::error!("Semantic embedder not initialized! Call ExerciseService::init_semantic_model() first");
-                return false;
-            }
-        };
-
-        let grader = SemanticGrader::new(embedder);
+        // Lexical grading when no semantic model is loaded
+        let grader = SemanticGrader::global();
 
         // Grade the answer, return false on error
         let grade = match grader.grade_answer(answer, &self.translation) {
@@ -133,9 +127,12 @@ impl ContextualTranslationExercise {
     /// - The word and its verse context
     /// - The word's translation (correct answer)
     /// - Alternative translations from other words (distractors)
+    ///
+    /// Options are shuffled with `seed`.
     pub async fn new(
         word_node_id: i64,
         ukey: &str,
+        seed: u64,
         content_repo: &dyn ContentRepository,
     ) -> Result<Self> {
         // Parse knowledge node
@@ -191,7 +188,7 @@ impl ContextualTranslationExercise {
         // Combine correct answer with distractors and shuffle
         let mut options = vec![correct_answer.clone()];
         options.extend(distractors);
-        options.shuffle(&mut rand::thread_rng());
+        options.shuffle(&mut StdRng::seed_from_u64(seed));
 
         Ok(Self {
             node_id: word_node_id,
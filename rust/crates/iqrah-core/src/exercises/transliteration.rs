@@ -0,0 +1,67 @@
+// exercises/transliteration.rs
+// Lenient comparison of typed Latin transliterations
+//
+// Learners type transliterations in many conventions ("ar-raḥīm",
+// "arraheem", "Ar Rahim"). Both sides are folded to plain lowercase letters
+// before comparing: diacritics and ayn/hamza marks are dropped, long-vowel
+// spellings (ee, oo, aa) and doubled consonants collapse to one letter.
+
+use super::word_diff::char_similarity;
+
+/// Normalize a Latin transliteration for comparison
+pub fn normalize_transliteration(text: &str) -> String {
+    let mut normalized = String::with_capacity(text.len());
+    for c in text.to_lowercase().chars() {
+        let folded = match c {
+            'ā' | 'á' | 'à' | 'â' | 'ä' => 'a',
+            'ī' | 'í' | 'ì' | 'î' | 'ï' | 'e' | 'ē' | 'é' => 'i',
+            'ū' | 'ú' | 'ù' | 'û' | 'ü' | 'o' | 'ō' | 'ó' => 'u',
+            'ḥ' | 'ḫ' | 'ẖ' => 'h',
+            'ṣ' | 'š' => 's',
+            'ḍ' | 'ḏ' => 'd',
+            'ṭ' | 'ṯ' => 't',
+            'ẓ' | 'ž' => 'z',
+            'ġ' => 'g',
+            c if c.is_ascii_alphanumeric() => c,
+            // Apostrophes, ayn/hamza marks, hyphens and spaces
+            _ => continue,
+        };
+        if normalized.ends_with(folded) {
+            continue;
+        }
+        normalized.push(folded);
+    }
+    normalized
+}
+
+/// Similarity of two transliterations (0.0 to 1.0) after normalization,
+/// from their character edit distance
+pub fn transliteration_similarity(expected: &str, actual: &str) -> f64 {
+    let expected: Vec<char> = normalize_transliteration(expected).chars().collect();
+    let actual: Vec<char> = normalize_transliteration(actual).chars().collect();
+    char_similarity(&expected, &actual)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conventions_normalize_alike() {
+        let expected = normalize_transliteration("ar-raḥīm");
+        assert_eq!(normalize_transliteration("arraheem"), expected);
+        assert_eq!(normalize_transliteration("Ar Rahim"), expected);
+        assert_eq!(
+            normalize_transliteration("ʿālamīn"),
+            normalize_transliteration("'aalameen")
+        );
+    }
+
+    #[test]
+    fn test_similarity() {
+        assert_eq!(transliteration_similarity("bismi", "Bismi"), 1.0);
+        assert_eq!(transliteration_similarity("bismi", "bism"), 0.8);
+        assert_eq!(transliteration_similarity("", ""), 1.0);
+        assert!(transliteration_similarity("bismi", "rahim") < 0.5);
+    }
+}
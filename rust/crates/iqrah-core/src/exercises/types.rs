@@ -1,6 +1,7 @@
 // exercises/types.rs
 // Exercise trait and common types
 
+use super::word_diff::WordDiff;
 use serde::{Deserialize, Serialize};
 
 /// Trait for all exercise types
@@ -37,6 +38,9 @@ pub struct ExerciseResponse {
     /// Semantic similarity score (0.0 to 1.0) - only present for semantic grading
     #[serde(skip_serializing_if = "Option::is_none")]
     pub similarity_score: Option<f32>,
+    /// Word-aligned diff - only present for typed verse input
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub word_diff: Option<WordDiff>,
 }
 
 /// Enum for different exercise types (Phase 4.3)
@@ -47,6 +51,8 @@ pub enum ExerciseType {
     McqArToEn(Box<dyn Exercise>),
     McqEnToAr(Box<dyn Exercise>),
     TranslatePhrase(Box<dyn Exercise>),
+    Tajweed(Box<dyn Exercise>),
+    Tafsir(Box<dyn Exercise>),
 }
 
 impl ExerciseType {
@@ -58,6 +64,8 @@ impl ExerciseType {
             ExerciseType::McqArToEn(ex) => ex.as_ref(),
             ExerciseType::McqEnToAr(ex) => ex.as_ref(),
             ExerciseType::TranslatePhrase(ex) => ex.as_ref(),
+            ExerciseType::Tajweed(ex) => ex.as_ref(),
+            ExerciseType::Tafsir(ex) => ex.as_ref(),
         }
     }
 
@@ -69,6 +77,8 @@ impl ExerciseType {
             ExerciseType::McqArToEn(_) => "mcq_ar_to_en",
             ExerciseType::McqEnToAr(_) => "mcq_en_to_ar",
             ExerciseType::TranslatePhrase(_) => "translate_phrase",
+            ExerciseType::Tajweed(_) => "identify_tajweed_rule",
+            ExerciseType::Tafsir(_) => "tafsir_key_lesson",
         }
     }
 }
@@ -2,10 +2,16 @@
 // Answer validation logic for enum-based exercises
 
 use super::exercise_data::ExerciseData;
-use crate::semantic::grader::{SemanticGradeLabel, SemanticGrader, SEMANTIC_EMBEDDER};
-use crate::ContentRepository;
+use super::script;
+use super::transliteration::transliteration_similarity;
+use super::word_diff::{diff_words, GradeThresholds};
+use crate::domain::node_id;
+use crate::semantic::grader::{SemanticGradeLabel, SemanticGrader};
+use crate::{ContentRepository, HintLevel, ReviewGrade, Script, Word};
 use anyhow::Result;
+use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// Answer input from the user
 ///
@@ -15,7 +21,7 @@ use serde::{Deserialize, Serialize};
 /// - WordId: Node ID of a selected word
 /// - VerseKey: Verse reference (e.g., "1:1")
 /// - OptionIndex: Index of selected MCQ option (0-indexed)
-/// - Sequence: Ordered list of node IDs
+/// - Sequence: Ordered list of node IDs (verses or word tiles)
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum AnswerInput {
@@ -29,7 +35,7 @@ pub enum AnswerInput {
     VerseKey { value: String },
     /// MCQ option index (0-indexed)
     OptionIndex { value: usize },
-    /// Ordered sequence of IDs (for ayah_sequence)
+    /// Ordered sequence of IDs (for ayah_sequence, word_order)
     Sequence { values: Vec<i64> },
 }
 
@@ -46,6 +52,80 @@ pub struct ValidationResult {
     pub semantic_grade: Option<String>,
     /// The correct answer (if wrong or for review)
     pub correct_answer: Option<String>,
+    /// Review grade to record for this answer
+    pub suggested_grade: ReviewGrade,
+}
+
+impl ValidationResult {
+    /// Result of a graded answer. The correct answer is only revealed when the
+    /// answer was wrong.
+    pub fn graded(
+        is_correct: bool,
+        feedback: String,
+        similarity_score: Option<f32>,
+        correct_answer: String,
+        thresholds: &GradeThresholds,
+    ) -> Self {
+        Self {
+            is_correct,
+            feedback: Some(feedback),
+            similarity_score,
+            semantic_grade: None,
+            correct_answer: if is_correct {
+                None
+            } else {
+                Some(correct_answer)
+            },
+            suggested_grade: Self::suggest_grade(is_correct, similarity_score, thresholds),
+        }
+    }
+
+    /// Result for an answer of the wrong shape (e.g. text for an MCQ)
+    pub fn unexpected_input(expected: &str, correct_answer: Option<String>) -> Self {
+        Self {
+            is_correct: false,
+            feedback: Some(format!("Expected {} input", expected)),
+            similarity_score: None,
+            semantic_grade: None,
+            correct_answer,
+            suggested_grade: ReviewGrade::Again,
+        }
+    }
+
+    /// Review grade for an answer: Good/Again for all-or-nothing answers,
+    /// otherwise the partial-credit score mapped through `thresholds`
+    /// (never below Good when correct, never above Hard when wrong).
+    pub fn suggest_grade(
+        is_correct: bool,
+        similarity_score: Option<f32>,
+        thresholds: &GradeThresholds,
+    ) -> ReviewGrade {
+        let Some(score) = similarity_score else {
+            return if is_correct {
+                ReviewGrade::Good
+            } else {
+                ReviewGrade::Again
+            };
+        };
+
+        match (is_correct, thresholds.grade(f64::from(score))) {
+            (true, ReviewGrade::Again | ReviewGrade::Hard) => ReviewGrade::Good,
+            (false, ReviewGrade::Easy | ReviewGrade::Good) => ReviewGrade::Hard,
+            (_, grade) => grade,
+        }
+    }
+
+    /// Cap the suggested grade at what the most revealing hint shown still
+    /// allows (see `HintLevel::max_grade`)
+    pub fn capped_by_hint(mut self, hint_level: Option<HintLevel>) -> Self {
+        if let Some(hint_level) = hint_level {
+            let max_grade = hint_level.max_grade();
+            if (self.suggested_grade as u8) > (max_grade as u8) {
+                self.suggested_grade = max_grade;
+            }
+        }
+        self
+    }
 }
 
 /// Answer keys for validation
@@ -66,18 +146,23 @@ pub enum AnswerKeys {
     Root { letters: String },
     /// Part of speech tag
     PosTag { value: String },
+    /// Verse reference
+    VerseKey { value: String },
 }
 
 /// Trait for validating answers to exercises
 ///
 /// This trait provides the core validation logic for checking user answers
 /// against the correct answer keys, with support for different input types.
-pub trait ExerciseValidator {
+/// Content not stored in the `ExerciseData` (verse text, translations) is
+/// fetched through the `ContentRepository`.
+#[async_trait]
+pub trait ExerciseValidator: Send + Sync {
     /// Validate an answer input against the exercise data
     ///
     /// Fetches necessary content from the repository and checks if the answer is correct.
-    /// Returns a ValidationResult with feedback and scoring.
-    fn validate(
+    /// Returns a ValidationResult with feedback, scoring and a suggested review grade.
+    async fn validate(
         &self,
         exercise: &ExerciseData,
         answer: &AnswerInput,
@@ -85,7 +170,7 @@ pub trait ExerciseValidator {
     ) -> Result<ValidationResult>;
 
     /// Get answer keys for an exercise (what the correct answer is)
-    fn get_answer_keys(
+    async fn get_answer_keys(
         &self,
         exercise: &ExerciseData,
         content_repo: &dyn ContentRepository,
@@ -93,15 +178,74 @@ pub trait ExerciseValidator {
 }
 
 /// Default validator implementation
-pub struct DefaultValidator;
+///
+/// Accepted answer shapes per exercise type:
+/// - Typed Arabic (memorization, cloze, first letter, first word, full verse,
+///   ayah chain, reverse cloze, echo recall, recite page): `Text`, graded
+///   word by word
+/// - Typed English (translation, contextual translation, translate phrase):
+///   `Text`, with semantic grading when the model is loaded
+/// - Word MCQs (mcq_ar_to_en, mcq_en_to_ar, missing/next word): `WordId` of
+///   the chosen option, or its `Text`
+/// - Ordering (ayah_sequence, word_order): `Sequence`
+/// - Sequence recall: `OptionIndex` or `Sequence`
+/// - Find the mistake: `Position`
+/// - Identify root / POS tagging / grammar feature: `Text` (`OptionIndex`
+///   for POS and grammar feature options)
+/// - Cross-verse connection: `WordId` or `VerseKey` of the chosen verse
+/// - Mutashabihat: `VerseKey` or `OptionIndex`
+/// - Transliteration to Arabic: `WordId` of the chosen option, or its `Text`
+/// - Arabic to transliteration: `Text`, normalized leniently
+/// - Root family: `Sequence` of the picked word-instance IDs (any order)
+/// - Lemma meaning: `Sequence` giving, for each lemma, the index of the
+///   meaning matched to it
+/// - Location MCQs (surah, verse number, page half, verse line): `Position`
+///   or `OptionIndex`
+/// - Recite page: `Text`, graded word by word against the page's words
+/// - Page first word: `WordId` of the chosen option, or its `Text`
+/// - Listen and identify verse: `VerseKey` or `OptionIndex`
+/// - Listen and continue / listen and identify word: `WordId` of the chosen
+///   option, or its `Text`
+/// - Identify tajweed rule: `OptionIndex`, or `Text` naming the rule (slug or
+///   name)
+/// - Find tajweed rule: `Sequence` of the picked word-instance IDs (any order)
+/// - Tafsir key lesson: `VerseKey` of the chosen passage (its first verse) or
+///   `OptionIndex`
+/// - Revelation occasion: `VerseKey` or `OptionIndex`
+///
+/// Arabic answer keys are read in the validator's script (Uthmani unless set
+/// with `with_script`), and partial-credit scores are graded with its
+/// `GradeThresholds` (see `with_grade_thresholds`).
+pub struct DefaultValidator {
+    script: Script,
+    /// Maps partial-credit scores to review grades
+    thresholds: GradeThresholds,
+}
 
 impl DefaultValidator {
     /// Create a new default validator
     pub fn new() -> Self {
-        Self
+        Self::with_script(Script::default())
+    }
+
+    /// Validator whose Arabic answer keys are in `script`
+    pub fn with_script(script: Script) -> Self {
+        Self {
+            script,
+            thresholds: GradeThresholds::default(),
+        }
+    }
+
+    /// Grade partial-credit answers with `thresholds` instead of the defaults
+    pub fn with_grade_thresholds(mut self, thresholds: GradeThresholds) -> Self {
+        self.thresholds = thresholds;
+        self
     }
 
     /// Normalize Arabic text for comparison (remove diacritics)
+    ///
+    /// Uthmani, Indopak and simple spellings of a word normalize alike, so
+    /// answers are accepted in any of the scripts.
     pub fn normalize_arabic(text: &str) -> String {
         let normalized = text
             .chars()
@@ -122,7 +266,11 @@ impl DefaultValidator {
                     '\u{0655}' | // Hamza below
                     '\u{0656}' | // Subscript alef
                     '\u{0657}' | // Inverted damma
-                    '\u{0658}' // Mark noon ghunna
+                    '\u{0658}' | // Mark noon ghunna
+                    '\u{0659}'..='\u{065F}' | // Indopak vowel marks
+                    '\u{0640}' | // Tatweel
+                    '\u{06D6}'..='\u{06ED}' | // Quranic annotation marks (pause signs, small letters)
+                    '\u{08F0}'..='\u{08F2}' // Open tanween
                 )
             })
             // Normalize letter variants
@@ -133,7 +281,11 @@ impl DefaultValidator {
                 'آ' => 'ا', // Alif with madda
                 'ٰ' => 'ا',  // Alif khanjariyyah
                 'ى' => 'ي', // Alif maqsurah
+                'ی' => 'ي', // Farsi ya (Indopak)
+                'ک' => 'ك', // Keheh (Indopak)
                 'ة' => 'ه', // Ta marbuta
+                'ۃ' => 'ه', // Ta marbuta goal (Indopak)
+                'ہ' => 'ه', // Ha goal (Indopak)
                 'ۀ' => 'ه', // Hamza on Ha
                 _ => c,
             })
@@ -166,16 +318,322 @@ impl DefaultValidator {
         text.trim().to_lowercase()
     }
 
-    /// Perform semantic similarity grading (for Arabic text)
-    pub fn semantic_grade(user_text: &str, correct_text: &str) -> Result<ValidationResult> {
-        // Get embedder
-        let embedder = SEMANTIC_EMBEDDER.get().ok_or_else(|| {
-            anyhow::anyhow!(
-                "Semantic embedder not initialized. Call ExerciseService::init_semantic_model()"
-            )
-        })?;
+    /// Validate a label MCQ answer (POS tag, grammar feature) given either
+    /// as the label itself (`Text`) or as an index into `options`
+    fn validate_label_choice(
+        &self,
+        answer: &AnswerInput,
+        correct: &str,
+        options: &[String],
+        label_name: &str,
+    ) -> ValidationResult {
+        let chosen = match answer {
+            AnswerInput::Text { value } => Some(value.as_str()),
+            AnswerInput::OptionIndex { value } => options.get(*value).map(String::as_str),
+            _ => {
+                return ValidationResult::unexpected_input(
+                    "text or option index",
+                    Some(correct.to_string()),
+                );
+            }
+        };
+
+        let is_correct = chosen.map(Self::normalize_pos) == Some(Self::normalize_pos(correct));
+        ValidationResult::graded(
+            is_correct,
+            if is_correct {
+                "Correct!".to_string()
+            } else {
+                format!("The correct {} is: {}", label_name, correct)
+            },
+            None,
+            correct.to_string(),
+            &self.thresholds,
+        )
+    }
+
+    /// Validate a numeric MCQ answer given either as the value itself
+    /// (`Position`) or as an index into `options` (`OptionIndex`)
+    fn validate_choice(
+        &self,
+        answer: &AnswerInput,
+        correct: i32,
+        options: &[i32],
+        describe: impl Fn(i32) -> String,
+    ) -> ValidationResult {
+        let chosen = match answer {
+            AnswerInput::Position { value } => Some(*value),
+            AnswerInput::OptionIndex { value } => options.get(*value).copied(),
+            _ => {
+                return ValidationResult::unexpected_input(
+                    "option index or position",
+                    Some(correct.to_string()),
+                )
+            }
+        };
+
+        let is_correct = chosen == Some(correct);
+        ValidationResult::graded(
+            is_correct,
+            if is_correct {
+                "Correct!".to_string()
+            } else {
+                format!("The correct answer is: {}", describe(correct))
+            },
+            None,
+            correct.to_string(),
+            &self.thresholds,
+        )
+    }
+
+    /// Validate a verse MCQ answer given either as the verse key itself
+    /// (`VerseKey`) or as an index into `options` (`OptionIndex`).
+    /// `describe_mistake` words the feedback for the verse chosen instead.
+    fn validate_verse_choice(
+        &self,
+        answer: &AnswerInput,
+        correct: &str,
+        options: &[String],
+        describe_mistake: impl Fn(Option<&str>) -> String,
+    ) -> ValidationResult {
+        let chosen = match answer {
+            AnswerInput::VerseKey { value } => Some(value.as_str()),
+            AnswerInput::OptionIndex { value } => options.get(*value).map(String::as_str),
+            _ => {
+                return ValidationResult::unexpected_input(
+                    "verse key or option index",
+                    Some(correct.to_string()),
+                );
+            }
+        };
+
+        let is_correct = chosen == Some(correct);
+        ValidationResult::graded(
+            is_correct,
+            if is_correct {
+                "Correct!".to_string()
+            } else {
+                describe_mistake(chosen)
+            },
+            None,
+            correct.to_string(),
+            &self.thresholds,
+        )
+    }
+
+    /// Validate a pick of words, given as a `Sequence` of word IDs in any
+    /// order (or a single `WordId`), against the expected words. Wrong picks
+    /// cost as much as missed words; `describe_miss(found, wrong)` words the
+    /// feedback when the pick is not exact.
+    fn validate_word_picks(
+        &self,
+        answer: &AnswerInput,
+        expected: &[i64],
+        correct_answer: String,
+        describe_miss: impl Fn(usize, usize) -> String,
+    ) -> ValidationResult {
+        let picked: Vec<i64> = match answer {
+            AnswerInput::Sequence { values } => values.clone(),
+            AnswerInput::WordId { value } => vec![*value],
+            _ => {
+                return ValidationResult::unexpected_input(
+                    "sequence of word IDs",
+                    Some(correct_answer),
+                )
+            }
+        };
+        let mut distinct = picked;
+        distinct.sort_unstable();
+        distinct.dedup();
+        let found = distinct.iter().filter(|id| expected.contains(id)).count();
+        let wrong = distinct.len() - found;
+
+        let score = found as f32 / (expected.len() + wrong).max(1) as f32;
+        let is_correct = found == expected.len() && wrong == 0;
+        ValidationResult::graded(
+            is_correct,
+            if is_correct {
+                "Correct!".to_string()
+            } else {
+                describe_miss(found, wrong)
+            },
+            Some(score),
+            correct_answer,
+            &self.thresholds,
+        )
+    }
+
+    /// Texts of word-instance nodes in the validator's script, space separated
+    async fn words_text(
+        &self,
+        word_node_ids: &[i64],
+        content_repo: &dyn ContentRepository,
+    ) -> Result<String> {
+        let mut texts = Vec::with_capacity(word_node_ids.len());
+        for id in word_node_ids {
+            let word = word_of_node(*id, content_repo).await?;
+            texts.push(script::word_text(&word, self.script, content_repo).await?);
+        }
+        Ok(texts.join(" "))
+    }
+
+    /// Validate typed Arabic against the expected text, word by word
+    fn validate_arabic_text(&self, answer: &AnswerInput, expected: &str) -> ValidationResult {
+        let AnswerInput::Text { value: user_text } = answer else {
+            return ValidationResult::unexpected_input("text", None);
+        };
+
+        let diff = diff_words(expected, user_text);
+        let is_correct = diff.is_exact();
+        ValidationResult::graded(
+            is_correct,
+            if is_correct {
+                "Correct!".to_string()
+            } else {
+                format!("{} word(s) differ from the expected text", diff.distance)
+            },
+            Some(diff.accuracy as f32),
+            expected.to_string(),
+            &self.thresholds,
+        )
+    }
+
+    /// Validate a typed English translation. Exact matches (after
+    /// normalization) are correct; otherwise the semantic grader decides when
+    /// the model is loaded.
+    fn validate_english_text(
+        &self,
+        answer: &AnswerInput,
+        expected: &str,
+    ) -> Result<ValidationResult> {
+        let AnswerInput::Text { value: user_text } = answer else {
+            return Ok(ValidationResult::unexpected_input("text", None));
+        };
+
+        if Self::normalize_english(user_text) == Self::normalize_english(expected) {
+            return Ok(ValidationResult::graded(
+                true,
+                "Correct!".to_string(),
+                Some(1.0),
+                expected.to_string(),
+                &self.thresholds,
+            ));
+        }
+
+        Self::semantic_grade(user_text, expected)
+    }
+
+    /// Validate a typed transliteration. Spelling conventions are normalized
+    /// away (see `normalize_transliteration`); the character similarity gives
+    /// partial credit.
+    fn validate_transliteration(&self, answer: &AnswerInput, expected: &str) -> ValidationResult {
+        let AnswerInput::Text { value: user_text } = answer else {
+            return ValidationResult::unexpected_input("text", Some(expected.to_string()));
+        };
+
+        let similarity = transliteration_similarity(expected, user_text);
+        let is_correct = similarity >= 1.0;
+        ValidationResult::graded(
+            is_correct,
+            if is_correct {
+                "Correct!".to_string()
+            } else {
+                format!("The transliteration is: {}", expected)
+            },
+            Some(similarity as f32),
+            expected.to_string(),
+            &self.thresholds,
+        )
+    }
+
+    /// Validate a word MCQ answered with the chosen option's node ID or text
+    fn validate_word_choice(
+        &self,
+        answer: &AnswerInput,
+        correct_node_id: i64,
+        correct_text: &str,
+        normalize: fn(&str) -> String,
+    ) -> ValidationResult {
+        let is_correct = match answer {
+            AnswerInput::WordId { value } => *value == correct_node_id,
+            AnswerInput::Text { value } => normalize(value) == normalize(correct_text),
+            _ => {
+                return ValidationResult::unexpected_input(
+                    "word ID or text",
+                    Some(correct_text.to_string()),
+                )
+            }
+        };
 
-        let grader = SemanticGrader::new(embedder);
+        ValidationResult::graded(
+            is_correct,
+            if is_correct {
+                "Correct!".to_string()
+            } else {
+                format!("The correct answer is: {}", correct_text)
+            },
+            None,
+            correct_text.to_string(),
+            &self.thresholds,
+        )
+    }
+
+    /// Validate an ordering with LCS partial credit
+    fn validate_order<T: PartialEq>(
+        &self,
+        expected: &[T],
+        given: &[T],
+        unit: &str,
+        correct_answer: String,
+    ) -> ValidationResult {
+        let in_order = Self::longest_common_subsequence(expected, given);
+        let is_correct = given == expected;
+        let score = if expected.is_empty() {
+            0.0
+        } else {
+            in_order as f32 / expected.len() as f32
+        };
+
+        ValidationResult::graded(
+            is_correct,
+            if is_correct {
+                "Correct order!".to_string()
+            } else {
+                format!(
+                    "{} of {} {} in the right order",
+                    in_order,
+                    expected.len(),
+                    unit
+                )
+            },
+            Some(score),
+            correct_answer,
+            &self.thresholds,
+        )
+    }
+
+    /// Length of the longest common subsequence of two sequences
+    /// (used for partial credit on ordering exercises)
+    pub fn longest_common_subsequence<T: PartialEq>(a: &[T], b: &[T]) -> usize {
+        let mut prev = vec![0usize; b.len() + 1];
+        let mut curr = vec![0usize; b.len() + 1];
+        for x in a {
+            for (j, y) in b.iter().enumerate() {
+                curr[j + 1] = if x == y {
+                    prev[j] + 1
+                } else {
+                    prev[j + 1].max(curr[j])
+                };
+            }
+            std::mem::swap(&mut prev, &mut curr);
+        }
+        prev[b.len()]
+    }
+
+    /// Perform semantic similarity grading (for Arabic text), degraded to
+    /// lexical similarity when no semantic model is loaded
+    pub fn semantic_grade(user_text: &str, correct_text: &str) -> Result<ValidationResult> {
+        let grader = SemanticGrader::global();
         let grade = grader.grade_answer(user_text, correct_text)?;
 
         Ok(ValidationResult {
@@ -188,6 +646,11 @@ impl DefaultValidator {
             } else {
                 None
             },
+            suggested_grade: match grade.label {
+                SemanticGradeLabel::Excellent => ReviewGrade::Good,
+                SemanticGradeLabel::Partial => ReviewGrade::Hard,
+                SemanticGradeLabel::Incorrect => ReviewGrade::Again,
+            },
         })
     }
 }
@@ -198,31 +661,271 @@ impl Default for DefaultValidator {
     }
 }
 
+/// Verse key ("2:255") of a verse node ID
+fn verse_key_of(node_id: i64) -> Result<String> {
+    node_id::decode_verse(node_id)
+        .map(|(chapter, verse)| format!("{}:{}", chapter, verse))
+        .ok_or_else(|| anyhow::anyhow!("Invalid verse node ID: {}", node_id))
+}
+
+async fn verse_text(
+    verse_key: &str,
+    script: Script,
+    content_repo: &dyn ContentRepository,
+) -> Result<String> {
+    let verse = content_repo
+        .get_verse(verse_key)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("Verse not found: {}", verse_key))?;
+    script::verse_text(&verse, script, content_repo).await
+}
+
+async fn word_at(
+    verse_key: &str,
+    position: i32,
+    content_repo: &dyn ContentRepository,
+) -> Result<Word> {
+    content_repo
+        .get_words_for_verse(verse_key)
+        .await?
+        .into_iter()
+        .find(|w| w.position == position)
+        .ok_or_else(|| anyhow::anyhow!("No word at position {} in {}", position, verse_key))
+}
+
+async fn quran_text(
+    node_id: i64,
+    script: Script,
+    content_repo: &dyn ContentRepository,
+) -> Result<String> {
+    script::node_text(node_id, script, content_repo)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("Quran text not found for node {}", node_id))
+}
+
+async fn english_translation(node_id: i64, content_repo: &dyn ContentRepository) -> Result<String> {
+    content_repo
+        .get_translation(node_id, "en")
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("Translation not found for node {}", node_id))
+}
+
+/// Translation of a verse or word instance by a specific translator
+async fn translator_translation(
+    node_id: i64,
+    translator_id: i32,
+    content_repo: &dyn ContentRepository,
+) -> Result<String> {
+    let translation = if let Some((chapter, verse)) = node_id::decode_verse(node_id) {
+        content_repo
+            .get_verse_translation(&format!("{}:{}", chapter, verse), translator_id)
+            .await?
+    } else if let Some((chapter, verse, position)) = node_id::decode_word_instance(node_id) {
+        let word = word_at(
+            &format!("{}:{}", chapter, verse),
+            position as i32,
+            content_repo,
+        )
+        .await?;
+        content_repo
+            .get_word_translation(word.id, translator_id)
+            .await?
+    } else {
+        None
+    };
+
+    translation.ok_or_else(|| {
+        anyhow::anyhow!(
+            "Translation {} not found for node {}",
+            translator_id,
+            node_id
+        )
+    })
+}
+
+/// The word a verse-level word exercise asks for, with its word-instance node ID
+pub(super) async fn verse_word(
+    verse_node_id: i64,
+    position: i32,
+    content_repo: &dyn ContentRepository,
+) -> Result<(i64, Word)> {
+    let verse_key = verse_key_of(verse_node_id)?;
+    let word = word_at(&verse_key, position, content_repo).await?;
+    let (chapter, verse) = node_id::decode_verse(verse_node_id).unwrap_or_default();
+    Ok((
+        node_id::encode_word_instance(chapter, verse, position as u8),
+        word,
+    ))
+}
+
+/// The word behind a word or word-instance node
+pub(super) async fn word_of_node(
+    node_id: i64,
+    content_repo: &dyn ContentRepository,
+) -> Result<Word> {
+    if let Some((chapter, verse, position)) = node_id::decode_word_instance(node_id) {
+        return word_at(
+            &format!("{}:{}", chapter, verse),
+            position as i32,
+            content_repo,
+        )
+        .await;
+    }
+    let word_id = node_id::decode_word(node_id)
+        .ok_or_else(|| anyhow::anyhow!("Not a word node: {}", node_id))?;
+    content_repo
+        .get_word(word_id)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("Word not found: {}", word_id))
+}
+
+/// Words of a mushaf page in order: the words of `verse_keys` from the
+/// page's first to its last word-instance node
+async fn page_words(
+    verse_keys: &[String],
+    first_word_node_id: i64,
+    last_word_node_id: i64,
+    content_repo: &dyn ContentRepository,
+) -> Result<Vec<Word>> {
+    let mut words = Vec::new();
+    for verse_key in verse_keys {
+        let (chapter, verse) = verse_key
+            .split_once(':')
+            .and_then(|(c, v)| Some((c.parse().ok()?, v.parse().ok()?)))
+            .ok_or_else(|| anyhow::anyhow!("Invalid verse key: {}", verse_key))?;
+        let mut verse_words = content_repo.get_words_for_verse(verse_key).await?;
+        verse_words.sort_by_key(|w| w.position);
+        words.extend(verse_words.into_iter().filter(|w| {
+            let id = node_id::encode_word_instance(chapter, verse, w.position as u8);
+            (first_word_node_id..=last_word_node_id).contains(&id)
+        }));
+    }
+    Ok(words)
+}
+
+/// Latin transliteration of a word node
+async fn transliteration(node_id: i64, content_repo: &dyn ContentRepository) -> Result<String> {
+    word_of_node(node_id, content_repo)
+        .await?
+        .transliteration
+        .filter(|t| !t.trim().is_empty())
+        .ok_or_else(|| anyhow::anyhow!("Transliteration not found for node {}", node_id))
+}
+
+/// Index into `meaning_lemma_ids` of each lemma's meaning, in `lemma_ids` order
+fn lemma_meaning_indices(lemma_ids: &[String], meaning_lemma_ids: &[String]) -> Vec<i64> {
+    lemma_ids
+        .iter()
+        .map(|id| {
+            meaning_lemma_ids
+                .iter()
+                .position(|m| m == id)
+                .map_or(-1, |i| i as i64)
+        })
+        .collect()
+}
+
+#[async_trait]
 impl ExerciseValidator for DefaultValidator {
-    fn validate(
+    async fn validate(
         &self,
         exercise: &ExerciseData,
         answer: &AnswerInput,
         content_repo: &dyn ContentRepository,
     ) -> Result<ValidationResult> {
         match exercise {
-            ExerciseData::Memorization { node_id } => {
-                let AnswerInput::Text { value: user_text } = answer else {
-                    return Ok(ValidationResult {
-                        is_correct: false,
-                        feedback: Some("Expected text input".to_string()),
-                        similarity_score: None,
-                        semantic_grade: None,
-                        correct_answer: None,
-                    });
+            ExerciseData::Memorization { .. }
+            | ExerciseData::ClozeDeletion { .. }
+            | ExerciseData::FirstLetterHint { .. }
+            | ExerciseData::FullVerseInput { .. }
+            | ExerciseData::AyahChain { .. }
+            | ExerciseData::FirstWordRecall { .. }
+            | ExerciseData::ReverseCloze { .. }
+            | ExerciseData::EchoRecall { .. }
+            | ExerciseData::RecitePage { .. }
+            | ExerciseData::Translation { .. }
+            | ExerciseData::ContextualTranslation { .. }
+            | ExerciseData::TranslatePhrase { .. } => {
+                let AnswerKeys::Text { value: expected } =
+                    self.get_answer_keys(exercise, content_repo).await?
+                else {
+                    return Err(anyhow::anyhow!(
+                        "Exercise type {} has no text answer key",
+                        exercise.type_name()
+                    ));
                 };
+                if exercise.requires_english_input() {
+                    self.validate_english_text(answer, &expected)
+                } else {
+                    Ok(self.validate_arabic_text(answer, &expected))
+                }
+            }
+
+            ExerciseData::McqArToEn { node_id, .. } => {
+                let translation = english_translation(*node_id, content_repo).await?;
+                Ok(self.validate_word_choice(
+                    answer,
+                    *node_id,
+                    &translation,
+                    Self::normalize_english,
+                ))
+            }
+
+            ExerciseData::McqEnToAr { node_id, .. } => {
+                let text = quran_text(*node_id, self.script, content_repo).await?;
+                Ok(self.validate_word_choice(answer, *node_id, &text, Self::normalize_arabic))
+            }
+
+            ExerciseData::TransliterationToArabic { node_id, .. } => {
+                let word = word_of_node(*node_id, content_repo).await?;
+                let text = script::word_text(&word, self.script, content_repo).await?;
+                Ok(self.validate_word_choice(answer, *node_id, &text, Self::normalize_arabic))
+            }
+
+            ExerciseData::PageFirstWord {
+                first_word_node_id, ..
+            } => {
+                let word = word_of_node(*first_word_node_id, content_repo).await?;
+                let text = script::word_text(&word, self.script, content_repo).await?;
+                Ok(self.validate_word_choice(
+                    answer,
+                    *first_word_node_id,
+                    &text,
+                    Self::normalize_arabic,
+                ))
+            }
 
-                // Fetch correct text - this is async, so we'd need to adjust the trait
-                // For now, we'll return an error indicating async fetch is needed
-                // In a real implementation, this would be async
-                let _ = (node_id, content_repo, user_text);
-                Err(anyhow::anyhow!(
-                    "Memorization validation requires async content fetching - use ExerciseService"
+            ExerciseData::ArabicToTransliteration { node_id, .. } => {
+                let expected = transliteration(*node_id, content_repo).await?;
+                Ok(self.validate_transliteration(answer, &expected))
+            }
+
+            ExerciseData::MissingWordMcq {
+                node_id,
+                blank_position: position,
+                ..
+            }
+            | ExerciseData::NextWordMcq {
+                node_id,
+                context_position: position,
+                ..
+            }
+            | ExerciseData::ListenContinue {
+                node_id,
+                last_position: position,
+                ..
+            } => {
+                let position = match exercise {
+                    ExerciseData::MissingWordMcq { .. } => *position,
+                    _ => position + 1,
+                };
+                let (word_node_id, word) = verse_word(*node_id, position, content_repo).await?;
+                let word_text = script::word_text(&word, self.script, content_repo).await?;
+                Ok(self.validate_word_choice(
+                    answer,
+                    word_node_id,
+                    &word_text,
+                    Self::normalize_arabic,
                 ))
             }
 
@@ -230,145 +933,552 @@ impl ExerciseValidator for DefaultValidator {
                 mistake_position, ..
             } => {
                 let AnswerInput::Position { value: user_pos } = answer else {
-                    return Ok(ValidationResult {
-                        is_correct: false,
-                        feedback: Some("Expected position input".to_string()),
-                        similarity_score: None,
-                        semantic_grade: None,
-                        correct_answer: Some(mistake_position.to_string()),
-                    });
+                    return Ok(ValidationResult::unexpected_input(
+                        "position",
+                        Some(mistake_position.to_string()),
+                    ));
                 };
 
                 let is_correct = user_pos == mistake_position;
-                Ok(ValidationResult {
+                Ok(ValidationResult::graded(
                     is_correct,
-                    feedback: if is_correct {
-                        Some("Correct!".to_string())
-                    } else {
-                        Some(format!("The mistake is at position {}", mistake_position))
-                    },
-                    similarity_score: None,
-                    semantic_grade: None,
-                    correct_answer: if is_correct {
-                        None
+                    if is_correct {
+                        "Correct!".to_string()
                     } else {
-                        Some(mistake_position.to_string())
+                        format!("The mistake is at position {}", mistake_position)
                     },
-                })
+                    None,
+                    mistake_position.to_string(),
+                    &self.thresholds,
+                ))
             }
 
             ExerciseData::IdentifyRoot { root, .. } => {
                 let AnswerInput::Text { value: user_text } = answer else {
-                    return Ok(ValidationResult {
-                        is_correct: false,
-                        feedback: Some("Expected text input".to_string()),
-                        similarity_score: None,
-                        semantic_grade: None,
-                        correct_answer: Some(root.clone()),
-                    });
+                    return Ok(ValidationResult::unexpected_input(
+                        "text",
+                        Some(root.clone()),
+                    ));
                 };
 
-                let normalized_user = Self::normalize_arabic(user_text);
-                let normalized_root = Self::normalize_arabic(root);
-                let is_correct = normalized_user == normalized_root;
-
-                Ok(ValidationResult {
+                let is_correct = Self::normalize_arabic(user_text) == Self::normalize_arabic(root);
+                Ok(ValidationResult::graded(
                     is_correct,
-                    feedback: if is_correct {
-                        Some("Correct!".to_string())
+                    if is_correct {
+                        "Correct!".to_string()
                     } else {
-                        Some(format!("The root is: {}", root))
+                        format!("The root is: {}", root)
                     },
-                    similarity_score: None,
-                    semantic_grade: None,
-                    correct_answer: if is_correct { None } else { Some(root.clone()) },
-                })
+                    None,
+                    root.clone(),
+                    &self.thresholds,
+                ))
             }
 
-            ExerciseData::PosTagging { correct_pos, .. } => {
-                let AnswerInput::Text { value: user_text } = answer else {
-                    return Ok(ValidationResult {
-                        is_correct: false,
-                        feedback: Some("Expected text input".to_string()),
-                        similarity_score: None,
-                        semantic_grade: None,
-                        correct_answer: Some(correct_pos.clone()),
-                    });
+            ExerciseData::PosTagging {
+                correct_pos,
+                options,
+                ..
+            } => Ok(self.validate_label_choice(answer, correct_pos, options, "part of speech")),
+
+            ExerciseData::GrammarFeature {
+                feature,
+                correct_value,
+                options,
+                ..
+            } => Ok(self.validate_label_choice(
+                answer,
+                correct_value,
+                options,
+                &feature.as_str().replace('_', " "),
+            )),
+
+            ExerciseData::AyahSequence {
+                correct_sequence, ..
+            } => {
+                let AnswerInput::Sequence { values: user_seq } = answer else {
+                    return Ok(ValidationResult::unexpected_input("sequence", None));
                 };
 
-                let normalized_user = Self::normalize_pos(user_text);
-                let normalized_correct = Self::normalize_pos(correct_pos);
-                let is_correct = normalized_user == normalized_correct;
+                Ok(self.validate_order(
+                    correct_sequence,
+                    user_seq,
+                    "items",
+                    correct_sequence
+                        .iter()
+                        .map(|id| id.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                ))
+            }
+
+            ExerciseData::SequenceRecall {
+                correct_sequence,
+                options,
+                ..
+            } => {
+                let chosen = match answer {
+                    AnswerInput::Sequence { values } => Some(values),
+                    AnswerInput::OptionIndex { value } => options.get(*value),
+                    _ => {
+                        return Ok(ValidationResult::unexpected_input(
+                            "option index or sequence",
+                            None,
+                        ));
+                    }
+                };
 
-                Ok(ValidationResult {
+                let is_correct = chosen == Some(correct_sequence);
+                Ok(ValidationResult::graded(
                     is_correct,
-                    feedback: if is_correct {
-                        Some("Correct!".to_string())
+                    if is_correct {
+                        "Correct!".to_string()
                     } else {
-                        Some(format!("The correct part of speech is: {}", correct_pos))
+                        "That is not the continuation".to_string()
                     },
-                    similarity_score: None,
-                    semantic_grade: None,
-                    correct_answer: if is_correct {
-                        None
+                    None,
+                    correct_sequence
+                        .iter()
+                        .map(|id| id.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    &self.thresholds,
+                ))
+            }
+
+            ExerciseData::CrossVerseConnection {
+                related_verse_ids, ..
+            } => {
+                let correct_id = related_verse_ids
+                    .first()
+                    .copied()
+                    .ok_or_else(|| anyhow::anyhow!("Cross-verse connection has no verses"))?;
+                let correct_key = verse_key_of(correct_id)?;
+                let is_correct = match answer {
+                    AnswerInput::WordId { value } => *value == correct_id,
+                    AnswerInput::VerseKey { value } => *value == correct_key,
+                    _ => {
+                        return Ok(ValidationResult::unexpected_input(
+                            "node ID or verse key",
+                            Some(correct_key),
+                        ));
+                    }
+                };
+
+                Ok(ValidationResult::graded(
+                    is_correct,
+                    if is_correct {
+                        "Correct!".to_string()
                     } else {
-                        Some(correct_pos.clone())
+                        format!("The connected verse is {}", correct_key)
                     },
-                })
+                    None,
+                    correct_key,
+                    &self.thresholds,
+                ))
             }
 
-            ExerciseData::AyahSequence {
-                correct_sequence, ..
+            ExerciseData::IdentifySurah {
+                correct_chapter,
+                chapter_options,
+                ..
+            } => Ok(
+                self.validate_choice(answer, *correct_chapter, chapter_options, |n| {
+                    format!("Surah {}", n)
+                }),
+            ),
+
+            ExerciseData::IdentifyVerseNumber {
+                correct_verse_number,
+                verse_number_options,
+                ..
+            } => Ok(self.validate_choice(
+                answer,
+                *correct_verse_number,
+                verse_number_options,
+                |n| format!("Verse {}", n),
+            )),
+
+            ExerciseData::IdentifyPageHalf { half, .. } => {
+                Ok(self.validate_choice(answer, *half, &[1, 2], |n| {
+                    if n == 1 { "First half" } else { "Second half" }.to_string()
+                }))
+            }
+
+            ExerciseData::IdentifyVerseLine {
+                correct_line,
+                line_options,
+                ..
+            } => Ok(
+                self.validate_choice(answer, *correct_line, line_options, |n| {
+                    format!("Line {}", n)
+                }),
+            ),
+
+            ExerciseData::RootFamily {
+                root_id,
+                family_word_node_ids,
+                ..
             } => {
-                let AnswerInput::Sequence { values: user_seq } = answer else {
-                    return Ok(ValidationResult {
-                        is_correct: false,
-                        feedback: Some("Expected sequence input".to_string()),
-                        similarity_score: None,
-                        semantic_grade: None,
-                        correct_answer: None,
-                    });
+                let correct_answer = self.words_text(family_word_node_ids, content_repo).await?;
+                Ok(self.validate_word_picks(
+                    answer,
+                    family_word_node_ids,
+                    correct_answer,
+                    |found, wrong| {
+                        format!(
+                            "Found {} of {} words from the root {} ({} wrong)",
+                            found,
+                            family_word_node_ids.len(),
+                            root_id,
+                            wrong
+                        )
+                    },
+                ))
+            }
+
+            ExerciseData::LemmaMeaning {
+                lemma_ids,
+                meaning_lemma_ids,
+                ..
+            } => {
+                let mut pairs = Vec::with_capacity(lemma_ids.len());
+                for lemma_id in lemma_ids {
+                    let lemma = content_repo
+                        .get_lemma_by_id(lemma_id)
+                        .await?
+                        .ok_or_else(|| anyhow::anyhow!("Lemma not found: {}", lemma_id))?;
+                    pairs.push(format!(
+                        "{}: {}",
+                        lemma.arabic,
+                        lemma.description.unwrap_or_default()
+                    ));
+                }
+                let correct_answer = pairs.join("; ");
+
+                let AnswerInput::Sequence { values } = answer else {
+                    return Ok(ValidationResult::unexpected_input(
+                        "sequence of meaning indices",
+                        Some(correct_answer),
+                    ));
                 };
+                let expected = lemma_meaning_indices(lemma_ids, meaning_lemma_ids);
+                let matched = expected.iter().zip(values).filter(|(e, v)| e == v).count();
 
-                let is_correct = user_seq == correct_sequence;
-                Ok(ValidationResult {
+                let is_correct = matched == expected.len() && values.len() == expected.len();
+                Ok(ValidationResult::graded(
                     is_correct,
-                    feedback: if is_correct {
-                        Some("Correct sequence!".to_string())
+                    if is_correct {
+                        "Correct!".to_string()
                     } else {
-                        Some("Incorrect order".to_string())
+                        format!("{} of {} meanings matched", matched, expected.len())
                     },
-                    similarity_score: None,
-                    semantic_grade: None,
-                    correct_answer: if is_correct {
-                        None
+                    Some(matched as f32 / expected.len().max(1) as f32),
+                    correct_answer,
+                    &self.thresholds,
+                ))
+            }
+
+            ExerciseData::WordOrder {
+                correct_sequence,
+                shuffled_word_node_ids,
+                tile_texts,
+                ..
+            } => {
+                let AnswerInput::Sequence { values: user_seq } = answer else {
+                    return Ok(ValidationResult::unexpected_input("sequence", None));
+                };
+
+                // Compare tile text rather than IDs so repeated words are interchangeable
+                let text_of: HashMap<i64, &str> = shuffled_word_node_ids
+                    .iter()
+                    .copied()
+                    .zip(tile_texts.iter().map(String::as_str))
+                    .collect();
+                let to_texts = |ids: &[i64]| -> Vec<&str> {
+                    ids.iter()
+                        .map(|id| text_of.get(id).copied().unwrap_or_default())
+                        .collect()
+                };
+                let expected = to_texts(correct_sequence);
+
+                // Each tile must be placed exactly once; repeating or inventing
+                // tiles could otherwise still score partial order credit.
+                let mut placed = user_seq.clone();
+                placed.sort_unstable();
+                let mut tiles = shuffled_word_node_ids.clone();
+                tiles.sort_unstable();
+                if placed != tiles {
+                    return Ok(ValidationResult::graded(
+                        false,
+                        "Place every tile exactly once".to_string(),
+                        Some(0.0),
+                        expected.join(" "),
+                        &self.thresholds,
+                    ));
+                }
+
+                let given = to_texts(user_seq);
+
+                Ok(self.validate_order(&expected, &given, "words", expected.join(" ")))
+            }
+
+            ExerciseData::Mutashabihat {
+                verse_key,
+                option_verse_keys,
+                differing_positions,
+                ..
+            } => Ok(
+                self.validate_verse_choice(answer, verse_key, option_verse_keys, |chosen| {
+                    let position = chosen.and_then(|key| {
+                        option_verse_keys
+                            .iter()
+                            .position(|k| k == key)
+                            .and_then(|i| differing_positions.get(i))
+                    });
+                    match (chosen, position) {
+                        (Some(key), Some(position)) => format!(
+                            "That is {}; it differs from {} at word {}",
+                            key, verse_key, position
+                        ),
+                        _ => format!("The correct verse is {}", verse_key),
+                    }
+                }),
+            ),
+
+            ExerciseData::ListenIdentifyVerse {
+                verse_key,
+                option_verse_keys,
+                ..
+            } => Ok(
+                self.validate_verse_choice(answer, verse_key, option_verse_keys, |_| {
+                    format!("The recitation is from {}", verse_key)
+                }),
+            ),
+
+            ExerciseData::ListenIdentifyWord { node_id, .. } => {
+                let word = word_of_node(*node_id, content_repo).await?;
+                let text = script::word_text(&word, self.script, content_repo).await?;
+                Ok(self.validate_word_choice(answer, *node_id, &text, Self::normalize_arabic))
+            }
+
+            ExerciseData::IdentifyTajweedRule {
+                correct_rule,
+                rule_options,
+                ..
+            } => {
+                let is_correct = match answer {
+                    AnswerInput::Text { value } => correct_rule.matches_answer(value),
+                    AnswerInput::OptionIndex { value } => {
+                        rule_options.get(*value) == Some(correct_rule)
+                    }
+                    _ => {
+                        return Ok(ValidationResult::unexpected_input(
+                            "text or option index",
+                            Some(correct_rule.name().to_string()),
+                        ));
+                    }
+                };
+                Ok(ValidationResult::graded(
+                    is_correct,
+                    if is_correct {
+                        "Correct!".to_string()
                     } else {
-                        // Convert Vec<i64> to string representation
-                        Some(
-                            correct_sequence
-                                .iter()
-                                .map(|id| id.to_string())
-                                .collect::<Vec<_>>()
-                                .join(", "),
+                        format!("The rule here is {}", correct_rule.name())
+                    },
+                    None,
+                    correct_rule.name().to_string(),
+                    &self.thresholds,
+                ))
+            }
+
+            ExerciseData::FindTajweedRule {
+                rule,
+                rule_word_node_ids,
+                ..
+            } => {
+                let correct_answer = self.words_text(rule_word_node_ids, content_repo).await?;
+                Ok(self.validate_word_picks(
+                    answer,
+                    rule_word_node_ids,
+                    correct_answer,
+                    |found, wrong| {
+                        format!(
+                            "Found {} of {} words with {} ({} wrong)",
+                            found,
+                            rule_word_node_ids.len(),
+                            rule.name(),
+                            wrong
                         )
                     },
-                })
+                ))
             }
 
-            // Other exercise types require async content fetching
-            _ => Err(anyhow::anyhow!(
-                "Exercise type {} requires async validation - use ExerciseService",
-                exercise.type_name()
-            )),
+            ExerciseData::TafsirKeyLesson {
+                tafsir_id,
+                passage_key,
+                option_passage_keys,
+                ..
+            } => {
+                let lesson = content_repo
+                    .get_tafsir_passage(passage_key, *tafsir_id)
+                    .await?
+                    .and_then(|p| p.key_lesson)
+                    .ok_or_else(|| anyhow::anyhow!("Key lesson not found: {}", passage_key))?;
+                Ok(
+                    self.validate_verse_choice(answer, passage_key, option_passage_keys, |_| {
+                        format!("The key lesson is: {}", lesson)
+                    }),
+                )
+            }
+
+            ExerciseData::RevelationOccasion {
+                node_id,
+                option_verse_keys,
+                ..
+            } => {
+                let verse_key = verse_key_of(*node_id)?;
+                Ok(
+                    self.validate_verse_choice(answer, &verse_key, option_verse_keys, |_| {
+                        format!("The occasion is reported for {}", verse_key)
+                    }),
+                )
+            }
         }
     }
 
-    fn get_answer_keys(
+    async fn get_answer_keys(
         &self,
         exercise: &ExerciseData,
-        _content_repo: &dyn ContentRepository,
+        content_repo: &dyn ContentRepository,
     ) -> Result<AnswerKeys> {
+        let text = |value: String| Ok(AnswerKeys::Text { value });
+
         match exercise {
+            ExerciseData::Memorization { node_id } => {
+                text(quran_text(*node_id, self.script, content_repo).await?)
+            }
+
+            ExerciseData::Translation { node_id }
+            | ExerciseData::ContextualTranslation { node_id, .. } => {
+                text(english_translation(*node_id, content_repo).await?)
+            }
+
+            ExerciseData::TranslatePhrase {
+                node_id,
+                translator_id,
+            } => text(translator_translation(*node_id, *translator_id, content_repo).await?),
+
+            ExerciseData::ClozeDeletion {
+                node_id,
+                blank_position: position,
+                ..
+            }
+            | ExerciseData::FirstLetterHint {
+                node_id,
+                word_position: position,
+                ..
+            }
+            | ExerciseData::ReverseCloze {
+                node_id,
+                blank_position: position,
+                ..
+            } => {
+                let (_, word) = verse_word(*node_id, *position, content_repo).await?;
+                text(script::word_text(&word, self.script, content_repo).await?)
+            }
+
+            ExerciseData::FullVerseInput { node_id } => {
+                text(verse_text(&verse_key_of(*node_id)?, self.script, content_repo).await?)
+            }
+
+            ExerciseData::AyahChain {
+                verse_keys,
+                current_index,
+                ..
+            } => {
+                let verse_key = verse_keys
+                    .get(*current_index)
+                    .ok_or_else(|| anyhow::anyhow!("Ayah chain is already complete"))?;
+                text(verse_text(verse_key, self.script, content_repo).await?)
+            }
+
+            ExerciseData::FirstWordRecall { verse_key, .. } => {
+                let word = word_at(verse_key, 1, content_repo).await?;
+                text(script::word_text(&word, self.script, content_repo).await?)
+            }
+
+            ExerciseData::EchoRecall { ayah_node_ids } => {
+                let mut verses = Vec::with_capacity(ayah_node_ids.len());
+                for id in ayah_node_ids {
+                    verses.push(verse_text(&verse_key_of(*id)?, self.script, content_repo).await?);
+                }
+                text(verses.join(" "))
+            }
+
+            ExerciseData::RecitePage {
+                verse_keys,
+                first_word_node_id,
+                last_word_node_id,
+                ..
+            } => {
+                let words = page_words(
+                    verse_keys,
+                    *first_word_node_id,
+                    *last_word_node_id,
+                    content_repo,
+                )
+                .await?;
+                text(
+                    script::word_texts(&words, self.script, content_repo)
+                        .await?
+                        .join(" "),
+                )
+            }
+
+            ExerciseData::McqArToEn { node_id, .. }
+            | ExerciseData::McqEnToAr { node_id, .. }
+            | ExerciseData::TransliterationToArabic { node_id, .. }
+            | ExerciseData::PageFirstWord {
+                first_word_node_id: node_id,
+                ..
+            }
+            | ExerciseData::ListenIdentifyWord { node_id, .. } => {
+                Ok(AnswerKeys::WordId { value: *node_id })
+            }
+
+            ExerciseData::ArabicToTransliteration { node_id, .. } => {
+                text(transliteration(*node_id, content_repo).await?)
+            }
+
+            ExerciseData::MissingWordMcq {
+                node_id,
+                blank_position: position,
+                ..
+            } => {
+                let (word_node_id, _) = verse_word(*node_id, *position, content_repo).await?;
+                Ok(AnswerKeys::WordId {
+                    value: word_node_id,
+                })
+            }
+
+            ExerciseData::NextWordMcq {
+                node_id,
+                context_position,
+                ..
+            }
+            | ExerciseData::ListenContinue {
+                node_id,
+                last_position: context_position,
+                ..
+            } => {
+                let (word_node_id, _) =
+                    verse_word(*node_id, context_position + 1, content_repo).await?;
+                Ok(AnswerKeys::WordId {
+                    value: word_node_id,
+                })
+            }
+
             ExerciseData::FindMistake {
                 mistake_position, ..
             } => Ok(AnswerKeys::Position {
@@ -383,17 +1493,78 @@ impl ExerciseValidator for DefaultValidator {
                 value: correct_pos.clone(),
             }),
 
+            ExerciseData::GrammarFeature { correct_value, .. } => Ok(AnswerKeys::Text {
+                value: correct_value.clone(),
+            }),
+
+            ExerciseData::RootFamily {
+                family_word_node_ids: word_node_ids,
+                ..
+            }
+            | ExerciseData::FindTajweedRule {
+                rule_word_node_ids: word_node_ids,
+                ..
+            } => Ok(AnswerKeys::Sequence {
+                values: word_node_ids.clone(),
+            }),
+
+            ExerciseData::IdentifyTajweedRule { correct_rule, .. } => Ok(AnswerKeys::Text {
+                value: correct_rule.as_str().to_string(),
+            }),
+
+            ExerciseData::LemmaMeaning {
+                lemma_ids,
+                meaning_lemma_ids,
+                ..
+            } => Ok(AnswerKeys::Sequence {
+                values: lemma_meaning_indices(lemma_ids, meaning_lemma_ids),
+            }),
+
             ExerciseData::AyahSequence {
                 correct_sequence, ..
+            }
+            | ExerciseData::SequenceRecall {
+                correct_sequence, ..
+            }
+            | ExerciseData::WordOrder {
+                correct_sequence, ..
             } => Ok(AnswerKeys::Sequence {
                 values: correct_sequence.clone(),
             }),
 
-            // Other exercise types require async content fetching
-            _ => Err(anyhow::anyhow!(
-                "Exercise type {} requires async answer key retrieval",
-                exercise.type_name()
-            )),
+            ExerciseData::CrossVerseConnection {
+                related_verse_ids, ..
+            } => related_verse_ids
+                .first()
+                .map(|id| AnswerKeys::WordId { value: *id })
+                .ok_or_else(|| anyhow::anyhow!("Cross-verse connection has no verses")),
+
+            ExerciseData::IdentifySurah {
+                correct_chapter: value,
+                ..
+            }
+            | ExerciseData::IdentifyVerseNumber {
+                correct_verse_number: value,
+                ..
+            }
+            | ExerciseData::IdentifyPageHalf { half: value, .. }
+            | ExerciseData::IdentifyVerseLine {
+                correct_line: value,
+                ..
+            } => Ok(AnswerKeys::Position { value: *value }),
+
+            ExerciseData::Mutashabihat { verse_key, .. }
+            | ExerciseData::ListenIdentifyVerse { verse_key, .. }
+            | ExerciseData::TafsirKeyLesson {
+                passage_key: verse_key,
+                ..
+            } => Ok(AnswerKeys::VerseKey {
+                value: verse_key.clone(),
+            }),
+
+            ExerciseData::RevelationOccasion { node_id, .. } => Ok(AnswerKeys::VerseKey {
+                value: verse_key_of(*node_id)?,
+            }),
         }
     }
 }
@@ -411,6 +1582,15 @@ mod tests {
         assert!(!normalized.contains('\u{0650}')); // No Kasra
     }
 
+    #[test]
+    fn test_normalize_arabic_across_scripts() {
+        let uthmani = DefaultValidator::normalize_arabic("بِسْمِ ٱلرَّحِيمِ");
+        let indopak = DefaultValidator::normalize_arabic("بِسۡمِ الرَّحِیۡمِ");
+        let simple = DefaultValidator::normalize_arabic("بسم الرحيم");
+        assert_eq!(uthmani, simple);
+        assert_eq!(indopak, simple);
+    }
+
     #[test]
     fn test_normalize_english() {
         let text = "In the name of Allah, the Most Gracious!";
@@ -427,9 +1607,257 @@ mod tests {
         assert_eq!(DefaultValidator::normalize_pos("Verb"), "verb");
     }
 
-    // Note: Validation tests that require ContentRepository are integration tests
-    // and should be in a separate test file with database setup.
-    // The validator logic is tested through the ExerciseService in integration tests.
+    #[tokio::test]
+    async fn test_validate_location_exercises() {
+        let validator = DefaultValidator::new();
+        let repo = crate::ports::content_repository::MockContentRepository::new();
+        let surah = ExerciseData::IdentifySurah {
+            node_id: 1,
+            verse_key: "2:255".to_string(),
+            correct_chapter: 2,
+            chapter_options: vec![3, 2, 1, 4],
+            seed: 0,
+        };
+
+        let by_index = AnswerInput::OptionIndex { value: 1 };
+        let right = validator.validate(&surah, &by_index, &repo).await.unwrap();
+        assert!(right.is_correct);
+        assert_eq!(right.suggested_grade, ReviewGrade::Good);
+
+        let wrong = validator
+            .validate(&surah, &AnswerInput::Position { value: 3 }, &repo)
+            .await
+            .unwrap();
+        assert!(!wrong.is_correct);
+        assert_eq!(wrong.correct_answer, Some("2".to_string()));
+        assert_eq!(wrong.suggested_grade, ReviewGrade::Again);
+        assert_eq!(
+            validator.get_answer_keys(&surah, &repo).await.unwrap(),
+            AnswerKeys::Position { value: 2 }
+        );
+
+        let page_half = ExerciseData::IdentifyPageHalf {
+            node_id: 1,
+            verse_key: "2:255".to_string(),
+            page: 42,
+            half: 2,
+        };
+        let second = AnswerInput::OptionIndex { value: 1 };
+        assert!(
+            validator
+                .validate(&page_half, &second, &repo)
+                .await
+                .unwrap()
+                .is_correct
+        );
+
+        let verse_line = ExerciseData::IdentifyVerseLine {
+            node_id: 1,
+            verse_key: "2:255".to_string(),
+            page: 42,
+            correct_line: 7,
+            line_options: vec![8, 7, 6, 9],
+            seed: 0,
+        };
+        let wrong_line = validator
+            .validate(&verse_line, &AnswerInput::OptionIndex { value: 0 }, &repo)
+            .await
+            .unwrap();
+        assert!(!wrong_line.is_correct);
+        assert_eq!(
+            wrong_line.feedback.as_deref(),
+            Some("The correct answer is: Line 7")
+        );
+        assert_eq!(
+            validator.get_answer_keys(&verse_line, &repo).await.unwrap(),
+            AnswerKeys::Position { value: 7 }
+        );
+    }
+
+    fn verse_words(texts: &[&str]) -> Vec<Word> {
+        texts
+            .iter()
+            .enumerate()
+            .map(|(i, text)| Word {
+                id: i as i64 + 1,
+                verse_key: "1:1".to_string(),
+                position: i as i32 + 1,
+                text_uthmani: text.to_string(),
+                text_simple: None,
+                transliteration: None,
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_validate_fetches_content() {
+        let validator = DefaultValidator::new();
+        let mut repo = crate::ports::content_repository::MockContentRepository::new();
+        repo.expect_get_quran_text()
+            .returning(|_| Ok(Some("بِسْمِ ٱللَّهِ".to_string())));
+        repo.expect_get_words_for_verse()
+            .returning(|_| Ok(verse_words(&["بِسْمِ", "ٱللَّهِ", "ٱلرَّحْمَٰنِ", "ٱلرَّحِيمِ"])));
+
+        // Memorization: graded word by word against the node's text
+        let memorization = ExerciseData::Memorization { node_id: 7 };
+        let exact = validator
+            .validate(
+                &memorization,
+                &AnswerInput::Text {
+                    value: "بسم الله".to_string(),
+                },
+                &repo,
+            )
+            .await
+            .unwrap();
+        assert!(exact.is_correct);
+        assert_eq!(exact.suggested_grade, ReviewGrade::Easy);
+
+        let half = validator
+            .validate(
+                &memorization,
+                &AnswerInput::Text {
+                    value: "بسم".to_string(),
+                },
+                &repo,
+            )
+            .await
+            .unwrap();
+        assert!(!half.is_correct);
+        assert_eq!(half.similarity_score, Some(0.5));
+        assert_eq!(half.suggested_grade, ReviewGrade::Again);
+        assert_eq!(half.correct_answer, Some("بِسْمِ ٱللَّهِ".to_string()));
+
+        // Lenient thresholds give the same answer partial credit
+        let lenient = DefaultValidator::new().with_grade_thresholds(GradeThresholds {
+            hard: 0.5,
+            ..GradeThresholds::default()
+        });
+        let half = lenient
+            .validate(
+                &memorization,
+                &AnswerInput::Text {
+                    value: "بسم".to_string(),
+                },
+                &repo,
+            )
+            .await
+            .unwrap();
+        assert!(!half.is_correct);
+        assert_eq!(half.suggested_grade, ReviewGrade::Hard);
+
+        // Missing word MCQ: the chosen word instance or its text
+        let verse_id = node_id::encode_verse(1, 1);
+        let missing = ExerciseData::MissingWordMcq {
+            node_id: verse_id,
+            blank_position: 2,
+            distractor_node_ids: vec![node_id::encode_word_instance(1, 1, 3)],
+            distractor_strategy: Default::default(),
+            seed: 0,
+        };
+        let answer_id = node_id::encode_word_instance(1, 1, 2);
+        assert_eq!(
+            validator.get_answer_keys(&missing, &repo).await.unwrap(),
+            AnswerKeys::WordId { value: answer_id }
+        );
+        for (answer, expected) in [
+            (AnswerInput::WordId { value: answer_id }, true),
+            (
+                AnswerInput::WordId {
+                    value: node_id::encode_word_instance(1, 1, 3),
+                },
+                false,
+            ),
+            (
+                AnswerInput::Text {
+                    value: "الله".to_string(),
+                },
+                true,
+            ),
+        ] {
+            let result = validator.validate(&missing, &answer, &repo).await.unwrap();
+            assert_eq!(result.is_correct, expected, "{:?}", answer);
+        }
+
+        // Wrong input shape is graded as a miss, not an error
+        let shape = validator
+            .validate(&missing, &AnswerInput::Position { value: 3 }, &repo)
+            .await
+            .unwrap();
+        assert!(!shape.is_correct);
+        assert_eq!(shape.suggested_grade, ReviewGrade::Again);
+    }
+
+    #[test]
+    fn test_suggest_grade() {
+        let defaults = GradeThresholds::default();
+        assert_eq!(
+            ValidationResult::suggest_grade(true, None, &defaults),
+            ReviewGrade::Good
+        );
+        assert_eq!(
+            ValidationResult::suggest_grade(false, None, &defaults),
+            ReviewGrade::Again
+        );
+        assert_eq!(
+            ValidationResult::suggest_grade(true, Some(1.0), &defaults),
+            ReviewGrade::Easy
+        );
+        // Near misses are Hard, never Good
+        assert_eq!(
+            ValidationResult::suggest_grade(false, Some(0.95), &defaults),
+            ReviewGrade::Hard
+        );
+        assert_eq!(
+            ValidationResult::suggest_grade(false, Some(0.7), &defaults),
+            ReviewGrade::Hard
+        );
+        assert_eq!(
+            ValidationResult::suggest_grade(false, Some(0.2), &defaults),
+            ReviewGrade::Again
+        );
+    }
+
+    #[test]
+    fn test_suggest_grade_with_custom_thresholds() {
+        let strict = GradeThresholds {
+            easy: 1.0,
+            good: 0.95,
+            hard: 0.8,
+        };
+        assert_eq!(
+            ValidationResult::suggest_grade(false, Some(0.7), &strict),
+            ReviewGrade::Again
+        );
+        assert_eq!(
+            ValidationResult::suggest_grade(true, Some(0.7), &strict),
+            ReviewGrade::Good
+        );
+        assert_eq!(
+            ValidationResult::suggest_grade(false, Some(0.9), &strict),
+            ReviewGrade::Hard
+        );
+    }
+
+    #[test]
+    fn test_longest_common_subsequence() {
+        assert_eq!(
+            DefaultValidator::longest_common_subsequence(&[1, 2, 3, 4], &[1, 2, 3, 4]),
+            4
+        );
+        assert_eq!(
+            DefaultValidator::longest_common_subsequence(&[1, 2, 3, 4], &[2, 1, 3, 4]),
+            3
+        );
+        assert_eq!(
+            DefaultValidator::longest_common_subsequence(&[1, 2, 3], &[3, 2, 1]),
+            1
+        );
+        assert_eq!(
+            DefaultValidator::longest_common_subsequence::<i32>(&[], &[1]),
+            0
+        );
+    }
 
     #[test]
     fn test_answer_input_serialization() {
@@ -449,6 +1877,7 @@ mod tests {
             similarity_score: Some(0.95),
             semantic_grade: Some("Excellent".to_string()),
             correct_answer: None,
+            suggested_grade: ReviewGrade::Good,
         };
         let json = serde_json::to_string(&result).unwrap();
         let _deserialized: ValidationResult = serde_json::from_str(&json).unwrap();
@@ -0,0 +1,338 @@
+// exercises/word_diff.rs
+// Word-level alignment grading for typed verse input
+
+use super::memorization::MemorizationExercise;
+use crate::ReviewGrade;
+use serde::{Deserialize, Serialize};
+
+/// Kind of difference at a word position
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WordEditKind {
+    /// Word typed correctly
+    Match,
+    /// A different word was typed in place of the expected one
+    Substitution,
+    /// An extra word was typed
+    Insertion,
+    /// The expected word is missing
+    Deletion,
+    /// The word was typed but swapped with its neighbor
+    Swap,
+}
+
+/// One aligned word position
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WordEdit {
+    pub kind: WordEditKind,
+    /// 1-indexed position in the expected text (for insertions, the position
+    /// of the expected word the extra word precedes)
+    pub expected_position: usize,
+    /// Expected word (None for insertions)
+    pub expected: Option<String>,
+    /// Typed word (None for deletions)
+    pub actual: Option<String>,
+}
+
+/// Aligned diff between expected and typed text
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WordDiff {
+    /// Aligned positions in expected-text order
+    pub edits: Vec<WordEdit>,
+    /// Word edit distance (a swap counts as one edit)
+    pub distance: usize,
+    /// 1.0 for an exact match, down to 0.0
+    pub accuracy: f64,
+}
+
+impl WordDiff {
+    /// Whether the typed text matched exactly (after normalization)
+    pub fn is_exact(&self) -> bool {
+        self.distance == 0
+    }
+
+    /// Non-matching positions
+    pub fn mistakes(&self) -> impl Iterator<Item = &WordEdit> {
+        self.edits.iter().filter(|e| e.kind != WordEditKind::Match)
+    }
+}
+
+/// Accuracy thresholds mapping a `WordDiff` to a `ReviewGrade`
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct GradeThresholds {
+    /// Minimum accuracy for Easy
+    pub easy: f64,
+    /// Minimum accuracy for Good
+    pub good: f64,
+    /// Minimum accuracy for Hard (below is Again)
+    pub hard: f64,
+}
+
+impl Default for GradeThresholds {
+    fn default() -> Self {
+        Self {
+            easy: 1.0,
+            good: 0.9,
+            hard: 0.6,
+        }
+    }
+}
+
+impl GradeThresholds {
+    /// User setting holding the thresholds as JSON
+    pub const SETTING_KEY: &'static str = "grade_thresholds";
+
+    /// Thresholds stored under `SETTING_KEY`; `None` when malformed
+    pub fn from_setting(value: &str) -> Option<Self> {
+        serde_json::from_str(value).ok()
+    }
+
+    /// Map an accuracy score to a review grade
+    pub fn grade(&self, accuracy: f64) -> ReviewGrade {
+        if accuracy >= self.easy {
+            ReviewGrade::Easy
+        } else if accuracy >= self.good {
+            ReviewGrade::Good
+        } else if accuracy >= self.hard {
+            ReviewGrade::Hard
+        } else {
+            ReviewGrade::Again
+        }
+    }
+}
+
+/// Align typed text against the expected text word by word.
+///
+/// Tokens are compared after Arabic normalization (diacritics and letter
+/// variants ignored); the edits carry the original words for display.
+pub fn diff_words(expected: &str, actual: &str) -> WordDiff {
+    let exp: Vec<&str> = expected.split_whitespace().collect();
+    let act: Vec<&str> = actual.split_whitespace().collect();
+    let exp_norm: Vec<String> = exp
+        .iter()
+        .map(|w| MemorizationExercise::normalize_arabic(w))
+        .collect();
+    let act_norm: Vec<String> = act
+        .iter()
+        .map(|w| MemorizationExercise::normalize_arabic(w))
+        .collect();
+
+    let (n, m) = (exp.len(), act.len());
+
+    // Optimal string alignment distance over words
+    let mut dp = vec![vec![0usize; m + 1]; n + 1];
+    for (i, row) in dp.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in dp[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=n {
+        for j in 1..=m {
+            let cost = usize::from(exp_norm[i - 1] != act_norm[j - 1]);
+            let mut best = (dp[i - 1][j - 1] + cost)
+                .min(dp[i - 1][j] + 1)
+                .min(dp[i][j - 1] + 1);
+            if is_swap(&exp_norm, &act_norm, i, j) {
+                best = best.min(dp[i - 2][j - 2] + 1);
+            }
+            dp[i][j] = best;
+        }
+    }
+
+    // Backtrace from the end, then reverse into expected order
+    let mut edits = Vec::with_capacity(n.max(m));
+    let (mut i, mut j) = (n, m);
+    while i > 0 || j > 0 {
+        if i > 0 && j > 0 && exp_norm[i - 1] == act_norm[j - 1] && dp[i][j] == dp[i - 1][j - 1] {
+            edits.push(edit(
+                WordEditKind::Match,
+                i,
+                Some(exp[i - 1]),
+                Some(act[j - 1]),
+            ));
+            i -= 1;
+            j -= 1;
+        } else if is_swap(&exp_norm, &act_norm, i, j) && dp[i][j] == dp[i - 2][j - 2] + 1 {
+            edits.push(edit(
+                WordEditKind::Swap,
+                i,
+                Some(exp[i - 1]),
+                Some(act[j - 1]),
+            ));
+            edits.push(edit(
+                WordEditKind::Swap,
+                i - 1,
+                Some(exp[i - 2]),
+                Some(act[j - 2]),
+            ));
+            i -= 2;
+            j -= 2;
+        } else if i > 0 && j > 0 && dp[i][j] == dp[i - 1][j - 1] + 1 {
+            edits.push(edit(
+                WordEditKind::Substitution,
+                i,
+                Some(exp[i - 1]),
+                Some(act[j - 1]),
+            ));
+            i -= 1;
+            j -= 1;
+        } else if i > 0 && dp[i][j] == dp[i - 1][j] + 1 {
+            edits.push(edit(WordEditKind::Deletion, i, Some(exp[i - 1]), None));
+            i -= 1;
+        } else {
+            edits.push(edit(WordEditKind::Insertion, i + 1, None, Some(act[j - 1])));
+            j -= 1;
+        }
+    }
+    edits.reverse();
+
+    let distance = dp[n][m];
+    let longest = n.max(m);
+    let accuracy = if longest == 0 {
+        1.0
+    } else {
+        1.0 - distance as f64 / longest as f64
+    };
+
+    WordDiff {
+        edits,
+        distance,
+        accuracy,
+    }
+}
+
+/// Similarity of two character sequences (0.0 to 1.0) from their edit
+/// distance; two empty sequences are identical
+pub(crate) fn char_similarity(a: &[char], b: &[char]) -> f64 {
+    let longest = a.len().max(b.len());
+    if longest == 0 {
+        return 1.0;
+    }
+    1.0 - edit_distance(a, b) as f64 / longest as f64
+}
+
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.iter().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+fn is_swap(exp: &[String], act: &[String], i: usize, j: usize) -> bool {
+    i > 1
+        && j > 1
+        && exp[i - 1] != act[j - 1]
+        && exp[i - 1] == act[j - 2]
+        && exp[i - 2] == act[j - 1]
+}
+
+fn edit(
+    kind: WordEditKind,
+    expected_position: usize,
+    expected: Option<&str>,
+    actual: Option<&str>,
+) -> WordEdit {
+    WordEdit {
+        kind,
+        expected_position,
+        expected: expected.map(str::to_string),
+        actual: actual.map(str::to_string),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VERSE: &str = "قُلْ هُوَ ٱللَّهُ أَحَدٌ";
+
+    fn kinds(diff: &WordDiff) -> Vec<WordEditKind> {
+        diff.edits.iter().map(|e| e.kind).collect()
+    }
+
+    #[test]
+    fn test_char_similarity() {
+        let chars = |s: &str| s.chars().collect::<Vec<_>>();
+        assert_eq!(char_similarity(&[], &[]), 1.0);
+        assert_eq!(char_similarity(&chars("abcd"), &chars("abcd")), 1.0);
+        assert_eq!(char_similarity(&chars("abcd"), &chars("abed")), 0.75);
+        assert_eq!(char_similarity(&chars("ab"), &chars("abcd")), 0.5);
+        assert_eq!(char_similarity(&chars("ab"), &[]), 0.0);
+    }
+
+    #[test]
+    fn test_exact_match_ignores_diacritics() {
+        let diff = diff_words(VERSE, "قل هو الله احد");
+        assert!(diff.is_exact());
+        assert_eq!(diff.accuracy, 1.0);
+        assert_eq!(
+            GradeThresholds::default().grade(diff.accuracy),
+            ReviewGrade::Easy
+        );
+    }
+
+    #[test]
+    fn test_deletion_and_substitution() {
+        let diff = diff_words(VERSE, "قل هو احد");
+        assert_eq!(
+            kinds(&diff),
+            vec![
+                WordEditKind::Match,
+                WordEditKind::Match,
+                WordEditKind::Deletion,
+                WordEditKind::Match
+            ]
+        );
+        assert_eq!(diff.mistakes().next().unwrap().expected_position, 3);
+        assert_eq!(diff.accuracy, 0.75);
+
+        let diff = diff_words(VERSE, "قل هو الرب احد");
+        let mistake = diff.mistakes().next().unwrap();
+        assert_eq!(mistake.kind, WordEditKind::Substitution);
+        assert_eq!(mistake.actual.as_deref(), Some("الرب"));
+    }
+
+    #[test]
+    fn test_insertion_and_swap() {
+        let diff = diff_words(VERSE, "قل هو و الله احد");
+        let mistake = diff.mistakes().next().unwrap();
+        assert_eq!(mistake.kind, WordEditKind::Insertion);
+        assert_eq!(mistake.expected_position, 3);
+        assert_eq!(diff.distance, 1);
+
+        let diff = diff_words(VERSE, "قل هو احد الله");
+        assert_eq!(diff.distance, 1);
+        assert_eq!(
+            kinds(&diff),
+            vec![
+                WordEditKind::Match,
+                WordEditKind::Match,
+                WordEditKind::Swap,
+                WordEditKind::Swap
+            ]
+        );
+    }
+
+    #[test]
+    fn test_grade_thresholds() {
+        let thresholds = GradeThresholds::default();
+        assert_eq!(thresholds.grade(0.95), ReviewGrade::Good);
+        assert_eq!(thresholds.grade(0.7), ReviewGrade::Hard);
+        assert_eq!(thresholds.grade(0.2), ReviewGrade::Again);
+
+        let lenient = GradeThresholds {
+            easy: 0.9,
+            good: 0.75,
+            hard: 0.5,
+        };
+        assert_eq!(lenient.grade(0.75), ReviewGrade::Good);
+        assert_eq!(diff_words(VERSE, "").accuracy, 0.0);
+    }
+}
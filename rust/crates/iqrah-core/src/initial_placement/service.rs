@@ -15,7 +15,7 @@ use crate::ports::{ContentRepository, UserRepository};
 
 use super::config::InitialPlacementConfig;
 use super::summary::{InitialPlacementSummary, SurahPlacementResult};
-use super::types::IntakeAnswers;
+use super::types::{ArabicLevel, IntakeAnswers};
 
 /// Service for applying initial knowledge placement based on intake answers.
 ///
@@ -60,7 +60,8 @@ where
     /// Apply initial placement based on intake answers.
     ///
     /// This initializes the user's memory states for verses and vocabulary
-    /// based on their self-reported knowledge levels.
+    /// based on their self-reported knowledge levels, and stores their Arabic
+    /// reading level (`ArabicLevel::SETTING_KEY`) when reported.
     ///
     /// # Arguments
     /// * `user_id` - User identifier
@@ -123,6 +124,13 @@ where
             self.user_repo.save_memory_states_batch(&all_states).await?;
         }
 
+        // Remembered for exercise selection (transliteration bridges)
+        if let Some(level) = answers.arabic_level {
+            self.user_repo
+                .set_setting(ArabicLevel::SETTING_KEY, level.as_str())
+                .await?;
+        }
+
         info!(
             "Initial placement complete: {} verses, {} vocab nodes",
             summary.verses_initialized, summary.vocab_nodes_initialized
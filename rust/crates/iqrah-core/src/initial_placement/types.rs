@@ -50,6 +50,38 @@ pub enum ArabicLevel {
 }
 
 impl ArabicLevel {
+    /// App setting holding the learner's level
+    pub const SETTING_KEY: &'static str = "arabic_level";
+
+    /// Slug used in settings and APIs
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::BasicReading => "basic_reading",
+            Self::ComfortableReading => "comfortable_reading",
+            Self::FluentReading => "fluent_reading",
+            Self::NativeLike => "native_like",
+        }
+    }
+
+    /// Parse a slug produced by `as_str`
+    pub fn from_slug(slug: &str) -> Option<Self> {
+        match slug {
+            "none" => Some(Self::None),
+            "basic_reading" => Some(Self::BasicReading),
+            "comfortable_reading" => Some(Self::ComfortableReading),
+            "fluent_reading" => Some(Self::FluentReading),
+            "native_like" => Some(Self::NativeLike),
+            _ => None,
+        }
+    }
+
+    /// Whether the learner still needs transliteration to bridge into
+    /// reading Arabic script
+    pub fn needs_transliteration(&self) -> bool {
+        matches!(self, Self::None | Self::BasicReading)
+    }
+
     /// Get the reading fluency multiplier for this level.
     ///
     /// Returns a value 0.0-1.0 that can be used to adjust difficulty
@@ -123,6 +155,20 @@ mod tests {
         assert_eq!(ArabicLevel::NativeLike.fluency_multiplier(), 1.0);
     }
 
+    #[test]
+    fn test_arabic_level_slug_roundtrip() {
+        for level in [
+            ArabicLevel::None,
+            ArabicLevel::BasicReading,
+            ArabicLevel::ComfortableReading,
+            ArabicLevel::FluentReading,
+            ArabicLevel::NativeLike,
+        ] {
+            assert_eq!(ArabicLevel::from_slug(level.as_str()), Some(level));
+        }
+        assert_eq!(ArabicLevel::from_slug("fluent"), None);
+    }
+
     #[test]
     fn test_effective_reading_fluency_prefers_explicit() {
         let answers = IntakeAnswers {
@@ -14,6 +14,9 @@ pub mod testing;
 
 // Re-export commonly used types
 pub use domain::{
+    // Recitation Audio
+    AudioSource,
+    AyahJunction,
     // V2 Domain Models
     Chapter,
     // Package Management
@@ -27,7 +30,10 @@ pub use domain::{
     Edge,
     EdgeType,
     Exercise,
+    ExerciseCheckpoint,
+    GrammarFeature,
     Hint,
+    HintLevel,
     ImportStats,
     ImportedEdge,
     ImportedNode,
@@ -37,22 +43,41 @@ pub use domain::{
     KnowledgeNode,
     Language,
     Lemma,
+    LemmaOccurrences,
     MemoryState,
     // Morphology Models
+    MorphologyFeatures,
     MorphologySegment,
+    MushafLine,
     Node,
+    NodeStatus,
     NodeType,
+    PackageContent,
     PackageType,
     PropagationDetail,
     PropagationEvent,
+    Reciter,
     ReviewGrade,
     Root,
+    RootOccurrences,
+    Script,
     Session,
     SessionItem,
     SessionSummary,
+    SimilarVerse,
+    // Tafsir
+    Tafsir,
+    TafsirPassage,
+    // Tajweed
+    TajweedAnnotation,
+    TajweedRule,
+    TajweedSpan,
     Translator,
     Verse,
+    VerseRecitation,
     Word,
+    WordAudioClip,
+    WordSegment,
     WordVisibility,
 };
 
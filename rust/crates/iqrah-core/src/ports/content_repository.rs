@@ -1,6 +1,8 @@
 use crate::domain::{
-    Chapter, ContentPackage, Edge, InstalledPackage, Language, Lemma, MorphologySegment, Node,
-    NodeType, PackageType, Root, Translator, Verse, Word,
+    Chapter, ContentPackage, Edge, InstalledPackage, Language, Lemma, LemmaOccurrences,
+    MorphologySegment, MushafLine, Node, NodeType, PackageContent, PackageType, Reciter, Root,
+    RootOccurrences, SimilarVerse, Tafsir, TafsirPassage, TajweedAnnotation, Translator, Verse,
+    VerseRecitation, Word, WordAudioClip,
 };
 use async_trait::async_trait;
 use std::collections::HashMap;
@@ -36,6 +38,24 @@ pub trait ContentRepository: Send + Sync {
         }
     }
 
+    /// Get script content for many nodes in one script
+    ///
+    /// Nodes without content are left out of the map. Default implementation
+    /// calls `get_script_content` once per node.
+    async fn get_script_contents_batch(
+        &self,
+        node_ids: &[i64],
+        script_slug: &str,
+    ) -> anyhow::Result<HashMap<i64, String>> {
+        let mut contents = HashMap::new();
+        for &node_id in node_ids {
+            if let Some(text) = self.get_script_content(node_id, script_slug).await? {
+                contents.insert(node_id, text);
+            }
+        }
+        Ok(contents)
+    }
+
     /// Get translation for a node in a specific language
     async fn get_translation(&self, node_id: i64, lang: &str) -> anyhow::Result<Option<String>>;
 
@@ -184,6 +204,18 @@ pub trait ContentRepository: Send + Sync {
     /// Mark a package as installed
     async fn mark_package_installed(&self, package_id: &str) -> anyhow::Result<()>;
 
+    /// Atomically install the content of a package
+    ///
+    /// Inserts the owner of the content (a translator records the package's
+    /// author and version), then its rows, and marks the package installed,
+    /// in a single transaction. If any operation fails, all changes are
+    /// rolled back.
+    async fn install_package_content(
+        &self,
+        package: &ContentPackage,
+        content: &PackageContent,
+    ) -> anyhow::Result<()>;
+
     /// Mark a package as uninstalled
     async fn mark_package_uninstalled(&self, package_id: &str) -> anyhow::Result<()>;
 
@@ -196,6 +228,85 @@ pub trait ContentRepository: Send + Sync {
     /// Get enabled packages
     async fn get_enabled_packages(&self) -> anyhow::Result<Vec<InstalledPackage>>;
 
+    // ========================================================================
+    // Recitation Audio Methods
+    // ========================================================================
+
+    /// Get installed reciters, ordered by name
+    async fn get_reciters(&self) -> anyhow::Result<Vec<Reciter>>;
+
+    /// Insert a reciter
+    async fn insert_reciter(
+        &self,
+        slug: &str,
+        name: &str,
+        style: Option<String>,
+        package_id: Option<String>, // Link to content package
+    ) -> anyhow::Result<i32>;
+
+    /// Insert or replace a verse recitation and its word segments
+    async fn insert_verse_recitation(&self, recitation: &VerseRecitation) -> anyhow::Result<()>;
+
+    /// Insert or replace a standalone word clip
+    async fn insert_word_audio(&self, clip: &WordAudioClip) -> anyhow::Result<()>;
+
+    /// Get a reciter's recitation of a verse, with word segments
+    async fn get_verse_recitation(
+        &self,
+        verse_key: &str,
+        reciter_id: i32,
+    ) -> anyhow::Result<Option<VerseRecitation>>;
+
+    /// Get a reciter's audio for a word: its standalone clip when installed,
+    /// otherwise its segment of the verse recitation
+    async fn get_word_audio(
+        &self,
+        verse_key: &str,
+        position: i32,
+        reciter_id: i32,
+    ) -> anyhow::Result<Option<WordAudioClip>>;
+
+    // ========================================================================
+    // Tafsir Methods
+    // ========================================================================
+
+    /// Get tafsirs from installed and enabled packages, ordered by name
+    async fn get_tafsirs(&self) -> anyhow::Result<Vec<Tafsir>>;
+
+    /// Insert a tafsir
+    async fn insert_tafsir(
+        &self,
+        slug: &str,
+        name: &str,
+        author: Option<String>,
+        language_code: Option<String>,
+        package_id: Option<String>, // Link to content package
+    ) -> anyhow::Result<i32>;
+
+    /// Insert or replace a tafsir passage and its themes
+    async fn insert_tafsir_passage(&self, passage: &TafsirPassage) -> anyhow::Result<()>;
+
+    /// Get the passage of a tafsir commenting on a verse
+    async fn get_tafsir_passage(
+        &self,
+        verse_key: &str,
+        tafsir_id: i32,
+    ) -> anyhow::Result<Option<TafsirPassage>>;
+
+    /// Get a tafsir's passages on a chapter, in verse order
+    async fn get_tafsir_passages_for_chapter(
+        &self,
+        chapter_number: i32,
+        tafsir_id: i32,
+    ) -> anyhow::Result<Vec<TafsirPassage>>;
+
+    /// Get a tafsir's passages covering a theme, in mushaf order
+    async fn get_tafsir_passages_by_theme(
+        &self,
+        theme: &str,
+        tafsir_id: i32,
+    ) -> anyhow::Result<Vec<TafsirPassage>>;
+
     // ========================================================================
     // Morphology Methods (for grammar exercises)
     // ========================================================================
@@ -210,6 +321,69 @@ pub trait ContentRepository: Send + Sync {
     /// Get a lemma by its ID
     async fn get_lemma_by_id(&self, lemma_id: &str) -> anyhow::Result<Option<Lemma>>;
 
+    /// Get words with a segment derived from the given root, in mushaf order.
+    /// Default implementation returns no words (no morphology data).
+    async fn get_words_for_root(&self, _root_id: &str, _limit: u32) -> anyhow::Result<Vec<Word>> {
+        Ok(Vec::new())
+    }
+
+    /// Get corpus occurrence counts for the given lemmas (lemmas without
+    /// data are omitted). Default implementation returns no counts.
+    async fn get_lemma_frequencies(
+        &self,
+        _lemma_ids: &[String],
+    ) -> anyhow::Result<HashMap<String, u32>> {
+        Ok(HashMap::new())
+    }
+
+    /// Get the roots of the words in the given verses, each with the derived
+    /// words in those verses and its occurrence count across the Quran, most
+    /// frequent root first. Default implementation returns no roots.
+    async fn get_root_occurrences_in_verses(
+        &self,
+        _verse_keys: &[String],
+    ) -> anyhow::Result<Vec<RootOccurrences>> {
+        Ok(Vec::new())
+    }
+
+    /// Get the lemmas derived from a root with their occurrence counts, most
+    /// frequent first. Default implementation returns no lemmas.
+    async fn get_lemmas_for_root(&self, _root_id: &str) -> anyhow::Result<Vec<LemmaOccurrences>> {
+        Ok(Vec::new())
+    }
+
+    // ========================================================================
+    // Mutashabihat Methods (similar-verse discrimination)
+    // ========================================================================
+
+    /// Get verses similar to the given verse, most similar first
+    async fn get_similar_verses(
+        &self,
+        verse_key: &str,
+        limit: u32,
+    ) -> anyhow::Result<Vec<SimilarVerse>>;
+
+    // ========================================================================
+    // Mushaf Layout Methods (15-line Madani page layout)
+    // ========================================================================
+
+    /// Get the lines of a mushaf page in order (empty when no layout is loaded)
+    async fn get_mushaf_page(&self, page: i32) -> anyhow::Result<Vec<MushafLine>>;
+
+    /// Get the mushaf line holding a word-instance node
+    async fn get_mushaf_line_for_word(&self, word_id: i64) -> anyhow::Result<Option<MushafLine>>;
+
+    // ========================================================================
+    // Tajweed Methods
+    // ========================================================================
+
+    /// Get the tajweed annotations of a verse's words, in reading order
+    /// (empty when the content database has none)
+    async fn get_tajweed_annotations(
+        &self,
+        verse_key: &str,
+    ) -> anyhow::Result<Vec<TajweedAnnotation>>;
+
     // ========================================================================
     // Scheduler v2.0 Methods
     // ========================================================================
@@ -1,4 +1,7 @@
-use crate::domain::{MemoryState, PropagationEvent, Session, SessionItem, SessionSummary};
+use crate::domain::{
+    ExerciseCheckpoint, MemoryState, NodeStatus, PropagationEvent, Session, SessionItem,
+    SessionSummary,
+};
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 
@@ -123,6 +126,71 @@ pub trait UserRepository: Send + Sync {
     /// Ok(()) if all states saved successfully
     async fn save_memory_states_batch(&self, states: &[MemoryState]) -> anyhow::Result<()>;
 
+    // ========================================================================
+    // Node Status (Suspend / Bury / Reset)
+    // ========================================================================
+
+    /// Set the scheduling status for a set of nodes
+    ///
+    /// `NodeStatus::Active` removes any stored status, returning the nodes
+    /// to normal rotation.
+    async fn set_node_status(
+        &self,
+        user_id: &str,
+        node_ids: &[i64],
+        status: NodeStatus,
+    ) -> anyhow::Result<()>;
+
+    /// Get the scheduling status of a node (`Active` if none is stored)
+    async fn get_node_status(&self, user_id: &str, node_id: i64) -> anyhow::Result<NodeStatus>;
+
+    /// Get IDs of nodes that must not be scheduled at `now`
+    ///
+    /// Includes suspended nodes and nodes buried until after `now`.
+    async fn get_inactive_node_ids(
+        &self,
+        user_id: &str,
+        now: DateTime<Utc>,
+    ) -> anyhow::Result<std::collections::HashSet<i64>>;
+
+    /// Clear FSRS/energy state for a set of nodes
+    ///
+    /// The review log (session items, propagation events) is kept intact.
+    /// Returns the number of memory states removed.
+    async fn reset_memory_states(&self, user_id: &str, node_ids: &[i64]) -> anyhow::Result<u64>;
+
+    // ========================================================================
+    // Exercise Checkpoints
+    // ========================================================================
+
+    /// Save the in-progress state of a stateful exercise
+    ///
+    /// Replaces any checkpoint stored for the same (session_id, position).
+    async fn save_exercise_checkpoint(&self, checkpoint: &ExerciseCheckpoint)
+        -> anyhow::Result<()>;
+
+    /// Get the checkpoint of a session item, if any
+    async fn get_exercise_checkpoint(
+        &self,
+        session_id: &str,
+        position: i32,
+    ) -> anyhow::Result<Option<ExerciseCheckpoint>>;
+
+    /// Delete the checkpoint of a session item (no-op if none is stored)
+    async fn delete_exercise_checkpoint(
+        &self,
+        session_id: &str,
+        position: i32,
+    ) -> anyhow::Result<()>;
+
+    /// Delete checkpoints last updated before `cutoff`
+    ///
+    /// Returns the number of checkpoints removed.
+    async fn delete_exercise_checkpoints_before(
+        &self,
+        cutoff: DateTime<Utc>,
+    ) -> anyhow::Result<u64>;
+
     // ========================================================================
     // Scheduler v2.0 Methods
     // ========================================================================
@@ -49,7 +49,9 @@ pub use scoring::{
     calculate_days_overdue, calculate_priority_score, calculate_readiness,
     compute_weighted_urgency, count_unsatisfied_parents,
 };
-pub use session_generator::{generate_session, SessionMode};
+pub use session_generator::{
+    exclude_inactive_candidates, generate_session, is_node_inactive, SessionMode,
+};
 pub use types::{
     CandidateNode, InMemNode, MemoryBasics, ParentEnergyMap, SessionMixConfig, UserProfile,
     MASTERY_THRESHOLD,
@@ -1,3 +1,4 @@
+use crate::domain::node_id;
 use crate::scheduler_v2::events::{
     BucketAllocation, NullEventSink, SchedulerEvent, SchedulerEventSink, ScoreBreakdown,
     SessionModeEvent,
@@ -12,7 +13,7 @@ use crate::scheduler_v2::{
     calculate_days_overdue, calculate_readiness, compute_weighted_urgency, CandidateNode,
     InMemNode, ParentEnergyMap, SessionMixConfig, UserProfile,
 };
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 // ============================================================================
 // SESSION MODE
@@ -277,6 +278,31 @@ pub fn generate_session(
     }
 }
 
+/// Whether a node is suspended or buried according to `inactive_ids`.
+///
+/// Knowledge nodes inherit the status of their base node, so suspending a
+/// verse also suspends its memorization/translation axes.
+pub fn is_node_inactive(id: i64, inactive_ids: &HashSet<i64>) -> bool {
+    if inactive_ids.contains(&id) {
+        return true;
+    }
+    node_id::decode_knowledge_id(id).is_some_and(|(base_id, _)| inactive_ids.contains(&base_id))
+}
+
+/// Removes suspended/buried nodes from the candidate list.
+///
+/// Applied before `generate_session` so inactive nodes never reach scoring.
+/// `inactive_ids` comes from `UserRepository::get_inactive_node_ids`.
+pub fn exclude_inactive_candidates(
+    candidates: &mut Vec<CandidateNode>,
+    inactive_ids: &HashSet<i64>,
+) {
+    if inactive_ids.is_empty() {
+        return;
+    }
+    candidates.retain(|candidate| !is_node_inactive(candidate.id, inactive_ids));
+}
+
 /// Helper to get unsatisfied parent IDs for event emission
 fn get_unsatisfied_parent_ids(parent_ids: &[i64], parent_energies: &ParentEnergyMap) -> Vec<i64> {
     use crate::scheduler_v2::types::MASTERY_THRESHOLD;
@@ -982,4 +1008,30 @@ mod tests {
             score_low
         );
     }
+
+    #[test]
+    fn test_exclude_inactive_candidates() {
+        use crate::domain::KnowledgeAxis;
+
+        let verse = node_id::encode_verse(1, 1);
+        let verse_axis =
+            node_id::encode_knowledge(node_id::encode_verse(1, 2), KnowledgeAxis::Memorization);
+        let other = node_id::encode_verse(1, 3);
+
+        let mut candidates = vec![
+            make_candidate(verse, 0.5, 0.3, 0.2, 0.5, 0, 1000),
+            make_candidate(verse_axis, 0.5, 0.3, 0.2, 0.5, 0, 2000),
+            make_candidate(other, 0.5, 0.3, 0.2, 0.5, 0, 3000),
+        ];
+
+        let inactive: HashSet<i64> = [verse, node_id::encode_verse(1, 2)].into_iter().collect();
+        exclude_inactive_candidates(&mut candidates, &inactive);
+
+        let ids: Vec<i64> = candidates.iter().map(|c| c.id).collect();
+        assert_eq!(
+            ids,
+            vec![other],
+            "suspended base and its axis node are excluded"
+        );
+    }
 }
@@ -17,6 +17,13 @@
 // - Subsequent startups: ~1-3 seconds (loads from cache)
 // - Per-exercise: ~0ms (singleton already in RAM)
 //
+// ## Offline Loading
+// - `SemanticEmbedder::from_dir`: a model folder (tokenizer.json,
+//   model.safetensors, config.json), never touching the Hub
+// - `SemanticEmbedder::from_bytes`: tokenizer + safetensors bytes, e.g. from
+//   app assets
+// - Without any model, grading degrades to lexical similarity (see grader.rs)
+//
 // ## Flutter Integration Example
 // ```dart
 // import 'dart:io';
@@ -39,8 +46,18 @@
 
 use anyhow::{Context, Result};
 use model2vec_rs::model::StaticModel;
+use std::path::Path;
 use std::sync::Arc;
 
+/// Model files of a model2vec folder
+const TOKENIZER_FILE: &str = "tokenizer.json";
+const MODEL_FILE: &str = "model.safetensors";
+const CONFIG_FILE: &str = "config.json";
+
+/// config.json for models loaded from bytes (normalized embeddings, as the
+/// potion models)
+const DEFAULT_CONFIG: &str = r#"{"model_type": "model2vec", "normalize": true}"#;
+
 /// Semantic embedder using model2vec with multilingual embeddings
 ///
 /// Uses potion-multilingual-128M: A distilled version of BAAI/bge-m3
@@ -123,6 +140,83 @@ impl SemanticEmbedder {
         })
     }
 
+    /// Load a model from a local model2vec folder (tokenizer.json,
+    /// model.safetensors and config.json), without the HuggingFace Hub
+    ///
+    /// Unlike `new`, a missing folder is an error rather than a model ID to
+    /// download.
+    pub fn from_dir(model_dir: impl AsRef<Path>) -> Result<Self> {
+        let model_dir = model_dir.as_ref();
+        if !model_dir.is_dir() {
+            anyhow::bail!(
+                "Semantic model directory not found: {}",
+                model_dir.display()
+            );
+        }
+        for file in [TOKENIZER_FILE, MODEL_FILE, CONFIG_FILE] {
+            if !model_dir.join(file).is_file() {
+                anyhow::bail!(
+                    "Semantic model directory {} is missing {}",
+                    model_dir.display(),
+                    file
+                );
+            }
+        }
+
+        tracing::info!("Loading semantic model from {}", model_dir.display());
+        let model = StaticModel::from_pretrained(model_dir, None, None, None)
+            .context("Failed to load semantic model")?;
+
+        Ok(Self {
+            model: Arc::new(model),
+        })
+    }
+
+    /// Load a model from in-memory tokenizer.json and model.safetensors bytes
+    /// (e.g., bundled app assets)
+    ///
+    /// model2vec-rs only loads from files, so the bytes are staged in a fresh
+    /// subdirectory of `staging_dir` (created if missing; on mobile, a
+    /// directory the app can write to such as its cache directory). The
+    /// subdirectory is removed once the model is loaded, leaving anything
+    /// else in `staging_dir` untouched.
+    pub fn from_bytes(
+        tokenizer_json: &[u8],
+        model_safetensors: &[u8],
+        staging_dir: impl AsRef<Path>,
+    ) -> Result<Self> {
+        let staging_dir = staging_dir.as_ref();
+        std::fs::create_dir_all(staging_dir).with_context(|| {
+            format!(
+                "Failed to create semantic model directory {}",
+                staging_dir.display()
+            )
+        })?;
+        let dir = staging_dir.join(format!("semantic-model-{:016x}", rand::random::<u64>()));
+        std::fs::create_dir(&dir).with_context(|| {
+            format!(
+                "Failed to create semantic model directory {}",
+                dir.display()
+            )
+        })?;
+
+        let staged = [
+            (TOKENIZER_FILE, tokenizer_json),
+            (MODEL_FILE, model_safetensors),
+            (CONFIG_FILE, DEFAULT_CONFIG.as_bytes()),
+        ];
+        let result = staged
+            .iter()
+            .try_for_each(|(file, bytes)| {
+                std::fs::write(dir.join(file), bytes)
+                    .with_context(|| format!("Failed to stage semantic model {}", file))
+            })
+            .and_then(|()| Self::from_dir(&dir));
+
+        let _ = std::fs::remove_dir_all(&dir);
+        result
+    }
+
     /// Embed a single text string
     ///
     /// # Arguments
@@ -42,8 +42,11 @@ pub struct SemanticGrade {
 }
 
 /// Semantic answer grader with configurable thresholds
+///
+/// Without an embedder (no model loaded) the grader is degraded: answers are
+/// scored by `lexical_similarity` against the same thresholds.
 pub struct SemanticGrader<'a> {
-    embedder: &'a SemanticEmbedder,
+    embedder: Option<&'a SemanticEmbedder>,
     /// Minimum similarity for "Excellent" grade (default: 0.85)
     excellent_min: f32,
     /// Minimum similarity for "Partial" grade (default: 0.70)
@@ -59,12 +62,37 @@ impl<'a> SemanticGrader<'a> {
     /// - Incorrect: < 0.70
     pub fn new(embedder: &'a SemanticEmbedder) -> Self {
         Self {
-            embedder,
+            embedder: Some(embedder),
             excellent_min: 0.85,
             partial_min: 0.70,
         }
     }
 
+    /// Create a degraded grader, scoring by lexical similarity, with default
+    /// thresholds
+    pub fn lexical() -> Self {
+        Self {
+            embedder: None,
+            excellent_min: 0.85,
+            partial_min: 0.70,
+        }
+    }
+
+    /// Create a grader over the global `SEMANTIC_EMBEDDER`, degraded to
+    /// lexical grading when no model is loaded
+    pub fn global() -> SemanticGrader<'static> {
+        match SEMANTIC_EMBEDDER.get() {
+            Some(embedder) => SemanticGrader::new(embedder),
+            None => SemanticGrader::lexical(),
+        }
+    }
+
+    /// Whether answers are graded by a semantic model (false when degraded
+    /// to lexical grading)
+    pub fn is_semantic(&self) -> bool {
+        self.embedder.is_some()
+    }
+
     /// Create a new grader with custom thresholds
     ///
     /// # Arguments
@@ -77,7 +105,7 @@ impl<'a> SemanticGrader<'a> {
         partial_min: f32,
     ) -> Self {
         Self {
-            embedder,
+            embedder: Some(embedder),
             excellent_min,
             partial_min,
         }
@@ -92,7 +120,10 @@ impl<'a> SemanticGrader<'a> {
     /// # Returns
     /// A SemanticGrade with label and similarity score
     pub fn grade_answer(&self, user_answer: &str, reference_answer: &str) -> Result<SemanticGrade> {
-        let similarity = self.embedder.similarity(user_answer, reference_answer)?;
+        let similarity = match self.embedder {
+            Some(embedder) => embedder.similarity(user_answer, reference_answer)?,
+            None => lexical_similarity(user_answer, reference_answer),
+        };
         let label = self.classify_similarity(similarity);
 
         Ok(SemanticGrade { label, similarity })
@@ -112,9 +143,13 @@ impl<'a> SemanticGrader<'a> {
         user_answer: &str,
         reference_answers: &[String],
     ) -> Result<SemanticGrade> {
-        let similarity = self
-            .embedder
-            .max_similarity(user_answer, reference_answers)?;
+        let similarity = match self.embedder {
+            Some(embedder) => embedder.max_similarity(user_answer, reference_answers)?,
+            None => reference_answers
+                .iter()
+                .map(|reference| lexical_similarity(user_answer, reference))
+                .fold(0.0, f32::max),
+        };
         let label = self.classify_similarity(similarity);
 
         Ok(SemanticGrade { label, similarity })
@@ -137,6 +172,57 @@ impl<'a> SemanticGrader<'a> {
     }
 }
 
+/// Similarity of two answers from their text alone, between 0.0 and 1.0
+///
+/// The better of the word overlap (Dice coefficient over lowercase words,
+/// punctuation dropped) and the character-bigram overlap, which tolerates
+/// typos. Used when no semantic model is loaded.
+pub fn lexical_similarity(text_a: &str, text_b: &str) -> f32 {
+    let words_a = lexical_words(text_a);
+    let words_b = lexical_words(text_b);
+    if words_a.is_empty() || words_b.is_empty() {
+        return 0.0;
+    }
+    if words_a == words_b {
+        return 1.0;
+    }
+
+    let bigrams = |words: &[String]| -> Vec<(char, char)> {
+        let chars: Vec<char> = words.join(" ").chars().collect();
+        chars.windows(2).map(|pair| (pair[0], pair[1])).collect()
+    };
+
+    dice_coefficient(&words_a, &words_b)
+        .max(dice_coefficient(&bigrams(&words_a), &bigrams(&words_b)))
+}
+
+fn lexical_words(text: &str) -> Vec<String> {
+    text.to_lowercase()
+        .split(|c: char| c.is_whitespace() || c.is_ascii_punctuation() || "،؛؟".contains(c))
+        .filter(|word| !word.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Dice coefficient of two multisets: 2 × shared items / total items
+fn dice_coefficient<T: PartialEq>(a: &[T], b: &[T]) -> f32 {
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    let mut unmatched: Vec<&T> = b.iter().collect();
+    let shared = a
+        .iter()
+        .filter(|item| {
+            unmatched
+                .iter()
+                .position(|other| other == item)
+                .map(|i| unmatched.swap_remove(i))
+                .is_some()
+        })
+        .count();
+    2.0 * shared as f32 / (a.len() + b.len()) as f32
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -231,6 +317,41 @@ mod tests {
         assert_eq!(sim, 0.0);
     }
 
+    #[test]
+    fn test_lexical_similarity() {
+        assert_eq!(lexical_similarity("Hello, world!", "hello world"), 1.0);
+        assert_eq!(lexical_similarity("", "hello"), 0.0);
+
+        let sim = lexical_similarity("In the name of God", "In the name of Allah");
+        assert!((0.7..0.85).contains(&sim), "got {}", sim);
+        // A typo keeps most character bigrams
+        assert!(lexical_similarity("mercifull", "merciful") >= 0.85);
+        assert!(lexical_similarity("completely different", "In the name of God") < 0.3);
+    }
+
+    #[test]
+    fn test_lexical_grader_degraded_mode() {
+        let grader = SemanticGrader::lexical();
+        assert!(!grader.is_semantic());
+
+        let grade = grader
+            .grade_answer("the Most Merciful", "The Most Merciful.")
+            .unwrap();
+        assert_eq!(grade.label, SemanticGradeLabel::Excellent);
+        let grade = grader
+            .grade_answer("In the name of God", "In the name of Allah")
+            .unwrap();
+        assert_eq!(grade.label, SemanticGradeLabel::Partial);
+        let grade = grader
+            .grade_against_many("lord", &["Lord".to_string(), "Master".to_string()])
+            .unwrap();
+        assert_eq!(grade.similarity, 1.0);
+        let grade = grader
+            .grade_answer("goodbye", "In the name of God")
+            .unwrap();
+        assert_eq!(grade.label, SemanticGradeLabel::Incorrect);
+    }
+
     #[test]
     fn test_grade_label_to_str() {
         assert_eq!(SemanticGradeLabel::Excellent.to_str(), "Excellent");
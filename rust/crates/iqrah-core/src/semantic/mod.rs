@@ -8,4 +8,6 @@ pub mod grader;
 mod tests;
 
 pub use embedding::SemanticEmbedder;
-pub use grader::{SemanticGrade, SemanticGradeLabel, SemanticGrader, SEMANTIC_EMBEDDER};
+pub use grader::{
+    lexical_similarity, SemanticGrade, SemanticGradeLabel, SemanticGrader, SEMANTIC_EMBEDDER,
+};
@@ -3,16 +3,9 @@
 
 use super::*;
 
-// Note: These tests assume a model is available at the test path.
-// In production, you would either:
-// 1. Bundle a test model
-// 2. Mock the embedder
-// 3. Skip these tests in CI and run them manually with a real model
-
 #[test]
 fn test_semantic_module_structure() {
     // Just verify the module structure is correct
-    // We can't test the actual model without a real model file
     // Test passes if module compiles correctly
 }
 
@@ -42,73 +35,118 @@ fn test_global_embedder_singleton() {
     // Initially should be empty
     assert!(SEMANTIC_EMBEDDER.get().is_none());
 
-    // Note: We can't actually initialize it here because we don't have a model file
-    // In production, this would be initialized at app startup
+    // Never initialized in tests: the singleton would switch every test in
+    // the crate from lexical to semantic grading. In production, it is
+    // initialized at app startup
+}
+
+// Tests against tests/fixtures/semantic-model: a tiny model2vec model whose
+// words embed to one-hot vectors, synonyms ("god"/"allah",
+// "praise"/"thanks", "mercy"/"merciful"/"compassionate") sharing one.
+const FIXTURE_MODEL_DIR: &str =
+    concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/semantic-model");
+
+#[test]
+fn test_embedder_from_fixture_dir() {
+    use embedding::SemanticEmbedder;
+
+    let embedder = SemanticEmbedder::from_dir(FIXTURE_MODEL_DIR).unwrap();
+
+    let emb = embedder.embed("Hello world in the name").unwrap();
+    assert_eq!(emb.len(), 8);
+
+    let sim = embedder.similarity("Hello world", "Hello world").unwrap();
+    assert_eq!(sim, 0.0, "Unknown words have no embedding");
+    let sim = embedder.similarity("the name", "the name").unwrap();
+    assert!(sim > 0.99, "Identical text should have high similarity");
+
+    let sim = embedder
+        .similarity("praise of god", "thanks of allah")
+        .unwrap();
+    assert!(sim > 0.99, "Synonyms should have high similarity");
+    let sim = embedder
+        .similarity("mercy", "completely different")
+        .unwrap();
+    assert!(sim < 0.1, "Unrelated text should have low similarity");
+
+    let max = embedder
+        .max_similarity("merciful", &["the name".to_string(), "mercy".to_string()])
+        .unwrap();
+    assert!(max > 0.99);
+}
+
+#[test]
+fn test_embedder_from_bytes() {
+    use embedding::SemanticEmbedder;
+
+    let dir = std::path::Path::new(FIXTURE_MODEL_DIR);
+    let tokenizer = std::fs::read(dir.join("tokenizer.json")).unwrap();
+    let model = std::fs::read(dir.join("model.safetensors")).unwrap();
+
+    let staging = tempfile::tempdir().unwrap();
+    let staging_dir = staging.path().join("semantic");
+
+    let embedder = SemanticEmbedder::from_bytes(&tokenizer, &model, &staging_dir).unwrap();
+    let sim = embedder.similarity("god", "allah").unwrap();
+    assert!(sim > 0.99);
+    // Staged files are cleaned up once the model is loaded
+    assert_eq!(std::fs::read_dir(&staging_dir).unwrap().count(), 0);
+
+    assert!(SemanticEmbedder::from_bytes(b"not a tokenizer", &model, &staging_dir).is_err());
+    assert_eq!(std::fs::read_dir(&staging_dir).unwrap().count(), 0);
+
+    // Files already in the staging directory are left alone
+    std::fs::write(staging_dir.join("tokenizer.json"), b"app data").unwrap();
+    SemanticEmbedder::from_bytes(&tokenizer, &model, &staging_dir).unwrap();
+    assert_eq!(
+        std::fs::read(staging_dir.join("tokenizer.json")).unwrap(),
+        b"app data"
+    );
+    assert_eq!(std::fs::read_dir(&staging_dir).unwrap().count(), 1);
 }
 
-// Integration tests that require a real model would go here
-// They should be marked with #[ignore] by default and run manually
 #[test]
-#[ignore]
-fn test_embedder_with_real_model() {
+fn test_embedder_from_missing_dir_never_downloads() {
     use embedding::SemanticEmbedder;
 
-    // This test requires a real model file
-    // Run with: cargo test -- --ignored
-    let model_path = "tests/fixtures/bge-m3-test.model";
-
-    match SemanticEmbedder::new(model_path) {
-        Ok(embedder) => {
-            // Test embedding
-            let emb = embedder.embed("Hello world").unwrap();
-            assert!(!emb.is_empty(), "Embedding should not be empty");
-
-            // Test similarity
-            let sim = embedder.similarity("Hello world", "Hello world").unwrap();
-            assert!(sim > 0.9, "Identical text should have high similarity");
-
-            let sim = embedder.similarity("Hello world", "Goodbye world").unwrap();
-            assert!(sim < 0.9, "Different text should have lower similarity");
-        }
-        Err(_) => {
-            // Model not found, skip test
-            println!("Model not found at {}, skipping test", model_path);
-        }
-    }
+    // A missing folder is an error, not a HuggingFace model ID
+    let err = SemanticEmbedder::from_dir("minishlab/potion-multilingual-128M")
+        .err()
+        .unwrap();
+    assert!(err.to_string().contains("not found"));
+
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::copy(
+        std::path::Path::new(FIXTURE_MODEL_DIR).join("tokenizer.json"),
+        dir.path().join("tokenizer.json"),
+    )
+    .unwrap();
+    let err = SemanticEmbedder::from_dir(dir.path()).err().unwrap();
+    assert!(err.to_string().contains("model.safetensors"));
 }
 
 #[test]
-#[ignore]
-fn test_grader_with_real_model() {
+fn test_grader_with_fixture_model() {
     use embedding::SemanticEmbedder;
-    use grader::SemanticGrader;
-
-    // This test requires a real model file
-    let model_path = "tests/fixtures/bge-m3-test.model";
-
-    match SemanticEmbedder::new(model_path) {
-        Ok(embedder) => {
-            let grader = SemanticGrader::new(&embedder);
-
-            // Test grading
-            let grade = grader
-                .grade_answer("In the name of God", "In the name of Allah")
-                .unwrap();
-            assert!(
-                grade.similarity > 0.7,
-                "Similar meanings should have reasonable similarity"
-            );
-
-            let grade = grader
-                .grade_answer("Completely different", "In the name of God")
-                .unwrap();
-            assert!(
-                grade.similarity < 0.7,
-                "Different meanings should have low similarity"
-            );
-        }
-        Err(_) => {
-            println!("Model not found at {}, skipping test", model_path);
-        }
-    }
+    use grader::{SemanticGradeLabel, SemanticGrader};
+
+    let embedder = SemanticEmbedder::from_dir(FIXTURE_MODEL_DIR).unwrap();
+    let grader = SemanticGrader::new(&embedder);
+    assert!(grader.is_semantic());
+
+    // Synonyms grade as meaning, where lexical grading only gives partial
+    // credit
+    let grade = grader
+        .grade_answer("In the name of God", "In the name of Allah")
+        .unwrap();
+    assert_eq!(grade.label, SemanticGradeLabel::Excellent);
+    let lexical = SemanticGrader::lexical()
+        .grade_answer("In the name of God", "In the name of Allah")
+        .unwrap();
+    assert_eq!(lexical.label, SemanticGradeLabel::Partial);
+
+    let grade = grader
+        .grade_answer("Completely different", "In the name of God")
+        .unwrap();
+    assert_eq!(grade.label, SemanticGradeLabel::Incorrect);
 }
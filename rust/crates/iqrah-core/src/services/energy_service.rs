@@ -62,6 +62,36 @@ pub fn map_energy_to_visibility(
     WordVisibility::Obscured { hint, coverage }
 }
 
+/// Maps the opening word of an ayah to a visibility state.
+///
+/// In a multi-ayah session the opening word is recalled from the end of the
+/// previous ayah, the junction where recitation most often breaks down. The
+/// word is masked by the strength of that transition (the weaker of its own
+/// energy and the previous ayah's closing word), so it is only hidden once the
+/// junction itself is learned. When obscured, the hint is always the first
+/// letter, the cue used to bridge into an ayah.
+///
+/// # Parameters
+/// - `energy`: The opening word's mastery level (0.0 to 1.0)
+/// - `text`: The opening word's text content
+/// - `closing_word_energy`: The energy of the previous ayah's last word
+pub fn map_junction_visibility(
+    energy: f64,
+    text: &str,
+    closing_word_energy: f64,
+) -> WordVisibility {
+    let strength = energy.min(closing_word_energy);
+    match map_energy_to_visibility(strength, text, Some(closing_word_energy), None) {
+        WordVisibility::Obscured { coverage, .. } => WordVisibility::Obscured {
+            hint: Hint::First {
+                char: text.chars().next().unwrap_or('_'),
+            },
+            coverage,
+        },
+        visibility => visibility,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -268,4 +298,26 @@ mod tests {
             _ => panic!("Expected Obscured"),
         }
     }
+
+    #[test]
+    fn test_junction_masked_by_weaker_side() {
+        // A well-known opening word stays visible while the transition is new
+        let visibility = map_junction_visibility(0.9, "test", 0.1);
+        assert_eq!(visibility, WordVisibility::Visible);
+
+        let visibility = map_junction_visibility(0.9, "test", 0.9);
+        assert_eq!(visibility, WordVisibility::Hidden);
+    }
+
+    #[test]
+    fn test_junction_hint_is_first_letter() {
+        // Both sides are anchors, which would give a Both hint mid-ayah
+        match map_junction_visibility(0.5, "قال", 0.6) {
+            WordVisibility::Obscured { hint, coverage } => {
+                assert_eq!(hint, Hint::First { char: 'ق' });
+                assert!(coverage > 0.0);
+            }
+            other => panic!("Expected Obscured variant, got {:?}", other),
+        }
+    }
 }
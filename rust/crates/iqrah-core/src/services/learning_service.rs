@@ -1,6 +1,8 @@
+use crate::domain::node_id;
+use crate::exercises::RecitationGrade;
 use crate::{
-    ContentRepository, MemoryState, PropagationDetail, PropagationEvent, ReviewGrade,
-    UserRepository,
+    ContentRepository, KnowledgeAxis, MemoryState, PropagationDetail, PropagationEvent,
+    ReviewGrade, UserRepository,
 };
 use anyhow::Result;
 use chrono::Utc;
@@ -94,6 +96,38 @@ impl LearningService {
         Ok(final_state)
     }
 
+    /// Record the per-word grades of a graded recitation
+    ///
+    /// Each word is reviewed on its memorization knowledge node, falling back
+    /// to the word-instance node; words with neither node in content.db are
+    /// skipped. Returns the reviewed node IDs with their new states.
+    #[instrument(skip(self, recitation), fields(user_id, words = recitation.words.len()))]
+    pub async fn process_recitation(
+        &self,
+        user_id: &str,
+        recitation: &RecitationGrade,
+    ) -> Result<Vec<(i64, MemoryState)>> {
+        let timestamp = Utc::now();
+        let mut reviewed = Vec::with_capacity(recitation.words.len());
+        for word in &recitation.words {
+            let word_node = word.expected.node_id;
+            let knowledge_node = node_id::encode_knowledge(word_node, KnowledgeAxis::Memorization);
+            let target = if self.content_repo.node_exists(knowledge_node).await? {
+                knowledge_node
+            } else if self.content_repo.node_exists(word_node).await? {
+                word_node
+            } else {
+                debug!(word_node, "No node to review for recited word");
+                continue;
+            };
+            let state = self
+                .process_review_at(user_id, target, word.grade, timestamp)
+                .await?;
+            reviewed.push((target, state));
+        }
+        Ok(reviewed)
+    }
+
     /// Get memory state or prepare initial state for a new node
     async fn get_or_create_initial_state(
         &self,
@@ -635,4 +669,49 @@ mod tests {
         assert!(result.energy <= 1.0, "Energy should not exceed 1.0");
         assert!(result.energy >= 0.0, "Energy should not be negative");
     }
+
+    #[tokio::test]
+    async fn test_process_recitation_reviews_memorization_nodes() {
+        use crate::exercises::{ExpectedWord, RecitationGrader, TranscriptWord};
+
+        let words: Vec<ExpectedWord> = ["قُلْ", "هُوَ", "ٱللَّهُ"]
+            .iter()
+            .enumerate()
+            .map(|(i, text)| ExpectedWord {
+                node_id: node_id::encode_word_instance(112, 1, i as u8 + 1),
+                verse_key: "112:1".to_string(),
+                position: i as i32 + 1,
+                text: text.to_string(),
+            })
+            .collect();
+        let memorization_1 =
+            node_id::encode_knowledge(words[0].node_id, KnowledgeAxis::Memorization);
+        let word_2 = words[1].node_id;
+
+        // Word 1 has a memorization node, word 2 only its word node, word 3 neither
+        let mut content_mock = MockContentRepository::new();
+        content_mock
+            .expect_node_exists()
+            .returning(move |id| Ok(id == memorization_1 || id == word_2));
+        content_mock
+            .expect_get_edges_from()
+            .returning(|_| Ok(vec![]));
+        let service = LearningService::new(
+            Arc::new(content_mock),
+            Arc::new(create_user_mock_for_new_state()),
+        );
+
+        let recitation =
+            RecitationGrader::default().grade(&words, &TranscriptWord::from_text("قل الله"), &[]);
+        let reviewed = service
+            .process_recitation("user1", &recitation)
+            .await
+            .unwrap();
+
+        assert_eq!(reviewed.len(), 2);
+        assert_eq!(reviewed[0].0, memorization_1);
+        assert_eq!(reviewed[1].0, word_2);
+        // Word 2 was skipped in the recitation
+        assert!(reviewed[1].1.energy < reviewed[0].1.energy);
+    }
 }
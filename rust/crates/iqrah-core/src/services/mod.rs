@@ -2,6 +2,7 @@ pub mod energy_service;
 mod learning_service;
 pub mod package_service;
 pub mod recall_model;
+pub mod response_grading;
 mod session_service;
 
 // Tests are now inline in respective service files
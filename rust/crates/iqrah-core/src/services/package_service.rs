@@ -1,13 +1,29 @@
-use crate::{ContentPackage, ContentRepository, InstalledPackage, PackageType};
-use anyhow::{bail, Context, Result};
+use crate::domain::node_id;
+use crate::{
+    AudioSource, ContentPackage, ContentRepository, InstalledPackage, PackageContent, PackageType,
+    Reciter, Tafsir, TafsirPassage, Translator, VerseRecitation, WordAudioClip, WordSegment,
+};
+use anyhow::{anyhow, bail, Context, Result};
 use sha2::Digest;
 use sqlx::SqlitePool;
+use std::collections::HashMap;
+use std::io::{Cursor, Read};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
+/// Name of the package database inside a zip package
+const ZIP_PACKAGE_DB: &str = "package.db";
+
+type PackageArchive = zip::ZipArchive<Cursor<Vec<u8>>>;
+
 /// Service for managing downloadable content packages
+///
+/// Packages are SQLite databases, or zip files holding a `package.db` plus
+/// the audio files its rows reference by `audio_path`.
 pub struct PackageService {
     content_repo: Arc<dyn ContentRepository>,
     http_client: reqwest::Client,
+    audio_dir: Option<PathBuf>,
 }
 
 impl PackageService {
@@ -15,9 +31,17 @@ impl PackageService {
         Self {
             content_repo,
             http_client: reqwest::Client::new(),
+            audio_dir: None,
         }
     }
 
+    /// Extract audio files of zip packages under `dir` (one subdirectory per
+    /// package) instead of storing them as blobs in content.db
+    pub fn with_audio_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.audio_dir = Some(dir.into());
+        self
+    }
+
     /// Get all available packages (optionally filtered)
     pub async fn get_available_packages(
         &self,
@@ -81,8 +105,40 @@ impl PackageService {
         Ok(bytes)
     }
 
-    /// Install a package from downloaded SQLite data
+    /// Install a package from a local SQLite or zip file
+    ///
+    /// Relative audio paths in a plain SQLite package are resolved against
+    /// the directory of the package file.
+    pub async fn install_package_from_file(
+        &self,
+        package_id: &str,
+        path: impl AsRef<Path>,
+    ) -> Result<()> {
+        let path = path.as_ref();
+        let package_data = std::fs::read(path)
+            .with_context(|| format!("Failed to read package file {}", path.display()))?;
+        let package_dir = std::path::absolute(path)?.parent().map(Path::to_path_buf);
+        self.install_package_data(package_id, package_data, package_dir.as_deref())
+            .await
+    }
+
+    /// Install a package from downloaded SQLite or zip data
     pub async fn install_package(&self, package_id: &str, package_data: Vec<u8>) -> Result<()> {
+        self.install_package_data(package_id, package_data, None)
+            .await
+    }
+
+    /// Install package data, resolving relative audio paths of a plain
+    /// SQLite package against `package_dir`
+    ///
+    /// Nothing is installed unless the whole package is: its rows are written
+    /// in one transaction, and audio extracted for a failed install is removed.
+    async fn install_package_data(
+        &self,
+        package_id: &str,
+        package_data: Vec<u8>,
+        package_dir: Option<&Path>,
+    ) -> Result<()> {
         // Get package metadata
         let package = self
             .content_repo
@@ -90,96 +146,356 @@ impl PackageService {
             .await?
             .context("Package not found in catalog")?;
 
-        // Write package data to temporary file
-        let temp_file = std::env::temp_dir().join(format!("{}.db", package_id));
-        std::fs::write(&temp_file, &package_data).context("Failed to write package file")?;
+        // Zip packages carry their database alongside audio files
+        let (package_db, mut archive) = if package_data.starts_with(b"PK\x03\x04") {
+            let mut archive = zip::ZipArchive::new(Cursor::new(package_data))
+                .context("Failed to open zip package")?;
+            let package_db = read_zip_entry(&mut archive, ZIP_PACKAGE_DB)?;
+            (package_db, Some(archive))
+        } else {
+            (package_data, None)
+        };
+
+        // Audio extracted by an earlier install belongs to that install
+        let extracted_dir = match (&self.audio_dir, &archive) {
+            (Some(audio_dir), Some(_)) => Some(audio_dir.join(package_id)).filter(|d| !d.exists()),
+            _ => None,
+        };
+
+        let content = self
+            .read_package_content(&package, &package_db, archive.as_mut(), package_dir)
+            .await;
+        let installed = match content {
+            Ok(content) => {
+                self.content_repo
+                    .install_package_content(&package, &content)
+                    .await
+            }
+            Err(e) => Err(e),
+        };
+
+        if installed.is_err() {
+            if let Some(dir) = extracted_dir.filter(|dir| dir.exists()) {
+                if let Err(e) = std::fs::remove_dir_all(&dir) {
+                    tracing::warn!(
+                        "Failed to remove audio of failed install {}: {}",
+                        package_id,
+                        e
+                    );
+                }
+            }
+        }
+        installed
+    }
+
+    /// Read the content of a package database
+    ///
+    /// The database is written to a temporary file for the duration of the
+    /// read; the file is removed and the pool closed whether or not the read
+    /// succeeds.
+    async fn read_package_content(
+        &self,
+        package: &ContentPackage,
+        package_db: &[u8],
+        archive: Option<&mut PackageArchive>,
+        package_dir: Option<&Path>,
+    ) -> Result<PackageContent> {
+        let temp_file = TempPackageFile::write(&package.package_id, package_db)?;
 
         // Open package database
         let package_pool =
-            SqlitePool::connect(&format!("sqlite://{}?mode=ro", temp_file.display()))
+            SqlitePool::connect(&format!("sqlite://{}?mode=ro", temp_file.path().display()))
                 .await
                 .context("Failed to open package database")?;
 
-        // Install based on package type
-        match package.package_type {
+        let content = match package.package_type {
             PackageType::VerseTranslation => {
-                self.install_translation_package(&package, &package_pool)
-                    .await?;
+                self.read_translation_package(package, &package_pool).await
             }
             PackageType::WordTranslation => {
-                bail!("Word translation packages not yet supported");
-            }
-            PackageType::TextVariant => {
-                bail!("Text variant packages not yet supported");
+                Err(anyhow!("Word translation packages not yet supported"))
             }
+            PackageType::TextVariant => Err(anyhow!("Text variant packages not yet supported")),
             PackageType::VerseRecitation => {
-                bail!("Verse recitation packages not yet supported");
+                self.read_recitation_package(package, &package_pool, archive, package_dir)
+                    .await
             }
             PackageType::WordAudio => {
-                bail!("Word audio packages not yet supported");
+                self.read_word_audio_package(package, &package_pool, archive, package_dir)
+                    .await
             }
             PackageType::Transliteration => {
-                bail!("Transliteration packages not yet supported");
+                Err(anyhow!("Transliteration packages not yet supported"))
             }
-        }
+            PackageType::Tafsir => self.read_tafsir_package(package, &package_pool).await,
+        };
 
-        // Close package database
         package_pool.close().await;
-
-        // Delete temporary file
-        std::fs::remove_file(&temp_file).ok();
-
-        // Mark package as installed
-        self.content_repo.mark_package_installed(package_id).await?;
-
-        Ok(())
+        content
     }
 
-    /// Install a translation package
-    async fn install_translation_package(
+    /// Read a translation package
+    async fn read_translation_package(
         &self,
         package: &ContentPackage,
         package_pool: &SqlitePool,
-    ) -> Result<()> {
-        // 1. Create translator entry linked to package
-        let translator_id = self
-            .content_repo
-            .insert_translator(
-                &format!(
+    ) -> Result<PackageContent> {
+        let translations = sqlx::query!(
+            "SELECT verse_key, translation FROM verse_translations ORDER BY verse_key",
+        )
+        .fetch_all(package_pool)
+        .await
+        .context("Failed to read translations from package")?;
+
+        let language_code = package.language_code.as_deref();
+        Ok(PackageContent::VerseTranslations {
+            translator: Translator {
+                id: 0,
+                slug: format!(
                     "{}-{}",
-                    package
-                        .language_code
-                        .as_ref()
-                        .unwrap_or(&"unknown".to_string()),
+                    language_code.unwrap_or("unknown"),
                     package.package_id
                 ),
-                &package.name,
-                package.language_code.as_ref().unwrap_or(&"en".to_string()),
-                package.description.clone(),
-                package.author.clone(),
-                package.license.clone(),
-                None, // website
-                Some(package.version.clone()),
-                Some(package.package_id.clone()), // Link translator to this package
-            )
-            .await?;
+                full_name: package.name.clone(),
+                language_code: language_code.unwrap_or("en").to_string(),
+                description: package.description.clone(),
+                license: package.license.clone(),
+                package_id: Some(package.package_id.clone()),
+            },
+            translations: translations
+                .into_iter()
+                .map(|row| (row.verse_key, row.translation))
+                .collect(),
+        })
+    }
 
-        // 2. Import verse translations from package
-        let translations = sqlx::query!(
-            "SELECT verse_key, translation FROM verse_translations ORDER BY verse_key",
+    /// Read a verse recitation package
+    ///
+    /// Reads `verse_audio(verse_key, audio, audio_path, mime_type, duration_ms)`
+    /// and the word timings in `word_segments(verse_key, position, start_ms,
+    /// end_ms)`.
+    async fn read_recitation_package(
+        &self,
+        package: &ContentPackage,
+        package_pool: &SqlitePool,
+        mut archive: Option<&mut PackageArchive>,
+        package_dir: Option<&Path>,
+    ) -> Result<PackageContent> {
+        let mut segments: HashMap<String, Vec<WordSegment>> = HashMap::new();
+        let segment_rows = sqlx::query_as::<_, (String, i64, i64, i64)>(
+            "SELECT verse_key, position, start_ms, end_ms FROM word_segments ORDER BY verse_key, position",
         )
         .fetch_all(package_pool)
         .await
-        .context("Failed to read translations from package")?;
+        .context("Failed to read word segments from package")?;
+        for (verse_key, position, start_ms, end_ms) in segment_rows {
+            segments.entry(verse_key).or_default().push(WordSegment {
+                position: position as i32,
+                start_ms,
+                end_ms,
+            });
+        }
 
-        // 3. Batch insert translations
-        for row in translations {
-            self.content_repo
-                .insert_verse_translation(&row.verse_key, translator_id, &row.translation, None)
-                .await?;
+        let clips = sqlx::query_as::<_, PackageAudioRow>(
+            "SELECT verse_key, 0 AS position, audio, audio_path, mime_type, duration_ms
+             FROM verse_audio ORDER BY verse_key",
+        )
+        .fetch_all(package_pool)
+        .await
+        .context("Failed to read verse audio from package")?;
+
+        let recitations = clips
+            .into_iter()
+            .map(|clip| {
+                Ok(VerseRecitation {
+                    reciter_id: 0,
+                    source: self.audio_source(
+                        package,
+                        &clip,
+                        archive.as_deref_mut(),
+                        package_dir,
+                    )?,
+                    segments: segments.remove(&clip.verse_key).unwrap_or_default(),
+                    verse_key: clip.verse_key,
+                    mime_type: clip.mime_type,
+                    duration_ms: clip.duration_ms,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(PackageContent::VerseRecitations {
+            reciter: package_reciter(package),
+            recitations,
+        })
+    }
+
+    /// Read a word audio package
+    ///
+    /// Reads `word_audio(verse_key, position, audio, audio_path, mime_type,
+    /// duration_ms)`.
+    async fn read_word_audio_package(
+        &self,
+        package: &ContentPackage,
+        package_pool: &SqlitePool,
+        mut archive: Option<&mut PackageArchive>,
+        package_dir: Option<&Path>,
+    ) -> Result<PackageContent> {
+        let clips = sqlx::query_as::<_, PackageAudioRow>(
+            "SELECT verse_key, position, audio, audio_path, mime_type, duration_ms
+             FROM word_audio ORDER BY verse_key, position",
+        )
+        .fetch_all(package_pool)
+        .await
+        .context("Failed to read word audio from package")?;
+
+        let clips = clips
+            .into_iter()
+            .map(|clip| {
+                Ok(WordAudioClip {
+                    reciter_id: 0,
+                    source: self.audio_source(
+                        package,
+                        &clip,
+                        archive.as_deref_mut(),
+                        package_dir,
+                    )?,
+                    verse_key: clip.verse_key,
+                    position: clip.position as i32,
+                    mime_type: clip.mime_type,
+                    duration_ms: clip.duration_ms,
+                    start_ms: None,
+                    end_ms: None,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(PackageContent::WordAudio {
+            reciter: package_reciter(package),
+            clips,
+        })
+    }
+
+    /// Read a tafsir package
+    ///
+    /// Reads `tafsir_passages(start_verse_key, end_verse_key, text, key_lesson,
+    /// revelation_occasion)` and the passages' themes in
+    /// `tafsir_themes(start_verse_key, theme)`. A passage covers verses of a
+    /// single chapter.
+    async fn read_tafsir_package(
+        &self,
+        package: &ContentPackage,
+        package_pool: &SqlitePool,
+    ) -> Result<PackageContent> {
+        let mut themes: HashMap<String, Vec<String>> = HashMap::new();
+        let theme_rows = sqlx::query_as::<_, (String, String)>(
+            "SELECT start_verse_key, theme FROM tafsir_themes ORDER BY start_verse_key, theme",
+        )
+        .fetch_all(package_pool)
+        .await
+        .context("Failed to read tafsir themes from package")?;
+        for (start_verse_key, theme) in theme_rows {
+            themes.entry(start_verse_key).or_default().push(theme);
         }
 
-        Ok(())
+        let rows = sqlx::query_as::<_, PackageTafsirRow>(
+            "SELECT start_verse_key, end_verse_key, text, key_lesson, revelation_occasion
+             FROM tafsir_passages ORDER BY start_verse_key",
+        )
+        .fetch_all(package_pool)
+        .await
+        .context("Failed to read tafsir passages from package")?;
+
+        let mut passages = Vec::with_capacity(rows.len());
+        for passage in rows {
+            let (chapter, start_verse) =
+                node_id::parse_verse(&node_id::verse_from_key(&passage.start_verse_key))?;
+            let (end_chapter, end_verse) =
+                node_id::parse_verse(&node_id::verse_from_key(&passage.end_verse_key))?;
+            if end_chapter != chapter || end_verse < start_verse {
+                bail!(
+                    "Invalid tafsir passage range in package: {}-{}",
+                    passage.start_verse_key,
+                    passage.end_verse_key
+                );
+            }
+
+            passages.push(TafsirPassage {
+                tafsir_id: 0,
+                chapter_number: chapter as i32,
+                start_verse: start_verse as i32,
+                end_verse: end_verse as i32,
+                themes: themes.remove(&passage.start_verse_key).unwrap_or_default(),
+                text: passage.text,
+                key_lesson: passage.key_lesson,
+                revelation_occasion: passage.revelation_occasion,
+            });
+        }
+
+        Ok(PackageContent::Tafsir {
+            tafsir: Tafsir {
+                tafsir_id: 0,
+                slug: package.package_id.clone(),
+                name: package.name.clone(),
+                author: package.author.clone(),
+                language_code: package.language_code.clone(),
+                package_id: Some(package.package_id.clone()),
+            },
+            passages,
+        })
+    }
+
+    /// Resolve where the audio of a package row is stored
+    ///
+    /// Inline audio is kept as a blob. A path into a zip package is extracted
+    /// to the audio directory when one is set, and stored as a blob otherwise.
+    /// In a plain SQLite package, URLs are kept as they are and paths are
+    /// resolved against `package_dir`; they are rejected when the package has
+    /// no directory (downloaded data). Paths must be relative and stay inside
+    /// the package.
+    fn audio_source(
+        &self,
+        package: &ContentPackage,
+        clip: &PackageAudioRow,
+        archive: Option<&mut PackageArchive>,
+        package_dir: Option<&Path>,
+    ) -> Result<AudioSource> {
+        if let Some(bytes) = &clip.audio {
+            return Ok(AudioSource::Blob(bytes.clone()));
+        }
+        let path = clip
+            .audio_path
+            .as_ref()
+            .with_context(|| format!("No audio for {} in package", clip.verse_key))?;
+        if archive.is_none() && path.contains("://") {
+            return Ok(AudioSource::File(path.clone()));
+        }
+        let entry_path = Path::new(path);
+        if entry_path.components().any(|c| {
+            !matches!(
+                c,
+                std::path::Component::Normal(_) | std::path::Component::CurDir
+            )
+        }) {
+            bail!("Invalid audio path in package: {}", path);
+        }
+
+        let Some(archive) = archive else {
+            let package_dir = package_dir
+                .with_context(|| format!("Relative audio path in package data: {}", path))?;
+            let target = package_dir.join(entry_path);
+            return Ok(AudioSource::File(target.to_string_lossy().into_owned()));
+        };
+
+        let bytes = read_zip_entry(archive, path)?;
+        let Some(audio_dir) = &self.audio_dir else {
+            return Ok(AudioSource::Blob(bytes));
+        };
+        let target = audio_dir.join(&package.package_id).join(entry_path);
+        if let Some(parent) = target.parent() {
+            std::fs::create_dir_all(parent).context("Failed to create audio directory")?;
+        }
+        std::fs::write(&target, bytes).context("Failed to extract audio file")?;
+        Ok(AudioSource::File(target.to_string_lossy().into_owned()))
     }
 
     /// Uninstall a package (removes all associated data)
@@ -194,6 +510,15 @@ impl PackageService {
         // due to the foreign key constraints in the schema
         self.content_repo.delete_package(package_id).await?;
 
+        // Remove audio files extracted from a zip package
+        if let Some(audio_dir) = &self.audio_dir {
+            let package_dir = audio_dir.join(package_id);
+            if package_dir.exists() {
+                std::fs::remove_dir_all(&package_dir)
+                    .context("Failed to remove package audio files")?;
+            }
+        }
+
         Ok(())
     }
 
@@ -223,6 +548,83 @@ impl PackageService {
     }
 }
 
+/// Audio row of a recitation or word audio package
+#[derive(sqlx::FromRow)]
+struct PackageAudioRow {
+    verse_key: String,
+    position: i64,
+    audio: Option<Vec<u8>>,
+    audio_path: Option<String>,
+    mime_type: String,
+    duration_ms: Option<i64>,
+}
+
+/// Passage row of a tafsir package
+#[derive(sqlx::FromRow)]
+struct PackageTafsirRow {
+    start_verse_key: String,
+    end_verse_key: String,
+    text: String,
+    key_lesson: Option<String>,
+    revelation_occasion: Option<String>,
+}
+
+/// The reciter an audio package brings (named after its author)
+fn package_reciter(package: &ContentPackage) -> Reciter {
+    Reciter {
+        reciter_id: 0,
+        slug: package.package_id.clone(),
+        name: package
+            .author
+            .clone()
+            .unwrap_or_else(|| package.name.clone()),
+        style: None,
+        package_id: Some(package.package_id.clone()),
+    }
+}
+
+/// Package database written to the temp directory, removed when dropped
+struct TempPackageFile(PathBuf);
+
+impl TempPackageFile {
+    fn write(package_id: &str, data: &[u8]) -> Result<Self> {
+        let path = std::env::temp_dir().join(format!(
+            "iqrah-package-{}-{:016x}.db",
+            package_id,
+            rand::random::<u64>()
+        ));
+        // Guard first so a partial write is removed too
+        let file = Self(path);
+        std::fs::write(&file.0, data).context("Failed to write package file")?;
+        Ok(file)
+    }
+
+    fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempPackageFile {
+    fn drop(&mut self) {
+        if let Err(e) = std::fs::remove_file(&self.0) {
+            if e.kind() != std::io::ErrorKind::NotFound {
+                tracing::warn!("Failed to remove {}: {}", self.0.display(), e);
+            }
+        }
+    }
+}
+
+fn read_zip_entry(archive: &mut PackageArchive, name: &str) -> Result<Vec<u8>> {
+    let mut entry = archive
+        .by_name(name)
+        .with_context(|| format!("Missing {} in zip package", name))?;
+    let mut bytes = Vec::with_capacity(entry.size() as usize);
+    entry
+        .read_to_end(&mut bytes)
+        .with_context(|| format!("Failed to read {} from zip package", name))?;
+    Ok(bytes)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -300,6 +702,38 @@ mod tests {
             Ok(())
         }
 
+        async fn install_package_content(
+            &self,
+            package: &ContentPackage,
+            content: &PackageContent,
+        ) -> Result<()> {
+            let PackageContent::VerseTranslations {
+                translator,
+                translations,
+            } = content
+            else {
+                unimplemented!()
+            };
+            let translator_id = self
+                .insert_translator(
+                    &translator.slug,
+                    &translator.full_name,
+                    &translator.language_code,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    translator.package_id.clone(),
+                )
+                .await?;
+            for (verse_key, translation) in translations {
+                self.insert_verse_translation(verse_key, translator_id, translation, None)
+                    .await?;
+            }
+            self.mark_package_installed(&package.package_id).await
+        }
+
         async fn mark_package_uninstalled(&self, package_id: &str) -> Result<()> {
             self.installed.lock().unwrap().remove(package_id);
             Ok(())
@@ -492,6 +926,91 @@ mod tests {
                 .collect())
         }
 
+        async fn get_reciters(&self) -> Result<Vec<crate::Reciter>> {
+            Ok(vec![])
+        }
+
+        async fn insert_reciter(
+            &self,
+            _slug: &str,
+            _name: &str,
+            _style: Option<String>,
+            _package_id: Option<String>,
+        ) -> Result<i32> {
+            Ok(1)
+        }
+
+        async fn insert_verse_recitation(
+            &self,
+            _recitation: &crate::VerseRecitation,
+        ) -> Result<()> {
+            Ok(())
+        }
+
+        async fn insert_word_audio(&self, _clip: &crate::WordAudioClip) -> Result<()> {
+            Ok(())
+        }
+
+        async fn get_verse_recitation(
+            &self,
+            _verse_key: &str,
+            _reciter_id: i32,
+        ) -> Result<Option<crate::VerseRecitation>> {
+            Ok(None)
+        }
+
+        async fn get_word_audio(
+            &self,
+            _verse_key: &str,
+            _position: i32,
+            _reciter_id: i32,
+        ) -> Result<Option<crate::WordAudioClip>> {
+            Ok(None)
+        }
+
+        async fn get_tafsirs(&self) -> Result<Vec<crate::Tafsir>> {
+            Ok(vec![])
+        }
+
+        async fn insert_tafsir(
+            &self,
+            _slug: &str,
+            _name: &str,
+            _author: Option<String>,
+            _language_code: Option<String>,
+            _package_id: Option<String>,
+        ) -> Result<i32> {
+            Ok(1)
+        }
+
+        async fn insert_tafsir_passage(&self, _passage: &crate::TafsirPassage) -> Result<()> {
+            Ok(())
+        }
+
+        async fn get_tafsir_passage(
+            &self,
+            _verse_key: &str,
+            _tafsir_id: i32,
+        ) -> Result<Option<crate::TafsirPassage>> {
+            Ok(None)
+        }
+
+        async fn get_tafsir_passages_for_chapter(
+            &self,
+            _chapter_number: i32,
+            _tafsir_id: i32,
+        ) -> Result<Vec<crate::TafsirPassage>> {
+            Ok(vec![])
+        }
+
+        async fn get_tafsir_passages_by_theme(
+            &self,
+            _theme: &str,
+            _tafsir_id: i32,
+        ) -> Result<Vec<crate::TafsirPassage>> {
+            Ok(vec![])
+        }
+
         async fn get_morphology_for_word(
             &self,
             _word_id: i64,
@@ -507,6 +1026,32 @@ mod tests {
             Ok(None)
         }
 
+        async fn get_similar_verses(
+            &self,
+            _verse_key: &str,
+            _limit: u32,
+        ) -> Result<Vec<crate::SimilarVerse>> {
+            Ok(vec![])
+        }
+
+        async fn get_mushaf_page(&self, _page: i32) -> Result<Vec<crate::MushafLine>> {
+            Ok(vec![])
+        }
+
+        async fn get_mushaf_line_for_word(
+            &self,
+            _word_id: i64,
+        ) -> Result<Option<crate::MushafLine>> {
+            Ok(None)
+        }
+
+        async fn get_tajweed_annotations(
+            &self,
+            _verse_key: &str,
+        ) -> Result<Vec<crate::TajweedAnnotation>> {
+            Ok(vec![])
+        }
+
         async fn get_scheduler_candidates(
             &self,
             _goal_id: &str,
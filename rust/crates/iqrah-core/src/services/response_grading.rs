@@ -0,0 +1,220 @@
+use crate::{HintLevel, ReviewGrade};
+
+/// Personal response-time baseline for one exercise type
+///
+/// Built from the durations of the user's recent correct answers to that
+/// exercise type (`session_items.duration_ms`). The median is used so a few
+/// abandoned or interrupted items do not skew it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ResponseBaseline {
+    /// Median response time in milliseconds
+    pub median_ms: f64,
+    /// Number of answers the median was computed from
+    pub samples: usize,
+}
+
+impl ResponseBaseline {
+    /// Baseline from past response times, or None when there are no usable samples
+    pub fn from_durations(durations_ms: &[i64]) -> Option<Self> {
+        let mut durations: Vec<i64> = durations_ms.iter().copied().filter(|d| *d > 0).collect();
+        if durations.is_empty() {
+            return None;
+        }
+        durations.sort_unstable();
+
+        let mid = durations.len() / 2;
+        let median_ms = if durations.len().is_multiple_of(2) {
+            (durations[mid - 1] + durations[mid]) as f64 / 2.0
+        } else {
+            durations[mid] as f64
+        };
+
+        Some(Self {
+            median_ms,
+            samples: durations.len(),
+        })
+    }
+}
+
+/// What is known about a single answer
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ResponseEvidence {
+    /// Grade from correctness alone (e.g. `ValidationResult::suggested_grade`)
+    pub base_grade: ReviewGrade,
+    /// Time taken to answer in milliseconds
+    pub duration_ms: u64,
+    /// Most revealing hint shown before answering
+    pub hint_level: Option<HintLevel>,
+}
+
+/// Converts correctness, latency and hint usage into the `ReviewGrade` fed to
+/// `LearningService::process_review`.
+///
+/// # Rules
+/// - Wrong answers (base grade Again) stay Again; speed does not rescue them.
+/// - A hinted answer is capped at `HintLevel::max_grade` of the most revealing
+///   hint shown. Otherwise a correct answer never drops below Hard.
+/// - With a baseline of at least `min_samples` answers, a hint-free answer
+///   at or below `fast_ratio` × median is raised one step (so a confident fast
+///   recall becomes Easy), and an answer at or above `slow_ratio` × median is
+///   lowered one step (so a slow correct answer becomes Hard).
+/// - Without a usable baseline, latency is ignored.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ResponseGradingModel {
+    /// Answers this fast relative to the median count as confident
+    pub fast_ratio: f64,
+    /// Answers this slow relative to the median count as struggling
+    pub slow_ratio: f64,
+    /// Minimum number of past answers before latency is trusted
+    pub min_samples: usize,
+}
+
+impl Default for ResponseGradingModel {
+    fn default() -> Self {
+        Self {
+            fast_ratio: 0.6,
+            slow_ratio: 1.6,
+            min_samples: 5,
+        }
+    }
+}
+
+impl ResponseGradingModel {
+    /// Number of past answers worth fetching for a baseline
+    pub const BASELINE_WINDOW: u32 = 50;
+
+    /// Final review grade for an answer
+    pub fn grade(
+        &self,
+        evidence: &ResponseEvidence,
+        baseline: Option<&ResponseBaseline>,
+    ) -> ReviewGrade {
+        if evidence.base_grade == ReviewGrade::Again {
+            return ReviewGrade::Again;
+        }
+
+        let mut level = evidence.base_grade as i32;
+
+        if let Some(baseline) = baseline.filter(|b| b.samples >= self.min_samples) {
+            let ratio = evidence.duration_ms as f64 / baseline.median_ms;
+            if ratio <= self.fast_ratio && evidence.hint_level.is_none() {
+                level += 1;
+            } else if ratio >= self.slow_ratio {
+                level -= 1;
+            }
+        }
+
+        // A correct answer is never graded as a lapse on its own
+        let mut level = level.clamp(ReviewGrade::Hard as i32, ReviewGrade::Easy as i32);
+        if let Some(hint) = evidence.hint_level {
+            level = level.min(hint.max_grade() as i32);
+        }
+        ReviewGrade::from(level as u8)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn baseline(median_ms: f64) -> ResponseBaseline {
+        ResponseBaseline {
+            median_ms,
+            samples: 10,
+        }
+    }
+
+    fn evidence(
+        base_grade: ReviewGrade,
+        duration_ms: u64,
+        hint_level: Option<HintLevel>,
+    ) -> ResponseEvidence {
+        ResponseEvidence {
+            base_grade,
+            duration_ms,
+            hint_level,
+        }
+    }
+
+    #[test]
+    fn test_baseline_uses_median() {
+        let b = ResponseBaseline::from_durations(&[1000, 9000, 2000, 0, 3000]).unwrap();
+        assert_eq!(b.median_ms, 2500.0);
+        assert_eq!(b.samples, 4);
+        assert!(ResponseBaseline::from_durations(&[]).is_none());
+    }
+
+    #[test]
+    fn test_fast_correct_answer_is_easy() {
+        let model = ResponseGradingModel::default();
+        let grade = model.grade(
+            &evidence(ReviewGrade::Good, 1000, None),
+            Some(&baseline(3000.0)),
+        );
+        assert_eq!(grade, ReviewGrade::Easy);
+    }
+
+    #[test]
+    fn test_slow_correct_answer_is_hard() {
+        let model = ResponseGradingModel::default();
+        let grade = model.grade(
+            &evidence(ReviewGrade::Good, 6000, None),
+            Some(&baseline(3000.0)),
+        );
+        assert_eq!(grade, ReviewGrade::Hard);
+    }
+
+    #[test]
+    fn test_typical_answer_keeps_base_grade() {
+        let model = ResponseGradingModel::default();
+        let grade = model.grade(
+            &evidence(ReviewGrade::Good, 3000, None),
+            Some(&baseline(3000.0)),
+        );
+        assert_eq!(grade, ReviewGrade::Good);
+    }
+
+    #[test]
+    fn test_wrong_answer_stays_again() {
+        let model = ResponseGradingModel::default();
+        let grade = model.grade(
+            &evidence(ReviewGrade::Again, 500, None),
+            Some(&baseline(3000.0)),
+        );
+        assert_eq!(grade, ReviewGrade::Again);
+    }
+
+    #[test]
+    fn test_hint_level_caps_grade() {
+        let model = ResponseGradingModel::default();
+        // Fast but hinted: no speed bonus
+        let hinted = model.grade(
+            &evidence(ReviewGrade::Good, 1000, Some(HintLevel::FirstLetter)),
+            Some(&baseline(3000.0)),
+        );
+        assert_eq!(hinted, ReviewGrade::Good);
+
+        let translated = model.grade(
+            &evidence(ReviewGrade::Easy, 3000, Some(HintLevel::Translation)),
+            None,
+        );
+        assert_eq!(translated, ReviewGrade::Hard);
+
+        let revealed = model.grade(
+            &evidence(ReviewGrade::Easy, 3000, Some(HintLevel::PartialReveal)),
+            None,
+        );
+        assert_eq!(revealed, ReviewGrade::Again);
+    }
+
+    #[test]
+    fn test_small_baseline_is_ignored() {
+        let model = ResponseGradingModel::default();
+        let thin = ResponseBaseline {
+            median_ms: 3000.0,
+            samples: 2,
+        };
+        let grade = model.grade(&evidence(ReviewGrade::Good, 500, None), Some(&thin));
+        assert_eq!(grade, ReviewGrade::Good);
+    }
+}
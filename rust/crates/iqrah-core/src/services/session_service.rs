@@ -1,4 +1,5 @@
-use crate::domain::{node_id, KnowledgeAxis, KnowledgeNode, MemoryState, NodeType};
+use crate::domain::{node_id, KnowledgeAxis, KnowledgeNode, MemoryState, NodeStatus, NodeType};
+use crate::scheduler_v2::is_node_inactive;
 use crate::{ContentRepository, Node, UserRepository};
 use anyhow::Result;
 use chrono::{DateTime, Utc};
@@ -123,6 +124,7 @@ impl SessionService {
         };
 
         let goal_scope = self.resolve_goal_scope(goal_id).await?;
+        let inactive_ids = self.user_repo.get_inactive_node_ids(user_id, now).await?;
 
         let due_states = self
             .user_repo
@@ -144,6 +146,10 @@ impl SessionService {
         let mut candidates: HashMap<i64, ScoredItem> = HashMap::new();
 
         for state in best_by_node.into_values() {
+            if is_node_inactive(state.node_id, &inactive_ids) {
+                continue;
+            }
+
             let node = match self.content_repo.get_node(state.node_id).await? {
                 Some(node) => node,
                 None => continue,
@@ -212,7 +218,10 @@ impl SessionService {
                         break;
                     }
 
-                    if candidates.contains_key(&node.id) || !goal_scope.matches(&node) {
+                    if candidates.contains_key(&node.id)
+                        || !goal_scope.matches(&node)
+                        || is_node_inactive(node.id, &inactive_ids)
+                    {
                         continue;
                     }
 
@@ -435,6 +444,66 @@ impl SessionService {
         Ok(None)
     }
 
+    /// Set the scheduling status (active, suspended, buried) for a set of nodes
+    pub async fn set_node_status(
+        &self,
+        user_id: &str,
+        node_ids: &[i64],
+        status: NodeStatus,
+    ) -> Result<()> {
+        self.user_repo
+            .set_node_status(user_id, node_ids, status)
+            .await
+    }
+
+    /// Get the scheduling status of a single node
+    pub async fn get_node_status(&self, user_id: &str, node_id: i64) -> Result<NodeStatus> {
+        self.user_repo.get_node_status(user_id, node_id).await
+    }
+
+    /// Reset FSRS/energy state for a set of nodes, keeping the review log.
+    ///
+    /// Knowledge-axis variants of verse and word-instance nodes are reset too,
+    /// since their memory states are tracked under separate IDs.
+    pub async fn reset_nodes(&self, user_id: &str, node_ids: &[i64]) -> Result<u64> {
+        let mut targets: Vec<i64> = Vec::with_capacity(node_ids.len());
+        for &id in node_ids {
+            targets.push(id);
+            if node_id::decode_verse(id).is_some() || node_id::decode_word_instance(id).is_some() {
+                targets.extend(
+                    ALL_AXES
+                        .iter()
+                        .map(|&axis| node_id::encode_knowledge(id, axis)),
+                );
+            }
+        }
+        self.user_repo.reset_memory_states(user_id, &targets).await
+    }
+
+    /// Resolve every schedulable node belonging to a chapter
+    /// (the chapter itself, its verses and their word instances).
+    pub async fn get_chapter_node_ids(&self, chapter_number: u8) -> Result<Vec<i64>> {
+        let mut ids = vec![node_id::encode_chapter(chapter_number)];
+        let verses = self
+            .content_repo
+            .get_verses_for_chapter(chapter_number as i32)
+            .await?;
+        for verse in verses {
+            let verse_number = verse.verse_number as u16;
+            ids.push(node_id::encode_verse(chapter_number, verse_number));
+            let words = self.content_repo.get_words_for_verse(&verse.key).await?;
+            ids.extend(words.iter().map(|word| {
+                node_id::encode_word_instance(chapter_number, verse_number, word.position as u8)
+            }));
+        }
+        Ok(ids)
+    }
+
+    /// Resolve the nodes assigned to a goal
+    pub async fn get_goal_node_ids(&self, goal_id: &str) -> Result<Vec<i64>> {
+        self.content_repo.get_nodes_for_goal(goal_id).await
+    }
+
     /// Get session state (for resuming)
     pub async fn get_session_state(&self) -> Result<Vec<i64>> {
         self.user_repo.get_session_state().await
@@ -475,6 +544,15 @@ impl SessionService {
     }
 }
 
+const ALL_AXES: [KnowledgeAxis; 6] = [
+    KnowledgeAxis::Memorization,
+    KnowledgeAxis::Translation,
+    KnowledgeAxis::Tafsir,
+    KnowledgeAxis::Tajweed,
+    KnowledgeAxis::ContextualMemorization,
+    KnowledgeAxis::Meaning,
+];
+
 #[derive(Debug, Default)]
 struct GoalScope {
     allowed_node_ids: Option<HashSet<i64>>,
@@ -705,8 +783,19 @@ mod tests {
 
     /// Helper to create a mock UserRepository with configurable due states
     fn create_user_mock_with_due_states(states: Vec<MemoryState>) -> MockUserRepository {
+        create_user_mock_with_inactive(states, HashSet::new())
+    }
+
+    /// Helper to create a mock UserRepository with due states and suspended/buried nodes
+    fn create_user_mock_with_inactive(
+        states: Vec<MemoryState>,
+        inactive_ids: HashSet<i64>,
+    ) -> MockUserRepository {
         let mut mock = MockUserRepository::new();
 
+        mock.expect_get_inactive_node_ids()
+            .returning(move |_, _| Ok(inactive_ids.clone()));
+
         // Clone states for get_due_states
         let states_clone = states.clone();
         mock.expect_get_due_states()
@@ -971,6 +1060,43 @@ mod tests {
         assert_eq!(items.len(), 1, "Should respect limit parameter");
     }
 
+    #[tokio::test]
+    async fn test_excludes_suspended_nodes() {
+        // Arrange
+        let content_repo = Arc::new(create_content_mock());
+        let now = Utc::now();
+
+        let states: Vec<MemoryState> = [1, 2]
+            .into_iter()
+            .map(|node_id| MemoryState {
+                user_id: "user1".to_string(),
+                node_id,
+                stability: 10.0,
+                difficulty: 5.0,
+                energy: 0.3,
+                last_reviewed: now,
+                due_at: now,
+                review_count: 3,
+            })
+            .collect();
+
+        let user_repo = Arc::new(create_user_mock_with_inactive(
+            states,
+            [2].into_iter().collect(),
+        ));
+        let service = SessionService::new(content_repo, user_repo);
+
+        // Act
+        let items = service
+            .get_due_items("user1", now, 10, false, None)
+            .await
+            .unwrap();
+
+        // Assert
+        let ids: Vec<i64> = items.iter().map(|item| item.node.id).collect();
+        assert_eq!(ids, vec![1], "Suspended node 2 must not be scheduled");
+    }
+
     #[tokio::test]
     async fn test_session_state_management() {
         // Arrange
@@ -3,7 +3,7 @@
 //! Provides pre-built test data and mock setup helpers to avoid duplication
 //! across test files.
 
-use crate::domain::{Chapter, MorphologySegment, Root, Verse, Word};
+use crate::domain::{Chapter, MorphologyFeatures, MorphologySegment, Root, Verse, Word};
 use crate::ports::content_repository::MockContentRepository;
 use mockall::predicate::*;
 use std::collections::HashMap;
@@ -215,6 +215,7 @@ pub fn create_morphology_verse_1_1() -> HashMap<i64, Vec<MorphologySegment>> {
             lemma_id: Some("اسم".to_string()),
             root_id: Some("س-م-و".to_string()),
             pos_tag: Some("noun".to_string()),
+            features: MorphologyFeatures::from_corpus_features("N|LEM:ٱسْم|ROOT:سمو|M|GEN"),
         }],
     );
 
@@ -228,6 +229,7 @@ pub fn create_morphology_verse_1_1() -> HashMap<i64, Vec<MorphologySegment>> {
             lemma_id: Some("الله".to_string()),
             root_id: Some("ا-ل-ه".to_string()),
             pos_tag: Some("noun".to_string()),
+            features: MorphologyFeatures::from_corpus_features("PN|LEM:ٱللَّه|ROOT:أله|GEN"),
         }],
     );
 
@@ -241,6 +243,7 @@ pub fn create_morphology_verse_1_1() -> HashMap<i64, Vec<MorphologySegment>> {
             lemma_id: Some("رحمن".to_string()),
             root_id: Some("ر-ح-م".to_string()),
             pos_tag: Some("noun".to_string()),
+            features: MorphologyFeatures::from_corpus_features("ADJ|LEM:رَحْمَٰن|ROOT:رحم|MS|GEN"),
         }],
     );
 
@@ -254,6 +257,7 @@ pub fn create_morphology_verse_1_1() -> HashMap<i64, Vec<MorphologySegment>> {
             lemma_id: Some("رحيم".to_string()),
             root_id: Some("ر-ح-م".to_string()),
             pos_tag: Some("noun".to_string()),
+            features: MorphologyFeatures::from_corpus_features("ADJ|LEM:رَحِيم|ROOT:رحم|MS|GEN"),
         }],
     );
 
@@ -3,7 +3,15 @@ use iqrah_core::domain::node_id as nid;
 use rusqlite::{Connection, params};
 use std::path::Path;
 
-use crate::data_loader::{MorphologyData, QuranData, load_morphology_data, load_quran_data};
+use iqrah_core::domain::TajweedSpan;
+use iqrah_core::domain::models::MushafLine;
+use iqrah_core::exercises::DefaultValidator;
+use std::collections::{BTreeMap, HashMap};
+
+use crate::data_loader::{
+    MorphologyData, QuranData, assign_verse_pages, load_lemma_glosses, load_morphology_data,
+    load_mushaf_layout, load_quran_data, load_tajweed_annotations, word_instance_id,
+};
 
 pub async fn build(data_dir: &Path, morphology: &Path, output_db: &Path) -> Result<()> {
     // 1. Initialize DB Schema using iqrah-storage
@@ -31,11 +39,34 @@ pub async fn build(data_dir: &Path, morphology: &Path, output_db: &Path) -> Resu
 
     // 3. Load Data
     println!("Loading Quran data...");
-    let quran_data = load_quran_data(data_dir)?;
+    let mut quran_data = load_quran_data(data_dir)?;
     let morphology_data = load_morphology_data(morphology)?;
+    let layout = load_mushaf_layout(data_dir, &quran_data.words)?;
+    if let Some(layout) = &layout {
+        assign_verse_pages(&mut quran_data.verses, layout);
+    }
+    let tajweed = load_tajweed_annotations(data_dir, &quran_data.words)?;
+    let lemma_glosses = load_lemma_glosses(data_dir, &morphology_data)?;
+    if lemma_glosses.is_none() {
+        println!("  No word-by-word English found, lemmas will have no meaning");
+    }
 
     println!("Populating content database...");
-    populate_content(&conn, &quran_data, &morphology_data)?;
+    populate_content(
+        &conn,
+        &quran_data,
+        &morphology_data,
+        &lemma_glosses.unwrap_or_default(),
+    )?;
+    populate_mutashabihat(&conn, &quran_data)?;
+    match &layout {
+        Some(layout) => populate_mushaf_layout(&conn, &quran_data, layout)?,
+        None => println!("  No mushaf layout found, skipping page data"),
+    }
+    match &tajweed {
+        Some(annotations) => populate_tajweed(&conn, annotations)?,
+        None => println!("  No tajweed annotations found, skipping"),
+    }
 
     println!("Content database created at {:?}", output_db);
 
@@ -46,6 +77,7 @@ fn populate_content(
     conn: &Connection,
     quran: &QuranData,
     morphology: &MorphologyData,
+    lemma_glosses: &HashMap<String, String>,
 ) -> Result<()> {
     // Use a transaction for performance
     conn.execute_batch("BEGIN TRANSACTION;")?;
@@ -156,12 +188,16 @@ fn populate_content(
     println!("  Inserted {} words", quran.words.len());
 
     // Insert Morphology (Lemmas and Roots)
-    let mut lemmas: std::collections::HashSet<String> = std::collections::HashSet::new();
+    // Each lemma maps to the root of the segments it appears in, if any
+    let mut lemmas: HashMap<String, Option<String>> = HashMap::new();
     let mut roots: std::collections::HashSet<String> = std::collections::HashSet::new();
 
     for segment in &morphology.segments {
         if let Some(ref lemma) = segment.lemma {
-            lemmas.insert(lemma.clone());
+            let root = lemmas.entry(lemma.clone()).or_default();
+            if root.is_none() {
+                *root = segment.root.clone();
+            }
         }
         if let Some(ref root) = segment.root {
             roots.insert(root.clone());
@@ -181,18 +217,349 @@ fn populate_content(
     println!("  Inserted {} roots", roots.len());
 
     // Insert Lemmas
-    let mut lemma_stmt =
-        conn.prepare("INSERT OR REPLACE INTO lemmas (lemma_id, arabic) VALUES (?1, ?2)")?;
-    for lemma in &lemmas {
+    let mut lemma_stmt = conn.prepare(
+        "INSERT OR REPLACE INTO lemmas (lemma_id, arabic, root_id, description)
+         VALUES (?1, ?2, ?3, ?4)",
+    )?;
+    for (lemma, root) in &lemmas {
         let id = nid::encode_lemma(lemma);
         let ukey = nid::lemma(lemma);
         // Insert into nodes table (node_type = 5 for Lemma)
         let _ = node_stmt.execute(params![id, ukey, 5]);
-        lemma_stmt.execute(params![ukey, lemma])?;
+        lemma_stmt.execute(params![
+            ukey,
+            lemma,
+            root.as_deref().map(nid::root),
+            lemma_glosses.get(lemma),
+        ])?;
+    }
+    println!(
+        "  Inserted {} lemmas ({} with a meaning)",
+        lemmas.len(),
+        lemmas
+            .keys()
+            .filter(|l| lemma_glosses.contains_key(*l))
+            .count()
+    );
+
+    // Insert Morphology Segments with their grammatical features
+    let word_ids: std::collections::HashSet<i64> =
+        quran.words.iter().filter_map(word_instance_id).collect();
+    let mut segment_stmt = conn.prepare(
+        "INSERT INTO morphology_segments (word_id, position, lemma_id, root_id, pos_tag,
+             verb_form, tense, mood, person, number, gender, grammatical_case, state)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)",
+    )?;
+    let mut segment_count = 0;
+    for segment in &morphology.segments {
+        let Some((ch_num, v_num, w_pos, position)) = segment.position() else {
+            continue;
+        };
+        let word_id = nid::encode_word_instance(ch_num, v_num, w_pos);
+        if !word_ids.contains(&word_id) {
+            continue;
+        }
+        let grammar = &segment.grammar;
+        segment_stmt.execute(params![
+            word_id,
+            position,
+            segment.lemma.as_deref().map(nid::lemma),
+            segment.root.as_deref().map(nid::root),
+            segment.pos,
+            grammar.verb_form,
+            grammar.tense,
+            grammar.mood,
+            grammar.person,
+            grammar.number,
+            grammar.gender,
+            grammar.case,
+            grammar.state,
+        ])?;
+        segment_count += 1;
+    }
+    println!("  Inserted {} morphology segments", segment_count);
+
+    conn.execute_batch("COMMIT;")?;
+
+    Ok(())
+}
+
+/// Fewest opening words two verses must share to count as mutashabihat
+///
+/// One word is enough: pairs like 2:58 and 7:161 diverge right after their
+/// first word yet are the classic mutashabihat.
+const MUTASHABIHAT_MIN_SHARED_PREFIX: usize = 1;
+/// Least word overlap (Dice coefficient) for verses to count as mutashabihat
+const MUTASHABIHAT_MIN_SIMILARITY: f64 = 0.5;
+/// Most similar verses kept for a verse
+const MUTASHABIHAT_MAX_PER_VERSE: usize = 20;
+
+/// Insert the similar verses (mutashabihat) of every verse
+///
+/// Verses are similar when they open with the same words and share most of
+/// their words overall. Spellings are compared normalized, so that only the
+/// wording counts. Identical verses (e.g. the refrain of Ar-Rahman) are left
+/// out, since nothing in their wording tells them apart.
+fn populate_mutashabihat(conn: &Connection, quran: &QuranData) -> Result<()> {
+    let mut verse_words: BTreeMap<&str, Vec<(i32, String)>> = BTreeMap::new();
+    for word in &quran.words {
+        verse_words
+            .entry(word.verse_key.as_str())
+            .or_default()
+            .push((
+                word.position,
+                DefaultValidator::normalize_arabic(&word.text_uthmani),
+            ));
+    }
+    let verse_words: Vec<(&str, Vec<String>)> = verse_words
+        .into_iter()
+        .map(|(verse_key, mut words)| {
+            words.sort_by_key(|(position, _)| *position);
+            (verse_key, words.into_iter().map(|(_, text)| text).collect())
+        })
+        .collect();
+
+    // Only verses with the same opening can be similar, so compare within
+    // groups of verses sharing their first words.
+    let mut by_opening: HashMap<&[String], Vec<usize>> = HashMap::new();
+    for (index, (_, words)) in verse_words.iter().enumerate() {
+        if words.len() >= MUTASHABIHAT_MIN_SHARED_PREFIX {
+            by_opening
+                .entry(&words[..MUTASHABIHAT_MIN_SHARED_PREFIX])
+                .or_default()
+                .push(index);
+        }
+    }
+
+    let mut similar: HashMap<&str, Vec<(&str, usize, f64)>> = HashMap::new();
+    for group in by_opening.values() {
+        for (i, &a) in group.iter().enumerate() {
+            for &b in &group[i + 1..] {
+                let (key_a, words_a) = &verse_words[a];
+                let (key_b, words_b) = &verse_words[b];
+                if words_a == words_b {
+                    continue;
+                }
+                let similarity = word_overlap(words_a, words_b);
+                if similarity < MUTASHABIHAT_MIN_SIMILARITY {
+                    continue;
+                }
+                let shared_prefix = words_a
+                    .iter()
+                    .zip(words_b)
+                    .take_while(|(x, y)| x == y)
+                    .count();
+                similar
+                    .entry(key_a)
+                    .or_default()
+                    .push((key_b, shared_prefix, similarity));
+                similar
+                    .entry(key_b)
+                    .or_default()
+                    .push((key_a, shared_prefix, similarity));
+            }
+        }
+    }
+
+    conn.execute_batch("BEGIN TRANSACTION;")?;
+
+    let mut stmt = conn.prepare(
+        "INSERT OR REPLACE INTO mutashabihat (verse_key, similar_verse_key, shared_prefix_words, similarity)
+         VALUES (?1, ?2, ?3, ?4)",
+    )?;
+    let mut pair_count = 0;
+    for (verse_key, mut others) in similar {
+        others.sort_by(|x, y| y.2.total_cmp(&x.2).then_with(|| x.0.cmp(y.0)));
+        others.truncate(MUTASHABIHAT_MAX_PER_VERSE);
+        for (similar_verse_key, shared_prefix, similarity) in others {
+            stmt.execute(params![
+                verse_key,
+                similar_verse_key,
+                shared_prefix as i64,
+                similarity
+            ])?;
+            pair_count += 1;
+        }
     }
-    println!("  Inserted {} lemmas", lemmas.len());
+    println!("  Inserted {} similar verse pairs", pair_count);
 
     conn.execute_batch("COMMIT;")?;
 
     Ok(())
 }
+
+/// Dice coefficient of the words of two verses, counting repeated words
+fn word_overlap(a: &[String], b: &[String]) -> f64 {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for word in a {
+        *counts.entry(word).or_default() += 1;
+    }
+    let mut common = 0;
+    for word in b {
+        if let Some(count) = counts.get_mut(word.as_str()).filter(|c| **c > 0) {
+            *count -= 1;
+            common += 1;
+        }
+    }
+    2.0 * common as f64 / (a.len() + b.len()) as f64
+}
+
+/// Insert the mushaf lines and a memorization goal for every page
+///
+/// A page goal holds the verses that start on the page.
+fn populate_mushaf_layout(
+    conn: &Connection,
+    quran: &QuranData,
+    layout: &[MushafLine],
+) -> Result<()> {
+    conn.execute_batch("BEGIN TRANSACTION;")?;
+
+    let mut line_stmt = conn.prepare(
+        "INSERT OR REPLACE INTO mushaf_lines (page, line_number, line_type, first_word_id, last_word_id)
+         VALUES (?1, ?2, ?3, ?4, ?5)",
+    )?;
+    for line in layout {
+        line_stmt.execute(params![
+            line.page,
+            line.line_number,
+            line.line_type,
+            line.first_word_id,
+            line.last_word_id,
+        ])?;
+    }
+    println!("  Inserted {} mushaf lines", layout.len());
+
+    let mut page_verses: BTreeMap<i32, Vec<i64>> = BTreeMap::new();
+    for verse in quran.verses.iter().filter(|v| v.page > 0) {
+        page_verses
+            .entry(verse.page)
+            .or_default()
+            .push(nid::encode_verse(
+                verse.chapter_number as u8,
+                verse.verse_number as u16,
+            ));
+    }
+
+    let mut goal_stmt = conn.prepare(
+        "INSERT OR REPLACE INTO goals (goal_id, goal_type, goal_group, label, description)
+         VALUES (?1, 'page', 'memorization', ?2, ?3)",
+    )?;
+    let mut node_goal_stmt =
+        conn.prepare("INSERT OR REPLACE INTO node_goals (goal_id, node_id) VALUES (?1, ?2)")?;
+    for (page, verse_ids) in &page_verses {
+        let goal_id = format!("memorization:page-{}", page);
+        goal_stmt.execute(params![
+            goal_id,
+            format!("Memorize page {}", page),
+            format!("Verses starting on mushaf page {}", page),
+        ])?;
+        for verse_id in verse_ids {
+            node_goal_stmt.execute(params![goal_id, verse_id])?;
+        }
+    }
+    println!("  Inserted {} page goals", page_verses.len());
+
+    conn.execute_batch("COMMIT;")?;
+
+    Ok(())
+}
+
+/// Insert the tajweed rule spans of words
+fn populate_tajweed(conn: &Connection, annotations: &[(i64, TajweedSpan)]) -> Result<()> {
+    conn.execute_batch("BEGIN TRANSACTION;")?;
+
+    let mut stmt = conn.prepare(
+        "INSERT OR REPLACE INTO tajweed_annotations (word_id, start_char, end_char, rule)
+         VALUES (?1, ?2, ?3, ?4)",
+    )?;
+    for (word_id, span) in annotations {
+        stmt.execute(params![word_id, span.start, span.end, span.rule.as_str()])?;
+    }
+    println!("  Inserted {} tajweed annotations", annotations.len());
+
+    conn.execute_batch("COMMIT;")?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iqrah_core::domain::models::Word;
+
+    fn quran(verses: &[(&str, &str)]) -> QuranData {
+        let mut words = Vec::new();
+        for (verse_key, text) in verses {
+            for (index, word) in text.split_whitespace().enumerate() {
+                words.push(Word {
+                    id: words.len() as i64 + 1,
+                    verse_key: verse_key.to_string(),
+                    position: index as i32 + 1,
+                    text_uthmani: word.to_string(),
+                    text_simple: None,
+                    transliteration: None,
+                });
+            }
+        }
+        QuranData {
+            chapters: Vec::new(),
+            verses: Vec::new(),
+            words,
+        }
+    }
+
+    fn similar_pairs(conn: &Connection) -> Vec<(String, String, i64)> {
+        let mut stmt = conn
+            .prepare(
+                "SELECT verse_key, similar_verse_key, shared_prefix_words
+                 FROM mutashabihat ORDER BY verse_key, similar_verse_key",
+            )
+            .unwrap();
+        stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))
+            .unwrap()
+            .collect::<rusqlite::Result<_>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_populate_mutashabihat_pairs_near_matches_and_skips_identical_verses() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch("CREATE TABLE verses (verse_key TEXT PRIMARY KEY);")
+            .unwrap();
+        conn.execute_batch(include_str!(
+            "../../iqrah-storage/migrations_content/20250205000001_mutashabihat.sql"
+        ))
+        .unwrap();
+
+        let refrain = "فَبِأَىِّ ءَالَآءِ رَبِّكُمَا تُكَذِّبَانِ";
+        let quran = quran(&[
+            ("1:2", "ٱلْحَمْدُ لِلَّهِ رَبِّ ٱلْعَـٰلَمِينَ"),
+            (
+                "2:58",
+                "وَإِذْ قُلْنَا ٱدْخُلُوا۟ هَـٰذِهِ ٱلْقَرْيَةَ فَكُلُوا۟ مِنْهَا حَيْثُ شِئْتُمْ رَغَدًا وَٱدْخُلُوا۟ ٱلْبَابَ سُجَّدًا وَقُولُوا۟ حِطَّةٌ نَّغْفِرْ لَكُمْ خَطَـٰيَـٰكُمْ وَسَنَزِيدُ ٱلْمُحْسِنِينَ",
+            ),
+            ("55:13", refrain),
+            ("55:16", refrain),
+            (
+                "7:161",
+                "وَإِذْ قِيلَ لَهُمُ ٱسْكُنُوا۟ هَـٰذِهِ ٱلْقَرْيَةَ وَكُلُوا۟ مِنْهَا حَيْثُ شِئْتُمْ وَقُولُوا۟ حِطَّةٌ وَٱدْخُلُوا۟ ٱلْبَابَ سُجَّدًا نَّغْفِرْ لَكُمْ خَطِيٓـَٔـٰتِكُمْ سَنَزِيدُ ٱلْمُحْسِنِينَ",
+            ),
+        ]);
+
+        for verse_key in ["1:2", "2:58", "55:13", "55:16", "7:161"] {
+            conn.execute("INSERT INTO verses VALUES (?1)", [verse_key])
+                .unwrap();
+        }
+        populate_mutashabihat(&conn, &quran).unwrap();
+
+        // 2:58 and 7:161 share only their first word, and the refrain copies
+        // are identical, so only the former pair is kept.
+        assert_eq!(
+            similar_pairs(&conn),
+            vec![
+                ("2:58".to_string(), "7:161".to_string(), 1),
+                ("7:161".to_string(), "2:58".to_string(), 1),
+            ]
+        );
+    }
+}
@@ -1,5 +1,8 @@
 use anyhow::{Context, Result};
-use iqrah_core::domain::models::{Chapter, Verse, Word};
+use iqrah_core::domain::models::{Chapter, MushafLine, Verse, Word};
+use iqrah_core::domain::morphology::MorphologyFeatures;
+use iqrah_core::domain::node_id as nid;
+use iqrah_core::domain::{TajweedSpan, parse_tajweed_markup};
 use serde::Deserialize;
 use std::collections::HashMap;
 use std::fs::File;
@@ -63,7 +66,7 @@ pub fn load_quran_data(data_dir: &Path) -> Result<QuranData> {
             id: 0,
             verse_key: verse_key.clone(),
             position,
-            text_uthmani: word_json.text_uthmani,
+            text_uthmani: word_json.text,
             text_simple: None,
             transliteration: None,
         };
@@ -111,6 +114,170 @@ pub fn load_quran_data(data_dir: &Path) -> Result<QuranData> {
     })
 }
 
+/// Word-instance node ID of a word
+pub fn word_instance_id(word: &Word) -> Option<i64> {
+    let (ch_num, v_num) = word.verse_key.split_once(':')?;
+    Some(nid::encode_word_instance(
+        ch_num.parse().ok()?,
+        v_num.parse().ok()?,
+        word.position as u8,
+    ))
+}
+
+/// Load the 15-line Madani mushaf layout, if the data directory has one
+///
+/// The layout file lists every line of every page. Its word ranges use QUL
+/// word IDs, which number the words of the Quran in mushaf order starting
+/// at 1; they are resolved to word-instance node IDs against `words`.
+pub fn load_mushaf_layout(data_dir: &Path, words: &[Word]) -> Result<Option<Vec<MushafLine>>> {
+    let layout_path = data_dir.join("structural-metadata/mushaf-layout-15-lines.json");
+    if !layout_path.exists() {
+        return Ok(None);
+    }
+    let layout: Vec<MushafLineJson> = load_json(&layout_path)?;
+
+    let mut word_ids: Vec<i64> = words.iter().filter_map(word_instance_id).collect();
+    word_ids.sort_unstable();
+    let resolve = |qul_id: Option<i64>| -> Result<Option<i64>> {
+        qul_id
+            .map(|id| {
+                usize::try_from(id - 1)
+                    .ok()
+                    .and_then(|index| word_ids.get(index).copied())
+                    .with_context(|| format!("Layout word {} is out of range", id))
+            })
+            .transpose()
+    };
+
+    let mut lines = Vec::with_capacity(layout.len());
+    for line in layout {
+        lines.push(MushafLine {
+            page: line.page_number,
+            line_number: line.line_number,
+            line_type: line.line_type,
+            first_word_id: resolve(line.first_word_id)?,
+            last_word_id: resolve(line.last_word_id)?,
+        });
+    }
+    lines.sort_by_key(|l| (l.page, l.line_number));
+    Ok(Some(lines))
+}
+
+/// Load tajweed rule spans per word-instance node ID, if the data directory
+/// has QUL's tajweed-tagged word text
+///
+/// The file has the same shape as `uthmani.json`. Words whose text with the
+/// tags removed differs from their Uthmani text are skipped, since their
+/// spans would not line up.
+pub fn load_tajweed_annotations(
+    data_dir: &Path,
+    words: &[Word],
+) -> Result<Option<Vec<(i64, TajweedSpan)>>> {
+    let tajweed_path = data_dir.join("text/wbw/uthmani-tajweed.json");
+    if !tajweed_path.exists() {
+        return Ok(None);
+    }
+    let tagged: HashMap<String, WordJson> = load_json(&tajweed_path)?;
+
+    let mut annotations = Vec::new();
+    let mut skipped = 0;
+    for word in words {
+        let key = format!("{}:{}", word.verse_key, word.position);
+        let (Some(tagged), Some(word_id)) = (tagged.get(&key), word_instance_id(word)) else {
+            continue;
+        };
+        let (text, spans) = parse_tajweed_markup(&tagged.text);
+        if text != word.text_uthmani {
+            skipped += 1;
+            continue;
+        }
+        annotations.extend(spans.into_iter().map(|span| (word_id, span)));
+    }
+    if skipped > 0 {
+        println!(
+            "  Skipped tajweed annotations of {} words not matching the Uthmani text",
+            skipped
+        );
+    }
+    annotations.sort_by_key(|(word_id, span)| (*word_id, span.start, span.end));
+    Ok(Some(annotations))
+}
+
+/// Load an English gloss per lemma, if the data directory has QUL's English
+/// word-by-word translation
+///
+/// The file has the same shape as `uthmani.json`. A lemma's gloss is the
+/// translation given most often to the words whose stem has that lemma;
+/// prefixes and suffixes are skipped, since the translation covers the whole
+/// word.
+pub fn load_lemma_glosses(
+    data_dir: &Path,
+    morphology: &MorphologyData,
+) -> Result<Option<HashMap<String, String>>> {
+    let english_path = data_dir.join("text/wbw/english.json");
+    if !english_path.exists() {
+        return Ok(None);
+    }
+    let translations: HashMap<String, WordJson> = load_json(&english_path)?;
+
+    let mut counts: HashMap<&str, HashMap<&str, usize>> = HashMap::new();
+    for segment in &morphology.segments {
+        let (Some(lemma), Some((chapter, verse, word, _))) = (&segment.lemma, segment.position())
+        else {
+            continue;
+        };
+        if segment
+            .features
+            .split('|')
+            .any(|f| f == "PREF" || f == "SUFF")
+        {
+            continue;
+        }
+        let Some(translation) = translations.get(&format!("{}:{}:{}", chapter, verse, word)) else {
+            continue;
+        };
+        let gloss = translation.text.trim();
+        if !gloss.is_empty() {
+            *counts.entry(lemma).or_default().entry(gloss).or_default() += 1;
+        }
+    }
+
+    Ok(Some(
+        counts
+            .into_iter()
+            .filter_map(|(lemma, glosses)| {
+                // Most frequent gloss; ties go to the alphabetically first
+                let (gloss, _) = glosses
+                    .into_iter()
+                    .max_by(|a, b| a.1.cmp(&b.1).then_with(|| b.0.cmp(a.0)))?;
+                Some((lemma.to_string(), gloss.to_string()))
+            })
+            .collect(),
+    ))
+}
+
+/// Set each verse's page to the page its first word is on
+pub fn assign_verse_pages(verses: &mut [Verse], layout: &[MushafLine]) {
+    let mut ayah_lines: Vec<&MushafLine> = layout
+        .iter()
+        .filter(|l| l.first_word_id.is_some())
+        .collect();
+    ayah_lines.sort_by_key(|l| l.first_word_id);
+
+    for verse in verses {
+        let first_word =
+            nid::encode_word_instance(verse.chapter_number as u8, verse.verse_number as u16, 1);
+        let index = ayah_lines.partition_point(|l| l.first_word_id <= Some(first_word));
+        if let Some(line) = index
+            .checked_sub(1)
+            .map(|i| ayah_lines[i])
+            .filter(|l| l.contains_word(first_word))
+        {
+            verse.page = line.page;
+        }
+    }
+}
+
 fn load_json<T: for<'a> Deserialize<'a>>(path: &Path) -> Result<T> {
     let file = File::open(path).with_context(|| format!("Failed to open {:?}", path))?;
     let reader = BufReader::new(file);
@@ -120,8 +287,16 @@ fn load_json<T: for<'a> Deserialize<'a>>(path: &Path) -> Result<T> {
 // JSON mapping structs
 #[derive(Deserialize)]
 struct WordJson {
-    #[serde(rename = "text")]
-    text_uthmani: String,
+    text: String,
+}
+
+#[derive(Deserialize)]
+struct MushafLineJson {
+    page_number: i32,
+    line_number: i32,
+    line_type: String,
+    first_word_id: Option<i64>,
+    last_word_id: Option<i64>,
 }
 
 #[derive(Deserialize)]
@@ -162,6 +337,18 @@ pub struct MorphologySegment {
     pub lemma: Option<String>,
     #[serde(skip)]
     pub pos: Option<String>,
+    #[serde(skip)]
+    pub grammar: MorphologyFeatures,
+}
+
+impl MorphologySegment {
+    /// (chapter, verse, word, segment) from a location such as "(1:1:1:1)"
+    pub fn position(&self) -> Option<(u8, u16, u8, i32)> {
+        let location = self.location.trim_matches(|c| c == '(' || c == ')');
+        let mut parts = location.split(':').map(str::parse::<i32>);
+        let mut next = || parts.next()?.ok();
+        Some((next()? as u8, next()? as u16, next()? as u8, next()?))
+    }
 }
 
 pub fn load_morphology_data(path: &Path) -> Result<MorphologyData> {
@@ -172,7 +359,7 @@ pub fn load_morphology_data(path: &Path) -> Result<MorphologyData> {
     for result in rdr.deserialize() {
         let mut segment: MorphologySegment = result?;
 
-        // Parse features to extract root and lemma
+        // Parse features to extract root, lemma and grammatical features
         // FEATURES: "P|PREF|LEM:ب" or "N|LEM:ٱِسْم|ROOT:smw|M|GEN"
         for feature in segment.features.split('|') {
             if feature.starts_with("ROOT:") {
@@ -182,6 +369,7 @@ pub fn load_morphology_data(path: &Path) -> Result<MorphologyData> {
             }
         }
         segment.pos = Some(segment.tag.clone());
+        segment.grammar = MorphologyFeatures::from_corpus_features(&segment.features);
 
         segments.push(segment);
     }
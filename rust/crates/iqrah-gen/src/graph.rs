@@ -1,4 +1,4 @@
-use crate::data_loader::{load_morphology_data, load_quran_data};
+use crate::data_loader::{load_morphology_data, load_quran_data, load_tajweed_annotations};
 use anyhow::Result;
 
 // use indicatif::ProgressBar;
@@ -7,7 +7,7 @@ use iqrah_core::domain::node_id as nid;
 use petgraph::graph::{DiGraph, NodeIndex};
 use petgraph::visit::EdgeRef;
 // use rayon::prelude::*;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
 // use std::sync::Mutex;
 
@@ -52,6 +52,18 @@ pub fn build(
     );
     println!("Loaded {} morphology segments", morphology.segments.len());
 
+    // Verses with annotated tajweed rules get tajweed knowledge nodes
+    let tajweed_verses: HashSet<i64> = load_tajweed_annotations(data_dir, &quran.words)?
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|&(word_id, _)| nid::decode_word_instance(word_id))
+        .map(|(ch, v, _)| nid::encode_verse(ch, v))
+        .collect();
+    println!(
+        "Loaded tajweed annotations for {} verses",
+        tajweed_verses.len()
+    );
+
     let mut graph = Graph::new();
     let mut node_map: HashMap<i64, NodeIndex> = HashMap::new();
 
@@ -188,7 +200,7 @@ pub fn build(
     );
 
     // 4. Build Knowledge Graph
-    crate::knowledge::build_knowledge_edges(&mut graph, &mut node_map);
+    crate::knowledge::build_knowledge_edges(&mut graph, &mut node_map, &tajweed_verses);
 
     println!(
         "Knowledge graph built: {} nodes, {} edges",
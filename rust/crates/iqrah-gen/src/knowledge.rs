@@ -2,10 +2,18 @@ use crate::graph::{EdgeData, EdgeType, Graph, NodeData};
 use iqrah_core::domain::models::{KnowledgeAxis, NodeType};
 use iqrah_core::domain::node_id as nid;
 use petgraph::graph::NodeIndex;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
+/// Create knowledge nodes for content nodes and link them
+///
+/// Tajweed knowledge nodes are only created for `tajweed_verses`, the verses
+/// with annotated tajweed rules.
 #[allow(clippy::collapsible_if)]
-pub fn build_knowledge_edges(graph: &mut Graph, node_map: &mut HashMap<i64, NodeIndex>) {
+pub fn build_knowledge_edges(
+    graph: &mut Graph,
+    node_map: &mut HashMap<i64, NodeIndex>,
+    tajweed_verses: &HashSet<i64>,
+) {
     println!("Building knowledge edges...");
 
     // We need to collect nodes by type for parallel processing
@@ -54,6 +62,12 @@ pub fn build_knowledge_edges(graph: &mut Graph, node_map: &mut HashMap<i64, Node
         knowledge_nodes.push((k_id, nid::to_ukey(k_id).unwrap(), NodeType::Knowledge));
     }
 
+    // Tajweed Axis
+    for &(_idx, id) in verses.iter().filter(|(_, id)| tajweed_verses.contains(id)) {
+        let k_id = nid::encode_knowledge(id, KnowledgeAxis::Tajweed);
+        knowledge_nodes.push((k_id, nid::to_ukey(k_id).unwrap(), NodeType::Knowledge));
+    }
+
     // Add nodes to graph
     for (id, ukey, ntype) in knowledge_nodes {
         node_map.entry(id).or_insert_with(|| {
@@ -219,4 +233,21 @@ pub fn build_knowledge_edges(graph: &mut Graph, node_map: &mut HashMap<i64, Node
             );
         }
     }
+
+    // Cross-Axis: Tajweed -> Memorization
+    for &(_idx, id) in &verses {
+        if let (Some(t_idx), Some(m_idx)) = (
+            get_k_idx(id, KnowledgeAxis::Tajweed, node_map),
+            get_k_idx(id, KnowledgeAxis::Memorization, node_map),
+        ) {
+            graph.add_edge(
+                t_idx,
+                m_idx,
+                EdgeData {
+                    edge_type: EdgeType::Knowledge,
+                    weight: 0.5,
+                },
+            );
+        }
+    }
 }
@@ -6,12 +6,15 @@
 use anyhow::Result;
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
-use iqrah_core::domain::{MemoryState, PropagationEvent, Session, SessionItem, SessionSummary};
+use iqrah_core::domain::{
+    ExerciseCheckpoint, MemoryState, NodeStatus, PropagationEvent, Session, SessionItem,
+    SessionSummary,
+};
 use iqrah_core::ports::UserRepository;
 use iqrah_core::scheduler_v2::bandit::BanditArmState;
 use iqrah_core::scheduler_v2::profiles::ProfileName;
 use iqrah_core::scheduler_v2::MemoryBasics;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::RwLock;
 
 /// In-memory user repository for simulation.
@@ -42,6 +45,12 @@ pub struct InMemoryUserRepository {
 
     /// Propagation events log (optional, for debugging)
     propagation_log: RwLock<Vec<PropagationEvent>>,
+
+    /// Non-active node statuses indexed by (user_id, node_id)
+    node_statuses: RwLock<HashMap<(String, i64), NodeStatus>>,
+
+    /// Stateful exercise checkpoints indexed by (session_id, position)
+    exercise_checkpoints: RwLock<HashMap<(String, i32), ExerciseCheckpoint>>,
 }
 
 impl InMemoryUserRepository {
@@ -56,6 +65,8 @@ impl InMemoryUserRepository {
             stats: RwLock::new(HashMap::new()),
             settings: RwLock::new(HashMap::new()),
             propagation_log: RwLock::new(Vec::new()),
+            node_statuses: RwLock::new(HashMap::new()),
+            exercise_checkpoints: RwLock::new(HashMap::new()),
         }
     }
 
@@ -400,6 +411,87 @@ impl UserRepository for InMemoryUserRepository {
         Ok(())
     }
 
+    async fn set_node_status(
+        &self,
+        user_id: &str,
+        node_ids: &[i64],
+        status: NodeStatus,
+    ) -> Result<()> {
+        let mut statuses = self.node_statuses.write().unwrap();
+        for &node_id in node_ids {
+            let key = (user_id.to_string(), node_id);
+            if status == NodeStatus::Active {
+                statuses.remove(&key);
+            } else {
+                statuses.insert(key, status);
+            }
+        }
+        Ok(())
+    }
+
+    async fn get_node_status(&self, user_id: &str, node_id: i64) -> Result<NodeStatus> {
+        let statuses = self.node_statuses.read().unwrap();
+        Ok(statuses
+            .get(&(user_id.to_string(), node_id))
+            .copied()
+            .unwrap_or(NodeStatus::Active))
+    }
+
+    async fn get_inactive_node_ids(
+        &self,
+        user_id: &str,
+        now: DateTime<Utc>,
+    ) -> Result<HashSet<i64>> {
+        let statuses = self.node_statuses.read().unwrap();
+        Ok(statuses
+            .iter()
+            .filter(|((uid, _), status)| uid == user_id && !status.is_active_at(now))
+            .map(|((_, node_id), _)| *node_id)
+            .collect())
+    }
+
+    async fn reset_memory_states(&self, user_id: &str, node_ids: &[i64]) -> Result<u64> {
+        let mut states = self.memory_states.write().unwrap();
+        let removed = node_ids
+            .iter()
+            .filter(|&&node_id| states.remove(&(user_id.to_string(), node_id)).is_some())
+            .count();
+        Ok(removed as u64)
+    }
+
+    async fn save_exercise_checkpoint(&self, checkpoint: &ExerciseCheckpoint) -> Result<()> {
+        let mut checkpoints = self.exercise_checkpoints.write().unwrap();
+        checkpoints.insert(
+            (checkpoint.session_id.clone(), checkpoint.position),
+            checkpoint.clone(),
+        );
+        Ok(())
+    }
+
+    async fn get_exercise_checkpoint(
+        &self,
+        session_id: &str,
+        position: i32,
+    ) -> Result<Option<ExerciseCheckpoint>> {
+        let checkpoints = self.exercise_checkpoints.read().unwrap();
+        Ok(checkpoints
+            .get(&(session_id.to_string(), position))
+            .cloned())
+    }
+
+    async fn delete_exercise_checkpoint(&self, session_id: &str, position: i32) -> Result<()> {
+        let mut checkpoints = self.exercise_checkpoints.write().unwrap();
+        checkpoints.remove(&(session_id.to_string(), position));
+        Ok(())
+    }
+
+    async fn delete_exercise_checkpoints_before(&self, cutoff: DateTime<Utc>) -> Result<u64> {
+        let mut checkpoints = self.exercise_checkpoints.write().unwrap();
+        let before = checkpoints.len();
+        checkpoints.retain(|_, checkpoint| checkpoint.updated_at >= cutoff);
+        Ok((before - checkpoints.len()) as u64)
+    }
+
     async fn get_parent_energies(
         &self,
         user_id: &str,
@@ -5,7 +5,7 @@ use axum::{
     routing::{get, post},
     Router,
 };
-use iqrah_core::domain::ReviewGrade;
+use iqrah_core::domain::{ReviewGrade, Script};
 use serde::Deserialize;
 use serde_json::json;
 use std::sync::Arc;
@@ -40,6 +40,14 @@ pub fn create_http_router() -> Router<Arc<AppState>> {
             "/verses/:verse_key/translations/:translator_id",
             get(get_verse_translation),
         )
+        .route(
+            "/users/:user_id/settings/script",
+            get(get_user_preferred_script),
+        )
+        .route(
+            "/users/:user_id/settings/script",
+            post(set_user_preferred_script),
+        )
         // Package management endpoints (specific routes before parameterized routes)
         .route("/packages", get(list_packages))
         .route("/packages/installed", get(list_installed_packages))
@@ -403,6 +411,44 @@ async fn set_user_preferred_translator(
     })))
 }
 
+/// Get the script exercises are shown in
+async fn get_user_preferred_script(
+    State(state): State<Arc<AppState>>,
+    Path(user_id): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    Ok(Json(json!({
+        "user_id": user_id,
+        "preferred_script": state.exercise_service.script(),
+    })))
+}
+
+#[derive(Deserialize)]
+struct SetScriptRequest {
+    script: String,
+}
+
+/// Set the script exercises are shown and checked in
+async fn set_user_preferred_script(
+    State(state): State<Arc<AppState>>,
+    Path(user_id): Path<String>,
+    Json(payload): Json<SetScriptRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let script = Script::from_slug(&payload.script)
+        .ok_or_else(|| AppError::InvalidInput(format!("Unknown script: {}", payload.script)))?;
+
+    state
+        .user_repo
+        .set_setting(Script::SETTING_KEY, script.as_str())
+        .await?;
+    state.exercise_service.set_script(script);
+
+    Ok(Json(json!({
+        "user_id": user_id,
+        "preferred_script": script,
+        "message": "Preference updated successfully",
+    })))
+}
+
 /// Get verse translation for a specific translator
 async fn get_verse_translation(
     State(state): State<Arc<AppState>>,
@@ -1,8 +1,9 @@
 use axum::{routing::get, Router};
 use iqrah_core::{
+    exercises::GradeThresholds,
     ports::{ContentRepository, UserRepository},
     services::{LearningService, SessionService},
-    ExerciseService,
+    ExerciseService, Script,
 };
 use iqrah_storage::{
     create_content_repository, init_content_db, init_user_db, SqliteUserRepository,
@@ -62,36 +63,59 @@ async fn main() -> anyhow::Result<()> {
         Arc::clone(&user_repo),
     ));
 
-    let exercise_service = Arc::new(ExerciseService::new(Arc::clone(&content_repo)));
+    let script = user_repo
+        .get_setting(Script::SETTING_KEY)
+        .await?
+        .and_then(|slug| Script::from_slug(&slug))
+        .unwrap_or_default();
+    let grade_thresholds = user_repo
+        .get_setting(GradeThresholds::SETTING_KEY)
+        .await?
+        .and_then(|json| GradeThresholds::from_setting(&json))
+        .unwrap_or_default();
+    let exercise_service = Arc::new(
+        ExerciseService::new(Arc::clone(&content_repo))
+            .with_script(script)
+            .with_grade_thresholds(grade_thresholds),
+    );
 
     // Initialize semantic grading model
+    // Without it the server still starts: typed answers are graded lexically
     tracing::info!("Initializing semantic grading model...");
 
-    // Get cache directory from environment variable (optional)
-    // For server: can use system default
-    // For mobile: Flutter should set HF_HOME before calling Rust
-    let cache_dir = std::env::var("HF_HOME").ok();
-    if let Some(ref dir) = cache_dir {
-        tracing::info!("Using cache directory from HF_HOME: {}", dir);
+    let model_result = if let Ok(model_dir) = std::env::var("SEMANTIC_MODEL_DIR") {
+        // Bundled/offline model folder: never touches the HuggingFace Hub
+        tracing::info!("Model directory: {}", model_dir);
+        ExerciseService::init_semantic_model_from_dir(&model_dir)
     } else {
-        tracing::info!("No HF_HOME set, will use system default cache location");
-    }
+        // Get cache directory from environment variable (optional)
+        // For server: can use system default
+        // For mobile: Flutter should set HF_HOME before calling Rust
+        let cache_dir = std::env::var("HF_HOME").ok();
+        if let Some(ref dir) = cache_dir {
+            tracing::info!("Using cache directory from HF_HOME: {}", dir);
+        } else {
+            tracing::info!("No HF_HOME set, will use system default cache location");
+        }
+
+        // Get model ID (default to multilingual model)
+        let model_id = std::env::var("SEMANTIC_MODEL_ID")
+            .unwrap_or_else(|_| "minishlab/potion-multilingual-128M".to_string());
+        tracing::info!("Model ID: {}", model_id);
 
-    // Get model ID (default to multilingual model)
-    let model_id = std::env::var("SEMANTIC_MODEL_ID")
-        .unwrap_or_else(|_| "minishlab/potion-multilingual-128M".to_string());
-    tracing::info!("Model ID: {}", model_id);
+        ExerciseService::init_semantic_model(&model_id, cache_dir.as_deref())
+    };
 
-    match ExerciseService::init_semantic_model(&model_id, cache_dir.as_deref()) {
+    match model_result {
         Ok(_) => tracing::info!("✅ Semantic grading model initialized successfully"),
         Err(e) => {
-            tracing::error!("❌ Failed to initialize semantic model: {}", e);
-            tracing::error!("Model ID: {}", model_id);
-            tracing::error!("Troubleshooting:");
-            tracing::error!("  - Check internet connection (for first download)");
-            tracing::error!("  - Set HF_HOME to a writable directory");
-            tracing::error!("  - Set SEMANTIC_MODEL_ID to use a different model");
-            return Err(e);
+            tracing::warn!("⚠️ Failed to initialize semantic model: {}", e);
+            tracing::warn!("Falling back to lexical grading of typed answers");
+            tracing::warn!("Troubleshooting:");
+            tracing::warn!("  - Check internet connection (for first download)");
+            tracing::warn!("  - Set HF_HOME to a writable directory");
+            tracing::warn!("  - Set SEMANTIC_MODEL_ID to use a different model");
+            tracing::warn!("  - Set SEMANTIC_MODEL_DIR to load a local model folder offline");
         }
     }
 
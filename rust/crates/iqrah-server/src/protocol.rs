@@ -1,3 +1,5 @@
+use iqrah_core::exercises::ExerciseData;
+use iqrah_core::HintLevel;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -34,6 +36,10 @@ pub enum Command {
         word_node_id: String,
         recall_time_ms: u32,
     },
+    /// Resume an Echo Recall or Memorization session from its last checkpoint
+    ///
+    /// Answered with `SessionStarted` carrying the saved state.
+    ResumeExercise { session_id: Uuid },
     /// End the current session and save state
     EndSession {
         #[serde(skip_serializing_if = "Option::is_none")]
@@ -55,8 +61,30 @@ pub enum Command {
         #[serde(skip_serializing_if = "Option::is_none")]
         format: Option<String>, // "mcq_ar_to_en", "mcq_en_to_ar", or None for default
     },
+    /// Serve a V2 exercise for a node
+    ///
+    /// Answered with `ExerciseServed`; the exercise is checkpointed under the
+    /// returned session so `CheckAnswer` can validate against it.
+    ServeExercise { node_id: String },
     /// Check answer for an exercise (Phase 4.3)
-    CheckAnswer { node_id: String, answer: String },
+    ///
+    /// `answer` is plain text or a JSON-encoded `AnswerInput`. With
+    /// `session_id` (from `ExerciseServed`), the answer is checked against the
+    /// exercise served for that session; otherwise it is checked as free text
+    /// for the node's knowledge axis. `hint_level` is the most revealing hint
+    /// shown, which caps the suggested grade.
+    CheckAnswer {
+        node_id: String,
+        answer: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        session_id: Option<Uuid>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        hint_level: Option<HintLevel>,
+    },
+    /// Reveal the next rung of an exercise's hint ladder
+    ///
+    /// `step` is the number of hints already shown (0 for the first hint).
+    GetHint { exercise: ExerciseData, step: u32 },
 }
 
 /// Server-to-Client events for WebSocket communication
@@ -96,6 +124,11 @@ pub enum Event {
         #[serde(skip_serializing_if = "Option::is_none")]
         options: Option<Vec<String>>,
     },
+    /// V2 exercise served and checkpointed under `session_id`
+    ExerciseServed {
+        session_id: Uuid,
+        exercise: ExerciseData,
+    },
     /// Answer checked (Phase 4.3)
     AnswerChecked {
         is_correct: bool,
@@ -110,5 +143,21 @@ pub enum Event {
         /// Semantic similarity score (0.0 to 1.0)
         #[serde(skip_serializing_if = "Option::is_none")]
         similarity_score: Option<f32>,
+        /// Feedback message from the validator
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        feedback: Option<String>,
+        /// Suggested review grade (1=Again, 2=Hard, 3=Good, 4=Easy)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        suggested_grade: Option<u8>,
+    },
+    /// A hint from the exercise's progressive hint ladder
+    HintRevealed {
+        step: u32,
+        level: HintLevel,
+        text: String,
+        /// Highest grade still achievable (1=Again, 2=Hard, 3=Good, 4=Easy)
+        max_grade: u8,
+        /// Hints left on the ladder after this one
+        remaining: u32,
     },
 }
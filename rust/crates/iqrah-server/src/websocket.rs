@@ -17,15 +17,21 @@ use crate::{
     AppState,
 };
 use iqrah_core::domain::node_id as nid;
+use iqrah_core::exercises::{
+    expire_checkpoints, load_checkpoint, save_checkpoint, AnswerInput, ExerciseData,
+    ExerciseService,
+};
+use iqrah_core::{AyahJunction, ExerciseCheckpoint, HintLevel};
+
+/// A WebSocket session holds a single exercise, checkpointed at this position
+const CHECKPOINT_POSITION: i32 = 0;
 
 /// Session state for a running exercise
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ExerciseSession {
-    #[allow(dead_code)]
     pub session_id: Uuid,
     pub exercise_type: String,
     pub node_id: String,
-    #[allow(dead_code)]
     pub user_id: String,
     /// Exercise-specific state (word energies for Memorization, etc.)
     pub state: serde_json::Value,
@@ -154,6 +160,9 @@ async fn handle_command(
             )
             .await
         }
+        Command::ResumeExercise { session_id } => {
+            handle_resume_exercise(session_id, app_state, sessions).await
+        }
         Command::EndSession { session_id } => {
             let sid = session_id.or(current_session_id);
             handle_end_session(sid, user_id, app_state, sessions).await
@@ -168,9 +177,16 @@ async fn handle_command(
             axis,
             format,
         } => handle_generate_exercise(&node_id, axis, format, app_state).await,
-        Command::CheckAnswer { node_id, answer } => {
-            handle_check_answer(&node_id, &answer, app_state).await
+        Command::ServeExercise { node_id } => {
+            handle_serve_exercise(user_id, &node_id, app_state).await
         }
+        Command::CheckAnswer {
+            node_id,
+            answer,
+            session_id,
+            hint_level,
+        } => handle_check_answer(&node_id, &answer, session_id, hint_level, app_state).await,
+        Command::GetHint { exercise, step } => handle_get_hint(&exercise, step, app_state).await,
     }
 }
 
@@ -258,6 +274,7 @@ async fn initialize_memorization_state(
     let exercise = MemorizationAyahExercise::new(
         user_id,
         verse_node_id,
+        app_state.exercise_service.script(),
         app_state.content_repo.as_ref(),
         app_state.user_repo.as_ref(),
     )
@@ -305,7 +322,7 @@ async fn handle_update_memorization_word(
     session_id: Option<Uuid>,
     word_node_id: String,
     action: String,
-    _app_state: &AppState,
+    app_state: &AppState,
     sessions: SessionMap,
 ) -> Vec<Event> {
     let sid = match session_id {
@@ -346,22 +363,22 @@ async fn handle_update_memorization_word(
     };
 
     // Update the word's energy in the session state
-    if let Some(words) = session.state["words"].as_array_mut() {
-        for word in words.iter_mut() {
-            if word["node_id"] == word_node_id {
-                let current_energy = word["energy"].as_f64().unwrap_or(0.0);
-                let new_energy = (current_energy + energy_delta).min(1.0); // Cap at 1.0
-                word["energy"] = json!(new_energy);
-
-                return vec![Event::StateUpdated {
-                    new_state: session.state.clone(),
-                }];
-            }
-        }
-    }
+    let word = session.state["words"]
+        .as_array_mut()
+        .and_then(|words| words.iter_mut().find(|w| w["node_id"] == word_node_id));
+    let Some(word) = word else {
+        return vec![Event::Error {
+            message: format!("Word not found in session: {}", word_node_id),
+        }];
+    };
+    let current_energy = word["energy"].as_f64().unwrap_or(0.0);
+    let new_energy = (current_energy + energy_delta).min(1.0); // Cap at 1.0
+    word["energy"] = json!(new_energy);
+
+    save_session_checkpoint(session, app_state).await;
 
-    vec![Event::Error {
-        message: format!("Word not found in session: {}", word_node_id),
+    vec![Event::StateUpdated {
+        new_state: session.state.clone(),
     }]
 }
 
@@ -393,6 +410,15 @@ async fn handle_end_session(
         }
     };
 
+    // The exercise is finished, so it no longer needs resuming
+    if let Err(e) = app_state
+        .user_repo
+        .delete_exercise_checkpoint(&sid.to_string(), CHECKPOINT_POSITION)
+        .await
+    {
+        tracing::error!("Failed to delete checkpoint for {}: {}", sid, e);
+    }
+
     // Save state to database based on exercise type
     if session.exercise_type == "MemorizationAyah" || session.exercise_type == "EchoRecall" {
         // Save word energies to the database
@@ -416,11 +442,19 @@ async fn handle_end_session(
         }
     }
 
+    // Review each ayah junction on its transition node
+    let junctions = if session.exercise_type == "EchoRecall" {
+        review_echo_recall_junctions(&session, user_id, app_state).await
+    } else {
+        Vec::new()
+    };
+
     // Generate summary
     let summary = json!({
         "session_id": session_id,
         "exercise_type": session.exercise_type,
         "node_id": session.node_id,
+        "junctions": junctions,
     });
 
     vec![Event::SessionFinished {
@@ -429,6 +463,51 @@ async fn handle_end_session(
     }]
 }
 
+/// Review the ayah junctions of a finished Echo Recall session
+///
+/// Returns the junctions so they can be reported in the session summary.
+async fn review_echo_recall_junctions(
+    session: &ExerciseSession,
+    user_id: &str,
+    app_state: &AppState,
+) -> Vec<AyahJunction> {
+    use iqrah_core::{EchoRecallExercise, EchoRecallState};
+
+    let state: EchoRecallState = match serde_json::from_value(session.state.clone()) {
+        Ok(s) => s,
+        Err(e) => {
+            tracing::error!("Failed to deserialize state: {}", e);
+            return Vec::new();
+        }
+    };
+    let junctions = EchoRecallExercise::from_state(user_id, Vec::new(), state).finalize_junctions();
+
+    for junction in &junctions {
+        let Some(transition_id) = junction.transition_node_id() else {
+            continue;
+        };
+        if !matches!(
+            app_state.content_repo.node_exists(transition_id).await,
+            Ok(true)
+        ) {
+            continue;
+        }
+        if let Err(e) = app_state
+            .learning_service
+            .process_review(user_id, transition_id, junction.grade())
+            .await
+        {
+            tracing::error!(
+                "Failed to review junction into {}: {}",
+                junction.to_verse,
+                e
+            );
+        }
+    }
+
+    junctions
+}
+
 /// Start an Echo Recall session
 async fn handle_start_echo_recall(
     user_id: &str,
@@ -444,6 +523,7 @@ async fn handle_start_echo_recall(
     let exercise = match EchoRecallExercise::new(
         user_id,
         ayah_node_ids.clone(),
+        app_state.exercise_service.script(),
         app_state.content_repo.as_ref(),
         app_state.user_repo.as_ref(),
     )
@@ -490,7 +570,7 @@ async fn handle_submit_echo_recall(
     word_node_id: String,
     recall_time_ms: u32,
     _user_id: &str,
-    _app_state: &AppState,
+    app_state: &AppState,
     sessions: SessionMap,
 ) -> Vec<Event> {
     use iqrah_core::{EchoRecallExercise, EchoRecallState};
@@ -545,10 +625,93 @@ async fn handle_submit_echo_recall(
 
     // Update session state
     session.state = new_state.clone();
+    save_session_checkpoint(session, app_state).await;
 
     vec![Event::StateUpdated { new_state }]
 }
 
+/// Checkpoint a session after an interaction so it can be resumed with
+/// `ResumeExercise` if the connection drops
+///
+/// Failures are logged and do not fail the interaction.
+async fn save_session_checkpoint(session: &ExerciseSession, app_state: &AppState) {
+    let state = match serde_json::to_string(session) {
+        Ok(state) => state,
+        Err(e) => {
+            tracing::error!("Failed to serialize session {}: {}", session.session_id, e);
+            return;
+        }
+    };
+    // Echo Recall sessions span several ayahs; the first one is recorded
+    let node_id = session
+        .node_id
+        .split(',')
+        .next()
+        .and_then(nid::from_ukey)
+        .unwrap_or_default();
+    let checkpoint = ExerciseCheckpoint {
+        session_id: session.session_id.to_string(),
+        position: CHECKPOINT_POSITION,
+        user_id: session.user_id.clone(),
+        exercise_type: session.exercise_type.clone(),
+        node_id,
+        state,
+        updated_at: chrono::Utc::now(),
+    };
+    if let Err(e) = app_state
+        .user_repo
+        .save_exercise_checkpoint(&checkpoint)
+        .await
+    {
+        tracing::error!("Failed to checkpoint session {}: {}", session.session_id, e);
+    }
+}
+
+/// Resume a checkpointed session (e.g. after a reconnect)
+async fn handle_resume_exercise(
+    session_id: Uuid,
+    app_state: &AppState,
+    sessions: SessionMap,
+) -> Vec<Event> {
+    if let Err(e) = expire_checkpoints(app_state.user_repo.as_ref(), chrono::Utc::now()).await {
+        tracing::error!("Failed to expire checkpoints: {}", e);
+    }
+
+    let checkpoint = match app_state
+        .user_repo
+        .get_exercise_checkpoint(&session_id.to_string(), CHECKPOINT_POSITION)
+        .await
+    {
+        Ok(Some(checkpoint)) => checkpoint,
+        Ok(None) => {
+            return vec![Event::Error {
+                message: format!("No saved progress for session: {}", session_id),
+            }];
+        }
+        Err(e) => {
+            return vec![Event::Error {
+                message: format!("Failed to load saved progress: {}", e),
+            }];
+        }
+    };
+
+    let session: ExerciseSession = match serde_json::from_str(&checkpoint.state) {
+        Ok(s) => s,
+        Err(e) => {
+            return vec![Event::Error {
+                message: format!("Failed to deserialize state: {}", e),
+            }];
+        }
+    };
+    let initial_state = session.state.clone();
+    sessions.write().await.insert(session_id, session);
+
+    vec![Event::SessionStarted {
+        session_id,
+        initial_state,
+    }]
+}
+
 /// Get due items for a session (Phase 4)
 async fn handle_get_due_items(
     user_id: &str,
@@ -689,9 +852,9 @@ async fn handle_generate_exercise(
     }
 }
 
-/// Check answer for an exercise (Phase 4.3)
-async fn handle_check_answer(node_id: &str, answer: &str, app_state: &AppState) -> Vec<Event> {
-    // Generate exercise first (we need it to check the answer)
+/// Serve a V2 exercise for a node, checkpointed under a new session so its
+/// answer is checked against what was served
+async fn handle_serve_exercise(user_id: &str, node_id: &str, app_state: &AppState) -> Vec<Event> {
     let nid_val = match nid::from_ukey(node_id) {
         Some(id) => id,
         None => {
@@ -700,31 +863,131 @@ async fn handle_check_answer(node_id: &str, answer: &str, app_state: &AppState)
             }]
         }
     };
-    let exercise_result = app_state
+
+    let exercise = match app_state
         .exercise_service
-        .generate_exercise(nid_val, node_id)
-        .await;
+        .generate_exercise_v2(nid_val, node_id)
+        .await
+    {
+        Ok(exercise) => exercise,
+        Err(e) => {
+            return vec![Event::Error {
+                message: format!("Failed to generate exercise: {}", e),
+            }]
+        }
+    };
 
-    match exercise_result {
-        Ok(exercise_type) => {
-            let exercise = exercise_type.as_exercise();
-            let response = app_state.exercise_service.check_answer(exercise, answer);
-
-            vec![Event::AnswerChecked {
-                is_correct: response.is_correct,
-                hint: response.hint,
-                correct_answer: response.correct_answer,
-                options: response.options,
-                semantic_grade: response.semantic_grade,
-                similarity_score: response.similarity_score,
+    let session_id = Uuid::new_v4();
+    if let Err(e) = save_checkpoint(
+        app_state.user_repo.as_ref(),
+        &session_id.to_string(),
+        CHECKPOINT_POSITION,
+        user_id,
+        &exercise,
+    )
+    .await
+    {
+        return vec![Event::Error {
+            message: format!("Failed to save served exercise: {}", e),
+        }];
+    }
+
+    vec![Event::ExerciseServed {
+        session_id,
+        exercise,
+    }]
+}
+
+/// Check answer for an exercise (Phase 4.3)
+async fn handle_check_answer(
+    node_id: &str,
+    answer: &str,
+    session_id: Option<Uuid>,
+    hint_level: Option<HintLevel>,
+    app_state: &AppState,
+) -> Vec<Event> {
+    let nid_val = match nid::from_ukey(node_id) {
+        Some(id) => id,
+        None => {
+            return vec![Event::Error {
+                message: format!("Invalid node ID: {}", node_id),
             }]
         }
+    };
+    let exercise = match session_id {
+        Some(sid) => match load_checkpoint::<ExerciseData>(
+            app_state.user_repo.as_ref(),
+            &sid.to_string(),
+            CHECKPOINT_POSITION,
+        )
+        .await
+        {
+            Ok(Some(exercise)) => exercise,
+            Ok(None) => {
+                return vec![Event::Error {
+                    message: format!("No exercise served for session: {}", sid),
+                }]
+            }
+            Err(e) => {
+                return vec![Event::Error {
+                    message: format!("Failed to load served exercise: {}", e),
+                }]
+            }
+        },
+        None => ExerciseService::text_exercise_for(nid_val, node_id),
+    };
+    let answer =
+        serde_json::from_str::<AnswerInput>(answer).unwrap_or_else(|_| AnswerInput::Text {
+            value: answer.to_string(),
+        });
+
+    match app_state
+        .exercise_service
+        .validate_answer(&exercise, &answer, hint_level)
+        .await
+    {
+        Ok(result) => vec![Event::AnswerChecked {
+            is_correct: result.is_correct,
+            hint: None,
+            correct_answer: result.correct_answer,
+            options: None,
+            semantic_grade: result.semantic_grade,
+            similarity_score: result.similarity_score,
+            feedback: result.feedback,
+            suggested_grade: Some(result.suggested_grade as u8),
+        }],
         Err(e) => vec![Event::Error {
             message: format!("Failed to check answer: {}", e),
         }],
     }
 }
 
+/// Reveal the hint at `step` of an exercise's hint ladder
+async fn handle_get_hint(exercise: &ExerciseData, step: u32, app_state: &AppState) -> Vec<Event> {
+    let ladder = match app_state.exercise_service.get_hint_ladder(exercise).await {
+        Ok(ladder) => ladder,
+        Err(e) => {
+            return vec![Event::Error {
+                message: format!("Failed to get hint: {}", e),
+            }]
+        }
+    };
+
+    let remaining = ladder.len().saturating_sub(step as usize + 1) as u32;
+    match ladder.into_iter().nth(step as usize) {
+        Some(hint) => vec![Event::HintRevealed {
+            step,
+            level: hint.level,
+            text: hint.text,
+            max_grade: hint.max_grade as u8,
+            remaining,
+        }],
+        None => vec![Event::Error {
+            message: "No more hints for this exercise".to_string(),
+        }],
+    }
+}
+
 /// Helper to send an event to the client
 async fn send_event(
     sender: &mut futures::stream::SplitSink<WebSocket, Message>,
@@ -0,0 +1,161 @@
+//! Session build benchmark
+//!
+//! Builds page-sized Echo Recall sessions against SQLite and reports the time
+//! and the number of SQL statements per build. Fails when a build issues more
+//! than `MAX_QUERIES_PER_BUILD` statements, so per-word (N+1) lookups show up
+//! as a regression.
+//!
+//! Run with: `cargo bench -p iqrah-storage --bench session_build`
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use iqrah_core::domain::node_id as nid;
+use iqrah_core::exercises::EchoRecallExercise;
+use iqrah_core::Script;
+use iqrah_storage::{
+    create_content_repository, init_test_content_db, init_user_db, SqliteUserRepository,
+};
+use sqlx::SqlitePool;
+use tracing::{Event, Subscriber};
+use tracing_subscriber::layer::{Context, SubscriberExt};
+use tracing_subscriber::{Layer, Registry};
+
+/// Verses on the synthetic page (2:1 to 2:15)
+const PAGE_VERSES: u16 = 15;
+const WORDS_PER_VERSE: u8 = 12;
+const ITERATIONS: u32 = 50;
+/// Words, energies and texts, plus the Uthmani fallback for other scripts
+const MAX_QUERIES_PER_BUILD: usize = 4;
+const USER_ID: &str = "bench_user";
+
+/// Counts the statements sqlx logs under the `sqlx::query` target
+struct QueryCounter(Arc<AtomicUsize>);
+
+impl<S: Subscriber> Layer<S> for QueryCounter {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        if event.metadata().target() == "sqlx::query" {
+            self.0.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+/// Words, Uthmani text for every word, Indopak text for half of them and
+/// memory states for a third
+async fn seed_page(content: &SqlitePool, user: &SqlitePool) -> anyhow::Result<()> {
+    let uthmani: i64 =
+        sqlx::query_scalar("SELECT resource_id FROM script_resources WHERE slug = 'uthmani'")
+            .fetch_one(content)
+            .await?;
+    let indopak: i64 = sqlx::query_scalar(
+        "INSERT INTO script_resources (slug, name, type, direction)
+         VALUES ('indopak', 'Indopak', 1, 'rtl')
+         RETURNING resource_id",
+    )
+    .fetch_one(content)
+    .await?;
+
+    for verse in 1..=PAGE_VERSES {
+        for position in 1..=WORDS_PER_VERSE {
+            let id = nid::encode_word_instance(2, verse, position);
+            sqlx::query("INSERT INTO words (word_id, verse_key, position) VALUES (?, ?, ?)")
+                .bind(id)
+                .bind(format!("2:{}", verse))
+                .bind(position as i32)
+                .execute(content)
+                .await?;
+            sqlx::query("INSERT INTO nodes (id, ukey, node_type) VALUES (?, ?, 4)")
+                .bind(id)
+                .bind(nid::word_instance(2, verse, position))
+                .execute(content)
+                .await?;
+            sqlx::query(
+                "INSERT INTO script_contents (resource_id, node_id, text_content)
+                 VALUES (?, ?, 'كَلِمَةٌ')",
+            )
+            .bind(uthmani)
+            .bind(id)
+            .execute(content)
+            .await?;
+            if position % 2 == 0 {
+                sqlx::query(
+                    "INSERT INTO script_contents (resource_id, node_id, text_content)
+                     VALUES (?, ?, 'کَلِمَۃٌ')",
+                )
+                .bind(indopak)
+                .bind(id)
+                .execute(content)
+                .await?;
+            }
+            if position % 3 == 0 {
+                sqlx::query(
+                    "INSERT INTO user_memory_states (user_id, content_key, energy)
+                     VALUES (?, ?, 0.6)",
+                )
+                .bind(USER_ID)
+                .bind(id)
+                .execute(user)
+                .await?;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let queries = Arc::new(AtomicUsize::new(0));
+    tracing::subscriber::set_global_default(
+        Registry::default().with(QueryCounter(queries.clone())),
+    )?;
+
+    let dir = tempfile::tempdir()?;
+    let content_pool =
+        init_test_content_db(dir.path().join("content.db").to_str().unwrap()).await?;
+    let user_pool = init_user_db(dir.path().join("user.db").to_str().unwrap()).await?;
+    seed_page(&content_pool, &user_pool).await?;
+
+    let content_repo = create_content_repository(content_pool);
+    let user_repo = SqliteUserRepository::new(user_pool);
+    let ayahs: Vec<String> = (1..=PAGE_VERSES)
+        .map(|verse| nid::verse(2, verse))
+        .collect();
+
+    let mut within_bound = true;
+    for script in [Script::Uthmani, Script::Indopak] {
+        // Warm up the pool so connection setup is not counted
+        EchoRecallExercise::new(USER_ID, ayahs.clone(), script, &content_repo, &user_repo).await?;
+
+        queries.store(0, Ordering::Relaxed);
+        let mut elapsed = Duration::ZERO;
+        for _ in 0..ITERATIONS {
+            let start = Instant::now();
+            let exercise =
+                EchoRecallExercise::new(USER_ID, ayahs.clone(), script, &content_repo, &user_repo)
+                    .await?;
+            elapsed += start.elapsed();
+            assert_eq!(
+                exercise.state().words.len(),
+                PAGE_VERSES as usize * WORDS_PER_VERSE as usize
+            );
+        }
+
+        let per_build = queries.load(Ordering::Relaxed) / ITERATIONS as usize;
+        println!(
+            "echo_recall/{:<8} {} words: {:>8.2?} per build, {} queries per build",
+            script.as_str(),
+            PAGE_VERSES as usize * WORDS_PER_VERSE as usize,
+            elapsed / ITERATIONS,
+            per_build
+        );
+        within_bound &= per_build <= MAX_QUERIES_PER_BUILD;
+    }
+
+    anyhow::ensure!(
+        within_bound,
+        "session build exceeded {} queries",
+        MAX_QUERIES_PER_BUILD
+    );
+    Ok(())
+}
@@ -0,0 +1,17 @@
+-- ============================================================================
+-- Mutashabihat (similar verses)
+-- Date: 2025-02-05
+-- ============================================================================
+--
+-- Precomputed verse similarity used by the mutashabihat discrimination
+-- exercise. Pairs are stored in both directions.
+
+CREATE TABLE mutashabihat (
+    verse_key TEXT NOT NULL,
+    similar_verse_key TEXT NOT NULL,
+    shared_prefix_words INTEGER NOT NULL DEFAULT 0,  -- leading words in common
+    similarity REAL NOT NULL,                        -- 0.0 to 1.0
+    PRIMARY KEY (verse_key, similar_verse_key),
+    FOREIGN KEY (verse_key) REFERENCES verses(verse_key) ON DELETE CASCADE,
+    FOREIGN KEY (similar_verse_key) REFERENCES verses(verse_key) ON DELETE CASCADE
+) STRICT, WITHOUT ROWID;
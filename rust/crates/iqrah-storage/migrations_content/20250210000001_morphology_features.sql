@@ -0,0 +1,18 @@
+-- ============================================================================
+-- Morphology features
+-- Date: 2025-02-10
+-- ============================================================================
+--
+-- Grammatical features of each segment from the Quranic Arabic Corpus,
+-- stored as lowercase English labels ("imperfect", "plural", ...). Verb
+-- forms are Roman numerals ("I" to "XII"). Used by the grammar feature
+-- exercise.
+
+ALTER TABLE morphology_segments ADD COLUMN verb_form TEXT;
+ALTER TABLE morphology_segments ADD COLUMN tense TEXT;             -- perfect, imperfect, imperative
+ALTER TABLE morphology_segments ADD COLUMN mood TEXT;              -- indicative, subjunctive, jussive
+ALTER TABLE morphology_segments ADD COLUMN person TEXT;            -- first, second, third
+ALTER TABLE morphology_segments ADD COLUMN number TEXT;            -- singular, dual, plural
+ALTER TABLE morphology_segments ADD COLUMN gender TEXT;            -- masculine, feminine
+ALTER TABLE morphology_segments ADD COLUMN grammatical_case TEXT;  -- nominative, accusative, genitive
+ALTER TABLE morphology_segments ADD COLUMN state TEXT;             -- definite, indefinite
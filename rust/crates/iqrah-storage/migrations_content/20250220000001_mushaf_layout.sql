@@ -0,0 +1,20 @@
+-- ============================================================================
+-- Mushaf layout (15-line Madani)
+-- Date: 2025-02-20
+-- ============================================================================
+--
+-- Line-level layout of the 15-line Madani mushaf: every page has 15 lines,
+-- each holding a range of words or a surah name / basmalah header. Word
+-- ranges are inclusive word-instance node IDs, which sort in mushaf order.
+-- Used by the page memorization exercises.
+
+CREATE TABLE mushaf_lines (
+    page INTEGER NOT NULL,         -- 1 to 604
+    line_number INTEGER NOT NULL,  -- 1 to 15
+    line_type TEXT NOT NULL,       -- ayah, surah_name, basmallah
+    first_word_id INTEGER,         -- NULL for surah name and basmalah lines
+    last_word_id INTEGER,
+    PRIMARY KEY (page, line_number)
+) STRICT, WITHOUT ROWID;
+
+CREATE INDEX idx_mushaf_lines_last_word ON mushaf_lines(last_word_id);
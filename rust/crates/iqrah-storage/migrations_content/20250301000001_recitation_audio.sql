@@ -0,0 +1,61 @@
+-- ============================================================================
+-- Recitation audio (verse recitation and word audio packages)
+-- Date: 2025-03-01
+-- ============================================================================
+--
+-- Audio installed from `verse_recitation` and `word_audio` content packages.
+-- Each package brings one reciter. Clips are stored either inline as blobs or
+-- as paths to files extracted next to the database. Verse recitations carry
+-- per-word timing segments so a single word can be played from the verse
+-- clip. Words are keyed by (verse_key, position), matching word-instance
+-- node IDs.
+
+CREATE TABLE reciters (
+    reciter_id INTEGER PRIMARY KEY,
+    slug TEXT NOT NULL UNIQUE,
+    name TEXT NOT NULL,
+    style TEXT,                    -- murattal, mujawwad, muallim
+    package_id TEXT,
+    FOREIGN KEY (package_id) REFERENCES content_packages(package_id) ON DELETE CASCADE
+) STRICT;
+
+CREATE TABLE verse_recitations (
+    reciter_id INTEGER NOT NULL,
+    verse_key TEXT NOT NULL,
+    audio_path TEXT,
+    audio_blob BLOB,
+    mime_type TEXT NOT NULL,
+    duration_ms INTEGER,
+    PRIMARY KEY (reciter_id, verse_key),
+    FOREIGN KEY (reciter_id) REFERENCES reciters(reciter_id) ON DELETE CASCADE,
+    FOREIGN KEY (verse_key) REFERENCES verses(verse_key),
+    CHECK (audio_path IS NOT NULL OR audio_blob IS NOT NULL)
+) STRICT, WITHOUT ROWID;
+
+-- Word timings within a verse recitation
+CREATE TABLE recitation_segments (
+    reciter_id INTEGER NOT NULL,
+    verse_key TEXT NOT NULL,
+    position INTEGER NOT NULL,     -- 1-indexed word position
+    start_ms INTEGER NOT NULL,
+    end_ms INTEGER NOT NULL,
+    PRIMARY KEY (reciter_id, verse_key, position),
+    FOREIGN KEY (reciter_id, verse_key)
+        REFERENCES verse_recitations(reciter_id, verse_key) ON DELETE CASCADE,
+    CHECK (end_ms >= start_ms)
+) STRICT, WITHOUT ROWID;
+
+-- Standalone clips of single words
+CREATE TABLE word_audio (
+    reciter_id INTEGER NOT NULL,
+    verse_key TEXT NOT NULL,
+    position INTEGER NOT NULL,
+    audio_path TEXT,
+    audio_blob BLOB,
+    mime_type TEXT NOT NULL,
+    duration_ms INTEGER,
+    PRIMARY KEY (reciter_id, verse_key, position),
+    FOREIGN KEY (reciter_id) REFERENCES reciters(reciter_id) ON DELETE CASCADE,
+    FOREIGN KEY (verse_key) REFERENCES verses(verse_key),
+    CHECK (audio_path IS NOT NULL OR audio_blob IS NOT NULL)
+) STRICT, WITHOUT ROWID;
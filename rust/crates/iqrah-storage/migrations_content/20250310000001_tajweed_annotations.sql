@@ -0,0 +1,20 @@
+-- ============================================================================
+-- Tajweed annotations
+-- Date: 2025-03-10
+-- ============================================================================
+--
+-- Tajweed rules on spans of words, from QUL's tajweed-tagged Uthmani text.
+-- A span covers characters start_char..end_char (0-indexed, end exclusive)
+-- of the word's Uthmani text; overlapping spans are allowed (a ghunnah
+-- inside an idgham). Rules are stored as slugs (madd_lazim, ikhfa, ...).
+-- Used by the tajweed axis exercises.
+
+CREATE TABLE tajweed_annotations (
+    word_id INTEGER NOT NULL,      -- word-instance node ID
+    start_char INTEGER NOT NULL,
+    end_char INTEGER NOT NULL,
+    rule TEXT NOT NULL,
+    PRIMARY KEY (word_id, start_char, rule),
+    FOREIGN KEY (word_id) REFERENCES words(word_id),
+    CHECK (end_char > start_char)
+) STRICT, WITHOUT ROWID;
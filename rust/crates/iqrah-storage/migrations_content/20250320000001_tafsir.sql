@@ -0,0 +1,93 @@
+-- ============================================================================
+-- Tafsir (commentary packages)
+-- Date: 2025-03-20
+-- ============================================================================
+--
+-- Commentary installed from `tafsir` content packages. Each package brings
+-- one tafsir. A passage comments on a range of verses of one chapter
+-- (start_verse..end_verse, inclusive) and may name the range's key lesson,
+-- its occasion of revelation (asbab al-nuzul) and the themes it covers.
+-- Used by the tafsir axis exercises.
+--
+-- Allowing the 'tafsir' package type means rebuilding content_packages (a
+-- CHECK constraint can't be altered). init_content_db runs migrations with
+-- foreign keys off, so dropping the old table doesn't cascade to installed
+-- packages and reciters.
+
+CREATE TABLE content_packages_new (
+    package_id TEXT PRIMARY KEY,
+    package_type TEXT NOT NULL CHECK (package_type IN (
+        'verse_translation',
+        'word_translation',
+        'text_variant',
+        'verse_recitation',
+        'word_audio',
+        'transliteration',
+        'tafsir'
+    )),
+    name TEXT NOT NULL,
+    language_code TEXT,
+    author TEXT,
+    version TEXT NOT NULL,
+    description TEXT,
+    file_size INTEGER,
+    download_url TEXT,
+    checksum TEXT,
+    license TEXT,
+    created_at INTEGER NOT NULL DEFAULT (unixepoch()),
+    updated_at INTEGER,
+    FOREIGN KEY (language_code) REFERENCES languages(language_code)
+) STRICT;
+
+INSERT INTO content_packages_new (
+    package_id, package_type, name, language_code, author, version, description,
+    file_size, download_url, checksum, license, created_at, updated_at
+)
+SELECT
+    package_id, package_type, name, language_code, author, version, description,
+    file_size, download_url, checksum, license, created_at, updated_at
+FROM content_packages;
+
+DROP TABLE content_packages;
+ALTER TABLE content_packages_new RENAME TO content_packages;
+
+CREATE INDEX idx_content_packages_type_lang ON content_packages(package_type, language_code);
+
+CREATE TABLE tafsirs (
+    tafsir_id INTEGER PRIMARY KEY,
+    slug TEXT NOT NULL UNIQUE,
+    name TEXT NOT NULL,            -- "Tafsir Ibn Kathir (abridged)"
+    author TEXT,
+    language_code TEXT,
+    package_id TEXT,
+    FOREIGN KEY (language_code) REFERENCES languages(language_code),
+    FOREIGN KEY (package_id) REFERENCES content_packages(package_id) ON DELETE CASCADE
+) STRICT;
+
+CREATE TABLE tafsir_passages (
+    tafsir_id INTEGER NOT NULL,
+    chapter_number INTEGER NOT NULL,
+    start_verse INTEGER NOT NULL,
+    end_verse INTEGER NOT NULL,
+    text TEXT NOT NULL,
+    key_lesson TEXT,
+    revelation_occasion TEXT,      -- asbab al-nuzul, when reported
+    PRIMARY KEY (tafsir_id, chapter_number, start_verse),
+    FOREIGN KEY (tafsir_id) REFERENCES tafsirs(tafsir_id) ON DELETE CASCADE,
+    FOREIGN KEY (chapter_number) REFERENCES chapters(chapter_number),
+    CHECK (end_verse >= start_verse)
+) STRICT, WITHOUT ROWID;
+
+-- Themes of a passage (e.g., "patience", "tawhid"), shared across passages
+-- to connect verses
+CREATE TABLE tafsir_themes (
+    tafsir_id INTEGER NOT NULL,
+    chapter_number INTEGER NOT NULL,
+    start_verse INTEGER NOT NULL,
+    theme TEXT NOT NULL,
+    PRIMARY KEY (tafsir_id, chapter_number, start_verse, theme),
+    FOREIGN KEY (tafsir_id, chapter_number, start_verse)
+        REFERENCES tafsir_passages(tafsir_id, chapter_number, start_verse) ON DELETE CASCADE
+) STRICT, WITHOUT ROWID;
+
+CREATE INDEX idx_tafsir_themes_theme ON tafsir_themes(tafsir_id, theme);
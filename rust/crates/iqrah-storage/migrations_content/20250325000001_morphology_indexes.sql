@@ -0,0 +1,15 @@
+-- ============================================================================
+-- Morphology lookup indexes
+-- Date: 2025-03-25
+-- ============================================================================
+--
+-- Root and lemma exercises count the words of a root or lemma across the
+-- whole Quran (get_root_occurrences_in_verses, get_lemmas_for_root) and look
+-- up the segments of a word. Without indexes each lookup scans every segment.
+-- The root and lemma indexes include word_id so the distinct word counts are
+-- answered from the index alone.
+
+CREATE INDEX idx_morphology_segments_root ON morphology_segments(root_id, word_id);
+CREATE INDEX idx_morphology_segments_lemma ON morphology_segments(lemma_id, word_id);
+CREATE INDEX idx_morphology_segments_word ON morphology_segments(word_id);
+CREATE INDEX idx_lemmas_root ON lemmas(root_id);
@@ -0,0 +1,18 @@
+-- ============================================================================
+-- Node scheduling status (suspend / bury)
+-- Date: 2025-02-01
+-- ============================================================================
+--
+-- Nodes without a row are active. Suspended nodes stay out of rotation until
+-- explicitly unsuspended; buried nodes return once buried_until has passed.
+
+CREATE TABLE user_node_status (
+    user_id TEXT NOT NULL,
+    content_key INTEGER NOT NULL,  -- i64 encoded node ID
+    status TEXT NOT NULL CHECK (status IN ('suspended', 'buried')),
+    buried_until INTEGER,          -- epoch milliseconds, only for 'buried'
+    updated_at INTEGER NOT NULL,   -- epoch milliseconds
+    PRIMARY KEY (user_id, content_key)
+) STRICT, WITHOUT ROWID;
+
+CREATE INDEX idx_node_status_user ON user_node_status(user_id, status);
@@ -0,0 +1,10 @@
+-- ============================================================================
+-- Progressive hints on session items
+-- Date: 2025-02-10
+-- ============================================================================
+--
+-- Most revealing hint level shown before the item was answered
+-- (1 = first letter ... 5 = partial reveal). NULL when no hint was used.
+
+ALTER TABLE session_items
+    ADD COLUMN hint_level INTEGER CHECK (hint_level BETWEEN 1 AND 5);
@@ -0,0 +1,25 @@
+-- ============================================================================
+-- In-progress state of stateful exercises
+-- Date: 2025-02-15
+-- ============================================================================
+--
+-- Echo Recall, Memorization Ayah and Ayah Chain keep their state between
+-- interactions. The state is checkpointed here after every interaction so a
+-- session item can be resumed after the app is killed.
+--
+-- Keyed by (session_id, position). There is no foreign key to sessions:
+-- WebSocket sessions are not stored in the sessions table. `state` is the
+-- exercise serialized as JSON; rows older than the checkpoint TTL are expired.
+
+CREATE TABLE exercise_checkpoints (
+    session_id TEXT NOT NULL,
+    position INTEGER NOT NULL,
+    user_id TEXT NOT NULL,
+    exercise_type TEXT NOT NULL,
+    node_id INTEGER NOT NULL,
+    state TEXT NOT NULL,
+    updated_at INTEGER NOT NULL,
+    PRIMARY KEY (session_id, position)
+) STRICT, WITHOUT ROWID;
+
+CREATE INDEX idx_exercise_checkpoints_updated_at ON exercise_checkpoints(updated_at);
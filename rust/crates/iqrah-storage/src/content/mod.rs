@@ -13,7 +13,10 @@ pub use repository::SqliteContentRepository;
 
 use crate::error::{Result, StorageError};
 use crate::version::{get_schema_version, is_compatible};
-use sqlx::{sqlite::SqliteConnectOptions, SqlitePool};
+use sqlx::{
+    sqlite::{SqliteConnectOptions, SqlitePoolOptions},
+    SqlitePool,
+};
 use std::str::FromStr;
 
 const EXPECTED_CONTENT_VERSION: &str = "2.1.0";
@@ -47,10 +50,21 @@ pub async fn init_content_db(db_path: &str) -> Result<SqlitePool> {
         .create_if_missing(true)
         .foreign_keys(true);
 
-    let pool = SqlitePool::connect_with(options).await?;
-
-    // Run migrations for content database
-    sqlx::migrate!("./migrations_content").run(&pool).await?;
+    let pool = SqlitePool::connect_with(options.clone()).await?;
+
+    // Run migrations for content database. Foreign keys are off meanwhile so
+    // that table rebuilds (the only way to change a CHECK constraint) don't
+    // cascade deletes; the pragma can't be set inside a migration's
+    // transaction, so migrations get a connection of their own.
+    let migration_pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect_with(options.foreign_keys(false))
+        .await?;
+    let migrated = sqlx::migrate!("./migrations_content")
+        .run(&migration_pool)
+        .await;
+    migration_pool.close().await;
+    migrated?;
 
     // Verify schema version compatibility
     let db_version = get_schema_version(&pool).await?;
@@ -101,6 +101,14 @@ pub struct MorphologySegmentRow {
     pub lemma_id: Option<String>,
     pub root_id: Option<String>,
     pub pos_tag: Option<String>,
+    pub verb_form: Option<String>,
+    pub tense: Option<String>,
+    pub mood: Option<String>,
+    pub person: Option<String>,
+    pub number: Option<String>,
+    pub gender: Option<String>,
+    pub grammatical_case: Option<String>,
+    pub state: Option<String>,
 }
 
 #[allow(dead_code)]
@@ -112,6 +120,106 @@ pub struct LanguageRow {
     pub direction: String,
 }
 
+#[derive(Debug, Clone, FromRow)]
+pub struct SimilarVerseRow {
+    pub similar_verse_key: String,
+    pub shared_prefix_words: i64,
+    pub similarity: f64,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct MushafLineRow {
+    pub page: i64,
+    pub line_number: i64,
+    pub line_type: String,
+    pub first_word_id: Option<i64>,
+    pub last_word_id: Option<i64>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct TajweedAnnotationRow {
+    pub word_id: i64,
+    pub verse_key: String,
+    pub position: i64,
+    pub rule: String,
+    pub start_char: i64,
+    pub end_char: i64,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct ReciterRow {
+    pub reciter_id: i64,
+    pub slug: String,
+    pub name: String,
+    pub style: Option<String>,
+    pub package_id: Option<String>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct AudioClipRow {
+    pub audio_path: Option<String>,
+    pub audio_blob: Option<Vec<u8>>,
+    pub mime_type: String,
+    pub duration_ms: Option<i64>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct RecitationSegmentRow {
+    pub position: i64,
+    pub start_ms: i64,
+    pub end_ms: i64,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct TafsirRow {
+    pub tafsir_id: i64,
+    pub slug: String,
+    pub name: String,
+    pub author: Option<String>,
+    pub language_code: Option<String>,
+    pub package_id: Option<String>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct TafsirPassageRow {
+    pub chapter_number: i64,
+    pub start_verse: i64,
+    pub end_verse: i64,
+    pub text: String,
+    pub key_lesson: Option<String>,
+    pub revelation_occasion: Option<String>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct TafsirThemeRow {
+    pub chapter_number: i64,
+    pub start_verse: i64,
+    pub theme: String,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct LemmaFrequencyRow {
+    pub lemma_id: String,
+    pub occurrences: i64,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct RootWordRow {
+    pub root_id: String,
+    pub word_id: i64,
+    pub occurrences: i64,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct LemmaOccurrencesRow {
+    pub lemma_id: String,
+    pub arabic: String,
+    pub transliteration: Option<String>,
+    pub root_id: Option<String>,
+    pub description: Option<String>,
+    pub occurrences: i64,
+}
+
 #[allow(dead_code)]
 #[derive(Debug, Clone, FromRow)]
 pub struct LemmaRow {
@@ -1,16 +1,22 @@
 use super::models::{
-    CandidateNodeRow, ChapterRow, ContentPackageRow, EdgeRow, GoalRow, InstalledPackageRow,
-    LanguageRow, LemmaRow, MorphologySegmentRow, NodeGoalRow, NodeRow, PrerequisiteRow, RootRow,
-    TranslatorRow, VerseRow, VerseTranslationRow, WordRow,
+    AudioClipRow, CandidateNodeRow, ChapterRow, ContentPackageRow, EdgeRow, GoalRow,
+    InstalledPackageRow, LanguageRow, LemmaFrequencyRow, LemmaOccurrencesRow, LemmaRow,
+    MorphologySegmentRow, MushafLineRow, NodeGoalRow, NodeRow, PrerequisiteRow,
+    RecitationSegmentRow, ReciterRow, RootRow, RootWordRow, SimilarVerseRow, TafsirPassageRow,
+    TafsirRow, TafsirThemeRow, TajweedAnnotationRow, TranslatorRow, VerseRow, VerseTranslationRow,
+    WordRow,
 };
 use async_trait::async_trait;
 use chrono::DateTime;
 use iqrah_core::{
-    ports::content_repository::SchedulerGoal, scheduler_v2::CandidateNode, Chapter, ContentPackage,
-    ContentRepository, DistributionType, Edge, EdgeType, InstalledPackage, Language, Lemma,
-    MorphologySegment, Node, NodeType, PackageType, Root, Translator, Verse, Word,
+    ports::content_repository::SchedulerGoal, scheduler_v2::CandidateNode, AudioSource, Chapter,
+    ContentPackage, ContentRepository, DistributionType, Edge, EdgeType, InstalledPackage,
+    Language, Lemma, LemmaOccurrences, MorphologyFeatures, MorphologySegment, MushafLine, Node,
+    NodeType, PackageContent, PackageType, Reciter, Root, RootOccurrences, SimilarVerse, Tafsir,
+    TafsirPassage, TajweedAnnotation, TajweedRule, Translator, Verse, VerseRecitation, Word,
+    WordAudioClip, WordSegment,
 };
-use sqlx::{query_as, SqlitePool};
+use sqlx::{query_as, SqliteConnection, SqlitePool};
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -281,6 +287,34 @@ impl ContentRepository for SqliteContentRepository {
         Ok(row.map(|(text,)| text))
     }
 
+    async fn get_script_contents_batch(
+        &self,
+        node_ids: &[i64],
+        script_slug: &str,
+    ) -> anyhow::Result<HashMap<i64, String>> {
+        let mut contents = HashMap::new();
+
+        // SQLite parameter limit is ~999, so chunk into batches of 500
+        for chunk in node_ids.chunks(500) {
+            // Dynamic IN-clause size is data-dependent; query! cannot validate runtime SQL text.
+            let placeholders = chunk.iter().map(|_| "?").collect::<Vec<_>>().join(", ");
+            let sql = format!(
+                "SELECT sc.node_id, sc.text_content
+                 FROM script_contents sc
+                 JOIN script_resources sr ON sc.resource_id = sr.resource_id
+                 WHERE sr.slug = ? AND sc.node_id IN ({})",
+                placeholders
+            );
+            let mut query = query_as::<_, (i64, String)>(&sql).bind(script_slug);
+            for node_id in chunk {
+                query = query.bind(node_id);
+            }
+            contents.extend(query.fetch_all(&self.pool).await?);
+        }
+
+        Ok(contents)
+    }
+
     async fn get_translation(&self, node_id: i64, lang: &str) -> anyhow::Result<Option<String>> {
         // V2 schema: Query from verse_translations or word_translations
         use iqrah_core::domain::node_id as nid;
@@ -612,34 +646,45 @@ impl ContentRepository for SqliteContentRepository {
         }
 
         use iqrah_core::domain::node_id as nid;
-        let mut all_words = Vec::new();
-
-        for &ayah_id in ayah_node_ids {
-            // Use decode_verse to handle "VERSE:1:1" encoded as i64
-            let (chapter, verse) = match nid::decode_verse(ayah_id) {
-                Some(cv) => cv,
-                None => continue, // Skip invalid IDs
-            };
-
-            let verse_key = format!("{}:{}", chapter, verse);
-
-            // Query words for this verse_key
-            let rows = query_as::<_, (i64, i64)>(
-                "SELECT word_id, created_at FROM words WHERE verse_key = ? ORDER BY position",
-            )
-            .bind(&verse_key)
-            .fetch_all(&self.pool)
-            .await?;
 
-            for (word_id, _created_at) in rows {
-                // word_id is already an encoded WordInstance node_id
-                all_words.push(Node {
-                    id: word_id,
-                    ukey: nid::to_ukey(word_id).unwrap_or_default(),
-                    node_type: NodeType::WordInstance,
-                });
+        // Use decode_verse to handle "VERSE:1:1" encoded as i64, skipping invalid IDs
+        let mut verse_order: HashMap<String, usize> = HashMap::new();
+        for (chapter, verse) in ayah_node_ids.iter().filter_map(|&id| nid::decode_verse(id)) {
+            let next = verse_order.len();
+            verse_order
+                .entry(format!("{}:{}", chapter, verse))
+                .or_insert(next);
+        }
+        if verse_order.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let verse_keys: Vec<&String> = verse_order.keys().collect();
+        let mut rows: Vec<(i64, String, i32)> = Vec::new();
+        for chunk in verse_keys.chunks(500) {
+            // Dynamic IN-clause size is data-dependent; query! cannot validate runtime SQL text.
+            let placeholders = chunk.iter().map(|_| "?").collect::<Vec<_>>().join(", ");
+            let sql = format!(
+                "SELECT word_id, verse_key, position FROM words WHERE verse_key IN ({})",
+                placeholders
+            );
+            let mut query = query_as::<_, (i64, String, i32)>(&sql);
+            for key in chunk {
+                query = query.bind(*key);
             }
+            rows.extend(query.fetch_all(&self.pool).await?);
         }
+        rows.sort_by_key(|(_, verse_key, position)| (verse_order[verse_key], *position));
+
+        // word_id is already an encoded WordInstance node_id
+        let all_words = rows
+            .into_iter()
+            .map(|(word_id, _, _)| Node {
+                id: word_id,
+                ukey: nid::to_ukey(word_id).unwrap_or_default(),
+                node_type: NodeType::WordInstance,
+            })
+            .collect();
 
         Ok(all_words)
     }
@@ -1118,9 +1163,9 @@ impl ContentRepository for SqliteContentRepository {
         version: Option<String>,
         package_id: Option<String>,
     ) -> anyhow::Result<i32> {
-        let result = sqlx::query!(
-            "INSERT INTO translators (slug, full_name, language_code, description, copyright_holder, license, website, version, package_id)
-             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        let mut conn = self.pool.acquire().await?;
+        insert_translator_row(
+            &mut conn,
             slug,
             full_name,
             language_code,
@@ -1129,12 +1174,9 @@ impl ContentRepository for SqliteContentRepository {
             license,
             website,
             version,
-            package_id
+            package_id,
         )
-        .execute(&self.pool)
-        .await?;
-
-        Ok(result.last_insert_rowid() as i32)
+        .await
     }
 
     async fn insert_verse_translation(
@@ -1144,21 +1186,9 @@ impl ContentRepository for SqliteContentRepository {
         translation: &str,
         footnotes: Option<String>,
     ) -> anyhow::Result<()> {
-        sqlx::query!(
-            "INSERT INTO verse_translations (verse_key, translator_id, translation, footnotes)
-             VALUES (?, ?, ?, ?)
-             ON CONFLICT(verse_key, translator_id) DO UPDATE SET
-                translation = excluded.translation,
-                footnotes = excluded.footnotes",
-            verse_key,
-            translator_id,
-            translation,
-            footnotes
-        )
-        .execute(&self.pool)
-        .await?;
-
-        Ok(())
+        let mut conn = self.pool.acquire().await?;
+        insert_verse_translation_row(&mut conn, verse_key, translator_id, translation, footnotes)
+            .await
     }
 
     // ========================================================================
@@ -1335,17 +1365,94 @@ impl ContentRepository for SqliteContentRepository {
     }
 
     async fn mark_package_installed(&self, package_id: &str) -> anyhow::Result<()> {
-        sqlx::query!(
-            "INSERT INTO installed_packages (package_id, installed_at, enabled) \
-             VALUES (?, unixepoch(), 1) \
-             ON CONFLICT(package_id) DO UPDATE SET \
-                enabled = 1, \
-                installed_at = unixepoch()",
-            package_id
-        )
-        .execute(&self.pool)
-        .await?;
+        let mut conn = self.pool.acquire().await?;
+        mark_package_installed_row(&mut conn, package_id).await
+    }
+
+    async fn install_package_content(
+        &self,
+        package: &ContentPackage,
+        content: &PackageContent,
+    ) -> anyhow::Result<()> {
+        let package_id = Some(package.package_id.clone());
+        let mut tx = self.pool.begin().await?;
+
+        match content {
+            PackageContent::VerseTranslations {
+                translator,
+                translations,
+            } => {
+                let translator_id = insert_translator_row(
+                    &mut tx,
+                    &translator.slug,
+                    &translator.full_name,
+                    &translator.language_code,
+                    translator.description.clone(),
+                    package.author.clone(),
+                    translator.license.clone(),
+                    None,
+                    Some(package.version.clone()),
+                    package_id,
+                )
+                .await?;
+                for (verse_key, translation) in translations {
+                    insert_verse_translation_row(
+                        &mut tx,
+                        verse_key,
+                        translator_id,
+                        translation,
+                        None,
+                    )
+                    .await?;
+                }
+            }
+            PackageContent::VerseRecitations {
+                reciter,
+                recitations,
+            } => {
+                let reciter_id = insert_reciter_row(
+                    &mut tx,
+                    &reciter.slug,
+                    &reciter.name,
+                    reciter.style.clone(),
+                    package_id,
+                )
+                .await?;
+                for recitation in recitations {
+                    insert_verse_recitation_row(&mut tx, reciter_id, recitation).await?;
+                }
+            }
+            PackageContent::WordAudio { reciter, clips } => {
+                let reciter_id = insert_reciter_row(
+                    &mut tx,
+                    &reciter.slug,
+                    &reciter.name,
+                    reciter.style.clone(),
+                    package_id,
+                )
+                .await?;
+                for clip in clips {
+                    insert_word_audio_row(&mut tx, reciter_id, clip).await?;
+                }
+            }
+            PackageContent::Tafsir { tafsir, passages } => {
+                let tafsir_id = insert_tafsir_row(
+                    &mut tx,
+                    &tafsir.slug,
+                    &tafsir.name,
+                    tafsir.author.clone(),
+                    tafsir.language_code.clone(),
+                    package_id,
+                )
+                .await?;
+                for passage in passages {
+                    insert_tafsir_passage_row(&mut tx, tafsir_id, passage).await?;
+                }
+            }
+        }
 
+        mark_package_installed_row(&mut tx, &package.package_id).await?;
+        tx.commit().await?;
         Ok(())
     }
 
@@ -1404,6 +1511,306 @@ impl ContentRepository for SqliteContentRepository {
             .collect()
     }
 
+    // ========================================================================
+    // Recitation Audio Methods
+    // ========================================================================
+
+    async fn get_reciters(&self) -> anyhow::Result<Vec<Reciter>> {
+        let rows = query_as::<_, ReciterRow>(
+            "SELECT reciter_id, slug, name, style, package_id
+             FROM reciters
+             WHERE package_id IS NULL
+                OR package_id IN (SELECT package_id FROM installed_packages WHERE enabled = 1)
+             ORDER BY name",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|r| Reciter {
+                reciter_id: r.reciter_id as i32,
+                slug: r.slug,
+                name: r.name,
+                style: r.style,
+                package_id: r.package_id,
+            })
+            .collect())
+    }
+
+    async fn insert_reciter(
+        &self,
+        slug: &str,
+        name: &str,
+        style: Option<String>,
+        package_id: Option<String>,
+    ) -> anyhow::Result<i32> {
+        let mut conn = self.pool.acquire().await?;
+        insert_reciter_row(&mut conn, slug, name, style, package_id).await
+    }
+
+    async fn insert_verse_recitation(&self, recitation: &VerseRecitation) -> anyhow::Result<()> {
+        let mut tx = self.pool.begin().await?;
+        insert_verse_recitation_row(&mut tx, recitation.reciter_id, recitation).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn insert_word_audio(&self, clip: &WordAudioClip) -> anyhow::Result<()> {
+        let mut conn = self.pool.acquire().await?;
+        insert_word_audio_row(&mut conn, clip.reciter_id, clip).await
+    }
+
+    async fn get_verse_recitation(
+        &self,
+        verse_key: &str,
+        reciter_id: i32,
+    ) -> anyhow::Result<Option<VerseRecitation>> {
+        let Some(row) = query_as::<_, AudioClipRow>(
+            "SELECT audio_path, audio_blob, mime_type, duration_ms
+             FROM verse_recitations
+             WHERE reciter_id = ? AND verse_key = ?",
+        )
+        .bind(reciter_id)
+        .bind(verse_key)
+        .fetch_optional(&self.pool)
+        .await?
+        else {
+            return Ok(None);
+        };
+
+        let segments = query_as::<_, RecitationSegmentRow>(
+            "SELECT position, start_ms, end_ms
+             FROM recitation_segments
+             WHERE reciter_id = ? AND verse_key = ?
+             ORDER BY position",
+        )
+        .bind(reciter_id)
+        .bind(verse_key)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(Some(VerseRecitation {
+            reciter_id,
+            verse_key: verse_key.to_string(),
+            source: audio_source_from_row(row.audio_path, row.audio_blob)?,
+            mime_type: row.mime_type,
+            duration_ms: row.duration_ms,
+            segments: segments
+                .into_iter()
+                .map(|s| WordSegment {
+                    position: s.position as i32,
+                    start_ms: s.start_ms,
+                    end_ms: s.end_ms,
+                })
+                .collect(),
+        }))
+    }
+
+    async fn get_word_audio(
+        &self,
+        verse_key: &str,
+        position: i32,
+        reciter_id: i32,
+    ) -> anyhow::Result<Option<WordAudioClip>> {
+        let clip = query_as::<_, AudioClipRow>(
+            "SELECT audio_path, audio_blob, mime_type, duration_ms
+             FROM word_audio
+             WHERE reciter_id = ? AND verse_key = ? AND position = ?",
+        )
+        .bind(reciter_id)
+        .bind(verse_key)
+        .bind(position)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        if let Some(row) = clip {
+            return Ok(Some(WordAudioClip {
+                reciter_id,
+                verse_key: verse_key.to_string(),
+                position,
+                source: audio_source_from_row(row.audio_path, row.audio_blob)?,
+                mime_type: row.mime_type,
+                duration_ms: row.duration_ms,
+                start_ms: None,
+                end_ms: None,
+            }));
+        }
+
+        // Fall back to the word's segment of the verse recitation
+        let Some(recitation) = self.get_verse_recitation(verse_key, reciter_id).await? else {
+            return Ok(None);
+        };
+        Ok(recitation
+            .segment(position)
+            .copied()
+            .map(|segment| WordAudioClip {
+                reciter_id,
+                verse_key: verse_key.to_string(),
+                position,
+                source: recitation.source.clone(),
+                mime_type: recitation.mime_type.clone(),
+                duration_ms: Some(segment.end_ms - segment.start_ms),
+                start_ms: Some(segment.start_ms),
+                end_ms: Some(segment.end_ms),
+            }))
+    }
+
+    // ========================================================================
+    // Tafsir Methods
+    // ========================================================================
+
+    async fn get_tafsirs(&self) -> anyhow::Result<Vec<Tafsir>> {
+        let rows = query_as::<_, TafsirRow>(
+            "SELECT tafsir_id, slug, name, author, language_code, package_id
+             FROM tafsirs
+             WHERE package_id IS NULL
+                OR package_id IN (SELECT package_id FROM installed_packages WHERE enabled = 1)
+             ORDER BY name",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|r| Tafsir {
+                tafsir_id: r.tafsir_id as i32,
+                slug: r.slug,
+                name: r.name,
+                author: r.author,
+                language_code: r.language_code,
+                package_id: r.package_id,
+            })
+            .collect())
+    }
+
+    async fn insert_tafsir(
+        &self,
+        slug: &str,
+        name: &str,
+        author: Option<String>,
+        language_code: Option<String>,
+        package_id: Option<String>,
+    ) -> anyhow::Result<i32> {
+        let mut conn = self.pool.acquire().await?;
+        insert_tafsir_row(&mut conn, slug, name, author, language_code, package_id).await
+    }
+
+    async fn insert_tafsir_passage(&self, passage: &TafsirPassage) -> anyhow::Result<()> {
+        let mut tx = self.pool.begin().await?;
+        insert_tafsir_passage_row(&mut tx, passage.tafsir_id, passage).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn get_tafsir_passage(
+        &self,
+        verse_key: &str,
+        tafsir_id: i32,
+    ) -> anyhow::Result<Option<TafsirPassage>> {
+        let Some(verse) = self.get_verse(verse_key).await? else {
+            return Ok(None);
+        };
+
+        let Some(row) = query_as::<_, TafsirPassageRow>(
+            "SELECT chapter_number, start_verse, end_verse, text, key_lesson, revelation_occasion
+             FROM tafsir_passages
+             WHERE tafsir_id = ? AND chapter_number = ? AND start_verse <= ? AND end_verse >= ?
+             ORDER BY start_verse DESC
+             LIMIT 1",
+        )
+        .bind(tafsir_id)
+        .bind(verse.chapter_number)
+        .bind(verse.verse_number)
+        .bind(verse.verse_number)
+        .fetch_optional(&self.pool)
+        .await?
+        else {
+            return Ok(None);
+        };
+
+        let themes = query_as::<_, TafsirThemeRow>(
+            "SELECT chapter_number, start_verse, theme
+             FROM tafsir_themes
+             WHERE tafsir_id = ? AND chapter_number = ? AND start_verse = ?",
+        )
+        .bind(tafsir_id)
+        .bind(row.chapter_number)
+        .bind(row.start_verse)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(tafsir_passages_from_rows(tafsir_id, vec![row], themes).pop())
+    }
+
+    async fn get_tafsir_passages_for_chapter(
+        &self,
+        chapter_number: i32,
+        tafsir_id: i32,
+    ) -> anyhow::Result<Vec<TafsirPassage>> {
+        let rows = query_as::<_, TafsirPassageRow>(
+            "SELECT chapter_number, start_verse, end_verse, text, key_lesson, revelation_occasion
+             FROM tafsir_passages
+             WHERE tafsir_id = ? AND chapter_number = ?
+             ORDER BY start_verse",
+        )
+        .bind(tafsir_id)
+        .bind(chapter_number)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let themes = query_as::<_, TafsirThemeRow>(
+            "SELECT chapter_number, start_verse, theme
+             FROM tafsir_themes
+             WHERE tafsir_id = ? AND chapter_number = ?",
+        )
+        .bind(tafsir_id)
+        .bind(chapter_number)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(tafsir_passages_from_rows(tafsir_id, rows, themes))
+    }
+
+    async fn get_tafsir_passages_by_theme(
+        &self,
+        theme: &str,
+        tafsir_id: i32,
+    ) -> anyhow::Result<Vec<TafsirPassage>> {
+        let rows = query_as::<_, TafsirPassageRow>(
+            "SELECT p.chapter_number, p.start_verse, p.end_verse, p.text, p.key_lesson,
+                    p.revelation_occasion
+             FROM tafsir_passages p
+             JOIN tafsir_themes t
+                ON t.tafsir_id = p.tafsir_id
+               AND t.chapter_number = p.chapter_number
+               AND t.start_verse = p.start_verse
+             WHERE p.tafsir_id = ? AND t.theme = ?
+             ORDER BY p.chapter_number, p.start_verse",
+        )
+        .bind(tafsir_id)
+        .bind(theme)
+        .fetch_all(&self.pool)
+        .await?;
+
+        // All themes of the matching passages, not just `theme`
+        let themes = query_as::<_, TafsirThemeRow>(
+            "SELECT t.chapter_number, t.start_verse, t.theme
+             FROM tafsir_themes t
+             JOIN tafsir_themes m
+                ON m.tafsir_id = t.tafsir_id
+               AND m.chapter_number = t.chapter_number
+               AND m.start_verse = t.start_verse
+             WHERE m.tafsir_id = ? AND m.theme = ?",
+        )
+        .bind(tafsir_id)
+        .bind(theme)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(tafsir_passages_from_rows(tafsir_id, rows, themes))
+    }
+
     // ========================================================================
     // Morphology Methods (for grammar exercises)
     // ========================================================================
@@ -1413,7 +1820,8 @@ impl ContentRepository for SqliteContentRepository {
         word_id: i64,
     ) -> anyhow::Result<Vec<MorphologySegment>> {
         let rows = query_as::<_, MorphologySegmentRow>(
-            "SELECT segment_id, word_id, position, lemma_id, root_id, pos_tag
+            "SELECT segment_id, word_id, position, lemma_id, root_id, pos_tag,
+                    verb_form, tense, mood, person, number, gender, grammatical_case, state
              FROM morphology_segments
              WHERE word_id = ?
              ORDER BY position",
@@ -1431,6 +1839,16 @@ impl ContentRepository for SqliteContentRepository {
                 lemma_id: r.lemma_id,
                 root_id: r.root_id,
                 pos_tag: r.pos_tag,
+                features: MorphologyFeatures {
+                    verb_form: r.verb_form,
+                    tense: r.tense,
+                    mood: r.mood,
+                    person: r.person,
+                    number: r.number,
+                    gender: r.gender,
+                    case: r.grammatical_case,
+                    state: r.state,
+                },
             })
             .collect())
     }
@@ -1468,9 +1886,253 @@ impl ContentRepository for SqliteContentRepository {
             arabic: r.arabic,
             root_id: r.root_id,
             transliteration: r.transliteration,
+            description: r.description,
         }))
     }
 
+    async fn get_words_for_root(&self, root_id: &str, limit: u32) -> anyhow::Result<Vec<Word>> {
+        let rows = query_as::<_, WordRow>(
+            "SELECT w.word_id, w.verse_key, w.position,
+                    sc_uth.text_content as text_uthmani,
+                    sc_sim.text_content as text_simple,
+                    sc_tr.text_content as transliteration,
+                    w.letter_count, w.created_at
+             FROM words w
+             JOIN nodes n ON (n.ukey = 'WORD:' || w.word_id OR n.ukey = CAST(w.word_id AS TEXT))
+             LEFT JOIN script_resources sr_uth ON sr_uth.slug = 'uthmani'
+             LEFT JOIN script_contents sc_uth ON sc_uth.node_id = n.id AND sc_uth.resource_id = sr_uth.resource_id
+             LEFT JOIN script_resources sr_sim ON sr_sim.slug = 'simple'
+             LEFT JOIN script_contents sc_sim ON sc_sim.node_id = n.id AND sc_sim.resource_id = sr_sim.resource_id
+             LEFT JOIN script_resources sr_tr ON sr_tr.slug = 'transliteration'
+             LEFT JOIN script_contents sc_tr ON sc_tr.node_id = n.id AND sc_tr.resource_id = sr_tr.resource_id
+             WHERE w.word_id IN (SELECT word_id FROM morphology_segments WHERE root_id = ?)
+             ORDER BY w.word_id
+             LIMIT ?",
+        )
+        .bind(root_id)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|r| Word {
+                id: r.word_id,
+                verse_key: r.verse_key,
+                position: r.position,
+                text_uthmani: r.text_uthmani,
+                text_simple: r.text_simple,
+                transliteration: r.transliteration,
+            })
+            .collect())
+    }
+
+    async fn get_lemma_frequencies(
+        &self,
+        lemma_ids: &[String],
+    ) -> anyhow::Result<HashMap<String, u32>> {
+        if lemma_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        // Dynamic IN-clause size is data-dependent; query! cannot validate runtime SQL text.
+        let placeholders: Vec<String> = lemma_ids.iter().map(|_| "?".to_string()).collect();
+        let query_str = format!(
+            "SELECT lemma_id, COUNT(*) as occurrences
+             FROM morphology_segments
+             WHERE lemma_id IN ({})
+             GROUP BY lemma_id",
+            placeholders.join(",")
+        );
+
+        let mut query_builder = query_as::<_, LemmaFrequencyRow>(&query_str);
+        for id in lemma_ids {
+            query_builder = query_builder.bind(id);
+        }
+
+        let rows = query_builder.fetch_all(&self.pool).await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|r| (r.lemma_id, r.occurrences as u32))
+            .collect())
+    }
+
+    async fn get_root_occurrences_in_verses(
+        &self,
+        verse_keys: &[String],
+    ) -> anyhow::Result<Vec<RootOccurrences>> {
+        if verse_keys.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // Dynamic IN-clause size is data-dependent; query! cannot validate runtime SQL text.
+        let placeholders: Vec<String> = verse_keys.iter().map(|_| "?".to_string()).collect();
+        let query_str = format!(
+            "SELECT DISTINCT ms.root_id, w.word_id,
+                    (SELECT COUNT(DISTINCT all_ms.word_id)
+                     FROM morphology_segments all_ms
+                     WHERE all_ms.root_id = ms.root_id) as occurrences
+             FROM words w
+             JOIN morphology_segments ms ON ms.word_id = w.word_id
+             WHERE ms.root_id IS NOT NULL AND w.verse_key IN ({})
+             ORDER BY w.word_id",
+            placeholders.join(",")
+        );
+
+        let mut query_builder = query_as::<_, RootWordRow>(&query_str);
+        for key in verse_keys {
+            query_builder = query_builder.bind(key);
+        }
+
+        let mut roots: Vec<RootOccurrences> = Vec::new();
+        for row in query_builder.fetch_all(&self.pool).await? {
+            match roots.iter_mut().find(|r| r.root_id == row.root_id) {
+                Some(root) => root.word_ids.push(row.word_id),
+                None => roots.push(RootOccurrences {
+                    root_id: row.root_id,
+                    occurrences: row.occurrences as u32,
+                    word_ids: vec![row.word_id],
+                }),
+            }
+        }
+        roots.sort_by(|a, b| {
+            b.occurrences
+                .cmp(&a.occurrences)
+                .then_with(|| a.root_id.cmp(&b.root_id))
+        });
+        Ok(roots)
+    }
+
+    async fn get_lemmas_for_root(&self, root_id: &str) -> anyhow::Result<Vec<LemmaOccurrences>> {
+        let rows = query_as::<_, LemmaOccurrencesRow>(
+            "SELECT l.lemma_id, l.arabic, l.transliteration, l.root_id, l.description,
+                    COUNT(DISTINCT ms.word_id) as occurrences
+             FROM lemmas l
+             JOIN morphology_segments ms ON ms.lemma_id = l.lemma_id
+             WHERE l.root_id = ?
+             GROUP BY l.lemma_id
+             ORDER BY occurrences DESC, l.lemma_id",
+        )
+        .bind(root_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|r| LemmaOccurrences {
+                lemma: Lemma {
+                    lemma_id: r.lemma_id,
+                    arabic: r.arabic,
+                    root_id: r.root_id,
+                    transliteration: r.transliteration,
+                    description: r.description,
+                },
+                occurrences: r.occurrences as u32,
+            })
+            .collect())
+    }
+
+    // ========================================================================
+    // Mutashabihat Methods
+    // ========================================================================
+
+    async fn get_similar_verses(
+        &self,
+        verse_key: &str,
+        limit: u32,
+    ) -> anyhow::Result<Vec<SimilarVerse>> {
+        let rows = query_as::<_, SimilarVerseRow>(
+            "SELECT similar_verse_key, shared_prefix_words, similarity
+             FROM mutashabihat
+             WHERE verse_key = ?
+             ORDER BY similarity DESC, similar_verse_key
+             LIMIT ?",
+        )
+        .bind(verse_key)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|r| SimilarVerse {
+                verse_key: r.similar_verse_key,
+                shared_prefix_words: r.shared_prefix_words as i32,
+                similarity: r.similarity,
+            })
+            .collect())
+    }
+
+    // ========================================================================
+    // Mushaf Layout Methods
+    // ========================================================================
+
+    async fn get_mushaf_page(&self, page: i32) -> anyhow::Result<Vec<MushafLine>> {
+        let rows = query_as::<_, MushafLineRow>(
+            "SELECT page, line_number, line_type, first_word_id, last_word_id
+             FROM mushaf_lines
+             WHERE page = ?
+             ORDER BY line_number",
+        )
+        .bind(page)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(mushaf_line_from_row).collect())
+    }
+
+    async fn get_mushaf_line_for_word(&self, word_id: i64) -> anyhow::Result<Option<MushafLine>> {
+        let row = query_as::<_, MushafLineRow>(
+            "SELECT page, line_number, line_type, first_word_id, last_word_id
+             FROM mushaf_lines
+             WHERE last_word_id >= ? AND first_word_id <= ?
+             ORDER BY last_word_id
+             LIMIT 1",
+        )
+        .bind(word_id)
+        .bind(word_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(mushaf_line_from_row))
+    }
+
+    // ========================================================================
+    // Tajweed Methods
+    // ========================================================================
+
+    async fn get_tajweed_annotations(
+        &self,
+        verse_key: &str,
+    ) -> anyhow::Result<Vec<TajweedAnnotation>> {
+        let rows = query_as::<_, TajweedAnnotationRow>(
+            "SELECT t.word_id, w.verse_key, w.position, t.rule, t.start_char, t.end_char
+             FROM tajweed_annotations t
+             JOIN words w ON w.word_id = t.word_id
+             WHERE w.verse_key = ?
+             ORDER BY w.position, t.start_char, t.end_char",
+        )
+        .bind(verse_key)
+        .fetch_all(&self.pool)
+        .await?;
+
+        // Rules this build doesn't know are skipped
+        Ok(rows
+            .into_iter()
+            .filter_map(|r| {
+                Some(TajweedAnnotation {
+                    word_id: r.word_id,
+                    verse_key: r.verse_key,
+                    position: r.position as i32,
+                    rule: TajweedRule::from_slug(&r.rule)?,
+                    start_char: r.start_char as i32,
+                    end_char: r.end_char as i32,
+                })
+            })
+            .collect())
+    }
+
     // ========================================================================
     // Scheduler v2.0 Methods
     // ========================================================================
@@ -1691,3 +2353,319 @@ impl ContentRepository for SqliteContentRepository {
         Ok(result)
     }
 }
+
+fn mushaf_line_from_row(r: MushafLineRow) -> MushafLine {
+    MushafLine {
+        page: r.page as i32,
+        line_number: r.line_number as i32,
+        line_type: r.line_type,
+        first_word_id: r.first_word_id,
+        last_word_id: r.last_word_id,
+    }
+}
+
+/// Build passages from their rows and the rows of their themes (themes
+/// sorted by name)
+fn tafsir_passages_from_rows(
+    tafsir_id: i32,
+    rows: Vec<TafsirPassageRow>,
+    theme_rows: Vec<TafsirThemeRow>,
+) -> Vec<TafsirPassage> {
+    let mut themes: HashMap<(i64, i64), Vec<String>> = HashMap::new();
+    for t in theme_rows {
+        themes
+            .entry((t.chapter_number, t.start_verse))
+            .or_default()
+            .push(t.theme);
+    }
+
+    rows.into_iter()
+        .map(|r| {
+            let mut passage_themes = themes
+                .remove(&(r.chapter_number, r.start_verse))
+                .unwrap_or_default();
+            passage_themes.sort();
+            TafsirPassage {
+                tafsir_id,
+                chapter_number: r.chapter_number as i32,
+                start_verse: r.start_verse as i32,
+                end_verse: r.end_verse as i32,
+                text: r.text,
+                key_lesson: r.key_lesson,
+                revelation_occasion: r.revelation_occasion,
+                themes: passage_themes,
+            }
+        })
+        .collect()
+}
+
+// ============================================================================
+// Inserts shared by the single-row methods and `install_package_content`
+// ============================================================================
+
+#[allow(clippy::too_many_arguments)]
+async fn insert_translator_row(
+    conn: &mut SqliteConnection,
+    slug: &str,
+    full_name: &str,
+    language_code: &str,
+    description: Option<String>,
+    copyright_holder: Option<String>,
+    license: Option<String>,
+    website: Option<String>,
+    version: Option<String>,
+    package_id: Option<String>,
+) -> anyhow::Result<i32> {
+    let result = sqlx::query!(
+        "INSERT INTO translators (slug, full_name, language_code, description, copyright_holder, license, website, version, package_id)
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        slug,
+        full_name,
+        language_code,
+        description,
+        copyright_holder,
+        license,
+        website,
+        version,
+        package_id
+    )
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(result.last_insert_rowid() as i32)
+}
+
+async fn insert_verse_translation_row(
+    conn: &mut SqliteConnection,
+    verse_key: &str,
+    translator_id: i32,
+    translation: &str,
+    footnotes: Option<String>,
+) -> anyhow::Result<()> {
+    sqlx::query!(
+        "INSERT INTO verse_translations (verse_key, translator_id, translation, footnotes)
+         VALUES (?, ?, ?, ?)
+         ON CONFLICT(verse_key, translator_id) DO UPDATE SET
+            translation = excluded.translation,
+            footnotes = excluded.footnotes",
+        verse_key,
+        translator_id,
+        translation,
+        footnotes
+    )
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(())
+}
+
+async fn mark_package_installed_row(
+    conn: &mut SqliteConnection,
+    package_id: &str,
+) -> anyhow::Result<()> {
+    sqlx::query!(
+        "INSERT INTO installed_packages (package_id, installed_at, enabled) \
+         VALUES (?, unixepoch(), 1) \
+         ON CONFLICT(package_id) DO UPDATE SET \
+            enabled = 1, \
+            installed_at = unixepoch()",
+        package_id
+    )
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(())
+}
+
+async fn insert_reciter_row(
+    conn: &mut SqliteConnection,
+    slug: &str,
+    name: &str,
+    style: Option<String>,
+    package_id: Option<String>,
+) -> anyhow::Result<i32> {
+    let result =
+        sqlx::query("INSERT INTO reciters (slug, name, style, package_id) VALUES (?, ?, ?, ?)")
+            .bind(slug)
+            .bind(name)
+            .bind(style)
+            .bind(package_id)
+            .execute(&mut *conn)
+            .await?;
+
+    Ok(result.last_insert_rowid() as i32)
+}
+
+/// Insert or replace a verse recitation of `reciter_id` and its word
+/// segments (run it in a transaction)
+async fn insert_verse_recitation_row(
+    conn: &mut SqliteConnection,
+    reciter_id: i32,
+    recitation: &VerseRecitation,
+) -> anyhow::Result<()> {
+    let (audio_path, audio_blob) = audio_columns(&recitation.source);
+    sqlx::query(
+        "INSERT INTO verse_recitations
+            (reciter_id, verse_key, audio_path, audio_blob, mime_type, duration_ms)
+         VALUES (?, ?, ?, ?, ?, ?)
+         ON CONFLICT(reciter_id, verse_key) DO UPDATE SET
+            audio_path = excluded.audio_path,
+            audio_blob = excluded.audio_blob,
+            mime_type = excluded.mime_type,
+            duration_ms = excluded.duration_ms",
+    )
+    .bind(reciter_id)
+    .bind(&recitation.verse_key)
+    .bind(audio_path)
+    .bind(audio_blob)
+    .bind(&recitation.mime_type)
+    .bind(recitation.duration_ms)
+    .execute(&mut *conn)
+    .await?;
+
+    sqlx::query("DELETE FROM recitation_segments WHERE reciter_id = ? AND verse_key = ?")
+        .bind(reciter_id)
+        .bind(&recitation.verse_key)
+        .execute(&mut *conn)
+        .await?;
+
+    for segment in &recitation.segments {
+        sqlx::query(
+            "INSERT INTO recitation_segments (reciter_id, verse_key, position, start_ms, end_ms)
+             VALUES (?, ?, ?, ?, ?)",
+        )
+        .bind(reciter_id)
+        .bind(&recitation.verse_key)
+        .bind(segment.position)
+        .bind(segment.start_ms)
+        .bind(segment.end_ms)
+        .execute(&mut *conn)
+        .await?;
+    }
+
+    Ok(())
+}
+
+async fn insert_word_audio_row(
+    conn: &mut SqliteConnection,
+    reciter_id: i32,
+    clip: &WordAudioClip,
+) -> anyhow::Result<()> {
+    let (audio_path, audio_blob) = audio_columns(&clip.source);
+    sqlx::query(
+        "INSERT INTO word_audio
+            (reciter_id, verse_key, position, audio_path, audio_blob, mime_type, duration_ms)
+         VALUES (?, ?, ?, ?, ?, ?, ?)
+         ON CONFLICT(reciter_id, verse_key, position) DO UPDATE SET
+            audio_path = excluded.audio_path,
+            audio_blob = excluded.audio_blob,
+            mime_type = excluded.mime_type,
+            duration_ms = excluded.duration_ms",
+    )
+    .bind(reciter_id)
+    .bind(&clip.verse_key)
+    .bind(clip.position)
+    .bind(audio_path)
+    .bind(audio_blob)
+    .bind(&clip.mime_type)
+    .bind(clip.duration_ms)
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(())
+}
+
+async fn insert_tafsir_row(
+    conn: &mut SqliteConnection,
+    slug: &str,
+    name: &str,
+    author: Option<String>,
+    language_code: Option<String>,
+    package_id: Option<String>,
+) -> anyhow::Result<i32> {
+    let result = sqlx::query(
+        "INSERT INTO tafsirs (slug, name, author, language_code, package_id)
+         VALUES (?, ?, ?, ?, ?)",
+    )
+    .bind(slug)
+    .bind(name)
+    .bind(author)
+    .bind(language_code)
+    .bind(package_id)
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(result.last_insert_rowid() as i32)
+}
+
+/// Insert or replace a passage of `tafsir_id` and its themes (run it in a
+/// transaction)
+async fn insert_tafsir_passage_row(
+    conn: &mut SqliteConnection,
+    tafsir_id: i32,
+    passage: &TafsirPassage,
+) -> anyhow::Result<()> {
+    sqlx::query(
+        "INSERT INTO tafsir_passages
+            (tafsir_id, chapter_number, start_verse, end_verse, text, key_lesson,
+             revelation_occasion)
+         VALUES (?, ?, ?, ?, ?, ?, ?)
+         ON CONFLICT(tafsir_id, chapter_number, start_verse) DO UPDATE SET
+            end_verse = excluded.end_verse,
+            text = excluded.text,
+            key_lesson = excluded.key_lesson,
+            revelation_occasion = excluded.revelation_occasion",
+    )
+    .bind(tafsir_id)
+    .bind(passage.chapter_number)
+    .bind(passage.start_verse)
+    .bind(passage.end_verse)
+    .bind(&passage.text)
+    .bind(&passage.key_lesson)
+    .bind(&passage.revelation_occasion)
+    .execute(&mut *conn)
+    .await?;
+
+    sqlx::query(
+        "DELETE FROM tafsir_themes
+         WHERE tafsir_id = ? AND chapter_number = ? AND start_verse = ?",
+    )
+    .bind(tafsir_id)
+    .bind(passage.chapter_number)
+    .bind(passage.start_verse)
+    .execute(&mut *conn)
+    .await?;
+
+    for theme in &passage.themes {
+        sqlx::query(
+            "INSERT OR IGNORE INTO tafsir_themes (tafsir_id, chapter_number, start_verse, theme)
+             VALUES (?, ?, ?, ?)",
+        )
+        .bind(tafsir_id)
+        .bind(passage.chapter_number)
+        .bind(passage.start_verse)
+        .bind(theme)
+        .execute(&mut *conn)
+        .await?;
+    }
+
+    Ok(())
+}
+
+fn audio_columns(source: &AudioSource) -> (Option<&str>, Option<&[u8]>) {
+    match source {
+        AudioSource::File(path) => (Some(path.as_str()), None),
+        AudioSource::Blob(bytes) => (None, Some(bytes.as_slice())),
+    }
+}
+
+fn audio_source_from_row(
+    audio_path: Option<String>,
+    audio_blob: Option<Vec<u8>>,
+) -> anyhow::Result<AudioSource> {
+    match (audio_path, audio_blob) {
+        (_, Some(bytes)) => Ok(AudioSource::Blob(bytes)),
+        (Some(path), None) => Ok(AudioSource::File(path)),
+        (None, None) => Err(anyhow::anyhow!("Audio clip has neither a path nor a blob")),
+    }
+}
@@ -60,6 +60,24 @@ pub struct SessionItemRow {
     pub grade: i64,
     pub duration_ms: Option<i64>,
     pub completed_at: Option<i64>,
+    pub hint_level: Option<i64>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct NodeStatusRow {
+    pub status: String,
+    pub buried_until: Option<i64>, // milliseconds since epoch
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct ExerciseCheckpointRow {
+    pub session_id: String,
+    pub position: i64,
+    pub user_id: String,
+    pub exercise_type: String,
+    pub node_id: i64,
+    pub state: String,
+    pub updated_at: i64, // milliseconds since epoch
 }
 
 #[derive(Debug, Clone, FromRow)]
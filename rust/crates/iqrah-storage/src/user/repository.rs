@@ -1,15 +1,16 @@
 use super::models::{
-    BanditArmRow, MemoryBasicsRow, MemoryStateRow, ParentEnergyRow, SessionItemRow, SessionRow,
-    SessionStateRow, UserStatRow,
+    BanditArmRow, ExerciseCheckpointRow, MemoryBasicsRow, MemoryStateRow, NodeStatusRow,
+    ParentEnergyRow, SessionItemRow, SessionRow, SessionStateRow, UserStatRow,
 };
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use iqrah_core::{
     scheduler_v2::{BanditArmState, MemoryBasics},
-    MemoryState, PropagationEvent, Session, SessionItem, SessionSummary, UserRepository,
+    ExerciseCheckpoint, HintLevel, MemoryState, NodeStatus, PropagationEvent, Session, SessionItem,
+    SessionSummary, UserRepository,
 };
 use sqlx::{query_as, Sqlite, SqlitePool, Transaction};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 pub struct SqliteUserRepository {
     pool: SqlitePool,
@@ -222,7 +223,8 @@ impl SqliteUserRepository {
                 si.exercise_type as \"exercise_type!\",
                 si.grade as \"grade!\",
                 si.duration_ms as \"duration_ms?\",
-                si.completed_at as \"completed_at?\"
+                si.completed_at as \"completed_at?\",
+                si.hint_level as \"hint_level?\"
              FROM session_items si
              JOIN sessions s ON s.id = si.session_id
              WHERE s.user_id = ? AND si.completed_at IS NOT NULL AND si.completed_at > ?
@@ -243,10 +245,83 @@ impl SqliteUserRepository {
                 grade: r.grade as i32,
                 duration_ms: r.duration_ms,
                 completed_at: r.completed_at.and_then(DateTime::from_timestamp_millis),
+                hint_level: r.hint_level.and_then(|l| HintLevel::from_level(l as u8)),
             })
             .collect())
     }
 
+    /// Most recent completed session items for a node, newest first
+    pub async fn get_recent_session_items(
+        &self,
+        user_id: &str,
+        node_id: i64,
+        limit: u32,
+    ) -> anyhow::Result<Vec<SessionItem>> {
+        let rows = sqlx::query_as!(
+            SessionItemRow,
+            "SELECT
+                si.id as \"id!\",
+                si.session_id as \"session_id!\",
+                si.node_id as \"node_id!\",
+                si.exercise_type as \"exercise_type!\",
+                si.grade as \"grade!\",
+                si.duration_ms as \"duration_ms?\",
+                si.completed_at as \"completed_at?\",
+                si.hint_level as \"hint_level?\"
+             FROM session_items si
+             JOIN sessions s ON s.id = si.session_id
+             WHERE s.user_id = ? AND si.node_id = ? AND si.completed_at IS NOT NULL
+             ORDER BY si.completed_at DESC, si.id DESC
+             LIMIT ?",
+            user_id,
+            node_id,
+            limit
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|r| SessionItem {
+                id: r.id,
+                session_id: r.session_id,
+                node_id: r.node_id,
+                exercise_type: r.exercise_type,
+                grade: r.grade as i32,
+                duration_ms: r.duration_ms,
+                completed_at: r.completed_at.and_then(DateTime::from_timestamp_millis),
+                hint_level: r.hint_level.and_then(|l| HintLevel::from_level(l as u8)),
+            })
+            .collect())
+    }
+
+    /// Response times (ms) of the user's most recent correctly answered items
+    /// of an exercise type without hints, newest first. Used as the personal
+    /// latency baseline.
+    pub async fn get_response_durations(
+        &self,
+        user_id: &str,
+        exercise_type: &str,
+        limit: u32,
+    ) -> anyhow::Result<Vec<i64>> {
+        let rows = sqlx::query_scalar!(
+            "SELECT si.duration_ms as \"duration_ms!\"
+             FROM session_items si
+             JOIN sessions s ON s.id = si.session_id
+             WHERE s.user_id = ? AND si.exercise_type = ? AND si.grade > 1
+               AND si.duration_ms IS NOT NULL AND si.duration_ms > 0 AND si.hint_level IS NULL
+             ORDER BY si.completed_at DESC, si.id DESC
+             LIMIT ?",
+            user_id,
+            exercise_type,
+            limit
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows)
+    }
+
     pub async fn upsert_memory_state_if_newer(&self, state: &MemoryState) -> anyhow::Result<()> {
         let user_id = state.user_id.as_str();
         let last_reviewed = state.last_reviewed.timestamp_millis();
@@ -641,15 +716,17 @@ impl UserRepository for SqliteUserRepository {
         let session_id = item.session_id.as_str();
         let exercise_type = item.exercise_type.as_str();
         let completed_at = item.completed_at.map(|t| t.timestamp_millis());
+        let hint_level = item.hint_level.map(|l| l as i64);
         sqlx::query!(
-            "INSERT INTO session_items (session_id, node_id, exercise_type, grade, duration_ms, completed_at)
-             VALUES (?, ?, ?, ?, ?, ?)",
+            "INSERT INTO session_items (session_id, node_id, exercise_type, grade, duration_ms, completed_at, hint_level)
+             VALUES (?, ?, ?, ?, ?, ?, ?)",
             session_id,
             item.node_id,
             exercise_type,
             item.grade,
             item.duration_ms,
-            completed_at
+            completed_at,
+            hint_level
         )
         .execute(&self.pool)
         .await?;
@@ -831,6 +908,228 @@ impl UserRepository for SqliteUserRepository {
         Ok(())
     }
 
+    // ========================================================================
+    // Node Status (Suspend / Bury / Reset)
+    // ========================================================================
+
+    async fn set_node_status(
+        &self,
+        user_id: &str,
+        node_ids: &[i64],
+        status: NodeStatus,
+    ) -> anyhow::Result<()> {
+        if node_ids.is_empty() {
+            return Ok(());
+        }
+
+        let now_ms = Utc::now().timestamp_millis();
+        let (status_str, buried_until) = match status {
+            NodeStatus::Active => (None, None),
+            NodeStatus::Suspended => (Some("suspended"), None),
+            NodeStatus::BuriedUntil(until) => (Some("buried"), Some(until.timestamp_millis())),
+        };
+
+        let mut tx = self.pool.begin().await?;
+
+        for node_id in node_ids {
+            match status_str {
+                Some(status_str) => {
+                    sqlx::query!(
+                        "INSERT INTO user_node_status
+                         (user_id, content_key, status, buried_until, updated_at)
+                         VALUES (?, ?, ?, ?, ?)
+                         ON CONFLICT(user_id, content_key) DO UPDATE SET
+                            status = excluded.status,
+                            buried_until = excluded.buried_until,
+                            updated_at = excluded.updated_at",
+                        user_id,
+                        node_id,
+                        status_str,
+                        buried_until,
+                        now_ms
+                    )
+                    .execute(&mut *tx)
+                    .await?;
+                }
+                None => {
+                    sqlx::query!(
+                        "DELETE FROM user_node_status WHERE user_id = ? AND content_key = ?",
+                        user_id,
+                        node_id
+                    )
+                    .execute(&mut *tx)
+                    .await?;
+                }
+            }
+        }
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn get_node_status(&self, user_id: &str, node_id: i64) -> anyhow::Result<NodeStatus> {
+        let row = sqlx::query_as!(
+            NodeStatusRow,
+            "SELECT status, buried_until
+             FROM user_node_status
+             WHERE user_id = ? AND content_key = ?",
+            user_id,
+            node_id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(match row {
+            None => NodeStatus::Active,
+            Some(r) if r.status == "suspended" => NodeStatus::Suspended,
+            Some(r) => match r.buried_until.and_then(DateTime::from_timestamp_millis) {
+                Some(until) => NodeStatus::BuriedUntil(until),
+                None => NodeStatus::Active,
+            },
+        })
+    }
+
+    async fn get_inactive_node_ids(
+        &self,
+        user_id: &str,
+        now: DateTime<Utc>,
+    ) -> anyhow::Result<HashSet<i64>> {
+        let now_ms = now.timestamp_millis();
+        let rows = sqlx::query!(
+            "SELECT content_key
+             FROM user_node_status
+             WHERE user_id = ?
+               AND (status = 'suspended' OR (status = 'buried' AND buried_until > ?))",
+            user_id,
+            now_ms
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|r| r.content_key).collect())
+    }
+
+    async fn reset_memory_states(&self, user_id: &str, node_ids: &[i64]) -> anyhow::Result<u64> {
+        if node_ids.is_empty() {
+            return Ok(0);
+        }
+
+        // Only FSRS/energy state is cleared; session_items and propagation
+        // events are the review log and are left untouched.
+        let mut tx = self.pool.begin().await?;
+        let mut removed = 0;
+
+        for node_id in node_ids {
+            let result = sqlx::query!(
+                "DELETE FROM user_memory_states WHERE user_id = ? AND content_key = ?",
+                user_id,
+                node_id
+            )
+            .execute(&mut *tx)
+            .await?;
+            removed += result.rows_affected();
+        }
+
+        tx.commit().await?;
+        Ok(removed)
+    }
+
+    // ========================================================================
+    // Exercise Checkpoints
+    // ========================================================================
+
+    async fn save_exercise_checkpoint(
+        &self,
+        checkpoint: &ExerciseCheckpoint,
+    ) -> anyhow::Result<()> {
+        let session_id = checkpoint.session_id.as_str();
+        let user_id = checkpoint.user_id.as_str();
+        let exercise_type = checkpoint.exercise_type.as_str();
+        let state = checkpoint.state.as_str();
+        let updated_at = checkpoint.updated_at.timestamp_millis();
+        sqlx::query!(
+            "INSERT INTO exercise_checkpoints
+                (session_id, position, user_id, exercise_type, node_id, state, updated_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?)
+             ON CONFLICT(session_id, position) DO UPDATE SET
+                user_id = excluded.user_id,
+                exercise_type = excluded.exercise_type,
+                node_id = excluded.node_id,
+                state = excluded.state,
+                updated_at = excluded.updated_at",
+            session_id,
+            checkpoint.position,
+            user_id,
+            exercise_type,
+            checkpoint.node_id,
+            state,
+            updated_at
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn get_exercise_checkpoint(
+        &self,
+        session_id: &str,
+        position: i32,
+    ) -> anyhow::Result<Option<ExerciseCheckpoint>> {
+        let row = sqlx::query_as!(
+            ExerciseCheckpointRow,
+            "SELECT session_id, position, user_id, exercise_type, node_id, state, updated_at
+             FROM exercise_checkpoints
+             WHERE session_id = ? AND position = ?",
+            session_id,
+            position
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(|r| ExerciseCheckpoint {
+            session_id: r.session_id,
+            position: r.position as i32,
+            user_id: r.user_id,
+            exercise_type: r.exercise_type,
+            node_id: r.node_id,
+            state: r.state,
+            updated_at: DateTime::from_timestamp_millis(r.updated_at)
+                .unwrap_or(DateTime::UNIX_EPOCH),
+        }))
+    }
+
+    async fn delete_exercise_checkpoint(
+        &self,
+        session_id: &str,
+        position: i32,
+    ) -> anyhow::Result<()> {
+        sqlx::query!(
+            "DELETE FROM exercise_checkpoints WHERE session_id = ? AND position = ?",
+            session_id,
+            position
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn delete_exercise_checkpoints_before(
+        &self,
+        cutoff: DateTime<Utc>,
+    ) -> anyhow::Result<u64> {
+        let cutoff = cutoff.timestamp_millis();
+        let result = sqlx::query!(
+            "DELETE FROM exercise_checkpoints WHERE updated_at < ?",
+            cutoff
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
     // ========================================================================
     // Scheduler v2.0 Methods
     // ========================================================================
@@ -1,6 +1,9 @@
 use chrono::Utc;
 use iqrah_core::domain::node_id as nid;
-use iqrah_core::{ContentRepository, MemoryState, UserRepository};
+use iqrah_core::{
+    ContentRepository, ExerciseCheckpoint, HintLevel, MemoryState, NodeStatus, SessionItem,
+    UserRepository,
+};
 use iqrah_storage::{
     create_content_repository, init_test_content_db, init_user_db, SqliteUserRepository,
 };
@@ -227,6 +230,242 @@ async fn test_update_energy() {
     assert_eq!(updated.stability, 1.0); // Other fields unchanged
 }
 
+#[tokio::test]
+async fn test_node_status_suspend_bury_and_reset() {
+    let pool = init_user_db(":memory:").await.unwrap();
+    let repo = SqliteUserRepository::new(pool.clone());
+    let now = Utc::now();
+
+    // Suspend one node, bury another until tomorrow and a third until yesterday
+    repo.set_node_status("user1", &[1], NodeStatus::Suspended)
+        .await
+        .unwrap();
+    repo.set_node_status(
+        "user1",
+        &[2],
+        NodeStatus::BuriedUntil(now + chrono::Duration::days(1)),
+    )
+    .await
+    .unwrap();
+    repo.set_node_status(
+        "user1",
+        &[3],
+        NodeStatus::BuriedUntil(now - chrono::Duration::days(1)),
+    )
+    .await
+    .unwrap();
+
+    let inactive = repo.get_inactive_node_ids("user1", now).await.unwrap();
+    assert!(inactive.contains(&1));
+    assert!(inactive.contains(&2));
+    assert!(!inactive.contains(&3), "Expired burial is active again");
+    assert!(repo
+        .get_inactive_node_ids("user2", now)
+        .await
+        .unwrap()
+        .is_empty());
+
+    assert_eq!(
+        repo.get_node_status("user1", 1).await.unwrap(),
+        NodeStatus::Suspended
+    );
+
+    // Unsuspend
+    repo.set_node_status("user1", &[1], NodeStatus::Active)
+        .await
+        .unwrap();
+    assert_eq!(
+        repo.get_node_status("user1", 1).await.unwrap(),
+        NodeStatus::Active
+    );
+
+    // Reset clears memory state but keeps the review log
+    let state = MemoryState {
+        user_id: "user1".to_string(),
+        node_id: 1,
+        stability: 4.0,
+        difficulty: 6.0,
+        energy: 0.7,
+        last_reviewed: now,
+        due_at: now,
+        review_count: 5,
+    };
+    repo.save_memory_state(&state).await.unwrap();
+    sqlx::query(
+        "INSERT INTO sessions (id, user_id, goal_id, started_at, items_count, items_completed)
+         VALUES ('s1', 'user1', 'g', 0, 1, 1)",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+    sqlx::query(
+        "INSERT INTO session_items (session_id, node_id, exercise_type, grade)
+         VALUES ('s1', 1, 'memorization', 3)",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let removed = repo.reset_memory_states("user1", &[1, 99]).await.unwrap();
+    assert_eq!(removed, 1);
+    assert!(repo.get_memory_state("user1", 1).await.unwrap().is_none());
+
+    let log_count: i64 = sqlx::query("SELECT COUNT(*) AS c FROM session_items WHERE node_id = 1")
+        .fetch_one(&pool)
+        .await
+        .unwrap()
+        .get("c");
+    assert_eq!(log_count, 1, "Review log must survive a reset");
+}
+
+#[tokio::test]
+async fn test_response_durations_for_baseline() {
+    let pool = init_user_db(":memory:").await.unwrap();
+    let repo = SqliteUserRepository::new(pool.clone());
+    sqlx::query(
+        "INSERT INTO sessions (id, user_id, goal_id, started_at, items_count, items_completed)
+         VALUES ('s1', 'user1', 'g', 0, 5, 5), ('s2', 'user2', 'g', 0, 1, 1)",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+    for (session, exercise_type, grade, duration, completed_at) in [
+        ("s1", "memorization", 3, Some(2000), 1),
+        ("s1", "memorization", 4, Some(1500), 2),
+        ("s1", "memorization", 1, Some(9000), 3), // wrong answer
+        ("s1", "memorization", 3, None, 4),       // no timing
+        ("s1", "translation", 3, Some(4000), 5),
+        ("s2", "memorization", 3, Some(500), 6), // other user
+    ] {
+        sqlx::query(
+            "INSERT INTO session_items (session_id, node_id, exercise_type, grade, duration_ms, completed_at)
+             VALUES (?, 1, ?, ?, ?, ?)",
+        )
+        .bind(session)
+        .bind(exercise_type)
+        .bind(grade)
+        .bind(duration)
+        .bind(completed_at)
+        .execute(&pool)
+        .await
+        .unwrap();
+    }
+
+    let durations = repo
+        .get_response_durations("user1", "memorization", 50)
+        .await
+        .unwrap();
+    assert_eq!(durations, vec![1500, 2000], "Newest correct, timed answers");
+    assert_eq!(
+        repo.get_response_durations("user1", "memorization", 1)
+            .await
+            .unwrap(),
+        vec![1500]
+    );
+}
+
+#[tokio::test]
+async fn test_session_item_hint_level() {
+    let pool = init_user_db(":memory:").await.unwrap();
+    let repo = SqliteUserRepository::new(pool.clone());
+    sqlx::query(
+        "INSERT INTO sessions (id, user_id, goal_id, started_at, items_count, items_completed)
+         VALUES ('s1', 'user1', 'g', 0, 2, 2)",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    for (hint_level, duration_ms, completed_at) in
+        [(None, 2000, 1), (Some(HintLevel::Translation), 8000, 2)]
+    {
+        let item = SessionItem {
+            id: 0,
+            session_id: "s1".to_string(),
+            node_id: 7,
+            exercise_type: "memorization".to_string(),
+            grade: 2,
+            duration_ms: Some(duration_ms),
+            completed_at: chrono::DateTime::from_timestamp_millis(completed_at),
+            hint_level,
+        };
+        repo.insert_session_item(&item).await.unwrap();
+    }
+
+    let items = repo.get_recent_session_items("user1", 7, 10).await.unwrap();
+    let levels: Vec<_> = items.iter().map(|i| i.hint_level).collect();
+    assert_eq!(levels, vec![Some(HintLevel::Translation), None]);
+
+    let durations = repo
+        .get_response_durations("user1", "memorization", 50)
+        .await
+        .unwrap();
+    assert_eq!(
+        durations,
+        vec![2000],
+        "Hinted answers are not in the baseline"
+    );
+}
+
+#[tokio::test]
+async fn test_exercise_checkpoints() {
+    let pool = init_user_db(":memory:").await.unwrap();
+    let repo = SqliteUserRepository::new(pool);
+    let now = Utc::now();
+
+    let checkpoint = |position: i32, state: &str, updated_at| ExerciseCheckpoint {
+        session_id: "s1".to_string(),
+        position,
+        user_id: "user1".to_string(),
+        exercise_type: "echo_recall".to_string(),
+        node_id: 42,
+        state: state.to_string(),
+        updated_at,
+    };
+    repo.save_exercise_checkpoint(&checkpoint(0, "{\"step\":1}", now))
+        .await
+        .unwrap();
+    // Saving the same item again replaces its state
+    repo.save_exercise_checkpoint(&checkpoint(0, "{\"step\":2}", now))
+        .await
+        .unwrap();
+    repo.save_exercise_checkpoint(&checkpoint(1, "{}", now - chrono::Duration::days(30)))
+        .await
+        .unwrap();
+
+    let saved = repo
+        .get_exercise_checkpoint("s1", 0)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(saved.state, "{\"step\":2}");
+    assert_eq!(saved.node_id, 42);
+    assert_eq!(saved.updated_at.timestamp_millis(), now.timestamp_millis());
+    assert!(repo
+        .get_exercise_checkpoint("s2", 0)
+        .await
+        .unwrap()
+        .is_none());
+
+    let expired = repo
+        .delete_exercise_checkpoints_before(now - chrono::Duration::days(7))
+        .await
+        .unwrap();
+    assert_eq!(expired, 1);
+    assert!(repo
+        .get_exercise_checkpoint("s1", 1)
+        .await
+        .unwrap()
+        .is_none());
+
+    repo.delete_exercise_checkpoint("s1", 0).await.unwrap();
+    assert!(repo
+        .get_exercise_checkpoint("s1", 0)
+        .await
+        .unwrap()
+        .is_none());
+}
+
 #[tokio::test]
 async fn test_two_database_integration() {
     // This test demonstrates the two-database architecture working together with v2 schema
@@ -335,6 +574,288 @@ async fn test_v2_verse_queries() {
     assert!(verse.is_none(), "Non-existent verse should return None");
 }
 
+#[tokio::test]
+async fn test_similar_verses_and_mutashabihat_exercise() {
+    let pool = init_test_content_db(":memory:").await.unwrap();
+    for (verse_key, similar_verse_key, similarity) in [
+        ("1:1", "1:3", 0.5),
+        ("1:1", "1:2", 0.25),
+        ("1:3", "1:1", 0.5),
+    ] {
+        sqlx::query(
+            "INSERT INTO mutashabihat (verse_key, similar_verse_key, shared_prefix_words, similarity)
+             VALUES (?, ?, 0, ?)",
+        )
+        .bind(verse_key)
+        .bind(similar_verse_key)
+        .bind(similarity)
+        .execute(&pool)
+        .await
+        .unwrap();
+    }
+    let repo = create_content_repository(pool.clone());
+
+    let similar = repo.get_similar_verses("1:1", 10).await.unwrap();
+    let keys: Vec<&str> = similar.iter().map(|s| s.verse_key.as_str()).collect();
+    assert_eq!(keys, vec!["1:3", "1:2"], "Most similar first");
+    assert!(repo.get_similar_verses("1:4", 10).await.unwrap().is_empty());
+
+    let verse_id = nid::encode_verse(1, 1);
+    let err = iqrah_core::exercises::generate_mutashabihat(verse_id, "VERSE:1:1", 0, &repo)
+        .await
+        .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("No distinguishable similar verses"),
+        "Verses differing from the first word share no opening: {err}"
+    );
+
+    // Give 1:2 the opening of 1:1 so that it can be told apart at word 3
+    let uthmani_id: i64 =
+        sqlx::query_scalar("SELECT resource_id FROM script_resources WHERE slug = 'uthmani'")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    for (position, text) in [(1, "بِسْمِ"), (2, "ٱللَّهِ"), (3, "رَبِّ")] {
+        let word_id: i64 = sqlx::query_scalar(
+            "INSERT INTO words (verse_key, position, letter_count) VALUES ('1:2', ?, 3)
+             RETURNING word_id",
+        )
+        .bind(position)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        let node_id = nid::encode_word(word_id);
+        sqlx::query("INSERT INTO nodes (id, ukey, node_type) VALUES (?, 'WORD:' || ?, 3)")
+            .bind(node_id)
+            .bind(word_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query(
+            "INSERT INTO script_contents (resource_id, node_id, text_content) VALUES (?, ?, ?)",
+        )
+        .bind(uthmani_id)
+        .bind(node_id)
+        .bind(text)
+        .execute(&pool)
+        .await
+        .unwrap();
+    }
+
+    let exercise = iqrah_core::exercises::generate_mutashabihat(verse_id, "VERSE:1:1", 0, &repo)
+        .await
+        .unwrap();
+    let iqrah_core::exercises::ExerciseData::Mutashabihat {
+        verse_key,
+        prompt_word_count,
+        option_verse_keys,
+        differing_positions,
+        ..
+    } = exercise
+    else {
+        panic!("Expected Mutashabihat exercise");
+    };
+    assert_eq!(verse_key, "1:1");
+    assert_eq!(option_verse_keys.len(), 2, "1:3 shares no opening");
+    assert_eq!(
+        prompt_word_count, 2,
+        "Both verses open with two shared words"
+    );
+    let correct = option_verse_keys.iter().position(|k| k == "1:1").unwrap();
+    assert_eq!(differing_positions[correct], 0);
+    let sibling = option_verse_keys.iter().position(|k| k == "1:2").unwrap();
+    assert_eq!(differing_positions[sibling], 3);
+}
+
+#[tokio::test]
+async fn test_location_exercises_from_sample_data() {
+    use iqrah_core::exercises::{
+        generate_identify_page_half, generate_identify_surah, generate_identify_verse_number,
+        ExerciseData,
+    };
+
+    let pool = init_test_content_db(":memory:").await.unwrap();
+    let repo = create_content_repository(pool);
+    let verse_id = nid::encode_verse(1, 3);
+
+    let ExerciseData::IdentifySurah {
+        correct_chapter,
+        chapter_options,
+        ..
+    } = generate_identify_surah(verse_id, "VERSE:1:3", 0, &repo)
+        .await
+        .unwrap()
+    else {
+        panic!("Expected IdentifySurah exercise");
+    };
+    assert_eq!(correct_chapter, 1);
+    assert!(chapter_options.contains(&1));
+    assert!(chapter_options.len() >= 2, "Neighboring surahs offered");
+
+    let ExerciseData::IdentifyVerseNumber {
+        correct_verse_number,
+        verse_number_options,
+        ..
+    } = generate_identify_verse_number(verse_id, "VERSE:1:3", 0, &repo)
+        .await
+        .unwrap()
+    else {
+        panic!("Expected IdentifyVerseNumber exercise");
+    };
+    assert_eq!(correct_verse_number, 3);
+    assert_eq!(verse_number_options.len(), 4);
+    assert!(verse_number_options.contains(&3));
+    assert!(verse_number_options.iter().all(|n| (1..=7).contains(n)));
+
+    let ExerciseData::IdentifyPageHalf { page, half, .. } =
+        generate_identify_page_half(verse_id, "VERSE:1:3", &repo)
+            .await
+            .unwrap()
+    else {
+        panic!("Expected IdentifyPageHalf exercise");
+    };
+    assert_eq!(page, 1);
+    assert!(half == 1 || half == 2);
+}
+
+#[tokio::test]
+async fn test_mushaf_layout_and_page_exercises() {
+    use iqrah_core::exercises::{
+        generate_identify_page_half, generate_identify_verse_line, generate_page_first_word,
+        generate_recite_page, AnswerInput, DefaultValidator, ExerciseData, ExerciseValidator,
+    };
+
+    let pool = init_test_content_db(":memory:").await.unwrap();
+    let word = nid::encode_word_instance;
+    let mut lines = vec![
+        (1, 1, "surah_name", None, None),
+        (1, 2, "ayah", Some(word(1, 1, 1)), Some(word(1, 1, 4))),
+        (1, 3, "ayah", Some(word(1, 2, 1)), Some(word(1, 3, 2))),
+    ];
+    // One line on each of the nearby pages offered as distractors
+    for page in 2..=7 {
+        let verse = page as u16;
+        lines.push((
+            page,
+            1,
+            "ayah",
+            Some(word(2, verse, 1)),
+            Some(word(2, verse, 5)),
+        ));
+    }
+    // A verse starting in the bottom half of page 2
+    lines.push((2, 12, "ayah", Some(word(2, 30, 1)), Some(word(2, 30, 9))));
+    for (page, line_number, line_type, first, last) in lines {
+        sqlx::query(
+            "INSERT INTO mushaf_lines (page, line_number, line_type, first_word_id, last_word_id)
+             VALUES (?, ?, ?, ?, ?)",
+        )
+        .bind(page)
+        .bind(line_number)
+        .bind(line_type)
+        .bind(first)
+        .bind(last)
+        .execute(&pool)
+        .await
+        .unwrap();
+    }
+    let repo = create_content_repository(pool);
+
+    let lines = repo.get_mushaf_page(1).await.unwrap();
+    assert_eq!(lines.len(), 3);
+    assert_eq!(lines[0].line_type, "surah_name");
+    assert_eq!(lines[0].first_word_id, None);
+    let line = repo
+        .get_mushaf_line_for_word(word(1, 3, 1))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!((line.page, line.line_number), (1, 3));
+    assert!(repo
+        .get_mushaf_line_for_word(word(1, 7, 1))
+        .await
+        .unwrap()
+        .is_none());
+
+    let verse_id = nid::encode_verse(1, 1);
+    let recite = generate_recite_page(verse_id, "VERSE:1:1", &repo)
+        .await
+        .unwrap();
+    let ExerciseData::RecitePage {
+        page, verse_keys, ..
+    } = &recite
+    else {
+        panic!("Expected RecitePage exercise");
+    };
+    assert_eq!(*page, 1);
+    assert_eq!(verse_keys, &["1:1", "1:2", "1:3"]);
+
+    // Only verse 1:1 has words in the sample data
+    let validator = DefaultValidator::new();
+    let answer = AnswerInput::Text {
+        value: "بِسْمِ ٱللَّهِ ٱلرَّحْمَٰنِ ٱلرَّحِيمِ".to_string(),
+    };
+    let result = validator.validate(&recite, &answer, &repo).await.unwrap();
+    assert!(result.is_correct);
+
+    let first_word = generate_page_first_word(verse_id, "VERSE:1:1", 0, &repo)
+        .await
+        .unwrap();
+    let ExerciseData::PageFirstWord {
+        first_word_node_id,
+        distractor_node_ids,
+        ..
+    } = &first_word
+    else {
+        panic!("Expected PageFirstWord exercise");
+    };
+    assert_eq!(*first_word_node_id, word(1, 1, 1));
+    assert_eq!(distractor_node_ids.len(), 3);
+    assert!(distractor_node_ids
+        .iter()
+        .all(|id| nid::decode_word_instance(*id).is_some_and(|(c, _, p)| c == 2 && p == 1)));
+    let pick = AnswerInput::WordId {
+        value: word(1, 1, 1),
+    };
+    assert!(
+        validator
+            .validate(&first_word, &pick, &repo)
+            .await
+            .unwrap()
+            .is_correct
+    );
+
+    let ExerciseData::IdentifyVerseLine {
+        page,
+        correct_line,
+        line_options,
+        ..
+    } = generate_identify_verse_line(nid::encode_verse(1, 2), "VERSE:1:2", 0, &repo)
+        .await
+        .unwrap()
+    else {
+        panic!("Expected IdentifyVerseLine exercise");
+    };
+    assert_eq!((page, correct_line), (1, 3));
+    assert_eq!(line_options.len(), 4);
+    assert!(line_options.contains(&3));
+    assert!(line_options.iter().all(|n| (1..=15).contains(n)));
+
+    // The page half follows the line the verse starts on
+    for (chapter, verse, expected) in [(1, 1, (1, 1)), (2, 30, (2, 2))] {
+        let ukey = format!("VERSE:{}:{}", chapter, verse);
+        let ExerciseData::IdentifyPageHalf { page, half, .. } =
+            generate_identify_page_half(nid::encode_verse(chapter, verse), &ukey, &repo)
+                .await
+                .unwrap()
+        else {
+            panic!("Expected IdentifyPageHalf exercise");
+        };
+        assert_eq!((page, half), expected, "{}", ukey);
+    }
+}
+
 #[tokio::test]
 async fn test_v2_word_queries() {
     let pool = init_test_content_db(":memory:").await.unwrap();
@@ -369,6 +890,148 @@ async fn test_v2_word_queries() {
     assert!(word.is_none(), "Non-existent word should return None");
 }
 
+#[tokio::test]
+async fn test_root_family_and_lemma_frequencies() {
+    let pool = init_test_content_db(":memory:").await.unwrap();
+    let repo = create_content_repository(pool.clone());
+    let words = repo.get_words_for_verse("1:1").await.unwrap();
+
+    sqlx::query("INSERT INTO roots (root_id, arabic) VALUES ('r-ḥ-m', 'رحم')")
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query(
+        "INSERT INTO lemmas (lemma_id, arabic, root_id) VALUES ('raḥmān', 'رحمن', 'r-ḥ-m')",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+    // "ٱلرَّحْمَٰنِ" and "ٱلرَّحِيمِ" share the root; only the first has the lemma
+    for (word, lemma) in [(&words[2], Some("raḥmān")), (&words[3], None)] {
+        sqlx::query(
+            "INSERT INTO morphology_segments (word_id, position, lemma_id, root_id, pos_tag)
+             VALUES (?, 1, ?, 'r-ḥ-m', 'ADJ')",
+        )
+        .bind(word.id)
+        .bind(lemma)
+        .execute(&pool)
+        .await
+        .unwrap();
+    }
+
+    let family = repo.get_words_for_root("r-ḥ-m", 10).await.unwrap();
+    let ids: Vec<i64> = family.iter().map(|w| w.id).collect();
+    assert_eq!(ids, vec![words[2].id, words[3].id]);
+    assert_eq!(repo.get_words_for_root("r-ḥ-m", 1).await.unwrap().len(), 1);
+    assert!(repo
+        .get_words_for_root("k-t-b", 10)
+        .await
+        .unwrap()
+        .is_empty());
+
+    let frequencies = repo
+        .get_lemma_frequencies(&["raḥmān".to_string(), "unknown".to_string()])
+        .await
+        .unwrap();
+    assert_eq!(frequencies.get("raḥmān"), Some(&1));
+    assert!(!frequencies.contains_key("unknown"));
+    assert!(repo.get_lemma_frequencies(&[]).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_morphology_features() {
+    let pool = init_test_content_db(":memory:").await.unwrap();
+    let repo = create_content_repository(pool.clone());
+    let words = repo.get_words_for_verse("1:1").await.unwrap();
+
+    sqlx::query(
+        "INSERT INTO morphology_segments (word_id, position, pos_tag, state)
+         VALUES (?1, 1, 'DET', 'definite'), (?1, 2, 'ADJ', NULL)",
+    )
+    .bind(words[3].id)
+    .execute(&pool)
+    .await
+    .unwrap();
+    sqlx::query(
+        "UPDATE morphology_segments
+         SET gender = 'masculine', number = 'singular', grammatical_case = 'genitive'
+         WHERE position = 2",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let segments = repo.get_morphology_for_word(words[3].id).await.unwrap();
+    assert_eq!(segments.len(), 2);
+    assert_eq!(segments[0].features.state.as_deref(), Some("definite"));
+    let stem = &segments[1].features;
+    assert_eq!(stem.gender.as_deref(), Some("masculine"));
+    assert_eq!(stem.number.as_deref(), Some("singular"));
+    assert_eq!(stem.case.as_deref(), Some("genitive"));
+    assert_eq!(stem.verb_form, None);
+}
+
+#[tokio::test]
+async fn test_root_occurrences_and_lemmas_for_root() {
+    let pool = init_test_content_db(":memory:").await.unwrap();
+    let repo = create_content_repository(pool.clone());
+    let words = repo.get_words_for_verse("1:1").await.unwrap();
+
+    sqlx::query("INSERT INTO roots (root_id, arabic) VALUES ('r-ḥ-m', 'رحم'), ('s-m-w', 'سمو')")
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query(
+        "INSERT INTO lemmas (lemma_id, arabic, root_id, description)
+         VALUES ('raḥmān', 'رحمن', 'r-ḥ-m', 'Most Gracious'),
+                ('raḥīm', 'رحيم', 'r-ḥ-m', 'Most Merciful'),
+                ('ism', 'اسم', 's-m-w', 'name')",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+    for (word, lemma, root) in [
+        (&words[0], "ism", "s-m-w"),
+        (&words[2], "raḥmān", "r-ḥ-m"),
+        (&words[3], "raḥīm", "r-ḥ-m"),
+    ] {
+        sqlx::query(
+            "INSERT INTO morphology_segments (word_id, position, lemma_id, root_id, pos_tag)
+             VALUES (?, 1, ?, ?, 'N')",
+        )
+        .bind(word.id)
+        .bind(lemma)
+        .bind(root)
+        .execute(&pool)
+        .await
+        .unwrap();
+    }
+
+    let roots = repo
+        .get_root_occurrences_in_verses(&["1:1".to_string()])
+        .await
+        .unwrap();
+    assert_eq!(roots.len(), 2);
+    assert_eq!(roots[0].root_id, "r-ḥ-m");
+    assert_eq!(roots[0].occurrences, 2);
+    assert_eq!(roots[0].word_ids, vec![words[2].id, words[3].id]);
+    assert_eq!(roots[1].word_ids, vec![words[0].id]);
+    assert!(repo
+        .get_root_occurrences_in_verses(&["1:2".to_string()])
+        .await
+        .unwrap()
+        .is_empty());
+
+    let lemmas = repo.get_lemmas_for_root("r-ḥ-m").await.unwrap();
+    let ids: Vec<&str> = lemmas.iter().map(|l| l.lemma.lemma_id.as_str()).collect();
+    assert_eq!(ids, vec!["raḥmān", "raḥīm"]);
+    assert_eq!(lemmas[0].occurrences, 1);
+    assert_eq!(
+        lemmas[1].lemma.description.as_deref(),
+        Some("Most Merciful")
+    );
+}
+
 #[tokio::test]
 async fn test_v2_language_queries() {
     let pool = init_test_content_db(":memory:").await.unwrap();
@@ -462,6 +1125,32 @@ async fn test_script_content_variants() {
     assert!(simple.unwrap().contains("بسم الله"));
 }
 
+#[tokio::test]
+async fn test_batch_script_contents_and_ayah_words() {
+    let pool = init_test_content_db(":memory:").await.unwrap();
+    let repo = create_content_repository(pool);
+    let verse_ids = [nid::encode_verse(1, 2), nid::encode_verse(1, 1)];
+
+    let texts = repo
+        .get_script_contents_batch(&[verse_ids[0], verse_ids[1], 42], "simple")
+        .await
+        .unwrap();
+    assert_eq!(texts.len(), 2);
+    assert_eq!(texts[&verse_ids[1]], "بسم الله الرحمن الرحيم");
+    assert!(repo
+        .get_script_contents_batch(&[], "uthmani")
+        .await
+        .unwrap()
+        .is_empty());
+
+    // Verse 1:2 has no words in the sample data; 1:1 keeps mushaf order
+    let words_1_1 = repo.get_words_for_verse("1:1").await.unwrap();
+    let nodes = repo.get_words_in_ayahs(&verse_ids).await.unwrap();
+    let ids: Vec<i64> = nodes.iter().map(|n| n.id).collect();
+    let expected: Vec<i64> = words_1_1.iter().map(|w| w.id).collect();
+    assert_eq!(ids, expected);
+}
+
 #[tokio::test]
 async fn test_v2_translation_queries() {
     let pool = init_test_content_db(":memory:").await.unwrap();
@@ -593,3 +1282,828 @@ async fn test_v2_schema_version_validation() {
         "Schema version should be 2.1.0 for v2 database"
     );
 }
+
+/// Write a package database with the given schema and rows
+async fn write_package_db(path: &std::path::Path, statements: &[&str]) -> Vec<u8> {
+    let options = sqlx::sqlite::SqliteConnectOptions::new()
+        .filename(path)
+        .create_if_missing(true);
+    let pool = sqlx::SqlitePool::connect_with(options).await.unwrap();
+    for statement in statements {
+        sqlx::query(statement).execute(&pool).await.unwrap();
+    }
+    pool.close().await;
+    std::fs::read(path).unwrap()
+}
+
+fn audio_package(
+    package_id: &str,
+    package_type: iqrah_core::PackageType,
+) -> iqrah_core::ContentPackage {
+    iqrah_core::ContentPackage {
+        package_id: package_id.to_string(),
+        package_type,
+        name: "Test Recitation".to_string(),
+        language_code: None,
+        author: Some("Test Reciter".to_string()),
+        version: "1.0".to_string(),
+        description: None,
+        file_size: None,
+        download_url: None,
+        checksum: None,
+        license: None,
+    }
+}
+
+#[tokio::test]
+async fn test_install_verse_recitation_package() {
+    use iqrah_core::{AudioSource, PackageService, PackageType};
+    use std::sync::Arc;
+
+    let dir = tempfile::TempDir::new().unwrap();
+    let pool = init_test_content_db(":memory:").await.unwrap();
+    let repo: Arc<dyn ContentRepository> = Arc::new(create_content_repository(pool));
+    let service = PackageService::new(Arc::clone(&repo));
+    service
+        .add_to_catalog(&audio_package(
+            "recitation-test",
+            PackageType::VerseRecitation,
+        ))
+        .await
+        .unwrap();
+
+    let package = write_package_db(
+        &dir.path().join("recitation.db"),
+        &[
+            "CREATE TABLE verse_audio (verse_key TEXT, audio BLOB, audio_path TEXT, mime_type TEXT, duration_ms INTEGER)",
+            "CREATE TABLE word_segments (verse_key TEXT, position INTEGER, start_ms INTEGER, end_ms INTEGER)",
+            "INSERT INTO verse_audio VALUES ('1:1', X'494433', NULL, 'audio/mpeg', 4000)",
+            "INSERT INTO verse_audio VALUES ('1:2', NULL, 'https://cdn.example/001002.mp3', 'audio/mpeg', 3500)",
+            "INSERT INTO word_segments VALUES ('1:1', 2, 900, 1500), ('1:1', 1, 0, 900)",
+        ],
+    )
+    .await;
+    service
+        .install_package("recitation-test", package)
+        .await
+        .unwrap();
+
+    let reciters = repo.get_reciters().await.unwrap();
+    assert_eq!(reciters.len(), 1);
+    assert_eq!(reciters[0].name, "Test Reciter");
+    let reciter_id = reciters[0].reciter_id;
+
+    let verse = repo
+        .get_verse_recitation("1:1", reciter_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(verse.source, AudioSource::Blob(b"ID3".to_vec()));
+    assert_eq!(verse.duration_ms, Some(4000));
+    let positions: Vec<i32> = verse.segments.iter().map(|s| s.position).collect();
+    assert_eq!(positions, vec![1, 2]);
+
+    // Words without their own clip play their segment of the verse
+    let word = repo
+        .get_word_audio("1:1", 2, reciter_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!((word.start_ms, word.end_ms), (Some(900), Some(1500)));
+    assert_eq!(word.source, verse.source);
+    assert!(repo
+        .get_word_audio("1:1", 3, reciter_id)
+        .await
+        .unwrap()
+        .is_none());
+
+    let verse_2 = repo
+        .get_verse_recitation("1:2", reciter_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        verse_2.source,
+        AudioSource::File("https://cdn.example/001002.mp3".to_string())
+    );
+    assert!(verse_2.segments.is_empty());
+
+    // Disabled packages hide their reciters; uninstalling removes the audio
+    service.disable_package("recitation-test").await.unwrap();
+    assert!(repo.get_reciters().await.unwrap().is_empty());
+    service.uninstall_package("recitation-test").await.unwrap();
+    assert!(repo
+        .get_verse_recitation("1:1", reciter_id)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_install_recitation_package_resolves_relative_audio_paths() {
+    use iqrah_core::{AudioSource, PackageService, PackageType};
+    use std::sync::Arc;
+
+    let dir = tempfile::TempDir::new().unwrap();
+    let pool = init_test_content_db(":memory:").await.unwrap();
+    let repo: Arc<dyn ContentRepository> = Arc::new(create_content_repository(pool));
+    let service = PackageService::new(Arc::clone(&repo));
+    service
+        .add_to_catalog(&audio_package(
+            "recitation-test",
+            PackageType::VerseRecitation,
+        ))
+        .await
+        .unwrap();
+
+    let package_path = dir.path().join("recitation.db");
+    let package = write_package_db(
+        &package_path,
+        &[
+            "CREATE TABLE verse_audio (verse_key TEXT, audio BLOB, audio_path TEXT, mime_type TEXT, duration_ms INTEGER)",
+            "CREATE TABLE word_segments (verse_key TEXT, position INTEGER, start_ms INTEGER, end_ms INTEGER)",
+            "INSERT INTO verse_audio VALUES ('1:1', NULL, 'audio/001001.mp3', 'audio/mpeg', 4000)",
+        ],
+    )
+    .await;
+
+    // Downloaded data has no directory for the path to point into
+    assert!(service
+        .install_package("recitation-test", package)
+        .await
+        .is_err());
+
+    service
+        .install_package_from_file("recitation-test", &package_path)
+        .await
+        .unwrap();
+    let reciter_id = repo.get_reciters().await.unwrap()[0].reciter_id;
+    let verse = repo
+        .get_verse_recitation("1:1", reciter_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        verse.source,
+        AudioSource::File(
+            dir.path()
+                .join("audio/001001.mp3")
+                .to_string_lossy()
+                .into_owned()
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_install_word_audio_zip_package() {
+    use iqrah_core::{AudioSource, PackageService, PackageType};
+    use std::sync::Arc;
+
+    let dir = tempfile::TempDir::new().unwrap();
+    let audio_dir = dir.path().join("audio");
+    let pool = init_test_content_db(":memory:").await.unwrap();
+    let repo: Arc<dyn ContentRepository> = Arc::new(create_content_repository(pool));
+    let service = PackageService::new(Arc::clone(&repo)).with_audio_dir(&audio_dir);
+    service
+        .add_to_catalog(&audio_package("word-audio-test", PackageType::WordAudio))
+        .await
+        .unwrap();
+
+    let package_db = write_package_db(
+        &dir.path().join("word_audio.db"),
+        &[
+            "CREATE TABLE word_audio (verse_key TEXT, position INTEGER, audio BLOB, audio_path TEXT, mime_type TEXT, duration_ms INTEGER)",
+            "INSERT INTO word_audio VALUES ('1:1', 1, NULL, 'words/001_001_001.mp3', 'audio/mpeg', 700)",
+        ],
+    )
+    .await;
+    let zip_path = dir.path().join("word-audio-test.zip");
+    write_zip_package(
+        &zip_path,
+        &package_db,
+        &[("words/001_001_001.mp3", b"bismi")],
+    );
+
+    service
+        .install_package_from_file("word-audio-test", &zip_path)
+        .await
+        .unwrap();
+
+    let reciter_id = repo.get_reciters().await.unwrap()[0].reciter_id;
+    let clip = repo
+        .get_word_audio("1:1", 1, reciter_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!((clip.start_ms, clip.end_ms), (None, None));
+    let AudioSource::File(path) = &clip.source else {
+        panic!("Expected extracted audio file, got {:?}", clip.source);
+    };
+    assert!(path.starts_with(audio_dir.join("word-audio-test").to_str().unwrap()));
+    assert_eq!(std::fs::read(path).unwrap(), b"bismi");
+
+    service.uninstall_package("word-audio-test").await.unwrap();
+    assert!(!audio_dir.join("word-audio-test").exists());
+}
+
+/// Zip package holding `package_db` and the given audio files
+fn write_zip_package(path: &std::path::Path, package_db: &[u8], files: &[(&str, &[u8])]) {
+    use std::io::Write;
+
+    let mut zip = zip::ZipWriter::new(std::fs::File::create(path).unwrap());
+    let options = zip::write::SimpleFileOptions::default();
+    zip.start_file("package.db", options).unwrap();
+    zip.write_all(package_db).unwrap();
+    for (name, bytes) in files {
+        zip.start_file(*name, options).unwrap();
+        zip.write_all(bytes).unwrap();
+    }
+    zip.finish().unwrap();
+}
+
+#[tokio::test]
+async fn test_failed_package_install_leaves_nothing_behind() {
+    use iqrah_core::{PackageService, PackageType};
+    use std::sync::Arc;
+
+    let dir = tempfile::TempDir::new().unwrap();
+    let audio_dir = dir.path().join("audio");
+    let pool = init_test_content_db(":memory:").await.unwrap();
+    let repo: Arc<dyn ContentRepository> = Arc::new(create_content_repository(pool));
+    let service = PackageService::new(Arc::clone(&repo)).with_audio_dir(&audio_dir);
+    service
+        .add_to_catalog(&audio_package("rollback-test", PackageType::WordAudio))
+        .await
+        .unwrap();
+
+    // The second clip is for a verse that is not in the content database
+    let package_db = write_package_db(
+        &dir.path().join("word_audio.db"),
+        &[
+            "CREATE TABLE word_audio (verse_key TEXT, position INTEGER, audio BLOB, audio_path TEXT, mime_type TEXT, duration_ms INTEGER)",
+            "INSERT INTO word_audio VALUES ('1:1', 1, NULL, 'words/001_001_001.mp3', 'audio/mpeg', 700)",
+            "INSERT INTO word_audio VALUES ('9:999', 1, NULL, 'words/009_999_001.mp3', 'audio/mpeg', 700)",
+        ],
+    )
+    .await;
+    let zip_path = dir.path().join("rollback-test.zip");
+    write_zip_package(
+        &zip_path,
+        &package_db,
+        &[
+            ("words/001_001_001.mp3", b"bismi"),
+            ("words/009_999_001.mp3", b"?"),
+        ],
+    );
+
+    assert!(service
+        .install_package_from_file("rollback-test", &zip_path)
+        .await
+        .is_err());
+
+    // The reciter and the first clip were rolled back with the second clip
+    assert!(repo.get_reciters().await.unwrap().is_empty());
+    assert!(!service.is_installed("rollback-test").await.unwrap());
+    assert!(!audio_dir.join("rollback-test").exists());
+    let leftover_temp_files = std::fs::read_dir(std::env::temp_dir())
+        .unwrap()
+        .filter_map(Result::ok)
+        .filter(|entry| {
+            entry
+                .file_name()
+                .to_string_lossy()
+                .starts_with("iqrah-package-rollback-test-")
+        })
+        .count();
+    assert_eq!(leftover_temp_files, 0);
+}
+
+#[tokio::test]
+async fn test_install_rejects_audio_paths_outside_the_package() {
+    use iqrah_core::{PackageService, PackageType};
+    use std::sync::Arc;
+
+    let dir = tempfile::TempDir::new().unwrap();
+    let pool = init_test_content_db(":memory:").await.unwrap();
+    let repo: Arc<dyn ContentRepository> = Arc::new(create_content_repository(pool));
+    let service = PackageService::new(Arc::clone(&repo)).with_audio_dir(dir.path().join("audio"));
+    service
+        .add_to_catalog(&audio_package(
+            "recitation-test",
+            PackageType::VerseRecitation,
+        ))
+        .await
+        .unwrap();
+
+    for (i, audio_path) in ["/etc/passwd", "../001001.mp3", "audio/../../001001.mp3"]
+        .into_iter()
+        .enumerate()
+    {
+        let package_path = dir.path().join(format!("recitation-{i}.db"));
+        let package_db = write_package_db(
+            &package_path,
+            &[
+                "CREATE TABLE verse_audio (verse_key TEXT, audio BLOB, audio_path TEXT, mime_type TEXT, duration_ms INTEGER)",
+                "CREATE TABLE word_segments (verse_key TEXT, position INTEGER, start_ms INTEGER, end_ms INTEGER)",
+                &format!(
+                    "INSERT INTO verse_audio VALUES ('1:1', NULL, '{audio_path}', 'audio/mpeg', 4000)"
+                ),
+            ],
+        )
+        .await;
+        let err = service
+            .install_package_from_file("recitation-test", &package_path)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("Invalid audio path"), "{err}");
+
+        let zip_path = dir.path().join(format!("recitation-{i}.zip"));
+        write_zip_package(&zip_path, &package_db, &[(audio_path, b"ID3")]);
+        let err = service
+            .install_package_from_file("recitation-test", &zip_path)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("Invalid audio path"), "{err}");
+    }
+    assert!(repo.get_reciters().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_listen_exercises_from_recitation_timings() {
+    use iqrah_core::exercises::{
+        generate_listen_continue, generate_listen_identify_verse, generate_listen_identify_word,
+        AnswerInput, DefaultValidator, DistractorDifficulty, ExerciseData, ExerciseValidator,
+        SemanticScoring,
+    };
+    use iqrah_core::{PackageService, PackageType};
+    use std::sync::Arc;
+
+    let dir = tempfile::TempDir::new().unwrap();
+    let pool = init_test_content_db(":memory:").await.unwrap();
+    let repo: Arc<dyn ContentRepository> = Arc::new(create_content_repository(pool));
+    let service = PackageService::new(Arc::clone(&repo));
+    service
+        .add_to_catalog(&audio_package(
+            "recitation-test",
+            PackageType::VerseRecitation,
+        ))
+        .await
+        .unwrap();
+    let package = write_package_db(
+        &dir.path().join("recitation.db"),
+        &[
+            "CREATE TABLE verse_audio (verse_key TEXT, audio BLOB, audio_path TEXT, mime_type TEXT, duration_ms INTEGER)",
+            "CREATE TABLE word_segments (verse_key TEXT, position INTEGER, start_ms INTEGER, end_ms INTEGER)",
+            "INSERT INTO verse_audio VALUES ('1:1', X'494433', NULL, 'audio/mpeg', 4000)",
+            "INSERT INTO word_segments VALUES ('1:1', 1, 0, 900), ('1:1', 2, 900, 1500), ('1:1', 3, 1500, 2600), ('1:1', 4, 2600, 4000)",
+        ],
+    )
+    .await;
+    service
+        .install_package("recitation-test", package)
+        .await
+        .unwrap();
+
+    let validator = DefaultValidator::new();
+    let verse_id = nid::encode_verse(1, 1);
+
+    let exercise = generate_listen_identify_verse(verse_id, "VERSE:1:1", 7, repo.as_ref())
+        .await
+        .unwrap();
+    let ExerciseData::ListenIdentifyVerse {
+        first_position,
+        last_position,
+        start_ms,
+        end_ms,
+        ref option_verse_keys,
+        ..
+    } = exercise
+    else {
+        panic!("Expected ListenIdentifyVerse exercise");
+    };
+    assert_eq!((first_position, last_position), (1, 4));
+    assert_eq!((start_ms, end_ms), (0, 4000));
+    assert!(option_verse_keys.contains(&"1:1".to_string()));
+    assert!(option_verse_keys.len() >= 2);
+    let result = validator
+        .validate(
+            &exercise,
+            &AnswerInput::VerseKey {
+                value: "1:1".to_string(),
+            },
+            repo.as_ref(),
+        )
+        .await
+        .unwrap();
+    assert!(result.is_correct);
+
+    let exercise = generate_listen_continue(
+        verse_id,
+        "VERSE:1:1",
+        7,
+        DistractorDifficulty::Medium,
+        SemanticScoring::IfLoaded,
+        repo.as_ref(),
+    )
+    .await
+    .unwrap();
+    let ExerciseData::ListenContinue {
+        first_position,
+        last_position,
+        start_ms,
+        end_ms,
+        ..
+    } = exercise
+    else {
+        panic!("Expected ListenContinue exercise");
+    };
+    assert_eq!(first_position, 1);
+    assert!((1..4).contains(&last_position));
+    assert_eq!(start_ms, 0);
+    assert_eq!(end_ms, [900, 1500, 2600][last_position as usize - 1]);
+    let answer = nid::encode_word_instance(1, 1, last_position as u8 + 1);
+    let result = validator
+        .validate(
+            &exercise,
+            &AnswerInput::WordId { value: answer },
+            repo.as_ref(),
+        )
+        .await
+        .unwrap();
+    assert!(result.is_correct);
+
+    // Words without their own clip play their segment of the verse
+    let word_id = nid::encode_word_instance(1, 1, 3);
+    let exercise = generate_listen_identify_word(
+        word_id,
+        "WORD_INSTANCE:1:1:3",
+        7,
+        DistractorDifficulty::Medium,
+        SemanticScoring::IfLoaded,
+        repo.as_ref(),
+    )
+    .await
+    .unwrap();
+    let ExerciseData::ListenIdentifyWord {
+        position,
+        start_ms,
+        end_ms,
+        ..
+    } = exercise
+    else {
+        panic!("Expected ListenIdentifyWord exercise");
+    };
+    assert_eq!(position, 3);
+    assert_eq!((start_ms, end_ms), (Some(1500), Some(2600)));
+    let result = validator
+        .validate(
+            &exercise,
+            &AnswerInput::WordId { value: word_id },
+            repo.as_ref(),
+        )
+        .await
+        .unwrap();
+    assert!(result.is_correct);
+}
+
+#[tokio::test]
+async fn test_tajweed_exercises_from_annotations() {
+    use iqrah_core::exercises::{
+        generate_find_tajweed_rule, generate_identify_tajweed_rule, AnswerInput, DefaultValidator,
+        ExerciseData, ExerciseValidator,
+    };
+    use iqrah_core::TajweedRule;
+
+    let pool = init_test_content_db(":memory:").await.unwrap();
+    let word = |position| nid::encode_word_instance(1, 1, position);
+    for (position, start, end, rule) in [
+        (3, 9, 11, "madd_tabii"),
+        (2, 0, 1, "hamzat_wasl"),
+        (3, 0, 1, "hamzat_wasl"),
+        (4, 0, 1, "hamzat_wasl"),
+    ] {
+        sqlx::query(
+            "INSERT INTO tajweed_annotations (word_id, start_char, end_char, rule)
+             SELECT word_id, ?, ?, ? FROM words WHERE verse_key = '1:1' AND position = ?",
+        )
+        .bind(start)
+        .bind(end)
+        .bind(rule)
+        .bind(position)
+        .execute(&pool)
+        .await
+        .unwrap();
+    }
+    let repo = create_content_repository(pool);
+
+    let annotations = repo.get_tajweed_annotations("1:1").await.unwrap();
+    let spans: Vec<_> = annotations
+        .iter()
+        .map(|a| (a.position, a.start_char, a.rule))
+        .collect();
+    assert_eq!(
+        spans,
+        vec![
+            (2, 0, TajweedRule::HamzatWasl),
+            (3, 0, TajweedRule::HamzatWasl),
+            (3, 9, TajweedRule::MaddTabii),
+            (4, 0, TajweedRule::HamzatWasl),
+        ]
+    );
+    assert!(repo
+        .get_tajweed_annotations("1:2")
+        .await
+        .unwrap()
+        .is_empty());
+
+    let validator = DefaultValidator::new();
+    let verse_id = nid::encode_verse(1, 1);
+
+    for seed in 0..8 {
+        let exercise = generate_identify_tajweed_rule(verse_id, "VERSE:1:1", seed, &repo)
+            .await
+            .unwrap();
+        let ExerciseData::IdentifyTajweedRule {
+            correct_rule,
+            ref rule_options,
+            ..
+        } = exercise
+        else {
+            panic!("Expected IdentifyTajweedRule exercise");
+        };
+        assert_eq!(rule_options.len(), 4);
+        assert_eq!(
+            rule_options.iter().filter(|r| **r == correct_rule).count(),
+            1
+        );
+        // Distractors of the same family come first, then rules found in
+        // the verse
+        if correct_rule == TajweedRule::MaddTabii {
+            assert!(rule_options.iter().all(|r| r.family() == "madd"));
+        } else {
+            assert!(rule_options.contains(&TajweedRule::Silent));
+            assert!(rule_options.contains(&TajweedRule::LamShamsiyyah));
+            assert!(rule_options.contains(&TajweedRule::MaddTabii));
+        }
+
+        let result = validator
+            .validate(
+                &exercise,
+                &AnswerInput::Text {
+                    value: correct_rule.name().to_string(),
+                },
+                &repo,
+            )
+            .await
+            .unwrap();
+        assert!(result.is_correct);
+        let wrong = rule_options
+            .iter()
+            .position(|r| *r != correct_rule)
+            .unwrap();
+        let result = validator
+            .validate(&exercise, &AnswerInput::OptionIndex { value: wrong }, &repo)
+            .await
+            .unwrap();
+        assert!(!result.is_correct);
+    }
+
+    let exercise = generate_find_tajweed_rule(verse_id, "VERSE:1:1:tajweed", 3, &repo)
+        .await
+        .unwrap();
+    let ExerciseData::FindTajweedRule {
+        rule,
+        ref rule_word_node_ids,
+        ..
+    } = exercise
+    else {
+        panic!("Expected FindTajweedRule exercise");
+    };
+    let expected = match rule {
+        TajweedRule::HamzatWasl => vec![word(2), word(3), word(4)],
+        _ => vec![word(3)],
+    };
+    assert_eq!(rule_word_node_ids, &expected);
+    let mut picks = expected.clone();
+    picks.reverse();
+    let result = validator
+        .validate(&exercise, &AnswerInput::Sequence { values: picks }, &repo)
+        .await
+        .unwrap();
+    assert!(result.is_correct);
+    let result = validator
+        .validate(
+            &exercise,
+            &AnswerInput::Sequence {
+                values: vec![word(1)],
+            },
+            &repo,
+        )
+        .await
+        .unwrap();
+    assert!(!result.is_correct);
+
+    assert!(
+        generate_find_tajweed_rule(nid::encode_verse(1, 2), "VERSE:1:2", 0, &repo)
+            .await
+            .is_err()
+    );
+}
+
+#[tokio::test]
+async fn test_tafsir_package_and_exercises() {
+    use iqrah_core::exercises::{
+        generate_revelation_occasion, generate_tafsir_key_lesson, generate_tafsir_theme_connection,
+        AnswerInput, DefaultValidator, ExerciseData, ExerciseValidator,
+    };
+    use iqrah_core::{PackageService, PackageType};
+    use std::sync::Arc;
+
+    let dir = tempfile::TempDir::new().unwrap();
+    let pool = init_test_content_db(":memory:").await.unwrap();
+    let repo: Arc<dyn ContentRepository> = Arc::new(create_content_repository(pool));
+    let service = PackageService::new(Arc::clone(&repo));
+    let mut catalog_entry = audio_package("tafsir-test", PackageType::Tafsir);
+    catalog_entry.name = "Test Tafsir".to_string();
+    catalog_entry.language_code = Some("en".to_string());
+    service.add_to_catalog(&catalog_entry).await.unwrap();
+
+    let package = write_package_db(
+        &dir.path().join("tafsir.db"),
+        &[
+            "CREATE TABLE tafsir_passages (start_verse_key TEXT, end_verse_key TEXT, text TEXT, key_lesson TEXT, revelation_occasion TEXT)",
+            "CREATE TABLE tafsir_themes (start_verse_key TEXT, theme TEXT)",
+            "INSERT INTO tafsir_passages VALUES
+                ('1:1', '1:1', 'Beginning with the name of Allah.', 'Begin every deed with His name', NULL),
+                ('1:2', '1:4', 'All praise belongs to Allah.', 'All praise is due to Allah alone', 'Recited in every prayer'),
+                ('1:5', '1:5', 'Worship and help are sought from Him.', 'Worship Allah alone', NULL),
+                ('1:6', '1:7', 'A prayer for guidance.', 'Ask for guidance daily', NULL),
+                ('2:2', '2:5', 'Guidance for the mindful.', 'The Book guides the mindful', NULL)",
+            "INSERT INTO tafsir_themes VALUES
+                ('1:1', 'mercy'), ('1:2', 'praise'), ('1:2', 'mercy'),
+                ('1:5', 'worship'), ('1:6', 'guidance'), ('2:2', 'guidance')",
+        ],
+    )
+    .await;
+    service
+        .install_package("tafsir-test", package)
+        .await
+        .unwrap();
+
+    let tafsirs = repo.get_tafsirs().await.unwrap();
+    assert_eq!(tafsirs.len(), 1);
+    assert_eq!(tafsirs[0].name, "Test Tafsir");
+    assert_eq!(tafsirs[0].language_code.as_deref(), Some("en"));
+    let tafsir_id = tafsirs[0].tafsir_id;
+
+    // A verse inside a range resolves to the passage commenting on it
+    let passage = repo
+        .get_tafsir_passage("1:3", tafsir_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!((passage.start_verse, passage.end_verse), (2, 4));
+    assert_eq!(passage.themes, vec!["mercy", "praise"]);
+    assert_eq!(
+        passage.revelation_occasion.as_deref(),
+        Some("Recited in every prayer")
+    );
+    assert!(repo
+        .get_tafsir_passage("2:1", tafsir_id)
+        .await
+        .unwrap()
+        .is_none());
+
+    let chapter = repo
+        .get_tafsir_passages_for_chapter(1, tafsir_id)
+        .await
+        .unwrap();
+    let starts: Vec<i32> = chapter.iter().map(|p| p.start_verse).collect();
+    assert_eq!(starts, vec![1, 2, 5, 6]);
+    let guidance = repo
+        .get_tafsir_passages_by_theme("guidance", tafsir_id)
+        .await
+        .unwrap();
+    let keys: Vec<String> = guidance.iter().map(|p| p.start_verse_key()).collect();
+    assert_eq!(keys, vec!["1:6", "2:2"]);
+
+    let validator = DefaultValidator::new();
+    let verse_id = nid::encode_verse(1, 3);
+
+    for seed in 0..4 {
+        let exercise = generate_tafsir_key_lesson(verse_id, "VERSE:1:3", seed, repo.as_ref())
+            .await
+            .unwrap();
+        let ExerciseData::TafsirKeyLesson {
+            ref passage_key,
+            ref option_passage_keys,
+            ..
+        } = exercise
+        else {
+            panic!("Expected TafsirKeyLesson exercise");
+        };
+        assert_eq!(passage_key, "1:2");
+        assert_eq!(option_passage_keys.len(), 4);
+        let correct = option_passage_keys
+            .iter()
+            .position(|k| k == passage_key)
+            .unwrap();
+        let result = validator
+            .validate(
+                &exercise,
+                &AnswerInput::OptionIndex { value: correct },
+                repo.as_ref(),
+            )
+            .await
+            .unwrap();
+        assert!(result.is_correct);
+        let result = validator
+            .validate(
+                &exercise,
+                &AnswerInput::OptionIndex {
+                    value: (correct + 1) % 4,
+                },
+                repo.as_ref(),
+            )
+            .await
+            .unwrap();
+        assert!(!result.is_correct);
+    }
+
+    let exercise = generate_revelation_occasion(verse_id, "VERSE:1:3", 7, repo.as_ref())
+        .await
+        .unwrap();
+    let ExerciseData::RevelationOccasion {
+        ref option_verse_keys,
+        ..
+    } = exercise
+    else {
+        panic!("Expected RevelationOccasion exercise");
+    };
+    assert_eq!(option_verse_keys.len(), 4);
+    // Other verses of the passage share its occasion and are never offered
+    assert!(!option_verse_keys.iter().any(|k| k == "1:2" || k == "1:4"));
+    let result = validator
+        .validate(
+            &exercise,
+            &AnswerInput::VerseKey {
+                value: "1:3".to_string(),
+            },
+            repo.as_ref(),
+        )
+        .await
+        .unwrap();
+    assert!(result.is_correct);
+    assert!(
+        generate_revelation_occasion(nid::encode_verse(1, 1), "VERSE:1:1", 0, repo.as_ref())
+            .await
+            .is_err()
+    );
+
+    let exercise = generate_tafsir_theme_connection(
+        nid::encode_verse(1, 6),
+        "VERSE:1:6:tafsir",
+        0,
+        repo.as_ref(),
+    )
+    .await
+    .unwrap();
+    let ExerciseData::CrossVerseConnection {
+        ref related_verse_ids,
+        ref connection_theme,
+        ..
+    } = exercise
+    else {
+        panic!("Expected CrossVerseConnection exercise");
+    };
+    assert_eq!(connection_theme, "guidance");
+    assert_eq!(related_verse_ids[0], nid::encode_verse(2, 2));
+    assert!(related_verse_ids.len() > 1);
+    assert!(!related_verse_ids.contains(&nid::encode_verse(1, 7)));
+    let result = validator
+        .validate(
+            &exercise,
+            &AnswerInput::VerseKey {
+                value: "2:2".to_string(),
+            },
+            repo.as_ref(),
+        )
+        .await
+        .unwrap();
+    assert!(result.is_correct);
+
+    // Disabled packages hide their tafsir; uninstalling removes the passages
+    service.disable_package("tafsir-test").await.unwrap();
+    assert!(repo.get_tafsirs().await.unwrap().is_empty());
+    assert!(
+        generate_tafsir_key_lesson(verse_id, "VERSE:1:3", 0, repo.as_ref())
+            .await
+            .is_err()
+    );
+    service.uninstall_package("tafsir-test").await.unwrap();
+    assert!(repo
+        .get_tafsir_passage("1:3", tafsir_id)
+        .await
+        .unwrap()
+        .is_none());
+}
@@ -40,6 +40,16 @@ db_path = Path(sys.argv[2])
 content_sql = root / "crates/iqrah-storage/migrations_content/20241126000001_unified_content_schema.sql"
 user_sql = root / "crates/iqrah-storage/migrations_user/20241126000001_user_schema.sql"
 sessions_sql = root / "crates/iqrah-storage/migrations_user/20250115000001_sessions.sql"
+mutashabihat_sql = root / "crates/iqrah-storage/migrations_content/20250205000001_mutashabihat.sql"
+node_status_sql = root / "crates/iqrah-storage/migrations_user/20250201000001_node_status.sql"
+session_item_hints_sql = root / "crates/iqrah-storage/migrations_user/20250210000001_session_item_hints.sql"
+exercise_checkpoints_sql = root / "crates/iqrah-storage/migrations_user/20250215000001_exercise_checkpoints.sql"
+morphology_features_sql = root / "crates/iqrah-storage/migrations_content/20250210000001_morphology_features.sql"
+mushaf_layout_sql = root / "crates/iqrah-storage/migrations_content/20250220000001_mushaf_layout.sql"
+recitation_audio_sql = root / "crates/iqrah-storage/migrations_content/20250301000001_recitation_audio.sql"
+tajweed_annotations_sql = root / "crates/iqrah-storage/migrations_content/20250310000001_tajweed_annotations.sql"
+tafsir_sql = root / "crates/iqrah-storage/migrations_content/20250320000001_tafsir.sql"
+morphology_indexes_sql = root / "crates/iqrah-storage/migrations_content/20250325000001_morphology_indexes.sql"
 
 def normalize_schema_version(sql: str) -> str:
     sql = sql.replace(
@@ -57,8 +67,18 @@ def normalize_schema_version(sql: str) -> str:
 conn = sqlite3.connect(db_path)
 try:
     conn.executescript(normalize_schema_version(content_sql.read_text(encoding="utf-8")))
+    conn.executescript(mutashabihat_sql.read_text(encoding="utf-8"))
+    conn.executescript(morphology_features_sql.read_text(encoding="utf-8"))
+    conn.executescript(mushaf_layout_sql.read_text(encoding="utf-8"))
+    conn.executescript(recitation_audio_sql.read_text(encoding="utf-8"))
+    conn.executescript(tajweed_annotations_sql.read_text(encoding="utf-8"))
+    conn.executescript(tafsir_sql.read_text(encoding="utf-8"))
+    conn.executescript(morphology_indexes_sql.read_text(encoding="utf-8"))
     conn.executescript(normalize_schema_version(user_sql.read_text(encoding="utf-8")))
     conn.executescript(sessions_sql.read_text(encoding="utf-8"))
+    conn.executescript(node_status_sql.read_text(encoding="utf-8"))
+    conn.executescript(session_item_hints_sql.read_text(encoding="utf-8"))
+    conn.executescript(exercise_checkpoints_sql.read_text(encoding="utf-8"))
 finally:
     conn.close()
 PY
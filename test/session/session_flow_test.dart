@@ -53,10 +53,9 @@ class FakeSessionService extends SessionService {
   @override
   Future<void> submitItem({
     required String sessionId,
-    required String nodeId,
-    required String exerciseType,
-    required int grade,
+    required bool isCorrect,
     required int durationMs,
+    int hintLevel = 0,
   }) async {
     if (submitItemError != null) throw submitItemError!;
     submitCount += 1;
@@ -97,7 +96,8 @@ api.SessionItemDto _buildItem({
     position: position,
     nodeId: nodeId,
     exerciseType: 'memorization',
-    exercise: api.ExerciseDataDto.memorization(nodeId: nodeId),
+    exercise:
+        api.ExerciseDataDto.memorization(nodeId: nodeId, seed: BigInt.zero),
   );
 }
 
@@ -12,6 +12,7 @@ void main() {
     final echoExercise = api.ExerciseDataDto.echoRecall(
       userId: 'user-1',
       ayahNodeIds: const ['VERSE:1:1'],
+      seed: BigInt.zero,
     );
 
     notifier.startAdhocReview([echoExercise, echoExercise]);
@@ -54,7 +55,8 @@ void main() {
     addTearDown(container.dispose);
 
     final notifier = container.read(sessionProvider.notifier);
-    final exercise = api.ExerciseDataDto.memorization(nodeId: 'WORD:1');
+    final exercise =
+        api.ExerciseDataDto.memorization(nodeId: 'WORD:1', seed: BigInt.zero);
 
     notifier.startAdhocReview([exercise]);
 
@@ -75,6 +77,7 @@ void main() {
     final echoExercise = api.ExerciseDataDto.echoRecall(
       userId: 'user-1',
       ayahNodeIds: const [], // Empty list
+      seed: BigInt.zero,
     );
 
     notifier.startAdhocReview([echoExercise]);
@@ -94,7 +97,8 @@ void main() {
     addTearDown(container.dispose);
 
     final notifier = container.read(sessionProvider.notifier);
-    final exercise = api.ExerciseDataDto.memorization(nodeId: 'WORD:1');
+    final exercise =
+        api.ExerciseDataDto.memorization(nodeId: 'WORD:1', seed: BigInt.zero);
 
     notifier.startAdhocReview([exercise]);
     expect(container.read(sessionProvider).mode, SessionMode.adhoc);
@@ -110,7 +114,8 @@ void main() {
     addTearDown(container.dispose);
 
     final notifier = container.read(sessionProvider.notifier);
-    final exercise = api.ExerciseDataDto.memorization(nodeId: 'WORD:1');
+    final exercise =
+        api.ExerciseDataDto.memorization(nodeId: 'WORD:1', seed: BigInt.zero);
 
     // First adhoc session
     notifier.startAdhocReview([exercise]);
@@ -144,9 +149,9 @@ void main() {
 
     final notifier = container.read(sessionProvider.notifier);
     final exercises = [
-      api.ExerciseDataDto.memorization(nodeId: 'WORD:1'),
-      api.ExerciseDataDto.memorization(nodeId: 'WORD:2'),
-      api.ExerciseDataDto.memorization(nodeId: 'WORD:3'),
+      api.ExerciseDataDto.memorization(nodeId: 'WORD:1', seed: BigInt.zero),
+      api.ExerciseDataDto.memorization(nodeId: 'WORD:2', seed: BigInt.zero),
+      api.ExerciseDataDto.memorization(nodeId: 'WORD:3', seed: BigInt.zero),
     ];
 
     notifier.startAdhocReview(exercises);